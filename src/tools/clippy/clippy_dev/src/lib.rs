@@ -22,7 +22,7 @@
     Regex::new(
         r#"(?x)
     declare_clippy_lint!\s*[\{(]
-    (?:\s+///.*)*
+    (?P<docs>(?:\s+///.*)*)
     \s+pub\s+(?P<name>[A-Z_][A-Z_0-9]*)\s*,\s*
     (?P<cat>[a-z_]+)\s*,\s*
     "(?P<desc>(?:[^"\\]+|\\(?s).(?-s))*)"\s*[})]
@@ -54,6 +54,8 @@ pub struct Lint {
     pub desc: String,
     pub deprecation: Option<String>,
     pub module: String,
+    /// The documentation, without the `///` of the doc comments
+    pub docs: String,
 }
 
 impl Lint {
@@ -65,9 +67,22 @@ pub fn new(name: &str, group: &str, desc: &str, deprecation: Option<&str>, modul
             desc: NL_ESCAPE_RE.replace(&desc.replace("\\\"", "\""), "").to_string(),
             deprecation: deprecation.map(ToString::to_string),
             module: module.to_string(),
+            docs: String::new(),
         }
     }
 
+    /// Sets the documentation from the doc comments `docs`, e.g. `/// **What it does:** ..`.
+    #[must_use]
+    pub fn with_docs(mut self, docs: &str) -> Self {
+        self.docs = docs
+            .lines()
+            .map(str::trim_start)
+            .filter_map(|line| line.strip_prefix("///"))
+            .map(|line| line.strip_prefix(' ').unwrap_or(line))
+            .join("\n");
+        self
+    }
+
     /// Returns all non-deprecated lints and non-internal lints
     #[must_use]
     pub fn usable_lints(lints: &[Self]) -> Vec<Self> {
@@ -117,6 +132,38 @@ pub fn gen_modules_list<'a>(lints: impl Iterator<Item = &'a Lint>) -> Vec<String
         .collect::<Vec<String>>()
 }
 
+/// Generates the list of lint categories in `src/sarif.rs`.
+#[must_use]
+pub fn gen_lint_category_list<'a>(lints: impl Iterator<Item = &'a Lint>) -> Vec<String> {
+    lints
+        .sorted_by_key(|l| &l.name)
+        .map(|l| format!("    (\"{}\", \"{}\"),", l.name, l.group))
+        .collect()
+}
+
+/// Generates the entries of `ALL_LINTS` in `clippy_lints/src/lint_list.rs`.
+#[must_use]
+pub fn gen_lint_info_list<'a>(lints: impl Iterator<Item = &'a Lint>) -> Vec<String> {
+    lints
+        .sorted_by_key(|l| &l.name)
+        .flat_map(|l| {
+            // the shortest delimiter of a raw string that doesn't occur in the documentation
+            let hashes = (0..)
+                .map(|n| "#".repeat(n))
+                .find(|hashes| !l.docs.contains(&format!("\"{}", hashes)))
+                .unwrap();
+            vec![
+                "    LintInfo {".to_string(),
+                format!("        lint: crate::{}::{},", l.module, l.name.to_uppercase()),
+                format!("        name: \"{}\",", l.name),
+                format!("        group: \"{}\",", l.group),
+                format!("        docs: r{0}\"{1}\"{0},", hashes, l.docs),
+                "    },".to_string(),
+            ]
+        })
+        .collect()
+}
+
 /// Generates the list of lint links at the bottom of the README
 #[must_use]
 pub fn gen_changelog_lint_list<'a>(lints: impl Iterator<Item = &'a Lint>) -> Vec<String> {
@@ -204,7 +251,7 @@ fn gather_from_file(dir_entry: &walkdir::DirEntry) -> impl Iterator<Item = Lint>
 fn parse_contents(content: &str, module: &str) -> impl Iterator<Item = Lint> {
     let lints = DEC_CLIPPY_LINT_RE
         .captures_iter(content)
-        .map(|m| Lint::new(&m["name"], &m["cat"], &m["desc"], None, module));
+        .map(|m| Lint::new(&m["name"], &m["cat"], &m["desc"], None, module).with_docs(&m["docs"]));
     let deprecated = DEC_DEPRECATED_LINT_RE
         .captures_iter(content)
         .map(|m| Lint::new(&m["name"], "Deprecated", &m["desc"], Some(&m["desc"]), module));
@@ -401,6 +448,36 @@ fn test_parse_contents() {
     assert_eq!(expected, result);
 }
 
+#[test]
+fn test_parse_contents_docs() {
+    let result: Vec<Lint> = parse_contents(
+        r#"
+declare_clippy_lint! {
+    /// **What it does:** Checks for things.
+    ///
+    /// ```rust
+    ///     indented();
+    /// ```
+    pub PTR_ARG,
+    style,
+    "text"
+}
+    "#,
+        "module_name",
+    )
+    .collect();
+
+    let expected = vec![
+        Lint::new("ptr_arg", "style", "text", None, "module_name")
+            .with_docs("/// **What it does:** Checks for things.\n///\n/// ```rust\n///     indented();\n/// ```"),
+    ];
+    assert_eq!(expected, result);
+    assert_eq!(
+        "**What it does:** Checks for things.\n\n```rust\n    indented();\n```",
+        result[0].docs
+    );
+}
+
 #[test]
 fn test_replace_region() {
     let text = "\nabc\n123\n789\ndef\nghi";
@@ -542,6 +619,42 @@ fn test_gen_modules_list() {
     assert_eq!(expected, gen_modules_list(lints.iter()));
 }
 
+#[test]
+fn test_gen_lint_category_list() {
+    let lints = vec![
+        Lint::new("should_assert_eq", "group1", "abc", None, "module_name"),
+        Lint::new("incorrect_stuff", "group3", "abc", None, "another_module"),
+    ];
+    let expected = vec![
+        "    (\"incorrect_stuff\", \"group3\"),".to_string(),
+        "    (\"should_assert_eq\", \"group1\"),".to_string(),
+    ];
+    assert_eq!(expected, gen_lint_category_list(lints.iter()));
+}
+
+#[test]
+fn test_gen_lint_info_list() {
+    let lints = vec![
+        Lint::new("should_assert_eq", "group1", "abc", None, "module_name").with_docs("/// Use `\"a\"#`."),
+        Lint::new("incorrect_stuff", "group3", "abc", None, "another_module").with_docs("/// Line\n///\n///  Code"),
+    ];
+    let expected = vec![
+        "    LintInfo {",
+        "        lint: crate::another_module::INCORRECT_STUFF,",
+        "        name: \"incorrect_stuff\",",
+        "        group: \"group3\",",
+        "        docs: r\"Line\n\n Code\",",
+        "    },",
+        "    LintInfo {",
+        "        lint: crate::module_name::SHOULD_ASSERT_EQ,",
+        "        name: \"should_assert_eq\",",
+        "        group: \"group1\",",
+        "        docs: r##\"Use `\"a\"#`.\"##,",
+        "    },",
+    ];
+    assert_eq!(expected, gen_lint_info_list(lints.iter()));
+}
+
 #[test]
 fn test_gen_lint_group_list() {
     let lints = vec![
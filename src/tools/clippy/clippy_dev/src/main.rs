@@ -94,7 +94,7 @@ fn get_clap_config<'a>() -> ArgMatches<'a> {
         )
         .subcommand(
             SubCommand::with_name("new_lint")
-                .about("Create new lint and run `cargo dev update_lints`")
+                .about("Create new lint, register its lint pass and run `cargo dev update_lints`")
                 .arg(
                     Arg::with_name("pass")
                         .short("p")
@@ -27,7 +27,11 @@ fn context<C: AsRef<str>>(self, text: C) -> Self {
     }
 }
 
-/// Creates the files required to implement and test a new lint and runs `update_lints`.
+/// The comment in `clippy_lints/src/lib.rs` before which the passes of new lints are registered.
+const REGISTER_PASSES_END: &str = "// end register passes, do not remove this comment, it’s used in `new_lint`";
+
+/// Creates the files required to implement and test a new lint, registers its lint pass in
+/// `clippy_lints/src/lib.rs` and runs `update_lints`.
 ///
 /// # Errors
 ///
@@ -41,7 +45,8 @@ pub fn create(pass: Option<&str>, lint_name: Option<&str>, category: Option<&str
     };
 
     create_lint(&lint).context("Unable to create lint implementation")?;
-    create_test(&lint).context("Unable to create a test for the new lint")
+    create_test(&lint).context("Unable to create a test for the new lint")?;
+    register_pass(&lint).context("Unable to register the lint pass")
 }
 
 fn create_lint(lint: &LintData<'_>) -> io::Result<()> {
@@ -96,6 +101,45 @@ fn create_project_layout<P: Into<PathBuf>>(lint_name: &str, location: P, case: &
     }
 }
 
+/// Registers the lint pass of the new lint after the passes of the other lints in
+/// `register_plugins`.
+fn register_pass(lint: &LintData<'_>) -> io::Result<()> {
+    let path = lint.project_root.join("clippy_lints/src/lib.rs");
+    let lib = fs::read_to_string(&path).context(format!("reading file: {}", path.display()))?;
+    let registration = get_registration(lint.pass, lint.name, &to_camel_case(lint.name));
+    let lib = insert_registration(&lib, &registration).ok_or_else(|| {
+        io::Error::new(
+            ErrorKind::Other,
+            format!("`{}` is missing from `clippy_lints/src/lib.rs`", REGISTER_PASSES_END),
+        )
+    })?;
+    fs::write(&path, lib).context(format!("writing to file: {}", path.display()))
+}
+
+/// Inserts the line `registration` before the line of `REGISTER_PASSES_END` in `lib`, with the
+/// same indentation. Returns `None` if `lib` doesn't contain the comment.
+fn insert_registration(lib: &str, registration: &str) -> Option<String> {
+    let end = lib.find(REGISTER_PASSES_END)?;
+    let line_start = lib[..end].rfind('\n').map_or(0, |i| i + 1);
+    let indent = &lib[line_start..end];
+    Some(format!(
+        "{}{}{}\n{}",
+        &lib[..line_start],
+        indent,
+        registration,
+        &lib[line_start..]
+    ))
+}
+
+fn get_registration(pass: &str, lint_name: &str, camel_case_name: &str) -> String {
+    format!(
+        "passes.register_{pass}_pass({name}::{camel}::get_lints(), || box {name}::{camel});",
+        pass = pass,
+        name = lint_name,
+        camel = camel_case_name
+    )
+}
+
 fn write_file<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> io::Result<()> {
     fn inner(path: &Path, contents: &[u8]) -> io::Result<()> {
         OpenOptions::new()
@@ -203,6 +247,24 @@ impl {type}{lifetimes} for {name_camel} {{}}
     )
 }
 
+#[test]
+fn test_insert_registration() {
+    let lib = format!("fn register_plugins() {{\n    {}\n}}\n", REGISTER_PASSES_END);
+    let registration = get_registration("early", "foo_functions", "FooFunctions");
+    assert_eq!(
+        registration,
+        "passes.register_early_pass(foo_functions::FooFunctions::get_lints(), || box foo_functions::FooFunctions);"
+    );
+    assert_eq!(
+        insert_registration(&lib, &registration).unwrap(),
+        format!(
+            "fn register_plugins() {{\n    {}\n    {}\n}}\n",
+            registration, REGISTER_PASSES_END
+        )
+    );
+    assert_eq!(insert_registration("fn register_plugins() {}", &registration), None);
+}
+
 #[test]
 fn test_camel_case() {
     let s = "a_lint";
@@ -1,6 +1,6 @@
 use crate::{
-    gather_all, gen_changelog_lint_list, gen_deprecated, gen_lint_group_list, gen_modules_list, gen_register_lint_list,
-    replace_region_in_file, Lint, DOCS_LINK,
+    gather_all, gen_changelog_lint_list, gen_deprecated, gen_lint_category_list, gen_lint_group_list,
+    gen_lint_info_list, gen_modules_list, gen_register_lint_list, replace_region_in_file, Lint, DOCS_LINK,
 };
 use std::path::Path;
 
@@ -82,10 +82,30 @@ pub fn run(update_mode: UpdateMode) {
     )
     .changed;
 
+    file_change |= replace_region_in_file(
+        Path::new("clippy_lints/src/lint_list.rs"),
+        "begin lint list",
+        "end lint list",
+        false,
+        update_mode == UpdateMode::Change,
+        || gen_lint_info_list(usable_lints.iter()),
+    )
+    .changed;
+
+    file_change |= replace_region_in_file(
+        Path::new("src/sarif.rs"),
+        "begin lint categories",
+        "end lint categories",
+        false,
+        update_mode == UpdateMode::Change,
+        || gen_lint_category_list(usable_lints.iter()),
+    )
+    .changed;
+
     // Generate lists of lints in the clippy::all lint group
     file_change |= replace_region_in_file(
         Path::new("clippy_lints/src/lib.rs"),
-        r#"store.register_group\(true, "clippy::all""#,
+        r#"groups.register_group\(true, "clippy::all""#,
         r#"\]\);"#,
         false,
         update_mode == UpdateMode::Change,
@@ -104,7 +124,7 @@ pub fn run(update_mode: UpdateMode) {
     for (lint_group, lints) in Lint::by_lint_group(usable_lints.into_iter().chain(internal_lints)) {
         file_change |= replace_region_in_file(
             Path::new("clippy_lints/src/lib.rs"),
-            &format!("store.register_group\\(true, \"clippy::{}\"", lint_group),
+            &format!("groups.register_group\\(true, \"clippy::{}\"", lint_group),
             r#"\]\);"#,
             false,
             update_mode == UpdateMode::Change,
@@ -1,22 +1,24 @@
 //! checks for attributes
 
-use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then};
-use clippy_utils::match_panic_def_id;
+use clippy_utils::attrs::get_attr;
+use clippy_utils::diagnostics::{allow_in_stmt, span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::source::{first_line_of_span, is_present_in_source, snippet_opt, without_block_comments};
+use clippy_utils::{in_external_macro, match_panic_def_id};
 use if_chain::if_chain;
-use rustc_ast::{AttrKind, AttrStyle, Attribute, Lit, LitKind, MetaItemKind, NestedMetaItem};
+use rustc_ast::{AstLike, AttrKind, AttrStyle, Attribute, Lit, LitKind, MetaItemKind, NestedMetaItem};
+use rustc_ast_pretty::pprust;
 use rustc_errors::Applicability;
 use rustc_hir::{
     Block, Expr, ExprKind, ImplItem, ImplItemKind, Item, ItemKind, StmtKind, TraitFn, TraitItem, TraitItemKind,
 };
 use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintContext};
-use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::source_map::Span;
 use rustc_span::sym;
 use rustc_span::symbol::{Symbol, SymbolStr};
 use semver::Version;
+use std::slice;
 
 static UNIX_SYSTEMS: &[&str] = &[
     "android",
@@ -506,6 +508,38 @@ fn check_attribute(&mut self, cx: &EarlyContext<'_>, attr: &Attribute) {
         check_deprecated_cfg_attr(cx, attr);
         check_mismatched_target_os(cx, attr);
     }
+
+    fn check_stmt(&mut self, cx: &EarlyContext<'_>, stmt: &rustc_ast::Stmt) {
+        check_allow_next(cx, stmt);
+    }
+}
+
+/// Registers the lints allowed in `stmt` by `#[clippy::allow_next(..)]`, and by `#[allow(..)]` if
+/// it is a macro call, see `allow_in_stmt`. This runs before the expansion, which drops the
+/// attributes of macro calls.
+fn check_allow_next(cx: &EarlyContext<'_>, stmt: &rustc_ast::Stmt) {
+    let is_mac_call = matches!(stmt.kind, rustc_ast::StmtKind::MacCall(_));
+    let mut lints = Vec::new();
+    for attr in stmt.attrs() {
+        let is_allow_next = get_attr(cx.sess, slice::from_ref(attr), "allow_next").next().is_some();
+        if !is_allow_next && !(is_mac_call && attr.has_name(sym::allow)) {
+            continue;
+        }
+        for item in attr.meta_item_list().unwrap_or_default() {
+            if let Some(meta) = item.meta_item() {
+                let name = pprust::path_to_string(&meta.path).to_lowercase();
+                // `#[clippy::allow_next]` defaults to Clippy lints
+                if is_allow_next && meta.path.segments.len() == 1 {
+                    lints.push(format!("clippy::{}", name));
+                } else {
+                    lints.push(name);
+                }
+            }
+        }
+    }
+    if !lints.is_empty() {
+        allow_in_stmt(stmt.span, lints);
+    }
 }
 
 fn check_empty_line_after_outer_attr(cx: &EarlyContext<'_>, item: &rustc_ast::Item) {
@@ -482,8 +482,12 @@ impl<'a, 'tcx> Visitor<'tcx> for NotSimplificationVisitor<'a, 'tcx> {
     type Map = Map<'tcx>;
 
     fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
-        if let ExprKind::Unary(UnOp::Not, inner) = &expr.kind {
-            if let Some(suggestion) = simplify_not(self.cx, inner) {
+        if_chain! {
+            if let ExprKind::Unary(UnOp::Not, inner) = &expr.kind;
+            // the suggestion can't be applied to an expression from a macro's definition
+            if !in_macro(expr.span);
+            if let Some(suggestion) = simplify_not(self.cx, inner);
+            then {
                 span_lint_and_sugg(
                     self.cx,
                     NONMINIMAL_BOOL,
@@ -48,7 +48,8 @@ fn should_lint(cx: &LateContext<'_>, cast_op: &Expr<'_>, cast_from: Ty<'_>, cast
                 if_chain! {
                     if method_name == "unwrap";
                     if let Some(arglist) = method_chain_args(cast_op, &["unwrap"]);
-                    if let ExprKind::MethodCall(inner_path, _, _, _) = &arglist[0][0].kind;
+                    if let Some([receiver, ..]) = arglist.first();
+                    if let ExprKind::MethodCall(inner_path, _, _, _) = &receiver.kind;
                     then {
                         method_name = inner_path.ident.name.as_str();
                     }
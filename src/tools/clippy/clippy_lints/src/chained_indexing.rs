@@ -0,0 +1,150 @@
+//! lint on chained indexing of nested collections, e.g. `v[i][j]`
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::value_range::value_range;
+use clippy_utils::{get_parent_expr, higher};
+use rustc_errors::Applicability;
+use rustc_hir::{BorrowKind, Expr, ExprKind, Mutability};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for chained indexing of nested `Vec`s, slices and arrays, like
+    /// `v[i][j]` on a `Vec<Vec<T>>`.
+    ///
+    /// **Why is this bad?** Every index of the chain can panic. Chaining `get` calls with
+    /// `and_then` handles all of them in a single `Option`.
+    ///
+    /// **Known problems:** The suggested rewrite returns an `Option`, so the surrounding code
+    /// usually needs to be adapted as well. Indexes that are known to be in bounds, e.g. because of
+    /// an `if i < v.len()` guard, are not linted.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let grid = vec![vec![0; 3]; 3];
+    /// let (i, j) = (1, 2);
+    ///
+    /// // Bad
+    /// let cell = &grid[i][j];
+    ///
+    /// // Good
+    /// let cell = grid.get(i).and_then(|r| r.get(j));
+    /// ```
+    pub CHAINED_INDEXING,
+    pedantic,
+    "chained indexing of nested collections, like `v[i][j]`"
+}
+
+declare_lint_pass!(ChainedIndexing => [CHAINED_INDEXING]);
+
+impl<'tcx> LateLintPass<'tcx> for ChainedIndexing {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        // only the outermost indexing of a chain is linted
+        if !matches!(expr.kind, ExprKind::Index(..)) || expr.span.from_expansion() {
+            return;
+        }
+        if let Some(parent) = get_parent_expr(cx, expr) {
+            if matches!(parent.kind, ExprKind::Index(base, _) if base.hir_id == expr.hir_id) {
+                return;
+            }
+        }
+
+        // the indexes from the innermost to the outermost one
+        let mut indexes = Vec::new();
+        let mut base = expr;
+        while let ExprKind::Index(inner, index) = base.kind {
+            if higher::range_like(cx, index).is_some()
+                || !is_indexable_collection(cx, cx.typeck_results().expr_ty(inner))
+            {
+                break;
+            }
+            indexes.push((inner, index));
+            base = inner;
+        }
+        indexes.reverse();
+        if indexes.len() < 2 || indexes.iter().all(|&(inner, index)| is_in_bounds(cx, inner, index)) {
+            return;
+        }
+
+        span_lint_and_then(cx, CHAINED_INDEXING, expr.span, "chained indexing may panic", |diag| {
+            let help = "consider chaining `.get()` calls with `.and_then()` instead";
+            if let Some((span, sugg, applicability)) = suggestion(cx, expr, base, &indexes) {
+                diag.span_suggestion_verbose(span, help, sugg, applicability);
+            } else {
+                diag.help(help);
+            }
+        });
+    }
+}
+
+/// Whether `ty` is a (reference to a) `Vec`, slice or array.
+fn is_indexable_collection(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    let ty = ty.peel_refs();
+    matches!(ty.kind(), ty::Slice(_) | ty::Array(..)) || is_type_diagnostic_item(cx, ty, sym::vec_type)
+}
+
+/// Whether `index` is known to be in bounds of `collection`.
+fn is_in_bounds<'tcx>(cx: &LateContext<'tcx>, collection: &'tcx Expr<'tcx>, index: &'tcx Expr<'tcx>) -> bool {
+    let len = match cx.typeck_results().expr_ty(collection).peel_refs().kind() {
+        ty::Array(_, len) => len.try_eval_usize(cx.tcx, cx.param_env).map(u128::from),
+        _ => None,
+    };
+    value_range(cx, index).is_below_len(cx, collection, len)
+}
+
+/// Builds the `v.get(i).and_then(|r| r.get(j))` rewrite of `v[i][j]`, whose innermost collection
+/// is `base`. A borrow of the indexing expression is replaced as a whole. Returns `None` if the
+/// indexing expression is used as a place, e.g. as the receiver of a method call.
+fn suggestion(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    base: &Expr<'_>,
+    indexes: &[(&Expr<'_>, &Expr<'_>)],
+) -> Option<(Span, String, Applicability)> {
+    let (span, method) = match get_parent_expr(cx, expr) {
+        Some(parent) => match parent.kind {
+            ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _) => (parent.span, "get"),
+            ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, _) => (parent.span, "get_mut"),
+            ExprKind::Field(place, _)
+            | ExprKind::Assign(place, ..)
+            | ExprKind::AssignOp(_, place, _)
+            | ExprKind::MethodCall(_, _, [place, ..], _)
+                if place.hir_id == expr.hir_id =>
+            {
+                return None;
+            }
+            _ => (expr.span, "get"),
+        },
+        None => (expr.span, "get"),
+    };
+
+    // `get` returns an `Option`, so the rewrite is never a drop-in replacement
+    let mut applicability = Applicability::MaybeIncorrect;
+    let receiver = Sugg::hir_with_applicability(cx, base, "..", &mut applicability).maybe_par();
+    let indexes: Vec<_> = indexes
+        .iter()
+        .map(|&(_, index)| snippet_with_applicability(cx, index.span, "..", &mut applicability))
+        .collect();
+
+    // the closure parameter must not shadow a variable used by the indexes
+    let mut param = String::from("r");
+    while indexes.iter().any(|index| {
+        index
+            .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .any(|word| word == param)
+    }) {
+        param.push('_');
+    }
+
+    let mut sugg = format!("{}.{}({})", receiver, method, indexes[0]);
+    for index in &indexes[1..] {
+        sugg.push_str(&format!(".and_then(|{0}| {0}.{1}({2}))", param, method, index));
+    }
+    Some((span, sugg, applicability))
+}
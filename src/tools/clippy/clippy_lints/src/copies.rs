@@ -226,7 +226,7 @@ fn lint_same_then_else<'tcx>(
             expr,
         );
     } else if end_eq != 0 || (has_expr && expr_eq) {
-        let block = blocks[blocks.len() - 1];
+        let block = *blocks.last().unwrap();
         let (start_stmts, block_stmts) = block.stmts.split_at(start_eq);
         let (block_stmts, end_stmts) = block_stmts.split_at(block_stmts.len() - end_eq);
 
@@ -445,7 +445,7 @@ fn emit_branches_sharing_code_lint(
     }
 
     if lint_end {
-        let block = blocks[blocks.len() - 1];
+        let block = *blocks.last().unwrap();
         let span_end = block.span.shrink_to_hi();
 
         let moved_start = if end_stmts == 0 && block.expr.is_some() {
@@ -456,7 +456,7 @@ fn emit_branches_sharing_code_lint(
         .source_callsite();
         let moved_end = block
             .expr
-            .map_or_else(|| block.stmts[block.stmts.len() - 1].span, |expr| expr.span)
+            .map_or_else(|| block.stmts.last().unwrap().span, |expr| expr.span)
             .source_callsite();
 
         let moved_span = moved_start.to(moved_end);
@@ -1,6 +1,6 @@
 use clippy_utils::diagnostics::{span_lint, span_lint_and_note};
 use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
-use clippy_utils::{is_entrypoint_fn, is_expn_of, match_panic_def_id, method_chain_args, return_ty};
+use clippy_utils::{in_external_macro, is_entrypoint_fn, is_expn_of, match_panic_def_id, method_chain_args, return_ty};
 use if_chain::if_chain;
 use itertools::Itertools;
 use rustc_ast::ast::{Async, AttrKind, Attribute, FnKind, FnRetTy, ItemKind};
@@ -14,7 +14,6 @@
 use rustc_hir::{AnonConst, Expr, ExprKind, QPath};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::map::Map;
-use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
 use rustc_parse::maybe_new_parser_from_source_str;
 use rustc_parse::parser::ForceCollect;
@@ -727,8 +726,8 @@ fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
         }
 
         // check for `unwrap`
-        if let Some(arglists) = method_chain_args(expr, &["unwrap"]) {
-            let reciever_ty = self.typeck_results.expr_ty(&arglists[0][0]).peel_refs();
+        if let Some([[receiver, ..], ..]) = method_chain_args(expr, &["unwrap"]).as_deref() {
+            let reciever_ty = self.typeck_results.expr_ty(receiver).peel_refs();
             if is_type_diagnostic_item(self.cx, reciever_ty, sym::option_type)
                 || is_type_diagnostic_item(self.cx, reciever_ty, sym::result_type)
             {
@@ -1,9 +1,9 @@
 //! Lint on if expressions with an else if, but without a final else branch.
 
 use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::in_external_macro;
 use rustc_ast::ast::{Expr, ExprKind};
 use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
-use rustc_middle::lint::in_external_macro;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 
 declare_clippy_lint! {
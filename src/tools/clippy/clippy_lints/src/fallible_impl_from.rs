@@ -92,8 +92,8 @@ fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
             }
 
             // check for `unwrap`
-            if let Some(arglists) = method_chain_args(expr, &["unwrap"]) {
-                let reciever_ty = self.typeck_results.expr_ty(&arglists[0][0]).peel_refs();
+            if let Some([[receiver, ..], ..]) = method_chain_args(expr, &["unwrap"]).as_deref() {
+                let reciever_ty = self.typeck_results.expr_ty(receiver).peel_refs();
                 if is_type_diagnostic_item(self.lcx, reciever_ty, sym::option_type)
                     || is_type_diagnostic_item(self.lcx, reciever_ty, sym::result_type)
                 {
@@ -0,0 +1,156 @@
+//! lint on indexing with caller-provided values in functions at the FFI boundary
+
+use crate::utils::{ffi, guards};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::higher;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::value_range::value_range;
+use rustc_hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirId, HirIdSet};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+use rustc_target::spec::abi::Abi;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for indexing and slicing of a slice, an array, a `Vec` or a
+    /// `Box<[T]>` with a value provided by the caller, without checking it against the length, in
+    /// functions at the FFI boundary: functions with a foreign ABI, like `extern "C" fn`, and
+    /// functions taking a raw pointer and an integer, like `fn sum(data: *const u32, len: usize)`.
+    ///
+    /// **Why is this bad?** The values passed by foreign code can't be trusted, and the indexing
+    /// panics if they are out of bounds. A panic unwinding out of a function with a foreign ABI is
+    /// undefined behavior. Validating the values first lets the function report an error to the
+    /// caller instead.
+    ///
+    /// **Known problems:** The values provided by the caller are the parameters and the local
+    /// variables computed from them, and only comparisons of the index with the length in the
+    /// conditions guarding the indexing are recognized as validation.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,ignore
+    /// // Bad
+    /// #[no_mangle]
+    /// pub extern "C" fn nth(data: *const u32, len: usize, n: usize) -> u32 {
+    ///     let data = unsafe { std::slice::from_raw_parts(data, len) };
+    ///     data[n]
+    /// }
+    ///
+    /// // Good
+    /// #[no_mangle]
+    /// pub extern "C" fn nth(data: *const u32, len: usize, n: usize) -> u32 {
+    ///     let data = unsafe { std::slice::from_raw_parts(data, len) };
+    ///     if n < data.len() { data[n] } else { 0 }
+    /// }
+    /// ```
+    pub FFI_UNCHECKED_INDEX,
+    restriction,
+    "indexing with a caller-provided value in a function at the FFI boundary"
+}
+
+declare_lint_pass!(FfiUncheckedIndex => [FFI_UNCHECKED_INDEX]);
+
+impl<'tcx> LateLintPass<'tcx> for FfiUncheckedIndex {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        _: HirId,
+    ) {
+        if span.from_expansion() || !ffi::is_ffi_boundary(cx, kind, decl, body) {
+            return;
+        }
+        let foreign_abi = match kind {
+            FnKind::ItemFn(_, _, header, _) => header.abi != Abi::Rust,
+            FnKind::Method(_, sig, _) => sig.header.abi != Abi::Rust,
+            FnKind::Closure => false,
+        };
+        let mut visitor = IndexVisitor {
+            cx,
+            caller_provided: ffi::caller_provided_locals(body),
+            foreign_abi,
+        };
+        visitor.visit_expr(&body.value);
+    }
+}
+
+struct IndexVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    /// The local variables holding values provided by the caller.
+    caller_provided: HirIdSet,
+    /// Whether the function has a foreign ABI, which makes unwinding out of it undefined behavior.
+    foreign_abi: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for IndexVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Index(base, index) = expr.kind {
+            if !expr.span.from_expansion()
+                && is_indexable_collection(self.cx, self.cx.typeck_results().expr_ty(base))
+                && (ffi::uses_any(index, &self.caller_provided) || ffi::uses_any(base, &self.caller_provided))
+                && !is_validated(self.cx, expr, base, index)
+            {
+                self.lint(expr, base);
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+impl<'a, 'tcx> IndexVisitor<'a, 'tcx> {
+    fn lint(&self, expr: &Expr<'_>, base: &Expr<'_>) {
+        span_lint_and_then(
+            self.cx,
+            FFI_UNCHECKED_INDEX,
+            expr.span,
+            "indexing with a caller-provided value that isn't checked against the length",
+            |diag| {
+                if self.foreign_abi {
+                    diag.note("a panic unwinding out of a function with a foreign ABI is undefined behavior");
+                }
+                diag.help(&format!(
+                    "consider validating the index against `{0}.len()` first, or using `{0}.get(..)`",
+                    snippet(self.cx, base.span, "..")
+                ));
+            },
+        );
+    }
+}
+
+/// Whether the index of `expr` on `base` is known to be in bounds, or compared with the length
+/// of `base` by the conditions guarding `expr`. The bounds of a range must be at most the length.
+fn is_validated<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    base: &Expr<'_>,
+    index: &'tcx Expr<'tcx>,
+) -> bool {
+    match higher::range(index) {
+        Some(range) => range.start.into_iter().chain(range.end).all(|bound| {
+            value_range(cx, bound).is_at_most_len(cx, base, None) || guards::is_len_guarded(cx, expr, bound, base, true)
+        }),
+        None => {
+            value_range(cx, index).is_below_len(cx, base, None) || guards::is_len_guarded(cx, expr, index, base, false)
+        },
+    }
+}
+
+/// Whether `ty` is a (reference to a) slice, array, `Vec` or `Box<[T]>`.
+fn is_indexable_collection(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    let ty = ty.peel_refs();
+    let ty = if ty.is_box() { ty.boxed_ty() } else { ty };
+    matches!(ty.kind(), ty::Slice(_) | ty::Array(..)) || is_type_diagnostic_item(cx, ty, sym::vec_type)
+}
@@ -5,7 +5,6 @@
 use rustc_lint::{LateContext, LintContext};
 use rustc_middle::{
     hir::map::Map,
-    lint::in_external_macro,
     ty::{self, Ty},
 };
 use rustc_span::{sym, Span};
@@ -14,7 +13,7 @@
 use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
 use clippy_utils::source::snippet_opt;
 use clippy_utils::ty::is_must_use_ty;
-use clippy_utils::{match_def_path, must_use_attr, return_ty, trait_ref_of_method};
+use clippy_utils::{in_external_macro, match_def_path, must_use_attr, return_ty, trait_ref_of_method};
 
 use super::{DOUBLE_MUST_USE, MUST_USE_CANDIDATE, MUST_USE_UNIT};
 
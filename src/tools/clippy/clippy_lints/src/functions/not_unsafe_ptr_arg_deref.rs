@@ -3,10 +3,11 @@
 use rustc_middle::{hir::map::Map, ty};
 
 use clippy_utils::diagnostics::span_lint;
+use clippy_utils::path_to_local;
 use clippy_utils::ty::type_is_unsafe_function;
-use clippy_utils::{iter_input_pats, path_to_local};
 
 use super::NOT_UNSAFE_PTR_ARG_DEREF;
+use crate::utils::ffi;
 
 pub(super) fn check_fn(
     cx: &LateContext<'tcx>,
@@ -40,10 +41,7 @@ fn check_raw_ptr(
 ) {
     let expr = &body.value;
     if unsafety == hir::Unsafety::Normal && cx.access_levels.is_exported(hir_id) {
-        let raw_ptrs = iter_input_pats(decl, body)
-            .zip(decl.inputs.iter())
-            .filter_map(|(arg, ty)| raw_ptr_arg(arg, ty))
-            .collect::<HirIdSet>();
+        let raw_ptrs = ffi::raw_ptr_params(decl, body);
 
         if !raw_ptrs.is_empty() {
             let typeck_results = cx.tcx.typeck_body(body.id());
@@ -58,14 +56,6 @@ fn check_raw_ptr(
     }
 }
 
-fn raw_ptr_arg(arg: &hir::Param<'_>, ty: &hir::Ty<'_>) -> Option<hir::HirId> {
-    if let (&hir::PatKind::Binding(_, id, _, _), &hir::TyKind::Ptr(_)) = (&arg.pat.kind, &ty.kind) {
-        Some(id)
-    } else {
-        None
-    }
-}
-
 struct DerefVisitor<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
     ptrs: HirIdSet,
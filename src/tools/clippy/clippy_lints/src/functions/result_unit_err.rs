@@ -1,6 +1,5 @@
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LintContext};
-use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
 use rustc_span::{sym, Span};
 use rustc_typeck::hir_ty_to_ty;
@@ -8,8 +7,8 @@
 use if_chain::if_chain;
 
 use clippy_utils::diagnostics::span_lint_and_help;
-use clippy_utils::trait_ref_of_method;
 use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{in_external_macro, trait_ref_of_method};
 
 use super::RESULT_UNIT_ERR;
 
@@ -1,9 +1,9 @@
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LintContext};
-use rustc_middle::lint::in_external_macro;
 use rustc_span::Span;
 
 use clippy_utils::diagnostics::span_lint;
+use clippy_utils::in_external_macro;
 use clippy_utils::source::snippet;
 
 use super::TOO_MANY_LINES;
@@ -1,14 +1,16 @@
-//! lint on using `x.get(x.len() - 1)` instead of `x.last()`
+//! lint on using `x.get(x.len() - 1)` or `x[x.len() - 1]` instead of `x.last()`
 
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::SpanlessEq;
+use clippy_utils::{find_binding_init, get_parent_expr, path_to_local, SpanlessEq};
 use if_chain::if_chain;
 use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
-use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_hir::{BinOpKind, BindingAnnotation, BorrowKind, Expr, ExprKind, Mutability, Node, PatKind};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::source_map::Spanned;
 use rustc_span::sym;
@@ -45,10 +47,42 @@
     "Using `x.get(x.len() - 1)` when `x.last()` is correct and simpler"
 }
 
-declare_lint_pass!(GetLastWithLen => [GET_LAST_WITH_LEN]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for indexing with `x[x.len() - 1]` instead of using
+    /// `x.last()`, including when the length or the index is stored in a binding first.
+    ///
+    /// **Why is this bad?** `x.last()` says what is meant. It also makes the empty case
+    /// explicit: `x[x.len() - 1]` panics with an arithmetic overflow (or indexes out of
+    /// bounds in release mode) if `x` is empty, while `x.last()` returns `None`.
+    ///
+    /// **Known problems:** The suggestion keeps the panic on empty collections by using
+    /// `.unwrap()`. Consider handling the `None` case instead.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let x = vec![2, 3, 5];
+    ///
+    /// // Bad
+    /// let last_element = x[x.len() - 1];
+    ///
+    /// // Good
+    /// let last_element = *x.last().unwrap();
+    /// ```
+    pub INDEXING_LAST_WITH_LEN,
+    style,
+    "Using `x[x.len() - 1]` instead of `x.last()`"
+}
+
+declare_lint_pass!(GetLastWithLen => [GET_LAST_WITH_LEN, INDEXING_LAST_WITH_LEN]);
 
 impl<'tcx> LateLintPass<'tcx> for GetLastWithLen {
-    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Index(array, index) = expr.kind {
+            check_indexing(cx, expr, array, index);
+            return;
+        }
+
         if_chain! {
             // Is a method call
             if let ExprKind::MethodCall(path, _, args, _) = expr.kind;
@@ -105,3 +139,124 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         }
     }
 }
+
+fn check_indexing<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, array: &Expr<'_>, index: &'tcx Expr<'tcx>) {
+    let array_ty = cx.typeck_results().expr_ty(array).peel_refs();
+    if !(matches!(array_ty.kind(), ty::Slice(_) | ty::Array(..))
+        || is_type_diagnostic_item(cx, array_ty, sym::vec_type))
+    {
+        return;
+    }
+
+    // Looking through bindings is only correct if `array` can't change its length in between.
+    let through_bindings = match path_to_local(array) {
+        Some(id) => {
+            matches!(cx.tcx.hir().find(id), Some(Node::Binding(pat))
+                if matches!(pat.kind, PatKind::Binding(BindingAnnotation::Unannotated, ..)))
+        },
+        None => false,
+    };
+    if !is_len_minus_one(cx, array, index, through_bindings) || expr.span.from_expansion() {
+        return;
+    }
+
+    let (mutability, span, deref) = match get_parent_expr(cx, expr) {
+        Some(parent) => match parent.kind {
+            ExprKind::AddrOf(BorrowKind::Ref, mutability, _) => (mutability, parent.span, false),
+            ExprKind::MethodCall(_, _, [receiver, ..], _)
+            | ExprKind::Field(receiver, _)
+            | ExprKind::Index(receiver, _)
+                if receiver.hir_id == expr.hir_id =>
+            {
+                (place_mutability(cx, expr), expr.span, false)
+            },
+            _ => (place_mutability(cx, expr), expr.span, true),
+        },
+        None => (place_mutability(cx, expr), expr.span, true),
+    };
+
+    let mut applicability = Applicability::MachineApplicable;
+    let array_snippet = Sugg::hir_with_applicability(cx, array, "..", &mut applicability).maybe_par();
+    let method = match mutability {
+        Mutability::Not => "last",
+        Mutability::Mut => "last_mut",
+    };
+    let sugg = format!(
+        "{}{}.{}().unwrap()",
+        if deref { "*" } else { "" },
+        array_snippet,
+        method
+    );
+
+    span_lint_and_sugg(
+        cx,
+        INDEXING_LAST_WITH_LEN,
+        span,
+        "accessing last element with `x[x.len() - 1]`",
+        "try",
+        sugg,
+        applicability,
+    );
+}
+
+/// Checks if `index` is `array.len() - 1`. With `through_bindings`, `index` and the left hand
+/// side of the subtraction may also be immutable bindings to these expressions.
+fn is_len_minus_one<'tcx>(
+    cx: &LateContext<'tcx>,
+    array: &Expr<'_>,
+    index: &'tcx Expr<'tcx>,
+    through_bindings: bool,
+) -> bool {
+    let resolve = |e: &'tcx Expr<'tcx>| -> &'tcx Expr<'tcx> {
+        if_chain! {
+            if through_bindings;
+            if let Some(id) = path_to_local(e);
+            if let Some(init) = find_binding_init(cx, id);
+            then {
+                init
+            } else {
+                e
+            }
+        }
+    };
+
+    if_chain! {
+        if let ExprKind::Binary(op, lhs, rhs) = resolve(index).kind;
+        if op.node == BinOpKind::Sub;
+        if let ExprKind::Lit(rhs_lit) = &rhs.kind;
+        if let LitKind::Int(1, ..) = rhs_lit.node;
+        if let ExprKind::MethodCall(len_path, _, [len_receiver], _) = resolve(lhs).kind;
+        if len_path.ident.name == sym!(len);
+        then {
+            SpanlessEq::new(cx).eq_expr(array, len_receiver)
+        } else {
+            false
+        }
+    }
+}
+
+/// Returns whether the indexing expression is used mutably, i.e. assigned to, borrowed mutably
+/// or used as the receiver of a `&mut self` method, possibly through field and index projections.
+fn place_mutability<'tcx>(cx: &LateContext<'tcx>, mut expr: &'tcx Expr<'tcx>) -> Mutability {
+    loop {
+        let mut_adjusted = cx.typeck_results().adjustments().get(expr.hir_id).map_or(false, |adj| {
+            adj.iter()
+                .any(|a| matches!(a.target.kind(), ty::Ref(_, _, Mutability::Mut)))
+        });
+        if mut_adjusted {
+            return Mutability::Mut;
+        }
+
+        match get_parent_expr(cx, expr) {
+            Some(parent) => match parent.kind {
+                ExprKind::AddrOf(_, Mutability::Mut, _) => return Mutability::Mut,
+                ExprKind::Assign(lhs, ..) | ExprKind::AssignOp(_, lhs, _) if lhs.hir_id == expr.hir_id => {
+                    return Mutability::Mut;
+                },
+                ExprKind::Field(base, _) | ExprKind::Index(base, _) if base.hir_id == expr.hir_id => expr = parent,
+                _ => return Mutability::Not,
+            },
+            None => return Mutability::Not,
+        }
+    }
+}
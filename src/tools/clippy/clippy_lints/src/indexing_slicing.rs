@@ -1,17 +1,43 @@
 //! lint on indexing and slicing operations
 
-use crate::consts::{constant, Constant};
-use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
-use clippy_utils::higher;
+use crate::consts::{constant, constant_with_locals, Constant};
+use crate::utils::guards;
+use crate::utils::known_len::{const_usize, empty_with_capacity, known_len, known_slice_len, vec_len};
+use crate::utils::mir_lint_pass::{Def, MirContext, MirLintPass};
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then, span_lint_and_then_multi};
+use clippy_utils::higher::RangeLike;
+use clippy_utils::source::{snippet_opt, snippet_with_applicability};
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{is_type_diagnostic_item, match_type};
+use clippy_utils::value_range::{value_range, ValueRange};
+use clippy_utils::{
+    clip, get_parent_expr, higher, is_in_test_code, is_trait_method, last_path_segment, match_def_path, meets_edition,
+    path_to_local, paths, sext, SpanlessEq,
+};
+use if_chain::if_chain;
 use rustc_ast::ast::RangeLimits;
-use rustc_hir::{Expr, ExprKind};
-use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{BinOpKind, BorrowKind, ConstContext, Expr, ExprKind, Mutability, Node, Pat, UnOp};
+use rustc_lint::{LateContext, LateLintPass, Lint};
+use rustc_middle::hir::map::Map;
+use rustc_middle::mir::{AssertKind, Field, Location, Operand, Place, Rvalue, Terminator, TerminatorKind};
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_middle::ty::{self, Ty, UintTy};
+use rustc_session::{declare_lint_pass, declare_tool_lint, impl_lint_pass};
+use rustc_span::edition::Edition;
+use rustc_span::{sym, Span};
+use std::convert::TryFrom;
+use std::ptr;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for out of bounds array indexing with a constant
-    /// index.
+    /// index, or an index that is known to be at least the length, e.g. `x[i]`
+    /// inside `if i >= 4 { .. }` for an array of length 4. Local `Vec`s created with `vec![elem; N]` (or `Vec::with_capacity`
+    /// followed by `resize`) that are not otherwise mutated are checked as well, and so are slices
+    /// created from arrays, e.g. `s[4]` after `let s: &[i32] = &x;`, and array fields accessed
+    /// through a reference, e.g. `self.field[4]`. Calls of the `const fn`s of the crate with
+    /// constant arguments are evaluated, e.g. `x[magic_index()]`.
     ///
     /// **Why is this bad?** This will always panic at runtime.
     ///
@@ -21,14 +47,17 @@
     /// ```no_run
     /// # #![allow(const_err)]
     /// let x = [1, 2, 3, 4];
+    /// let v = vec![0; 4];
     ///
     /// // Bad
     /// x[9];
     /// &x[2..9];
+    /// v[4];
     ///
     /// // Good
     /// x[0];
     /// x[3];
+    /// v[3];
     /// ```
     pub OUT_OF_BOUNDS_INDEXING,
     correctness,
@@ -39,11 +68,21 @@
     /// **What it does:** Checks for usage of indexing or slicing. Arrays are special cases, this lint
     /// does report on arrays if we can tell that slicing operations are in bounds and does not
     /// lint on constant `usize` indexing on arrays because that is handled by rustc's `const_err` lint.
+    /// Indexes that are known to be in bounds, like `x[i]` inside `if i < x.len() { .. }`, after
+    /// `assert!(i < x.len())` or in a `for i in 0..x.len()` loop, are not linted either.
+    /// Indexes that are reduced modulo the length, like `x[i % x.len()]` or
+    /// `x[i & (x.len() - 1)]`, only panic if `x` is empty, so they are not linted if `x` is known
+    /// not to be empty, e.g. inside `if !x.is_empty() { .. }`.
+    /// Indexing a `HashMap` or `BTreeMap` is covered by `index_map` instead.
+    /// Ranges stored in a binding first (`let r = 1..n; &x[r]`) and other values implementing
+    /// `RangeBounds<usize>` are checked as slicing.
+    /// Arrays up to the length configured with `indexing-slicing-max-array-size` aren't linted.
     ///
     /// **Why is this bad?** Indexing and slicing can panic at runtime and there are
     /// safe alternatives.
     ///
-    /// **Known problems:** Hopefully none.
+    /// **Known problems:** The suggested `.get()` rewrite returns an `Option`, so the
+    /// surrounding code usually needs to be adapted as well.
     ///
     /// **Example:**
     /// ```rust,no_run
@@ -84,78 +123,554 @@
     "indexing/slicing usage"
 }
 
-declare_lint_pass!(IndexingSlicing => [INDEXING_SLICING, OUT_OF_BOUNDS_INDEXING]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for slicing of `str`s and `String`s.
+    ///
+    /// **Why is this bad?** String slices are indexed by bytes. Slicing panics if a bound is
+    /// not on a UTF-8 character boundary, which is easy to get wrong with non-ASCII text.
+    ///
+    /// **Known problems:** The lint stays silent if the string and both bounds are constants and
+    /// the bounds are known to lie on character boundaries.
+    ///
+    /// **Example:**
+    /// ```rust,no_run
+    /// let s = String::from("Ünicode");
+    /// let n = 1;
+    ///
+    /// // Bad
+    /// &s[..n];
+    ///
+    /// // Good
+    /// s.get(..n);
+    /// ```
+    pub STRING_SLICE,
+    restriction,
+    "slicing a string, which may panic on non-UTF-8 character boundaries"
+}
 
-impl<'tcx> LateLintPass<'tcx> for IndexingSlicing {
-    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        if let ExprKind::Index(array, index) = &expr.kind {
-            let ty = cx.typeck_results().expr_ty(array).peel_refs();
-            if let Some(range) = higher::range(index) {
-                // Ranged indexes, i.e., &x[n..m], &x[n..], &x[..n] and &x[..]
-                if let ty::Array(_, s) = ty.kind() {
-                    let size: u128 = if let Some(size) = s.try_eval_usize(cx.tcx, cx.param_env) {
-                        size.into()
-                    } else {
-                        return;
-                    };
+declare_clippy_lint! {
+    /// **What it does:** Checks for indexing a `HashMap` or `BTreeMap` with `map[&key]`.
+    ///
+    /// **Why is this bad?** Indexing panics if the key isn't in the map. `get` returns an
+    /// `Option` instead, and the `entry` API inserts the missing keys.
+    ///
+    /// **Known problems:** The suggested `.get()` rewrite returns an `Option`, so the
+    /// surrounding code usually needs to be adapted as well.
+    ///
+    /// **Example:**
+    /// ```rust,no_run
+    /// # use std::collections::HashMap;
+    /// let map: HashMap<&str, u32> = HashMap::new();
+    ///
+    /// // Bad
+    /// let count = map["apples"];
+    ///
+    /// // Good
+    /// let count = map.get("apples").copied().unwrap_or(0);
+    /// ```
+    pub INDEX_MAP,
+    restriction,
+    "indexing a `HashMap` or `BTreeMap`, which panics on missing keys"
+}
 
-                    let const_range = to_const_range(cx, range, size);
-
-                    if let (Some(start), _) = const_range {
-                        if start > size {
-                            span_lint(
-                                cx,
-                                OUT_OF_BOUNDS_INDEXING,
-                                range.start.map_or(expr.span, |start| start.span),
-                                "range is out of bounds",
-                            );
-                            return;
-                        }
-                    }
+declare_clippy_lint! {
+    /// **What it does:** Checks for slicing with an inclusive range `x[..=n]` (or `x[m..=n]`)
+    /// whose end is past the end of the array, or is `usize::MAX`.
+    ///
+    /// **Why is this bad?** An inclusive range includes its end, so `x[..=n]` needs `n + 1`
+    /// elements. Ending it at the length of the array is an off-by-one error, and ending it at
+    /// `usize::MAX` always panics because `n + 1` overflows. Either way the slicing panics at
+    /// runtime.
+    ///
+    /// **Known problems:** Hopefully none.
+    ///
+    /// **Example:**
+    /// ```no_run
+    /// let x = [1, 2, 3, 4];
+    ///
+    /// // Bad
+    /// &x[..=4];
+    /// &x[1..=usize::MAX];
+    ///
+    /// // Good
+    /// &x[..4];
+    /// &x[..=3];
+    /// &x[1..];
+    /// ```
+    pub INCLUSIVE_RANGE_OUT_OF_BOUNDS,
+    correctness,
+    "slicing with an inclusive range that ends past the end of the array"
+}
 
-                    if let (_, Some(end)) = const_range {
-                        if end > size {
-                            span_lint(
-                                cx,
-                                OUT_OF_BOUNDS_INDEXING,
-                                range.end.map_or(expr.span, |end| end.span),
-                                "range is out of bounds",
-                            );
-                            return;
-                        }
-                    }
+declare_clippy_lint! {
+    /// **What it does:** Checks for slicing with a range whose constant start is after its
+    /// constant end, like `&x[5..2]` or `&x[4..=2]`.
+    ///
+    /// **Why is this bad?** Slicing with a reversed range always panics, with "slice index starts
+    /// at 5 but ends at 2", even if both bounds are within the length. Ranges ending where they
+    /// start, like `&x[2..2]`, are empty and don't panic.
+    ///
+    /// **Known problems:** Only the bounds known at compile time are checked.
+    ///
+    /// **Example:**
+    /// ```no_run
+    /// let x = [1, 2, 3, 4, 5, 6];
+    ///
+    /// // Bad
+    /// &x[5..2];
+    ///
+    /// // Good
+    /// &x[2..5];
+    /// ```
+    pub REVERSED_SLICE_RANGE,
+    correctness,
+    "slicing with a range whose start is after its end"
+}
 
-                    if let (Some(_), Some(_)) = const_range {
-                        // early return because both start and end are constants
-                        // and we have proven above that they are in bounds
-                        return;
-                    }
-                }
+declare_clippy_lint! {
+    /// **What it does:** Checks for slicing and indexing of an array, or of a collection whose
+    /// length is known, with a bound computed from `mem::size_of::<T>()` that is past its end,
+    /// like `&buf[..size_of::<Header>()]` with a buffer smaller than `Header`, or a range of
+    /// consecutive structures like `&buf[size_of::<Header>()..][..size_of::<Body>()]` when they
+    /// don't all fit in the buffer.
+    ///
+    /// **Why is this bad?** The slicing always panics. Reading a structure from a byte buffer
+    /// needs a buffer of at least the size of the structure.
+    ///
+    /// **Known problems:** The size of a type depends on the target, so the lint may only be
+    /// emitted on some targets. Only the sizes of concrete types are known.
+    ///
+    /// **Example:**
+    /// ```no_run
+    /// # use std::mem::size_of;
+    /// struct Header {
+    ///     magic: u32,
+    ///     len: u32,
+    /// }
+    ///
+    /// // Bad
+    /// let buf = [0u8; 4];
+    /// let header = &buf[..size_of::<Header>()];
+    ///
+    /// // Good
+    /// let buf = [0u8; size_of::<Header>()];
+    /// let header = &buf[..size_of::<Header>()];
+    /// ```
+    pub SIZE_OF_OUT_OF_BOUNDS,
+    correctness,
+    "slicing a buffer with the size of a type that doesn't fit in it"
+}
 
-                let help_msg = match (range.start, range.end) {
-                    (None, Some(_)) => "consider using `.get(..n)`or `.get_mut(..n)` instead",
-                    (Some(_), None) => "consider using `.get(n..)` or .get_mut(n..)` instead",
-                    (Some(_), Some(_)) => "consider using `.get(n..m)` or `.get_mut(n..m)` instead",
-                    (None, None) => return, // [..] is ok.
-                };
+declare_clippy_lint! {
+    /// **What it does:** Checks for subtractions of unsigned integers in indexes and slicing
+    /// bounds, like `v[i - 1]` or `&v[len - n..]`, that aren't known to be guarded against
+    /// underflow, e.g. by an `if i > 0 { .. }`.
+    ///
+    /// **Why is this bad?** If the subtrahend is larger, the subtraction panics in debug builds
+    /// and wraps around to a huge index in release builds, which then panics as out of bounds.
+    /// An off-by-one at the start of a collection is a common source of panics in production.
+    ///
+    /// **Known problems:** Only guards comparing the operands with constants or with the length
+    /// of a collection (`if n <= v.len() { v[v.len() - n] }`) are recognized, so a subtraction
+    /// guarded by a comparison of two variables is linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let v = vec![1, 2, 3];
+    /// # let i = 1;
+    /// // Bad
+    /// let prev = v[i - 1];
+    ///
+    /// // Good
+    /// let prev = i.checked_sub(1).and_then(|i| v.get(i));
+    /// let prev = v[i.saturating_sub(1)];
+    /// ```
+    pub INDEX_UNDERFLOW,
+    restriction,
+    "subtraction in an index that can underflow, like `v[i - 1]`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for indexing and slicing a local `Vec` created with
+    /// `Vec::with_capacity(n)` before any elements were added to it, e.g. with `push` or
+    /// `resize`.
+    ///
+    /// **Why is this bad?** `with_capacity` only allocates memory for `n` elements, the length of
+    /// the vector is still 0. Indexing it always panics at runtime.
+    ///
+    /// **Known problems:** Only the statements of the block declaring the vector are followed, so
+    /// a vector passed to another function or mutated in a branch isn't checked.
+    ///
+    /// **Example:**
+    /// ```no_run
+    /// // Bad
+    /// let mut v = Vec::with_capacity(10);
+    /// for i in 0..10 {
+    ///     v[i] = i;
+    /// }
+    ///
+    /// // Good
+    /// let mut v = Vec::with_capacity(10);
+    /// for i in 0..10 {
+    ///     v.push(i);
+    /// }
+    ///
+    /// // Good
+    /// let mut v = vec![0; 10];
+    /// for i in 0..10 {
+    ///     v[i] = i;
+    /// }
+    /// ```
+    pub WITH_CAPACITY_INDEXING,
+    correctness,
+    "indexing a `Vec` created with `with_capacity` before adding elements"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for indexes and slicing bounds that are signed integers cast to
+    /// `usize`, like `v[x as usize]` where `x` is an `i32`.
+    ///
+    /// **Why is this bad?** A negative value wraps around to a huge index when cast with `as`, so
+    /// the indexing panics with a confusing message, or, if the collection is large enough, accesses
+    /// the wrong element. `usize::try_from` makes the conversion fail on negative values instead.
+    ///
+    /// **Known problems:** Checks of the value before the cast, like `if x >= 0 { v[x as usize] }`,
+    /// aren't recognized. The suggestion needs `std::convert::TryFrom` to be imported before
+    /// edition 2021.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::convert::TryFrom;
+    /// # let v = vec![1, 2, 3];
+    /// let x: i32 = 1;
+    ///
+    /// // Bad
+    /// let y = v[x as usize];
+    ///
+    /// // Good
+    /// let y = v[usize::try_from(x).expect("negative index")];
+    /// ```
+    pub SIGNED_INDEX_CAST,
+    pedantic,
+    "indexing with a signed integer cast to `usize`, like `v[x as usize]`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for indexing and slicing in the body of a `const fn` that may
+    /// panic, i.e. the cases `INDEXING_SLICING` reports.
+    ///
+    /// **Why is this bad?** A panic while evaluating a constant is a compile error, so the function
+    /// can't be used in constants for the arguments that make it panic, and the error points at the
+    /// constant instead of the function. The `get` methods aren't `const fn`, so the bounds have to
+    /// be checked explicitly.
+    ///
+    /// **Known problems:** A function that is only called with valid indexes is reported as well.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// // Bad
+    /// const fn nth_digit(i: usize) -> u8 {
+    ///     b"0123456789"[i]
+    /// }
+    ///
+    /// // Good
+    /// const fn nth_digit(i: usize) -> u8 {
+    ///     let digits = b"0123456789";
+    ///     if i < digits.len() { digits[i] } else { b'?' }
+    /// }
+    /// ```
+    pub CONST_FN_INDEXING,
+    correctness,
+    "indexing or slicing that may panic in a `const fn`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `nth` with a constant argument on iterators of a known length,
+    /// i.e. iterators over an array or a local `Vec` whose length is known (see
+    /// `out_of_bounds_indexing`) and ranges with constant bounds, where the argument is at least
+    /// the length.
+    ///
+    /// **Why is this bad?** The result is always `None`, which is most likely a bug, like an out
+    /// of bounds index.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x = [1, 2, 3, 4];
+    ///
+    /// // Bad
+    /// let a = x.iter().nth(9);
+    /// let b = (0..3).nth(10);
+    ///
+    /// // Good
+    /// let a = x.iter().nth(3);
+    /// let b = (0..3).nth(2);
+    /// ```
+    pub NTH_OUT_OF_BOUNDS,
+    correctness,
+    "`nth` with an argument exceeding the known length of the iterator, which always returns `None`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for indexing with the loop variable of a `for` loop over a range
+    /// plus an offset, like `v[i + k]` in `for i in 0..n { .. }`, where the index isn't known to
+    /// be in bounds, i.e. the end of the range isn't bounded by the length of the indexed
+    /// collection minus the offset, like `0..v.len() - k`.
+    ///
+    /// **Why is this bad?** Nothing relates the range to the length of the collection, so the
+    /// last iterations may go past its end and panic. Iterator adapters like
+    /// `v.iter().skip(k).take(n)` stop at the end of the collection instead.
+    ///
+    /// **Known problems:** The range isn't related to the length when the loop is guarded by a
+    /// condition on another variable, like `if n + k <= v.len()`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let v = vec![1, 2, 3];
+    /// # let (n, k) = (2, 1);
+    /// // Bad
+    /// for i in 0..n {
+    ///     println!("{}", v[i + k]);
+    /// }
+    ///
+    /// // Good
+    /// for x in v.iter().skip(k).take(n) {
+    ///     println!("{}", x);
+    /// }
+    /// ```
+    pub LOOP_INDEX_OFFSET,
+    pedantic,
+    "indexing with a `for` loop variable plus an offset that isn't bounded by the length"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for indexing the `Vec` collected from an iterator with a single
+    /// index, like `iter.collect::<Vec<_>>()[0]`.
+    ///
+    /// **Why is this bad?** Collecting allocates a `Vec` and consumes the whole iterator to keep a
+    /// single element, and the indexing panics if the iterator is too short. `next()` and `nth(n)`
+    /// stop at the element and return `None` if there is none.
+    ///
+    /// **Known problems:** The iterator isn't consumed past the element anymore, so the side
+    /// effects of the closures of adapters like `map` don't run for the other elements.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let names = ["a", "b"];
+    /// // Bad
+    /// let first = names.iter().map(|name| name.len()).collect::<Vec<_>>()[0];
+    ///
+    /// // Good
+    /// let first = names.iter().map(|name| name.len()).next().unwrap();
+    /// ```
+    pub COLLECT_THEN_INDEX,
+    perf,
+    "indexing the `Vec` collected from an iterator with a single index, like `iter.collect::<Vec<_>>()[0]`"
+}
 
-                span_lint_and_help(cx, INDEXING_SLICING, expr.span, "slicing may panic", None, help_msg);
+declare_clippy_lint! {
+    /// **What it does:** Checks for indexing a collection with a constant index in an arm of a
+    /// `match` on its length, like `2 => v[5]` in `match v.len() { .. }`, where the index is out of
+    /// bounds for every length the arm matches.
+    ///
+    /// **Why is this bad?** The indexing always panics in this arm. Either the index or the
+    /// pattern of the arm is wrong.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// // Bad
+    /// match v.len() {
+    ///     2 => v[0] + v[2],
+    ///     _ => 0,
+    /// }
+    ///
+    /// // Good
+    /// match v.len() {
+    ///     2 => v[0] + v[1],
+    ///     _ => 0,
+    /// }
+    /// ```
+    ///
+    /// The arms of a `match` on the length are guards of the indexing as well:
+    /// `INDEXING_SLICING` doesn't lint the indexes in bounds for every length the arm matches.
+    pub MATCHED_LEN_OUT_OF_BOUNDS,
+    correctness,
+    "indexing out of bounds of the length matched by the arm of a `match` on the length"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for indexing and slicing a `Cow`, `Box`, `Rc` or `Arc` right where
+    /// it is constructed, like `Cow::from(s)[a..b]` or `Box::new(array)[i]`.
+    ///
+    /// **Why is this bad?** The pointer only exists to be indexed, and if the index is out of
+    /// bounds, the panic points at the temporary instead of the value it is constructed from.
+    /// Binding the pointer and using `get` lets the code handle the error where the value is known.
+    ///
+    /// **Known problems:** Only calls of the constructors (`new`, `from`, `Cow::Borrowed` and
+    /// `Cow::Owned`) are recognized, not conversions with `into()`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::borrow::Cow;
+    /// # let s = "abc";
+    /// // Bad
+    /// let prefix = &Cow::from(s)[..2];
+    ///
+    /// // Good
+    /// let cow = Cow::from(s);
+    /// let prefix = cow.get(..2);
+    /// ```
+    pub CONSTRUCT_THEN_INDEX,
+    pedantic,
+    "indexing a `Cow`, `Box`, `Rc` or `Arc` right where it is constructed"
+}
+
+#[derive(Copy, Clone)]
+pub struct IndexingSlicing {
+    allow_in_tests: bool,
+    /// Arrays up to this length aren't linted by `INDEXING_SLICING`.
+    max_array_size: u64,
+}
+
+impl IndexingSlicing {
+    pub fn new(allow_in_tests: bool, max_array_size: u64) -> Self {
+        Self {
+            allow_in_tests,
+            max_array_size,
+        }
+    }
+}
+
+impl_lint_pass!(IndexingSlicing => [
+    INDEXING_SLICING,
+    OUT_OF_BOUNDS_INDEXING,
+    STRING_SLICE,
+    INDEX_MAP,
+    INCLUSIVE_RANGE_OUT_OF_BOUNDS,
+    REVERSED_SLICE_RANGE,
+    SIZE_OF_OUT_OF_BOUNDS,
+    INDEX_UNDERFLOW,
+    WITH_CAPACITY_INDEXING,
+    SIGNED_INDEX_CAST,
+    CONST_FN_INDEXING,
+    NTH_OUT_OF_BOUNDS,
+    LOOP_INDEX_OFFSET,
+    COLLECT_THEN_INDEX,
+    MATCHED_LEN_OUT_OF_BOUNDS,
+    CONSTRUCT_THEN_INDEX,
+]);
+
+impl<'tcx> LateLintPass<'tcx> for IndexingSlicing {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::MethodCall(path, _, [iter, n], _) = expr.kind {
+            if path.ident.as_str() == "nth" && !expr.span.from_expansion() && is_trait_method(cx, expr, sym::Iterator) {
+                check_nth(cx, expr, iter, n);
+            }
+        } else if let ExprKind::Index(array, index) = &expr.kind {
+            check_collect_then_index(cx, expr, array, index);
+            check_construct_then_index(cx, expr, array, index);
+            self.check_underflow(cx, expr, index);
+            self.check_signed_cast(cx, expr, index);
+
+            let ty = cx.typeck_results().expr_ty(array).peel_refs();
+            if is_type_diagnostic_item(cx, ty, sym::hashmap_type) || match_type(cx, ty, &paths::BTREEMAP) {
+                self.lint_indexing(
+                    cx,
+                    INDEX_MAP,
+                    expr,
+                    array,
+                    index,
+                    "indexing a map panics if the key is missing",
+                    "consider using `.get(&key)` or the `entry` API instead",
+                );
+            } else if let Some(range) = higher::range_like(cx, index) {
+                match range {
+                    // Ranged indexes, i.e., &x[n..m], &x[n..], &x[..n] and &x[..]
+                    RangeLike::Range(range) => self.check_range(cx, expr, array, index, ty, range),
+                    // Ranges whose bounds are unknown, e.g. a `Range<usize>` parameter
+                    RangeLike::Opaque => {
+                        if ty.is_str() || is_type_diagnostic_item(cx, ty, sym::string_type) {
+                            lint_string_slice(cx, expr, array, index);
+                        }
+                        self.lint_indexing(
+                            cx,
+                            INDEXING_SLICING,
+                            expr,
+                            array,
+                            index,
+                            "slicing may panic",
+                            "consider using `.get(range)` or `.get_mut(range)` instead",
+                        );
+                    },
+                }
             } else {
                 // Catchall non-range index, i.e., [n] or [n << m]
-                if let ty::Array(..) = ty.kind() {
+                // rustc's `unconditional_panic` lint catches constant indexes on arrays, but doesn't
+                // know the length of a `Vec`, of an array field behind a reference, like
+                // `self.field[5]`, or the range of guarded indexes.
+                let rustc_checks_array = matches!(ty.kind(), ty::Array(..)) && !is_field_behind_reference(cx, array);
+                let size = if let ty::Array(_, s) = ty.kind() {
                     // Index is a constant uint.
-                    if let Some(..) = constant(cx, cx.typeck_results(), index) {
+                    if rustc_checks_array && constant(cx, cx.typeck_results(), index).is_some() {
                         // Let rustc's `const_err` lint handle constant `usize` indexing on arrays.
                         return;
                     }
+                    s.try_eval_usize(cx.tcx, cx.param_env).map(u128::from)
+                } else {
+                    vec_len(cx, expr, array).map(|(len, _)| len)
+                };
+
+                let index_range = value_range(cx, index);
+                if let Some(size) = size {
+                    if index_range.min >= size && !(rustc_checks_array && is_const_for_rustc(cx, index)) {
+                        let label = value_label("this index is", &index_range);
+                        lint_out_of_bounds(
+                            cx,
+                            expr,
+                            array,
+                            size,
+                            expr.span,
+                            "index is out of bounds",
+                            label,
+                            Some(index),
+                            None,
+                        );
+                        return;
+                    }
                 }
+                if check_matched_len(cx, expr, array, &index_range) {
+                    return;
+                }
+                if index_range.is_below_len(cx, array, size) || guards::is_len_guarded(cx, expr, index, array, false) {
+                    return;
+                }
+                if is_reduced_modulo_len(cx, index, array) {
+                    if size.map_or(false, |size| size > 0) || guards::is_non_empty_guarded(cx, expr, array) {
+                        return;
+                    }
+                    self.lint_indexing(
+                        cx,
+                        INDEXING_SLICING,
+                        expr,
+                        array,
+                        index,
+                        "indexing may panic if the collection is empty",
+                        "consider checking that the collection isn't empty first, or using `.get(n)` instead",
+                    );
+                    return;
+                }
+                self.check_loop_offset(cx, expr, array, index);
 
-                span_lint_and_help(
+                self.lint_indexing(
                     cx,
                     INDEXING_SLICING,
-                    expr.span,
+                    expr,
+                    array,
+                    index,
                     "indexing may panic",
-                    None,
                     "consider using `.get(n)` or `.get_mut(n)` instead",
                 );
             }
@@ -163,6 +678,1059 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
     }
 }
 
+impl IndexingSlicing {
+    /// Checks the ranged index `index`, i.e. `&x[n..m]`, `&x[n..]`, `&x[..n]` or `&x[..]`, of the
+    /// `Index` expression `expr` on `array`, whose type with the references peeled off is `ty`.
+    fn check_range<'tcx>(
+        self,
+        cx: &LateContext<'tcx>,
+        expr: &'tcx Expr<'tcx>,
+        array: &'tcx Expr<'tcx>,
+        index: &'tcx Expr<'tcx>,
+        ty: Ty<'tcx>,
+        range: higher::Range<'tcx>,
+    ) {
+        if ty.is_str() || is_type_diagnostic_item(cx, ty, sym::string_type) {
+            check_string_slice(cx, expr, array, index, range);
+        }
+
+        let size = if let ty::Array(_, s) = ty.kind() {
+            if let Some(size) = s.try_eval_usize(cx.tcx, cx.param_env) {
+                Some(size.into())
+            } else {
+                return;
+            }
+        } else {
+            vec_len(cx, expr, array).map(|(len, _)| len)
+        };
+
+        let mut start = range
+            .start
+            .map_or_else(|| ValueRange::exact(0), |start| value_range(cx, start));
+        let mut end = match range.end {
+            Some(end) if range.limits == RangeLimits::Closed => value_range(cx, end).plus(1),
+            Some(end) => value_range(cx, end),
+            None => ValueRange::len_of(array, size),
+        };
+        // A range stored in a binding is linted at the indexing. Its bounds were evaluated where the
+        // binding was initialized, so they aren't related to the current length of the collection.
+        let is_stored = range
+            .start
+            .into_iter()
+            .chain(range.end)
+            .any(|bound| !index.span.contains(bound.span));
+        let bound_span = |bound: Option<&Expr<'_>>| match bound {
+            Some(bound) if !is_stored => bound.span,
+            _ => expr.span,
+        };
+        if is_stored {
+            start.len_bounds.clear();
+            if range.end.is_some() {
+                end.len_bounds.clear();
+            }
+        }
+
+        if let (Some(end_expr), RangeLimits::Closed) = (range.end, range.limits) {
+            if check_inclusive_end(cx, index, range.start, end_expr, size) {
+                return;
+            }
+        }
+
+        if let Some(size) = size {
+            if start.min > size {
+                let label = value_label("the range starts at", &start);
+                lint_out_of_bounds(
+                    cx,
+                    expr,
+                    array,
+                    size,
+                    bound_span(range.start),
+                    "range is out of bounds",
+                    label,
+                    range.start,
+                    None,
+                );
+                return;
+            }
+
+            if end.min > size {
+                // the written end, which is one less than `end` for an inclusive range
+                let end_expr = range.end.map(|end_expr| value_range(cx, end_expr));
+                let label = value_label("the range ends at", end_expr.as_ref().unwrap_or(&end));
+                // Clamping a literal end of an exclusive range to the length is the only fix that
+                // keeps the start of the range.
+                let clamped_end = range.end.filter(|end_expr| {
+                    range.limits == RangeLimits::HalfOpen
+                        && !is_stored
+                        && !end_expr.span.from_expansion()
+                        && matches!(end_expr.kind, ExprKind::Lit(_))
+                });
+                lint_out_of_bounds(
+                    cx,
+                    expr,
+                    array,
+                    size,
+                    bound_span(range.end),
+                    "range is out of bounds",
+                    label,
+                    range.end,
+                    clamped_end.map(|end_expr| end_expr.span),
+                );
+                return;
+            }
+        }
+
+        if check_reversed(cx, index, range) {
+            return;
+        }
+
+        // The slicing can't panic if `start <= end <= len`
+        let start_before_end = if range.end.is_some() {
+            start.max.map_or(false, |max| max <= end.min)
+        } else {
+            start.is_at_most_len(cx, array, size)
+        };
+        if start_before_end && end.is_at_most_len(cx, array, size) {
+            return;
+        }
+        if !is_stored && is_range_guarded(cx, expr, array, range) {
+            return;
+        }
+
+        let help_msg = match (range.start, range.end) {
+            (None, Some(_)) => "consider using `.get(..n)` or `.get_mut(..n)` instead",
+            (Some(_), None) => "consider using `.get(n..)` or `.get_mut(n..)` instead",
+            (Some(_), Some(_)) => "consider using `.get(n..m)` or `.get_mut(n..m)` instead",
+            (None, None) => return, // [..] is ok.
+        };
+
+        self.lint_indexing(cx, INDEXING_SLICING, expr, array, index, "slicing may panic", help_msg);
+    }
+
+    /// Emits `INDEX_UNDERFLOW` on the unguarded subtractions in `index`, the index of `expr`, or in
+    /// its bounds if it is a range.
+    fn check_underflow<'tcx>(self, cx: &LateContext<'tcx>, expr: &Expr<'_>, index: &'tcx Expr<'tcx>) {
+        if self.allow_in_tests && is_in_test_code(cx.tcx, expr.hir_id) {
+            return;
+        }
+
+        match higher::range(index) {
+            Some(range) => {
+                for bound in range.start.into_iter().chain(range.end) {
+                    lint_underflow(cx, bound);
+                }
+            },
+            None => lint_underflow(cx, index),
+        }
+    }
+
+    /// Emits `SIGNED_INDEX_CAST` if `index`, the index of `expr`, or one of its bounds if it is a
+    /// range, is a signed integer cast to `usize`.
+    fn check_signed_cast<'tcx>(self, cx: &LateContext<'tcx>, expr: &Expr<'_>, index: &'tcx Expr<'tcx>) {
+        if self.allow_in_tests && is_in_test_code(cx.tcx, expr.hir_id) {
+            return;
+        }
+
+        match higher::range(index) {
+            Some(range) => {
+                for bound in range.start.into_iter().chain(range.end) {
+                    lint_signed_cast(cx, bound);
+                }
+            },
+            None => lint_signed_cast(cx, index),
+        }
+    }
+
+    /// Emits `LOOP_INDEX_OFFSET` if `index` is the loop variable of a `for` loop over a range plus
+    /// an offset. The range analysis has already failed to bound `index` by the length of `array`.
+    fn check_loop_offset<'tcx>(
+        self,
+        cx: &LateContext<'tcx>,
+        expr: &Expr<'_>,
+        array: &Expr<'_>,
+        index: &'tcx Expr<'tcx>,
+    ) {
+        if expr.span.from_expansion() || (self.allow_in_tests && is_in_test_code(cx.tcx, expr.hir_id)) {
+            return;
+        }
+        let (lhs, rhs) = match index.kind {
+            ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::Add => (lhs, rhs),
+            _ => return,
+        };
+        let (pat, arg, range, offset) = match for_loop_over_range(cx, lhs)
+            .map(|loop_| (loop_, rhs))
+            .or_else(|| for_loop_over_range(cx, rhs).map(|loop_| (loop_, lhs)))
+        {
+            Some(((pat, arg, range), offset)) => (pat, arg, range, offset),
+            None => return,
+        };
+        if const_usize(cx, offset) == Some(0) {
+            return;
+        }
+
+        let mut applicability = Applicability::HasPlaceholders;
+        let collection = Sugg::hir_with_applicability(cx, array, "..", &mut applicability);
+        let offset_snip = snippet_with_applicability(cx, offset.span, "..", &mut applicability);
+        let starts_at_zero = range.start.map_or(true, |start| const_usize(cx, start) == Some(0));
+        let iter = match (starts_at_zero, range.limits, range.end) {
+            // the iterator ends at the end of the collection anyway
+            (true, RangeLimits::HalfOpen, Some(end)) if is_len_call(end) => {
+                format!("{}.iter().skip({})", collection.maybe_par(), offset_snip)
+            },
+            (true, RangeLimits::HalfOpen, Some(end)) => format!(
+                "{}.iter().skip({}).take({})",
+                collection.maybe_par(),
+                offset_snip,
+                snippet_with_applicability(cx, end.span, "..", &mut applicability)
+            ),
+            _ => format!("{}.iter().skip(..)", collection.maybe_par()),
+        };
+        let var_name = snippet_with_applicability(cx, pat.span, "..", &mut applicability);
+        span_lint_and_then(
+            cx,
+            LOOP_INDEX_OFFSET,
+            expr.span,
+            "indexing with an offset from the loop variable may be out of bounds",
+            |diag| {
+                diag.span_note(
+                    arg.span,
+                    &format!(
+                        "`{}` iterates over this range, which isn't bounded by the length minus the offset",
+                        var_name
+                    ),
+                );
+                diag.help(&format!(
+                    "consider iterating over `{}`, with `.enumerate()` if `{}` is needed",
+                    iter, var_name
+                ));
+            },
+        );
+    }
+
+    /// Emits `lint` on `expr`, unless indexing is allowed in tests or, for `INDEXING_SLICING`, on
+    /// arrays of this size. `INDEXING_SLICING` in a `const fn` is emitted as `CONST_FN_INDEXING`.
+    #[allow(clippy::too_many_arguments)]
+    fn lint_indexing(
+        self,
+        cx: &LateContext<'_>,
+        lint: &'static Lint,
+        expr: &Expr<'_>,
+        array: &Expr<'_>,
+        index: &Expr<'_>,
+        msg: &str,
+        help_msg: &str,
+    ) {
+        if self.allow_in_tests && is_in_test_code(cx.tcx, expr.hir_id) {
+            return;
+        }
+        if ptr::eq(lint, INDEXING_SLICING) && is_in_const_fn(cx, expr) {
+            span_lint_and_then(
+                cx,
+                CONST_FN_INDEXING,
+                expr.span,
+                &format!("{} in a `const fn`", msg),
+                |diag| {
+                    diag.note("a panic makes the evaluation fail when the function is called in a constant");
+                    diag.help("compare the index to the length first, `len` is a `const fn`");
+                },
+            );
+            return;
+        }
+        if_chain! {
+            if ptr::eq(lint, INDEXING_SLICING) && self.max_array_size > 0;
+            if let ty::Array(_, size) = cx.typeck_results().expr_ty(array).peel_refs().kind();
+            if let Some(size) = size.try_eval_usize(cx.tcx, cx.param_env);
+            if size <= self.max_array_size;
+            then {
+                return;
+            }
+        }
+
+        lint_with_get_suggestion(cx, lint, expr, array, index, msg, help_msg);
+    }
+}
+
+/// Returns the pattern, the iterated expression and the range of the `for` loop over a range
+/// with an end whose loop variable `var` refers to.
+fn for_loop_over_range<'tcx>(
+    cx: &LateContext<'tcx>,
+    var: &Expr<'_>,
+) -> Option<(&'tcx Pat<'tcx>, &'tcx Expr<'tcx>, higher::Range<'tcx>)> {
+    let local = path_to_local(var)?;
+    let (pat, arg, ..) = cx.tcx.hir().parent_iter(var.hir_id).find_map(|(_, node)| match node {
+        Node::Expr(e) => higher::for_loop(e).filter(|(pat, ..)| pat.hir_id == local),
+        _ => None,
+    })?;
+    match higher::range(arg)? {
+        range @ higher::Range { end: Some(_), .. } => Some((pat, arg, range)),
+        _ => None,
+    }
+}
+
+fn is_len_call(expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::MethodCall(path, _, [_], _) if path.ident.name.as_str() == "len")
+}
+
+/// Whether `expr` is `x.len()`.
+fn is_len_of(cx: &LateContext<'_>, expr: &Expr<'_>, collection: &Expr<'_>) -> bool {
+    matches!(
+        expr.kind,
+        ExprKind::MethodCall(path, _, [receiver], _)
+            if path.ident.name == sym!(len) && SpanlessEq::new(cx).eq_expr(receiver, collection)
+    )
+}
+
+/// Whether `index` is below the length of `collection` unless it is empty: `i % x.len()`, or
+/// `i & (x.len() - 1)`, which is at most `x.len() - 1` whether or not the length is a power of two.
+fn is_reduced_modulo_len(cx: &LateContext<'_>, index: &Expr<'_>, collection: &Expr<'_>) -> bool {
+    let is_len_minus_one = |expr: &Expr<'_>| {
+        matches!(
+            expr.kind,
+            ExprKind::Binary(op, len, one)
+                if op.node == BinOpKind::Sub
+                    && is_len_of(cx, len, collection)
+                    && constant(cx, cx.typeck_results(), one).map(|(c, _)| c) == Some(Constant::Int(1))
+        )
+    };
+    match index.kind {
+        ExprKind::Binary(op, _, rhs) if op.node == BinOpKind::Rem => is_len_of(cx, rhs, collection),
+        ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::BitAnd => {
+            is_len_minus_one(lhs) || is_len_minus_one(rhs)
+        },
+        _ => false,
+    }
+}
+
+/// Whether `expr` is in the body of a `const fn`.
+fn is_in_const_fn(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let hir = cx.tcx.hir();
+    let owner = hir.local_def_id(hir.enclosing_body_owner(expr.hir_id));
+    hir.body_const_context(owner) == Some(ConstContext::ConstFn)
+}
+
+/// Whether `expr` is a field reached through a reference, like `self.field` in a method taking
+/// `&self`, or a `Box`. rustc can't evaluate the length of such places, even for arrays.
+fn is_field_behind_reference(cx: &LateContext<'_>, mut expr: &Expr<'_>) -> bool {
+    if !matches!(expr.kind, ExprKind::Field(..)) {
+        return false;
+    }
+    let typeck = cx.typeck_results();
+    loop {
+        if matches!(typeck.expr_ty(expr).kind(), ty::Ref(..))
+            || typeck
+                .expr_adjustments(expr)
+                .iter()
+                .any(|adjustment| matches!(adjustment.kind, Adjust::Deref(_)))
+        {
+            return true;
+        }
+        match expr.kind {
+            ExprKind::Field(base, _) | ExprKind::Index(base, _) => expr = base,
+            ExprKind::Unary(UnOp::Deref, _) => return true,
+            _ => return false,
+        }
+    }
+}
+
+/// Emits `COLLECT_THEN_INDEX` if `expr` indexes `array`, a `Vec` collected from an iterator, with
+/// the single index `index`.
+fn check_collect_then_index(cx: &LateContext<'_>, expr: &Expr<'_>, array: &Expr<'_>, index: &Expr<'_>) {
+    if_chain! {
+        if !expr.span.from_expansion();
+        if let ExprKind::MethodCall(path, _, [iter], _) = array.kind;
+        if path.ident.as_str() == "collect" && is_trait_method(cx, array, sym::Iterator);
+        if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(array), sym::vec_type);
+        if matches!(cx.typeck_results().expr_ty(index).kind(), ty::Uint(UintTy::Usize));
+        then {
+            let mut applicability = Applicability::MaybeIncorrect;
+            let iter = snippet_with_applicability(cx, iter.span, "..", &mut applicability);
+            let sugg = if const_usize(cx, index) == Some(0) {
+                format!("{}.next().unwrap()", iter)
+            } else {
+                let index = snippet_with_applicability(cx, index.span, "..", &mut applicability);
+                format!("{}.nth({}).unwrap()", iter, index)
+            };
+            span_lint_and_then(
+                cx,
+                COLLECT_THEN_INDEX,
+                expr.span,
+                "collecting an iterator to index a single element",
+                |diag| {
+                    diag.note("collecting allocates a `Vec` and consumes the whole iterator");
+                    diag.span_suggestion(
+                        expr.span,
+                        "consider taking the element from the iterator",
+                        sugg,
+                        applicability,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Emits `CONSTRUCT_THEN_INDEX` if `expr` indexes `array`, the call of a constructor of a `Cow`,
+/// `Box`, `Rc` or `Arc`, with `index`. The shape of `array` matters, not just its type: the pointer
+/// must be created by the indexing expression itself.
+fn check_construct_then_index(cx: &LateContext<'_>, expr: &Expr<'_>, array: &Expr<'_>, index: &Expr<'_>) {
+    if_chain! {
+        if !expr.span.from_expansion();
+        if let ExprKind::Call(callee, [arg]) = array.kind;
+        if let ExprKind::Path(ref qpath) = callee.kind;
+        if matches!(
+            &*last_path_segment(qpath).ident.as_str(),
+            "new" | "from" | "Borrowed" | "Owned"
+        );
+        let ty = cx.typeck_results().expr_ty(array);
+        if let Some(kind) = if ty.is_box() {
+            Some("Box")
+        } else if is_type_diagnostic_item(cx, ty, sym::Rc) {
+            Some("Rc")
+        } else if is_type_diagnostic_item(cx, ty, sym::Arc) {
+            Some("Arc")
+        } else if match_type(cx, ty, &paths::COW) {
+            Some("Cow")
+        } else {
+            None
+        };
+        then {
+            span_lint_and_then(
+                cx,
+                CONSTRUCT_THEN_INDEX,
+                expr.span,
+                &format!("indexing a `{}` right where it is constructed", kind),
+                |diag| {
+                    diag.span_note(arg.span, &format!("the `{}` is constructed from this value", kind));
+                    diag.help(&format!(
+                        "consider binding the `{}` to a variable and using `.get({})`, or indexing the value directly",
+                        kind,
+                        snippet_opt(cx, index.span).unwrap_or_else(|| "..".into())
+                    ));
+                },
+            );
+        }
+    }
+}
+
+/// Emits `NTH_OUT_OF_BOUNDS` if the constant `n` of the call `expr` of `nth` on `iter` is at least
+/// the length of `iter`, which is known if `iter` is a range with constant bounds or iterates over
+/// a collection whose length is known, see `known_len`.
+fn check_nth<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, iter: &Expr<'_>, n: &Expr<'_>) {
+    let n = match const_usize(cx, n) {
+        Some(n) => n,
+        None => return,
+    };
+    let (len, len_label) = match iter.kind {
+        ExprKind::MethodCall(path, _, [collection], _)
+            if matches!(path.ident.as_str().as_ref(), "iter" | "iter_mut" | "into_iter") =>
+        {
+            match known_len(cx, expr, collection) {
+                Some(len) => (len.len, (len.span, format!("the {} has length {}", len.kind, len.len))),
+                None => return,
+            }
+        }
+        _ => match higher::range(iter).and_then(|range| range_len(cx, &range)) {
+            Some(len) => (len, (iter.span, format!("the range has length {}", len))),
+            None => return,
+        },
+    };
+    if n >= len {
+        span_lint_and_then_multi(
+            cx,
+            NTH_OUT_OF_BOUNDS,
+            expr.span,
+            "`nth` exceeds the length of the iterator",
+            &[len_label],
+            |diag| {
+                diag.note(&format!("`nth({})` always returns `None`", n));
+            },
+        );
+    }
+}
+
+/// Returns the number of elements of `range` if both bounds are integer constants.
+fn range_len(cx: &LateContext<'_>, range: &higher::Range<'_>) -> Option<u128> {
+    let (start, end) = (range.start?, range.end?);
+    let bound = |e: &Expr<'_>| match (
+        constant(cx, cx.typeck_results(), e)?.0,
+        cx.typeck_results().expr_ty(e).kind(),
+    ) {
+        (Constant::Int(n), ty::Int(ity)) => Some(sext(cx.tcx, n, *ity)),
+        (Constant::Int(n), ty::Uint(_)) => i128::try_from(n).ok(),
+        _ => None,
+    };
+    let (start, end) = (bound(start)?, bound(end)?);
+    let len = match range.limits {
+        RangeLimits::HalfOpen => end.checked_sub(start)?,
+        RangeLimits::Closed => end.checked_sub(start)?.checked_add(1)?,
+    };
+    Some(u128::try_from(len).unwrap_or(0))
+}
+
+/// Emits `STRING_SLICE` on `expr`, unless the string and the range are constants and the range
+/// is known to be valid.
+fn check_string_slice(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    array: &Expr<'_>,
+    index: &Expr<'_>,
+    range: higher::Range<'_>,
+) {
+    if range.start.is_none() && range.end.is_none() {
+        // [..] is ok.
+        return;
+    }
+
+    if let Some((Constant::Str(s), _)) = constant_with_locals(cx, cx.typeck_results(), array) {
+        if let (Some(start), Some(end)) = to_const_range(cx, range, s.len() as u128) {
+            let is_boundary = |i: u128| usize::try_from(i).map_or(false, |i| s.is_char_boundary(i));
+            if start <= end && is_boundary(start) && is_boundary(end) {
+                return;
+            }
+        }
+    }
+
+    lint_string_slice(cx, expr, array, index);
+}
+
+/// Emits `STRING_SLICE` on `expr`.
+fn lint_string_slice(cx: &LateContext<'_>, expr: &Expr<'_>, array: &Expr<'_>, index: &Expr<'_>) {
+    lint_with_get_suggestion(
+        cx,
+        STRING_SLICE,
+        expr,
+        array,
+        index,
+        "slicing a string may panic if a bound is not on a character boundary",
+        "consider using `.get()` instead, or find the character boundaries with `.char_indices()`",
+    );
+}
+
+/// Emits `OUT_OF_BOUNDS_INDEXING` on the indexing `expr` at `span`, with `label` on it and a label
+/// with the length `size` where the length of `array` is set. Emits `WITH_CAPACITY_INDEXING`
+/// instead if `array` is a `Vec` that is still empty after `Vec::with_capacity`, and
+/// `SIZE_OF_OUT_OF_BOUNDS` if `bound`, the index or the bound of the range that is out of bounds,
+/// uses `mem::size_of`. `clamped_end` is the span of a literal range end that is suggested to be
+/// replaced by the length.
+#[allow(clippy::too_many_arguments)]
+fn lint_out_of_bounds<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    array: &Expr<'_>,
+    size: u128,
+    span: Span,
+    msg: &str,
+    label: String,
+    bound: Option<&'tcx Expr<'tcx>>,
+    clamped_end: Option<Span>,
+) {
+    if let Some(init_span) = empty_with_capacity(cx, expr, array) {
+        let labels = [
+            (span, label),
+            (init_span, "the vector is created empty here".to_string()),
+        ];
+        span_lint_and_then_multi(
+            cx,
+            WITH_CAPACITY_INDEXING,
+            span,
+            "indexing a `Vec` created with `with_capacity` before adding elements",
+            &labels,
+            |diag| {
+                diag.note("`with_capacity` only allocates memory, the length of the vector is 0");
+                diag.help("add the elements with `push` first, or create them with `vec![elem; n]`");
+            },
+        );
+        return;
+    }
+    let (kind, len_span) = known_len(cx, expr, array).map_or(("vector", array.span), |len| (len.kind, len.span));
+    let labels = [(span, label), (len_span, format!("the {} has length {}", kind, size))];
+    let sized_types = bound.map_or_else(Vec::new, |bound| size_of_types(cx, bound));
+    if !sized_types.is_empty() {
+        span_lint_and_then_multi(cx, SIZE_OF_OUT_OF_BOUNDS, span, msg, &labels, |diag| {
+            for (ty, size) in sized_types {
+                diag.note(&format!("`{}` is {} bytes", ty, size));
+            }
+            diag.help(&format!("the {} is too small for the types read from it", kind));
+        });
+        return;
+    }
+    // the value of a call that is the whole bound is in the label already
+    let const_fn_calls = bound.map_or_else(Vec::new, |bound| {
+        let mut calls = const_fn_calls(cx, bound);
+        calls.retain(|(call, _)| call.span != bound.span);
+        calls
+    });
+    span_lint_and_then_multi(cx, OUT_OF_BOUNDS_INDEXING, span, msg, &labels, |diag| {
+        for (call, value) in const_fn_calls {
+            diag.span_note(call.span, &format!("this call evaluates to {}", value));
+        }
+        if let Some(end_span) = clamped_end {
+            diag.span_suggestion(
+                end_span,
+                &format!("end the range at the length of the {}", kind),
+                size.to_string(),
+                Applicability::MaybeIncorrect,
+            );
+        }
+    });
+}
+
+/// Checks the indexing `expr` of `array` with an index in `index_range` if it is in an arm of a
+/// `match` on the length of `array`. Emits `MATCHED_LEN_OUT_OF_BOUNDS` if the index is out of
+/// bounds for every length the arm matches. Returns whether the lint was emitted, or the index is
+/// in bounds for every length.
+fn check_matched_len<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    array: &Expr<'_>,
+    index_range: &ValueRange<'_>,
+) -> bool {
+    let matched = match guards::matched_len(cx, expr, array) {
+        Some(matched) => matched,
+        None => return false,
+    };
+    let max = match matched.max {
+        Some(max) if index_range.min >= max => max,
+        _ => return index_range.max.map_or(false, |max| max < matched.min),
+    };
+    let len = if max == matched.min {
+        max.to_string()
+    } else {
+        format!("at most {}", max)
+    };
+    let labels = [
+        (expr.span, value_label("this index is", index_range)),
+        (matched.span, format!("the length is {} in this arm", len)),
+    ];
+    span_lint_and_then_multi(
+        cx,
+        MATCHED_LEN_OUT_OF_BOUNDS,
+        expr.span,
+        "index is out of bounds of the length matched by this arm",
+        &labels,
+        |_| {},
+    );
+    true
+}
+
+/// Whether rustc's `unconditional_panic` lint knows the value of the constant `index`. It doesn't
+/// evaluate `mem::size_of` or the calls of `const fn`s.
+fn is_const_for_rustc<'tcx>(cx: &LateContext<'tcx>, index: &'tcx Expr<'tcx>) -> bool {
+    const_usize(cx, index).is_some() && size_of_types(cx, index).is_empty() && const_fn_calls(cx, index).is_empty()
+}
+
+/// Returns the concrete types `T` of the calls of `mem::size_of::<T>()` in `expr`, with their
+/// size.
+fn size_of_types<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Vec<(Ty<'tcx>, u64)> {
+    struct SizeOfVisitor<'a, 'tcx> {
+        cx: &'a LateContext<'tcx>,
+        types: Vec<(Ty<'tcx>, u64)>,
+    }
+
+    impl<'a, 'tcx> Visitor<'tcx> for SizeOfVisitor<'a, 'tcx> {
+        type Map = Map<'tcx>;
+
+        fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+            if_chain! {
+                if let ExprKind::Call(callee, []) = expr.kind;
+                if let ExprKind::Path(ref qpath) = callee.kind;
+                if let Some(def_id) = self.cx.qpath_res(qpath, callee.hir_id).opt_def_id();
+                if match_def_path(self.cx, def_id, &paths::MEM_SIZE_OF);
+                if let Some(ty) = self.cx.typeck_results().node_substs(callee.hir_id).types().next();
+                if let Ok(layout) = self.cx.tcx.layout_of(self.cx.param_env.and(ty));
+                if !self.types.iter().any(|&(known, _)| known == ty);
+                then {
+                    self.types.push((ty, layout.size.bytes()));
+                }
+            }
+            walk_expr(self, expr);
+        }
+
+        fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+            NestedVisitorMap::None
+        }
+    }
+
+    let mut visitor = SizeOfVisitor { cx, types: Vec::new() };
+    visitor.visit_expr(expr);
+    visitor.types
+}
+
+/// Returns the calls of the `const fn`s of the crate in `expr` that evaluate to an integer, with
+/// their value, see `constant_with_locals`.
+fn const_fn_calls<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Vec<(&'tcx Expr<'tcx>, u128)> {
+    struct ConstFnVisitor<'a, 'tcx> {
+        cx: &'a LateContext<'tcx>,
+        calls: Vec<(&'tcx Expr<'tcx>, u128)>,
+    }
+
+    impl<'a, 'tcx> Visitor<'tcx> for ConstFnVisitor<'a, 'tcx> {
+        type Map = Map<'tcx>;
+
+        fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+            if_chain! {
+                if let ExprKind::Call(callee, _) = expr.kind;
+                if let ExprKind::Path(ref qpath) = callee.kind;
+                if let Some(def_id) = self.cx.qpath_res(qpath, callee.hir_id).opt_def_id();
+                if def_id.is_local() && self.cx.tcx.is_const_fn_raw(def_id);
+                if let Some(value) = const_usize(self.cx, expr);
+                then {
+                    self.calls.push((expr, value));
+                    return;
+                }
+            }
+            walk_expr(self, expr);
+        }
+
+        fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+            NestedVisitorMap::None
+        }
+    }
+
+    let mut visitor = ConstFnVisitor { cx, calls: Vec::new() };
+    visitor.visit_expr(expr);
+    visitor.calls
+}
+
+/// Returns `"{prefix} {value}"` if `range` is a single value, or `"{prefix} {min} or more"`
+/// otherwise.
+fn value_label(prefix: &str, range: &ValueRange<'_>) -> String {
+    if range.max == Some(range.min) {
+        format!("{} {}", prefix, range.min)
+    } else {
+        format!("{} {} or more", prefix, range.min)
+    }
+}
+
+/// Emits `INCLUSIVE_RANGE_OUT_OF_BOUNDS` if the inclusive range `index` ending at `end` is known to
+/// go past the end of a collection of length `size`, or to end at `usize::MAX`. Returns whether
+/// the lint was emitted.
+fn check_inclusive_end<'tcx>(
+    cx: &LateContext<'tcx>,
+    index: &Expr<'_>,
+    start: Option<&Expr<'_>>,
+    end: &'tcx Expr<'tcx>,
+    size: Option<u128>,
+) -> bool {
+    let end_min = value_range(cx, end).min;
+    // the range may be stored in a binding
+    let is_stored = !index.span.contains(end.span);
+    let span = if is_stored { index.span } else { end.span };
+    if end_min >= clip(cx.tcx, u128::MAX, UintTy::Usize) {
+        span_lint_and_help(
+            cx,
+            INCLUSIVE_RANGE_OUT_OF_BOUNDS,
+            span,
+            "inclusive range ending at `usize::MAX`, which always panics",
+            None,
+            "`..=n` slices up to index `n + 1`, which overflows here; use `m..` to slice until the end",
+        );
+        return true;
+    }
+
+    let size = match size {
+        Some(size) if end_min >= size => size,
+        _ => return false,
+    };
+    span_lint_and_then(
+        cx,
+        INCLUSIVE_RANGE_OUT_OF_BOUNDS,
+        span,
+        "inclusive range is out of bounds",
+        |diag| {
+            diag.note(&format!(
+                "`..=n` includes the element at `n`, so it needs `n + 1` elements, but there are only {}",
+                size
+            ));
+            // `..=len` is usually meant to be `..len`
+            let op_span = index
+                .span
+                .with_lo(start.map_or(index.span.lo(), |start| start.span.hi()))
+                .with_hi(end.span.lo());
+            if end_min == size
+                && !is_stored
+                && !index.span.from_expansion()
+                && snippet_opt(cx, op_span).map_or(false, |op| op.trim() == "..=")
+            {
+                diag.span_suggestion(
+                    op_span,
+                    "use an exclusive range to slice until the end",
+                    "..".to_string(),
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        },
+    );
+    true
+}
+
+/// Emits `REVERSED_SLICE_RANGE` if the constant start of the range `index` is after its constant
+/// end. Returns whether the lint was emitted.
+fn check_reversed(cx: &LateContext<'_>, index: &Expr<'_>, range: higher::Range<'_>) -> bool {
+    let (start_expr, end_expr) = match (range.start, range.end) {
+        (Some(start), Some(end)) => (start, end),
+        _ => return false,
+    };
+    let (start, end) = match (const_usize(cx, start_expr), const_usize(cx, end_expr)) {
+        (Some(start), Some(end)) if range.limits == RangeLimits::Closed => (start, end.saturating_add(1)),
+        (Some(start), Some(end)) => (start, end),
+        _ => return false,
+    };
+    if start <= end {
+        return false;
+    }
+    // the range may be stored in a binding
+    let is_stored = !index.span.contains(start_expr.span) || !index.span.contains(end_expr.span);
+    let span = if is_stored {
+        index.span
+    } else {
+        start_expr.span.to(end_expr.span)
+    };
+    span_lint_and_then(
+        cx,
+        REVERSED_SLICE_RANGE,
+        span,
+        "range is reversed, slicing with it always panics",
+        |diag| {
+            diag.note(&format!("the slicing starts at {} but ends at {}", start, end));
+            if is_stored || index.span.from_expansion() {
+                return;
+            }
+            let mut applicability = Applicability::MaybeIncorrect;
+            let dots = match range.limits {
+                RangeLimits::HalfOpen => "..",
+                RangeLimits::Closed => "..=",
+            };
+            diag.span_suggestion(
+                span,
+                "swap the bounds of the range",
+                format!(
+                    "{}{}{}",
+                    snippet_with_applicability(cx, end_expr.span, "..", &mut applicability),
+                    dots,
+                    snippet_with_applicability(cx, start_expr.span, "..", &mut applicability)
+                ),
+                applicability,
+            );
+        },
+    );
+    true
+}
+
+/// Whether the bounds of `range`, the index of `expr` on `array`, are checked by comparisons
+/// guarding `expr`, like `i <= j && j <= x.len()` for `x[i..j]`. A missing start is 0 and a
+/// missing end is the length.
+fn is_range_guarded<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    array: &Expr<'_>,
+    range: higher::Range<'_>,
+) -> bool {
+    let guards = guards::dominating_comparisons(cx, expr);
+    let is_len_guarded = |bound: &Expr<'_>, inclusive: bool| {
+        guards
+            .iter()
+            .any(|guard| guard.implies_len(cx, bound, array, inclusive))
+    };
+    let start_before_end = match (range.start, range.end) {
+        (Some(start), Some(end)) => guards.iter().any(|guard| guard.implies(cx, start, end, true)),
+        (Some(start), None) => is_len_guarded(start, true),
+        (None, _) => true,
+    };
+    let end_in_bounds = match range.end {
+        Some(end) => is_len_guarded(end, range.limits == RangeLimits::HalfOpen),
+        None => true,
+    };
+    start_before_end && end_in_bounds
+}
+
+/// Emits `SIGNED_INDEX_CAST` if `expr` casts a signed integer to `usize`, unless it is a constant
+/// that isn't negative.
+fn lint_signed_cast(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    if_chain! {
+        if let ExprKind::Cast(inner, _) = expr.kind;
+        if !expr.span.from_expansion();
+        if let ty::Int(int_ty) = *cx.typeck_results().expr_ty(inner).kind();
+        if let ty::Uint(UintTy::Usize) = cx.typeck_results().expr_ty(expr).kind();
+        if !matches!(
+            constant(cx, cx.typeck_results(), inner),
+            Some((Constant::Int(n), _)) if sext(cx.tcx, n, int_ty) >= 0
+        );
+        then {
+            let mut applicability = Applicability::MaybeIncorrect;
+            let snip = snippet_with_applicability(cx, inner.span, "..", &mut applicability);
+            // the operand of a cast is parenthesized if it's a binary operation
+            let inner = match inner.kind {
+                ExprKind::Binary(..) => snip.strip_prefix('(').and_then(|s| s.strip_suffix(')')).unwrap_or(&snip),
+                _ => &snip,
+            };
+            span_lint_and_then(
+                cx,
+                SIGNED_INDEX_CAST,
+                expr.span,
+                "casting a signed integer to `usize` for indexing wraps around negative values",
+                |diag| {
+                    diag.span_suggestion(
+                        expr.span,
+                        "use `usize::try_from` to fail on negative values",
+                        format!("usize::try_from({}).unwrap()", inner),
+                        applicability,
+                    );
+                    // `TryFrom` is only in the prelude since the 2021 edition
+                    if !meets_edition(expr.span, Edition::Edition2021) {
+                        diag.note("`std::convert::TryFrom` has to be imported before the 2021 edition");
+                    }
+                },
+            );
+        }
+    }
+}
+
+/// Emits `INDEX_UNDERFLOW` on the outermost unsigned subtractions in the arithmetic `expr` that
+/// aren't known to be guarded against underflow.
+fn lint_underflow<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+    match expr.kind {
+        ExprKind::Binary(op, lhs, rhs)
+            if op.node == BinOpKind::Sub
+                && matches!(cx.typeck_results().expr_ty(expr).kind(), ty::Uint(_))
+                && !expr.span.from_expansion() =>
+        {
+            if is_guarded_sub(cx, lhs, rhs)
+                || guards::is_dominated_by(cx, expr, rhs, lhs, true)
+                || is_non_empty_len_minus_one(cx, expr, lhs, rhs)
+            {
+                lint_underflow(cx, lhs);
+                lint_underflow(cx, rhs);
+                return;
+            }
+            let lhs = Sugg::hir(cx, lhs, "..").maybe_par();
+            let rhs = Sugg::hir(cx, rhs, "..");
+            span_lint_and_help(
+                cx,
+                INDEX_UNDERFLOW,
+                expr.span,
+                "this subtraction in an index can underflow",
+                None,
+                &format!(
+                    "consider using `{0}.checked_sub({1})` with `.get()`, or `{0}.saturating_sub({1})` \
+                     if clamping at zero is intended",
+                    lhs, rhs
+                ),
+            );
+        }
+        ExprKind::Binary(_, lhs, rhs) => {
+            lint_underflow(cx, lhs);
+            lint_underflow(cx, rhs);
+        },
+        ExprKind::Cast(inner, _) => lint_underflow(cx, inner),
+        _ => {},
+    }
+}
+
+/// Whether `lhs - rhs` is known not to underflow, i.e. if `rhs` is at most `lhs`, which is the
+/// case if its maximum is at most the minimum of `lhs`, or if `lhs` is `x.len()` and `rhs` is at
+/// most the length of `x`.
+fn is_guarded_sub<'tcx>(cx: &LateContext<'tcx>, lhs: &'tcx Expr<'tcx>, rhs: &'tcx Expr<'tcx>) -> bool {
+    let rhs_range = value_range(cx, rhs);
+    if rhs_range.max.map_or(false, |max| max <= value_range(cx, lhs).min) {
+        return true;
+    }
+    match lhs.kind {
+        ExprKind::MethodCall(path, _, [receiver], _) if path.ident.name == sym!(len) => {
+            rhs_range.is_at_most_len(cx, receiver, None)
+        },
+        _ => false,
+    }
+}
+
+/// Whether the subtraction `expr` is `x.len() - 1` and guarded by a check that `x` isn't empty.
+fn is_non_empty_len_minus_one<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    lhs: &Expr<'_>,
+    rhs: &Expr<'_>,
+) -> bool {
+    match lhs.kind {
+        ExprKind::MethodCall(path, _, [receiver], _) if path.ident.name == sym!(len) => {
+            constant(cx, cx.typeck_results(), rhs).map(|(c, _)| c) == Some(Constant::Int(1))
+                && guards::is_non_empty_guarded(cx, expr, receiver)
+        },
+        _ => false,
+    }
+}
+
+/// Emits `lint` on `expr`, suggesting a `get`/`get_mut` call instead of the indexing when such a
+/// rewrite is possible. `INDEXING_SLICING` gets a label with the length of `array` where it is
+/// set, if it is known.
+fn lint_with_get_suggestion(
+    cx: &LateContext<'_>,
+    lint: &'static Lint,
+    expr: &Expr<'_>,
+    array: &Expr<'_>,
+    index: &Expr<'_>,
+    msg: &str,
+    help_msg: &str,
+) {
+    let labels: Vec<(Span, String)> = if ptr::eq(lint, INDEXING_SLICING) {
+        known_slice_len(cx, expr, array)
+            .map(|len| (len.span, format!("the {} has length {}", len.kind, len.len)))
+            .into_iter()
+            .collect()
+    } else {
+        Vec::new()
+    };
+    span_lint_and_then_multi(cx, lint, expr.span, msg, &labels, |diag| {
+        if let Some((span, sugg, applicability)) = get_suggestion(cx, expr, array, index) {
+            diag.span_suggestion_verbose(span, help_msg, sugg, applicability);
+        } else {
+            diag.help(help_msg);
+        }
+    });
+}
+
+/// Builds the `x.get(n)` rewrite of `x[n]`. A borrow of the indexing expression (`&x[a..b]` or
+/// `&mut x[a..b]`) is replaced as a whole. Returns `None` if the indexing expression is used as a
+/// place, e.g. as the receiver of another indexing, of a method call or of an assignment.
+fn get_suggestion(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    array: &Expr<'_>,
+    index: &Expr<'_>,
+) -> Option<(Span, String, Applicability)> {
+    if expr.span.from_expansion() {
+        return None;
+    }
+
+    let (span, method) = match get_parent_expr(cx, expr) {
+        Some(parent) => match parent.kind {
+            ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _) => (parent.span, "get"),
+            ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, _) => (parent.span, "get_mut"),
+            ExprKind::Index(place, _)
+            | ExprKind::Field(place, _)
+            | ExprKind::Assign(place, ..)
+            | ExprKind::AssignOp(_, place, _)
+                if place.hir_id == expr.hir_id =>
+            {
+                return None;
+            }
+            ExprKind::MethodCall(_, _, [receiver, ..], _) if receiver.hir_id == expr.hir_id => return None,
+            _ => (expr.span, "get"),
+        },
+        None => (expr.span, "get"),
+    };
+
+    // `get` returns an `Option`, so the rewrite is never a drop-in replacement
+    let mut applicability = Applicability::MaybeIncorrect;
+    let receiver = Sugg::hir_with_applicability(cx, array, "..", &mut applicability);
+    let index = Sugg::hir_with_applicability(cx, index, "..", &mut applicability);
+
+    Some((
+        span,
+        Sugg::method_call(receiver, method, &[index]).to_string(),
+        applicability,
+    ))
+}
+
 /// Returns a tuple of options with the start and end (exclusive) values of
 /// the range. If the start or end is not constant, None is returned.
 fn to_const_range<'tcx>(
@@ -172,7 +1740,7 @@ fn to_const_range<'tcx>(
 ) -> (Option<u128>, Option<u128>) {
     let s = range
         .start
-        .map(|expr| constant(cx, cx.typeck_results(), expr).map(|(c, _)| c));
+        .map(|expr| constant_with_locals(cx, cx.typeck_results(), expr).map(|(c, _)| c));
     let start = match s {
         Some(Some(Constant::Int(x))) => Some(x),
         Some(_) => None,
@@ -181,11 +1749,11 @@ fn to_const_range<'tcx>(
 
     let e = range
         .end
-        .map(|expr| constant(cx, cx.typeck_results(), expr).map(|(c, _)| c));
+        .map(|expr| constant_with_locals(cx, cx.typeck_results(), expr).map(|(c, _)| c));
     let end = match e {
         Some(Some(Constant::Int(x))) => {
             if range.limits == RangeLimits::Closed {
-                Some(x + 1)
+                x.checked_add(1)
             } else {
                 Some(x)
             }
@@ -196,3 +1764,105 @@ fn to_const_range<'tcx>(
 
     (start, end)
 }
+
+declare_lint_pass!(OutOfBoundsIndexing => [OUT_OF_BOUNDS_INDEXING]);
+
+/// The part of `OUT_OF_BOUNDS_INDEXING` running on MIR, for indexing and slicing slices whose
+/// length is known from the array they were created from. The HIR checks don't know the length of
+/// slices, and rustc's `unconditional_panic` lint only knows the length of arrays.
+impl MirLintPass for OutOfBoundsIndexing {
+    fn check_terminator<'tcx>(&mut self, cx: &MirContext<'_, 'tcx>, terminator: &Terminator<'tcx>, location: Location) {
+        if terminator.source_info.span.from_expansion() {
+            return;
+        }
+
+        let tcx = cx.cx.tcx;
+        match &terminator.kind {
+            TerminatorKind::Assert {
+                msg: AssertKind::BoundsCheck { len, index },
+                ..
+            } => {
+                if_chain! {
+                    if let Some(len) = len.place();
+                    if let Some((Def::Assign(Rvalue::Len(slice)), def_location)) = cx.def(len, location);
+                    if let ty::Slice(_) = slice.ty(cx.mir, tcx).ty.kind();
+                    if let Some(len) = cx.len(*slice, def_location);
+                    if let Some(index) = cx.eval_uint(index, location);
+                    if index >= len;
+                    then {
+                        cx.span_lint(OUT_OF_BOUNDS_INDEXING, terminator.source_info, "index is out of bounds");
+                    }
+                }
+            },
+            TerminatorKind::Call { func, args, .. } => {
+                let lang_items = tcx.lang_items();
+                if_chain! {
+                    if let [slice, range] = &**args;
+                    if let ty::FnDef(def_id, substs) = *func.ty(cx.mir, tcx).kind();
+                    if let Some(trait_id) = tcx.trait_of_item(def_id);
+                    if lang_items.index_trait() == Some(trait_id) || lang_items.index_mut_trait() == Some(trait_id);
+                    if let ty::Slice(_) = substs.type_at(0).kind();
+                    if let Some(len) = cx.ref_len(slice, location);
+                    if let Some((start, end)) = eval_range(cx, range, location);
+                    if start > len || end.map_or(false, |end| end > len);
+                    then {
+                        cx.span_lint(OUT_OF_BOUNDS_INDEXING, terminator.source_info, "range is out of bounds");
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+/// Evaluates the start and the exclusive end of the range `operand` at `location`.
+fn eval_range<'tcx>(
+    cx: &MirContext<'_, 'tcx>,
+    range: &Operand<'tcx>,
+    location: Location,
+) -> Option<(u128, Option<u128>)> {
+    let tcx = cx.cx.tcx;
+    let lang_items = tcx.lang_items();
+    let mut place = range.place()?;
+    let mut location = location;
+    let adt = match place.ty(cx.mir, tcx).ty.kind() {
+        ty::Adt(adt, _) => adt.did,
+        _ => return None,
+    };
+    let field = |place: Place<'tcx>, index: usize| {
+        let field = tcx.mk_place_field(place, Field::from_usize(index), tcx.types.usize);
+        cx.eval_uint(&Operand::Copy(field), location)
+    };
+
+    if Some(adt) == lang_items.range_struct() {
+        Some((field(place, 0)?, Some(field(place, 1)?)))
+    } else if Some(adt) == lang_items.range_from_struct() {
+        Some((field(place, 0)?, None))
+    } else if Some(adt) == lang_items.range_to_struct() {
+        Some((0, Some(field(place, 0)?)))
+    } else if Some(adt) == lang_items.range_to_inclusive_struct() {
+        Some((0, Some(field(place, 0)?.checked_add(1)?)))
+    } else if Some(adt) == lang_items.range_inclusive_struct() {
+        // The fields of `RangeInclusive` are private, it is created with `RangeInclusive::new`
+        loop {
+            match cx.def(place, location)? {
+                (Def::Assign(Rvalue::Use(Operand::Copy(src) | Operand::Move(src))), def_location) => {
+                    place = *src;
+                    location = def_location;
+                },
+                (Def::Call(func, [start, end]), def_location) => {
+                    return match *func.ty(cx.mir, tcx).kind() {
+                        ty::FnDef(def_id, _) if lang_items.range_inclusive_new_method() == Some(def_id) => Some((
+                            cx.eval_uint(start, def_location)?,
+                            Some(cx.eval_uint(end, def_location)?.checked_add(1)?),
+                        )),
+                        _ => None,
+                    };
+                },
+                _ => return None,
+            }
+        }
+    } else {
+        None
+    }
+}
@@ -1,9 +1,9 @@
 //! lint when items are used after statements
 
 use clippy_utils::diagnostics::span_lint;
+use clippy_utils::in_external_macro;
 use rustc_ast::ast::{Block, ItemKind, StmtKind};
 use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
-use rustc_middle::lint::in_external_macro;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 
 declare_clippy_lint! {
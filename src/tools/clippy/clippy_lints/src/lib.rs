@@ -32,6 +32,7 @@
 extern crate rustc_infer;
 extern crate rustc_lexer;
 extern crate rustc_lint;
+extern crate rustc_metadata;
 extern crate rustc_middle;
 extern crate rustc_mir;
 extern crate rustc_parse;
@@ -44,8 +45,12 @@
 
 use clippy_utils::parse_msrv;
 use rustc_data_structures::fx::FxHashSet;
-use rustc_lint::LintId;
+use rustc_data_structures::sync::Lrc;
+use rustc_lint::{Lint, LintId};
+use rustc_middle::ty::query::Providers;
 use rustc_session::Session;
+use rustc_span::{BytePos, SourceFile, Span};
+use std::convert::TryFrom;
 
 /// Macro used to declare a Clippy lint.
 ///
@@ -163,6 +168,13 @@ macro_rules! extract_msrv_attr {
 mod consts;
 #[macro_use]
 mod utils;
+pub mod lint_cache;
+pub mod lint_list;
+mod meta_groups;
+mod pass_registry;
+pub mod plugins;
+pub mod profiles;
+pub mod timings;
 
 // begin lints modules, do not remove this comment, it’s used in `update_lints`
 mod absurd_extreme_comparisons;
@@ -185,6 +197,7 @@ macro_rules! extract_msrv_attr {
 mod cargo_common_metadata;
 mod case_sensitive_file_extension_comparisons;
 mod casts;
+mod chained_indexing;
 mod checked_conversions;
 mod cognitive_complexity;
 mod collapsible_if;
@@ -219,6 +232,7 @@ macro_rules! extract_msrv_attr {
 mod exit;
 mod explicit_write;
 mod fallible_impl_from;
+mod ffi_unchecked_index;
 mod float_equality_without_abs;
 mod float_literal;
 mod floating_point_arithmetic;
@@ -259,9 +273,11 @@ macro_rules! extract_msrv_attr {
 mod macro_use;
 mod main_recursion;
 mod manual_async_fn;
+mod manual_from_bytes;
 mod manual_map;
 mod manual_non_exhaustive;
 mod manual_ok_or;
+mod manual_slice_pattern;
 mod manual_strip;
 mod manual_unwrap_or;
 mod map_clone;
@@ -308,9 +324,13 @@ macro_rules! extract_msrv_attr {
 mod option_env_unwrap;
 mod option_if_let_else;
 mod overflow_check_conditional;
+mod panic_in_drop;
+mod panic_in_fmt;
 mod panic_in_result_fn;
 mod panic_unimplemented;
+mod panicking_index_impl;
 mod partialeq_ne_impl;
+mod partially_read_buffer;
 mod pass_by_ref_or_value;
 mod path_buf_push_overwrite;
 mod pattern_type_mismatch;
@@ -320,6 +340,7 @@ macro_rules! extract_msrv_attr {
 mod ptr_offset_with_cast;
 mod question_mark;
 mod ranges;
+mod reachable_panic;
 mod redundant_clone;
 mod redundant_closure_call;
 mod redundant_else;
@@ -331,6 +352,7 @@ macro_rules! extract_msrv_attr {
 mod reference;
 mod regex;
 mod repeat_once;
+mod repeated_indexing;
 mod returns;
 mod self_assignment;
 mod semicolon_if_nothing_returned;
@@ -339,6 +361,7 @@ macro_rules! extract_msrv_attr {
 mod single_component_path_imports;
 mod size_of_in_element_count;
 mod slow_vector_initialization;
+mod split_indexing;
 mod stable_sort_primitive;
 mod strings;
 mod suspicious_operation_groupings;
@@ -353,7 +376,11 @@ macro_rules! extract_msrv_attr {
 mod transmuting_null;
 mod try_err;
 mod types;
+mod unchecked_division;
+mod unchecked_env_args;
 mod undropped_manually_drops;
+mod unguarded_raw_pointer_index;
+mod unhelpful_expect_message;
 mod unicode;
 mod unit_return_expecting_ord;
 mod unit_types;
@@ -368,10 +395,13 @@ macro_rules! extract_msrv_attr {
 mod unused_unit;
 mod unwrap;
 mod unwrap_in_result;
+mod unwrap_indexing;
+mod unwrapped_index_conversion;
 mod upper_case_acronyms;
 mod use_self;
 mod useless_conversion;
 mod vec;
+mod vec_front_in_loop;
 mod vec_init_then_push;
 mod vec_resize_to_zero;
 mod verbose_file_reads;
@@ -382,6 +412,8 @@ macro_rules! extract_msrv_attr {
 mod zero_sized_map_values;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
+pub use crate::lint_list::{LintInfo, ALL_LINTS};
+use crate::pass_registry::PassRegistry;
 pub use crate::utils::conf::Conf;
 use crate::utils::conf::TryConf;
 
@@ -400,50 +432,118 @@ pub fn register_pre_expansion_lints(store: &mut rustc_lint::LintStore) {
     store.register_pre_expansion_pass(|| box dbg_macro::DbgMacro);
 }
 
-#[doc(hidden)]
-pub fn read_conf(sess: &Session) -> Conf {
+/// Search for the configuration file, resolving a relative path against the directory of the crate
+/// root.
+fn conf_file_path(sess: &Session) -> std::io::Result<Option<std::path::PathBuf>> {
+    find_conf_file(sess.local_crate_source_file.as_deref())
+}
+
+/// Search for the configuration file, resolving a relative path against the directory of
+/// `crate_file`, the crate root.
+fn find_conf_file(crate_file: Option<&std::path::Path>) -> std::io::Result<Option<std::path::PathBuf>> {
     use std::path::Path;
-    let file_name = match utils::conf::lookup_conf_file() {
-        Ok(Some(path)) => path,
-        Ok(None) => return Conf::default(),
+    Ok(utils::conf::lookup_conf_file()?.map(|file_name| {
+        if file_name.is_relative() {
+            crate_file
+                .and_then(Path::parent)
+                .unwrap_or_else(|| Path::new(""))
+                .join(file_name)
+        } else {
+            file_name
+        }
+    }))
+}
+
+/// Records the configuration file in the dep-info of the crate, so that Cargo checks it again when
+/// the file changes. In a workspace, every member has its own configuration file, or the one of
+/// the closest parent directory, so each member is only checked again for the file it uses.
+/// Returns the file in the source map, to point at the errors in it.
+fn track_conf_file(sess: &Session, file_name: Option<&std::path::Path>) -> Option<Lrc<SourceFile>> {
+    use rustc_span::Symbol;
+    sess.parse_sess.env_depinfo.lock().insert((
+        Symbol::intern("CLIPPY_CONF_DIR"),
+        std::env::var("CLIPPY_CONF_DIR").ok().as_deref().map(Symbol::intern),
+    ));
+    // the files in the source map are the inputs of the crate, errors reading the file are
+    // reported by `read_conf`
+    file_name.and_then(|file_name| sess.source_map().load_file(file_name).ok())
+}
+
+/// Makes the emitted suggestions that `cargo clippy --fix` should apply according to the
+/// `fix-applicability` configuration value machine applicable.
+#[doc(hidden)]
+pub fn set_fix_applicability(conf: &Conf) {
+    clippy_utils::diagnostics::set_fix_applicability(conf.fix_applicability.applicability());
+}
+
+/// Makes the emitted suggestions that may be incorrect machine applicable, for the diff printed by
+/// `--suggest-only`, which is reviewed before it is applied.
+#[doc(hidden)]
+pub fn set_suggest_only_applicability() {
+    clippy_utils::diagnostics::set_fix_applicability(rustc_errors::Applicability::MaybeIncorrect);
+}
+
+/// Reads the configuration file, with the configuration values of `profile` replacing the ones in
+/// the file.
+#[doc(hidden)]
+pub fn read_conf(sess: &Session, profile: Option<&profiles::Profile>) -> Conf {
+    let file_name = match conf_file_path(sess) {
+        Ok(file_name) => file_name,
         Err(error) => {
             sess.struct_err(&format!("error finding Clippy's configuration file: {}", error))
                 .emit();
-            return Conf::default();
+            None
         },
     };
+    let source_file = track_conf_file(sess, file_name.as_deref());
 
-    let file_name = if file_name.is_relative() {
-        sess.local_crate_source_file
-            .as_deref()
-            .and_then(Path::parent)
-            .unwrap_or_else(|| Path::new(""))
-            .join(file_name)
-    } else {
-        file_name
+    let overrides = profile
+        .map(|profile| &profile.conf.config)
+        .filter(|config| !config.is_empty());
+    let TryConf { conf, errors } = match (&file_name, overrides) {
+        (_, Some(overrides)) => utils::conf::read_with_overrides(file_name.as_deref(), overrides),
+        (Some(file_name), None) => utils::conf::read(file_name),
+        (None, None) => return Conf::default(),
+    };
+    let source = match (file_name, profile) {
+        (Some(file_name), _) => format!("Clippy's configuration file `{}`", file_name.display()),
+        (None, Some(profile)) => format!("the configuration of the Clippy profile `{}`", profile.name),
+        (None, None) => unreachable!(),
     };
-
-    let TryConf { conf, errors } = utils::conf::read(&file_name);
     // all conf errors are non-fatal, we just use the default conf in case of error
     for error in errors {
-        sess.struct_err(&format!(
-            "error reading Clippy's configuration file `{}`: {}",
-            file_name.display(),
-            error
-        ))
-        .emit();
+        let message = format!("error reading {}: {}", source, error.message);
+        let span = source_file.as_ref().and_then(|file| {
+            let range = utils::conf::key_span(file.src.as_ref()?, error.key.as_deref()?)?;
+            let pos = |offset: usize| Some(file.start_pos + BytePos(u32::try_from(offset).ok()?));
+            Some(Span::with_root_ctxt(pos(range.start)?, pos(range.end)?))
+        });
+        match span {
+            Some(span) => sess.struct_span_err(span, &message).emit(),
+            None => sess.struct_err(&message).emit(),
+        }
     }
 
     conf
 }
 
-/// Register all lints and lint groups with the rustc plugin registry
+/// Override the compiler queries Clippy needs to customize.
+///
+/// Used in `./src/driver.rs`.
+pub fn override_queries(_: &Session, providers: &mut Providers, _: &mut Providers) {
+    utils::levels::provide(providers);
+}
+
+/// Register all lints and lint groups with the rustc plugin registry, and the lint passes emitting
+/// the lints in `filter` (a list of lint and lint group names), or all of them without a filter.
 ///
 /// Used in `./src/driver.rs`.
 #[allow(clippy::too_many_lines)]
 #[rustfmt::skip]
-pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf: &Conf) {
+pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf: &Conf, filter: Option<&[String]>) {
     register_removed_non_tool_lints(store);
+    clippy_utils::diagnostics::set_migration_notes(MIGRATION_NOTES);
+    clippy_utils::diagnostics::set_report_in_external_macros(conf.report_in_external_macros);
 
     // begin deprecated lints, do not remove this comment, it’s used in `update_lints`
     store.register_removed(
@@ -574,6 +674,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         casts::FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
         casts::PTR_AS_PTR,
         casts::UNNECESSARY_CAST,
+        chained_indexing::CHAINED_INDEXING,
         checked_conversions::CHECKED_CONVERSIONS,
         cognitive_complexity::COGNITIVE_COMPLEXITY,
         collapsible_if::COLLAPSIBLE_ELSE_IF,
@@ -631,6 +732,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         exit::EXIT,
         explicit_write::EXPLICIT_WRITE,
         fallible_impl_from::FALLIBLE_IMPL_FROM,
+        ffi_unchecked_index::FFI_UNCHECKED_INDEX,
         float_equality_without_abs::FLOAT_EQUALITY_WITHOUT_ABS,
         float_literal::EXCESSIVE_PRECISION,
         float_literal::LOSSY_FLOAT_LITERAL,
@@ -652,6 +754,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         functions::TOO_MANY_LINES,
         future_not_send::FUTURE_NOT_SEND,
         get_last_with_len::GET_LAST_WITH_LEN,
+        get_last_with_len::INDEXING_LAST_WITH_LEN,
         identity_op::IDENTITY_OP,
         if_let_mutex::IF_LET_MUTEX,
         if_let_some_result::IF_LET_SOME_RESULT,
@@ -661,8 +764,22 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         implicit_return::IMPLICIT_RETURN,
         implicit_saturating_sub::IMPLICIT_SATURATING_SUB,
         inconsistent_struct_constructor::INCONSISTENT_STRUCT_CONSTRUCTOR,
+        indexing_slicing::COLLECT_THEN_INDEX,
+        indexing_slicing::CONSTRUCT_THEN_INDEX,
+        indexing_slicing::CONST_FN_INDEXING,
+        indexing_slicing::INCLUSIVE_RANGE_OUT_OF_BOUNDS,
         indexing_slicing::INDEXING_SLICING,
+        indexing_slicing::INDEX_MAP,
+        indexing_slicing::INDEX_UNDERFLOW,
+        indexing_slicing::LOOP_INDEX_OFFSET,
+        indexing_slicing::MATCHED_LEN_OUT_OF_BOUNDS,
+        indexing_slicing::NTH_OUT_OF_BOUNDS,
         indexing_slicing::OUT_OF_BOUNDS_INDEXING,
+        indexing_slicing::REVERSED_SLICE_RANGE,
+        indexing_slicing::SIGNED_INDEX_CAST,
+        indexing_slicing::SIZE_OF_OUT_OF_BOUNDS,
+        indexing_slicing::STRING_SLICE,
+        indexing_slicing::WITH_CAPACITY_INDEXING,
         infinite_iter::INFINITE_ITER,
         infinite_iter::MAYBE_INFINITE_ITER,
         inherent_impl::MULTIPLE_INHERENT_IMPL,
@@ -691,6 +808,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         literal_representation::MISTYPED_LITERAL_SUFFIXES,
         literal_representation::UNREADABLE_LITERAL,
         literal_representation::UNUSUAL_BYTE_GROUPINGS,
+        loops::CHARS_NTH_LOOP,
         loops::EMPTY_LOOP,
         loops::EXPLICIT_COUNTER_LOOP,
         loops::EXPLICIT_INTO_ITER_LOOP,
@@ -698,8 +816,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         loops::FOR_KV_MAP,
         loops::FOR_LOOPS_OVER_FALLIBLES,
         loops::ITER_NEXT_LOOP,
+        loops::MANUAL_BINARY_SEARCH,
         loops::MANUAL_FLATTEN,
         loops::MANUAL_MEMCPY,
+        loops::MANUAL_ZIP,
         loops::MUT_RANGE_BOUND,
         loops::NEEDLESS_COLLECT,
         loops::NEEDLESS_RANGE_LOOP,
@@ -712,9 +832,11 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         macro_use::MACRO_USE_IMPORTS,
         main_recursion::MAIN_RECURSION,
         manual_async_fn::MANUAL_ASYNC_FN,
+        manual_from_bytes::MANUAL_FROM_BYTES,
         manual_map::MANUAL_MAP,
         manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE,
         manual_ok_or::MANUAL_OK_OR,
+        manual_slice_pattern::MANUAL_SLICE_PATTERN,
         manual_strip::MANUAL_STRIP,
         manual_unwrap_or::MANUAL_UNWRAP_OR,
         map_clone::MAP_CLONE,
@@ -762,6 +884,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         methods::FLAT_MAP_OPTION,
         methods::FROM_ITER_INSTEAD_OF_COLLECT,
         methods::GET_UNWRAP,
+        methods::GET_UNWRAP_OR_CALL,
         methods::IMPLICIT_CLONE,
         methods::INEFFICIENT_TO_STRING,
         methods::INSPECT_FOR_EACH,
@@ -779,7 +902,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         methods::MAP_COLLECT_RESULT_UNIT,
         methods::MAP_FLATTEN,
         methods::MAP_UNWRAP_OR,
+        methods::METHOD_INDEX_OUT_OF_BOUNDS,
         methods::NEW_RET_NO_SELF,
+        methods::NONZERO_NEW_UNWRAP,
         methods::OK_EXPECT,
         methods::OPTION_AS_REF_DEREF,
         methods::OPTION_FILTER_MAP,
@@ -791,12 +916,16 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         methods::SINGLE_CHAR_ADD_STR,
         methods::SINGLE_CHAR_PATTERN,
         methods::SKIP_WHILE_NEXT,
+        methods::SLICE_LENGTH_MISMATCH,
         methods::STRING_EXTEND_CHARS,
         methods::SUSPICIOUS_MAP,
+        methods::UNGUARDED_GET_UNCHECKED,
         methods::UNINIT_ASSUMED_INIT,
         methods::UNNECESSARY_FILTER_MAP,
         methods::UNNECESSARY_FOLD,
         methods::UNNECESSARY_LAZY_EVALUATIONS,
+        methods::UNWRAPPED_FIRST_OR_LAST,
+        methods::UNWRAPPED_GET,
         methods::UNWRAP_USED,
         methods::USELESS_ASREF,
         methods::WRONG_PUB_SELF_CONVENTION,
@@ -830,6 +959,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         mut_mut::MUT_MUT,
         mut_mutex_lock::MUT_MUTEX_LOCK,
         mut_reference::UNNECESSARY_MUT_PASSED,
+        mutable_debug_assertion::DEBUG_ASSERT_INDEXING,
         mutable_debug_assertion::DEBUG_ASSERT_WITH_MUT_CALL,
         mutex_atomic::MUTEX_ATOMIC,
         mutex_atomic::MUTEX_INTEGER,
@@ -858,12 +988,18 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         option_env_unwrap::OPTION_ENV_UNWRAP,
         option_if_let_else::OPTION_IF_LET_ELSE,
         overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL,
+        panic_in_drop::PANIC_IN_DROP,
+        panic_in_fmt::PANIC_IN_FMT,
         panic_in_result_fn::PANIC_IN_RESULT_FN,
         panic_unimplemented::PANIC,
+        panic_unimplemented::PANIC_IN_LIBRARY,
         panic_unimplemented::TODO,
         panic_unimplemented::UNIMPLEMENTED,
         panic_unimplemented::UNREACHABLE,
+        panicking_index_impl::PANICKING_INDEX_IMPL,
+        panicking_index_impl::PANICKING_USER_INDEX,
         partialeq_ne_impl::PARTIALEQ_NE_IMPL,
+        partially_read_buffer::PARTIALLY_READ_BUFFER,
         pass_by_ref_or_value::LARGE_TYPES_PASSED_BY_VALUE,
         pass_by_ref_or_value::TRIVIALLY_COPY_PASS_BY_REF,
         path_buf_push_overwrite::PATH_BUF_PUSH_OVERWRITE,
@@ -881,6 +1017,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         ranges::RANGE_PLUS_ONE,
         ranges::RANGE_ZIP_WITH_LEN,
         ranges::REVERSED_EMPTY_RANGES,
+        reachable_panic::REACHABLE_PANIC,
         redundant_clone::REDUNDANT_CLONE,
         redundant_closure_call::REDUNDANT_CLOSURE_CALL,
         redundant_else::REDUNDANT_ELSE,
@@ -894,6 +1031,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         regex::INVALID_REGEX,
         regex::TRIVIAL_REGEX,
         repeat_once::REPEAT_ONCE,
+        repeated_indexing::REPEATED_INDEXING,
         returns::LET_AND_RETURN,
         returns::NEEDLESS_RETURN,
         self_assignment::SELF_ASSIGNMENT,
@@ -905,6 +1043,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         single_component_path_imports::SINGLE_COMPONENT_PATH_IMPORTS,
         size_of_in_element_count::SIZE_OF_IN_ELEMENT_COUNT,
         slow_vector_initialization::SLOW_VECTOR_INITIALIZATION,
+        split_indexing::SPLIT_INDEXING,
         stable_sort_primitive::STABLE_SORT_PRIMITIVE,
         strings::STRING_ADD,
         strings::STRING_ADD_ASSIGN,
@@ -945,7 +1084,11 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         types::REDUNDANT_ALLOCATION,
         types::TYPE_COMPLEXITY,
         types::VEC_BOX,
+        unchecked_division::UNCHECKED_DIVISION,
+        unchecked_env_args::UNCHECKED_ENV_ARGS,
         undropped_manually_drops::UNDROPPED_MANUALLY_DROPS,
+        unguarded_raw_pointer_index::UNGUARDED_RAW_POINTER_INDEX,
+        unhelpful_expect_message::UNHELPFUL_EXPECT_MESSAGE,
         unicode::INVISIBLE_CHARACTERS,
         unicode::NON_ASCII_LITERAL,
         unicode::UNICODE_NOT_NFC,
@@ -966,10 +1109,13 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         unwrap::PANICKING_UNWRAP,
         unwrap::UNNECESSARY_UNWRAP,
         unwrap_in_result::UNWRAP_IN_RESULT,
+        unwrap_indexing::UNWRAP_INDEXING,
+        unwrapped_index_conversion::UNWRAPPED_INDEX_CONVERSION,
         upper_case_acronyms::UPPER_CASE_ACRONYMS,
         use_self::USE_SELF,
         useless_conversion::USELESS_CONVERSION,
         vec::USELESS_VEC,
+        vec_front_in_loop::VEC_FRONT_IN_LOOP,
         vec_init_then_push::VEC_INIT_THEN_PUSH,
         vec_resize_to_zero::VEC_RESIZE_TO_ZERO,
         verbose_file_reads::VERBOSE_FILE_READS,
@@ -990,65 +1136,67 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     ]);
     // end register lints, do not remove this comment, it’s used in `update_lints`
 
+    let mut passes = PassRegistry::default();
+
     // all the internal lints
     #[cfg(feature = "internal-lints")]
     {
-        store.register_early_pass(|| box utils::internal_lints::ClippyLintsInternal);
-        store.register_early_pass(|| box utils::internal_lints::ProduceIce);
-        store.register_late_pass(|| box utils::inspector::DeepCodeInspector);
-        store.register_late_pass(|| box utils::internal_lints::CollapsibleCalls);
-        store.register_late_pass(|| box utils::internal_lints::CompilerLintFunctions::new());
-        store.register_late_pass(|| box utils::internal_lints::IfChainStyle);
-        store.register_late_pass(|| box utils::internal_lints::InvalidPaths);
-        store.register_late_pass(|| box utils::internal_lints::InterningDefinedSymbol::default());
-        store.register_late_pass(|| box utils::internal_lints::LintWithoutLintPass::default());
-        store.register_late_pass(|| box utils::internal_lints::MatchTypeOnDiagItem);
-        store.register_late_pass(|| box utils::internal_lints::OuterExpnDataPass);
+        passes.register_early_pass(utils::internal_lints::ClippyLintsInternal::get_lints(), || box utils::internal_lints::ClippyLintsInternal);
+        passes.register_early_pass(utils::internal_lints::ProduceIce::get_lints(), || box utils::internal_lints::ProduceIce);
+        passes.register_late_pass(utils::inspector::DeepCodeInspector::get_lints(), || box utils::inspector::DeepCodeInspector);
+        passes.register_late_pass(utils::internal_lints::CollapsibleCalls::get_lints(), || box utils::internal_lints::CollapsibleCalls);
+        passes.register_late_pass(utils::internal_lints::CompilerLintFunctions::get_lints(), || box utils::internal_lints::CompilerLintFunctions::new());
+        passes.register_late_pass(utils::internal_lints::IfChainStyle::get_lints(), || box utils::internal_lints::IfChainStyle);
+        passes.register_late_pass(utils::internal_lints::InvalidPaths::get_lints(), || box utils::internal_lints::InvalidPaths);
+        passes.register_late_pass(utils::internal_lints::InterningDefinedSymbol::get_lints(), || box utils::internal_lints::InterningDefinedSymbol::default());
+        passes.register_whole_crate_late_pass(utils::internal_lints::LintWithoutLintPass::get_lints(), || box utils::internal_lints::LintWithoutLintPass::default());
+        passes.register_late_pass(utils::internal_lints::MatchTypeOnDiagItem::get_lints(), || box utils::internal_lints::MatchTypeOnDiagItem);
+        passes.register_late_pass(utils::internal_lints::OuterExpnDataPass::get_lints(), || box utils::internal_lints::OuterExpnDataPass);
     }
     #[cfg(feature = "metadata-collector-lint")]
     {
         if std::env::var("ENABLE_METADATA_COLLECTION").eq(&Ok("1".to_string())) {
-            store.register_late_pass(|| box utils::internal_lints::metadata_collector::MetadataCollector::default());
+            passes.register_late_pass(utils::internal_lints::metadata_collector::MetadataCollector::get_lints(), || box utils::internal_lints::metadata_collector::MetadataCollector::default());
         }
     }
 
-    store.register_late_pass(|| box utils::author::Author);
-    store.register_late_pass(|| box await_holding_invalid::AwaitHolding);
-    store.register_late_pass(|| box serde_api::SerdeApi);
+    passes.register_late_pass(utils::author::Author::get_lints(), || box utils::author::Author);
+    passes.register_late_pass(await_holding_invalid::AwaitHolding::get_lints(), || box await_holding_invalid::AwaitHolding);
+    passes.register_late_pass(serde_api::SerdeApi::get_lints(), || box serde_api::SerdeApi);
     let vec_box_size_threshold = conf.vec_box_size_threshold;
     let type_complexity_threshold = conf.type_complexity_threshold;
-    store.register_late_pass(move || box types::Types::new(vec_box_size_threshold, type_complexity_threshold));
-    store.register_late_pass(|| box booleans::NonminimalBool);
-    store.register_late_pass(|| box eq_op::EqOp);
-    store.register_late_pass(|| box enum_clike::UnportableVariant);
-    store.register_late_pass(|| box float_literal::FloatLiteral);
+    passes.register_late_pass(types::Types::get_lints(), move || box types::Types::new(vec_box_size_threshold, type_complexity_threshold));
+    passes.register_late_pass(booleans::NonminimalBool::get_lints(), || box booleans::NonminimalBool);
+    passes.register_late_pass(eq_op::EqOp::get_lints(), || box eq_op::EqOp);
+    passes.register_late_pass(enum_clike::UnportableVariant::get_lints(), || box enum_clike::UnportableVariant);
+    passes.register_late_pass(float_literal::FloatLiteral::get_lints(), || box float_literal::FloatLiteral);
     let verbose_bit_mask_threshold = conf.verbose_bit_mask_threshold;
-    store.register_late_pass(move || box bit_mask::BitMask::new(verbose_bit_mask_threshold));
-    store.register_late_pass(|| box ptr::Ptr);
-    store.register_late_pass(|| box ptr_eq::PtrEq);
-    store.register_late_pass(|| box needless_bool::NeedlessBool);
-    store.register_late_pass(|| box needless_bool::BoolComparison);
-    store.register_late_pass(|| box needless_for_each::NeedlessForEach);
-    store.register_late_pass(|| box approx_const::ApproxConstant);
-    store.register_late_pass(|| box misc::MiscLints);
-    store.register_late_pass(|| box eta_reduction::EtaReduction);
-    store.register_late_pass(|| box identity_op::IdentityOp);
-    store.register_late_pass(|| box erasing_op::ErasingOp);
-    store.register_late_pass(|| box mut_mut::MutMut);
-    store.register_late_pass(|| box mut_reference::UnnecessaryMutPassed);
-    store.register_late_pass(|| box len_zero::LenZero);
-    store.register_late_pass(|| box attrs::Attributes);
-    store.register_late_pass(|| box blocks_in_if_conditions::BlocksInIfConditions);
-    store.register_late_pass(|| box collapsible_match::CollapsibleMatch);
-    store.register_late_pass(|| box unicode::Unicode);
-    store.register_late_pass(|| box unit_return_expecting_ord::UnitReturnExpectingOrd);
-    store.register_late_pass(|| box strings::StringAdd);
-    store.register_late_pass(|| box implicit_return::ImplicitReturn);
-    store.register_late_pass(|| box implicit_saturating_sub::ImplicitSaturatingSub);
-    store.register_late_pass(|| box default_numeric_fallback::DefaultNumericFallback);
-    store.register_late_pass(|| box inconsistent_struct_constructor::InconsistentStructConstructor);
-    store.register_late_pass(|| box non_octal_unix_permissions::NonOctalUnixPermissions);
-    store.register_early_pass(|| box unnecessary_self_imports::UnnecessarySelfImports);
+    passes.register_late_pass(bit_mask::BitMask::get_lints(), move || box bit_mask::BitMask::new(verbose_bit_mask_threshold));
+    passes.register_late_pass(ptr::Ptr::get_lints(), || box ptr::Ptr);
+    passes.register_late_pass(ptr_eq::PtrEq::get_lints(), || box ptr_eq::PtrEq);
+    passes.register_late_pass(needless_bool::NeedlessBool::get_lints(), || box needless_bool::NeedlessBool);
+    passes.register_late_pass(needless_bool::BoolComparison::get_lints(), || box needless_bool::BoolComparison);
+    passes.register_late_pass(needless_for_each::NeedlessForEach::get_lints(), || box needless_for_each::NeedlessForEach);
+    passes.register_late_pass(approx_const::ApproxConstant::get_lints(), || box approx_const::ApproxConstant);
+    passes.register_late_pass(misc::MiscLints::get_lints(), || box misc::MiscLints);
+    passes.register_late_pass(eta_reduction::EtaReduction::get_lints(), || box eta_reduction::EtaReduction);
+    passes.register_late_pass(identity_op::IdentityOp::get_lints(), || box identity_op::IdentityOp);
+    passes.register_late_pass(erasing_op::ErasingOp::get_lints(), || box erasing_op::ErasingOp);
+    passes.register_late_pass(mut_mut::MutMut::get_lints(), || box mut_mut::MutMut);
+    passes.register_late_pass(mut_reference::UnnecessaryMutPassed::get_lints(), || box mut_reference::UnnecessaryMutPassed);
+    passes.register_late_pass(len_zero::LenZero::get_lints(), || box len_zero::LenZero);
+    passes.register_late_pass(attrs::Attributes::get_lints(), || box attrs::Attributes);
+    passes.register_late_pass(blocks_in_if_conditions::BlocksInIfConditions::get_lints(), || box blocks_in_if_conditions::BlocksInIfConditions);
+    passes.register_late_pass(collapsible_match::CollapsibleMatch::get_lints(), || box collapsible_match::CollapsibleMatch);
+    passes.register_late_pass(unicode::Unicode::get_lints(), || box unicode::Unicode);
+    passes.register_late_pass(unit_return_expecting_ord::UnitReturnExpectingOrd::get_lints(), || box unit_return_expecting_ord::UnitReturnExpectingOrd);
+    passes.register_late_pass(strings::StringAdd::get_lints(), || box strings::StringAdd);
+    passes.register_late_pass(implicit_return::ImplicitReturn::get_lints(), || box implicit_return::ImplicitReturn);
+    passes.register_late_pass(implicit_saturating_sub::ImplicitSaturatingSub::get_lints(), || box implicit_saturating_sub::ImplicitSaturatingSub);
+    passes.register_late_pass(default_numeric_fallback::DefaultNumericFallback::get_lints(), || box default_numeric_fallback::DefaultNumericFallback);
+    passes.register_late_pass(inconsistent_struct_constructor::InconsistentStructConstructor::get_lints(), || box inconsistent_struct_constructor::InconsistentStructConstructor);
+    passes.register_late_pass(non_octal_unix_permissions::NonOctalUnixPermissions::get_lints(), || box non_octal_unix_permissions::NonOctalUnixPermissions);
+    passes.register_early_pass(unnecessary_self_imports::UnnecessarySelfImports::get_lints(), || box unnecessary_self_imports::UnnecessarySelfImports);
 
     let msrv = conf.msrv.as_ref().and_then(|s| {
         parse_msrv(s, None, None).or_else(|| {
@@ -1057,223 +1205,267 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         })
     });
 
-    store.register_late_pass(move || box methods::Methods::new(msrv));
-    store.register_late_pass(move || box matches::Matches::new(msrv));
-    store.register_early_pass(move || box manual_non_exhaustive::ManualNonExhaustive::new(msrv));
-    store.register_late_pass(move || box manual_strip::ManualStrip::new(msrv));
-    store.register_early_pass(move || box redundant_static_lifetimes::RedundantStaticLifetimes::new(msrv));
-    store.register_early_pass(move || box redundant_field_names::RedundantFieldNames::new(msrv));
-    store.register_late_pass(move || box checked_conversions::CheckedConversions::new(msrv));
-    store.register_late_pass(move || box mem_replace::MemReplace::new(msrv));
-    store.register_late_pass(move || box ranges::Ranges::new(msrv));
-    store.register_late_pass(move || box from_over_into::FromOverInto::new(msrv));
-    store.register_late_pass(move || box use_self::UseSelf::new(msrv));
-    store.register_late_pass(move || box missing_const_for_fn::MissingConstForFn::new(msrv));
-    store.register_late_pass(move || box needless_question_mark::NeedlessQuestionMark);
-    store.register_late_pass(move || box casts::Casts::new(msrv));
-    store.register_early_pass(move || box unnested_or_patterns::UnnestedOrPatterns::new(msrv));
+    passes.register_late_pass(methods::Methods::get_lints(), move || box methods::Methods::new(msrv));
+    passes.register_late_pass(matches::Matches::get_lints(), move || box matches::Matches::new(msrv));
+    passes.register_early_pass(manual_non_exhaustive::ManualNonExhaustive::get_lints(), move || box manual_non_exhaustive::ManualNonExhaustive::new(msrv));
+    passes.register_late_pass(manual_strip::ManualStrip::get_lints(), move || box manual_strip::ManualStrip::new(msrv));
+    passes.register_early_pass(redundant_static_lifetimes::RedundantStaticLifetimes::get_lints(), move || box redundant_static_lifetimes::RedundantStaticLifetimes::new(msrv));
+    passes.register_early_pass(redundant_field_names::RedundantFieldNames::get_lints(), move || box redundant_field_names::RedundantFieldNames::new(msrv));
+    passes.register_late_pass(checked_conversions::CheckedConversions::get_lints(), move || box checked_conversions::CheckedConversions::new(msrv));
+    passes.register_late_pass(mem_replace::MemReplace::get_lints(), move || box mem_replace::MemReplace::new(msrv));
+    passes.register_late_pass(ranges::Ranges::get_lints(), move || box ranges::Ranges::new(msrv));
+    passes.register_late_pass(from_over_into::FromOverInto::get_lints(), move || box from_over_into::FromOverInto::new(msrv));
+    passes.register_late_pass(use_self::UseSelf::get_lints(), move || box use_self::UseSelf::new(msrv));
+    passes.register_late_pass(missing_const_for_fn::MissingConstForFn::get_lints(), move || box missing_const_for_fn::MissingConstForFn::new(msrv));
+    passes.register_late_pass(needless_question_mark::NeedlessQuestionMark::get_lints(), move || box needless_question_mark::NeedlessQuestionMark);
+    passes.register_late_pass(casts::Casts::get_lints(), move || box casts::Casts::new(msrv));
+    passes.register_early_pass(unnested_or_patterns::UnnestedOrPatterns::get_lints(), move || box unnested_or_patterns::UnnestedOrPatterns::new(msrv));
 
-    store.register_late_pass(|| box size_of_in_element_count::SizeOfInElementCount);
-    store.register_late_pass(|| box map_clone::MapClone);
-    store.register_late_pass(|| box map_err_ignore::MapErrIgnore);
-    store.register_late_pass(|| box shadow::Shadow);
-    store.register_late_pass(|| box unit_types::UnitTypes);
-    store.register_late_pass(|| box loops::Loops);
-    store.register_late_pass(|| box main_recursion::MainRecursion::default());
-    store.register_late_pass(|| box lifetimes::Lifetimes);
-    store.register_late_pass(|| box entry::HashMapPass);
-    store.register_late_pass(|| box minmax::MinMaxPass);
-    store.register_late_pass(|| box open_options::OpenOptions);
-    store.register_late_pass(|| box zero_div_zero::ZeroDiv);
-    store.register_late_pass(|| box mutex_atomic::Mutex);
-    store.register_late_pass(|| box needless_update::NeedlessUpdate);
-    store.register_late_pass(|| box needless_borrow::NeedlessBorrow::default());
-    store.register_late_pass(|| box needless_borrowed_ref::NeedlessBorrowedRef);
-    store.register_late_pass(|| box no_effect::NoEffect);
-    store.register_late_pass(|| box temporary_assignment::TemporaryAssignment);
-    store.register_late_pass(|| box transmute::Transmute);
+    passes.register_late_pass(size_of_in_element_count::SizeOfInElementCount::get_lints(), || box size_of_in_element_count::SizeOfInElementCount);
+    passes.register_late_pass(map_clone::MapClone::get_lints(), || box map_clone::MapClone);
+    passes.register_late_pass(map_err_ignore::MapErrIgnore::get_lints(), || box map_err_ignore::MapErrIgnore);
+    passes.register_late_pass(shadow::Shadow::get_lints(), || box shadow::Shadow);
+    passes.register_late_pass(unit_types::UnitTypes::get_lints(), || box unit_types::UnitTypes);
+    passes.register_late_pass(loops::Loops::get_lints(), || box loops::Loops);
+    passes.register_late_pass(main_recursion::MainRecursion::get_lints(), || box main_recursion::MainRecursion::default());
+    passes.register_late_pass(lifetimes::Lifetimes::get_lints(), || box lifetimes::Lifetimes);
+    passes.register_late_pass(entry::HashMapPass::get_lints(), || box entry::HashMapPass);
+    passes.register_late_pass(minmax::MinMaxPass::get_lints(), || box minmax::MinMaxPass);
+    passes.register_late_pass(open_options::OpenOptions::get_lints(), || box open_options::OpenOptions);
+    passes.register_late_pass(zero_div_zero::ZeroDiv::get_lints(), || box zero_div_zero::ZeroDiv);
+    passes.register_late_pass(mutex_atomic::Mutex::get_lints(), || box mutex_atomic::Mutex);
+    passes.register_late_pass(needless_update::NeedlessUpdate::get_lints(), || box needless_update::NeedlessUpdate);
+    passes.register_late_pass(needless_borrow::NeedlessBorrow::get_lints(), || box needless_borrow::NeedlessBorrow::default());
+    passes.register_late_pass(needless_borrowed_ref::NeedlessBorrowedRef::get_lints(), || box needless_borrowed_ref::NeedlessBorrowedRef);
+    passes.register_late_pass(no_effect::NoEffect::get_lints(), || box no_effect::NoEffect);
+    passes.register_late_pass(temporary_assignment::TemporaryAssignment::get_lints(), || box temporary_assignment::TemporaryAssignment);
+    passes.register_late_pass(transmute::Transmute::get_lints(), || box transmute::Transmute);
     let cognitive_complexity_threshold = conf.cognitive_complexity_threshold;
-    store.register_late_pass(move || box cognitive_complexity::CognitiveComplexity::new(cognitive_complexity_threshold));
+    passes.register_late_pass(cognitive_complexity::CognitiveComplexity::get_lints(), move || box cognitive_complexity::CognitiveComplexity::new(cognitive_complexity_threshold));
     let too_large_for_stack = conf.too_large_for_stack;
-    store.register_late_pass(move || box escape::BoxedLocal{too_large_for_stack});
-    store.register_late_pass(move || box vec::UselessVec{too_large_for_stack});
-    store.register_late_pass(|| box panic_unimplemented::PanicUnimplemented);
-    store.register_late_pass(|| box strings::StringLitAsBytes);
-    store.register_late_pass(|| box derive::Derive);
-    store.register_late_pass(|| box get_last_with_len::GetLastWithLen);
-    store.register_late_pass(|| box drop_forget_ref::DropForgetRef);
-    store.register_late_pass(|| box empty_enum::EmptyEnum);
-    store.register_late_pass(|| box absurd_extreme_comparisons::AbsurdExtremeComparisons);
-    store.register_late_pass(|| box invalid_upcast_comparisons::InvalidUpcastComparisons);
-    store.register_late_pass(|| box regex::Regex::default());
-    store.register_late_pass(|| box copies::CopyAndPaste);
-    store.register_late_pass(|| box copy_iterator::CopyIterator);
-    store.register_late_pass(|| box format::UselessFormat);
-    store.register_late_pass(|| box swap::Swap);
-    store.register_late_pass(|| box overflow_check_conditional::OverflowCheckConditional);
-    store.register_late_pass(|| box new_without_default::NewWithoutDefault::default());
+    passes.register_late_pass(escape::BoxedLocal::get_lints(), move || box escape::BoxedLocal{too_large_for_stack});
+    passes.register_late_pass(vec::UselessVec::get_lints(), move || box vec::UselessVec{too_large_for_stack});
+    passes.register_late_pass(panic_unimplemented::PanicUnimplemented::get_lints(), || box panic_unimplemented::PanicUnimplemented);
+    passes.register_late_pass(strings::StringLitAsBytes::get_lints(), || box strings::StringLitAsBytes);
+    passes.register_late_pass(derive::Derive::get_lints(), || box derive::Derive);
+    passes.register_late_pass(get_last_with_len::GetLastWithLen::get_lints(), || box get_last_with_len::GetLastWithLen);
+    passes.register_late_pass(drop_forget_ref::DropForgetRef::get_lints(), || box drop_forget_ref::DropForgetRef);
+    passes.register_late_pass(empty_enum::EmptyEnum::get_lints(), || box empty_enum::EmptyEnum);
+    passes.register_late_pass(absurd_extreme_comparisons::AbsurdExtremeComparisons::get_lints(), || box absurd_extreme_comparisons::AbsurdExtremeComparisons);
+    passes.register_late_pass(invalid_upcast_comparisons::InvalidUpcastComparisons::get_lints(), || box invalid_upcast_comparisons::InvalidUpcastComparisons);
+    passes.register_late_pass(regex::Regex::get_lints(), || box regex::Regex::default());
+    passes.register_late_pass(copies::CopyAndPaste::get_lints(), || box copies::CopyAndPaste);
+    passes.register_late_pass(copy_iterator::CopyIterator::get_lints(), || box copy_iterator::CopyIterator);
+    passes.register_late_pass(format::UselessFormat::get_lints(), || box format::UselessFormat);
+    passes.register_late_pass(swap::Swap::get_lints(), || box swap::Swap);
+    passes.register_late_pass(overflow_check_conditional::OverflowCheckConditional::get_lints(), || box overflow_check_conditional::OverflowCheckConditional);
+    passes.register_late_pass(new_without_default::NewWithoutDefault::get_lints(), || box new_without_default::NewWithoutDefault::default());
     let blacklisted_names = conf.blacklisted_names.iter().cloned().collect::<FxHashSet<_>>();
-    store.register_late_pass(move || box blacklisted_name::BlacklistedName::new(blacklisted_names.clone()));
+    passes.register_late_pass(blacklisted_name::BlacklistedName::get_lints(), move || box blacklisted_name::BlacklistedName::new(blacklisted_names.clone()));
     let too_many_arguments_threshold = conf.too_many_arguments_threshold;
     let too_many_lines_threshold = conf.too_many_lines_threshold;
-    store.register_late_pass(move || box functions::Functions::new(too_many_arguments_threshold, too_many_lines_threshold));
+    passes.register_late_pass(functions::Functions::get_lints(), move || box functions::Functions::new(too_many_arguments_threshold, too_many_lines_threshold));
     let doc_valid_idents = conf.doc_valid_idents.iter().cloned().collect::<FxHashSet<_>>();
-    store.register_late_pass(move || box doc::DocMarkdown::new(doc_valid_idents.clone()));
-    store.register_late_pass(|| box neg_multiply::NegMultiply);
-    store.register_late_pass(|| box mem_discriminant::MemDiscriminant);
-    store.register_late_pass(|| box mem_forget::MemForget);
-    store.register_late_pass(|| box arithmetic::Arithmetic::default());
-    store.register_late_pass(|| box assign_ops::AssignOps);
-    store.register_late_pass(|| box let_if_seq::LetIfSeq);
-    store.register_late_pass(|| box eval_order_dependence::EvalOrderDependence);
-    store.register_late_pass(|| box missing_doc::MissingDoc::new());
-    store.register_late_pass(|| box missing_inline::MissingInline);
-    store.register_late_pass(move || box exhaustive_items::ExhaustiveItems);
-    store.register_late_pass(|| box if_let_some_result::OkIfLet);
-    store.register_late_pass(|| box partialeq_ne_impl::PartialEqNeImpl);
-    store.register_late_pass(|| box unused_io_amount::UnusedIoAmount);
+    passes.register_late_pass(doc::DocMarkdown::get_lints(), move || box doc::DocMarkdown::new(doc_valid_idents.clone()));
+    passes.register_late_pass(neg_multiply::NegMultiply::get_lints(), || box neg_multiply::NegMultiply);
+    passes.register_late_pass(mem_discriminant::MemDiscriminant::get_lints(), || box mem_discriminant::MemDiscriminant);
+    passes.register_late_pass(mem_forget::MemForget::get_lints(), || box mem_forget::MemForget);
+    passes.register_late_pass(arithmetic::Arithmetic::get_lints(), || box arithmetic::Arithmetic::default());
+    passes.register_late_pass(assign_ops::AssignOps::get_lints(), || box assign_ops::AssignOps);
+    passes.register_late_pass(let_if_seq::LetIfSeq::get_lints(), || box let_if_seq::LetIfSeq);
+    passes.register_late_pass(eval_order_dependence::EvalOrderDependence::get_lints(), || box eval_order_dependence::EvalOrderDependence);
+    passes.register_late_pass(missing_doc::MissingDoc::get_lints(), || box missing_doc::MissingDoc::new());
+    passes.register_late_pass(missing_inline::MissingInline::get_lints(), || box missing_inline::MissingInline);
+    passes.register_late_pass(exhaustive_items::ExhaustiveItems::get_lints(), move || box exhaustive_items::ExhaustiveItems);
+    passes.register_late_pass(if_let_some_result::OkIfLet::get_lints(), || box if_let_some_result::OkIfLet);
+    passes.register_late_pass(partialeq_ne_impl::PartialEqNeImpl::get_lints(), || box partialeq_ne_impl::PartialEqNeImpl);
+    passes.register_late_pass(unused_io_amount::UnusedIoAmount::get_lints(), || box unused_io_amount::UnusedIoAmount);
     let enum_variant_size_threshold = conf.enum_variant_size_threshold;
-    store.register_late_pass(move || box large_enum_variant::LargeEnumVariant::new(enum_variant_size_threshold));
-    store.register_late_pass(|| box explicit_write::ExplicitWrite);
-    store.register_late_pass(|| box needless_pass_by_value::NeedlessPassByValue);
+    passes.register_late_pass(large_enum_variant::LargeEnumVariant::get_lints(), move || box large_enum_variant::LargeEnumVariant::new(enum_variant_size_threshold));
+    passes.register_late_pass(explicit_write::ExplicitWrite::get_lints(), || box explicit_write::ExplicitWrite);
+    passes.register_late_pass(needless_pass_by_value::NeedlessPassByValue::get_lints(), || box needless_pass_by_value::NeedlessPassByValue);
     let pass_by_ref_or_value = pass_by_ref_or_value::PassByRefOrValue::new(
         conf.trivial_copy_size_limit,
         conf.pass_by_value_size_limit,
         &sess.target,
     );
-    store.register_late_pass(move || box pass_by_ref_or_value);
-    store.register_late_pass(|| box ref_option_ref::RefOptionRef);
-    store.register_late_pass(|| box try_err::TryErr);
-    store.register_late_pass(|| box bytecount::ByteCount);
-    store.register_late_pass(|| box infinite_iter::InfiniteIter);
-    store.register_late_pass(|| box inline_fn_without_body::InlineFnWithoutBody);
-    store.register_late_pass(|| box useless_conversion::UselessConversion::default());
-    store.register_late_pass(|| box implicit_hasher::ImplicitHasher);
-    store.register_late_pass(|| box fallible_impl_from::FallibleImplFrom);
-    store.register_late_pass(|| box double_comparison::DoubleComparisons);
-    store.register_late_pass(|| box question_mark::QuestionMark);
-    store.register_early_pass(|| box suspicious_operation_groupings::SuspiciousOperationGroupings);
-    store.register_late_pass(|| box suspicious_trait_impl::SuspiciousImpl);
-    store.register_late_pass(|| box map_unit_fn::MapUnit);
-    store.register_late_pass(|| box inherent_impl::MultipleInherentImpl::default());
-    store.register_late_pass(|| box neg_cmp_op_on_partial_ord::NoNegCompOpForPartialOrd);
-    store.register_late_pass(|| box unwrap::Unwrap);
-    store.register_late_pass(|| box duration_subsec::DurationSubsec);
-    store.register_late_pass(|| box indexing_slicing::IndexingSlicing);
-    store.register_late_pass(|| box non_copy_const::NonCopyConst);
-    store.register_late_pass(|| box ptr_offset_with_cast::PtrOffsetWithCast);
-    store.register_late_pass(|| box redundant_clone::RedundantClone);
-    store.register_late_pass(|| box slow_vector_initialization::SlowVectorInit);
-    store.register_late_pass(|| box unnecessary_sort_by::UnnecessarySortBy);
-    store.register_late_pass(|| box unnecessary_wraps::UnnecessaryWraps);
-    store.register_late_pass(|| box assertions_on_constants::AssertionsOnConstants);
-    store.register_late_pass(|| box transmuting_null::TransmutingNull);
-    store.register_late_pass(|| box path_buf_push_overwrite::PathBufPushOverwrite);
-    store.register_late_pass(|| box integer_division::IntegerDivision);
-    store.register_late_pass(|| box inherent_to_string::InherentToString);
+    passes.register_late_pass(pass_by_ref_or_value::PassByRefOrValue::get_lints(), move || box pass_by_ref_or_value);
+    passes.register_late_pass(ref_option_ref::RefOptionRef::get_lints(), || box ref_option_ref::RefOptionRef);
+    passes.register_late_pass(try_err::TryErr::get_lints(), || box try_err::TryErr);
+    passes.register_late_pass(bytecount::ByteCount::get_lints(), || box bytecount::ByteCount);
+    passes.register_late_pass(infinite_iter::InfiniteIter::get_lints(), || box infinite_iter::InfiniteIter);
+    passes.register_late_pass(inline_fn_without_body::InlineFnWithoutBody::get_lints(), || box inline_fn_without_body::InlineFnWithoutBody);
+    passes.register_late_pass(useless_conversion::UselessConversion::get_lints(), || box useless_conversion::UselessConversion::default());
+    passes.register_late_pass(implicit_hasher::ImplicitHasher::get_lints(), || box implicit_hasher::ImplicitHasher);
+    passes.register_late_pass(fallible_impl_from::FallibleImplFrom::get_lints(), || box fallible_impl_from::FallibleImplFrom);
+    passes.register_late_pass(double_comparison::DoubleComparisons::get_lints(), || box double_comparison::DoubleComparisons);
+    passes.register_late_pass(question_mark::QuestionMark::get_lints(), || box question_mark::QuestionMark);
+    passes.register_early_pass(suspicious_operation_groupings::SuspiciousOperationGroupings::get_lints(), || box suspicious_operation_groupings::SuspiciousOperationGroupings);
+    passes.register_late_pass(suspicious_trait_impl::SuspiciousImpl::get_lints(), || box suspicious_trait_impl::SuspiciousImpl);
+    passes.register_late_pass(map_unit_fn::MapUnit::get_lints(), || box map_unit_fn::MapUnit);
+    passes.register_whole_crate_late_pass(inherent_impl::MultipleInherentImpl::get_lints(), || box inherent_impl::MultipleInherentImpl::default());
+    passes.register_late_pass(neg_cmp_op_on_partial_ord::NoNegCompOpForPartialOrd::get_lints(), || box neg_cmp_op_on_partial_ord::NoNegCompOpForPartialOrd);
+    passes.register_late_pass(unwrap::Unwrap::get_lints(), || box unwrap::Unwrap);
+    passes.register_late_pass(duration_subsec::DurationSubsec::get_lints(), || box duration_subsec::DurationSubsec);
+    let allow_indexing_slicing_in_tests = conf.allow_indexing_slicing_in_tests;
+    let indexing_slicing_max_array_size = conf.indexing_slicing_max_array_size;
+    passes.register_late_pass(indexing_slicing::IndexingSlicing::get_lints(), move || {
+        box indexing_slicing::IndexingSlicing::new(allow_indexing_slicing_in_tests, indexing_slicing_max_array_size)
+    });
+    passes.register_late_pass(chained_indexing::ChainedIndexing::get_lints(), || box chained_indexing::ChainedIndexing);
+    passes.register_late_pass(unwrap_indexing::UnwrapIndexing::get_lints(), || box unwrap_indexing::UnwrapIndexing);
+    let reachable_panic_allowed = conf.reachable_panic_allowed.clone();
+    passes.register_whole_crate_late_pass(reachable_panic::ReachablePanic::get_lints(), move || box reachable_panic::ReachablePanic::new(&reachable_panic_allowed));
+    let repeated_indexing_threshold = conf.repeated_indexing_threshold;
+    passes.register_late_pass(repeated_indexing::RepeatedIndexing::get_lints(), move || box repeated_indexing::RepeatedIndexing::new(repeated_indexing_threshold));
+    let unhelpful_expect_messages: Vec<_> = conf
+        .unhelpful_expect_messages
+        .iter()
+        .filter_map(|pattern| {
+            ::regex::Regex::new(pattern)
+                .map_err(|e| {
+                    sess.err(&format!(
+                        "error reading Clippy's configuration file. `{}` is not a valid regular expression: {}",
+                        pattern, e
+                    ));
+                })
+                .ok()
+        })
+        .collect();
+    passes.register_late_pass(unhelpful_expect_message::UnhelpfulExpectMessage::get_lints(), move || box unhelpful_expect_message::UnhelpfulExpectMessage::new(unhelpful_expect_messages.clone()));
+    passes.register_late_pass(panic_in_drop::PanicInDrop::get_lints(), || box panic_in_drop::PanicInDrop);
+    passes.register_late_pass(panic_in_fmt::PanicInFmt::get_lints(), || box panic_in_fmt::PanicInFmt);
+    passes.register_late_pass(split_indexing::SplitIndexing::get_lints(), || box split_indexing::SplitIndexing);
+    passes.register_late_pass(manual_slice_pattern::ManualSlicePattern::get_lints(), || box manual_slice_pattern::ManualSlicePattern);
+    passes.register_late_pass(partially_read_buffer::PartiallyReadBuffer::get_lints(), || box partially_read_buffer::PartiallyReadBuffer);
+    passes.register_late_pass(unwrapped_index_conversion::UnwrappedIndexConversion::get_lints(), || box unwrapped_index_conversion::UnwrappedIndexConversion);
+    passes.register_late_pass(panicking_index_impl::PanickingIndexImpl::get_lints(), || box panicking_index_impl::PanickingIndexImpl);
+    passes.register_late_pass(ffi_unchecked_index::FfiUncheckedIndex::get_lints(), || box ffi_unchecked_index::FfiUncheckedIndex);
+    passes.register_late_pass(unguarded_raw_pointer_index::UnguardedRawPointerIndex::get_lints(), || box unguarded_raw_pointer_index::UnguardedRawPointerIndex);
+    passes.register_late_pass(indexing_slicing::OutOfBoundsIndexing::get_lints(), || box utils::mir_lint_pass::MirLints::new(vec![box indexing_slicing::OutOfBoundsIndexing]));
+    passes.register_late_pass(non_copy_const::NonCopyConst::get_lints(), || box non_copy_const::NonCopyConst);
+    passes.register_late_pass(ptr_offset_with_cast::PtrOffsetWithCast::get_lints(), || box ptr_offset_with_cast::PtrOffsetWithCast);
+    passes.register_late_pass(redundant_clone::RedundantClone::get_lints(), || box redundant_clone::RedundantClone);
+    passes.register_late_pass(slow_vector_initialization::SlowVectorInit::get_lints(), || box slow_vector_initialization::SlowVectorInit);
+    passes.register_late_pass(unnecessary_sort_by::UnnecessarySortBy::get_lints(), || box unnecessary_sort_by::UnnecessarySortBy);
+    passes.register_late_pass(unnecessary_wraps::UnnecessaryWraps::get_lints(), || box unnecessary_wraps::UnnecessaryWraps);
+    passes.register_late_pass(assertions_on_constants::AssertionsOnConstants::get_lints(), || box assertions_on_constants::AssertionsOnConstants);
+    passes.register_late_pass(transmuting_null::TransmutingNull::get_lints(), || box transmuting_null::TransmutingNull);
+    passes.register_late_pass(path_buf_push_overwrite::PathBufPushOverwrite::get_lints(), || box path_buf_push_overwrite::PathBufPushOverwrite);
+    passes.register_late_pass(integer_division::IntegerDivision::get_lints(), || box integer_division::IntegerDivision);
+    passes.register_late_pass(unchecked_division::UncheckedDivision::get_lints(), || box unchecked_division::UncheckedDivision);
+    passes.register_late_pass(inherent_to_string::InherentToString::get_lints(), || box inherent_to_string::InherentToString);
     let max_trait_bounds = conf.max_trait_bounds;
-    store.register_late_pass(move || box trait_bounds::TraitBounds::new(max_trait_bounds));
-    store.register_late_pass(|| box comparison_chain::ComparisonChain);
-    store.register_late_pass(|| box mut_key::MutableKeyType);
-    store.register_late_pass(|| box modulo_arithmetic::ModuloArithmetic);
-    store.register_early_pass(|| box reference::DerefAddrOf);
-    store.register_early_pass(|| box reference::RefInDeref);
-    store.register_early_pass(|| box double_parens::DoubleParens);
-    store.register_late_pass(|| box to_string_in_display::ToStringInDisplay::new());
-    store.register_early_pass(|| box unsafe_removed_from_name::UnsafeNameRemoval);
-    store.register_early_pass(|| box if_not_else::IfNotElse);
-    store.register_early_pass(|| box else_if_without_else::ElseIfWithoutElse);
-    store.register_early_pass(|| box int_plus_one::IntPlusOne);
-    store.register_early_pass(|| box formatting::Formatting);
-    store.register_early_pass(|| box misc_early::MiscEarlyLints);
-    store.register_early_pass(|| box redundant_closure_call::RedundantClosureCall);
-    store.register_late_pass(|| box redundant_closure_call::RedundantClosureCall);
-    store.register_early_pass(|| box unused_unit::UnusedUnit);
-    store.register_late_pass(|| box returns::Return);
-    store.register_early_pass(|| box collapsible_if::CollapsibleIf);
-    store.register_early_pass(|| box items_after_statements::ItemsAfterStatements);
-    store.register_early_pass(|| box precedence::Precedence);
-    store.register_early_pass(|| box needless_continue::NeedlessContinue);
-    store.register_early_pass(|| box redundant_else::RedundantElse);
-    store.register_late_pass(|| box create_dir::CreateDir);
-    store.register_early_pass(|| box needless_arbitrary_self_type::NeedlessArbitrarySelfType);
+    passes.register_late_pass(trait_bounds::TraitBounds::get_lints(), move || box trait_bounds::TraitBounds::new(max_trait_bounds));
+    passes.register_late_pass(comparison_chain::ComparisonChain::get_lints(), || box comparison_chain::ComparisonChain);
+    passes.register_late_pass(mut_key::MutableKeyType::get_lints(), || box mut_key::MutableKeyType);
+    passes.register_late_pass(modulo_arithmetic::ModuloArithmetic::get_lints(), || box modulo_arithmetic::ModuloArithmetic);
+    passes.register_early_pass(reference::DerefAddrOf::get_lints(), || box reference::DerefAddrOf);
+    passes.register_early_pass(reference::RefInDeref::get_lints(), || box reference::RefInDeref);
+    passes.register_early_pass(double_parens::DoubleParens::get_lints(), || box double_parens::DoubleParens);
+    passes.register_late_pass(to_string_in_display::ToStringInDisplay::get_lints(), || box to_string_in_display::ToStringInDisplay::new());
+    passes.register_early_pass(unsafe_removed_from_name::UnsafeNameRemoval::get_lints(), || box unsafe_removed_from_name::UnsafeNameRemoval);
+    passes.register_early_pass(if_not_else::IfNotElse::get_lints(), || box if_not_else::IfNotElse);
+    passes.register_early_pass(else_if_without_else::ElseIfWithoutElse::get_lints(), || box else_if_without_else::ElseIfWithoutElse);
+    passes.register_early_pass(int_plus_one::IntPlusOne::get_lints(), || box int_plus_one::IntPlusOne);
+    passes.register_early_pass(formatting::Formatting::get_lints(), || box formatting::Formatting);
+    passes.register_early_pass(misc_early::MiscEarlyLints::get_lints(), || box misc_early::MiscEarlyLints);
+    passes.register_early_pass(redundant_closure_call::RedundantClosureCall::get_lints(), || box redundant_closure_call::RedundantClosureCall);
+    passes.register_late_pass(redundant_closure_call::RedundantClosureCall::get_lints(), || box redundant_closure_call::RedundantClosureCall);
+    passes.register_early_pass(unused_unit::UnusedUnit::get_lints(), || box unused_unit::UnusedUnit);
+    passes.register_late_pass(returns::Return::get_lints(), || box returns::Return);
+    passes.register_early_pass(collapsible_if::CollapsibleIf::get_lints(), || box collapsible_if::CollapsibleIf);
+    passes.register_early_pass(items_after_statements::ItemsAfterStatements::get_lints(), || box items_after_statements::ItemsAfterStatements);
+    passes.register_early_pass(precedence::Precedence::get_lints(), || box precedence::Precedence);
+    passes.register_early_pass(needless_continue::NeedlessContinue::get_lints(), || box needless_continue::NeedlessContinue);
+    passes.register_early_pass(redundant_else::RedundantElse::get_lints(), || box redundant_else::RedundantElse);
+    passes.register_late_pass(create_dir::CreateDir::get_lints(), || box create_dir::CreateDir);
+    passes.register_early_pass(needless_arbitrary_self_type::NeedlessArbitrarySelfType::get_lints(), || box needless_arbitrary_self_type::NeedlessArbitrarySelfType);
     let cargo_ignore_publish = conf.cargo_ignore_publish;
-    store.register_late_pass(move || box cargo_common_metadata::CargoCommonMetadata::new(cargo_ignore_publish));
-    store.register_late_pass(|| box multiple_crate_versions::MultipleCrateVersions);
-    store.register_late_pass(|| box wildcard_dependencies::WildcardDependencies);
+    passes.register_late_pass(cargo_common_metadata::CargoCommonMetadata::get_lints(), move || box cargo_common_metadata::CargoCommonMetadata::new(cargo_ignore_publish));
+    passes.register_late_pass(multiple_crate_versions::MultipleCrateVersions::get_lints(), || box multiple_crate_versions::MultipleCrateVersions);
+    passes.register_late_pass(wildcard_dependencies::WildcardDependencies::get_lints(), || box wildcard_dependencies::WildcardDependencies);
     let literal_representation_lint_fraction_readability = conf.unreadable_literal_lint_fractions;
-    store.register_early_pass(move || box literal_representation::LiteralDigitGrouping::new(literal_representation_lint_fraction_readability));
+    passes.register_early_pass(literal_representation::LiteralDigitGrouping::get_lints(), move || box literal_representation::LiteralDigitGrouping::new(literal_representation_lint_fraction_readability));
     let literal_representation_threshold = conf.literal_representation_threshold;
-    store.register_early_pass(move || box literal_representation::DecimalLiteralRepresentation::new(literal_representation_threshold));
+    passes.register_early_pass(literal_representation::DecimalLiteralRepresentation::get_lints(), move || box literal_representation::DecimalLiteralRepresentation::new(literal_representation_threshold));
     let enum_variant_name_threshold = conf.enum_variant_name_threshold;
-    store.register_early_pass(move || box enum_variants::EnumVariantNames::new(enum_variant_name_threshold));
-    store.register_early_pass(|| box tabs_in_doc_comments::TabsInDocComments);
+    passes.register_early_pass(enum_variants::EnumVariantNames::get_lints(), move || box enum_variants::EnumVariantNames::new(enum_variant_name_threshold));
+    passes.register_early_pass(tabs_in_doc_comments::TabsInDocComments::get_lints(), || box tabs_in_doc_comments::TabsInDocComments);
     let upper_case_acronyms_aggressive = conf.upper_case_acronyms_aggressive;
-    store.register_early_pass(move || box upper_case_acronyms::UpperCaseAcronyms::new(upper_case_acronyms_aggressive));
-    store.register_late_pass(|| box default::Default::default());
-    store.register_late_pass(|| box unused_self::UnusedSelf);
-    store.register_late_pass(|| box mutable_debug_assertion::DebugAssertWithMutCall);
-    store.register_late_pass(|| box exit::Exit);
-    store.register_late_pass(|| box to_digit_is_some::ToDigitIsSome);
+    passes.register_early_pass(upper_case_acronyms::UpperCaseAcronyms::get_lints(), move || box upper_case_acronyms::UpperCaseAcronyms::new(upper_case_acronyms_aggressive));
+    passes.register_late_pass(default::Default::get_lints(), || box default::Default::default());
+    passes.register_late_pass(unused_self::UnusedSelf::get_lints(), || box unused_self::UnusedSelf);
+    passes.register_late_pass(mutable_debug_assertion::DebugAssertWithMutCall::get_lints(), || box mutable_debug_assertion::DebugAssertWithMutCall);
+    passes.register_late_pass(exit::Exit::get_lints(), || box exit::Exit);
+    passes.register_late_pass(to_digit_is_some::ToDigitIsSome::get_lints(), || box to_digit_is_some::ToDigitIsSome);
     let array_size_threshold = conf.array_size_threshold;
-    store.register_late_pass(move || box large_stack_arrays::LargeStackArrays::new(array_size_threshold));
-    store.register_late_pass(move || box large_const_arrays::LargeConstArrays::new(array_size_threshold));
-    store.register_late_pass(|| box floating_point_arithmetic::FloatingPointArithmetic);
-    store.register_early_pass(|| box as_conversions::AsConversions);
-    store.register_late_pass(|| box let_underscore::LetUnderscore);
-    store.register_late_pass(|| box atomic_ordering::AtomicOrdering);
-    store.register_early_pass(|| box single_component_path_imports::SingleComponentPathImports);
+    passes.register_late_pass(large_stack_arrays::LargeStackArrays::get_lints(), move || box large_stack_arrays::LargeStackArrays::new(array_size_threshold));
+    passes.register_late_pass(large_const_arrays::LargeConstArrays::get_lints(), move || box large_const_arrays::LargeConstArrays::new(array_size_threshold));
+    passes.register_late_pass(floating_point_arithmetic::FloatingPointArithmetic::get_lints(), || box floating_point_arithmetic::FloatingPointArithmetic);
+    passes.register_early_pass(as_conversions::AsConversions::get_lints(), || box as_conversions::AsConversions);
+    passes.register_late_pass(let_underscore::LetUnderscore::get_lints(), || box let_underscore::LetUnderscore);
+    passes.register_late_pass(atomic_ordering::AtomicOrdering::get_lints(), || box atomic_ordering::AtomicOrdering);
+    passes.register_early_pass(single_component_path_imports::SingleComponentPathImports::get_lints(), || box single_component_path_imports::SingleComponentPathImports);
     let max_fn_params_bools = conf.max_fn_params_bools;
     let max_struct_bools = conf.max_struct_bools;
-    store.register_early_pass(move || box excessive_bools::ExcessiveBools::new(max_struct_bools, max_fn_params_bools));
-    store.register_early_pass(|| box option_env_unwrap::OptionEnvUnwrap);
+    passes.register_early_pass(excessive_bools::ExcessiveBools::get_lints(), move || box excessive_bools::ExcessiveBools::new(max_struct_bools, max_fn_params_bools));
+    passes.register_early_pass(option_env_unwrap::OptionEnvUnwrap::get_lints(), || box option_env_unwrap::OptionEnvUnwrap);
     let warn_on_all_wildcard_imports = conf.warn_on_all_wildcard_imports;
-    store.register_late_pass(move || box wildcard_imports::WildcardImports::new(warn_on_all_wildcard_imports));
-    store.register_late_pass(|| box verbose_file_reads::VerboseFileReads);
-    store.register_late_pass(|| box redundant_pub_crate::RedundantPubCrate::default());
-    store.register_late_pass(|| box unnamed_address::UnnamedAddress);
-    store.register_late_pass(|| box dereference::Dereferencing::default());
-    store.register_late_pass(|| box option_if_let_else::OptionIfLetElse);
-    store.register_late_pass(|| box future_not_send::FutureNotSend);
-    store.register_late_pass(|| box if_let_mutex::IfLetMutex);
-    store.register_late_pass(|| box mut_mutex_lock::MutMutexLock);
-    store.register_late_pass(|| box match_on_vec_items::MatchOnVecItems);
-    store.register_late_pass(|| box manual_async_fn::ManualAsyncFn);
-    store.register_late_pass(|| box vec_resize_to_zero::VecResizeToZero);
-    store.register_late_pass(|| box panic_in_result_fn::PanicInResultFn);
+    passes.register_late_pass(wildcard_imports::WildcardImports::get_lints(), move || box wildcard_imports::WildcardImports::new(warn_on_all_wildcard_imports));
+    passes.register_late_pass(verbose_file_reads::VerboseFileReads::get_lints(), || box verbose_file_reads::VerboseFileReads);
+    passes.register_late_pass(redundant_pub_crate::RedundantPubCrate::get_lints(), || box redundant_pub_crate::RedundantPubCrate::default());
+    passes.register_late_pass(unnamed_address::UnnamedAddress::get_lints(), || box unnamed_address::UnnamedAddress);
+    passes.register_late_pass(dereference::Dereferencing::get_lints(), || box dereference::Dereferencing::default());
+    passes.register_late_pass(option_if_let_else::OptionIfLetElse::get_lints(), || box option_if_let_else::OptionIfLetElse);
+    passes.register_late_pass(future_not_send::FutureNotSend::get_lints(), || box future_not_send::FutureNotSend);
+    passes.register_late_pass(if_let_mutex::IfLetMutex::get_lints(), || box if_let_mutex::IfLetMutex);
+    passes.register_late_pass(mut_mutex_lock::MutMutexLock::get_lints(), || box mut_mutex_lock::MutMutexLock);
+    passes.register_late_pass(match_on_vec_items::MatchOnVecItems::get_lints(), || box match_on_vec_items::MatchOnVecItems);
+    passes.register_late_pass(manual_async_fn::ManualAsyncFn::get_lints(), || box manual_async_fn::ManualAsyncFn);
+    passes.register_late_pass(vec_resize_to_zero::VecResizeToZero::get_lints(), || box vec_resize_to_zero::VecResizeToZero);
+    passes.register_late_pass(panic_in_result_fn::PanicInResultFn::get_lints(), || box panic_in_result_fn::PanicInResultFn);
     let single_char_binding_names_threshold = conf.single_char_binding_names_threshold;
-    store.register_early_pass(move || box non_expressive_names::NonExpressiveNames {
+    passes.register_early_pass(non_expressive_names::NonExpressiveNames::get_lints(), move || box non_expressive_names::NonExpressiveNames {
         single_char_binding_names_threshold,
     });
-    store.register_late_pass(|| box macro_use::MacroUseImports::default());
-    store.register_late_pass(|| box map_identity::MapIdentity);
-    store.register_late_pass(|| box pattern_type_mismatch::PatternTypeMismatch);
-    store.register_late_pass(|| box stable_sort_primitive::StableSortPrimitive);
-    store.register_late_pass(|| box repeat_once::RepeatOnce);
-    store.register_late_pass(|| box unwrap_in_result::UnwrapInResult);
-    store.register_late_pass(|| box self_assignment::SelfAssignment);
-    store.register_late_pass(|| box manual_unwrap_or::ManualUnwrapOr);
-    store.register_late_pass(|| box manual_ok_or::ManualOkOr);
-    store.register_late_pass(|| box float_equality_without_abs::FloatEqualityWithoutAbs);
-    store.register_late_pass(|| box semicolon_if_nothing_returned::SemicolonIfNothingReturned);
-    store.register_late_pass(|| box async_yields_async::AsyncYieldsAsync);
+    passes.register_whole_crate_late_pass(macro_use::MacroUseImports::get_lints(), || box macro_use::MacroUseImports::default());
+    passes.register_late_pass(map_identity::MapIdentity::get_lints(), || box map_identity::MapIdentity);
+    passes.register_late_pass(pattern_type_mismatch::PatternTypeMismatch::get_lints(), || box pattern_type_mismatch::PatternTypeMismatch);
+    passes.register_late_pass(stable_sort_primitive::StableSortPrimitive::get_lints(), || box stable_sort_primitive::StableSortPrimitive);
+    passes.register_late_pass(repeat_once::RepeatOnce::get_lints(), || box repeat_once::RepeatOnce);
+    passes.register_late_pass(unwrap_in_result::UnwrapInResult::get_lints(), || box unwrap_in_result::UnwrapInResult);
+    passes.register_late_pass(self_assignment::SelfAssignment::get_lints(), || box self_assignment::SelfAssignment);
+    passes.register_late_pass(manual_unwrap_or::ManualUnwrapOr::get_lints(), || box manual_unwrap_or::ManualUnwrapOr);
+    passes.register_late_pass(manual_ok_or::ManualOkOr::get_lints(), || box manual_ok_or::ManualOkOr);
+    passes.register_late_pass(float_equality_without_abs::FloatEqualityWithoutAbs::get_lints(), || box float_equality_without_abs::FloatEqualityWithoutAbs);
+    passes.register_late_pass(semicolon_if_nothing_returned::SemicolonIfNothingReturned::get_lints(), || box semicolon_if_nothing_returned::SemicolonIfNothingReturned);
+    passes.register_late_pass(async_yields_async::AsyncYieldsAsync::get_lints(), || box async_yields_async::AsyncYieldsAsync);
     let disallowed_methods = conf.disallowed_methods.iter().cloned().collect::<FxHashSet<_>>();
-    store.register_late_pass(move || box disallowed_method::DisallowedMethod::new(&disallowed_methods));
-    store.register_early_pass(|| box asm_syntax::InlineAsmX86AttSyntax);
-    store.register_early_pass(|| box asm_syntax::InlineAsmX86IntelSyntax);
-    store.register_late_pass(|| box undropped_manually_drops::UndroppedManuallyDrops);
-    store.register_late_pass(|| box strings::StrToString);
-    store.register_late_pass(|| box strings::StringToString);
-    store.register_late_pass(|| box zero_sized_map_values::ZeroSizedMapValues);
-    store.register_late_pass(|| box vec_init_then_push::VecInitThenPush::default());
-    store.register_late_pass(|| box case_sensitive_file_extension_comparisons::CaseSensitiveFileExtensionComparisons);
-    store.register_late_pass(|| box redundant_slicing::RedundantSlicing);
-    store.register_late_pass(|| box from_str_radix_10::FromStrRadix10);
-    store.register_late_pass(|| box manual_map::ManualMap);
-    store.register_late_pass(move || box if_then_some_else_none::IfThenSomeElseNone::new(msrv));
-    store.register_early_pass(|| box bool_assert_comparison::BoolAssertComparison);
+    passes.register_late_pass(disallowed_method::DisallowedMethod::get_lints(), move || box disallowed_method::DisallowedMethod::new(&disallowed_methods));
+    passes.register_early_pass(asm_syntax::InlineAsmX86AttSyntax::get_lints(), || box asm_syntax::InlineAsmX86AttSyntax);
+    passes.register_early_pass(asm_syntax::InlineAsmX86IntelSyntax::get_lints(), || box asm_syntax::InlineAsmX86IntelSyntax);
+    passes.register_late_pass(undropped_manually_drops::UndroppedManuallyDrops::get_lints(), || box undropped_manually_drops::UndroppedManuallyDrops);
+    passes.register_late_pass(strings::StrToString::get_lints(), || box strings::StrToString);
+    passes.register_late_pass(strings::StringToString::get_lints(), || box strings::StringToString);
+    passes.register_late_pass(zero_sized_map_values::ZeroSizedMapValues::get_lints(), || box zero_sized_map_values::ZeroSizedMapValues);
+    passes.register_late_pass(vec_init_then_push::VecInitThenPush::get_lints(), || box vec_init_then_push::VecInitThenPush::default());
+    passes.register_late_pass(case_sensitive_file_extension_comparisons::CaseSensitiveFileExtensionComparisons::get_lints(), || box case_sensitive_file_extension_comparisons::CaseSensitiveFileExtensionComparisons);
+    passes.register_late_pass(redundant_slicing::RedundantSlicing::get_lints(), || box redundant_slicing::RedundantSlicing);
+    passes.register_late_pass(from_str_radix_10::FromStrRadix10::get_lints(), || box from_str_radix_10::FromStrRadix10);
+    passes.register_late_pass(manual_map::ManualMap::get_lints(), || box manual_map::ManualMap);
+    passes.register_late_pass(if_then_some_else_none::IfThenSomeElseNone::get_lints(), move || box if_then_some_else_none::IfThenSomeElseNone::new(msrv));
+    passes.register_early_pass(bool_assert_comparison::BoolAssertComparison::get_lints(), || box bool_assert_comparison::BoolAssertComparison);
+    passes.register_late_pass(unchecked_env_args::UncheckedEnvArgs::get_lints(), || box unchecked_env_args::UncheckedEnvArgs);
+    passes.register_late_pass(vec_front_in_loop::VecFrontInLoop::get_lints(), || box vec_front_in_loop::VecFrontInLoop);
+    passes.register_late_pass(manual_from_bytes::ManualFromBytes::get_lints(), || box manual_from_bytes::ManualFromBytes);
+    // end register passes, do not remove this comment, it’s used in `new_lint`
+    // runs after the lints of the other passes
+    passes.register_late_pass(utils::repeated_in_macros::RepeatedInMacros::get_lints(), || box utils::repeated_in_macros::RepeatedInMacros);
 
-    store.register_group(true, "clippy::restriction", Some("clippy_restriction"), vec![
+    // the lints moved to other groups by the `reclassify` table are registered in their new groups
+    let mut groups = utils::reclassify::Groups::new(store, sess, &conf.reclassify);
+    groups.register_group(true, "clippy::restriction", Some("clippy_restriction"), vec![
         LintId::of(arithmetic::FLOAT_ARITHMETIC),
         LintId::of(arithmetic::INTEGER_ARITHMETIC),
         LintId::of(as_conversions::AS_CONVERSIONS),
@@ -1286,10 +1478,14 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(exhaustive_items::EXHAUSTIVE_ENUMS),
         LintId::of(exhaustive_items::EXHAUSTIVE_STRUCTS),
         LintId::of(exit::EXIT),
+        LintId::of(ffi_unchecked_index::FFI_UNCHECKED_INDEX),
         LintId::of(float_literal::LOSSY_FLOAT_LITERAL),
         LintId::of(if_then_some_else_none::IF_THEN_SOME_ELSE_NONE),
         LintId::of(implicit_return::IMPLICIT_RETURN),
         LintId::of(indexing_slicing::INDEXING_SLICING),
+        LintId::of(indexing_slicing::INDEX_MAP),
+        LintId::of(indexing_slicing::INDEX_UNDERFLOW),
+        LintId::of(indexing_slicing::STRING_SLICE),
         LintId::of(inherent_impl::MULTIPLE_INHERENT_IMPL),
         LintId::of(integer_division::INTEGER_DIVISION),
         LintId::of(let_underscore::LET_UNDERSCORE_MUST_USE),
@@ -1302,6 +1498,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(methods::EXPECT_USED),
         LintId::of(methods::FILETYPE_IS_FILE),
         LintId::of(methods::GET_UNWRAP),
+        LintId::of(methods::NONZERO_NEW_UNWRAP),
+        LintId::of(methods::UNWRAPPED_FIRST_OR_LAST),
+        LintId::of(methods::UNWRAPPED_GET),
         LintId::of(methods::UNWRAP_USED),
         LintId::of(methods::WRONG_PUB_SELF_CONVENTION),
         LintId::of(misc::FLOAT_CMP_CONST),
@@ -1309,12 +1508,18 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(missing_doc::MISSING_DOCS_IN_PRIVATE_ITEMS),
         LintId::of(missing_inline::MISSING_INLINE_IN_PUBLIC_ITEMS),
         LintId::of(modulo_arithmetic::MODULO_ARITHMETIC),
+        LintId::of(panic_in_drop::PANIC_IN_DROP),
+        LintId::of(panic_in_fmt::PANIC_IN_FMT),
         LintId::of(panic_in_result_fn::PANIC_IN_RESULT_FN),
         LintId::of(panic_unimplemented::PANIC),
+        LintId::of(panic_unimplemented::PANIC_IN_LIBRARY),
         LintId::of(panic_unimplemented::TODO),
         LintId::of(panic_unimplemented::UNIMPLEMENTED),
         LintId::of(panic_unimplemented::UNREACHABLE),
+        LintId::of(panicking_index_impl::PANICKING_INDEX_IMPL),
+        LintId::of(panicking_index_impl::PANICKING_USER_INDEX),
         LintId::of(pattern_type_mismatch::PATTERN_TYPE_MISMATCH),
+        LintId::of(reachable_panic::REACHABLE_PANIC),
         LintId::of(semicolon_if_nothing_returned::SEMICOLON_IF_NOTHING_RETURNED),
         LintId::of(shadow::SHADOW_REUSE),
         LintId::of(shadow::SHADOW_SAME),
@@ -1322,15 +1527,19 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(strings::STRING_TO_STRING),
         LintId::of(strings::STR_TO_STRING),
         LintId::of(types::RC_BUFFER),
+        LintId::of(unchecked_division::UNCHECKED_DIVISION),
+        LintId::of(unguarded_raw_pointer_index::UNGUARDED_RAW_POINTER_INDEX),
         LintId::of(unnecessary_self_imports::UNNECESSARY_SELF_IMPORTS),
         LintId::of(unwrap_in_result::UNWRAP_IN_RESULT),
+        LintId::of(unwrap_indexing::UNWRAP_INDEXING),
+        LintId::of(unwrapped_index_conversion::UNWRAPPED_INDEX_CONVERSION),
         LintId::of(verbose_file_reads::VERBOSE_FILE_READS),
         LintId::of(write::PRINT_STDERR),
         LintId::of(write::PRINT_STDOUT),
         LintId::of(write::USE_DEBUG),
     ]);
 
-    store.register_group(true, "clippy::pedantic", Some("clippy_pedantic"), vec![
+    groups.register_group(true, "clippy::pedantic", Some("clippy_pedantic"), vec![
         LintId::of(attrs::INLINE_ALWAYS),
         LintId::of(await_holding_invalid::AWAIT_HOLDING_LOCK),
         LintId::of(await_holding_invalid::AWAIT_HOLDING_REFCELL_REF),
@@ -1344,6 +1553,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(casts::CAST_PTR_ALIGNMENT),
         LintId::of(casts::CAST_SIGN_LOSS),
         LintId::of(casts::PTR_AS_PTR),
+        LintId::of(chained_indexing::CHAINED_INDEXING),
         LintId::of(checked_conversions::CHECKED_CONVERSIONS),
         LintId::of(copies::SAME_FUNCTIONS_IN_IF_CONDITION),
         LintId::of(copy_iterator::COPY_ITERATOR),
@@ -1365,6 +1575,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(if_not_else::IF_NOT_ELSE),
         LintId::of(implicit_hasher::IMPLICIT_HASHER),
         LintId::of(implicit_saturating_sub::IMPLICIT_SATURATING_SUB),
+        LintId::of(indexing_slicing::CONSTRUCT_THEN_INDEX),
+        LintId::of(indexing_slicing::LOOP_INDEX_OFFSET),
+        LintId::of(indexing_slicing::SIGNED_INDEX_CAST),
         LintId::of(infinite_iter::MAYBE_INFINITE_ITER),
         LintId::of(invalid_upcast_comparisons::INVALID_UPCAST_COMPARISONS),
         LintId::of(items_after_statements::ITEMS_AFTER_STATEMENTS),
@@ -1374,8 +1587,12 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(literal_representation::UNREADABLE_LITERAL),
         LintId::of(loops::EXPLICIT_INTO_ITER_LOOP),
         LintId::of(loops::EXPLICIT_ITER_LOOP),
+        LintId::of(loops::MANUAL_BINARY_SEARCH),
+        LintId::of(loops::MANUAL_ZIP),
         LintId::of(macro_use::MACRO_USE_IMPORTS),
+        LintId::of(manual_from_bytes::MANUAL_FROM_BYTES),
         LintId::of(manual_ok_or::MANUAL_OK_OR),
+        LintId::of(manual_slice_pattern::MANUAL_SLICE_PATTERN),
         LintId::of(match_on_vec_items::MATCH_ON_VEC_ITEMS),
         LintId::of(matches::MATCH_BOOL),
         LintId::of(matches::MATCH_SAME_ARMS),
@@ -1389,9 +1606,11 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(methods::INEFFICIENT_TO_STRING),
         LintId::of(methods::MAP_FLATTEN),
         LintId::of(methods::MAP_UNWRAP_OR),
+        LintId::of(methods::UNGUARDED_GET_UNCHECKED),
         LintId::of(misc::USED_UNDERSCORE_BINDING),
         LintId::of(misc_early::UNSEPARATED_LITERAL_SUFFIX),
         LintId::of(mut_mut::MUT_MUT),
+        LintId::of(mutable_debug_assertion::DEBUG_ASSERT_INDEXING),
         LintId::of(needless_continue::NEEDLESS_CONTINUE),
         LintId::of(needless_for_each::NEEDLESS_FOR_EACH),
         LintId::of(needless_pass_by_value::NEEDLESS_PASS_BY_VALUE),
@@ -1403,13 +1622,17 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(ranges::RANGE_PLUS_ONE),
         LintId::of(redundant_else::REDUNDANT_ELSE),
         LintId::of(ref_option_ref::REF_OPTION_REF),
+        LintId::of(repeated_indexing::REPEATED_INDEXING),
         LintId::of(shadow::SHADOW_UNRELATED),
+        LintId::of(split_indexing::SPLIT_INDEXING),
         LintId::of(strings::STRING_ADD_ASSIGN),
         LintId::of(trait_bounds::TRAIT_DUPLICATION_IN_BOUNDS),
         LintId::of(trait_bounds::TYPE_REPETITION_IN_BOUNDS),
         LintId::of(transmute::TRANSMUTE_PTR_TO_PTR),
         LintId::of(types::LINKEDLIST),
         LintId::of(types::OPTION_OPTION),
+        LintId::of(unchecked_env_args::UNCHECKED_ENV_ARGS),
+        LintId::of(unhelpful_expect_message::UNHELPFUL_EXPECT_MESSAGE),
         LintId::of(unicode::NON_ASCII_LITERAL),
         LintId::of(unicode::UNICODE_NOT_NFC),
         LintId::of(unit_types::LET_UNIT_VALUE),
@@ -1422,7 +1645,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     ]);
 
     #[cfg(feature = "internal-lints")]
-    store.register_group(true, "clippy::internal", Some("clippy_internal"), vec![
+    groups.register_group(true, "clippy::internal", Some("clippy_internal"), vec![
         LintId::of(utils::internal_lints::CLIPPY_LINTS_INTERNAL),
         LintId::of(utils::internal_lints::COLLAPSIBLE_SPAN_LINT_CALLS),
         LintId::of(utils::internal_lints::COMPILER_LINT_FUNCTIONS),
@@ -1437,7 +1660,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(utils::internal_lints::UNNECESSARY_SYMBOL_STR),
     ]);
 
-    store.register_group(true, "clippy::all", Some("clippy"), vec![
+    groups.register_group(true, "clippy::all", Some("clippy"), vec![
         LintId::of(absurd_extreme_comparisons::ABSURD_EXTREME_COMPARISONS),
         LintId::of(approx_const::APPROX_CONSTANT),
         LintId::of(assertions_on_constants::ASSERTIONS_ON_CONSTANTS),
@@ -1508,11 +1731,20 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(functions::RESULT_UNIT_ERR),
         LintId::of(functions::TOO_MANY_ARGUMENTS),
         LintId::of(get_last_with_len::GET_LAST_WITH_LEN),
+        LintId::of(get_last_with_len::INDEXING_LAST_WITH_LEN),
         LintId::of(identity_op::IDENTITY_OP),
         LintId::of(if_let_mutex::IF_LET_MUTEX),
         LintId::of(if_let_some_result::IF_LET_SOME_RESULT),
         LintId::of(inconsistent_struct_constructor::INCONSISTENT_STRUCT_CONSTRUCTOR),
+        LintId::of(indexing_slicing::COLLECT_THEN_INDEX),
+        LintId::of(indexing_slicing::CONST_FN_INDEXING),
+        LintId::of(indexing_slicing::INCLUSIVE_RANGE_OUT_OF_BOUNDS),
+        LintId::of(indexing_slicing::MATCHED_LEN_OUT_OF_BOUNDS),
+        LintId::of(indexing_slicing::NTH_OUT_OF_BOUNDS),
         LintId::of(indexing_slicing::OUT_OF_BOUNDS_INDEXING),
+        LintId::of(indexing_slicing::REVERSED_SLICE_RANGE),
+        LintId::of(indexing_slicing::SIZE_OF_OUT_OF_BOUNDS),
+        LintId::of(indexing_slicing::WITH_CAPACITY_INDEXING),
         LintId::of(infinite_iter::INFINITE_ITER),
         LintId::of(inherent_to_string::INHERENT_TO_STRING),
         LintId::of(inherent_to_string::INHERENT_TO_STRING_SHADOW_DISPLAY),
@@ -1529,6 +1761,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(literal_representation::INCONSISTENT_DIGIT_GROUPING),
         LintId::of(literal_representation::MISTYPED_LITERAL_SUFFIXES),
         LintId::of(literal_representation::UNUSUAL_BYTE_GROUPINGS),
+        LintId::of(loops::CHARS_NTH_LOOP),
         LintId::of(loops::EMPTY_LOOP),
         LintId::of(loops::EXPLICIT_COUNTER_LOOP),
         LintId::of(loops::FOR_KV_MAP),
@@ -1579,6 +1812,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(methods::FILTER_NEXT),
         LintId::of(methods::FLAT_MAP_IDENTITY),
         LintId::of(methods::FROM_ITER_INSTEAD_OF_COLLECT),
+        LintId::of(methods::GET_UNWRAP_OR_CALL),
         LintId::of(methods::INSPECT_FOR_EACH),
         LintId::of(methods::INTO_ITER_ON_REF),
         LintId::of(methods::ITERATOR_STEP_BY_ZERO),
@@ -1592,6 +1826,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(methods::MANUAL_FIND_MAP),
         LintId::of(methods::MANUAL_SATURATING_ARITHMETIC),
         LintId::of(methods::MAP_COLLECT_RESULT_UNIT),
+        LintId::of(methods::METHOD_INDEX_OUT_OF_BOUNDS),
         LintId::of(methods::NEW_RET_NO_SELF),
         LintId::of(methods::OK_EXPECT),
         LintId::of(methods::OPTION_AS_REF_DEREF),
@@ -1604,6 +1839,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(methods::SINGLE_CHAR_ADD_STR),
         LintId::of(methods::SINGLE_CHAR_PATTERN),
         LintId::of(methods::SKIP_WHILE_NEXT),
+        LintId::of(methods::SLICE_LENGTH_MISMATCH),
         LintId::of(methods::STRING_EXTEND_CHARS),
         LintId::of(methods::SUSPICIOUS_MAP),
         LintId::of(methods::UNINIT_ASSUMED_INIT),
@@ -1723,6 +1959,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(upper_case_acronyms::UPPER_CASE_ACRONYMS),
         LintId::of(useless_conversion::USELESS_CONVERSION),
         LintId::of(vec::USELESS_VEC),
+        LintId::of(vec_front_in_loop::VEC_FRONT_IN_LOOP),
         LintId::of(vec_init_then_push::VEC_INIT_THEN_PUSH),
         LintId::of(vec_resize_to_zero::VEC_RESIZE_TO_ZERO),
         LintId::of(write::PRINTLN_EMPTY_STRING),
@@ -1734,7 +1971,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(zero_div_zero::ZERO_DIVIDED_BY_ZERO),
     ]);
 
-    store.register_group(true, "clippy::style", Some("clippy_style"), vec![
+    groups.register_group(true, "clippy::style", Some("clippy_style"), vec![
         LintId::of(assertions_on_constants::ASSERTIONS_ON_CONSTANTS),
         LintId::of(assign_ops::ASSIGN_OP_PATTERN),
         LintId::of(attrs::BLANKET_CLIPPY_RESTRICTION_LINTS),
@@ -1763,6 +2000,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(functions::DOUBLE_MUST_USE),
         LintId::of(functions::MUST_USE_UNIT),
         LintId::of(functions::RESULT_UNIT_ERR),
+        LintId::of(get_last_with_len::INDEXING_LAST_WITH_LEN),
         LintId::of(if_let_some_result::IF_LET_SOME_RESULT),
         LintId::of(inconsistent_struct_constructor::INCONSISTENT_STRUCT_CONSTRUCTOR),
         LintId::of(inherent_to_string::INHERENT_TO_STRING),
@@ -1850,7 +2088,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(write::WRITE_WITH_NEWLINE),
     ]);
 
-    store.register_group(true, "clippy::complexity", Some("clippy_complexity"), vec![
+    groups.register_group(true, "clippy::complexity", Some("clippy_complexity"), vec![
         LintId::of(assign_ops::MISREFACTORED_ASSIGN_OP),
         LintId::of(attrs::DEPRECATED_CFG_ATTR),
         LintId::of(booleans::NONMINIMAL_BOOL),
@@ -1942,7 +2180,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(zero_div_zero::ZERO_DIVIDED_BY_ZERO),
     ]);
 
-    store.register_group(true, "clippy::correctness", Some("clippy_correctness"), vec![
+    groups.register_group(true, "clippy::correctness", Some("clippy_correctness"), vec![
         LintId::of(absurd_extreme_comparisons::ABSURD_EXTREME_COMPARISONS),
         LintId::of(approx_const::APPROX_CONSTANT),
         LintId::of(async_yields_async::ASYNC_YIELDS_ASYNC),
@@ -1969,7 +2207,14 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(formatting::POSSIBLE_MISSING_COMMA),
         LintId::of(functions::NOT_UNSAFE_PTR_ARG_DEREF),
         LintId::of(if_let_mutex::IF_LET_MUTEX),
+        LintId::of(indexing_slicing::CONST_FN_INDEXING),
+        LintId::of(indexing_slicing::INCLUSIVE_RANGE_OUT_OF_BOUNDS),
+        LintId::of(indexing_slicing::MATCHED_LEN_OUT_OF_BOUNDS),
+        LintId::of(indexing_slicing::NTH_OUT_OF_BOUNDS),
         LintId::of(indexing_slicing::OUT_OF_BOUNDS_INDEXING),
+        LintId::of(indexing_slicing::REVERSED_SLICE_RANGE),
+        LintId::of(indexing_slicing::SIZE_OF_OUT_OF_BOUNDS),
+        LintId::of(indexing_slicing::WITH_CAPACITY_INDEXING),
         LintId::of(infinite_iter::INFINITE_ITER),
         LintId::of(inherent_to_string::INHERENT_TO_STRING_SHADOW_DISPLAY),
         LintId::of(inline_fn_without_body::INLINE_FN_WITHOUT_BODY),
@@ -1983,6 +2228,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(mem_replace::MEM_REPLACE_WITH_UNINIT),
         LintId::of(methods::CLONE_DOUBLE_REF),
         LintId::of(methods::ITERATOR_STEP_BY_ZERO),
+        LintId::of(methods::METHOD_INDEX_OUT_OF_BOUNDS),
+        LintId::of(methods::SLICE_LENGTH_MISMATCH),
         LintId::of(methods::UNINIT_ASSUMED_INIT),
         LintId::of(methods::ZST_OFFSET),
         LintId::of(minmax::MIN_MAX),
@@ -2018,14 +2265,17 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(vec_resize_to_zero::VEC_RESIZE_TO_ZERO),
     ]);
 
-    store.register_group(true, "clippy::perf", Some("clippy_perf"), vec![
+    groups.register_group(true, "clippy::perf", Some("clippy_perf"), vec![
         LintId::of(entry::MAP_ENTRY),
         LintId::of(escape::BOXED_LOCAL),
+        LintId::of(indexing_slicing::COLLECT_THEN_INDEX),
         LintId::of(large_const_arrays::LARGE_CONST_ARRAYS),
         LintId::of(large_enum_variant::LARGE_ENUM_VARIANT),
+        LintId::of(loops::CHARS_NTH_LOOP),
         LintId::of(loops::MANUAL_MEMCPY),
         LintId::of(loops::NEEDLESS_COLLECT),
         LintId::of(methods::EXPECT_FUN_CALL),
+        LintId::of(methods::GET_UNWRAP_OR_CALL),
         LintId::of(methods::ITER_NTH),
         LintId::of(methods::OR_FUN_CALL),
         LintId::of(methods::SINGLE_CHAR_PATTERN),
@@ -2037,16 +2287,17 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(types::BOX_VEC),
         LintId::of(types::REDUNDANT_ALLOCATION),
         LintId::of(vec::USELESS_VEC),
+        LintId::of(vec_front_in_loop::VEC_FRONT_IN_LOOP),
         LintId::of(vec_init_then_push::VEC_INIT_THEN_PUSH),
     ]);
 
-    store.register_group(true, "clippy::cargo", Some("clippy_cargo"), vec![
+    groups.register_group(true, "clippy::cargo", Some("clippy_cargo"), vec![
         LintId::of(cargo_common_metadata::CARGO_COMMON_METADATA),
         LintId::of(multiple_crate_versions::MULTIPLE_CRATE_VERSIONS),
         LintId::of(wildcard_dependencies::WILDCARD_DEPENDENCIES),
     ]);
 
-    store.register_group(true, "clippy::nursery", Some("clippy_nursery"), vec![
+    groups.register_group(true, "clippy::nursery", Some("clippy_nursery"), vec![
         LintId::of(attrs::EMPTY_LINE_AFTER_OUTER_ATTR),
         LintId::of(cognitive_complexity::COGNITIVE_COMPLEXITY),
         LintId::of(disallowed_method::DISALLOWED_METHOD),
@@ -2059,6 +2310,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(mutable_debug_assertion::DEBUG_ASSERT_WITH_MUT_CALL),
         LintId::of(mutex_atomic::MUTEX_INTEGER),
         LintId::of(needless_borrow::NEEDLESS_BORROW),
+        LintId::of(partially_read_buffer::PARTIALLY_READ_BUFFER),
         LintId::of(path_buf_push_overwrite::PATH_BUF_PUSH_OVERWRITE),
         LintId::of(redundant_pub_crate::REDUNDANT_PUB_CRATE),
         LintId::of(regex::TRIVIAL_REGEX),
@@ -2066,6 +2318,11 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(transmute::USELESS_TRANSMUTE),
         LintId::of(use_self::USE_SELF),
     ]);
+
+    meta_groups::register(store);
+
+    // the lint groups have to be registered first, to resolve the names of groups in `filter`
+    passes.register(store, sess, filter);
 }
 
 #[rustfmt::skip]
@@ -2112,6 +2369,19 @@ fn register_removed_non_tool_lints(store: &mut rustc_lint::LintStore) {
     );
 }
 
+/// The notes added to every diagnostic of a lint, before the link to its documentation, to
+/// announce a change of its behavior, like a move to another group or a new default of one of its
+/// configuration values, and explain how to prepare for it. An entry is removed once the change is
+/// released.
+#[rustfmt::skip]
+static MIGRATION_NOTES: &[(&&Lint, &str)] = &[
+    (
+        &indexing_slicing::INDEXING_SLICING,
+        "the default of the `allow-indexing-slicing-in-tests` configuration value will change to `true`, \
+        set it to `false` in `clippy.toml` to keep linting `#[test]` functions and `#[cfg(test)]` code",
+    ),
+];
+
 /// Register renamed lints.
 ///
 /// Used in `./src/driver.rs`.
@@ -0,0 +1,393 @@
+//! Skips the late lint passes on the items that didn't change since the previous run and had no
+//! diagnostics, for `cargo clippy --cache`.
+//!
+//! The passes are wrapped in a pass that forwards every `check_*` method to the wrapped pass,
+//! except between `check_item` and `check_item_post` of a clean item of a module. The items are
+//! identified by a hash of their HIR, their attributes and their source text, without the spans,
+//! so moving an item doesn't change its hash. After the passes ran, the hashes of the items
+//! without diagnostics are written to a file per crate in the cache directory.
+//!
+//! The results of the lints on an item also depend on the rest of the crate, so the cache is
+//! cleared when one of these changes:
+//!
+//! - the arguments of the driver, `CLIPPY_ARGS`, the configuration file and the driver itself
+//! - the crates this crate depends on
+//! - the signatures of the items of the crate and the values of its constants and statics
+//!
+//! The bodies of the other functions of the crate aren't part of the hash, so a lint looking into
+//! the functions called by an item isn't run again when they change. The passes that collect
+//! information from all items are registered with `PassRegistry::register_whole_crate_late_pass`
+//! and never skip any items.
+
+use clippy_utils::diagnostics::{record_emitted_spans, take_emitted_spans};
+use rustc_ast as ast;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::stable_hasher::{HashStable, StableHasher};
+use rustc_data_structures::sync;
+use rustc_hir as hir;
+use rustc_hir::def_id::{LocalDefId, LOCAL_CRATE};
+use rustc_hir::intravisit::{self, NestedVisitorMap, Visitor};
+use rustc_lint::{LateContext, LateLintPass, LintPass, LintStore};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ich::StableHashingContext;
+use rustc_middle::ty::TyCtxt;
+use rustc_span::symbol::Symbol;
+use rustc_span::{BytePos, Span};
+use std::cell::RefCell;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+type LateLintPassObject = Box<dyn for<'tcx> LateLintPass<'tcx> + sync::Send + sync::Sync>;
+type Ctor<T> = Box<dyn Fn() -> T + sync::Send + sync::Sync>;
+
+thread_local! {
+    /// The names of the passes that need to see every item of the crate, see
+    /// `add_whole_crate_pass`.
+    static WHOLE_CRATE_PASSES: RefCell<FxHashSet<&'static str>> = RefCell::new(FxHashSet::default());
+}
+
+/// Marks the pass `name` as needing to see every item of the crate, so that it never skips any.
+/// Called by `PassRegistry` for the passes registered with `register_whole_crate_late_pass`.
+pub fn add_whole_crate_pass(name: &'static str) {
+    WHOLE_CRATE_PASSES.with(|passes| passes.borrow_mut().insert(name));
+}
+
+/// The hashes of the items without diagnostics, shared by the wrapped passes.
+#[derive(Clone)]
+pub struct LintCache(Arc<Mutex<State>>);
+
+struct State {
+    /// The directory of the cache files.
+    dir: PathBuf,
+    /// The cache file of the crate and the hash the items depend on, once the first item is
+    /// checked.
+    file: Option<(PathBuf, u64)>,
+    /// The items without diagnostics in the previous run, by hash.
+    clean: FxHashSet<u64>,
+    /// The items of modules checked or skipped by this run, with their hash and the bounds of
+    /// their span.
+    items: FxHashMap<LocalDefId, (u64, BytePos, BytePos)>,
+}
+
+impl LintCache {
+    /// Creates a cache writing its files to `dir`, which is created if it doesn't exist.
+    pub fn new(dir: PathBuf) -> Self {
+        Self(Arc::new(Mutex::new(State {
+            dir,
+            file: None,
+            clean: FxHashSet::default(),
+            items: FxHashMap::default(),
+        })))
+    }
+
+    /// Whether the lint passes can skip `item`, because it had no diagnostics in the previous run
+    /// and neither it nor the crate changed since.
+    fn is_clean<'tcx>(&self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) -> bool {
+        let mut state = self.lock();
+        if state.file.is_none() {
+            state.load(cx.tcx);
+        }
+        let (hash, ..) = *state.items.entry(item.def_id).or_insert_with(|| {
+            let span = item_span(cx.tcx, item).source_callsite();
+            (item_hash(cx.tcx, item), span.lo(), span.hi())
+        });
+        state.clean.contains(&hash)
+    }
+
+    /// Writes the hashes of the items without diagnostics to the cache file of the crate.
+    fn save(&self) {
+        let emitted: Vec<_> = take_emitted_spans()
+            .iter()
+            .map(|span| span.source_callsite().lo())
+            .collect();
+        let state = self.lock();
+        let (path, context) = match &state.file {
+            Some(file) => file,
+            None => return,
+        };
+        let mut text = format!("{:016x}\n", context);
+        for &(hash, lo, hi) in state.items.values() {
+            if !emitted.iter().any(|&pos| lo <= pos && pos < hi) {
+                text.push_str(&format!("{:016x}\n", hash));
+            }
+        }
+        if let Err(e) = fs::create_dir_all(&state.dir).and_then(|_| fs::write(path, text)) {
+            eprintln!("warning: failed to write `{}`: {}", path.display(), e);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.0.lock().expect("a lint pass panicked while using the lint cache")
+    }
+}
+
+impl State {
+    /// Reads the cache file of the crate, whose items are only clean if the hash they depend on is
+    /// the same.
+    fn load(&mut self, tcx: TyCtxt<'_>) {
+        let name = format!(
+            "{}-{}",
+            tcx.crate_name(LOCAL_CRATE),
+            tcx.sess.local_crate_disambiguator().to_fingerprint().to_hex()
+        );
+        let path = self.dir.join(name);
+        let context = context_hash(tcx);
+        let text = fs::read_to_string(&path).unwrap_or_default();
+        let mut lines = text.lines().map(|line| u64::from_str_radix(line, 16));
+        if let Some(Ok(previous)) = lines.next() {
+            if previous == context {
+                self.clean = lines.filter_map(Result::ok).collect();
+            }
+        }
+        self.file = Some((path, context));
+    }
+}
+
+/// Calls `register` and wraps the late lint passes it registers in `store` to skip the clean items.
+pub fn register_cached(store: &mut LintStore, cache: &LintCache, register: impl FnOnce(&mut LintStore)) {
+    let late = store.late_passes.len();
+    register(store);
+
+    for ctor in &mut store.late_passes[late..] {
+        let cache = cache.clone();
+        let inner = std::mem::replace(ctor, Box::new(|| unreachable!()));
+        *ctor = Box::new(move || -> LateLintPassObject {
+            let pass = inner();
+            if WHOLE_CRATE_PASSES.with(|passes| passes.borrow().contains(pass.name())) {
+                pass
+            } else {
+                box CachedLatePass {
+                    pass,
+                    cache: cache.clone(),
+                    skipped: None,
+                }
+            }
+        });
+    }
+
+    // the last pass writes the cache once the other passes emitted their lints
+    record_emitted_spans();
+    let cache = cache.clone();
+    let ctor: Ctor<LateLintPassObject> = Box::new(move || box SaveCache(cache.clone()));
+    store.late_passes.push(ctor);
+}
+
+struct CachedLatePass {
+    pass: LateLintPassObject,
+    cache: LintCache,
+    /// The clean item being skipped.
+    skipped: Option<LocalDefId>,
+}
+
+#[allow(rustc::lint_pass_impl_without_macro)]
+impl LintPass for CachedLatePass {
+    fn name(&self) -> &'static str {
+        self.pass.name()
+    }
+}
+
+macro_rules! cached_methods {
+    ($context:ty, [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => (
+        $(fn $name(&mut self, cx: $context, $($param: $arg),*) {
+            if self.skipped.is_none() {
+                self.pass.$name(cx, $($param),*);
+            }
+        })*
+    )
+}
+
+/// Forwards the methods of `$methods` except `check_item` and `check_item_post`, which are
+/// removed by the first rules.
+macro_rules! cached_late_lint_pass {
+    ([], [$hir:tt], [$($methods:tt)*]) => {
+        cached_late_lint_pass!(@filter [$hir], [], [$($methods)*]);
+    };
+    (@filter [$hir:tt], [$($kept:tt)*], [fn check_item($($param:tt)*); $($rest:tt)*]) => {
+        cached_late_lint_pass!(@filter [$hir], [$($kept)*], [$($rest)*]);
+    };
+    (@filter [$hir:tt], [$($kept:tt)*], [fn check_item_post($($param:tt)*); $($rest:tt)*]) => {
+        cached_late_lint_pass!(@filter [$hir], [$($kept)*], [$($rest)*]);
+    };
+    (@filter [$hir:tt], [$($kept:tt)*], [$(#[$attr:meta])* fn $name:ident($($param:tt)*); $($rest:tt)*]) => {
+        cached_late_lint_pass!(@filter [$hir], [$($kept)* fn $name($($param)*);], [$($rest)*]);
+    };
+    (@filter [$hir:tt], [$($kept:tt)*], []) => {
+        impl<$hir> LateLintPass<$hir> for CachedLatePass {
+            fn check_item(&mut self, cx: &LateContext<$hir>, item: &$hir hir::Item<$hir>) {
+                if self.skipped.is_none() {
+                    if is_module_item(cx.tcx, item) && self.cache.is_clean(cx, item) {
+                        self.skipped = Some(item.def_id);
+                    } else {
+                        self.pass.check_item(cx, item);
+                    }
+                }
+            }
+
+            fn check_item_post(&mut self, cx: &LateContext<$hir>, item: &$hir hir::Item<$hir>) {
+                match self.skipped {
+                    Some(def_id) if def_id == item.def_id => self.skipped = None,
+                    Some(_) => {},
+                    None => self.pass.check_item_post(cx, item),
+                }
+            }
+
+            cached_methods!(&LateContext<$hir>, [$($kept)*]);
+        }
+    };
+}
+
+rustc_lint::late_lint_methods!(cached_late_lint_pass, [], ['tcx]);
+
+/// Writes the cache in `check_crate_post`, after all other passes.
+struct SaveCache(LintCache);
+
+#[allow(rustc::lint_pass_impl_without_macro)]
+impl LintPass for SaveCache {
+    fn name(&self) -> &'static str {
+        "SaveCache"
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for SaveCache {
+    fn check_crate_post(&mut self, _: &LateContext<'tcx>, _: &'tcx hir::Crate<'tcx>) {
+        self.0.save();
+    }
+}
+
+/// Whether `item` is an item of a module, other than a module, which is cached as a whole.
+fn is_module_item(tcx: TyCtxt<'_>, item: &hir::Item<'_>) -> bool {
+    if matches!(item.kind, hir::ItemKind::Mod(_)) {
+        return false;
+    }
+    let parent = tcx.hir().get_parent_item(item.hir_id());
+    matches!(
+        tcx.hir().find(parent),
+        Some(
+            hir::Node::Crate(_)
+                | hir::Node::Item(hir::Item {
+                    kind: hir::ItemKind::Mod(_),
+                    ..
+                })
+        )
+    )
+}
+
+/// The span of `item` with its outer attributes.
+fn item_span(tcx: TyCtxt<'_>, item: &hir::Item<'_>) -> Span {
+    tcx.hir()
+        .attrs(item.hir_id())
+        .iter()
+        .filter(|attr| attr.style == ast::AttrStyle::Outer && !attr.span.from_expansion())
+        .fold(item.span, |span, attr| span.to(attr.span))
+}
+
+/// Hashes `item` and the items nested in it, with their bodies and attributes, the source text of
+/// `item` and the attributes of the modules containing it.
+fn item_hash<'tcx>(tcx: TyCtxt<'tcx>, item: &'tcx hir::Item<'tcx>) -> u64 {
+    let mut visitor = ItemHasher {
+        tcx,
+        hcx: tcx.create_no_span_stable_hashing_context(),
+        hasher: StableHasher::new(),
+    };
+    visitor.visit_item(item);
+    for (id, _) in tcx.hir().parent_iter(item.hir_id()) {
+        visitor.hash_attrs(id);
+    }
+    let source = tcx.sess.source_map().span_to_snippet(item_span(tcx, item)).ok();
+    source.hash_stable(&mut visitor.hcx, &mut visitor.hasher);
+    visitor.hasher.finish()
+}
+
+struct ItemHasher<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    hcx: StableHashingContext<'tcx>,
+    hasher: StableHasher,
+}
+
+impl<'tcx> ItemHasher<'tcx> {
+    fn hash_attrs(&mut self, id: hir::HirId) {
+        self.tcx.hir().attrs(id).hash_stable(&mut self.hcx, &mut self.hasher);
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for ItemHasher<'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_id(&mut self, id: hir::HirId) {
+        self.hash_attrs(id);
+    }
+
+    fn visit_item(&mut self, item: &'tcx hir::Item<'tcx>) {
+        item.hash_stable(&mut self.hcx, &mut self.hasher);
+        intravisit::walk_item(self, item);
+    }
+
+    fn visit_trait_item(&mut self, item: &'tcx hir::TraitItem<'tcx>) {
+        item.hash_stable(&mut self.hcx, &mut self.hasher);
+        intravisit::walk_trait_item(self, item);
+    }
+
+    fn visit_impl_item(&mut self, item: &'tcx hir::ImplItem<'tcx>) {
+        item.hash_stable(&mut self.hcx, &mut self.hasher);
+        intravisit::walk_impl_item(self, item);
+    }
+
+    fn visit_foreign_item(&mut self, item: &'tcx hir::ForeignItem<'tcx>) {
+        item.hash_stable(&mut self.hcx, &mut self.hasher);
+        intravisit::walk_foreign_item(self, item);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::All(self.tcx.hir())
+    }
+}
+
+/// Hashes what the lint results of every item depend on, see the module documentation.
+fn context_hash(tcx: TyCtxt<'_>) -> u64 {
+    let mut hashing_cx = tcx.create_no_span_stable_hashing_context();
+    let mut hasher = StableHasher::new();
+
+    let args: Vec<String> = std::env::args().collect();
+    args.hash_stable(&mut hashing_cx, &mut hasher);
+    std::env::var("CLIPPY_ARGS")
+        .ok()
+        .hash_stable(&mut hashing_cx, &mut hasher);
+    let conf = crate::conf_file_path(tcx.sess)
+        .ok()
+        .flatten()
+        .and_then(|path| fs::read_to_string(path).ok());
+    conf.hash_stable(&mut hashing_cx, &mut hasher);
+    let driver = std::env::current_exe()
+        .and_then(fs::metadata)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok());
+    driver
+        .map(|time| time.as_nanos())
+        .hash_stable(&mut hashing_cx, &mut hasher);
+    env!("CARGO_PKG_VERSION").hash_stable(&mut hashing_cx, &mut hasher);
+
+    for &cnum in tcx.crates() {
+        tcx.crate_hash(cnum).hash_stable(&mut hashing_cx, &mut hasher);
+    }
+
+    // the signatures of the items, and the values of the constants and statics
+    let krate = tcx.hir().krate();
+    for item in krate.items.values() {
+        let with_body = matches!(item.kind, hir::ItemKind::Const(..) | hir::ItemKind::Static(..));
+        hashing_cx.while_hashing_hir_bodies(with_body, |hashing_cx| item.hash_stable(hashing_cx, &mut hasher));
+    }
+    for item in krate.trait_items.values() {
+        let with_body = matches!(item.kind, hir::TraitItemKind::Const(..));
+        hashing_cx.while_hashing_hir_bodies(with_body, |hashing_cx| item.hash_stable(hashing_cx, &mut hasher));
+    }
+    for item in krate.impl_items.values() {
+        let with_body = matches!(item.kind, hir::ImplItemKind::Const(..));
+        hashing_cx.while_hashing_hir_bodies(with_body, |hashing_cx| item.hash_stable(hashing_cx, &mut hasher));
+    }
+    for item in krate.foreign_items.values() {
+        item.hash_stable(&mut hashing_cx, &mut hasher);
+    }
+    hasher.finish()
+}
@@ -0,0 +1,14063 @@
+//! The list of Clippy lints with their documentation, for tools listing them, like IDE plugins.
+
+// the documentation is copied verbatim
+#![allow(clippy::invisible_characters, clippy::non_ascii_literal, clippy::unicode_not_nfc)]
+
+use rustc_lint::{Level, Lint};
+
+/// A Clippy lint, see `ALL_LINTS`.
+#[derive(Debug)]
+pub struct LintInfo {
+    /// The lint, with its short description
+    pub lint: &'static Lint,
+    /// The name of the lint without the `clippy::` prefix, e.g. `"needless_range_loop"`
+    pub name: &'static str,
+    /// The category of the lint, e.g. `"style"`
+    pub group: &'static str,
+    /// The documentation of the lint in Markdown, with the **What it does:**, **Why is this bad?**,
+    /// **Known problems:** and **Example:** sections
+    pub docs: &'static str,
+}
+
+impl LintInfo {
+    /// The level of the lint if no flag or attribute sets it.
+    pub fn default_level(&self) -> Level {
+        self.lint.default_level
+    }
+}
+
+/// All Clippy lints, except the deprecated and internal ones, sorted by name.
+pub static ALL_LINTS: &[LintInfo] = &[
+    // begin lint list, do not remove this comment, it’s used in `update_lints`
+    LintInfo {
+        lint: crate::absurd_extreme_comparisons::ABSURD_EXTREME_COMPARISONS,
+        name: "absurd_extreme_comparisons",
+        group: "correctness",
+        docs: r#"**What it does:** Checks for comparisons where one side of the relation is
+either the minimum or maximum value for its type and warns if it involves a
+case that is always true or always false. Only integer and boolean types are
+checked.
+
+**Why is this bad?** An expression like `min <= x` may misleadingly imply
+that it is possible for `x` to be less than the minimum. Expressions like
+`max < x` are probably mistakes.
+
+**Known problems:** For `usize` the size of the current compile target will
+be assumed (e.g., 64 bits on 64 bit systems). This means code that uses such
+a comparison to detect target pointer width will trigger this lint. One can
+use `mem::sizeof` and compare its value or conditional compilation
+attributes
+like `#[cfg(target_pointer_width = "64")] ..` instead.
+
+**Example:**
+
+```rust
+let vec: Vec<isize> = Vec::new();
+if vec.len() <= 0 {}
+if 100 > i32::MAX {}
+```"#,
+    },
+    LintInfo {
+        lint: crate::swap::ALMOST_SWAPPED,
+        name: "almost_swapped",
+        group: "correctness",
+        docs: r"**What it does:** Checks for `foo = bar; bar = foo` sequences.
+
+**Why is this bad?** This looks like a failed attempt to swap.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let mut a = 1;
+# let mut b = 2;
+a = b;
+b = a;
+```
+If swapping is intended, use `swap()` instead:
+```rust
+# let mut a = 1;
+# let mut b = 2;
+std::mem::swap(&mut a, &mut b);
+```",
+    },
+    LintInfo {
+        lint: crate::approx_const::APPROX_CONSTANT,
+        name: "approx_constant",
+        group: "correctness",
+        docs: r"**What it does:** Checks for floating point literals that approximate
+constants which are defined in
+[`std::f32::consts`](https://doc.rust-lang.org/stable/std/f32/consts/#constants)
+or
+[`std::f64::consts`](https://doc.rust-lang.org/stable/std/f64/consts/#constants),
+respectively, suggesting to use the predefined constant.
+
+**Why is this bad?** Usually, the definition in the standard library is more
+precise than what people come up with. If you find that your definition is
+actually more precise, please [file a Rust
+issue](https://github.com/rust-lang/rust/issues).
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = 3.14;
+let y = 1_f64 / x;
+```
+Use predefined constants instead:
+```rust
+let x = std::f32::consts::PI;
+let y = std::f64::consts::FRAC_1_PI;
+```",
+    },
+    LintInfo {
+        lint: crate::as_conversions::AS_CONVERSIONS,
+        name: "as_conversions",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for usage of `as` conversions.
+
+Note that this lint is specialized in linting *every single* use of `as`
+regardless of whether good alternatives exist or not.
+If you want more precise lints for `as`, please consider using these separate lints:
+`unnecessary_cast`, `cast_lossless/possible_truncation/possible_wrap/precision_loss/sign_loss`,
+`fn_to_numeric_cast(_with_truncation)`, `char_lit_as_u8`, `ref_to_mut` and `ptr_as_ptr`.
+There is a good explanation the reason why this lint should work in this way and how it is useful
+[in this issue](https://github.com/rust-lang/rust-clippy/issues/5122).
+
+**Why is this bad?** `as` conversions will perform many kinds of
+conversions, including silently lossy conversions and dangerous coercions.
+There are cases when it makes sense to use `as`, so the lint is
+Allow by default.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+let a: u32;
+...
+f(a as u16);
+```
+
+Usually better represents the semantics you expect:
+```rust,ignore
+f(a.try_into()?);
+```
+or
+```rust,ignore
+f(a.try_into().expect("Unexpected u16 overflow in f"));
+```
+"#,
+    },
+    LintInfo {
+        lint: crate::assertions_on_constants::ASSERTIONS_ON_CONSTANTS,
+        name: "assertions_on_constants",
+        group: "style",
+        docs: r"**What it does:** Checks for `assert!(true)` and `assert!(false)` calls.
+
+**Why is this bad?** Will be optimized out by the compiler or should probably be replaced by a
+`panic!()` or `unreachable!()`
+
+**Known problems:** None
+
+**Example:**
+```rust,ignore
+assert!(false)
+assert!(true)
+const B: bool = false;
+assert!(B)
+```",
+    },
+    LintInfo {
+        lint: crate::assign_ops::ASSIGN_OP_PATTERN,
+        name: "assign_op_pattern",
+        group: "style",
+        docs: r"**What it does:** Checks for `a = a op b` or `a = b commutative_op a`
+patterns.
+
+**Why is this bad?** These can be written as the shorter `a op= b`.
+
+**Known problems:** While forbidden by the spec, `OpAssign` traits may have
+implementations that differ from the regular `Op` impl.
+
+**Example:**
+```rust
+let mut a = 5;
+let b = 0;
+// ...
+// Bad
+a = a + b;
+
+// Good
+a += b;
+```",
+    },
+    LintInfo {
+        lint: crate::async_yields_async::ASYNC_YIELDS_ASYNC,
+        name: "async_yields_async",
+        group: "correctness",
+        docs: r"**What it does:** Checks for async blocks that yield values of types
+that can themselves be awaited.
+
+**Why is this bad?** An await is likely missing.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+async fn foo() {}
+
+fn bar() {
+  let x = async {
+    foo()
+  };
+}
+```
+Use instead:
+```rust
+async fn foo() {}
+
+fn bar() {
+  let x = async {
+    foo().await
+  };
+}
+```",
+    },
+    LintInfo {
+        lint: crate::await_holding_invalid::AWAIT_HOLDING_LOCK,
+        name: "await_holding_lock",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for calls to await while holding a
+non-async-aware MutexGuard.
+
+**Why is this bad?** The Mutex types found in std::sync and parking_lot
+are not designed to operate in an async context across await points.
+
+There are two potential solutions. One is to use an asynx-aware Mutex
+type. Many asynchronous foundation crates provide such a Mutex type. The
+other solution is to ensure the mutex is unlocked before calling await,
+either by introducing a scope or an explicit call to Drop::drop.
+
+**Known problems:** Will report false positive for explicitly dropped guards ([#6446](https://github.com/rust-lang/rust-clippy/issues/6446)).
+
+**Example:**
+
+```rust,ignore
+use std::sync::Mutex;
+
+async fn foo(x: &Mutex<u32>) {
+  let guard = x.lock().unwrap();
+  *guard += 1;
+  bar.await;
+}
+```
+
+Use instead:
+```rust,ignore
+use std::sync::Mutex;
+
+async fn foo(x: &Mutex<u32>) {
+  {
+    let guard = x.lock().unwrap();
+    *guard += 1;
+  }
+  bar.await;
+}
+```",
+    },
+    LintInfo {
+        lint: crate::await_holding_invalid::AWAIT_HOLDING_REFCELL_REF,
+        name: "await_holding_refcell_ref",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for calls to await while holding a
+`RefCell` `Ref` or `RefMut`.
+
+**Why is this bad?** `RefCell` refs only check for exclusive mutable access
+at runtime. Holding onto a `RefCell` ref across an `await` suspension point
+risks panics from a mutable ref shared while other refs are outstanding.
+
+**Known problems:** Will report false positive for explicitly dropped refs ([#6353](https://github.com/rust-lang/rust-clippy/issues/6353)).
+
+**Example:**
+
+```rust,ignore
+use std::cell::RefCell;
+
+async fn foo(x: &RefCell<u32>) {
+  let mut y = x.borrow_mut();
+  *y += 1;
+  bar.await;
+}
+```
+
+Use instead:
+```rust,ignore
+use std::cell::RefCell;
+
+async fn foo(x: &RefCell<u32>) {
+  {
+     let mut y = x.borrow_mut();
+     *y += 1;
+  }
+  bar.await;
+}
+```",
+    },
+    LintInfo {
+        lint: crate::bit_mask::BAD_BIT_MASK,
+        name: "bad_bit_mask",
+        group: "correctness",
+        docs: r"**What it does:** Checks for incompatible bit masks in comparisons.
+
+The formula for detecting if an expression of the type `_ <bit_op> m
+<cmp_op> c` (where `<bit_op>` is one of {`&`, `|`} and `<cmp_op>` is one of
+{`!=`, `>=`, `>`, `!=`, `>=`, `>`}) can be determined from the following
+table:
+
+|Comparison  |Bit Op|Example     |is always|Formula               |
+|------------|------|------------|---------|----------------------|
+|`==` or `!=`| `&`  |`x & 2 == 3`|`false`  |`c & m != c`          |
+|`<`  or `>=`| `&`  |`x & 2 < 3` |`true`   |`m < c`               |
+|`>`  or `<=`| `&`  |`x & 1 > 1` |`false`  |`m <= c`              |
+|`==` or `!=`| `|`  |`x | 1 == 0`|`false`  |`c | m != c`          |
+|`<`  or `>=`| `|`  |`x | 1 < 1` |`false`  |`m >= c`              |
+|`<=` or `>` | `|`  |`x | 1 > 0` |`true`   |`m > c`               |
+
+**Why is this bad?** If the bits that the comparison cares about are always
+set to zero or one by the bit mask, the comparison is constant `true` or
+`false` (depending on mask, compared value, and operators).
+
+So the code is actively misleading, and the only reason someone would write
+this intentionally is to win an underhanded Rust contest or create a
+test-case for this lint.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let x = 1;
+if (x & 1 == 2) { }
+```",
+    },
+    LintInfo {
+        lint: crate::methods::BIND_INSTEAD_OF_MAP,
+        name: "bind_instead_of_map",
+        group: "complexity",
+        docs: r#"**What it does:** Checks for usage of `_.and_then(|x| Some(y))`, `_.and_then(|x| Ok(y))` or
+`_.or_else(|x| Err(y))`.
+
+**Why is this bad?** Readability, this can be written more concisely as
+`_.map(|x| y)` or `_.map_err(|x| y)`.
+
+**Known problems:** None
+
+**Example:**
+
+```rust
+# fn opt() -> Option<&'static str> { Some("42") }
+# fn res() -> Result<&'static str, &'static str> { Ok("42") }
+let _ = opt().and_then(|s| Some(s.len()));
+let _ = res().and_then(|s| if s.len() == 42 { Ok(10) } else { Ok(20) });
+let _ = res().or_else(|s| if s.len() == 42 { Err(10) } else { Err(20) });
+```
+
+The correct use would be:
+
+```rust
+# fn opt() -> Option<&'static str> { Some("42") }
+# fn res() -> Result<&'static str, &'static str> { Ok("42") }
+let _ = opt().map(|s| s.len());
+let _ = res().map(|s| if s.len() == 42 { 10 } else { 20 });
+let _ = res().map_err(|s| if s.len() == 42 { 10 } else { 20 });
+```"#,
+    },
+    LintInfo {
+        lint: crate::blacklisted_name::BLACKLISTED_NAME,
+        name: "blacklisted_name",
+        group: "style",
+        docs: r"**What it does:** Checks for usage of blacklisted names for variables, such
+as `foo`.
+
+**Why is this bad?** These names are usually placeholder names and should be
+avoided.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let foo = 3.14;
+```",
+    },
+    LintInfo {
+        lint: crate::attrs::BLANKET_CLIPPY_RESTRICTION_LINTS,
+        name: "blanket_clippy_restriction_lints",
+        group: "style",
+        docs: r"**What it does:** Checks for `warn`/`deny`/`forbid` attributes targeting the whole clippy::restriction category.
+
+**Why is this bad?** Restriction lints sometimes are in contrast with other lints or even go against idiomatic rust.
+These lints should only be enabled on a lint-by-lint basis and with careful consideration.
+
+**Known problems:** None.
+
+**Example:**
+Bad:
+```rust
+#![deny(clippy::restriction)]
+```
+
+Good:
+```rust
+#![deny(clippy::as_conversions)]
+```",
+    },
+    LintInfo {
+        lint: crate::blocks_in_if_conditions::BLOCKS_IN_IF_CONDITIONS,
+        name: "blocks_in_if_conditions",
+        group: "style",
+        docs: r"**What it does:** Checks for `if` conditions that use blocks containing an
+expression, statements or conditions that use closures with blocks.
+
+**Why is this bad?** Style, using blocks in the condition makes it hard to read.
+
+**Known problems:** None.
+
+**Examples:**
+```rust
+// Bad
+if { true } { /* ... */ }
+
+// Good
+if true { /* ... */ }
+```
+
+// or
+
+```rust
+# fn somefunc() -> bool { true };
+// Bad
+if { let x = somefunc(); x } { /* ... */ }
+
+// Good
+let res = { let x = somefunc(); x };
+if res { /* ... */ }
+```",
+    },
+    LintInfo {
+        lint: crate::bool_assert_comparison::BOOL_ASSERT_COMPARISON,
+        name: "bool_assert_comparison",
+        group: "style",
+        docs: r#"**What it does:** This lint warns about boolean comparisons in assert-like macros.
+
+**Why is this bad?** It is shorter to use the equivalent.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+// Bad
+assert_eq!("a".is_empty(), false);
+assert_ne!("a".is_empty(), true);
+
+// Good
+assert!(!"a".is_empty());
+```"#,
+    },
+    LintInfo {
+        lint: crate::needless_bool::BOOL_COMPARISON,
+        name: "bool_comparison",
+        group: "complexity",
+        docs: r"**What it does:** Checks for expressions of the form `x == true`,
+`x != true` and order comparisons such as `x < true` (or vice versa) and
+suggest using the variable directly.
+
+**Why is this bad?** Unnecessary code.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+if x == true {}
+if y == false {}
+```
+use `x` directly:
+```rust,ignore
+if x {}
+if !y {}
+```",
+    },
+    LintInfo {
+        lint: crate::non_copy_const::BORROW_INTERIOR_MUTABLE_CONST,
+        name: "borrow_interior_mutable_const",
+        group: "style",
+        docs: r"**What it does:** Checks if `const` items which is interior mutable (e.g.,
+contains a `Cell`, `Mutex`, `AtomicXxxx`, etc.) has been borrowed directly.
+
+**Why is this bad?** Consts are copied everywhere they are referenced, i.e.,
+every time you refer to the const a fresh instance of the `Cell` or `Mutex`
+or `AtomicXxxx` will be created, which defeats the whole purpose of using
+these types in the first place.
+
+The `const` value should be stored inside a `static` item.
+
+**Known problems:** When an enum has variants with interior mutability, use of its non
+interior mutable variants can generate false positives. See issue
+[#3962](https://github.com/rust-lang/rust-clippy/issues/3962)
+
+Types that have underlying or potential interior mutability trigger the lint whether
+the interior mutable field is used or not. See issues
+[#5812](https://github.com/rust-lang/rust-clippy/issues/5812) and
+[#3825](https://github.com/rust-lang/rust-clippy/issues/3825)
+
+**Example:**
+```rust
+use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+const CONST_ATOM: AtomicUsize = AtomicUsize::new(12);
+
+// Bad.
+CONST_ATOM.store(6, SeqCst); // the content of the atomic is unchanged
+assert_eq!(CONST_ATOM.load(SeqCst), 12); // because the CONST_ATOM in these lines are distinct
+
+// Good.
+static STATIC_ATOM: AtomicUsize = CONST_ATOM;
+STATIC_ATOM.store(9, SeqCst);
+assert_eq!(STATIC_ATOM.load(SeqCst), 9); // use a `static` item to refer to the same instance
+```",
+    },
+    LintInfo {
+        lint: crate::types::BORROWED_BOX,
+        name: "borrowed_box",
+        group: "complexity",
+        docs: r"**What it does:** Checks for use of `&Box<T>` anywhere in the code.
+Check the [Box documentation](https://doc.rust-lang.org/std/boxed/index.html) for more information.
+
+**Why is this bad?** Any `&Box<T>` can also be a `&T`, which is more
+general.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+fn foo(bar: &Box<T>) { ... }
+```
+
+Better:
+
+```rust,ignore
+fn foo(bar: &T) { ... }
+```",
+    },
+    LintInfo {
+        lint: crate::types::BOX_VEC,
+        name: "box_vec",
+        group: "perf",
+        docs: r"**What it does:** Checks for use of `Box<Vec<_>>` anywhere in the code.
+Check the [Box documentation](https://doc.rust-lang.org/std/boxed/index.html) for more information.
+
+**Why is this bad?** `Vec` already keeps its contents in a separate area on
+the heap. So if you `Box` it, you just add another level of indirection
+without any benefit whatsoever.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+struct X {
+    values: Box<Vec<Foo>>,
+}
+```
+
+Better:
+
+```rust,ignore
+struct X {
+    values: Vec<Foo>,
+}
+```",
+    },
+    LintInfo {
+        lint: crate::escape::BOXED_LOCAL,
+        name: "boxed_local",
+        group: "perf",
+        docs: r#"**What it does:** Checks for usage of `Box<T>` where an unboxed `T` would
+work fine.
+
+**Why is this bad?** This is an unnecessary allocation, and bad for
+performance. It is only necessary to allocate if you wish to move the box
+into something.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# fn foo(bar: usize) {}
+// Bad
+let x = Box::new(1);
+foo(*x);
+println!("{}", *x);
+
+// Good
+let x = 1;
+foo(x);
+println!("{}", x);
+```"#,
+    },
+    LintInfo {
+        lint: crate::copies::BRANCHES_SHARING_CODE,
+        name: "branches_sharing_code",
+        group: "complexity",
+        docs: r#"**What it does:** Checks if the `if` and `else` block contain shared code that can be
+moved out of the blocks.
+
+**Why is this bad?** Duplicate code is less maintainable.
+
+**Known problems:** Hopefully none.
+
+**Example:**
+```ignore
+let foo = if … {
+    println!("Hello World");
+    13
+} else {
+    println!("Hello World");
+    42
+};
+```
+
+Could be written as:
+```ignore
+println!("Hello World");
+let foo = if … {
+    13
+} else {
+    42
+};
+```"#,
+    },
+    LintInfo {
+        lint: crate::misc_early::BUILTIN_TYPE_SHADOW,
+        name: "builtin_type_shadow",
+        group: "style",
+        docs: r"**What it does:** Warns if a generic shadows a built-in type.
+
+**Why is this bad?** This gives surprising type errors.
+
+**Known problems:** None.
+
+**Example:**
+
+```ignore
+impl<u32> Foo<u32> {
+    fn impl_func(&self) -> u32 {
+        42
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::methods::BYTES_NTH,
+        name: "bytes_nth",
+        group: "style",
+        docs: r#"**What it does:** Checks for the use of `.bytes().nth()`.
+
+**Why is this bad?** `.as_bytes().get()` is more efficient and more
+readable.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+// Bad
+let _ = "Hello".bytes().nth(3);
+
+// Good
+let _ = "Hello".as_bytes().get(3);
+```"#,
+    },
+    LintInfo {
+        lint: crate::cargo_common_metadata::CARGO_COMMON_METADATA,
+        name: "cargo_common_metadata",
+        group: "cargo",
+        docs: r#"**What it does:** Checks to see if all common metadata is defined in
+`Cargo.toml`. See: https://rust-lang-nursery.github.io/api-guidelines/documentation.html#cargotoml-includes-all-common-metadata-c-metadata
+
+**Why is this bad?** It will be more difficult for users to discover the
+purpose of the crate, and key information related to it.
+
+**Known problems:** None.
+
+**Example:**
+```toml
+# This `Cargo.toml` is missing a description field:
+[package]
+name = "clippy"
+version = "0.0.212"
+repository = "https://github.com/rust-lang/rust-clippy"
+readme = "README.md"
+license = "MIT OR Apache-2.0"
+keywords = ["clippy", "lint", "plugin"]
+categories = ["development-tools", "development-tools::cargo-plugins"]
+```
+
+Should include a description field like:
+
+```toml
+# This `Cargo.toml` includes all common metadata
+[package]
+name = "clippy"
+version = "0.0.212"
+description = "A bunch of helpful lints to avoid common pitfalls in Rust"
+repository = "https://github.com/rust-lang/rust-clippy"
+readme = "README.md"
+license = "MIT OR Apache-2.0"
+keywords = ["clippy", "lint", "plugin"]
+categories = ["development-tools", "development-tools::cargo-plugins"]
+```"#,
+    },
+    LintInfo {
+        lint: crate::case_sensitive_file_extension_comparisons::CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS,
+        name: "case_sensitive_file_extension_comparisons",
+        group: "pedantic",
+        docs: r#"**What it does:**
+Checks for calls to `ends_with` with possible file extensions
+and suggests to use a case-insensitive approach instead.
+
+**Why is this bad?**
+`ends_with` is case-sensitive and may not detect files with a valid extension.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+fn is_rust_file(filename: &str) -> bool {
+    filename.ends_with(".rs")
+}
+```
+Use instead:
+```rust
+fn is_rust_file(filename: &str) -> bool {
+    filename.rsplit('.').next().map(|ext| ext.eq_ignore_ascii_case("rs")) == Some(true)
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::casts::CAST_LOSSLESS,
+        name: "cast_lossless",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for casts between numerical types that may
+be replaced by safe conversion functions.
+
+**Why is this bad?** Rust's `as` keyword will perform many kinds of
+conversions, including silently lossy conversions. Conversion functions such
+as `i32::from` will only perform lossless conversions. Using the conversion
+functions prevents conversions from turning into silent lossy conversions if
+the types of the input expressions ever change, and make it easier for
+people reading the code to know that the conversion is lossless.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn as_u64(x: u8) -> u64 {
+    x as u64
+}
+```
+
+Using `::from` would look like this:
+
+```rust
+fn as_u64(x: u8) -> u64 {
+    u64::from(x)
+}
+```",
+    },
+    LintInfo {
+        lint: crate::casts::CAST_POSSIBLE_TRUNCATION,
+        name: "cast_possible_truncation",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for casts between numerical types that may
+truncate large values. This is expected behavior, so the cast is `Allow` by
+default.
+
+**Why is this bad?** In some problem domains, it is good practice to avoid
+truncation. This lint can be activated to help assess where additional
+checks could be beneficial.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn as_u8(x: u64) -> u8 {
+    x as u8
+}
+```",
+    },
+    LintInfo {
+        lint: crate::casts::CAST_POSSIBLE_WRAP,
+        name: "cast_possible_wrap",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for casts from an unsigned type to a signed type of
+the same size. Performing such a cast is a 'no-op' for the compiler,
+i.e., nothing is changed at the bit level, and the binary representation of
+the value is reinterpreted. This can cause wrapping if the value is too big
+for the target signed type. However, the cast works as defined, so this lint
+is `Allow` by default.
+
+**Why is this bad?** While such a cast is not bad in itself, the results can
+be surprising when this is not the intended behavior, as demonstrated by the
+example below.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+u32::MAX as i32; // will yield a value of `-1`
+```",
+    },
+    LintInfo {
+        lint: crate::casts::CAST_PRECISION_LOSS,
+        name: "cast_precision_loss",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for casts from any numerical to a float type where
+the receiving type cannot store all values from the original type without
+rounding errors. This possible rounding is to be expected, so this lint is
+`Allow` by default.
+
+Basically, this warns on casting any integer with 32 or more bits to `f32`
+or any 64-bit integer to `f64`.
+
+**Why is this bad?** It's not bad at all. But in some applications it can be
+helpful to know where precision loss can take place. This lint can help find
+those places in the code.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = u64::MAX;
+x as f64;
+```",
+    },
+    LintInfo {
+        lint: crate::casts::CAST_PTR_ALIGNMENT,
+        name: "cast_ptr_alignment",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for casts, using `as` or `pointer::cast`,
+from a less-strictly-aligned pointer to a more-strictly-aligned pointer
+
+**Why is this bad?** Dereferencing the resulting pointer may be undefined
+behavior.
+
+**Known problems:** Using `std::ptr::read_unaligned` and `std::ptr::write_unaligned` or similar
+on the resulting pointer is fine. Is over-zealous: Casts with manual alignment checks or casts like
+u64-> u8 -> u16 can be fine. Miri is able to do a more in-depth analysis.
+
+**Example:**
+```rust
+let _ = (&1u8 as *const u8) as *const u16;
+let _ = (&mut 1u8 as *mut u8) as *mut u16;
+
+(&1u8 as *const u8).cast::<u16>();
+(&mut 1u8 as *mut u8).cast::<u16>();
+```",
+    },
+    LintInfo {
+        lint: crate::casts::CAST_REF_TO_MUT,
+        name: "cast_ref_to_mut",
+        group: "correctness",
+        docs: r"**What it does:** Checks for casts of `&T` to `&mut T` anywhere in the code.
+
+**Why is this bad?** It’s basically guaranteed to be undefined behaviour.
+`UnsafeCell` is the only way to obtain aliasable data that is considered
+mutable.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+fn x(r: &i32) {
+    unsafe {
+        *(r as *const _ as *mut _) += 1;
+    }
+}
+```
+
+Instead consider using interior mutability types.
+
+```rust
+use std::cell::UnsafeCell;
+
+fn x(r: &UnsafeCell<i32>) {
+    unsafe {
+        *r.get() += 1;
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::casts::CAST_SIGN_LOSS,
+        name: "cast_sign_loss",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for casts from a signed to an unsigned numerical
+type. In this case, negative values wrap around to large positive values,
+which can be quite surprising in practice. However, as the cast works as
+defined, this lint is `Allow` by default.
+
+**Why is this bad?** Possibly surprising results. You can activate this lint
+as a one-time check to see where numerical wrapping can arise.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let y: i8 = -1;
+y as u128; // will return 18446744073709551615
+```",
+    },
+    LintInfo {
+        lint: crate::chained_indexing::CHAINED_INDEXING,
+        name: "chained_indexing",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for chained indexing of nested `Vec`s, slices and arrays, like
+`v[i][j]` on a `Vec<Vec<T>>`.
+
+**Why is this bad?** Every index of the chain can panic. Chaining `get` calls with
+`and_then` handles all of them in a single `Option`.
+
+**Known problems:** The suggested rewrite returns an `Option`, so the surrounding code
+usually needs to be adapted as well. Indexes that are known to be in bounds, e.g. because of
+an `if i < v.len()` guard, are not linted.
+
+**Example:**
+
+```rust
+let grid = vec![vec![0; 3]; 3];
+let (i, j) = (1, 2);
+
+// Bad
+let cell = &grid[i][j];
+
+// Good
+let cell = grid.get(i).and_then(|r| r.get(j));
+```",
+    },
+    LintInfo {
+        lint: crate::casts::CHAR_LIT_AS_U8,
+        name: "char_lit_as_u8",
+        group: "complexity",
+        docs: r"**What it does:** Checks for expressions where a character literal is cast
+to `u8` and suggests using a byte literal instead.
+
+**Why is this bad?** In general, casting values to smaller types is
+error-prone and should be avoided where possible. In the particular case of
+converting a character literal to u8, it is easy to avoid by just using a
+byte literal instead. As an added bonus, `b'a'` is even slightly shorter
+than `'a' as u8`.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+'x' as u8
+```
+
+A better version, using the byte literal:
+
+```rust,ignore
+b'x'
+```",
+    },
+    LintInfo {
+        lint: crate::methods::CHARS_LAST_CMP,
+        name: "chars_last_cmp",
+        group: "style",
+        docs: r#"**What it does:** Checks for usage of `_.chars().last()` or
+`_.chars().next_back()` on a `str` to check if it ends with a given char.
+
+**Why is this bad?** Readability, this can be written more concisely as
+`_.ends_with(_)`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let name = "_";
+
+// Bad
+name.chars().last() == Some('_') || name.chars().next_back() == Some('-');
+
+// Good
+name.ends_with('_') || name.ends_with('-');
+```"#,
+    },
+    LintInfo {
+        lint: crate::methods::CHARS_NEXT_CMP,
+        name: "chars_next_cmp",
+        group: "style",
+        docs: r#"**What it does:** Checks for usage of `.chars().next()` on a `str` to check
+if it starts with a given char.
+
+**Why is this bad?** Readability, this can be written more concisely as
+`_.starts_with(_)`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let name = "foo";
+if name.chars().next() == Some('_') {};
+```
+Could be written as
+```rust
+let name = "foo";
+if name.starts_with('_') {};
+```"#,
+    },
+    LintInfo {
+        lint: crate::loops::CHARS_NTH_LOOP,
+        name: "chars_nth_loop",
+        group: "perf",
+        docs: r#"**What it does:** Checks for `s.chars().nth(i)` in the body of a loop, where `i` depends
+on the loop variable.
+
+**Why is this bad?** `chars().nth(i)` decodes the string from its start up to the `i`th
+character, so calling it on each iteration takes quadratic time. Strings can't be indexed
+by characters like in some other languages.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let s = "hello";
+for i in 0..s.chars().count() {
+    let c = s.chars().nth(i).unwrap();
+    println!("{}: {}", i, c);
+}
+```
+Use instead:
+```rust
+let s = "hello";
+for (i, c) in s.chars().enumerate() {
+    println!("{}: {}", i, c);
+}
+// or, if the characters are accessed in any order
+let chars: Vec<char> = s.chars().collect();
+for i in 0..chars.len() {
+    println!("{}: {}", i, chars[i]);
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::checked_conversions::CHECKED_CONVERSIONS,
+        name: "checked_conversions",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for explicit bounds checking when casting.
+
+**Why is this bad?** Reduces the readability of statements & is error prone.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let foo: u32 = 5;
+# let _ =
+foo <= i32::MAX as u32
+# ;
+```
+
+Could be written:
+
+```rust
+# use std::convert::TryFrom;
+# let foo = 1;
+# let _ =
+i32::try_from(foo).is_ok()
+# ;
+```",
+    },
+    LintInfo {
+        lint: crate::methods::CLONE_DOUBLE_REF,
+        name: "clone_double_ref",
+        group: "correctness",
+        docs: r#"**What it does:** Checks for usage of `.clone()` on an `&&T`.
+
+**Why is this bad?** Cloning an `&&T` copies the inner `&T`, instead of
+cloning the underlying `T`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn main() {
+    let x = vec![1];
+    let y = &&x;
+    let z = y.clone();
+    println!("{:p} {:p}", *y, z); // prints out the same pointer
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::methods::CLONE_ON_COPY,
+        name: "clone_on_copy",
+        group: "complexity",
+        docs: r"**What it does:** Checks for usage of `.clone()` on a `Copy` type.
+
+**Why is this bad?** The only reason `Copy` types implement `Clone` is for
+generics, not for using the `clone` method on a concrete type.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+42u64.clone();
+```",
+    },
+    LintInfo {
+        lint: crate::methods::CLONE_ON_REF_PTR,
+        name: "clone_on_ref_ptr",
+        group: "restriction",
+        docs: r"**What it does:** Checks for usage of `.clone()` on a ref-counted pointer,
+(`Rc`, `Arc`, `rc::Weak`, or `sync::Weak`), and suggests calling Clone via unified
+function syntax instead (e.g., `Rc::clone(foo)`).
+
+**Why is this bad?** Calling '.clone()' on an Rc, Arc, or Weak
+can obscure the fact that only the pointer is being cloned, not the underlying
+data.
+
+**Example:**
+```rust
+# use std::rc::Rc;
+let x = Rc::new(1);
+
+// Bad
+x.clone();
+
+// Good
+Rc::clone(&x);
+```",
+    },
+    LintInfo {
+        lint: crate::methods::CLONED_INSTEAD_OF_COPIED,
+        name: "cloned_instead_of_copied",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for usages of `cloned()` on an `Iterator` or `Option` where
+`copied()` could be used instead.
+
+**Why is this bad?** `copied()` is better because it guarantees that the type being cloned
+implements `Copy`.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+[1, 2, 3].iter().cloned();
+```
+Use instead:
+```rust
+[1, 2, 3].iter().copied();
+```",
+    },
+    LintInfo {
+        lint: crate::misc::CMP_NAN,
+        name: "cmp_nan",
+        group: "correctness",
+        docs: r"**What it does:** Checks for comparisons to NaN.
+
+**Why is this bad?** NaN does not compare meaningfully to anything – not
+even itself – so those comparisons are simply wrong.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let x = 1.0;
+
+// Bad
+if x == f32::NAN { }
+
+// Good
+if x.is_nan() { }
+```",
+    },
+    LintInfo {
+        lint: crate::ptr::CMP_NULL,
+        name: "cmp_null",
+        group: "style",
+        docs: r"**What it does:** This lint checks for equality comparisons with `ptr::null`
+
+**Why is this bad?** It's easier and more readable to use the inherent
+`.is_null()`
+method instead
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+// Bad
+if x == ptr::null {
+    ..
+}
+
+// Good
+if x.is_null() {
+    ..
+}
+```",
+    },
+    LintInfo {
+        lint: crate::misc::CMP_OWNED,
+        name: "cmp_owned",
+        group: "perf",
+        docs: r#"**What it does:** Checks for conversions to owned values just for the sake
+of a comparison.
+
+**Why is this bad?** The comparison can operate on a reference, so creating
+an owned value effectively throws it away directly afterwards, which is
+needlessly consuming code and heap space.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let x = "foo";
+# let y = String::from("foo");
+if x.to_owned() == y {}
+```
+Could be written as
+```rust
+# let x = "foo";
+# let y = String::from("foo");
+if x == y {}
+```"#,
+    },
+    LintInfo {
+        lint: crate::cognitive_complexity::COGNITIVE_COMPLEXITY,
+        name: "cognitive_complexity",
+        group: "nursery",
+        docs: r"**What it does:** Checks for methods with high cognitive complexity.
+
+**Why is this bad?** Methods of high cognitive complexity tend to be hard to
+both read and maintain. Also LLVM will tend to optimize small methods better.
+
+**Known problems:** Sometimes it's hard to find a way to reduce the
+complexity.
+
+**Example:** No. You'll see it when you get the warning.",
+    },
+    LintInfo {
+        lint: crate::collapsible_if::COLLAPSIBLE_ELSE_IF,
+        name: "collapsible_else_if",
+        group: "style",
+        docs: r"**What it does:** Checks for collapsible `else { if ... }` expressions
+that can be collapsed to `else if ...`.
+
+**Why is this bad?** Each `if`-statement adds one level of nesting, which
+makes code look more complex than it really is.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+
+if x {
+    …
+} else {
+    if y {
+        …
+    }
+}
+```
+
+Should be written:
+
+```rust.ignore
+if x {
+    …
+} else if y {
+    …
+}
+```",
+    },
+    LintInfo {
+        lint: crate::collapsible_if::COLLAPSIBLE_IF,
+        name: "collapsible_if",
+        group: "style",
+        docs: r"**What it does:** Checks for nested `if` statements which can be collapsed
+by `&&`-combining their conditions.
+
+**Why is this bad?** Each `if`-statement adds one level of nesting, which
+makes code look more complex than it really is.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+if x {
+    if y {
+        …
+    }
+}
+
+```
+
+Should be written:
+
+```rust.ignore
+if x && y {
+    …
+}
+```",
+    },
+    LintInfo {
+        lint: crate::collapsible_match::COLLAPSIBLE_MATCH,
+        name: "collapsible_match",
+        group: "style",
+        docs: r#"**What it does:** Finds nested `match` or `if let` expressions where the patterns may be "collapsed" together
+without adding any branches.
+
+Note that this lint is not intended to find _all_ cases where nested match patterns can be merged, but only
+cases where merging would most likely make the code more readable.
+
+**Why is this bad?** It is unnecessarily verbose and complex.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+fn func(opt: Option<Result<u64, String>>) {
+    let n = match opt {
+        Some(n) => match n {
+            Ok(n) => n,
+            _ => return,
+        }
+        None => return,
+    };
+}
+```
+Use instead:
+```rust
+fn func(opt: Option<Result<u64, String>>) {
+    let n = match opt {
+        Some(Ok(n)) => n,
+        _ => return,
+    };
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::indexing_slicing::COLLECT_THEN_INDEX,
+        name: "collect_then_index",
+        group: "perf",
+        docs: r#"**What it does:** Checks for indexing the `Vec` collected from an iterator with a single
+index, like `iter.collect::<Vec<_>>()[0]`.
+
+**Why is this bad?** Collecting allocates a `Vec` and consumes the whole iterator to keep a
+single element, and the indexing panics if the iterator is too short. `next()` and `nth(n)`
+stop at the element and return `None` if there is none.
+
+**Known problems:** The iterator isn't consumed past the element anymore, so the side
+effects of the closures of adapters like `map` don't run for the other elements.
+
+**Example:**
+```rust
+# let names = ["a", "b"];
+// Bad
+let first = names.iter().map(|name| name.len()).collect::<Vec<_>>()[0];
+
+// Good
+let first = names.iter().map(|name| name.len()).next().unwrap();
+```"#,
+    },
+    LintInfo {
+        lint: crate::comparison_chain::COMPARISON_CHAIN,
+        name: "comparison_chain",
+        group: "style",
+        docs: r"**What it does:** Checks comparison chains written with `if` that can be
+rewritten with `match` and `cmp`.
+
+**Why is this bad?** `if` is not guaranteed to be exhaustive and conditionals can get
+repetitive
+
+**Known problems:** The match statement may be slower due to the compiler
+not inlining the call to cmp. See issue [#5354](https://github.com/rust-lang/rust-clippy/issues/5354)
+
+**Example:**
+```rust,ignore
+# fn a() {}
+# fn b() {}
+# fn c() {}
+fn f(x: u8, y: u8) {
+    if x > y {
+        a()
+    } else if x < y {
+        b()
+    } else {
+        c()
+    }
+}
+```
+
+Could be written:
+
+```rust,ignore
+use std::cmp::Ordering;
+# fn a() {}
+# fn b() {}
+# fn c() {}
+fn f(x: u8, y: u8) {
+     match x.cmp(&y) {
+         Ordering::Greater => a(),
+         Ordering::Less => b(),
+         Ordering::Equal => c()
+     }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::len_zero::COMPARISON_TO_EMPTY,
+        name: "comparison_to_empty",
+        group: "style",
+        docs: r#"**What it does:** Checks for comparing to an empty slice such as `""` or `[]`,
+and suggests using `.is_empty()` where applicable.
+
+**Why is this bad?** Some structures can answer `.is_empty()` much faster
+than checking for equality. So it is good to get into the habit of using
+`.is_empty()`, and having it is cheap.
+Besides, it makes the intent clearer than a manual comparison in some contexts.
+
+**Known problems:** None.
+
+**Example:**
+
+```ignore
+if s == "" {
+    ..
+}
+
+if arr == [] {
+    ..
+}
+```
+Use instead:
+```ignore
+if s.is_empty() {
+    ..
+}
+
+if arr.is_empty() {
+    ..
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::indexing_slicing::CONST_FN_INDEXING,
+        name: "const_fn_indexing",
+        group: "correctness",
+        docs: r#"**What it does:** Checks for indexing and slicing in the body of a `const fn` that may
+panic, i.e. the cases `INDEXING_SLICING` reports.
+
+**Why is this bad?** A panic while evaluating a constant is a compile error, so the function
+can't be used in constants for the arguments that make it panic, and the error points at the
+constant instead of the function. The `get` methods aren't `const fn`, so the bounds have to
+be checked explicitly.
+
+**Known problems:** A function that is only called with valid indexes is reported as well.
+
+**Example:**
+```rust
+// Bad
+const fn nth_digit(i: usize) -> u8 {
+    b"0123456789"[i]
+}
+
+// Good
+const fn nth_digit(i: usize) -> u8 {
+    let digits = b"0123456789";
+    if i < digits.len() { digits[i] } else { b'?' }
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::indexing_slicing::CONSTRUCT_THEN_INDEX,
+        name: "construct_then_index",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for indexing and slicing a `Cow`, `Box`, `Rc` or `Arc` right where
+it is constructed, like `Cow::from(s)[a..b]` or `Box::new(array)[i]`.
+
+**Why is this bad?** The pointer only exists to be indexed, and if the index is out of
+bounds, the panic points at the temporary instead of the value it is constructed from.
+Binding the pointer and using `get` lets the code handle the error where the value is known.
+
+**Known problems:** Only calls of the constructors (`new`, `from`, `Cow::Borrowed` and
+`Cow::Owned`) are recognized, not conversions with `into()`.
+
+**Example:**
+```rust
+# use std::borrow::Cow;
+# let s = "abc";
+// Bad
+let prefix = &Cow::from(s)[..2];
+
+// Good
+let cow = Cow::from(s);
+let prefix = cow.get(..2);
+```"#,
+    },
+    LintInfo {
+        lint: crate::copy_iterator::COPY_ITERATOR,
+        name: "copy_iterator",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for types that implement `Copy` as well as
+`Iterator`.
+
+**Why is this bad?** Implicit copies can be confusing when working with
+iterator combinators.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+#[derive(Copy, Clone)]
+struct Countdown(u8);
+
+impl Iterator for Countdown {
+    // ...
+}
+
+let a: Vec<_> = my_iterator.take(1).collect();
+let b: Vec<_> = my_iterator.collect();
+```",
+    },
+    LintInfo {
+        lint: crate::create_dir::CREATE_DIR,
+        name: "create_dir",
+        group: "restriction",
+        docs: r#"**What it does:** Checks usage of `std::fs::create_dir` and suggest using `std::fs::create_dir_all` instead.
+
+**Why is this bad?** Sometimes `std::fs::create_dir` is mistakenly chosen over `std::fs::create_dir_all`.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+std::fs::create_dir("foo");
+```
+Use instead:
+```rust
+std::fs::create_dir_all("foo");
+```"#,
+    },
+    LintInfo {
+        lint: crate::transmute::CROSSPOINTER_TRANSMUTE,
+        name: "crosspointer_transmute",
+        group: "complexity",
+        docs: r"**What it does:** Checks for transmutes between a type `T` and `*T`.
+
+**Why is this bad?** It's easy to mistakenly transmute between a type and a
+pointer to that type.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+core::intrinsics::transmute(t) // where the result type is the same as
+                               // `*t` or `&t`'s
+```",
+    },
+    LintInfo {
+        lint: crate::dbg_macro::DBG_MACRO,
+        name: "dbg_macro",
+        group: "restriction",
+        docs: r"**What it does:** Checks for usage of dbg!() macro.
+
+**Why is this bad?** `dbg!` macro is intended as a debugging tool. It
+should not be in version control.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+// Bad
+dbg!(true)
+
+// Good
+true
+```",
+    },
+    LintInfo {
+        lint: crate::mutable_debug_assertion::DEBUG_ASSERT_INDEXING,
+        name: "debug_assert_indexing",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for indexing in `debug_assert!`, `debug_assert_eq!` and
+`debug_assert_ne!` macros that isn't done outside of them too, like `debug_assert!(v[i] > 0)`.
+
+**Why is this bad?** The indexing panics in debug builds when the index is out of bounds,
+but disappears with the assertion in release builds, so the two builds behave differently.
+Comparing the `Option` returned by `get` makes a missing element an explicit failure of the
+assertion.
+
+**Known problems:** Indexing an array with a constant index is only linted if it is out of
+bounds, since rustc checks it.
+
+**Example:**
+```rust,ignore
+// Bad
+debug_assert_eq!(v[i], 1);
+
+// Good
+debug_assert_eq!(v.get(i), Some(&1));
+```",
+    },
+    LintInfo {
+        lint: crate::mutable_debug_assertion::DEBUG_ASSERT_WITH_MUT_CALL,
+        name: "debug_assert_with_mut_call",
+        group: "nursery",
+        docs: r"**What it does:** Checks for function/method calls with a mutable
+parameter in `debug_assert!`, `debug_assert_eq!` and `debug_assert_ne!` macros.
+
+**Why is this bad?** In release builds `debug_assert!` macros are optimized out by the
+compiler.
+Therefore mutating something in a `debug_assert!` macro results in different behaviour
+between a release and debug build.
+
+**Known problems:** None
+
+**Example:**
+```rust,ignore
+debug_assert_eq!(vec![3].pop(), Some(3));
+// or
+fn take_a_mut_parameter(_: &mut u32) -> bool { unimplemented!() }
+debug_assert!(take_a_mut_parameter(&mut 5));
+```",
+    },
+    LintInfo {
+        lint: crate::literal_representation::DECIMAL_LITERAL_REPRESENTATION,
+        name: "decimal_literal_representation",
+        group: "restriction",
+        docs: r"**What it does:** Warns if there is a better representation for a numeric literal.
+
+**Why is this bad?** Especially for big powers of 2 a hexadecimal representation is more
+readable than a decimal representation.
+
+**Known problems:** None.
+
+**Example:**
+
+`255` => `0xFF`
+`65_535` => `0xFFFF`
+`4_042_322_160` => `0xF0F0_F0F0`",
+    },
+    LintInfo {
+        lint: crate::non_copy_const::DECLARE_INTERIOR_MUTABLE_CONST,
+        name: "declare_interior_mutable_const",
+        group: "style",
+        docs: r#"**What it does:** Checks for declaration of `const` items which is interior
+mutable (e.g., contains a `Cell`, `Mutex`, `AtomicXxxx`, etc.).
+
+**Why is this bad?** Consts are copied everywhere they are referenced, i.e.,
+every time you refer to the const a fresh instance of the `Cell` or `Mutex`
+or `AtomicXxxx` will be created, which defeats the whole purpose of using
+these types in the first place.
+
+The `const` should better be replaced by a `static` item if a global
+variable is wanted, or replaced by a `const fn` if a constructor is wanted.
+
+**Known problems:** A "non-constant" const item is a legacy way to supply an
+initialized value to downstream `static` items (e.g., the
+`std::sync::ONCE_INIT` constant). In this case the use of `const` is legit,
+and this lint should be suppressed.
+
+Even though the lint avoids triggering on a constant whose type has enums that have variants
+with interior mutability, and its value uses non interior mutable variants (see
+[#3962](https://github.com/rust-lang/rust-clippy/issues/3962) and
+[#3825](https://github.com/rust-lang/rust-clippy/issues/3825) for examples);
+it complains about associated constants without default values only based on its types;
+which might not be preferable.
+There're other enums plus associated constants cases that the lint cannot handle.
+
+Types that have underlying or potential interior mutability trigger the lint whether
+the interior mutable field is used or not. See issues
+[#5812](https://github.com/rust-lang/rust-clippy/issues/5812) and
+
+**Example:**
+```rust
+use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+
+// Bad.
+const CONST_ATOM: AtomicUsize = AtomicUsize::new(12);
+CONST_ATOM.store(6, SeqCst); // the content of the atomic is unchanged
+assert_eq!(CONST_ATOM.load(SeqCst), 12); // because the CONST_ATOM in these lines are distinct
+
+// Good.
+static STATIC_ATOM: AtomicUsize = AtomicUsize::new(15);
+STATIC_ATOM.store(9, SeqCst);
+assert_eq!(STATIC_ATOM.load(SeqCst), 9); // use a `static` item to refer to the same instance
+```"#,
+    },
+    LintInfo {
+        lint: crate::default_numeric_fallback::DEFAULT_NUMERIC_FALLBACK,
+        name: "default_numeric_fallback",
+        group: "restriction",
+        docs: r"**What it does:** Checks for usage of unconstrained numeric literals which may cause default numeric fallback in type
+inference.
+
+Default numeric fallback means that if numeric types have not yet been bound to concrete
+types at the end of type inference, then integer type is bound to `i32`, and similarly
+floating type is bound to `f64`.
+
+See [RFC0212](https://github.com/rust-lang/rfcs/blob/master/text/0212-restore-int-fallback.md) for more information about the fallback.
+
+**Why is this bad?** For those who are very careful about types, default numeric fallback
+can be a pitfall that cause unexpected runtime behavior.
+
+**Known problems:** This lint can only be allowed at the function level or above.
+
+**Example:**
+```rust
+let i = 10;
+let f = 1.23;
+```
+
+Use instead:
+```rust
+let i = 10i32;
+let f = 1.23f64;
+```",
+    },
+    LintInfo {
+        lint: crate::default::DEFAULT_TRAIT_ACCESS,
+        name: "default_trait_access",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for literal calls to `Default::default()`.
+
+**Why is this bad?** It's more clear to the reader to use the name of the type whose default is
+being gotten than the generic `Default`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+// Bad
+let s: String = Default::default();
+
+// Good
+let s = String::default();
+```",
+    },
+    LintInfo {
+        lint: crate::attrs::DEPRECATED_CFG_ATTR,
+        name: "deprecated_cfg_attr",
+        group: "complexity",
+        docs: r"**What it does:** Checks for `#[cfg_attr(rustfmt, rustfmt_skip)]` and suggests to replace it
+with `#[rustfmt::skip]`.
+
+**Why is this bad?** Since tool_attributes ([rust-lang/rust#44690](https://github.com/rust-lang/rust/issues/44690))
+are stable now, they should be used instead of the old `cfg_attr(rustfmt)` attributes.
+
+**Known problems:** This lint doesn't detect crate level inner attributes, because they get
+processed before the PreExpansionPass lints get executed. See
+[#3123](https://github.com/rust-lang/rust-clippy/pull/3123#issuecomment-422321765)
+
+**Example:**
+
+Bad:
+```rust
+#[cfg_attr(rustfmt, rustfmt_skip)]
+fn main() { }
+```
+
+Good:
+```rust
+#[rustfmt::skip]
+fn main() { }
+```",
+    },
+    LintInfo {
+        lint: crate::attrs::DEPRECATED_SEMVER,
+        name: "deprecated_semver",
+        group: "correctness",
+        docs: r#"**What it does:** Checks for `#[deprecated]` annotations with a `since`
+field that is not a valid semantic version.
+
+**Why is this bad?** For checking the version of the deprecation, it must be
+a valid semver. Failing that, the contained information is useless.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+#[deprecated(since = "forever")]
+fn something_else() { /* ... */ }
+```"#,
+    },
+    LintInfo {
+        lint: crate::reference::DEREF_ADDROF,
+        name: "deref_addrof",
+        group: "complexity",
+        docs: r"**What it does:** Checks for usage of `*&` and `*&mut` in expressions.
+
+**Why is this bad?** Immediately dereferencing a reference is no-op and
+makes the code less clear.
+
+**Known problems:** Multiple dereference/addrof pairs are not handled so
+the suggested fix for `x = **&&y` is `x = *&y`, which is still incorrect.
+
+**Example:**
+```rust,ignore
+// Bad
+let a = f(*&mut b);
+let c = *&d;
+
+// Good
+let a = f(b);
+let c = d;
+```",
+    },
+    LintInfo {
+        lint: crate::derive::DERIVE_HASH_XOR_EQ,
+        name: "derive_hash_xor_eq",
+        group: "correctness",
+        docs: r"**What it does:** Checks for deriving `Hash` but implementing `PartialEq`
+explicitly or vice versa.
+
+**Why is this bad?** The implementation of these traits must agree (for
+example for use with `HashMap`) so it’s probably a bad idea to use a
+default-generated `Hash` implementation with an explicitly defined
+`PartialEq`. In particular, the following must hold for any type:
+
+```text
+k1 == k2 ⇒ hash(k1) == hash(k2)
+```
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+#[derive(Hash)]
+struct Foo;
+
+impl PartialEq for Foo {
+    ...
+}
+```",
+    },
+    LintInfo {
+        lint: crate::derive::DERIVE_ORD_XOR_PARTIAL_ORD,
+        name: "derive_ord_xor_partial_ord",
+        group: "correctness",
+        docs: r"**What it does:** Checks for deriving `Ord` but implementing `PartialOrd`
+explicitly or vice versa.
+
+**Why is this bad?** The implementation of these traits must agree (for
+example for use with `sort`) so it’s probably a bad idea to use a
+default-generated `Ord` implementation with an explicitly defined
+`PartialOrd`. In particular, the following must hold for any type
+implementing `Ord`:
+
+```text
+k1.cmp(&k2) == k1.partial_cmp(&k2).unwrap()
+```
+
+**Known problems:** None.
+
+**Example:**
+
+```rust,ignore
+#[derive(Ord, PartialEq, Eq)]
+struct Foo;
+
+impl PartialOrd for Foo {
+    ...
+}
+```
+Use instead:
+```rust,ignore
+#[derive(PartialEq, Eq)]
+struct Foo;
+
+impl PartialOrd for Foo {
+    fn partial_cmp(&self, other: &Foo) -> Option<Ordering> {
+       Some(self.cmp(other))
+    }
+}
+
+impl Ord for Foo {
+    ...
+}
+```
+or, if you don't need a custom ordering:
+```rust,ignore
+#[derive(Ord, PartialOrd, PartialEq, Eq)]
+struct Foo;
+```",
+    },
+    LintInfo {
+        lint: crate::disallowed_method::DISALLOWED_METHOD,
+        name: "disallowed_method",
+        group: "nursery",
+        docs: r#"**What it does:** Denies the configured methods and functions in clippy.toml
+
+**Why is this bad?** Some methods are undesirable in certain contexts,
+and it's beneficial to lint for them as needed.
+
+**Known problems:** Currently, you must write each function as a
+fully-qualified path. This lint doesn't support aliases or reexported
+names; be aware that many types in `std` are actually reexports.
+
+For example, if you want to disallow `Duration::as_secs`, your clippy.toml
+configuration would look like
+`disallowed-methods = ["core::time::Duration::as_secs"]` and not
+`disallowed-methods = ["std::time::Duration::as_secs"]` as you might expect.
+
+**Example:**
+
+An example clippy.toml configuration:
+```toml
+# clippy.toml
+disallowed-methods = ["alloc::vec::Vec::leak", "std::time::Instant::now"]
+```
+
+```rust,ignore
+// Example code where clippy issues a warning
+let xs = vec![1, 2, 3, 4];
+xs.leak(); // Vec::leak is disallowed in the config.
+
+let _now = Instant::now(); // Instant::now is disallowed in the config.
+```
+
+Use instead:
+```rust,ignore
+// Example code which does not raise clippy warning
+let mut xs = Vec::new(); // Vec::new is _not_ disallowed in the config.
+xs.push(123); // Vec::push is _not_ disallowed in the config.
+```"#,
+    },
+    LintInfo {
+        lint: crate::eval_order_dependence::DIVERGING_SUB_EXPRESSION,
+        name: "diverging_sub_expression",
+        group: "complexity",
+        docs: r"**What it does:** Checks for diverging calls that are not match arms or
+statements.
+
+**Why is this bad?** It is often confusing to read. In addition, the
+sub-expression evaluation order for Rust is not well documented.
+
+**Known problems:** Someone might want to use `some_bool || panic!()` as a
+shorthand.
+
+**Example:**
+```rust,no_run
+# fn b() -> bool { true }
+# fn c() -> bool { true }
+let a = b() || panic!() || c();
+// `c()` is dead, `panic!()` is only called if `b()` returns `false`
+let x = (a, b, c, panic!());
+// can simply be replaced by `panic!()`
+```",
+    },
+    LintInfo {
+        lint: crate::doc::DOC_MARKDOWN,
+        name: "doc_markdown",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for the presence of `_`, `::` or camel-case words
+outside ticks in documentation.
+
+**Why is this bad?** *Rustdoc* supports markdown formatting, `_`, `::` and
+camel-case probably indicates some code which should be included between
+ticks. `_` can also be used for emphasis in markdown, this lint tries to
+consider that.
+
+**Known problems:** Lots of bad docs won’t be fixed, what the lint checks
+for is limited, and there are still false positives.
+
+In addition, when writing documentation comments, including `[]` brackets
+inside a link text would trip the parser. Therfore, documenting link with
+`[`SmallVec<[T; INLINE_CAPACITY]>`]` and then [`SmallVec<[T; INLINE_CAPACITY]>`]: SmallVec
+would fail.
+
+**Examples:**
+```rust
+/// Do something with the foo_bar parameter. See also
+/// that::other::module::foo.
+// ^ `foo_bar` and `that::other::module::foo` should be ticked.
+fn doit(foo_bar: usize) {}
+```
+
+```rust
+// Link text with `[]` brackets should be written as following:
+/// Consume the array and return the inner
+/// [`SmallVec<[T; INLINE_CAPACITY]>`][SmallVec].
+/// [SmallVec]: SmallVec
+fn main() {}
+```",
+    },
+    LintInfo {
+        lint: crate::double_comparison::DOUBLE_COMPARISONS,
+        name: "double_comparisons",
+        group: "complexity",
+        docs: r"**What it does:** Checks for double comparisons that could be simplified to a single expression.
+
+
+**Why is this bad?** Readability.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let x = 1;
+# let y = 2;
+if x == y || x < y {}
+```
+
+Could be written as:
+
+```rust
+# let x = 1;
+# let y = 2;
+if x <= y {}
+```",
+    },
+    LintInfo {
+        lint: crate::functions::DOUBLE_MUST_USE,
+        name: "double_must_use",
+        group: "style",
+        docs: r"**What it does:** Checks for a [`#[must_use]`] attribute without
+further information on functions and methods that return a type already
+marked as `#[must_use]`.
+
+[`#[must_use]`]: https://doc.rust-lang.org/reference/attributes/diagnostics.html#the-must_use-attribute
+
+**Why is this bad?** The attribute isn't needed. Not using the result
+will already be reported. Alternatively, one can add some text to the
+attribute to improve the lint message.
+
+**Known problems:** None.
+
+**Examples:**
+```rust
+#[must_use]
+fn double_must_use() -> Result<(), ()> {
+    unimplemented!();
+}
+```",
+    },
+    LintInfo {
+        lint: crate::misc_early::DOUBLE_NEG,
+        name: "double_neg",
+        group: "style",
+        docs: r"**What it does:** Detects expressions of the form `--x`.
+
+**Why is this bad?** It can mislead C/C++ programmers to think `x` was
+decremented.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let mut x = 3;
+--x;
+```",
+    },
+    LintInfo {
+        lint: crate::double_parens::DOUBLE_PARENS,
+        name: "double_parens",
+        group: "complexity",
+        docs: r"**What it does:** Checks for unnecessary double parentheses.
+
+**Why is this bad?** This makes code harder to read and might indicate a
+mistake.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+// Bad
+fn simple_double_parens() -> i32 {
+    ((0))
+}
+
+// Good
+fn simple_no_parens() -> i32 {
+    0
+}
+
+// or
+
+# fn foo(bar: usize) {}
+// Bad
+foo((0));
+
+// Good
+foo(0);
+```",
+    },
+    LintInfo {
+        lint: crate::drop_forget_ref::DROP_COPY,
+        name: "drop_copy",
+        group: "correctness",
+        docs: r"**What it does:** Checks for calls to `std::mem::drop` with a value
+that derives the Copy trait
+
+**Why is this bad?** Calling `std::mem::drop` [does nothing for types that
+implement Copy](https://doc.rust-lang.org/std/mem/fn.drop.html), since the
+value will be copied and moved into the function on invocation.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x: i32 = 42; // i32 implements Copy
+std::mem::drop(x) // A copy of x is passed to the function, leaving the
+                  // original unaffected
+```",
+    },
+    LintInfo {
+        lint: crate::drop_forget_ref::DROP_REF,
+        name: "drop_ref",
+        group: "correctness",
+        docs: r"**What it does:** Checks for calls to `std::mem::drop` with a reference
+instead of an owned value.
+
+**Why is this bad?** Calling `drop` on a reference will only drop the
+reference itself, which is a no-op. It will not call the `drop` method (from
+the `Drop` trait implementation) on the underlying referenced value, which
+is likely what was intended.
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+let mut lock_guard = mutex.lock();
+std::mem::drop(&lock_guard) // Should have been drop(lock_guard), mutex
+// still locked
+operation_that_requires_mutex_to_be_unlocked();
+```",
+    },
+    LintInfo {
+        lint: crate::misc_early::DUPLICATE_UNDERSCORE_ARGUMENT,
+        name: "duplicate_underscore_argument",
+        group: "style",
+        docs: r"**What it does:** Checks for function arguments having the similar names
+differing by an underscore.
+
+**Why is this bad?** It affects code readability.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+// Bad
+fn foo(a: i32, _a: i32) {}
+
+// Good
+fn bar(a: i32, _b: i32) {}
+```",
+    },
+    LintInfo {
+        lint: crate::duration_subsec::DURATION_SUBSEC,
+        name: "duration_subsec",
+        group: "complexity",
+        docs: r"**What it does:** Checks for calculation of subsecond microseconds or milliseconds
+from other `Duration` methods.
+
+**Why is this bad?** It's more concise to call `Duration::subsec_micros()` or
+`Duration::subsec_millis()` than to calculate them.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# use std::time::Duration;
+let dur = Duration::new(5, 0);
+
+// Bad
+let _micros = dur.subsec_nanos() / 1_000;
+let _millis = dur.subsec_nanos() / 1_000_000;
+
+// Good
+let _micros = dur.subsec_micros();
+let _millis = dur.subsec_millis();
+```",
+    },
+    LintInfo {
+        lint: crate::else_if_without_else::ELSE_IF_WITHOUT_ELSE,
+        name: "else_if_without_else",
+        group: "restriction",
+        docs: r"**What it does:** Checks for usage of if expressions with an `else if` branch,
+but without a final `else` branch.
+
+**Why is this bad?** Some coding guidelines require this (e.g., MISRA-C:2004 Rule 14.10).
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# fn a() {}
+# fn b() {}
+# let x: i32 = 1;
+if x.is_positive() {
+    a();
+} else if x.is_negative() {
+    b();
+}
+```
+
+Could be written:
+
+```rust
+# fn a() {}
+# fn b() {}
+# let x: i32 = 1;
+if x.is_positive() {
+    a();
+} else if x.is_negative() {
+    b();
+} else {
+    // We don't care about zero.
+}
+```",
+    },
+    LintInfo {
+        lint: crate::empty_enum::EMPTY_ENUM,
+        name: "empty_enum",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for `enum`s with no variants.
+
+As of this writing, the `never_type` is still a
+nightly-only experimental API. Therefore, this lint is only triggered
+if the `never_type` is enabled.
+
+**Why is this bad?** If you want to introduce a type which
+can't be instantiated, you should use `!` (the primitive type "never"),
+or a wrapper around it, because `!` has more extensive
+compiler support (type inference, etc...) and wrappers
+around it are the conventional way to define an uninhabited type.
+For further information visit [never type documentation](https://doc.rust-lang.org/std/primitive.never.html)
+
+
+**Known problems:** None.
+
+**Example:**
+
+Bad:
+```rust
+enum Test {}
+```
+
+Good:
+```rust
+#![feature(never_type)]
+
+struct Test(!);
+```"#,
+    },
+    LintInfo {
+        lint: crate::attrs::EMPTY_LINE_AFTER_OUTER_ATTR,
+        name: "empty_line_after_outer_attr",
+        group: "nursery",
+        docs: r"**What it does:** Checks for empty lines after outer attributes
+
+**Why is this bad?**
+Most likely the attribute was meant to be an inner attribute using a '!'.
+If it was meant to be an outer attribute, then the following item
+should not be separated by empty lines.
+
+**Known problems:** Can cause false positives.
+
+From the clippy side it's difficult to detect empty lines between an attributes and the
+following item because empty lines and comments are not part of the AST. The parsing
+currently works for basic cases but is not perfect.
+
+**Example:**
+```rust
+// Good (as inner attribute)
+#![allow(dead_code)]
+
+fn this_is_fine() { }
+
+// Bad
+#[allow(dead_code)]
+
+fn not_quite_good_code() { }
+
+// Good (as outer attribute)
+#[allow(dead_code)]
+fn this_is_fine_too() { }
+```",
+    },
+    LintInfo {
+        lint: crate::loops::EMPTY_LOOP,
+        name: "empty_loop",
+        group: "style",
+        docs: r"**What it does:** Checks for empty `loop` expressions.
+
+**Why is this bad?** These busy loops burn CPU cycles without doing
+anything. It is _almost always_ a better idea to `panic!` than to have
+a busy loop.
+
+If panicking isn't possible, think of the environment and either:
+  - block on something
+  - sleep the thread for some microseconds
+  - yield or pause the thread
+
+For `std` targets, this can be done with
+[`std::thread::sleep`](https://doc.rust-lang.org/std/thread/fn.sleep.html)
+or [`std::thread::yield_now`](https://doc.rust-lang.org/std/thread/fn.yield_now.html).
+
+For `no_std` targets, doing this is more complicated, especially because
+`#[panic_handler]`s can't panic. To stop/pause the thread, you will
+probably need to invoke some target-specific intrinsic. Examples include:
+  - [`x86_64::instructions::hlt`](https://docs.rs/x86_64/0.12.2/x86_64/instructions/fn.hlt.html)
+  - [`cortex_m::asm::wfi`](https://docs.rs/cortex-m/0.6.3/cortex_m/asm/fn.wfi.html)
+
+**Known problems:** None.
+
+**Example:**
+```no_run
+loop {}
+```",
+    },
+    LintInfo {
+        lint: crate::enum_clike::ENUM_CLIKE_UNPORTABLE_VARIANT,
+        name: "enum_clike_unportable_variant",
+        group: "correctness",
+        docs: r#"**What it does:** Checks for C-like enumerations that are
+`repr(isize/usize)` and have values that don't fit into an `i32`.
+
+**Why is this bad?** This will truncate the variant value on 32 bit
+architectures, but works fine on 64 bit.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# #[cfg(target_pointer_width = "64")]
+#[repr(usize)]
+enum NonPortable {
+    X = 0x1_0000_0000,
+    Y = 0,
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::wildcard_imports::ENUM_GLOB_USE,
+        name: "enum_glob_use",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for `use Enum::*`.
+
+**Why is this bad?** It is usually better style to use the prefixed name of
+an enumeration variant, rather than importing variants.
+
+**Known problems:** Old-style enumerations that prefix the variants are
+still around.
+
+**Example:**
+```rust,ignore
+// Bad
+use std::cmp::Ordering::*;
+foo(Less);
+
+// Good
+use std::cmp::Ordering;
+foo(Ordering::Less)
+```",
+    },
+    LintInfo {
+        lint: crate::enum_variants::ENUM_VARIANT_NAMES,
+        name: "enum_variant_names",
+        group: "style",
+        docs: r"**What it does:** Detects enumeration variants that are prefixed or suffixed
+by the same characters.
+
+**Why is this bad?** Enumeration variant names should specify their variant,
+not repeat the enumeration name.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+enum Cake {
+    BlackForestCake,
+    HummingbirdCake,
+    BattenbergCake,
+}
+```
+Could be written as:
+```rust
+enum Cake {
+    BlackForest,
+    Hummingbird,
+    Battenberg,
+}
+```",
+    },
+    LintInfo {
+        lint: crate::eq_op::EQ_OP,
+        name: "eq_op",
+        group: "correctness",
+        docs: r"**What it does:** Checks for equal operands to comparison, logical and
+bitwise, difference and division binary operators (`==`, `>`, etc., `&&`,
+`||`, `&`, `|`, `^`, `-` and `/`).
+
+**Why is this bad?** This is usually just a typo or a copy and paste error.
+
+**Known problems:** False negatives: We had some false positives regarding
+calls (notably [racer](https://github.com/phildawes/racer) had one instance
+of `x.pop() && x.pop()`), so we removed matching any function or method
+calls. We may introduce a list of known pure functions in the future.
+
+**Example:**
+```rust
+# let x = 1;
+if x + 1 == x + 1 {}
+```
+or
+```rust
+# let a = 3;
+# let b = 4;
+assert_eq!(a, a);
+```",
+    },
+    LintInfo {
+        lint: crate::erasing_op::ERASING_OP,
+        name: "erasing_op",
+        group: "correctness",
+        docs: r"**What it does:** Checks for erasing operations, e.g., `x * 0`.
+
+**Why is this bad?** The whole expression can be replaced by zero.
+This is most likely not the intended outcome and should probably be
+corrected
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = 1;
+0 / x;
+0 * x;
+x & 0;
+```",
+    },
+    LintInfo {
+        lint: crate::eval_order_dependence::EVAL_ORDER_DEPENDENCE,
+        name: "eval_order_dependence",
+        group: "complexity",
+        docs: r"**What it does:** Checks for a read and a write to the same variable where
+whether the read occurs before or after the write depends on the evaluation
+order of sub-expressions.
+
+**Why is this bad?** It is often confusing to read. In addition, the
+sub-expression evaluation order for Rust is not well documented.
+
+**Known problems:** Code which intentionally depends on the evaluation
+order, or which is correct for any evaluation order.
+
+**Example:**
+```rust
+let mut x = 0;
+
+// Bad
+let a = {
+    x = 1;
+    1
+} + x;
+// Unclear whether a is 1 or 2.
+
+// Good
+let tmp = {
+    x = 1;
+    1
+};
+let a = tmp + x;
+```",
+    },
+    LintInfo {
+        lint: crate::float_literal::EXCESSIVE_PRECISION,
+        name: "excessive_precision",
+        group: "style",
+        docs: r#"**What it does:** Checks for float literals with a precision greater
+than that supported by the underlying type.
+
+**Why is this bad?** Rust will truncate the literal silently.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+// Bad
+let v: f32 = 0.123_456_789_9;
+println!("{}", v); //  0.123_456_789
+
+// Good
+let v: f64 = 0.123_456_789_9;
+println!("{}", v); //  0.123_456_789_9
+```"#,
+    },
+    LintInfo {
+        lint: crate::exhaustive_items::EXHAUSTIVE_ENUMS,
+        name: "exhaustive_enums",
+        group: "restriction",
+        docs: r"**What it does:** Warns on any exported `enum`s that are not tagged `#[non_exhaustive]`
+
+**Why is this bad?** Exhaustive enums are typically fine, but a project which does
+not wish to make a stability commitment around exported enums may wish to
+disable them by default.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+enum Foo {
+    Bar,
+    Baz
+}
+```
+Use instead:
+```rust
+#[non_exhaustive]
+enum Foo {
+    Bar,
+    Baz
+}
+```",
+    },
+    LintInfo {
+        lint: crate::exhaustive_items::EXHAUSTIVE_STRUCTS,
+        name: "exhaustive_structs",
+        group: "restriction",
+        docs: r"**What it does:** Warns on any exported `structs`s that are not tagged `#[non_exhaustive]`
+
+**Why is this bad?** Exhaustive structs are typically fine, but a project which does
+not wish to make a stability commitment around exported structs may wish to
+disable them by default.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+struct Foo {
+    bar: u8,
+    baz: String,
+}
+```
+Use instead:
+```rust
+#[non_exhaustive]
+struct Foo {
+    bar: u8,
+    baz: String,
+}
+```",
+    },
+    LintInfo {
+        lint: crate::exit::EXIT,
+        name: "exit",
+        group: "restriction",
+        docs: r"**What it does:** `exit()`  terminates the program and doesn't provide a
+stack trace.
+
+**Why is this bad?** Ideally a program is terminated by finishing
+the main function.
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+std::process::exit(0)
+```",
+    },
+    LintInfo {
+        lint: crate::methods::EXPECT_FUN_CALL,
+        name: "expect_fun_call",
+        group: "perf",
+        docs: r#"**What it does:** Checks for calls to `.expect(&format!(...))`, `.expect(foo(..))`,
+etc., and suggests to use `unwrap_or_else` instead
+
+**Why is this bad?** The function will always be called.
+
+**Known problems:** If the function has side-effects, not calling it will
+change the semantics of the program, but you shouldn't rely on that anyway.
+
+**Example:**
+```rust
+# let foo = Some(String::new());
+# let err_code = "418";
+# let err_msg = "I'm a teapot";
+foo.expect(&format!("Err {}: {}", err_code, err_msg));
+```
+or
+```rust
+# let foo = Some(String::new());
+# let err_code = "418";
+# let err_msg = "I'm a teapot";
+foo.expect(format!("Err {}: {}", err_code, err_msg).as_str());
+```
+this can instead be written:
+```rust
+# let foo = Some(String::new());
+# let err_code = "418";
+# let err_msg = "I'm a teapot";
+foo.unwrap_or_else(|| panic!("Err {}: {}", err_code, err_msg));
+```"#,
+    },
+    LintInfo {
+        lint: crate::methods::EXPECT_USED,
+        name: "expect_used",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for `.expect()` calls on `Option`s and `Result`s.
+
+**Why is this bad?** Usually it is better to handle the `None` or `Err` case.
+Still, for a lot of quick-and-dirty code, `expect` is a good choice, which is why
+this lint is `Allow` by default.
+
+`result.expect()` will let the thread panic on `Err`
+values. Normally, you want to implement more sophisticated error handling,
+and propagate errors upwards with `?` operator.
+
+**Known problems:** None.
+
+**Examples:**
+```rust,ignore
+# let opt = Some(1);
+
+// Bad
+opt.expect("one");
+
+// Good
+let opt = Some(1);
+opt?;
+```
+
+// or
+
+```rust
+# let res: Result<usize, ()> = Ok(1);
+
+// Bad
+res.expect("one");
+
+// Good
+res?;
+# Ok::<(), ()>(())
+```"#,
+    },
+    LintInfo {
+        lint: crate::derive::EXPL_IMPL_CLONE_ON_COPY,
+        name: "expl_impl_clone_on_copy",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for explicit `Clone` implementations for `Copy`
+types.
+
+**Why is this bad?** To avoid surprising behaviour, these traits should
+agree and the behaviour of `Copy` cannot be overridden. In almost all
+situations a `Copy` type should have a `Clone` implementation that does
+nothing more than copy the object, which is what `#[derive(Copy, Clone)]`
+gets you.
+
+**Known problems:** Bounds of generic types are sometimes wrong: https://github.com/rust-lang/rust/issues/26925
+
+**Example:**
+```rust,ignore
+#[derive(Copy)]
+struct Foo;
+
+impl Clone for Foo {
+    // ..
+}
+```",
+    },
+    LintInfo {
+        lint: crate::loops::EXPLICIT_COUNTER_LOOP,
+        name: "explicit_counter_loop",
+        group: "complexity",
+        docs: r"**What it does:** Checks `for` loops over slices with an explicit counter
+and suggests the use of `.enumerate()`.
+
+**Why is it bad?** Using `.enumerate()` makes the intent more clear,
+declutters the code and may be faster in some instances.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let v = vec![1];
+# fn bar(bar: usize, baz: usize) {}
+let mut i = 0;
+for item in &v {
+    bar(i, *item);
+    i += 1;
+}
+```
+Could be written as
+```rust
+# let v = vec![1];
+# fn bar(bar: usize, baz: usize) {}
+for (i, item) in v.iter().enumerate() { bar(i, *item); }
+```",
+    },
+    LintInfo {
+        lint: crate::dereference::EXPLICIT_DEREF_METHODS,
+        name: "explicit_deref_methods",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for explicit `deref()` or `deref_mut()` method calls.
+
+**Why is this bad?** Dereferencing by `&*x` or `&mut *x` is clearer and more concise,
+when not part of a method chain.
+
+**Example:**
+```rust
+use std::ops::Deref;
+let a: &mut String = &mut String::from("foo");
+let b: &str = a.deref();
+```
+Could be written as:
+```rust
+let a: &mut String = &mut String::from("foo");
+let b = &*a;
+```
+
+This lint excludes
+```rust,ignore
+let _ = d.unwrap().deref();
+```"#,
+    },
+    LintInfo {
+        lint: crate::loops::EXPLICIT_INTO_ITER_LOOP,
+        name: "explicit_into_iter_loop",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for loops on `y.into_iter()` where `y` will do, and
+suggests the latter.
+
+**Why is this bad?** Readability.
+
+**Known problems:** None
+
+**Example:**
+```rust
+# let y = vec![1];
+// with `y` a `Vec` or slice:
+for x in y.into_iter() {
+    // ..
+}
+```
+can be rewritten to
+```rust
+# let y = vec![1];
+for x in y {
+    // ..
+}
+```",
+    },
+    LintInfo {
+        lint: crate::loops::EXPLICIT_ITER_LOOP,
+        name: "explicit_iter_loop",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for loops on `x.iter()` where `&x` will do, and
+suggests the latter.
+
+**Why is this bad?** Readability.
+
+**Known problems:** False negatives. We currently only warn on some known
+types.
+
+**Example:**
+```rust
+// with `y` a `Vec` or slice:
+# let y = vec![1];
+for x in y.iter() {
+    // ..
+}
+```
+can be rewritten to
+```rust
+# let y = vec![1];
+for x in &y {
+    // ..
+}
+```",
+    },
+    LintInfo {
+        lint: crate::explicit_write::EXPLICIT_WRITE,
+        name: "explicit_write",
+        group: "complexity",
+        docs: r#"**What it does:** Checks for usage of `write!()` / `writeln()!` which can be
+replaced with `(e)print!()` / `(e)println!()`
+
+**Why is this bad?** Using `(e)println! is clearer and more concise
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# use std::io::Write;
+# let bar = "furchtbar";
+// this would be clearer as `eprintln!("foo: {:?}", bar);`
+writeln!(&mut std::io::stderr(), "foo: {:?}", bar).unwrap();
+```"#,
+    },
+    LintInfo {
+        lint: crate::lifetimes::EXTRA_UNUSED_LIFETIMES,
+        name: "extra_unused_lifetimes",
+        group: "complexity",
+        docs: r"**What it does:** Checks for lifetimes in generics that are never used
+anywhere else.
+
+**Why is this bad?** The additional lifetimes make the code look more
+complicated, while there is nothing out of the ordinary going on. Removing
+them leads to more readable code.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+// Bad: unnecessary lifetimes
+fn unused_lifetime<'a>(x: u8) {
+    // ..
+}
+
+// Good
+fn no_lifetime(x: u8) {
+    // ...
+}
+```",
+    },
+    LintInfo {
+        lint: crate::fallible_impl_from::FALLIBLE_IMPL_FROM,
+        name: "fallible_impl_from",
+        group: "nursery",
+        docs: r"**What it does:** Checks for impls of `From<..>` that contain `panic!()` or `unwrap()`
+
+**Why is this bad?** `TryFrom` should be used if there's a possibility of failure.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+struct Foo(i32);
+
+// Bad
+impl From<String> for Foo {
+    fn from(s: String) -> Self {
+        Foo(s.parse().unwrap())
+    }
+}
+```
+
+```rust
+// Good
+struct Foo(i32);
+
+use std::convert::TryFrom;
+impl TryFrom<String> for Foo {
+    type Error = ();
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        if let Ok(parsed) = s.parse() {
+            Ok(Foo(parsed))
+        } else {
+            Err(())
+        }
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::ffi_unchecked_index::FFI_UNCHECKED_INDEX,
+        name: "ffi_unchecked_index",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for indexing and slicing of a slice, an array, a `Vec` or a
+`Box<[T]>` with a value provided by the caller, without checking it against the length, in
+functions at the FFI boundary: functions with a foreign ABI, like `extern "C" fn`, and
+functions taking a raw pointer and an integer, like `fn sum(data: *const u32, len: usize)`.
+
+**Why is this bad?** The values passed by foreign code can't be trusted, and the indexing
+panics if they are out of bounds. A panic unwinding out of a function with a foreign ABI is
+undefined behavior. Validating the values first lets the function report an error to the
+caller instead.
+
+**Known problems:** The values provided by the caller are the parameters and the local
+variables computed from them, and only comparisons of the index with the length in the
+conditions guarding the indexing are recognized as validation.
+
+**Example:**
+
+```rust,ignore
+// Bad
+#[no_mangle]
+pub extern "C" fn nth(data: *const u32, len: usize, n: usize) -> u32 {
+    let data = unsafe { std::slice::from_raw_parts(data, len) };
+    data[n]
+}
+
+// Good
+#[no_mangle]
+pub extern "C" fn nth(data: *const u32, len: usize, n: usize) -> u32 {
+    let data = unsafe { std::slice::from_raw_parts(data, len) };
+    if n < data.len() { data[n] } else { 0 }
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::default::FIELD_REASSIGN_WITH_DEFAULT,
+        name: "field_reassign_with_default",
+        group: "style",
+        docs: r"**What it does:** Checks for immediate reassignment of fields initialized
+with Default::default().
+
+**Why is this bad?**It's more idiomatic to use the [functional update syntax](https://doc.rust-lang.org/reference/expressions/struct-expr.html#functional-update-syntax).
+
+**Known problems:** Assignments to patterns that are of tuple type are not linted.
+
+**Example:**
+Bad:
+```
+# #[derive(Default)]
+# struct A { i: i32 }
+let mut a: A = Default::default();
+a.i = 42;
+```
+Use instead:
+```
+# #[derive(Default)]
+# struct A { i: i32 }
+let a = A {
+    i: 42,
+    .. Default::default()
+};
+```",
+    },
+    LintInfo {
+        lint: crate::methods::FILETYPE_IS_FILE,
+        name: "filetype_is_file",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for `FileType::is_file()`.
+
+**Why is this bad?** When people testing a file type with `FileType::is_file`
+they are testing whether a path is something they can get bytes from. But
+`is_file` doesn't cover special file types in unix-like systems, and doesn't cover
+symlink in windows. Using `!FileType::is_dir()` is a better way to that intention.
+
+**Example:**
+
+```rust
+# || {
+let metadata = std::fs::metadata("foo.txt")?;
+let filetype = metadata.file_type();
+
+if filetype.is_file() {
+    // read file
+}
+# Ok::<_, std::io::Error>(())
+# };
+```
+
+should be written as:
+
+```rust
+# || {
+let metadata = std::fs::metadata("foo.txt")?;
+let filetype = metadata.file_type();
+
+if !filetype.is_dir() {
+    // read file
+}
+# Ok::<_, std::io::Error>(())
+# };
+```"#,
+    },
+    LintInfo {
+        lint: crate::methods::FILTER_MAP_IDENTITY,
+        name: "filter_map_identity",
+        group: "complexity",
+        docs: r"**What it does:** Checks for usage of `filter_map(|x| x)`.
+
+**Why is this bad?** Readability, this can be written more concisely by using `flatten`.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+# let iter = vec![Some(1)].into_iter();
+iter.filter_map(|x| x);
+```
+Use instead:
+```rust
+# let iter = vec![Some(1)].into_iter();
+iter.flatten();
+```",
+    },
+    LintInfo {
+        lint: crate::methods::FILTER_MAP_NEXT,
+        name: "filter_map_next",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for usage of `_.filter_map(_).next()`.
+
+**Why is this bad?** Readability, this can be written more concisely as
+`_.find_map(_)`.
+
+**Known problems:** None
+
+**Example:**
+```rust
+ (0..3).filter_map(|x| if x == 2 { Some(x) } else { None }).next();
+```
+Can be written as
+
+```rust
+ (0..3).find_map(|x| if x == 2 { Some(x) } else { None });
+```",
+    },
+    LintInfo {
+        lint: crate::methods::FILTER_NEXT,
+        name: "filter_next",
+        group: "complexity",
+        docs: r"**What it does:** Checks for usage of `_.filter(_).next()`.
+
+**Why is this bad?** Readability, this can be written more concisely as
+`_.find(_)`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let vec = vec![1];
+vec.iter().filter(|x| **x == 0).next();
+```
+Could be written as
+```rust
+# let vec = vec![1];
+vec.iter().find(|x| **x == 0);
+```",
+    },
+    LintInfo {
+        lint: crate::methods::FLAT_MAP_IDENTITY,
+        name: "flat_map_identity",
+        group: "complexity",
+        docs: r"**What it does:** Checks for usage of `flat_map(|x| x)`.
+
+**Why is this bad?** Readability, this can be written more concisely by using `flatten`.
+
+**Known problems:** None
+
+**Example:**
+```rust
+# let iter = vec![vec![0]].into_iter();
+iter.flat_map(|x| x);
+```
+Can be written as
+```rust
+# let iter = vec![vec![0]].into_iter();
+iter.flatten();
+```",
+    },
+    LintInfo {
+        lint: crate::methods::FLAT_MAP_OPTION,
+        name: "flat_map_option",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for usages of `Iterator::flat_map()` where `filter_map()` could be
+used instead.
+
+**Why is this bad?** When applicable, `filter_map()` is more clear since it shows that
+`Option` is used to produce 0 or 1 items.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+let nums: Vec<i32> = ["1", "2", "whee!"].iter().flat_map(|x| x.parse().ok()).collect();
+```
+Use instead:
+```rust
+let nums: Vec<i32> = ["1", "2", "whee!"].iter().filter_map(|x| x.parse().ok()).collect();
+```"#,
+    },
+    LintInfo {
+        lint: crate::arithmetic::FLOAT_ARITHMETIC,
+        name: "float_arithmetic",
+        group: "restriction",
+        docs: r"**What it does:** Checks for float arithmetic.
+
+**Why is this bad?** For some embedded systems or kernel development, it
+can be useful to rule out floating-point numbers.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let a = 0.0;
+a + 1.0;
+```",
+    },
+    LintInfo {
+        lint: crate::misc::FLOAT_CMP,
+        name: "float_cmp",
+        group: "correctness",
+        docs: r"**What it does:** Checks for (in-)equality comparisons on floating-point
+values (apart from zero), except in functions called `*eq*` (which probably
+implement equality for a type involving floats).
+
+**Why is this bad?** Floating point calculations are usually imprecise, so
+asking if two values are *exactly* equal is asking for trouble. For a good
+guide on what to do, see [the floating point
+guide](http://www.floating-point-gui.de/errors/comparison).
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = 1.2331f64;
+let y = 1.2332f64;
+
+// Bad
+if y == 1.23f64 { }
+if y != x {} // where both are floats
+
+// Good
+let error_margin = f64::EPSILON; // Use an epsilon for comparison
+// Or, if Rust <= 1.42, use `std::f64::EPSILON` constant instead.
+// let error_margin = std::f64::EPSILON;
+if (y - 1.23f64).abs() < error_margin { }
+if (y - x).abs() > error_margin { }
+```",
+    },
+    LintInfo {
+        lint: crate::misc::FLOAT_CMP_CONST,
+        name: "float_cmp_const",
+        group: "restriction",
+        docs: r"**What it does:** Checks for (in-)equality comparisons on floating-point
+value and constant, except in functions called `*eq*` (which probably
+implement equality for a type involving floats).
+
+**Why is this bad?** Floating point calculations are usually imprecise, so
+asking if two values are *exactly* equal is asking for trouble. For a good
+guide on what to do, see [the floating point
+guide](http://www.floating-point-gui.de/errors/comparison).
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x: f64 = 1.0;
+const ONE: f64 = 1.00;
+
+// Bad
+if x == ONE { } // where both are floats
+
+// Good
+let error_margin = f64::EPSILON; // Use an epsilon for comparison
+// Or, if Rust <= 1.42, use `std::f64::EPSILON` constant instead.
+// let error_margin = std::f64::EPSILON;
+if (x - ONE).abs() < error_margin { }
+```",
+    },
+    LintInfo {
+        lint: crate::float_equality_without_abs::FLOAT_EQUALITY_WITHOUT_ABS,
+        name: "float_equality_without_abs",
+        group: "correctness",
+        docs: r"**What it does:** Checks for statements of the form `(a - b) < f32::EPSILON` or
+`(a - b) < f64::EPSILON`. Notes the missing `.abs()`.
+
+**Why is this bad?** The code without `.abs()` is more likely to have a bug.
+
+**Known problems:** If the user can ensure that b is larger than a, the `.abs()` is
+technically unneccessary. However, it will make the code more robust and doesn't have any
+large performance implications. If the abs call was deliberately left out for performance
+reasons, it is probably better to state this explicitly in the code, which then can be done
+with an allow.
+
+**Example:**
+
+```rust
+pub fn is_roughly_equal(a: f32, b: f32) -> bool {
+    (a - b) < f32::EPSILON
+}
+```
+Use instead:
+```rust
+pub fn is_roughly_equal(a: f32, b: f32) -> bool {
+    (a - b).abs() < f32::EPSILON
+}
+```",
+    },
+    LintInfo {
+        lint: crate::unnamed_address::FN_ADDRESS_COMPARISONS,
+        name: "fn_address_comparisons",
+        group: "correctness",
+        docs: r"**What it does:** Checks for comparisons with an address of a function item.
+
+**Why is this bad?** Function item address is not guaranteed to be unique and could vary
+between different code generation units. Furthermore different function items could have
+the same address after being merged together.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+type F = fn();
+fn a() {}
+let f: F = a;
+if f == a {
+    // ...
+}
+```",
+    },
+    LintInfo {
+        lint: crate::excessive_bools::FN_PARAMS_EXCESSIVE_BOOLS,
+        name: "fn_params_excessive_bools",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for excessive use of
+bools in function definitions.
+
+**Why is this bad?** Calls to such functions
+are confusing and error prone, because it's
+hard to remember argument order and you have
+no type system support to back you up. Using
+two-variant enums instead of bools often makes
+API easier to use.
+
+**Known problems:** None.
+
+**Example:**
+Bad:
+```rust,ignore
+fn f(is_round: bool, is_hot: bool) { ... }
+```
+
+Good:
+```rust,ignore
+enum Shape {
+    Round,
+    Spiky,
+}
+
+enum Temperature {
+    Hot,
+    IceCold,
+}
+
+fn f(shape: Shape, temperature: Temperature) { ... }
+```",
+    },
+    LintInfo {
+        lint: crate::casts::FN_TO_NUMERIC_CAST,
+        name: "fn_to_numeric_cast",
+        group: "style",
+        docs: r"**What it does:** Checks for casts of function pointers to something other than usize
+
+**Why is this bad?**
+Casting a function pointer to anything other than usize/isize is not portable across
+architectures, because you end up losing bits if the target type is too small or end up with a
+bunch of extra bits that waste space and add more instructions to the final binary than
+strictly necessary for the problem
+
+Casting to isize also doesn't make sense since there are no signed addresses.
+
+**Example**
+
+```rust
+// Bad
+fn fun() -> i32 { 1 }
+let a = fun as i64;
+
+// Good
+fn fun2() -> i32 { 1 }
+let a = fun2 as usize;
+```",
+    },
+    LintInfo {
+        lint: crate::casts::FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
+        name: "fn_to_numeric_cast_with_truncation",
+        group: "style",
+        docs: r"**What it does:** Checks for casts of a function pointer to a numeric type not wide enough to
+store address.
+
+**Why is this bad?**
+Such a cast discards some bits of the function's address. If this is intended, it would be more
+clearly expressed by casting to usize first, then casting the usize to the intended type (with
+a comment) to perform the truncation.
+
+**Example**
+
+```rust
+// Bad
+fn fn1() -> i16 {
+    1
+};
+let _ = fn1 as i32;
+
+// Better: Cast to usize first, then comment with the reason for the truncation
+fn fn2() -> i16 {
+    1
+};
+let fn_ptr = fn2 as usize;
+let fn_ptr_truncated = fn_ptr as i32;
+```",
+    },
+    LintInfo {
+        lint: crate::loops::FOR_KV_MAP,
+        name: "for_kv_map",
+        group: "style",
+        docs: r"**What it does:** Checks for iterating a map (`HashMap` or `BTreeMap`) and
+ignoring either the keys or values.
+
+**Why is this bad?** Readability. There are `keys` and `values` methods that
+can be used to express that don't need the values or keys.
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+for (k, _) in &map {
+    ..
+}
+```
+
+could be replaced by
+
+```ignore
+for k in map.keys() {
+    ..
+}
+```",
+    },
+    LintInfo {
+        lint: crate::loops::FOR_LOOPS_OVER_FALLIBLES,
+        name: "for_loops_over_fallibles",
+        group: "correctness",
+        docs: r"**What it does:** Checks for `for` loops over `Option` or `Result` values.
+
+**Why is this bad?** Readability. This is more clearly expressed as an `if
+let`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let opt = Some(1);
+
+// Bad
+for x in opt {
+    // ..
+}
+
+// Good
+if let Some(x) = opt {
+    // ..
+}
+```
+
+// or
+
+```rust
+# let res: Result<i32, std::io::Error> = Ok(1);
+
+// Bad
+for x in &res {
+    // ..
+}
+
+// Good
+if let Ok(x) = res {
+    // ..
+}
+```",
+    },
+    LintInfo {
+        lint: crate::drop_forget_ref::FORGET_COPY,
+        name: "forget_copy",
+        group: "correctness",
+        docs: r"**What it does:** Checks for calls to `std::mem::forget` with a value that
+derives the Copy trait
+
+**Why is this bad?** Calling `std::mem::forget` [does nothing for types that
+implement Copy](https://doc.rust-lang.org/std/mem/fn.drop.html) since the
+value will be copied and moved into the function on invocation.
+
+An alternative, but also valid, explanation is that Copy types do not
+implement
+the Drop trait, which means they have no destructors. Without a destructor,
+there
+is nothing for `std::mem::forget` to ignore.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x: i32 = 42; // i32 implements Copy
+std::mem::forget(x) // A copy of x is passed to the function, leaving the
+                    // original unaffected
+```",
+    },
+    LintInfo {
+        lint: crate::drop_forget_ref::FORGET_REF,
+        name: "forget_ref",
+        group: "correctness",
+        docs: r"**What it does:** Checks for calls to `std::mem::forget` with a reference
+instead of an owned value.
+
+**Why is this bad?** Calling `forget` on a reference will only forget the
+reference itself, which is a no-op. It will not forget the underlying
+referenced
+value, which is likely what was intended.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = Box::new(1);
+std::mem::forget(&x) // Should have been forget(x), x will still be dropped
+```",
+    },
+    LintInfo {
+        lint: crate::methods::FROM_ITER_INSTEAD_OF_COLLECT,
+        name: "from_iter_instead_of_collect",
+        group: "style",
+        docs: r"**What it does:** Checks for `from_iter()` function calls on types that implement the `FromIterator`
+trait.
+
+**Why is this bad?** It is recommended style to use collect. See
+[FromIterator documentation](https://doc.rust-lang.org/std/iter/trait.FromIterator.html)
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+use std::iter::FromIterator;
+
+let five_fives = std::iter::repeat(5).take(5);
+
+let v = Vec::from_iter(five_fives);
+
+assert_eq!(v, vec![5, 5, 5, 5, 5]);
+```
+Use instead:
+```rust
+let five_fives = std::iter::repeat(5).take(5);
+
+let v: Vec<i32> = five_fives.collect();
+
+assert_eq!(v, vec![5, 5, 5, 5, 5]);
+```",
+    },
+    LintInfo {
+        lint: crate::from_over_into::FROM_OVER_INTO,
+        name: "from_over_into",
+        group: "style",
+        docs: r"**What it does:** Searches for implementations of the `Into<..>` trait and suggests to implement `From<..>` instead.
+
+**Why is this bad?** According the std docs implementing `From<..>` is preferred since it gives you `Into<..>` for free where the reverse isn't true.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+struct StringWrapper(String);
+
+impl Into<StringWrapper> for String {
+    fn into(self) -> StringWrapper {
+        StringWrapper(self)
+    }
+}
+```
+Use instead:
+```rust
+struct StringWrapper(String);
+
+impl From<String> for StringWrapper {
+    fn from(s: String) -> StringWrapper {
+        StringWrapper(s)
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::from_str_radix_10::FROM_STR_RADIX_10,
+        name: "from_str_radix_10",
+        group: "style",
+        docs: r"**What it does:**
+Checks for function invocations of the form `primitive::from_str_radix(s, 10)`
+
+**Why is this bad?**
+This specific common use case can be rewritten as `s.parse::<primitive>()`
+(and in most cases, the turbofish can be removed), which reduces code length
+and complexity.
+
+**Known problems:**
+This lint may suggest using (&<expression>).parse() instead of <expression>.parse() directly
+in some cases, which is correct but adds unnecessary complexity to the code.
+
+**Example:**
+
+```ignore
+let input: &str = get_input();
+let num = u16::from_str_radix(input, 10)?;
+```
+Use instead:
+```ignore
+let input: &str = get_input();
+let num: u16 = input.parse()?;
+```",
+    },
+    LintInfo {
+        lint: crate::future_not_send::FUTURE_NOT_SEND,
+        name: "future_not_send",
+        group: "nursery",
+        docs: r"**What it does:** This lint requires Future implementations returned from
+functions and methods to implement the `Send` marker trait. It is mostly
+used by library authors (public and internal) that target an audience where
+multithreaded executors are likely to be used for running these Futures.
+
+**Why is this bad?** A Future implementation captures some state that it
+needs to eventually produce its final value. When targeting a multithreaded
+executor (which is the norm on non-embedded devices) this means that this
+state may need to be transported to other threads, in other words the
+whole Future needs to implement the `Send` marker trait. If it does not,
+then the resulting Future cannot be submitted to a thread pool in the
+end user’s code.
+
+Especially for generic functions it can be confusing to leave the
+discovery of this problem to the end user: the reported error location
+will be far from its cause and can in many cases not even be fixed without
+modifying the library where the offending Future implementation is
+produced.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+async fn not_send(bytes: std::rc::Rc<[u8]>) {}
+```
+Use instead:
+```rust
+async fn is_send(bytes: std::sync::Arc<[u8]>) {}
+```",
+    },
+    LintInfo {
+        lint: crate::get_last_with_len::GET_LAST_WITH_LEN,
+        name: "get_last_with_len",
+        group: "complexity",
+        docs: r"**What it does:** Checks for using `x.get(x.len() - 1)` instead of
+`x.last()`.
+
+**Why is this bad?** Using `x.last()` is easier to read and has the same
+result.
+
+Note that using `x[x.len() - 1]` is semantically different from
+`x.last()`.  Indexing into the array will panic on out-of-bounds
+accesses, while `x.get()` and `x.last()` will return `None`.
+
+There is another lint (get_unwrap) that covers the case of using
+`x.get(index).unwrap()` instead of `x[index]`.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+// Bad
+let x = vec![2, 3, 5];
+let last_element = x.get(x.len() - 1);
+
+// Good
+let x = vec![2, 3, 5];
+let last_element = x.last();
+```",
+    },
+    LintInfo {
+        lint: crate::methods::GET_UNWRAP,
+        name: "get_unwrap",
+        group: "restriction",
+        docs: r"**What it does:** Checks for use of `.get().unwrap()` (or
+`.get_mut().unwrap`) on a standard library type which implements `Index`
+
+**Why is this bad?** Using the Index trait (`[]`) is more clear and more
+concise.
+
+**Known problems:** Not a replacement for error handling: Using either
+`.unwrap()` or the Index trait (`[]`) carries the risk of causing a `panic`
+if the value being accessed is `None`. If the use of `.get().unwrap()` is a
+temporary placeholder for dealing with the `Option` type, then this does
+not mitigate the need for error handling. If there is a chance that `.get()`
+will be `None` in your program, then it is advisable that the `None` case
+is handled in a future refactor instead of using `.unwrap()` or the Index
+trait.
+
+**Example:**
+```rust
+let mut some_vec = vec![0, 1, 2, 3];
+let last = some_vec.get(3).unwrap();
+*some_vec.get_mut(0).unwrap() = 1;
+```
+The correct use would be:
+```rust
+let mut some_vec = vec![0, 1, 2, 3];
+let last = some_vec[3];
+some_vec[0] = 1;
+```",
+    },
+    LintInfo {
+        lint: crate::methods::GET_UNWRAP_OR_CALL,
+        name: "get_unwrap_or_call",
+        group: "perf",
+        docs: r"**What it does:** Checks for `.get(i).unwrap_or(&default)` where `default` calls a
+function, like `v.get(i).unwrap_or(&compute())` or `v.get(i).unwrap_or(&fallback.clone())`.
+
+**Why is this bad?** The default is computed even if the element exists. Unlike with
+`or_fun_call`, `unwrap_or_else(|| &compute())` doesn't compile, as it would return a
+reference to a temporary.
+
+**Known problems:** Getting the element with `cloned` or `copied` changes the type of the
+result from a reference to a value.
+
+**Example:**
+```rust
+# let v = vec![String::new()];
+# let fallback = String::new();
+# let i = 0;
+# fn compute() -> String { String::new() }
+let a = v.get(i).unwrap_or(&compute());
+let b = v.get(i).unwrap_or(&fallback.clone());
+```
+Use instead:
+```rust
+# let v = vec![String::new()];
+# let fallback = String::new();
+# let i = 0;
+# fn compute() -> String { String::new() }
+let a = v.get(i).cloned().unwrap_or_else(compute);
+let b = v.get(i).unwrap_or(&fallback);
+```",
+    },
+    LintInfo {
+        lint: crate::identity_op::IDENTITY_OP,
+        name: "identity_op",
+        group: "complexity",
+        docs: r"**What it does:** Checks for identity operations, e.g., `x + 0`.
+
+**Why is this bad?** This code can be removed without changing the
+meaning. So it just obscures what's going on. Delete it mercilessly.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let x = 1;
+x / 1 + 0 * 1 - 0 | 0;
+```",
+    },
+    LintInfo {
+        lint: crate::if_let_mutex::IF_LET_MUTEX,
+        name: "if_let_mutex",
+        group: "correctness",
+        docs: r"**What it does:** Checks for `Mutex::lock` calls in `if let` expression
+with lock calls in any of the else blocks.
+
+**Why is this bad?** The Mutex lock remains held for the whole
+`if let ... else` block and deadlocks.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust,ignore
+if let Ok(thing) = mutex.lock() {
+    do_thing();
+} else {
+    mutex.lock();
+}
+```
+Should be written
+```rust,ignore
+let locked = mutex.lock();
+if let Ok(thing) = locked {
+    do_thing(thing);
+} else {
+    use_locked(locked);
+}
+```",
+    },
+    LintInfo {
+        lint: crate::if_let_some_result::IF_LET_SOME_RESULT,
+        name: "if_let_some_result",
+        group: "style",
+        docs: r"**What it does:*** Checks for unnecessary `ok()` in if let.
+
+**Why is this bad?** Calling `ok()` in if let is unnecessary, instead match
+on `Ok(pat)`
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+for i in iter {
+    if let Some(value) = i.parse().ok() {
+        vec.push(value)
+    }
+}
+```
+Could be written:
+
+```ignore
+for i in iter {
+    if let Ok(value) = i.parse() {
+        vec.push(value)
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::if_not_else::IF_NOT_ELSE,
+        name: "if_not_else",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for usage of `!` or `!=` in an if condition with an
+else branch.
+
+**Why is this bad?** Negations reduce the readability of statements.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let v: Vec<usize> = vec![];
+# fn a() {}
+# fn b() {}
+if !v.is_empty() {
+    a()
+} else {
+    b()
+}
+```
+
+Could be written:
+
+```rust
+# let v: Vec<usize> = vec![];
+# fn a() {}
+# fn b() {}
+if v.is_empty() {
+    b()
+} else {
+    a()
+}
+```",
+    },
+    LintInfo {
+        lint: crate::copies::IF_SAME_THEN_ELSE,
+        name: "if_same_then_else",
+        group: "correctness",
+        docs: r"**What it does:** Checks for `if/else` with the same body as the *then* part
+and the *else* part.
+
+**Why is this bad?** This is probably a copy & paste error.
+
+**Known problems:** Hopefully none.
+
+**Example:**
+```ignore
+let foo = if … {
+    42
+} else {
+    42
+};
+```",
+    },
+    LintInfo {
+        lint: crate::if_then_some_else_none::IF_THEN_SOME_ELSE_NONE,
+        name: "if_then_some_else_none",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for if-else that could be written to `bool::then`.
+
+**Why is this bad?** Looks a little redundant. Using `bool::then` helps it have less lines of code.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+# let v = vec![0];
+let a = if v.is_empty() {
+    println!("true!");
+    Some(42)
+} else {
+    None
+};
+```
+
+Could be written:
+
+```rust
+# let v = vec![0];
+let a = v.is_empty().then(|| {
+    println!("true!");
+    42
+});
+```"#,
+    },
+    LintInfo {
+        lint: crate::copies::IFS_SAME_COND,
+        name: "ifs_same_cond",
+        group: "correctness",
+        docs: r"**What it does:** Checks for consecutive `if`s with the same condition.
+
+**Why is this bad?** This is probably a copy & paste error.
+
+**Known problems:** Hopefully none.
+
+**Example:**
+```ignore
+if a == b {
+    …
+} else if a == b {
+    …
+}
+```
+
+Note that this lint ignores all conditions with a function call as it could
+have side effects:
+
+```ignore
+if foo() {
+    …
+} else if foo() { // not linted
+    …
+}
+```",
+    },
+    LintInfo {
+        lint: crate::methods::IMPLICIT_CLONE,
+        name: "implicit_clone",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for the usage of `_.to_owned()`, `vec.to_vec()`, or similar when calling `_.clone()` would be clearer.
+
+**Why is this bad?** These methods do the same thing as `_.clone()` but may be confusing as
+to why we are calling `to_vec` on something that is already a `Vec` or calling `to_owned` on something that is already owned.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+let a = vec![1, 2, 3];
+let b = a.to_vec();
+let c = a.to_owned();
+```
+Use instead:
+```rust
+let a = vec![1, 2, 3];
+let b = a.clone();
+let c = a.clone();
+```",
+    },
+    LintInfo {
+        lint: crate::implicit_hasher::IMPLICIT_HASHER,
+        name: "implicit_hasher",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for public `impl` or `fn` missing generalization
+over different hashers and implicitly defaulting to the default hashing
+algorithm (`SipHash`).
+
+**Why is this bad?** `HashMap` or `HashSet` with custom hashers cannot be
+used with them.
+
+**Known problems:** Suggestions for replacing constructors can contain
+false-positives. Also applying suggestions can require modification of other
+pieces of code, possibly including external crates.
+
+**Example:**
+```rust
+# use std::collections::HashMap;
+# use std::hash::{Hash, BuildHasher};
+# trait Serialize {};
+impl<K: Hash + Eq, V> Serialize for HashMap<K, V> { }
+
+pub fn foo(map: &mut HashMap<i32, i32>) { }
+```
+could be rewritten as
+```rust
+# use std::collections::HashMap;
+# use std::hash::{Hash, BuildHasher};
+# trait Serialize {};
+impl<K: Hash + Eq, V, S: BuildHasher> Serialize for HashMap<K, V, S> { }
+
+pub fn foo<S: BuildHasher>(map: &mut HashMap<i32, i32, S>) { }
+```",
+    },
+    LintInfo {
+        lint: crate::implicit_return::IMPLICIT_RETURN,
+        name: "implicit_return",
+        group: "restriction",
+        docs: r"**What it does:** Checks for missing return statements at the end of a block.
+
+**Why is this bad?** Actually omitting the return keyword is idiomatic Rust code. Programmers
+coming from other languages might prefer the expressiveness of `return`. It's possible to miss
+the last returning statement because the only difference is a missing `;`. Especially in bigger
+code with multiple return paths having a `return` keyword makes it easier to find the
+corresponding statements.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn foo(x: usize) -> usize {
+    x
+}
+```
+add return
+```rust
+fn foo(x: usize) -> usize {
+    return x;
+}
+```",
+    },
+    LintInfo {
+        lint: crate::implicit_saturating_sub::IMPLICIT_SATURATING_SUB,
+        name: "implicit_saturating_sub",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for implicit saturating subtraction.
+
+**Why is this bad?** Simplicity and readability. Instead we can easily use an builtin function.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+let end: u32 = 10;
+let start: u32 = 5;
+
+let mut i: u32 = end - start;
+
+// Bad
+if i != 0 {
+    i -= 1;
+}
+
+// Good
+i = i.saturating_sub(1);
+```",
+    },
+    LintInfo {
+        lint: crate::floating_point_arithmetic::IMPRECISE_FLOPS,
+        name: "imprecise_flops",
+        group: "nursery",
+        docs: r"**What it does:** Looks for floating-point expressions that
+can be expressed using built-in methods to improve accuracy
+at the cost of performance.
+
+**Why is this bad?** Negatively impacts accuracy.
+
+**Known problems:** None
+
+**Example:**
+
+```rust
+let a = 3f32;
+let _ = a.powf(1.0 / 3.0);
+let _ = (1.0 + a).ln();
+let _ = a.exp() - 1.0;
+```
+
+is better expressed as
+
+```rust
+let a = 3f32;
+let _ = a.cbrt();
+let _ = a.ln_1p();
+let _ = a.exp_m1();
+```",
+    },
+    LintInfo {
+        lint: crate::indexing_slicing::INCLUSIVE_RANGE_OUT_OF_BOUNDS,
+        name: "inclusive_range_out_of_bounds",
+        group: "correctness",
+        docs: r"**What it does:** Checks for slicing with an inclusive range `x[..=n]` (or `x[m..=n]`)
+whose end is past the end of the array, or is `usize::MAX`.
+
+**Why is this bad?** An inclusive range includes its end, so `x[..=n]` needs `n + 1`
+elements. Ending it at the length of the array is an off-by-one error, and ending it at
+`usize::MAX` always panics because `n + 1` overflows. Either way the slicing panics at
+runtime.
+
+**Known problems:** Hopefully none.
+
+**Example:**
+```no_run
+let x = [1, 2, 3, 4];
+
+// Bad
+&x[..=4];
+&x[1..=usize::MAX];
+
+// Good
+&x[..4];
+&x[..=3];
+&x[1..];
+```",
+    },
+    LintInfo {
+        lint: crate::literal_representation::INCONSISTENT_DIGIT_GROUPING,
+        name: "inconsistent_digit_grouping",
+        group: "style",
+        docs: r"**What it does:** Warns if an integral or floating-point constant is
+grouped inconsistently with underscores.
+
+**Why is this bad?** Readers may incorrectly interpret inconsistently
+grouped digits.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+// Bad
+let x: u64 = 618_64_9189_73_511;
+
+// Good
+let x: u64 = 61_864_918_973_511;
+```",
+    },
+    LintInfo {
+        lint: crate::inconsistent_struct_constructor::INCONSISTENT_STRUCT_CONSTRUCTOR,
+        name: "inconsistent_struct_constructor",
+        group: "style",
+        docs: r"**What it does:** Checks for struct constructors where all fields are shorthand and
+the order of the field init shorthand in the constructor is inconsistent
+with the order in the struct definition.
+
+**Why is this bad?** Since the order of fields in a constructor doesn't affect the
+resulted instance as the below example indicates,
+
+```rust
+#[derive(Debug, PartialEq, Eq)]
+struct Foo {
+    x: i32,
+    y: i32,
+}
+let x = 1;
+let y = 2;
+
+// This assertion never fails:
+assert_eq!(Foo { x, y }, Foo { y, x });
+```
+
+inconsistent order can be confusing and decreases readability and consistency.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+struct Foo {
+    x: i32,
+    y: i32,
+}
+let x = 1;
+let y = 2;
+
+Foo { y, x };
+```
+
+Use instead:
+```rust
+# struct Foo {
+#     x: i32,
+#     y: i32,
+# }
+# let x = 1;
+# let y = 2;
+Foo { x, y };
+```",
+    },
+    LintInfo {
+        lint: crate::indexing_slicing::INDEX_MAP,
+        name: "index_map",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for indexing a `HashMap` or `BTreeMap` with `map[&key]`.
+
+**Why is this bad?** Indexing panics if the key isn't in the map. `get` returns an
+`Option` instead, and the `entry` API inserts the missing keys.
+
+**Known problems:** The suggested `.get()` rewrite returns an `Option`, so the
+surrounding code usually needs to be adapted as well.
+
+**Example:**
+```rust,no_run
+# use std::collections::HashMap;
+let map: HashMap<&str, u32> = HashMap::new();
+
+// Bad
+let count = map["apples"];
+
+// Good
+let count = map.get("apples").copied().unwrap_or(0);
+```"#,
+    },
+    LintInfo {
+        lint: crate::indexing_slicing::INDEX_UNDERFLOW,
+        name: "index_underflow",
+        group: "restriction",
+        docs: r"**What it does:** Checks for subtractions of unsigned integers in indexes and slicing
+bounds, like `v[i - 1]` or `&v[len - n..]`, that aren't known to be guarded against
+underflow, e.g. by an `if i > 0 { .. }`.
+
+**Why is this bad?** If the subtrahend is larger, the subtraction panics in debug builds
+and wraps around to a huge index in release builds, which then panics as out of bounds.
+An off-by-one at the start of a collection is a common source of panics in production.
+
+**Known problems:** Only guards comparing the operands with constants or with the length
+of a collection (`if n <= v.len() { v[v.len() - n] }`) are recognized, so a subtraction
+guarded by a comparison of two variables is linted.
+
+**Example:**
+```rust
+# let v = vec![1, 2, 3];
+# let i = 1;
+// Bad
+let prev = v[i - 1];
+
+// Good
+let prev = i.checked_sub(1).and_then(|i| v.get(i));
+let prev = v[i.saturating_sub(1)];
+```",
+    },
+    LintInfo {
+        lint: crate::get_last_with_len::INDEXING_LAST_WITH_LEN,
+        name: "indexing_last_with_len",
+        group: "style",
+        docs: r"**What it does:** Checks for indexing with `x[x.len() - 1]` instead of using
+`x.last()`, including when the length or the index is stored in a binding first.
+
+**Why is this bad?** `x.last()` says what is meant. It also makes the empty case
+explicit: `x[x.len() - 1]` panics with an arithmetic overflow (or indexes out of
+bounds in release mode) if `x` is empty, while `x.last()` returns `None`.
+
+**Known problems:** The suggestion keeps the panic on empty collections by using
+`.unwrap()`. Consider handling the `None` case instead.
+
+**Example:**
+
+```rust
+let x = vec![2, 3, 5];
+
+// Bad
+let last_element = x[x.len() - 1];
+
+// Good
+let last_element = *x.last().unwrap();
+```",
+    },
+    LintInfo {
+        lint: crate::indexing_slicing::INDEXING_SLICING,
+        name: "indexing_slicing",
+        group: "restriction",
+        docs: r"**What it does:** Checks for usage of indexing or slicing. Arrays are special cases, this lint
+does report on arrays if we can tell that slicing operations are in bounds and does not
+lint on constant `usize` indexing on arrays because that is handled by rustc's `const_err` lint.
+Indexes that are known to be in bounds, like `x[i]` inside `if i < x.len() { .. }`, after
+`assert!(i < x.len())` or in a `for i in 0..x.len()` loop, are not linted either.
+Indexes that are reduced modulo the length, like `x[i % x.len()]` or
+`x[i & (x.len() - 1)]`, only panic if `x` is empty, so they are not linted if `x` is known
+not to be empty, e.g. inside `if !x.is_empty() { .. }`.
+Indexing a `HashMap` or `BTreeMap` is covered by `index_map` instead.
+Ranges stored in a binding first (`let r = 1..n; &x[r]`) and other values implementing
+`RangeBounds<usize>` are checked as slicing.
+Arrays up to the length configured with `indexing-slicing-max-array-size` aren't linted.
+
+**Why is this bad?** Indexing and slicing can panic at runtime and there are
+safe alternatives.
+
+**Known problems:** The suggested `.get()` rewrite returns an `Option`, so the
+surrounding code usually needs to be adapted as well.
+
+**Example:**
+```rust,no_run
+// Vector
+let x = vec![0; 5];
+
+// Bad
+x[2];
+&x[2..100];
+&x[2..];
+&x[..100];
+
+// Good
+x.get(2);
+x.get(2..100);
+x.get(2..);
+x.get(..100);
+
+// Array
+let y = [0, 1, 2, 3];
+
+// Bad
+&y[10..100];
+&y[10..];
+&y[..100];
+
+// Good
+&y[2..];
+&y[..2];
+&y[0..3];
+y.get(10);
+y.get(10..100);
+y.get(10..);
+y.get(..100);
+```",
+    },
+    LintInfo {
+        lint: crate::bit_mask::INEFFECTIVE_BIT_MASK,
+        name: "ineffective_bit_mask",
+        group: "correctness",
+        docs: r"**What it does:** Checks for bit masks in comparisons which can be removed
+without changing the outcome. The basic structure can be seen in the
+following table:
+
+|Comparison| Bit Op  |Example    |equals |
+|----------|---------|-----------|-------|
+|`>` / `<=`|`|` / `^`|`x | 2 > 3`|`x > 3`|
+|`<` / `>=`|`|` / `^`|`x ^ 1 < 4`|`x < 4`|
+
+**Why is this bad?** Not equally evil as [`bad_bit_mask`](#bad_bit_mask),
+but still a bit misleading, because the bit mask is ineffective.
+
+**Known problems:** False negatives: This lint will only match instances
+where we have figured out the math (which is for a power-of-two compared
+value). This means things like `x | 1 >= 7` (which would be better written
+as `x >= 6`) will not be reported (but bit masks like this are fairly
+uncommon).
+
+**Example:**
+```rust
+# let x = 1;
+if (x | 1 > 3) {  }
+```",
+    },
+    LintInfo {
+        lint: crate::methods::INEFFICIENT_TO_STRING,
+        name: "inefficient_to_string",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for usage of `.to_string()` on an `&&T` where
+`T` implements `ToString` directly (like `&&str` or `&&String`).
+
+**Why is this bad?** This bypasses the specialized implementation of
+`ToString` and instead goes through the more expensive string formatting
+facilities.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+// Generic implementation for `T: Display` is used (slow)
+["foo", "bar"].iter().map(|s| s.to_string());
+
+// OK, the specialized impl is used
+["foo", "bar"].iter().map(|&s| s.to_string());
+```"#,
+    },
+    LintInfo {
+        lint: crate::matches::INFALLIBLE_DESTRUCTURING_MATCH,
+        name: "infallible_destructuring_match",
+        group: "style",
+        docs: r"**What it does:** Checks for matches being used to destructure a single-variant enum
+or tuple struct where a `let` will suffice.
+
+**Why is this bad?** Just readability – `let` doesn't nest, whereas a `match` does.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+enum Wrapper {
+    Data(i32),
+}
+
+let wrapper = Wrapper::Data(42);
+
+let data = match wrapper {
+    Wrapper::Data(i) => i,
+};
+```
+
+The correct use would be:
+```rust
+enum Wrapper {
+    Data(i32),
+}
+
+let wrapper = Wrapper::Data(42);
+let Wrapper::Data(data) = wrapper;
+```",
+    },
+    LintInfo {
+        lint: crate::infinite_iter::INFINITE_ITER,
+        name: "infinite_iter",
+        group: "correctness",
+        docs: r"**What it does:** Checks for iteration that is guaranteed to be infinite.
+
+**Why is this bad?** While there may be places where this is acceptable
+(e.g., in event streams), in most cases this is simply an error.
+
+**Known problems:** None.
+
+**Example:**
+```no_run
+use std::iter;
+
+iter::repeat(1_u8).collect::<Vec<_>>();
+```",
+    },
+    LintInfo {
+        lint: crate::inherent_to_string::INHERENT_TO_STRING,
+        name: "inherent_to_string",
+        group: "style",
+        docs: r#"**What it does:** Checks for the definition of inherent methods with a signature of `to_string(&self) -> String`.
+
+**Why is this bad?** This method is also implicitly defined if a type implements the `Display` trait. As the functionality of `Display` is much more versatile, it should be preferred.
+
+**Known problems:** None
+
+** Example:**
+
+```rust
+// Bad
+pub struct A;
+
+impl A {
+    pub fn to_string(&self) -> String {
+        "I am A".to_string()
+    }
+}
+```
+
+```rust
+// Good
+use std::fmt;
+
+pub struct A;
+
+impl fmt::Display for A {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "I am A")
+    }
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::inherent_to_string::INHERENT_TO_STRING_SHADOW_DISPLAY,
+        name: "inherent_to_string_shadow_display",
+        group: "correctness",
+        docs: r#"**What it does:** Checks for the definition of inherent methods with a signature of `to_string(&self) -> String` and if the type implementing this method also implements the `Display` trait.
+
+**Why is this bad?** This method is also implicitly defined if a type implements the `Display` trait. The less versatile inherent method will then shadow the implementation introduced by `Display`.
+
+**Known problems:** None
+
+** Example:**
+
+```rust
+// Bad
+use std::fmt;
+
+pub struct A;
+
+impl A {
+    pub fn to_string(&self) -> String {
+        "I am A".to_string()
+    }
+}
+
+impl fmt::Display for A {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "I am A, too")
+    }
+}
+```
+
+```rust
+// Good
+use std::fmt;
+
+pub struct A;
+
+impl fmt::Display for A {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "I am A")
+    }
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::attrs::INLINE_ALWAYS,
+        name: "inline_always",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for items annotated with `#[inline(always)]`,
+unless the annotated function is empty or simply panics.
+
+**Why is this bad?** While there are valid uses of this annotation (and once
+you know when to use it, by all means `allow` this lint), it's a common
+newbie-mistake to pepper one's code with it.
+
+As a rule of thumb, before slapping `#[inline(always)]` on a function,
+measure if that additional function call really affects your runtime profile
+sufficiently to make up for the increase in compile time.
+
+**Known problems:** False positives, big time. This lint is meant to be
+deactivated by everyone doing serious performance work. This means having
+done the measurement.
+
+**Example:**
+```ignore
+#[inline(always)]
+fn not_quite_hot_code(..) { ... }
+```",
+    },
+    LintInfo {
+        lint: crate::asm_syntax::INLINE_ASM_X86_ATT_SYNTAX,
+        name: "inline_asm_x86_att_syntax",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for usage of AT&T x86 assembly syntax.
+
+**Why is this bad?** The lint has been enabled to indicate a preference
+for Intel x86 assembly syntax.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust,no_run
+# #![feature(asm)]
+# unsafe { let ptr = "".as_ptr();
+asm!("lea ({}), {}", in(reg) ptr, lateout(reg) _, options(att_syntax));
+# }
+```
+Use instead:
+```rust,no_run
+# #![feature(asm)]
+# unsafe { let ptr = "".as_ptr();
+asm!("lea {}, [{}]", lateout(reg) _, in(reg) ptr);
+# }
+```"#,
+    },
+    LintInfo {
+        lint: crate::asm_syntax::INLINE_ASM_X86_INTEL_SYNTAX,
+        name: "inline_asm_x86_intel_syntax",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for usage of Intel x86 assembly syntax.
+
+**Why is this bad?** The lint has been enabled to indicate a preference
+for AT&T x86 assembly syntax.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust,no_run
+# #![feature(asm)]
+# unsafe { let ptr = "".as_ptr();
+asm!("lea {}, [{}]", lateout(reg) _, in(reg) ptr);
+# }
+```
+Use instead:
+```rust,no_run
+# #![feature(asm)]
+# unsafe { let ptr = "".as_ptr();
+asm!("lea ({}), {}", in(reg) ptr, lateout(reg) _, options(att_syntax));
+# }
+```"#,
+    },
+    LintInfo {
+        lint: crate::inline_fn_without_body::INLINE_FN_WITHOUT_BODY,
+        name: "inline_fn_without_body",
+        group: "correctness",
+        docs: r"**What it does:** Checks for `#[inline]` on trait methods without bodies
+
+**Why is this bad?** Only implementations of trait methods may be inlined.
+The inline attribute is ignored for trait methods without bodies.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+trait Animal {
+    #[inline]
+    fn name(&self) -> &'static str;
+}
+```",
+    },
+    LintInfo {
+        lint: crate::methods::INSPECT_FOR_EACH,
+        name: "inspect_for_each",
+        group: "complexity",
+        docs: r#"**What it does:** Checks for usage of `inspect().for_each()`.
+
+**Why is this bad?** It is the same as performing the computation
+inside `inspect` at the beginning of the closure in `for_each`.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+[1,2,3,4,5].iter()
+.inspect(|&x| println!("inspect the number: {}", x))
+.for_each(|&x| {
+    assert!(x >= 0);
+});
+```
+Can be written as
+```rust
+[1,2,3,4,5].iter()
+.for_each(|&x| {
+    println!("inspect the number: {}", x);
+    assert!(x >= 0);
+});
+```"#,
+    },
+    LintInfo {
+        lint: crate::int_plus_one::INT_PLUS_ONE,
+        name: "int_plus_one",
+        group: "complexity",
+        docs: r"**What it does:** Checks for usage of `x >= y + 1` or `x - 1 >= y` (and `<=`) in a block
+
+**Why is this bad?** Readability -- better to use `> y` instead of `>= y + 1`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let x = 1;
+# let y = 1;
+if x >= y + 1 {}
+```
+
+Could be written as:
+
+```rust
+# let x = 1;
+# let y = 1;
+if x > y {}
+```",
+    },
+    LintInfo {
+        lint: crate::arithmetic::INTEGER_ARITHMETIC,
+        name: "integer_arithmetic",
+        group: "restriction",
+        docs: r"**What it does:** Checks for integer arithmetic operations which could overflow or panic.
+
+Specifically, checks for any operators (`+`, `-`, `*`, `<<`, etc) which are capable
+of overflowing according to the [Rust
+Reference](https://doc.rust-lang.org/reference/expressions/operator-expr.html#overflow),
+or which can panic (`/`, `%`). No bounds analysis or sophisticated reasoning is
+attempted.
+
+**Why is this bad?** Integer overflow will trigger a panic in debug builds or will wrap in
+release mode. Division by zero will cause a panic in either mode. In some applications one
+wants explicitly checked, wrapping or saturating arithmetic.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let a = 0;
+a + 1;
+```",
+    },
+    LintInfo {
+        lint: crate::integer_division::INTEGER_DIVISION,
+        name: "integer_division",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for division of integers
+
+**Why is this bad?** When outside of some very specific algorithms,
+integer division is very often a mistake because it discards the
+remainder.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+// Bad
+let x = 3 / 2;
+println!("{}", x);
+
+// Good
+let x = 3f32 / 2f32;
+println!("{}", x);
+```"#,
+    },
+    LintInfo {
+        lint: crate::methods::INTO_ITER_ON_REF,
+        name: "into_iter_on_ref",
+        group: "style",
+        docs: r"**What it does:** Checks for `into_iter` calls on references which should be replaced by `iter`
+or `iter_mut`.
+
+**Why is this bad?** Readability. Calling `into_iter` on a reference will not move out its
+content into the resulting iterator, which is confusing. It is better just call `iter` or
+`iter_mut` directly.
+
+**Known problems:** None
+
+**Example:**
+
+```rust
+// Bad
+let _ = (&vec![3, 4, 5]).into_iter();
+
+// Good
+let _ = (&vec![3, 4, 5]).iter();
+```",
+    },
+    LintInfo {
+        lint: crate::atomic_ordering::INVALID_ATOMIC_ORDERING,
+        name: "invalid_atomic_ordering",
+        group: "correctness",
+        docs: r"**What it does:** Checks for usage of invalid atomic
+ordering in atomic loads/stores/exchanges/updates and
+memory fences.
+
+**Why is this bad?** Using an invalid atomic ordering
+will cause a panic at run-time.
+
+**Known problems:** None.
+
+**Example:**
+```rust,no_run
+# use std::sync::atomic::{self, AtomicU8, Ordering};
+
+let x = AtomicU8::new(0);
+
+// Bad: `Release` and `AcqRel` cannot be used for `load`.
+let _ = x.load(Ordering::Release);
+let _ = x.load(Ordering::AcqRel);
+
+// Bad: `Acquire` and `AcqRel` cannot be used for `store`.
+x.store(1, Ordering::Acquire);
+x.store(2, Ordering::AcqRel);
+
+// Bad: `Relaxed` cannot be used as a fence's ordering.
+atomic::fence(Ordering::Relaxed);
+atomic::compiler_fence(Ordering::Relaxed);
+
+// Bad: `Release` and `AcqRel` are both always invalid
+// for the failure ordering (the last arg).
+let _ = x.compare_exchange(1, 2, Ordering::SeqCst, Ordering::Release);
+let _ = x.compare_exchange_weak(2, 3, Ordering::AcqRel, Ordering::AcqRel);
+
+// Bad: The failure ordering is not allowed to be
+// stronger than the success order, and `SeqCst` is
+// stronger than `Relaxed`.
+let _ = x.fetch_update(Ordering::Relaxed, Ordering::SeqCst, |val| Some(val + val));
+```",
+    },
+    LintInfo {
+        lint: crate::ptr::INVALID_NULL_PTR_USAGE,
+        name: "invalid_null_ptr_usage",
+        group: "correctness",
+        docs: r"**What it does:** This lint checks for invalid usages of `ptr::null`.
+
+**Why is this bad?** This causes undefined behavior.
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+// Bad. Undefined behavior
+unsafe { std::slice::from_raw_parts(ptr::null(), 0); }
+```
+
+// Good
+unsafe { std::slice::from_raw_parts(NonNull::dangling().as_ptr(), 0); }
+```",
+    },
+    LintInfo {
+        lint: crate::regex::INVALID_REGEX,
+        name: "invalid_regex",
+        group: "correctness",
+        docs: r#"**What it does:** Checks [regex](https://crates.io/crates/regex) creation
+(with `Regex::new`, `RegexBuilder::new`, or `RegexSet::new`) for correct
+regex syntax.
+
+**Why is this bad?** This will lead to a runtime panic.
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+Regex::new("|")
+```"#,
+    },
+    LintInfo {
+        lint: crate::invalid_upcast_comparisons::INVALID_UPCAST_COMPARISONS,
+        name: "invalid_upcast_comparisons",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for comparisons where the relation is always either
+true or false, but where one side has been upcast so that the comparison is
+necessary. Only integer types are checked.
+
+**Why is this bad?** An expression like `let x : u8 = ...; (x as u32) > 300`
+will mistakenly imply that it is possible for `x` to be outside the range of
+`u8`.
+
+**Known problems:**
+https://github.com/rust-lang/rust-clippy/issues/886
+
+**Example:**
+```rust
+let x: u8 = 1;
+(x as u32) > 300;
+```",
+    },
+    LintInfo {
+        lint: crate::unicode::INVISIBLE_CHARACTERS,
+        name: "invisible_characters",
+        group: "correctness",
+        docs: r"**What it does:** Checks for invisible Unicode characters in the code.
+
+**Why is this bad?** Having an invisible character in the code makes for all
+sorts of April fools, but otherwise is very much frowned upon.
+
+**Known problems:** None.
+
+**Example:** You don't see it, but there may be a zero-width space or soft hyphen
+some­where in this text.",
+    },
+    LintInfo {
+        lint: crate::items_after_statements::ITEMS_AFTER_STATEMENTS,
+        name: "items_after_statements",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for items declared after some statement in a block.
+
+**Why is this bad?** Items live for the entire scope they are declared
+in. But statements are processed in order. This might cause confusion as
+it's hard to figure out which item is meant in a statement.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+// Bad
+fn foo() {
+    println!("cake");
+}
+
+fn main() {
+    foo(); // prints "foo"
+    fn foo() {
+        println!("foo");
+    }
+    foo(); // prints "foo"
+}
+```
+
+```rust
+// Good
+fn foo() {
+    println!("cake");
+}
+
+fn main() {
+    fn foo() {
+        println!("foo");
+    }
+    foo(); // prints "foo"
+    foo(); // prints "foo"
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::methods::ITER_CLONED_COLLECT,
+        name: "iter_cloned_collect",
+        group: "style",
+        docs: r"**What it does:** Checks for the use of `.cloned().collect()` on slice to
+create a `Vec`.
+
+**Why is this bad?** `.to_vec()` is clearer
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let s = [1, 2, 3, 4, 5];
+let s2: Vec<isize> = s[..].iter().cloned().collect();
+```
+The better use would be:
+```rust
+let s = [1, 2, 3, 4, 5];
+let s2: Vec<isize> = s.to_vec();
+```",
+    },
+    LintInfo {
+        lint: crate::methods::ITER_COUNT,
+        name: "iter_count",
+        group: "complexity",
+        docs: r"**What it does:** Checks for the use of `.iter().count()`.
+
+**Why is this bad?** `.len()` is more efficient and more
+readable.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+// Bad
+let some_vec = vec![0, 1, 2, 3];
+let _ = some_vec.iter().count();
+let _ = &some_vec[..].iter().count();
+
+// Good
+let some_vec = vec![0, 1, 2, 3];
+let _ = some_vec.len();
+let _ = &some_vec[..].len();
+```",
+    },
+    LintInfo {
+        lint: crate::loops::ITER_NEXT_LOOP,
+        name: "iter_next_loop",
+        group: "correctness",
+        docs: r"**What it does:** Checks for loops on `x.next()`.
+
+**Why is this bad?** `next()` returns either `Some(value)` if there was a
+value, or `None` otherwise. The insidious thing is that `Option<_>`
+implements `IntoIterator`, so that possibly one value will be iterated,
+leading to some hard to find bugs. No one will want to write such code
+[except to win an Underhanded Rust
+Contest](https://www.reddit.com/r/rust/comments/3hb0wm/underhanded_rust_contest/cu5yuhr).
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+for x in y.next() {
+    ..
+}
+```",
+    },
+    LintInfo {
+        lint: crate::methods::ITER_NEXT_SLICE,
+        name: "iter_next_slice",
+        group: "style",
+        docs: r"**What it does:** Checks for usage of `iter().next()` on a Slice or an Array
+
+**Why is this bad?** These can be shortened into `.get()`
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let a = [1, 2, 3];
+# let b = vec![1, 2, 3];
+a[2..].iter().next();
+b.iter().next();
+```
+should be written as:
+```rust
+# let a = [1, 2, 3];
+# let b = vec![1, 2, 3];
+a.get(2);
+b.get(0);
+```",
+    },
+    LintInfo {
+        lint: crate::methods::ITER_NTH,
+        name: "iter_nth",
+        group: "perf",
+        docs: r"**What it does:** Checks for use of `.iter().nth()` (and the related
+`.iter_mut().nth()`) on standard library types with O(1) element access.
+
+**Why is this bad?** `.get()` and `.get_mut()` are more efficient and more
+readable.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let some_vec = vec![0, 1, 2, 3];
+let bad_vec = some_vec.iter().nth(3);
+let bad_slice = &some_vec[..].iter().nth(3);
+```
+The correct use would be:
+```rust
+let some_vec = vec![0, 1, 2, 3];
+let bad_vec = some_vec.get(3);
+let bad_slice = &some_vec[..].get(3);
+```",
+    },
+    LintInfo {
+        lint: crate::methods::ITER_NTH_ZERO,
+        name: "iter_nth_zero",
+        group: "style",
+        docs: r"**What it does:** Checks for the use of `iter.nth(0)`.
+
+**Why is this bad?** `iter.next()` is equivalent to
+`iter.nth(0)`, as they both consume the next element,
+ but is more readable.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+# use std::collections::HashSet;
+// Bad
+# let mut s = HashSet::new();
+# s.insert(1);
+let x = s.iter().nth(0);
+
+// Good
+# let mut s = HashSet::new();
+# s.insert(1);
+let x = s.iter().next();
+```",
+    },
+    LintInfo {
+        lint: crate::methods::ITER_SKIP_NEXT,
+        name: "iter_skip_next",
+        group: "style",
+        docs: r"**What it does:** Checks for use of `.skip(x).next()` on iterators.
+
+**Why is this bad?** `.nth(x)` is cleaner. If `x` is a constant past the end of an array
+or a vector of a known length, the result is always `None`, which the lint points out as
+well.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let some_vec = vec![0, 1, 2, 3];
+let bad_vec = some_vec.iter().skip(3).next();
+let bad_slice = &some_vec[..].iter().skip(3).next();
+```
+The correct use would be:
+```rust
+let some_vec = vec![0, 1, 2, 3];
+let bad_vec = some_vec.iter().nth(3);
+let bad_slice = &some_vec[..].iter().nth(3);
+```",
+    },
+    LintInfo {
+        lint: crate::methods::ITERATOR_STEP_BY_ZERO,
+        name: "iterator_step_by_zero",
+        group: "correctness",
+        docs: r"**What it does:** Checks for calling `.step_by(0)` on iterators which panics. The step
+is evaluated as a constant, through immutable local bindings and the `const fn`s of the
+crate, so `.step_by(n)` after `let n = 0;` is linted too.
+
+**Why is this bad?** This very much looks like an oversight. Use `panic!()` instead if you
+actually intend to panic.
+
+**Known problems:** None.
+
+**Example:**
+```rust,should_panic
+for x in (0..100).step_by(0) {
+    //..
+}
+```",
+    },
+    LintInfo {
+        lint: crate::non_expressive_names::JUST_UNDERSCORES_AND_DIGITS,
+        name: "just_underscores_and_digits",
+        group: "style",
+        docs: r"**What it does:** Checks if you have variables whose name consists of just
+underscores and digits.
+
+**Why is this bad?** It's hard to memorize what a variable means without a
+descriptive name.
+
+**Known problems:** None?
+
+**Example:**
+```rust
+let _1 = 1;
+let ___1 = 1;
+let __1___2 = 11;
+```",
+    },
+    LintInfo {
+        lint: crate::large_const_arrays::LARGE_CONST_ARRAYS,
+        name: "large_const_arrays",
+        group: "perf",
+        docs: r"**What it does:** Checks for large `const` arrays that should
+be defined as `static` instead.
+
+**Why is this bad?** Performance: const variables are inlined upon use.
+Static items result in only one instance and has a fixed location in memory.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+// Bad
+pub const a = [0u32; 1_000_000];
+
+// Good
+pub static a = [0u32; 1_000_000];
+```",
+    },
+    LintInfo {
+        lint: crate::literal_representation::LARGE_DIGIT_GROUPS,
+        name: "large_digit_groups",
+        group: "pedantic",
+        docs: r"**What it does:** Warns if the digits of an integral or floating-point
+constant are grouped into groups that
+are too large.
+
+**Why is this bad?** Negatively impacts readability.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+let x: u64 = 6186491_8973511;
+```",
+    },
+    LintInfo {
+        lint: crate::large_enum_variant::LARGE_ENUM_VARIANT,
+        name: "large_enum_variant",
+        group: "perf",
+        docs: r"**What it does:** Checks for large size differences between variants on
+`enum`s.
+
+**Why is this bad?** Enum size is bounded by the largest variant. Having a
+large variant can penalize the memory layout of that enum.
+
+**Known problems:** This lint obviously cannot take the distribution of
+variants in your running program into account. It is possible that the
+smaller variants make up less than 1% of all instances, in which case
+the overhead is negligible and the boxing is counter-productive. Always
+measure the change this lint suggests.
+
+**Example:**
+
+```rust
+// Bad
+enum Test {
+    A(i32),
+    B([i32; 8000]),
+}
+
+// Possibly better
+enum Test2 {
+    A(i32),
+    B(Box<[i32; 8000]>),
+}
+```",
+    },
+    LintInfo {
+        lint: crate::large_stack_arrays::LARGE_STACK_ARRAYS,
+        name: "large_stack_arrays",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for local arrays that may be too large.
+
+**Why is this bad?** Large local arrays may cause stack overflow.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+let a = [0u32; 1_000_000];
+```",
+    },
+    LintInfo {
+        lint: crate::pass_by_ref_or_value::LARGE_TYPES_PASSED_BY_VALUE,
+        name: "large_types_passed_by_value",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for functions taking arguments by value, where
+the argument type is `Copy` and large enough to be worth considering
+passing by reference. Does not trigger if the function is being exported,
+because that might induce API breakage, if the parameter is declared as mutable,
+or if the argument is a `self`.
+
+**Why is this bad?** Arguments passed by value might result in an unnecessary
+shallow copy, taking up more space in the stack and requiring a call to
+`memcpy`, which can be expensive.
+
+**Example:**
+
+```rust
+#[derive(Clone, Copy)]
+struct TooLarge([u8; 2048]);
+
+// Bad
+fn foo(v: TooLarge) {}
+```
+```rust
+#[derive(Clone, Copy)]
+struct TooLarge([u8; 2048]);
+
+// Good
+fn foo(v: &TooLarge) {}
+```",
+    },
+    LintInfo {
+        lint: crate::len_zero::LEN_WITHOUT_IS_EMPTY,
+        name: "len_without_is_empty",
+        group: "style",
+        docs: r"**What it does:** Checks for items that implement `.len()` but not
+`.is_empty()`.
+
+**Why is this bad?** It is good custom to have both methods, because for
+some data structures, asking about the length will be a costly operation,
+whereas `.is_empty()` can usually answer in constant time. Also it used to
+lead to false positives on the [`len_zero`](#len_zero) lint – currently that
+lint will ignore such entities.
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+impl X {
+    pub fn len(&self) -> usize {
+        ..
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::len_zero::LEN_ZERO,
+        name: "len_zero",
+        group: "style",
+        docs: r"**What it does:** Checks for getting the length of something via `.len()`
+just to compare to zero, and suggests using `.is_empty()` where applicable.
+
+**Why is this bad?** Some structures can answer `.is_empty()` much faster
+than calculating their length. So it is good to get into the habit of using
+`.is_empty()`, and having it is cheap.
+Besides, it makes the intent clearer than a manual comparison in some contexts.
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+if x.len() == 0 {
+    ..
+}
+if y.len() != 0 {
+    ..
+}
+```
+instead use
+```ignore
+if x.is_empty() {
+    ..
+}
+if !y.is_empty() {
+    ..
+}
+```",
+    },
+    LintInfo {
+        lint: crate::returns::LET_AND_RETURN,
+        name: "let_and_return",
+        group: "style",
+        docs: r"**What it does:** Checks for `let`-bindings, which are subsequently
+returned.
+
+**Why is this bad?** It is just extraneous code. Remove it to make your code
+more rusty.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn foo() -> String {
+    let x = String::new();
+    x
+}
+```
+instead, use
+```
+fn foo() -> String {
+    String::new()
+}
+```",
+    },
+    LintInfo {
+        lint: crate::let_underscore::LET_UNDERSCORE_DROP,
+        name: "let_underscore_drop",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for `let _ = <expr>`
+where expr has a type that implements `Drop`
+
+**Why is this bad?** This statement immediately drops the initializer
+expression instead of extending its lifetime to the end of the scope, which
+is often not intended. To extend the expression's lifetime to the end of the
+scope, use an underscore-prefixed name instead (i.e. _var). If you want to
+explicitly drop the expression, `std::mem::drop` conveys your intention
+better and is less error-prone.
+
+**Known problems:** None.
+
+**Example:**
+
+Bad:
+```rust,ignore
+struct Droppable;
+impl Drop for Droppable {
+    fn drop(&mut self) {}
+}
+{
+    let _ = Droppable;
+    //               ^ dropped here
+    /* more code */
+}
+```
+
+Good:
+```rust,ignore
+{
+    let _droppable = Droppable;
+    /* more code */
+    // dropped at end of scope
+}
+```",
+    },
+    LintInfo {
+        lint: crate::let_underscore::LET_UNDERSCORE_LOCK,
+        name: "let_underscore_lock",
+        group: "correctness",
+        docs: r"**What it does:** Checks for `let _ = sync_lock`
+
+**Why is this bad?** This statement immediately drops the lock instead of
+extending its lifetime to the end of the scope, which is often not intended.
+To extend lock lifetime to the end of the scope, use an underscore-prefixed
+name instead (i.e. _lock). If you want to explicitly drop the lock,
+`std::mem::drop` conveys your intention better and is less error-prone.
+
+**Known problems:** None.
+
+**Example:**
+
+Bad:
+```rust,ignore
+let _ = mutex.lock();
+```
+
+Good:
+```rust,ignore
+let _lock = mutex.lock();
+```",
+    },
+    LintInfo {
+        lint: crate::let_underscore::LET_UNDERSCORE_MUST_USE,
+        name: "let_underscore_must_use",
+        group: "restriction",
+        docs: r"**What it does:** Checks for `let _ = <expr>`
+where expr is #[must_use]
+
+**Why is this bad?** It's better to explicitly
+handle the value of a #[must_use] expr
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn f() -> Result<u32, u32> {
+    Ok(0)
+}
+
+let _ = f();
+// is_ok() is marked #[must_use]
+let _ = f().is_ok();
+```",
+    },
+    LintInfo {
+        lint: crate::unit_types::LET_UNIT_VALUE,
+        name: "let_unit_value",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for binding a unit value.
+
+**Why is this bad?** A unit value cannot usefully be used anywhere. So
+binding one is kind of pointless.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = {
+    1;
+};
+```",
+    },
+    LintInfo {
+        lint: crate::types::LINKEDLIST,
+        name: "linkedlist",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for usage of any `LinkedList`, suggesting to use a
+`Vec` or a `VecDeque` (formerly called `RingBuf`).
+
+**Why is this bad?** Gankro says:
+
+> The TL;DR of `LinkedList` is that it's built on a massive amount of
+pointers and indirection.
+> It wastes memory, it has terrible cache locality, and is all-around slow.
+`RingBuf`, while
+> "only" amortized for push/pop, should be faster in the general case for
+almost every possible
+> workload, and isn't even amortized at all if you can predict the capacity
+you need.
+>
+> `LinkedList`s are only really good if you're doing a lot of merging or
+splitting of lists.
+> This is because they can just mangle some pointers instead of actually
+copying the data. Even
+> if you're doing a lot of insertion in the middle of the list, `RingBuf`
+can still be better
+> because of how expensive it is to seek to the middle of a `LinkedList`.
+
+**Known problems:** False positives – the instances where using a
+`LinkedList` makes sense are few and far between, but they can still happen.
+
+**Example:**
+```rust
+# use std::collections::LinkedList;
+let x: LinkedList<usize> = LinkedList::new();
+```"#,
+    },
+    LintInfo {
+        lint: crate::booleans::LOGIC_BUG,
+        name: "logic_bug",
+        group: "correctness",
+        docs: r"**What it does:** Checks for boolean expressions that contain terminals that
+can be eliminated.
+
+**Why is this bad?** This is most likely a logic bug.
+
+**Known problems:** Ignores short circuiting behavior.
+
+**Example:**
+```ignore
+if a && b || a { ... }
+```
+The `b` is unnecessary, the expression is equivalent to `if a`.",
+    },
+    LintInfo {
+        lint: crate::indexing_slicing::LOOP_INDEX_OFFSET,
+        name: "loop_index_offset",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for indexing with the loop variable of a `for` loop over a range
+plus an offset, like `v[i + k]` in `for i in 0..n { .. }`, where the index isn't known to
+be in bounds, i.e. the end of the range isn't bounded by the length of the indexed
+collection minus the offset, like `0..v.len() - k`.
+
+**Why is this bad?** Nothing relates the range to the length of the collection, so the
+last iterations may go past its end and panic. Iterator adapters like
+`v.iter().skip(k).take(n)` stop at the end of the collection instead.
+
+**Known problems:** The range isn't related to the length when the loop is guarded by a
+condition on another variable, like `if n + k <= v.len()`.
+
+**Example:**
+```rust
+# let v = vec![1, 2, 3];
+# let (n, k) = (2, 1);
+// Bad
+for i in 0..n {
+    println!("{}", v[i + k]);
+}
+
+// Good
+for x in v.iter().skip(k).take(n) {
+    println!("{}", x);
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::float_literal::LOSSY_FLOAT_LITERAL,
+        name: "lossy_float_literal",
+        group: "restriction",
+        docs: r"**What it does:** Checks for whole number float literals that
+cannot be represented as the underlying type without loss.
+
+**Why is this bad?** Rust will silently lose precision during
+conversion to a float.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+// Bad
+let _: f32 = 16_777_217.0; // 16_777_216.0
+
+// Good
+let _: f32 = 16_777_216.0;
+let _: f64 = 16_777_217.0;
+```",
+    },
+    LintInfo {
+        lint: crate::macro_use::MACRO_USE_IMPORTS,
+        name: "macro_use_imports",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for `#[macro_use] use...`.
+
+**Why is this bad?** Since the Rust 2018 edition you can import
+macro's directly, this is considered idiomatic.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+#[macro_use]
+use some_macro;
+```",
+    },
+    LintInfo {
+        lint: crate::main_recursion::MAIN_RECURSION,
+        name: "main_recursion",
+        group: "style",
+        docs: r"**What it does:** Checks for recursion using the entrypoint.
+
+**Why is this bad?** Apart from special setups (which we could detect following attributes like #![no_std]),
+recursing into main() seems like an unintuitive antipattern we should be able to detect.
+
+**Known problems:** None.
+
+**Example:**
+```no_run
+fn main() {
+    main();
+}
+```",
+    },
+    LintInfo {
+        lint: crate::manual_async_fn::MANUAL_ASYNC_FN,
+        name: "manual_async_fn",
+        group: "style",
+        docs: r"**What it does:** It checks for manual implementations of `async` functions.
+
+**Why is this bad?** It's more idiomatic to use the dedicated syntax.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+use std::future::Future;
+
+fn foo() -> impl Future<Output = i32> { async { 42 } }
+```
+Use instead:
+```rust
+async fn foo() -> i32 { 42 }
+```",
+    },
+    LintInfo {
+        lint: crate::loops::MANUAL_BINARY_SEARCH,
+        name: "manual_binary_search",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for hand-written binary searches: `while lo < hi` loops computing
+the midpoint `mid` of `lo` and `hi`, indexing a slice with `mid` and moving `lo` or `hi`
+to `mid`.
+
+**Why is this bad?** `binary_search_by` (or `partition_point`) is clearer, and doesn't
+index the slice. The manual version is easy to get wrong: `(lo + hi) / 2` can overflow,
+and an off-by-one in the bounds loops forever or indexes past the end of the slice, which
+panics.
+
+**Known problems:** Only loops declaring `mid` in their body are recognized.
+
+**Example:**
+```rust
+# let v = vec![1, 3, 5, 7];
+# let target = 5;
+let mut lo = 0;
+let mut hi = v.len();
+while lo < hi {
+    let mid = lo + (hi - lo) / 2;
+    if v[mid] < target {
+        lo = mid + 1;
+    } else {
+        hi = mid;
+    }
+}
+```
+Use instead:
+```rust
+# let v = vec![1, 3, 5, 7];
+# let target = 5;
+let lo = v.partition_point(|&x| x < target);
+```",
+    },
+    LintInfo {
+        lint: crate::methods::MANUAL_FILTER_MAP,
+        name: "manual_filter_map",
+        group: "complexity",
+        docs: r"**What it does:** Checks for usage of `_.filter(_).map(_)` that can be written more simply
+as `filter_map(_)`.
+
+**Why is this bad?** Redundant code in the `filter` and `map` operations is poor style and
+less performant.
+
+**Known problems:** None.
+
+**Example:**
+Bad:
+```rust
+(0_i32..10)
+    .filter(|n| n.checked_add(1).is_some())
+    .map(|n| n.checked_add(1).unwrap());
+```
+
+Good:
+```rust
+(0_i32..10).filter_map(|n| n.checked_add(1));
+```",
+    },
+    LintInfo {
+        lint: crate::methods::MANUAL_FIND_MAP,
+        name: "manual_find_map",
+        group: "complexity",
+        docs: r"**What it does:** Checks for usage of `_.find(_).map(_)` that can be written more simply
+as `find_map(_)`.
+
+**Why is this bad?** Redundant code in the `find` and `map` operations is poor style and
+less performant.
+
+**Known problems:** None.
+
+**Example:**
+Bad:
+```rust
+(0_i32..10)
+    .find(|n| n.checked_add(1).is_some())
+    .map(|n| n.checked_add(1).unwrap());
+```
+
+Good:
+```rust
+(0_i32..10).find_map(|n| n.checked_add(1));
+```",
+    },
+    LintInfo {
+        lint: crate::loops::MANUAL_FLATTEN,
+        name: "manual_flatten",
+        group: "complexity",
+        docs: r#"**What it does:** Check for unnecessary `if let` usage in a for loop
+where only the `Some` or `Ok` variant of the iterator element is used.
+
+**Why is this bad?** It is verbose and can be simplified
+by first calling the `flatten` method on the `Iterator`.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+let x = vec![Some(1), Some(2), Some(3)];
+for n in x {
+    if let Some(n) = n {
+        println!("{}", n);
+    }
+}
+```
+Use instead:
+```rust
+let x = vec![Some(1), Some(2), Some(3)];
+for n in x.into_iter().flatten() {
+    println!("{}", n);
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::manual_from_bytes::MANUAL_FROM_BYTES,
+        name: "manual_from_bytes",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for integers assembled from consecutive bytes of a slice, array
+or `Vec` by indexing, shifting and combining them with `|` or `+`, like
+`(buf[0] as u32) << 24 | (buf[1] as u32) << 16 | (buf[2] as u32) << 8 | buf[3] as u32`.
+
+**Why is this bad?** Each of the indexes can panic, and a wrong shift or index is hard to
+spot. `from_be_bytes` and `from_le_bytes` name the byte order and convert the bytes at once,
+after a single check of their number.
+
+**Known problems:** Only constant indexes are linted. The suggestion for a slice uses `?` on
+`try_into()`, which needs `std::convert::TryInto` in scope before the 2021 edition, and an
+error type of the function that `TryFromSliceError` converts into.
+
+**Example:**
+```rust,ignore
+// Bad
+let n = (buf[0] as u32) << 24 | (buf[1] as u32) << 16 | (buf[2] as u32) << 8 | buf[3] as u32;
+
+// Good
+let n = u32::from_be_bytes(buf[..4].try_into()?);
+```",
+    },
+    LintInfo {
+        lint: crate::manual_map::MANUAL_MAP,
+        name: "manual_map",
+        group: "style",
+        docs: r"**What it does:** Checks for usages of `match` which could be implemented using `map`
+
+**Why is this bad?** Using the `map` method is clearer and more concise.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+match Some(0) {
+    Some(x) => Some(x + 1),
+    None => None,
+};
+```
+Use instead:
+```rust
+Some(0).map(|x| x + 1);
+```",
+    },
+    LintInfo {
+        lint: crate::loops::MANUAL_MEMCPY,
+        name: "manual_memcpy",
+        group: "perf",
+        docs: r"**What it does:** Checks for for-loops that manually copy items between
+slices that could be optimized by having a memcpy.
+
+**Why is this bad?** It is not as fast as a memcpy.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let src = vec![1];
+# let mut dst = vec![0; 65];
+for i in 0..src.len() {
+    dst[i + 64] = src[i];
+}
+```
+Could be written as:
+```rust
+# let src = vec![1];
+# let mut dst = vec![0; 65];
+dst[64..(src.len() + 64)].clone_from_slice(&src[..]);
+```",
+    },
+    LintInfo {
+        lint: crate::manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE,
+        name: "manual_non_exhaustive",
+        group: "style",
+        docs: r"**What it does:** Checks for manual implementations of the non-exhaustive pattern.
+
+**Why is this bad?** Using the #[non_exhaustive] attribute expresses better the intent
+and allows possible optimizations when applied to enums.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+struct S {
+    pub a: i32,
+    pub b: i32,
+    _c: (),
+}
+
+enum E {
+    A,
+    B,
+    #[doc(hidden)]
+    _C,
+}
+
+struct T(pub i32, pub i32, ());
+```
+Use instead:
+```rust
+#[non_exhaustive]
+struct S {
+    pub a: i32,
+    pub b: i32,
+}
+
+#[non_exhaustive]
+enum E {
+    A,
+    B,
+}
+
+#[non_exhaustive]
+struct T(pub i32, pub i32);
+```",
+    },
+    LintInfo {
+        lint: crate::manual_ok_or::MANUAL_OK_OR,
+        name: "manual_ok_or",
+        group: "pedantic",
+        docs: r#"**What it does:**
+Finds patterns that reimplement `Option::ok_or`.
+
+**Why is this bad?**
+Concise code helps focusing on behavior instead of boilerplate.
+
+**Known problems:** None.
+
+**Examples:**
+```rust
+let foo: Option<i32> = None;
+foo.map_or(Err("error"), |v| Ok(v));
+```
+
+Use instead:
+```rust
+let foo: Option<i32> = None;
+foo.ok_or("error");
+```"#,
+    },
+    LintInfo {
+        lint: crate::ranges::MANUAL_RANGE_CONTAINS,
+        name: "manual_range_contains",
+        group: "style",
+        docs: r"**What it does:** Checks for expressions like `x >= 3 && x < 8` that could
+be more readably expressed as `(3..8).contains(x)`.
+
+**Why is this bad?** `contains` expresses the intent better and has less
+failure modes (such as fencepost errors or using `||` instead of `&&`).
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+// given
+let x = 6;
+
+assert!(x >= 3 && x < 8);
+```
+Use instead:
+```rust
+# let x = 6;
+assert!((3..8).contains(&x));
+```",
+    },
+    LintInfo {
+        lint: crate::methods::MANUAL_SATURATING_ARITHMETIC,
+        name: "manual_saturating_arithmetic",
+        group: "style",
+        docs: r"**What it does:** Checks for `.checked_add/sub(x).unwrap_or(MAX/MIN)`.
+
+**Why is this bad?** These can be written simply with `saturating_add/sub` methods.
+
+**Example:**
+
+```rust
+# let y: u32 = 0;
+# let x: u32 = 100;
+let add = x.checked_add(y).unwrap_or(u32::MAX);
+let sub = x.checked_sub(y).unwrap_or(u32::MIN);
+```
+
+can be written using dedicated methods for saturating addition/subtraction as:
+
+```rust
+# let y: u32 = 0;
+# let x: u32 = 100;
+let add = x.saturating_add(y);
+let sub = x.saturating_sub(y);
+```",
+    },
+    LintInfo {
+        lint: crate::manual_slice_pattern::MANUAL_SLICE_PATTERN,
+        name: "manual_slice_pattern",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for consecutive `let` statements binding the first elements of a
+slice or `Vec` with constant indexes, like `let a = x[0]; let b = x[1];`.
+
+**Why is this bad?** Each indexing checks the length of the slice again and may panic. A
+slice pattern checks the length once, and `if let` can handle a shorter slice without
+panicking.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+# let x = vec![1, 2, 3];
+let a = x[0];
+let b = x[1];
+let c = x[2];
+```
+Use instead:
+```rust
+# let x = vec![1, 2, 3];
+if let [a, b, c, ..] = x[..] {
+    // ..
+}
+```",
+    },
+    LintInfo {
+        lint: crate::manual_strip::MANUAL_STRIP,
+        name: "manual_strip",
+        group: "complexity",
+        docs: r#"**What it does:**
+Suggests using `strip_{prefix,suffix}` over `str::{starts,ends}_with` and slicing using
+the pattern's length.
+
+**Why is this bad?**
+Using `str:strip_{prefix,suffix}` is safer and may have better performance as there is no
+slicing which may panic and the compiler does not need to insert this panic code. It is
+also sometimes more readable as it removes the need for duplicating or storing the pattern
+used by `str::{starts,ends}_with` and in the slicing.
+
+**Known problems:**
+None.
+
+**Example:**
+
+```rust
+let s = "hello, world!";
+if s.starts_with("hello, ") {
+    assert_eq!(s["hello, ".len()..].to_uppercase(), "WORLD!");
+}
+```
+Use instead:
+```rust
+let s = "hello, world!";
+if let Some(end) = s.strip_prefix("hello, ") {
+    assert_eq!(end.to_uppercase(), "WORLD!");
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::swap::MANUAL_SWAP,
+        name: "manual_swap",
+        group: "complexity",
+        docs: r"**What it does:** Checks for manual swapping.
+
+**Why is this bad?** The `std::mem::swap` function exposes the intent better
+without deinitializing or copying either variable.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let mut a = 42;
+let mut b = 1337;
+
+let t = b;
+b = a;
+a = t;
+```
+Use std::mem::swap():
+```rust
+let mut a = 1;
+let mut b = 2;
+std::mem::swap(&mut a, &mut b);
+```",
+    },
+    LintInfo {
+        lint: crate::manual_unwrap_or::MANUAL_UNWRAP_OR,
+        name: "manual_unwrap_or",
+        group: "complexity",
+        docs: r"**What it does:**
+Finds patterns that reimplement `Option::unwrap_or` or `Result::unwrap_or`.
+
+**Why is this bad?**
+Concise code helps focusing on behavior instead of boilerplate.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let foo: Option<i32> = None;
+match foo {
+    Some(v) => v,
+    None => 1,
+};
+```
+
+Use instead:
+```rust
+let foo: Option<i32> = None;
+foo.unwrap_or(1);
+```",
+    },
+    LintInfo {
+        lint: crate::loops::MANUAL_ZIP,
+        name: "manual_zip",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for looping over a range of indices and indexing two or more
+sequences with the loop variable, like `for i in 0..n { a[i] + b[i] }`.
+
+**Why is this bad?** Zipping the iterators of the sequences is more readable, and avoids
+the bounds checks and the panics `indexing_slicing` warns about.
+
+**Known problems:** The zipped iterator stops at the end of the shortest sequence, where
+the indexing would panic.
+
+**Example:**
+```rust
+let a = vec![1, 2, 3];
+let b = vec![4, 5, 6];
+let mut sum = 0;
+for i in 0..a.len() {
+    sum += a[i] * b[i];
+}
+```
+Use instead:
+```rust
+let a = vec![1, 2, 3];
+let b = vec![4, 5, 6];
+let mut sum = 0;
+for (&a_item, &b_item) in a.iter().zip(b.iter()) {
+    sum += a_item * b_item;
+}
+```",
+    },
+    LintInfo {
+        lint: crate::non_expressive_names::MANY_SINGLE_CHAR_NAMES,
+        name: "many_single_char_names",
+        group: "style",
+        docs: r"**What it does:** Checks for too many variables whose name consists of a
+single character.
+
+**Why is this bad?** It's hard to memorize what a variable means without a
+descriptive name.
+
+**Known problems:** None?
+
+**Example:**
+```ignore
+let (a, b, c, d, e, f, g) = (...);
+```",
+    },
+    LintInfo {
+        lint: crate::map_clone::MAP_CLONE,
+        name: "map_clone",
+        group: "style",
+        docs: r"**What it does:** Checks for usage of `map(|x| x.clone())` or
+dereferencing closures for `Copy` types, on `Iterator` or `Option`,
+and suggests `cloned()` or `copied()` instead
+
+**Why is this bad?** Readability, this can be written more concisely
+
+**Known problems:** None
+
+**Example:**
+
+```rust
+let x = vec![42, 43];
+let y = x.iter();
+let z = y.map(|i| *i);
+```
+
+The correct use would be:
+
+```rust
+let x = vec![42, 43];
+let y = x.iter();
+let z = y.cloned();
+```",
+    },
+    LintInfo {
+        lint: crate::methods::MAP_COLLECT_RESULT_UNIT,
+        name: "map_collect_result_unit",
+        group: "style",
+        docs: r"**What it does:** Checks for usage of `_.map(_).collect::<Result<(), _>()`.
+
+**Why is this bad?** Using `try_for_each` instead is more readable and idiomatic.
+
+**Known problems:** None
+
+**Example:**
+
+```rust
+(0..3).map(|t| Err(t)).collect::<Result<(), _>>();
+```
+Use instead:
+```rust
+(0..3).try_for_each(|t| Err(t));
+```",
+    },
+    LintInfo {
+        lint: crate::entry::MAP_ENTRY,
+        name: "map_entry",
+        group: "perf",
+        docs: r"**What it does:** Checks for uses of `contains_key` + `insert` on `HashMap`
+or `BTreeMap`.
+
+**Why is this bad?** Using `entry` is more efficient.
+
+**Known problems:** The suggestion may have type inference errors in some cases. e.g.
+```rust
+let mut map = std::collections::HashMap::new();
+let _ = if !map.contains_key(&0) {
+    map.insert(0, 0)
+} else {
+    None
+};
+```
+
+**Example:**
+```rust
+# use std::collections::HashMap;
+# let mut map = HashMap::new();
+# let k = 1;
+# let v = 1;
+if !map.contains_key(&k) {
+    map.insert(k, v);
+}
+```
+can both be rewritten as:
+```rust
+# use std::collections::HashMap;
+# let mut map = HashMap::new();
+# let k = 1;
+# let v = 1;
+map.entry(k).or_insert(v);
+```",
+    },
+    LintInfo {
+        lint: crate::map_err_ignore::MAP_ERR_IGNORE,
+        name: "map_err_ignore",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for instances of `map_err(|_| Some::Enum)`
+
+**Why is this bad?** This `map_err` throws away the original error rather than allowing the enum to contain and report the cause of the error
+
+**Known problems:** None.
+
+**Example:**
+Before:
+```rust
+use std::fmt;
+
+#[derive(Debug)]
+enum Error {
+    Indivisible,
+    Remainder(u8),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Indivisible => write!(f, "could not divide input by three"),
+            Error::Remainder(remainder) => write!(
+                f,
+                "input is not divisible by three, remainder = {}",
+                remainder
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn divisible_by_3(input: &str) -> Result<(), Error> {
+    input
+        .parse::<i32>()
+        .map_err(|_| Error::Indivisible)
+        .map(|v| v % 3)
+        .and_then(|remainder| {
+            if remainder == 0 {
+                Ok(())
+            } else {
+                Err(Error::Remainder(remainder as u8))
+            }
+        })
+}
+ ```
+
+ After:
+ ```rust
+use std::{fmt, num::ParseIntError};
+
+#[derive(Debug)]
+enum Error {
+    Indivisible(ParseIntError),
+    Remainder(u8),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Indivisible(_) => write!(f, "could not divide input by three"),
+            Error::Remainder(remainder) => write!(
+                f,
+                "input is not divisible by three, remainder = {}",
+                remainder
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Indivisible(source) => Some(source),
+            _ => None,
+        }
+    }
+}
+
+fn divisible_by_3(input: &str) -> Result<(), Error> {
+    input
+        .parse::<i32>()
+        .map_err(Error::Indivisible)
+        .map(|v| v % 3)
+        .and_then(|remainder| {
+            if remainder == 0 {
+                Ok(())
+            } else {
+                Err(Error::Remainder(remainder as u8))
+            }
+        })
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::methods::MAP_FLATTEN,
+        name: "map_flatten",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for usage of `_.map(_).flatten(_)` on `Iterator` and `Option`
+
+**Why is this bad?** Readability, this can be written more concisely as
+`_.flat_map(_)`
+
+**Known problems:**
+
+**Example:**
+```rust
+let vec = vec![vec![1]];
+
+// Bad
+vec.iter().map(|x| x.iter()).flatten();
+
+// Good
+vec.iter().flat_map(|x| x.iter());
+```",
+    },
+    LintInfo {
+        lint: crate::map_identity::MAP_IDENTITY,
+        name: "map_identity",
+        group: "complexity",
+        docs: r"**What it does:** Checks for instances of `map(f)` where `f` is the identity function.
+
+**Why is this bad?** It can be written more concisely without the call to `map`.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+let x = [1, 2, 3];
+let y: Vec<_> = x.iter().map(|x| x).map(|x| 2*x).collect();
+```
+Use instead:
+```rust
+let x = [1, 2, 3];
+let y: Vec<_> = x.iter().map(|x| 2*x).collect();
+```",
+    },
+    LintInfo {
+        lint: crate::methods::MAP_UNWRAP_OR,
+        name: "map_unwrap_or",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for usage of `option.map(_).unwrap_or(_)` or `option.map(_).unwrap_or_else(_)` or
+`result.map(_).unwrap_or_else(_)`.
+
+**Why is this bad?** Readability, these can be written more concisely (resp.) as
+`option.map_or(_, _)`, `option.map_or_else(_, _)` and `result.map_or_else(_, _)`.
+
+**Known problems:** The order of the arguments is not in execution order
+
+**Examples:**
+```rust
+# let x = Some(1);
+
+// Bad
+x.map(|a| a + 1).unwrap_or(0);
+
+// Good
+x.map_or(0, |a| a + 1);
+```
+
+// or
+
+```rust
+# let x: Result<usize, ()> = Ok(1);
+# fn some_function(foo: ()) -> usize { 1 }
+
+// Bad
+x.map(|a| a + 1).unwrap_or_else(some_function);
+
+// Good
+x.map_or_else(some_function, |a| a + 1);
+```",
+    },
+    LintInfo {
+        lint: crate::matches::MATCH_AS_REF,
+        name: "match_as_ref",
+        group: "complexity",
+        docs: r"**What it does:** Checks for match which is used to add a reference to an
+`Option` value.
+
+**Why is this bad?** Using `as_ref()` or `as_mut()` instead is shorter.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x: Option<()> = None;
+
+// Bad
+let r: Option<&()> = match x {
+    None => None,
+    Some(ref v) => Some(v),
+};
+
+// Good
+let r: Option<&()> = x.as_ref();
+```",
+    },
+    LintInfo {
+        lint: crate::matches::MATCH_BOOL,
+        name: "match_bool",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for matches where match expression is a `bool`. It
+suggests to replace the expression with an `if...else` block.
+
+**Why is this bad?** It makes the code less readable.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# fn foo() {}
+# fn bar() {}
+let condition: bool = true;
+match condition {
+    true => foo(),
+    false => bar(),
+}
+```
+Use if/else instead:
+```rust
+# fn foo() {}
+# fn bar() {}
+let condition: bool = true;
+if condition {
+    foo();
+} else {
+    bar();
+}
+```",
+    },
+    LintInfo {
+        lint: crate::matches::MATCH_LIKE_MATCHES_MACRO,
+        name: "match_like_matches_macro",
+        group: "style",
+        docs: r"**What it does:** Checks for `match`  or `if let` expressions producing a
+`bool` that could be written using `matches!`
+
+**Why is this bad?** Readability and needless complexity.
+
+**Known problems:** This lint falsely triggers, if there are arms with
+`cfg` attributes that remove an arm evaluating to `false`.
+
+**Example:**
+```rust
+let x = Some(5);
+
+// Bad
+let a = match x {
+    Some(0) => true,
+    _ => false,
+};
+
+let a = if let Some(0) = x {
+    true
+} else {
+    false
+};
+
+// Good
+let a = matches!(x, Some(0));
+```",
+    },
+    LintInfo {
+        lint: crate::match_on_vec_items::MATCH_ON_VEC_ITEMS,
+        name: "match_on_vec_items",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for `match vec[idx]` or `match vec[n..m]`.
+
+**Why is this bad?** This can panic at runtime.
+
+**Known problems:** None.
+
+**Example:**
+```rust, no_run
+let arr = vec![0, 1, 2, 3];
+let idx = 1;
+
+// Bad
+match arr[idx] {
+    0 => println!("{}", 0),
+    1 => println!("{}", 3),
+    _ => {},
+}
+```
+Use instead:
+```rust, no_run
+let arr = vec![0, 1, 2, 3];
+let idx = 1;
+
+// Good
+match arr.get(idx) {
+    Some(0) => println!("{}", 0),
+    Some(1) => println!("{}", 3),
+    _ => {},
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::matches::MATCH_OVERLAPPING_ARM,
+        name: "match_overlapping_arm",
+        group: "style",
+        docs: r#"**What it does:** Checks for overlapping match arms.
+
+**Why is this bad?** It is likely to be an error and if not, makes the code
+less obvious.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = 5;
+match x {
+    1...10 => println!("1 ... 10"),
+    5...15 => println!("5 ... 15"),
+    _ => (),
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::matches::MATCH_REF_PATS,
+        name: "match_ref_pats",
+        group: "style",
+        docs: r"**What it does:** Checks for matches where all arms match a reference,
+suggesting to remove the reference and deref the matched expression
+instead. It also checks for `if let &foo = bar` blocks.
+
+**Why is this bad?** It just makes the code less readable. That reference
+destructuring adds nothing to the code.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+// Bad
+match x {
+    &A(ref y) => foo(y),
+    &B => bar(),
+    _ => frob(&x),
+}
+
+// Good
+match *x {
+    A(ref y) => foo(y),
+    B => bar(),
+    _ => frob(x),
+}
+```",
+    },
+    LintInfo {
+        lint: crate::matches::MATCH_SAME_ARMS,
+        name: "match_same_arms",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for `match` with identical arm bodies.
+
+**Why is this bad?** This is probably a copy & paste error. If arm bodies
+are the same on purpose, you can factor them
+[using `|`](https://doc.rust-lang.org/book/patterns.html#multiple-patterns).
+
+**Known problems:** False positive possible with order dependent `match`
+(see issue
+[#860](https://github.com/rust-lang/rust-clippy/issues/860)).
+
+**Example:**
+```rust,ignore
+match foo {
+    Bar => bar(),
+    Quz => quz(),
+    Baz => bar(), // <= oops
+}
+```
+
+This should probably be
+```rust,ignore
+match foo {
+    Bar => bar(),
+    Quz => quz(),
+    Baz => baz(), // <= fixed
+}
+```
+
+or if the original code was not a typo:
+```rust,ignore
+match foo {
+    Bar | Baz => bar(), // <= shows the intent better
+    Quz => quz(),
+}
+```",
+    },
+    LintInfo {
+        lint: crate::matches::MATCH_SINGLE_BINDING,
+        name: "match_single_binding",
+        group: "complexity",
+        docs: r"**What it does:** Checks for useless match that binds to only one value.
+
+**Why is this bad?** Readability and needless complexity.
+
+**Known problems:**  Suggested replacements may be incorrect when `match`
+is actually binding temporary value, bringing a 'dropped while borrowed' error.
+
+**Example:**
+```rust
+# let a = 1;
+# let b = 2;
+
+// Bad
+match (a, b) {
+    (c, d) => {
+        // useless match
+    }
+}
+
+// Good
+let (c, d) = (a, b);
+```",
+    },
+    LintInfo {
+        lint: crate::matches::MATCH_WILD_ERR_ARM,
+        name: "match_wild_err_arm",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for arm which matches all errors with `Err(_)`
+and take drastic actions like `panic!`.
+
+**Why is this bad?** It is generally a bad practice, similar to
+catching all exceptions in java with `catch(Exception)`
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x: Result<i32, &str> = Ok(3);
+match x {
+    Ok(_) => println!("ok"),
+    Err(_) => panic!("err"),
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::matches::MATCH_WILDCARD_FOR_SINGLE_VARIANTS,
+        name: "match_wildcard_for_single_variants",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for wildcard enum matches for a single variant.
+
+**Why is this bad?** New enum variants added by library updates can be missed.
+
+**Known problems:** Suggested replacements may not use correct path to enum
+if it's not present in the current scope.
+
+**Example:**
+
+```rust
+# enum Foo { A, B, C }
+# let x = Foo::B;
+// Bad
+match x {
+    Foo::A => {},
+    Foo::B => {},
+    _ => {},
+}
+
+// Good
+match x {
+    Foo::A => {},
+    Foo::B => {},
+    Foo::C => {},
+}
+```",
+    },
+    LintInfo {
+        lint: crate::indexing_slicing::MATCHED_LEN_OUT_OF_BOUNDS,
+        name: "matched_len_out_of_bounds",
+        group: "correctness",
+        docs: r"**What it does:** Checks for indexing a collection with a constant index in an arm of a
+`match` on its length, like `2 => v[5]` in `match v.len() { .. }`, where the index is out of
+bounds for every length the arm matches.
+
+**Why is this bad?** The indexing always panics in this arm. Either the index or the
+pattern of the arm is wrong.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+// Bad
+match v.len() {
+    2 => v[0] + v[2],
+    _ => 0,
+}
+
+// Good
+match v.len() {
+    2 => v[0] + v[1],
+    _ => 0,
+}
+```
+
+The arms of a `match` on the length are guards of the indexing as well:
+`INDEXING_SLICING` doesn't lint the indexes in bounds for every length the arm matches.",
+    },
+    LintInfo {
+        lint: crate::infinite_iter::MAYBE_INFINITE_ITER,
+        name: "maybe_infinite_iter",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for iteration that may be infinite.
+
+**Why is this bad?** While there may be places where this is acceptable
+(e.g., in event streams), in most cases this is simply an error.
+
+**Known problems:** The code may have a condition to stop iteration, but
+this lint is not clever enough to analyze it.
+
+**Example:**
+```rust
+let infinite_iter = 0..;
+[0..].iter().zip(infinite_iter.take_while(|x| *x > 5));
+```",
+    },
+    LintInfo {
+        lint: crate::mem_discriminant::MEM_DISCRIMINANT_NON_ENUM,
+        name: "mem_discriminant_non_enum",
+        group: "correctness",
+        docs: r#"**What it does:** Checks for calls of `mem::discriminant()` on a non-enum type.
+
+**Why is this bad?** The value of `mem::discriminant()` on non-enum types
+is unspecified.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+use std::mem;
+
+mem::discriminant(&"hello");
+mem::discriminant(&&Some(2));
+```"#,
+    },
+    LintInfo {
+        lint: crate::mem_forget::MEM_FORGET,
+        name: "mem_forget",
+        group: "restriction",
+        docs: r"**What it does:** Checks for usage of `std::mem::forget(t)` where `t` is
+`Drop`.
+
+**Why is this bad?** `std::mem::forget(t)` prevents `t` from running its
+destructor, possibly causing leaks.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# use std::mem;
+# use std::rc::Rc;
+mem::forget(Rc::new(55))
+```",
+    },
+    LintInfo {
+        lint: crate::mem_replace::MEM_REPLACE_OPTION_WITH_NONE,
+        name: "mem_replace_option_with_none",
+        group: "style",
+        docs: r"**What it does:** Checks for `mem::replace()` on an `Option` with
+`None`.
+
+**Why is this bad?** `Option` already has the method `take()` for
+taking its current value (Some(..) or None) and replacing it with
+`None`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+use std::mem;
+
+let mut an_option = Some(0);
+let replaced = mem::replace(&mut an_option, None);
+```
+Is better expressed with:
+```rust
+let mut an_option = Some(0);
+let taken = an_option.take();
+```",
+    },
+    LintInfo {
+        lint: crate::mem_replace::MEM_REPLACE_WITH_DEFAULT,
+        name: "mem_replace_with_default",
+        group: "style",
+        docs: r#"**What it does:** Checks for `std::mem::replace` on a value of type
+`T` with `T::default()`.
+
+**Why is this bad?** `std::mem` module already has the method `take` to
+take the current value and replace it with the default value of that type.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let mut text = String::from("foo");
+let replaced = std::mem::replace(&mut text, String::default());
+```
+Is better expressed with:
+```rust
+let mut text = String::from("foo");
+let taken = std::mem::take(&mut text);
+```"#,
+    },
+    LintInfo {
+        lint: crate::mem_replace::MEM_REPLACE_WITH_UNINIT,
+        name: "mem_replace_with_uninit",
+        group: "correctness",
+        docs: r"**What it does:** Checks for `mem::replace(&mut _, mem::uninitialized())`
+and `mem::replace(&mut _, mem::zeroed())`.
+
+**Why is this bad?** This will lead to undefined behavior even if the
+value is overwritten later, because the uninitialized value may be
+observed in the case of a panic.
+
+**Known problems:** None.
+
+**Example:**
+
+```
+use std::mem;
+# fn may_panic(v: Vec<i32>) -> Vec<i32> { v }
+
+#[allow(deprecated, invalid_value)]
+fn myfunc (v: &mut Vec<i32>) {
+    let taken_v = unsafe { mem::replace(v, mem::uninitialized()) };
+    let new_v = may_panic(taken_v); // undefined behavior on panic
+    mem::forget(mem::replace(v, new_v));
+}
+```
+
+The [take_mut](https://docs.rs/take_mut) crate offers a sound solution,
+at the cost of either lazily creating a replacement value or aborting
+on panic, to ensure that the uninitialized value cannot be observed.",
+    },
+    LintInfo {
+        lint: crate::methods::METHOD_INDEX_OUT_OF_BOUNDS,
+        name: "method_index_out_of_bounds",
+        group: "correctness",
+        docs: r"**What it does:** Checks for calls to `Vec::remove`, `Vec::swap_remove`,
+`Vec::split_off`, `split_at` and `split_at_mut` with a constant index that is out of bounds
+of an array, of a local `Vec` whose length is known, e.g. created with `vec![elem; N]`, or
+of a subslice of them with constant bounds, like `x[..4]`. Calls to `Vec::drain` and
+`Vec::splice` with a range whose constant start or end is greater than the length are
+checked as well.
+
+**Why is this bad?** This will always panic at runtime.
+
+**Known problems:** Hopefully none.
+
+**Example:**
+```no_run
+let mut v = vec![0; 4];
+let x = [1, 2, 3, 4];
+
+// Bad
+v.remove(4);
+x.split_at(5);
+v.drain(0..5);
+
+// Good
+v.remove(3);
+x.split_at(4);
+v.drain(0..4);
+```",
+    },
+    LintInfo {
+        lint: crate::minmax::MIN_MAX,
+        name: "min_max",
+        group: "correctness",
+        docs: r"**What it does:** Checks for expressions where `std::cmp::min` and `max` are
+used to clamp values, but switched so that the result is constant.
+
+**Why is this bad?** This is in all probability not the intended outcome. At
+the least it hurts readability of the code.
+
+**Known problems:** None
+
+**Example:**
+```ignore
+min(0, max(100, x))
+```
+or
+```ignore
+x.max(100).min(0)
+```
+It will always be equal to `0`. Probably the author meant to clamp the value
+between 0 and 100, but has erroneously swapped `min` and `max`.",
+    },
+    LintInfo {
+        lint: crate::attrs::MISMATCHED_TARGET_OS,
+        name: "mismatched_target_os",
+        group: "correctness",
+        docs: r#"**What it does:** Checks for cfg attributes having operating systems used in target family position.
+
+**Why is this bad?** The configuration option will not be recognised and the related item will not be included
+by the conditional compilation engine.
+
+**Known problems:** None.
+
+**Example:**
+
+Bad:
+```rust
+#[cfg(linux)]
+fn conditional() { }
+```
+
+Good:
+```rust
+#[cfg(target_os = "linux")]
+fn conditional() { }
+```
+
+Or:
+```rust
+#[cfg(unix)]
+fn conditional() { }
+```
+Check the [Rust Reference](https://doc.rust-lang.org/reference/conditional-compilation.html#target_os) for more details."#,
+    },
+    LintInfo {
+        lint: crate::assign_ops::MISREFACTORED_ASSIGN_OP,
+        name: "misrefactored_assign_op",
+        group: "complexity",
+        docs: r"**What it does:** Checks for `a op= a op b` or `a op= b op a` patterns.
+
+**Why is this bad?** Most likely these are bugs where one meant to write `a
+op= b`.
+
+**Known problems:** Clippy cannot know for sure if `a op= a op b` should have
+been `a = a op a op b` or `a = a op b`/`a op= b`. Therefore, it suggests both.
+If `a op= a op b` is really the correct behaviour it should be
+written as `a = a op a op b` as it's less confusing.
+
+**Example:**
+```rust
+let mut a = 5;
+let b = 2;
+// ...
+a += a + b;
+```",
+    },
+    LintInfo {
+        lint: crate::missing_const_for_fn::MISSING_CONST_FOR_FN,
+        name: "missing_const_for_fn",
+        group: "nursery",
+        docs: r"**What it does:**
+
+Suggests the use of `const` in functions and methods where possible.
+
+**Why is this bad?**
+
+Not having the function const prevents callers of the function from being const as well.
+
+**Known problems:**
+
+Const functions are currently still being worked on, with some features only being available
+on nightly. This lint does not consider all edge cases currently and the suggestions may be
+incorrect if you are using this lint on stable.
+
+Also, the lint only runs one pass over the code. Consider these two non-const functions:
+
+```rust
+fn a() -> i32 {
+    0
+}
+fn b() -> i32 {
+    a()
+}
+```
+
+When running Clippy, the lint will only suggest to make `a` const, because `b` at this time
+can't be const as it calls a non-const function. Making `a` const and running Clippy again,
+will suggest to make `b` const, too.
+
+**Example:**
+
+```rust
+# struct Foo {
+#     random_number: usize,
+# }
+# impl Foo {
+fn new() -> Self {
+    Self { random_number: 42 }
+}
+# }
+```
+
+Could be a const fn:
+
+```rust
+# struct Foo {
+#     random_number: usize,
+# }
+# impl Foo {
+const fn new() -> Self {
+    Self { random_number: 42 }
+}
+# }
+```",
+    },
+    LintInfo {
+        lint: crate::missing_doc::MISSING_DOCS_IN_PRIVATE_ITEMS,
+        name: "missing_docs_in_private_items",
+        group: "restriction",
+        docs: r"**What it does:** Warns if there is missing doc for any documentable item
+(public or private).
+
+**Why is this bad?** Doc is good. *rustc* has a `MISSING_DOCS`
+allowed-by-default lint for
+public members, but has no way to enforce documentation of private items.
+This lint fixes that.
+
+**Known problems:** None.",
+    },
+    LintInfo {
+        lint: crate::doc::MISSING_ERRORS_DOC,
+        name: "missing_errors_doc",
+        group: "pedantic",
+        docs: r"**What it does:** Checks the doc comments of publicly visible functions that
+return a `Result` type and warns if there is no `# Errors` section.
+
+**Why is this bad?** Documenting the type of errors that can be returned from a
+function can help callers write code to handle the errors appropriately.
+
+**Known problems:** None.
+
+**Examples:**
+
+Since the following function returns a `Result` it has an `# Errors` section in
+its doc comment:
+
+```rust
+# use std::io;
+/// # Errors
+///
+/// Will return `Err` if `filename` does not exist or the user does not have
+/// permission to read it.
+pub fn read(filename: String) -> io::Result<String> {
+    unimplemented!();
+}
+```",
+    },
+    LintInfo {
+        lint: crate::missing_inline::MISSING_INLINE_IN_PUBLIC_ITEMS,
+        name: "missing_inline_in_public_items",
+        group: "restriction",
+        docs: r"**What it does:** it lints if an exported function, method, trait method with default impl,
+or trait method impl is not `#[inline]`.
+
+**Why is this bad?** In general, it is not. Functions can be inlined across
+crates when that's profitable as long as any form of LTO is used. When LTO is disabled,
+functions that are not `#[inline]` cannot be inlined across crates. Certain types of crates
+might intend for most of the methods in their public API to be able to be inlined across
+crates even when LTO is disabled. For these types of crates, enabling this lint might make
+sense. It allows the crate to require all exported methods to be `#[inline]` by default, and
+then opt out for specific methods where this might not make sense.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+pub fn foo() {} // missing #[inline]
+fn ok() {} // ok
+#[inline] pub fn bar() {} // ok
+#[inline(always)] pub fn baz() {} // ok
+
+pub trait Bar {
+  fn bar(); // ok
+  fn def_bar() {} // missing #[inline]
+}
+
+struct Baz;
+impl Baz {
+   fn private() {} // ok
+}
+
+impl Bar for Baz {
+  fn bar() {} // ok - Baz is not exported
+}
+
+pub struct PubBaz;
+impl PubBaz {
+   fn private() {} // ok
+   pub fn not_ptrivate() {} // missing #[inline]
+}
+
+impl Bar for PubBaz {
+   fn bar() {} // missing #[inline]
+   fn def_bar() {} // missing #[inline]
+}
+```",
+    },
+    LintInfo {
+        lint: crate::doc::MISSING_PANICS_DOC,
+        name: "missing_panics_doc",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks the doc comments of publicly visible functions that
+may panic and warns if there is no `# Panics` section.
+
+**Why is this bad?** Documenting the scenarios in which panicking occurs
+can help callers who do not want to panic to avoid those situations.
+
+**Known problems:** None.
+
+**Examples:**
+
+Since the following function may panic it has a `# Panics` section in
+its doc comment:
+
+```rust
+/// # Panics
+///
+/// Will panic if y is 0
+pub fn divide_by(x: i32, y: i32) -> i32 {
+    if y == 0 {
+        panic!("Cannot divide by 0")
+    } else {
+        x / y
+    }
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::doc::MISSING_SAFETY_DOC,
+        name: "missing_safety_doc",
+        group: "style",
+        docs: r"**What it does:** Checks for the doc comments of publicly visible
+unsafe functions and warns if there is no `# Safety` section.
+
+**Why is this bad?** Unsafe functions should document their safety
+preconditions, so that users can be sure they are using them safely.
+
+**Known problems:** None.
+
+**Examples:**
+```rust
+# type Universe = ();
+/// This function should really be documented
+pub unsafe fn start_apocalypse(u: &mut Universe) {
+    unimplemented!();
+}
+```
+
+At least write a line about safety:
+
+```rust
+# type Universe = ();
+/// # Safety
+///
+/// This function should not be called before the horsemen are ready.
+pub unsafe fn start_apocalypse(u: &mut Universe) {
+    unimplemented!();
+}
+```",
+    },
+    LintInfo {
+        lint: crate::literal_representation::MISTYPED_LITERAL_SUFFIXES,
+        name: "mistyped_literal_suffixes",
+        group: "correctness",
+        docs: r"**What it does:** Warns for mistyped suffix in literals
+
+**Why is this bad?** This is most probably a typo
+
+**Known problems:**
+- Recommends a signed suffix, even though the number might be too big and an unsigned
+  suffix is required
+- Does not match on `_127` since that is a valid grouping for decimal and octal numbers
+
+**Example:**
+
+```rust
+// Probably mistyped
+2_32;
+
+// Good
+2_i32;
+```",
+    },
+    LintInfo {
+        lint: crate::misc_early::MIXED_CASE_HEX_LITERALS,
+        name: "mixed_case_hex_literals",
+        group: "style",
+        docs: r"**What it does:** Warns on hexadecimal literals with mixed-case letter
+digits.
+
+**Why is this bad?** It looks confusing.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+// Bad
+let y = 0x1a9BAcD;
+
+// Good
+let y = 0x1A9BACD;
+```",
+    },
+    LintInfo {
+        lint: crate::enum_variants::MODULE_INCEPTION,
+        name: "module_inception",
+        group: "style",
+        docs: r"**What it does:** Checks for modules that have the same name as their
+parent module
+
+**Why is this bad?** A typical beginner mistake is to have `mod foo;` and
+again `mod foo { ..
+}` in `foo.rs`.
+The expectation is that items inside the inner `mod foo { .. }` are then
+available
+through `foo::x`, but they are only available through
+`foo::foo::x`.
+If this is done on purpose, it would be better to choose a more
+representative module name.
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+// lib.rs
+mod foo;
+// foo.rs
+mod foo {
+    ...
+}
+```",
+    },
+    LintInfo {
+        lint: crate::enum_variants::MODULE_NAME_REPETITIONS,
+        name: "module_name_repetitions",
+        group: "pedantic",
+        docs: r"**What it does:** Detects type names that are prefixed or suffixed by the
+containing module's name.
+
+**Why is this bad?** It requires the user to type the module name twice.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+mod cake {
+    struct BlackForestCake;
+}
+```
+Could be written as:
+```rust
+mod cake {
+    struct BlackForest;
+}
+```",
+    },
+    LintInfo {
+        lint: crate::modulo_arithmetic::MODULO_ARITHMETIC,
+        name: "modulo_arithmetic",
+        group: "restriction",
+        docs: r"**What it does:** Checks for modulo arithmetic.
+
+**Why is this bad?** The results of modulo (%) operation might differ
+depending on the language, when negative numbers are involved.
+If you interop with different languages it might be beneficial
+to double check all places that use modulo arithmetic.
+
+For example, in Rust `17 % -3 = 2`, but in Python `17 % -3 = -1`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = -17 % 3;
+```",
+    },
+    LintInfo {
+        lint: crate::misc::MODULO_ONE,
+        name: "modulo_one",
+        group: "correctness",
+        docs: r"**What it does:** Checks for getting the remainder of a division by one or minus
+one.
+
+**Why is this bad?** The result for a divisor of one can only ever be zero; for
+minus one it can cause panic/overflow (if the left operand is the minimal value of
+the respective integer type) or results in zero. No one will write such code
+deliberately, unless trying to win an Underhanded Rust Contest. Even for that
+contest, it's probably a bad idea. Use something more underhanded.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let x = 1;
+let a = x % 1;
+let a = x % -1;
+```",
+    },
+    LintInfo {
+        lint: crate::multiple_crate_versions::MULTIPLE_CRATE_VERSIONS,
+        name: "multiple_crate_versions",
+        group: "cargo",
+        docs: r#"**What it does:** Checks to see if multiple versions of a crate are being
+used.
+
+**Why is this bad?** This bloats the size of targets, and can lead to
+confusing error messages when structs or traits are used interchangeably
+between different versions of a crate.
+
+**Known problems:** Because this can be caused purely by the dependencies
+themselves, it's not always possible to fix this issue.
+
+**Example:**
+```toml
+# This will pull in both winapi v0.3.x and v0.2.x, triggering a warning.
+[dependencies]
+ctrlc = "=3.1.0"
+ansi_term = "=0.11.0"
+```"#,
+    },
+    LintInfo {
+        lint: crate::inherent_impl::MULTIPLE_INHERENT_IMPL,
+        name: "multiple_inherent_impl",
+        group: "restriction",
+        docs: r"**What it does:** Checks for multiple inherent implementations of a struct
+
+**Why is this bad?** Splitting the implementation of a type makes the code harder to navigate.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+struct X;
+impl X {
+    fn one() {}
+}
+impl X {
+    fn other() {}
+}
+```
+
+Could be written:
+
+```rust
+struct X;
+impl X {
+    fn one() {}
+    fn other() {}
+}
+```",
+    },
+    LintInfo {
+        lint: crate::functions::MUST_USE_CANDIDATE,
+        name: "must_use_candidate",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for public functions that have no
+[`#[must_use]`] attribute, but return something not already marked
+must-use, have no mutable arg and mutate no statics.
+
+[`#[must_use]`]: https://doc.rust-lang.org/reference/attributes/diagnostics.html#the-must_use-attribute
+
+**Why is this bad?** Not bad at all, this lint just shows places where
+you could add the attribute.
+
+**Known problems:** The lint only checks the arguments for mutable
+types without looking if they are actually changed. On the other hand,
+it also ignores a broad range of potentially interesting side effects,
+because we cannot decide whether the programmer intends the function to
+be called for the side effect or the result. Expect many false
+positives. At least we don't lint if the result type is unit or already
+`#[must_use]`.
+
+**Examples:**
+```rust
+// this could be annotated with `#[must_use]`.
+fn id<T>(t: T) -> T { t }
+```",
+    },
+    LintInfo {
+        lint: crate::functions::MUST_USE_UNIT,
+        name: "must_use_unit",
+        group: "style",
+        docs: r"**What it does:** Checks for a [`#[must_use]`] attribute on
+unit-returning functions and methods.
+
+[`#[must_use]`]: https://doc.rust-lang.org/reference/attributes/diagnostics.html#the-must_use-attribute
+
+**Why is this bad?** Unit values are useless. The attribute is likely
+a remnant of a refactoring that removed the return type.
+
+**Known problems:** None.
+
+**Examples:**
+```rust
+#[must_use]
+fn useless() { }
+```",
+    },
+    LintInfo {
+        lint: crate::ptr::MUT_FROM_REF,
+        name: "mut_from_ref",
+        group: "correctness",
+        docs: r"**What it does:** This lint checks for functions that take immutable
+references and return mutable ones.
+
+**Why is this bad?** This is trivially unsound, as one can create two
+mutable references from the same (immutable!) source.
+This [error](https://github.com/rust-lang/rust/issues/39465)
+actually lead to an interim Rust release 1.15.1.
+
+**Known problems:** To be on the conservative side, if there's at least one
+mutable reference with the output lifetime, this lint will not trigger.
+In practice, this case is unlikely anyway.
+
+**Example:**
+```ignore
+fn foo(&Foo) -> &mut Bar { .. }
+```",
+    },
+    LintInfo {
+        lint: crate::mut_mut::MUT_MUT,
+        name: "mut_mut",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for instances of `mut mut` references.
+
+**Why is this bad?** Multiple `mut`s don't add anything meaningful to the
+source. This is either a copy'n'paste error, or it shows a fundamental
+misunderstanding of references.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let mut y = 1;
+let x = &mut &mut y;
+```",
+    },
+    LintInfo {
+        lint: crate::mut_mutex_lock::MUT_MUTEX_LOCK,
+        name: "mut_mutex_lock",
+        group: "style",
+        docs: r"**What it does:** Checks for `&mut Mutex::lock` calls
+
+**Why is this bad?** `Mutex::lock` is less efficient than
+calling `Mutex::get_mut`. In addition you also have a statically
+guarantee that the mutex isn't locked, instead of just a runtime
+guarantee.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+use std::sync::{Arc, Mutex};
+
+let mut value_rc = Arc::new(Mutex::new(42_u8));
+let value_mutex = Arc::get_mut(&mut value_rc).unwrap();
+
+let mut value = value_mutex.lock().unwrap();
+*value += 1;
+```
+Use instead:
+```rust
+use std::sync::{Arc, Mutex};
+
+let mut value_rc = Arc::new(Mutex::new(42_u8));
+let value_mutex = Arc::get_mut(&mut value_rc).unwrap();
+
+let value = value_mutex.get_mut().unwrap();
+*value += 1;
+```",
+    },
+    LintInfo {
+        lint: crate::loops::MUT_RANGE_BOUND,
+        name: "mut_range_bound",
+        group: "complexity",
+        docs: r#"**What it does:** Checks for loops which have a range bound that is a mutable variable
+
+**Why is this bad?** One might think that modifying the mutable variable changes the loop bounds
+
+**Known problems:** None
+
+**Example:**
+```rust
+let mut foo = 42;
+for i in 0..foo {
+    foo -= 1;
+    println!("{}", i); // prints numbers from 0 to 42, not 0 to 21
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::mut_key::MUTABLE_KEY_TYPE,
+        name: "mutable_key_type",
+        group: "correctness",
+        docs: r"**What it does:** Checks for sets/maps with mutable key types.
+
+**Why is this bad?** All of `HashMap`, `HashSet`, `BTreeMap` and
+`BtreeSet` rely on either the hash or the order of keys be unchanging,
+so having types with interior mutability is a bad idea.
+
+**Known problems:** It's correct to use a struct, that contains interior mutability
+as a key, when its `Hash` implementation doesn't access any of the interior mutable types.
+However, this lint is unable to recognize this, so it causes a false positive in theses cases.
+The `bytes` crate is a great example of this.
+
+**Example:**
+```rust
+use std::cmp::{PartialEq, Eq};
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::AtomicUsize;
+# #[allow(unused)]
+
+struct Bad(AtomicUsize);
+impl PartialEq for Bad {
+    fn eq(&self, rhs: &Self) -> bool {
+         ..
+; unimplemented!();
+    }
+}
+
+impl Eq for Bad {}
+
+impl Hash for Bad {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        ..
+; unimplemented!();
+    }
+}
+
+fn main() {
+    let _: HashSet<Bad> = HashSet::new();
+}
+```",
+    },
+    LintInfo {
+        lint: crate::mutex_atomic::MUTEX_ATOMIC,
+        name: "mutex_atomic",
+        group: "perf",
+        docs: r"**What it does:** Checks for usages of `Mutex<X>` where an atomic will do.
+
+**Why is this bad?** Using a mutex just to make access to a plain bool or
+reference sequential is shooting flies with cannons.
+`std::sync::atomic::AtomicBool` and `std::sync::atomic::AtomicPtr` are leaner and
+faster.
+
+**Known problems:** This lint cannot detect if the mutex is actually used
+for waiting before a critical section.
+
+**Example:**
+```rust
+# let y = true;
+
+// Bad
+# use std::sync::Mutex;
+let x = Mutex::new(&y);
+
+// Good
+# use std::sync::atomic::AtomicBool;
+let x = AtomicBool::new(y);
+```",
+    },
+    LintInfo {
+        lint: crate::mutex_atomic::MUTEX_INTEGER,
+        name: "mutex_integer",
+        group: "nursery",
+        docs: r"**What it does:** Checks for usages of `Mutex<X>` where `X` is an integral
+type.
+
+**Why is this bad?** Using a mutex just to make access to a plain integer
+sequential is
+shooting flies with cannons. `std::sync::atomic::AtomicUsize` is leaner and faster.
+
+**Known problems:** This lint cannot detect if the mutex is actually used
+for waiting before a critical section.
+
+**Example:**
+```rust
+# use std::sync::Mutex;
+let x = Mutex::new(0usize);
+
+// Good
+# use std::sync::atomic::AtomicUsize;
+let x = AtomicUsize::new(0usize);
+```",
+    },
+    LintInfo {
+        lint: crate::bytecount::NAIVE_BYTECOUNT,
+        name: "naive_bytecount",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for naive byte counts
+
+**Why is this bad?** The [`bytecount`](https://crates.io/crates/bytecount)
+crate has methods to count your bytes faster, especially for large slices.
+
+**Known problems:** If you have predominantly small slices, the
+`bytecount::count(..)` method may actually be slower. However, if you can
+ensure that less than 2³²-1 matches arise, the `naive_count_32(..)` can be
+faster in those cases.
+
+**Example:**
+
+```rust
+# let vec = vec![1_u8];
+&vec.iter().filter(|x| **x == 0u8).count(); // use bytecount::count instead
+```",
+    },
+    LintInfo {
+        lint: crate::needless_arbitrary_self_type::NEEDLESS_ARBITRARY_SELF_TYPE,
+        name: "needless_arbitrary_self_type",
+        group: "complexity",
+        docs: r"**What it does:** The lint checks for `self` in fn parameters that
+specify the `Self`-type explicitly
+**Why is this bad?** Increases the amount and decreases the readability of code
+
+**Known problems:** None
+
+**Example:**
+```rust
+enum ValType {
+    I32,
+    I64,
+    F32,
+    F64,
+}
+
+impl ValType {
+    pub fn bytes(self: Self) -> usize {
+        match self {
+            Self::I32 | Self::F32 => 4,
+            Self::I64 | Self::F64 => 8,
+        }
+    }
+}
+```
+
+Could be rewritten as
+
+```rust
+enum ValType {
+    I32,
+    I64,
+    F32,
+    F64,
+}
+
+impl ValType {
+    pub fn bytes(self) -> usize {
+        match self {
+            Self::I32 | Self::F32 => 4,
+            Self::I64 | Self::F64 => 8,
+        }
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::needless_bool::NEEDLESS_BOOL,
+        name: "needless_bool",
+        group: "complexity",
+        docs: r"**What it does:** Checks for expressions of the form `if c { true } else {
+false }` (or vice versa) and suggests using the condition directly.
+
+**Why is this bad?** Redundant code.
+
+**Known problems:** Maybe false positives: Sometimes, the two branches are
+painstakingly documented (which we, of course, do not detect), so they *may*
+have some value. Even then, the documentation can be rewritten to match the
+shorter code.
+
+**Example:**
+```rust,ignore
+if x {
+    false
+} else {
+    true
+}
+```
+Could be written as
+```rust,ignore
+!x
+```",
+    },
+    LintInfo {
+        lint: crate::needless_borrow::NEEDLESS_BORROW,
+        name: "needless_borrow",
+        group: "nursery",
+        docs: r"**What it does:** Checks for address of operations (`&`) that are going to
+be dereferenced immediately by the compiler.
+
+**Why is this bad?** Suggests that the receiver of the expression borrows
+the expression.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+// Bad
+let x: &i32 = &&&&&&5;
+
+// Good
+let x: &i32 = &5;
+```",
+    },
+    LintInfo {
+        lint: crate::needless_borrowed_ref::NEEDLESS_BORROWED_REFERENCE,
+        name: "needless_borrowed_reference",
+        group: "complexity",
+        docs: r"**What it does:** Checks for bindings that destructure a reference and borrow the inner
+value with `&ref`.
+
+**Why is this bad?** This pattern has no effect in almost all cases.
+
+**Known problems:** In some cases, `&ref` is needed to avoid a lifetime mismatch error.
+Example:
+```rust
+fn foo(a: &Option<String>, b: &Option<String>) {
+    match (a, b) {
+        (None, &ref c) | (&ref c, None) => (),
+        (&Some(ref c), _) => (),
+    };
+}
+```
+
+**Example:**
+Bad:
+```rust
+let mut v = Vec::<String>::new();
+let _ = v.iter_mut().filter(|&ref a| a.is_empty());
+```
+
+Good:
+```rust
+let mut v = Vec::<String>::new();
+let _ = v.iter_mut().filter(|a| a.is_empty());
+```",
+    },
+    LintInfo {
+        lint: crate::loops::NEEDLESS_COLLECT,
+        name: "needless_collect",
+        group: "perf",
+        docs: r"**What it does:** Checks for functions collecting an iterator when collect
+is not needed.
+
+**Why is this bad?** `collect` causes the allocation of a new data structure,
+when this allocation may not be needed.
+
+**Known problems:**
+None
+
+**Example:**
+```rust
+# let iterator = vec![1].into_iter();
+let len = iterator.clone().collect::<Vec<_>>().len();
+// should be
+let len = iterator.count();
+```",
+    },
+    LintInfo {
+        lint: crate::needless_continue::NEEDLESS_CONTINUE,
+        name: "needless_continue",
+        group: "pedantic",
+        docs: r#"**What it does:** The lint checks for `if`-statements appearing in loops
+that contain a `continue` statement in either their main blocks or their
+`else`-blocks, when omitting the `else`-block possibly with some
+rearrangement of code can make the code easier to understand.
+
+**Why is this bad?** Having explicit `else` blocks for `if` statements
+containing `continue` in their THEN branch adds unnecessary branching and
+nesting to the code. Having an else block containing just `continue` can
+also be better written by grouping the statements following the whole `if`
+statement within the THEN block and omitting the else block completely.
+
+**Known problems:** None
+
+**Example:**
+```rust
+# fn condition() -> bool { false }
+# fn update_condition() {}
+# let x = false;
+while condition() {
+    update_condition();
+    if x {
+        // ...
+    } else {
+        continue;
+    }
+    println!("Hello, world");
+}
+```
+
+Could be rewritten as
+
+```rust
+# fn condition() -> bool { false }
+# fn update_condition() {}
+# let x = false;
+while condition() {
+    update_condition();
+    if x {
+        // ...
+        println!("Hello, world");
+    }
+}
+```
+
+As another example, the following code
+
+```rust
+# fn waiting() -> bool { false }
+loop {
+    if waiting() {
+        continue;
+    } else {
+        // Do something useful
+    }
+    # break;
+}
+```
+Could be rewritten as
+
+```rust
+# fn waiting() -> bool { false }
+loop {
+    if waiting() {
+        continue;
+    }
+    // Do something useful
+    # break;
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::doc::NEEDLESS_DOCTEST_MAIN,
+        name: "needless_doctest_main",
+        group: "style",
+        docs: r"**What it does:** Checks for `fn main() { .. }` in doctests
+
+**Why is this bad?** The test can be shorter (and likely more readable)
+if the `fn main()` is left implicit.
+
+**Known problems:** None.
+
+**Examples:**
+``````rust
+/// An example of a doctest with a `main()` function
+///
+/// # Examples
+///
+/// ```
+/// fn main() {
+///     // this needs not be in an `fn`
+/// }
+/// ```
+fn needless_main() {
+    unimplemented!();
+}
+``````",
+    },
+    LintInfo {
+        lint: crate::needless_for_each::NEEDLESS_FOR_EACH,
+        name: "needless_for_each",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for usage of `for_each` that would be more simply written as a
+`for` loop.
+
+**Why is this bad?** `for_each` may be used after applying iterator transformers like
+`filter` for better readability and performance. It may also be used to fit a simple
+operation on one line.
+But when none of these apply, a simple `for` loop is more idiomatic.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+let v = vec![0, 1, 2];
+v.iter().for_each(|elem| {
+    println!("{}", elem);
+})
+```
+Use instead:
+```rust
+let v = vec![0, 1, 2];
+for elem in v.iter() {
+    println!("{}", elem);
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::lifetimes::NEEDLESS_LIFETIMES,
+        name: "needless_lifetimes",
+        group: "complexity",
+        docs: r"**What it does:** Checks for lifetime annotations which can be removed by
+relying on lifetime elision.
+
+**Why is this bad?** The additional lifetimes make the code look more
+complicated, while there is nothing out of the ordinary going on. Removing
+them leads to more readable code.
+
+**Known problems:**
+- We bail out if the function has a `where` clause where lifetimes
+are mentioned due to potenial false positives.
+- Lifetime bounds such as `impl Foo + 'a` and `T: 'a` must be elided with the
+placeholder notation `'_` because the fully elided notation leaves the type bound to `'static`.
+
+**Example:**
+```rust
+// Bad: unnecessary lifetime annotations
+fn in_and_out<'a>(x: &'a u8, y: u8) -> &'a u8 {
+    x
+}
+
+// Good
+fn elided(x: &u8, y: u8) -> &u8 {
+    x
+}
+```",
+    },
+    LintInfo {
+        lint: crate::needless_pass_by_value::NEEDLESS_PASS_BY_VALUE,
+        name: "needless_pass_by_value",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for functions taking arguments by value, but not
+consuming them in its
+body.
+
+**Why is this bad?** Taking arguments by reference is more flexible and can
+sometimes avoid
+unnecessary allocations.
+
+**Known problems:**
+* This lint suggests taking an argument by reference,
+however sometimes it is better to let users decide the argument type
+(by using `Borrow` trait, for example), depending on how the function is used.
+
+**Example:**
+```rust
+fn foo(v: Vec<i32>) {
+    assert_eq!(v.len(), 42);
+}
+```
+should be
+```rust
+fn foo(v: &[i32]) {
+    assert_eq!(v.len(), 42);
+}
+```",
+    },
+    LintInfo {
+        lint: crate::needless_question_mark::NEEDLESS_QUESTION_MARK,
+        name: "needless_question_mark",
+        group: "complexity",
+        docs: r"**What it does:**
+Suggests alternatives for useless applications of `?` in terminating expressions
+
+**Why is this bad?** There's no reason to use `?` to short-circuit when execution of the body will end there anyway.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+struct TO {
+    magic: Option<usize>,
+}
+
+fn f(to: TO) -> Option<usize> {
+    Some(to.magic?)
+}
+
+struct TR {
+    magic: Result<usize, bool>,
+}
+
+fn g(tr: Result<TR, bool>) -> Result<usize, bool> {
+    tr.and_then(|t| Ok(t.magic?))
+}
+
+```
+Use instead:
+```rust
+struct TO {
+    magic: Option<usize>,
+}
+
+fn f(to: TO) -> Option<usize> {
+   to.magic
+}
+
+struct TR {
+    magic: Result<usize, bool>,
+}
+
+fn g(tr: Result<TR, bool>) -> Result<usize, bool> {
+    tr.and_then(|t| t.magic)
+}
+```",
+    },
+    LintInfo {
+        lint: crate::loops::NEEDLESS_RANGE_LOOP,
+        name: "needless_range_loop",
+        group: "style",
+        docs: r#"**What it does:** Checks for looping over the range of `0..len` of some
+collection just to get the values by index.
+
+**Why is this bad?** Just iterating the collection itself makes the intent
+more clear and is probably faster.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let vec = vec!['a', 'b', 'c'];
+for i in 0..vec.len() {
+    println!("{}", vec[i]);
+}
+```
+Could be written as:
+```rust
+let vec = vec!['a', 'b', 'c'];
+for i in vec {
+    println!("{}", i);
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::returns::NEEDLESS_RETURN,
+        name: "needless_return",
+        group: "style",
+        docs: r"**What it does:** Checks for return statements at the end of a block.
+
+**Why is this bad?** Removing the `return` and semicolon will make the code
+more rusty.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn foo(x: usize) -> usize {
+    return x;
+}
+```
+simplify to
+```rust
+fn foo(x: usize) -> usize {
+    x
+}
+```",
+    },
+    LintInfo {
+        lint: crate::needless_update::NEEDLESS_UPDATE,
+        name: "needless_update",
+        group: "complexity",
+        docs: r"**What it does:** Checks for needlessly including a base struct on update
+when all fields are changed anyway.
+
+This lint is not applied to structs marked with
+[non_exhaustive](https://doc.rust-lang.org/reference/attributes/type_system.html).
+
+**Why is this bad?** This will cost resources (because the base has to be
+somewhere), and make the code less readable.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# struct Point {
+#     x: i32,
+#     y: i32,
+#     z: i32,
+# }
+# let zero_point = Point { x: 0, y: 0, z: 0 };
+
+// Bad
+Point {
+    x: 1,
+    y: 1,
+    z: 1,
+    ..zero_point
+};
+
+// Ok
+Point {
+    x: 1,
+    y: 1,
+    ..zero_point
+};
+```",
+    },
+    LintInfo {
+        lint: crate::neg_cmp_op_on_partial_ord::NEG_CMP_OP_ON_PARTIAL_ORD,
+        name: "neg_cmp_op_on_partial_ord",
+        group: "complexity",
+        docs: r"**What it does:**
+Checks for the usage of negated comparison operators on types which only implement
+`PartialOrd` (e.g., `f64`).
+
+**Why is this bad?**
+These operators make it easy to forget that the underlying types actually allow not only three
+potential Orderings (Less, Equal, Greater) but also a fourth one (Uncomparable). This is
+especially easy to miss if the operator based comparison result is negated.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+use std::cmp::Ordering;
+
+// Bad
+let a = 1.0;
+let b = f64::NAN;
+
+let _not_less_or_equal = !(a <= b);
+
+// Good
+let a = 1.0;
+let b = f64::NAN;
+
+let _not_less_or_equal = match a.partial_cmp(&b) {
+    None | Some(Ordering::Greater) => true,
+    _ => false,
+};
+```",
+    },
+    LintInfo {
+        lint: crate::neg_multiply::NEG_MULTIPLY,
+        name: "neg_multiply",
+        group: "style",
+        docs: r"**What it does:** Checks for multiplication by -1 as a form of negation.
+
+**Why is this bad?** It's more readable to just negate.
+
+**Known problems:** This only catches integers (for now).
+
+**Example:**
+```ignore
+x * -1
+```",
+    },
+    LintInfo {
+        lint: crate::loops::NEVER_LOOP,
+        name: "never_loop",
+        group: "correctness",
+        docs: r"**What it does:** Checks for loops that will always `break`, `return` or
+`continue` an outer loop.
+
+**Why is this bad?** This loop never loops, all it does is obfuscating the
+code.
+
+**Known problems:** None
+
+**Example:**
+```rust
+loop {
+    ..;
+    break;
+}
+```",
+    },
+    LintInfo {
+        lint: crate::methods::NEW_RET_NO_SELF,
+        name: "new_ret_no_self",
+        group: "style",
+        docs: r"**What it does:** Checks for `new` not returning a type that contains `Self`.
+
+**Why is this bad?** As a convention, `new` methods are used to make a new
+instance of a type.
+
+**Known problems:** None.
+
+**Example:**
+In an impl block:
+```rust
+# struct Foo;
+# struct NotAFoo;
+impl Foo {
+    fn new() -> NotAFoo {
+# NotAFoo
+    }
+}
+```
+
+```rust
+# struct Foo;
+struct Bar(Foo);
+impl Foo {
+    // Bad. The type name must contain `Self`
+    fn new() -> Bar {
+# Bar(Foo)
+    }
+}
+```
+
+```rust
+# struct Foo;
+# struct FooError;
+impl Foo {
+    // Good. Return type contains `Self`
+    fn new() -> Result<Foo, FooError> {
+# Ok(Foo)
+    }
+}
+```
+
+Or in a trait definition:
+```rust
+pub trait Trait {
+    // Bad. The type name must contain `Self`
+    fn new();
+}
+```
+
+```rust
+pub trait Trait {
+    // Good. Return type contains `Self`
+    fn new() -> Self;
+}
+```",
+    },
+    LintInfo {
+        lint: crate::new_without_default::NEW_WITHOUT_DEFAULT,
+        name: "new_without_default",
+        group: "style",
+        docs: r"**What it does:** Checks for types with a `fn new() -> Self` method and no
+implementation of
+[`Default`](https://doc.rust-lang.org/std/default/trait.Default.html).
+
+**Why is this bad?** The user might expect to be able to use
+[`Default`](https://doc.rust-lang.org/std/default/trait.Default.html) as the
+type can be constructed without arguments.
+
+**Known problems:** Hopefully none.
+
+**Example:**
+
+```ignore
+struct Foo(Bar);
+
+impl Foo {
+    fn new() -> Self {
+        Foo(Bar::new())
+    }
+}
+```
+
+To fix the lint, add a `Default` implementation that delegates to `new`:
+
+```ignore
+struct Foo(Bar);
+
+impl Default for Foo {
+    fn default() -> Self {
+        Foo::new()
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::no_effect::NO_EFFECT,
+        name: "no_effect",
+        group: "complexity",
+        docs: r"**What it does:** Checks for statements which have no effect.
+
+**Why is this bad?** Similar to dead code, these statements are actually
+executed. However, as they have no effect, all they do is make the code less
+readable.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+0;
+```",
+    },
+    LintInfo {
+        lint: crate::unicode::NON_ASCII_LITERAL,
+        name: "non_ascii_literal",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for non-ASCII characters in string literals.
+
+**Why is this bad?** Yeah, we know, the 90's called and wanted their charset
+back. Even so, there still are editors and other programs out there that
+don't work well with Unicode. So if the code is meant to be used
+internationally, on multiple operating systems, or has other portability
+requirements, activating this lint could be useful.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = String::from("€");
+```
+Could be written as:
+```rust
+let x = String::from("\u{20ac}");
+```"#,
+    },
+    LintInfo {
+        lint: crate::non_octal_unix_permissions::NON_OCTAL_UNIX_PERMISSIONS,
+        name: "non_octal_unix_permissions",
+        group: "correctness",
+        docs: r"**What it does:** Checks for non-octal values used to set Unix file permissions.
+
+**Why is this bad?** They will be converted into octal, creating potentially
+unintended file permissions.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust,ignore
+use std::fs::OpenOptions;
+use std::os::unix::fs::OpenOptionsExt;
+
+let mut options = OpenOptions::new();
+options.mode(644);
+```
+Use instead:
+```rust,ignore
+use std::fs::OpenOptions;
+use std::os::unix::fs::OpenOptionsExt;
+
+let mut options = OpenOptions::new();
+options.mode(0o644);
+```",
+    },
+    LintInfo {
+        lint: crate::booleans::NONMINIMAL_BOOL,
+        name: "nonminimal_bool",
+        group: "complexity",
+        docs: r"**What it does:** Checks for boolean expressions that can be written more
+concisely.
+
+**Why is this bad?** Readability of boolean expressions suffers from
+unnecessary duplication.
+
+**Known problems:** Ignores short circuiting behavior of `||` and
+`&&`. Ignores `|`, `&` and `^`.
+
+**Example:**
+```ignore
+if a && true  // should be: if a
+if !(a == b)  // should be: if a != b
+```",
+    },
+    LintInfo {
+        lint: crate::open_options::NONSENSICAL_OPEN_OPTIONS,
+        name: "nonsensical_open_options",
+        group: "correctness",
+        docs: r"**What it does:** Checks for duplicate open options as well as combinations
+that make no sense.
+
+**Why is this bad?** In the best case, the code will be harder to read than
+necessary. I don't know the worst case.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+use std::fs::OpenOptions;
+
+OpenOptions::new().read(true).truncate(true);
+```",
+    },
+    LintInfo {
+        lint: crate::methods::NONZERO_NEW_UNWRAP,
+        name: "nonzero_new_unwrap",
+        group: "restriction",
+        docs: r"**What it does:** Checks for `.unwrap()` on the result of `NonZeroUsize::new` and the
+`new` functions of the other nonzero integer types, when the value isn't known to be
+nonzero. Like for `unchecked_division`, a value is known to be nonzero if it is a nonzero
+constant, or a variable guarded by a comparison like `n != 0` or `n > 0` (or `n < 0` if it is
+signed).
+
+**Why is this bad?** `new` returns `None` for zero, so the `unwrap()` panics. The zero case
+should be handled, or ruled out with `new_unchecked` in an `unsafe` block documenting why
+the value can't be zero.
+
+**Known problems:** Only simple guards of the value are recognized.
+
+**Example:**
+```rust
+# use std::num::NonZeroUsize;
+fn chunk_size(n: usize) -> NonZeroUsize {
+    NonZeroUsize::new(n).unwrap()
+}
+```
+Use instead:
+```rust
+# use std::num::NonZeroUsize;
+fn chunk_size(n: usize) -> Option<NonZeroUsize> {
+    NonZeroUsize::new(n)
+}
+```",
+    },
+    LintInfo {
+        lint: crate::functions::NOT_UNSAFE_PTR_ARG_DEREF,
+        name: "not_unsafe_ptr_arg_deref",
+        group: "correctness",
+        docs: r#"**What it does:** Checks for public functions that dereference raw pointer
+arguments but are not marked `unsafe`.
+
+**Why is this bad?** The function should probably be marked `unsafe`, since
+for an arbitrary raw pointer, there is no way of telling for sure if it is
+valid.
+
+**Known problems:**
+
+* It does not check functions recursively so if the pointer is passed to a
+private non-`unsafe` function which does the dereferencing, the lint won't
+trigger.
+* It only checks for arguments whose type are raw pointers, not raw pointers
+got from an argument in some other way (`fn foo(bar: &[*const u8])` or
+`some_argument.get_raw_ptr()`).
+
+**Example:**
+```rust,ignore
+// Bad
+pub fn foo(x: *const u8) {
+    println!("{}", unsafe { *x });
+}
+
+// Good
+pub unsafe fn foo(x: *const u8) {
+    println!("{}", unsafe { *x });
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::indexing_slicing::NTH_OUT_OF_BOUNDS,
+        name: "nth_out_of_bounds",
+        group: "correctness",
+        docs: r"**What it does:** Checks for `nth` with a constant argument on iterators of a known length,
+i.e. iterators over an array or a local `Vec` whose length is known (see
+`out_of_bounds_indexing`) and ranges with constant bounds, where the argument is at least
+the length.
+
+**Why is this bad?** The result is always `None`, which is most likely a bug, like an out
+of bounds index.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = [1, 2, 3, 4];
+
+// Bad
+let a = x.iter().nth(9);
+let b = (0..3).nth(10);
+
+// Good
+let a = x.iter().nth(3);
+let b = (0..3).nth(2);
+```",
+    },
+    LintInfo {
+        lint: crate::methods::OK_EXPECT,
+        name: "ok_expect",
+        group: "style",
+        docs: r#"**What it does:** Checks for usage of `ok().expect(..)`.
+
+**Why is this bad?** Because you usually call `expect()` on the `Result`
+directly to get a better error message.
+
+**Known problems:** The error type needs to implement `Debug`
+
+**Example:**
+```rust
+# let x = Ok::<_, ()>(());
+
+// Bad
+x.ok().expect("why did I do this again?");
+
+// Good
+x.expect("why did I do this again?");
+```"#,
+    },
+    LintInfo {
+        lint: crate::eq_op::OP_REF,
+        name: "op_ref",
+        group: "style",
+        docs: r"**What it does:** Checks for arguments to `==` which have their address
+taken to satisfy a bound
+and suggests to dereference the other argument instead
+
+**Why is this bad?** It is more idiomatic to dereference the other argument.
+
+**Known problems:** None
+
+**Example:**
+```ignore
+// Bad
+&x == y
+
+// Good
+x == *y
+```",
+    },
+    LintInfo {
+        lint: crate::methods::OPTION_AS_REF_DEREF,
+        name: "option_as_ref_deref",
+        group: "complexity",
+        docs: r#"**What it does:** Checks for usage of `_.as_ref().map(Deref::deref)` or it's aliases (such as String::as_str).
+
+**Why is this bad?** Readability, this can be written more concisely as
+`_.as_deref()`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let opt = Some("".to_string());
+opt.as_ref().map(String::as_str)
+# ;
+```
+Can be written as
+```rust
+# let opt = Some("".to_string());
+opt.as_deref()
+# ;
+```"#,
+    },
+    LintInfo {
+        lint: crate::option_env_unwrap::OPTION_ENV_UNWRAP,
+        name: "option_env_unwrap",
+        group: "correctness",
+        docs: r#"**What it does:** Checks for usage of `option_env!(...).unwrap()` and
+suggests usage of the `env!` macro.
+
+**Why is this bad?** Unwrapping the result of `option_env!` will panic
+at run-time if the environment variable doesn't exist, whereas `env!`
+catches it at compile-time.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust,no_run
+let _ = option_env!("HOME").unwrap();
+```
+
+Is better expressed as:
+
+```rust,no_run
+let _ = env!("HOME");
+```"#,
+    },
+    LintInfo {
+        lint: crate::methods::OPTION_FILTER_MAP,
+        name: "option_filter_map",
+        group: "complexity",
+        docs: r"**What it does:** Checks for indirect collection of populated `Option`
+
+**Why is this bad?** `Option` is like a collection of 0-1 things, so `flatten`
+automatically does this without suspicious-looking `unwrap` calls.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+let _ = std::iter::empty::<Option<i32>>().filter(Option::is_some).map(Option::unwrap);
+```
+Use instead:
+```rust
+let _ = std::iter::empty::<Option<i32>>().flatten();
+```",
+    },
+    LintInfo {
+        lint: crate::option_if_let_else::OPTION_IF_LET_ELSE,
+        name: "option_if_let_else",
+        group: "pedantic",
+        docs: r"**What it does:**
+Lints usage of `if let Some(v) = ... { y } else { x }` which is more
+idiomatically done with `Option::map_or` (if the else bit is a pure
+expression) or `Option::map_or_else` (if the else bit is an impure
+expression).
+
+**Why is this bad?**
+Using the dedicated functions of the Option type is clearer and
+more concise than an `if let` expression.
+
+**Known problems:**
+This lint uses a deliberately conservative metric for checking
+if the inside of either body contains breaks or continues which will
+cause it to not suggest a fix if either block contains a loop with
+continues or breaks contained within the loop.
+
+**Example:**
+
+```rust
+# let optional: Option<u32> = Some(0);
+# fn do_complicated_function() -> u32 { 5 };
+let _ = if let Some(foo) = optional {
+    foo
+} else {
+    5
+};
+let _ = if let Some(foo) = optional {
+    foo
+} else {
+    let y = do_complicated_function();
+    y*y
+};
+```
+
+should be
+
+```rust
+# let optional: Option<u32> = Some(0);
+# fn do_complicated_function() -> u32 { 5 };
+let _ = optional.map_or(5, |foo| foo);
+let _ = optional.map_or_else(||{
+    let y = do_complicated_function();
+    y*y
+}, |foo| foo);
+```",
+    },
+    LintInfo {
+        lint: crate::methods::OPTION_MAP_OR_NONE,
+        name: "option_map_or_none",
+        group: "style",
+        docs: r"**What it does:** Checks for usage of `_.map_or(None, _)`.
+
+**Why is this bad?** Readability, this can be written more concisely as
+`_.and_then(_)`.
+
+**Known problems:** The order of the arguments is not in execution order.
+
+**Example:**
+```rust
+# let opt = Some(1);
+
+// Bad
+opt.map_or(None, |a| Some(a + 1));
+
+// Good
+opt.and_then(|a| Some(a + 1));
+```",
+    },
+    LintInfo {
+        lint: crate::map_unit_fn::OPTION_MAP_UNIT_FN,
+        name: "option_map_unit_fn",
+        group: "complexity",
+        docs: r"**What it does:** Checks for usage of `option.map(f)` where f is a function
+or closure that returns the unit type `()`.
+
+**Why is this bad?** Readability, this can be written more clearly with
+an if let statement
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+# fn do_stuff() -> Option<String> { Some(String::new()) }
+# fn log_err_msg(foo: String) -> Option<String> { Some(foo) }
+# fn format_msg(foo: String) -> String { String::new() }
+let x: Option<String> = do_stuff();
+x.map(log_err_msg);
+# let x: Option<String> = do_stuff();
+x.map(|msg| log_err_msg(format_msg(msg)));
+```
+
+The correct use would be:
+
+```rust
+# fn do_stuff() -> Option<String> { Some(String::new()) }
+# fn log_err_msg(foo: String) -> Option<String> { Some(foo) }
+# fn format_msg(foo: String) -> String { String::new() }
+let x: Option<String> = do_stuff();
+if let Some(msg) = x {
+    log_err_msg(msg);
+}
+
+# let x: Option<String> = do_stuff();
+if let Some(msg) = x {
+    log_err_msg(format_msg(msg));
+}
+```",
+    },
+    LintInfo {
+        lint: crate::types::OPTION_OPTION,
+        name: "option_option",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for use of `Option<Option<_>>` in function signatures and type
+definitions
+
+**Why is this bad?** `Option<_>` represents an optional value. `Option<Option<_>>`
+represents an optional optional value which is logically the same thing as an optional
+value but has an unneeded extra level of wrapping.
+
+If you have a case where `Some(Some(_))`, `Some(None)` and `None` are distinct cases,
+consider a custom `enum` instead, with clear names for each case.
+
+**Known problems:** None.
+
+**Example**
+```rust
+fn get_data() -> Option<Option<u32>> {
+    None
+}
+```
+
+Better:
+
+```rust
+pub enum Contents {
+    Data(Vec<u8>), // Was Some(Some(Vec<u8>))
+    NotYetFetched, // Was Some(None)
+    None,          // Was None
+}
+
+fn get_data() -> Contents {
+    Contents::None
+}
+```",
+    },
+    LintInfo {
+        lint: crate::methods::OR_FUN_CALL,
+        name: "or_fun_call",
+        group: "perf",
+        docs: r"**What it does:** Checks for calls to `.or(foo(..))`, `.unwrap_or(foo(..))`,
+etc., and suggests to use `or_else`, `unwrap_or_else`, etc., or
+`unwrap_or_default` instead.
+
+**Why is this bad?** The function will always be called and potentially
+allocate an object acting as the default.
+
+**Known problems:** If the function has side-effects, not calling it will
+change the semantic of the program, but you shouldn't rely on that anyway.
+
+**Example:**
+```rust
+# let foo = Some(String::new());
+foo.unwrap_or(String::new());
+```
+this can instead be written:
+```rust
+# let foo = Some(String::new());
+foo.unwrap_or_else(String::new);
+```
+or
+```rust
+# let foo = Some(String::new());
+foo.unwrap_or_default();
+```",
+    },
+    LintInfo {
+        lint: crate::indexing_slicing::OUT_OF_BOUNDS_INDEXING,
+        name: "out_of_bounds_indexing",
+        group: "correctness",
+        docs: r"**What it does:** Checks for out of bounds array indexing with a constant
+index, or an index that is known to be at least the length, e.g. `x[i]`
+inside `if i >= 4 { .. }` for an array of length 4. Local `Vec`s created with `vec![elem; N]` (or `Vec::with_capacity`
+followed by `resize`) that are not otherwise mutated are checked as well, and so are slices
+created from arrays, e.g. `s[4]` after `let s: &[i32] = &x;`, and array fields accessed
+through a reference, e.g. `self.field[4]`. Calls of the `const fn`s of the crate with
+constant arguments are evaluated, e.g. `x[magic_index()]`.
+
+**Why is this bad?** This will always panic at runtime.
+
+**Known problems:** Hopefully none.
+
+**Example:**
+```no_run
+# #![allow(const_err)]
+let x = [1, 2, 3, 4];
+let v = vec![0; 4];
+
+// Bad
+x[9];
+&x[2..9];
+v[4];
+
+// Good
+x[0];
+x[3];
+v[3];
+```",
+    },
+    LintInfo {
+        lint: crate::overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL,
+        name: "overflow_check_conditional",
+        group: "complexity",
+        docs: r"**What it does:** Detects classic underflow/overflow checks.
+
+**Why is this bad?** Most classic C underflow/overflow checks will fail in
+Rust. Users can use functions like `overflowing_*` and `wrapping_*` instead.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let a = 1;
+# let b = 2;
+a + b < a;
+```",
+    },
+    LintInfo {
+        lint: crate::panic_unimplemented::PANIC,
+        name: "panic",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for usage of `panic!`.
+
+**Why is this bad?** `panic!` will stop the execution of the executable
+
+**Known problems:** None.
+
+**Example:**
+```no_run
+panic!("even with a good reason");
+```"#,
+    },
+    LintInfo {
+        lint: crate::panic_in_drop::PANIC_IN_DROP,
+        name: "panic_in_drop",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for `unwrap()` and `expect()` on an `Option` or `Result`,
+indexing, and panicking macros like `panic!()`, `unreachable!()` and `assert!()` in
+implementations of `Drop::drop`.
+
+**Why is this bad?** Values are dropped while the stack is unwound after a panic. A panic in
+`drop` during the unwinding aborts the process, without running the remaining destructors
+or reporting the first panic to a `catch_unwind`.
+
+**Known problems:** Functions called from `drop` aren't checked.
+
+**Example:**
+
+```rust
+# use std::io::Write;
+struct Log(std::fs::File);
+
+// Bad
+impl Drop for Log {
+    fn drop(&mut self) {
+        self.0.flush().unwrap();
+    }
+}
+```
+Use instead:
+```rust
+# use std::io::Write;
+# struct Log(std::fs::File);
+impl Drop for Log {
+    fn drop(&mut self) {
+        if let Err(e) = self.0.flush() {
+            eprintln!("failed to flush the log: {}", e);
+        }
+    }
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::panic_in_fmt::PANIC_IN_FMT,
+        name: "panic_in_fmt",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for `unwrap()` and `expect()` on an `Option` or `Result`,
+indexing and slicing in implementations of `Display::fmt` and `Debug::fmt`.
+
+**Why is this bad?** Values are formatted in log messages, error reports and panic
+messages, far from the code that broke the invariant. A panic while formatting hides the
+message being written, and a panic while formatting a panic message aborts the process.
+Writing a fallback keeps the output readable.
+
+**Known problems:** Functions called from `fmt` aren't checked.
+
+**Example:**
+
+```rust
+# use std::fmt;
+struct Name {
+    parts: Vec<String>,
+}
+
+// Bad
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.parts[0])
+    }
+}
+```
+Use instead:
+```rust
+# use std::fmt;
+# struct Name {
+#     parts: Vec<String>,
+# }
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.parts.first() {
+            Some(first) => write!(f, "{}", first),
+            None => write!(f, "<unnamed>"),
+        }
+    }
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::panic_unimplemented::PANIC_IN_LIBRARY,
+        name: "panic_in_library",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for usage of `panic!`, `unimplemented!`, `todo!` and
+`unreachable!` in crates that are not compiled as an executable.
+
+**Why is this bad?** A library can't know whether its caller is able to recover from an
+error. Returning a `Result` or an `Option` leaves that decision to the caller, a panic
+takes it away.
+
+**Known problems:** Test harnesses are executables, so `#[cfg(test)]` code of a library
+is only checked when the library itself is compiled.
+
+**Example:**
+```rust
+pub fn parse_digit(c: char) -> u32 {
+    match c.to_digit(10) {
+        Some(d) => d,
+        None => panic!("not a digit: {}", c),
+    }
+}
+```
+Use instead:
+```rust
+pub fn parse_digit(c: char) -> Option<u32> {
+    c.to_digit(10)
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::panic_in_result_fn::PANIC_IN_RESULT_FN,
+        name: "panic_in_result_fn",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for usage of `panic!`, `unimplemented!`, `todo!`, `unreachable!` or assertions in a function of type result.
+
+**Why is this bad?** For some codebases, it is desirable for functions of type result to return an error instead of crashing. Hence panicking macros should be avoided.
+
+**Known problems:** Functions called from a function returning a `Result` may invoke a panicking macro. This is not checked.
+
+**Example:**
+
+```rust
+fn result_with_panic() -> Result<bool, String>
+{
+    panic!("error");
+}
+```
+Use instead:
+```rust
+fn result_without_panic() -> Result<bool, String> {
+    Err(String::from("error"))
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::panicking_index_impl::PANICKING_INDEX_IMPL,
+        name: "panicking_index_impl",
+        group: "restriction",
+        docs: r"**What it does:** Checks for `unwrap()`, `expect()` and indexing in implementations of
+`Index` and `IndexMut`, unless the panic is documented in a `# Panics` section or the type
+has a `get` method.
+
+**Why is this bad?** Indexing with `[]` looks the same for every type, so the panic is
+hidden from the users of the type. Without a `get`-style accessor, they can't look up an
+element that might be missing without panicking.
+
+**Known problems:** Functions called from the implementation may panic as well. This is
+not checked.
+
+**Example:**
+
+```rust
+use std::ops::Index;
+
+struct Registry {
+    names: Vec<String>,
+}
+
+impl Index<usize> for Registry {
+    type Output = String;
+
+    fn index(&self, id: usize) -> &String {
+        &self.names[id]
+    }
+}
+```
+Use instead:
+```rust
+# use std::ops::Index;
+# struct Registry {
+#     names: Vec<String>,
+# }
+impl Registry {
+    pub fn get(&self, id: usize) -> Option<&String> {
+        self.names.get(id)
+    }
+}
+
+impl Index<usize> for Registry {
+    type Output = String;
+
+    fn index(&self, id: usize) -> &String {
+        &self.names[id]
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::unwrap::PANICKING_UNWRAP,
+        name: "panicking_unwrap",
+        group: "correctness",
+        docs: r"**What it does:** Checks for calls of `unwrap[_err]()` that will always fail.
+
+**Why is this bad?** If panicking is desired, an explicit `panic!()` should be used.
+
+**Known problems:** This lint only checks `if` conditions not assignments.
+So something like `let x: Option<()> = None; x.unwrap();` will not be recognized.
+
+**Example:**
+```rust
+# let option = Some(0);
+# fn do_something_with(_x: usize) {}
+if option.is_none() {
+    do_something_with(option.unwrap())
+}
+```
+
+This code will always panic. The if condition should probably be inverted.",
+    },
+    LintInfo {
+        lint: crate::panicking_index_impl::PANICKING_USER_INDEX,
+        name: "panicking_user_index",
+        group: "restriction",
+        docs: r"**What it does:** Checks for indexing a type from outside of the standard library whose
+`Index` or `IndexMut` implementation documents a panic in a `# Panics` section, or calls
+`panic!`, `assert!` or a similar macro.
+
+**Why is this bad?** Like indexing a slice out of bounds, the indexing panics on some
+values, but `[]` doesn't show it. `indexing_slicing` only checks slices, arrays and `Vec`s.
+
+**Known problems:** Functions called from the implementation may panic as well. This is
+not checked. The implementations of other crates are only checked for a `# Panics` section.
+
+**Example:**
+
+```rust
+use std::ops::Index;
+
+struct Grid {
+    cells: Vec<u8>,
+}
+
+impl Grid {
+    fn get(&self, i: usize) -> Option<&u8> {
+        self.cells.get(i)
+    }
+}
+
+impl Index<usize> for Grid {
+    type Output = u8;
+
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    fn index(&self, i: usize) -> &u8 {
+        &self.cells[i]
+    }
+}
+
+# let grid = Grid { cells: vec![0] };
+// Bad
+let cell = grid[0];
+
+// Good
+let cell = grid.get(0);
+```",
+    },
+    LintInfo {
+        lint: crate::partialeq_ne_impl::PARTIALEQ_NE_IMPL,
+        name: "partialeq_ne_impl",
+        group: "complexity",
+        docs: r"**What it does:** Checks for manual re-implementations of `PartialEq::ne`.
+
+**Why is this bad?** `PartialEq::ne` is required to always return the
+negated result of `PartialEq::eq`, which is exactly what the default
+implementation does. Therefore, there should never be any need to
+re-implement it.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+struct Foo;
+
+impl PartialEq for Foo {
+   fn eq(&self, other: &Foo) -> bool { true }
+   fn ne(&self, other: &Foo) -> bool { !(self == other) }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::partially_read_buffer::PARTIALLY_READ_BUFFER,
+        name: "partially_read_buffer",
+        group: "nursery",
+        docs: r"**What it does:** Checks for array buffers that are filled with `Read::read`, whose count of
+read bytes is bound to a variable, and then used as a whole, like `&buf[..]` or `&buf`.
+
+**Why is this bad?** `read` may fill only a prefix of the buffer. The rest holds its initial
+zeroes or the data of a previous read, which is then used as if it had just been read, e.g.
+written to a file or sent over the network.
+
+**Known problems:** Only the statements following the `read` in the same block are checked.
+Comparing the count to anything but `0` is assumed to check that the buffer is full. The count
+isn't followed through later control flow, so a short read handled by a loop accumulating
+the count or by a function it is passed to is reported as well.
+
+**Example:**
+
+```rust
+# use std::io::{Read, Write};
+# fn f(mut input: impl Read, mut output: impl Write) -> std::io::Result<()> {
+let mut buf = [0u8; 64];
+let n = input.read(&mut buf)?;
+output.write_all(&buf[..])?;
+# Ok(())
+# }
+```
+Use instead:
+```rust
+# use std::io::{Read, Write};
+# fn f(mut input: impl Read, mut output: impl Write) -> std::io::Result<()> {
+let mut buf = [0u8; 64];
+let n = input.read(&mut buf)?;
+output.write_all(&buf[..n])?;
+# Ok(())
+# }
+```",
+    },
+    LintInfo {
+        lint: crate::path_buf_push_overwrite::PATH_BUF_PUSH_OVERWRITE,
+        name: "path_buf_push_overwrite",
+        group: "nursery",
+        docs: r#"**What it does:*** Checks for [push](https://doc.rust-lang.org/std/path/struct.PathBuf.html#method.push)
+calls on `PathBuf` that can cause overwrites.
+
+**Why is this bad?** Calling `push` with a root path at the start can overwrite the
+previous defined path.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+use std::path::PathBuf;
+
+let mut x = PathBuf::from("/foo");
+x.push("/bar");
+assert_eq!(x, PathBuf::from("/bar"));
+```
+Could be written:
+
+```rust
+use std::path::PathBuf;
+
+let mut x = PathBuf::from("/foo");
+x.push("bar");
+assert_eq!(x, PathBuf::from("/foo/bar"));
+```"#,
+    },
+    LintInfo {
+        lint: crate::pattern_type_mismatch::PATTERN_TYPE_MISMATCH,
+        name: "pattern_type_mismatch",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for patterns that aren't exact representations of the types
+they are applied to.
+
+To satisfy this lint, you will have to adjust either the expression that is matched
+against or the pattern itself, as well as the bindings that are introduced by the
+adjusted patterns. For matching you will have to either dereference the expression
+with the `*` operator, or amend the patterns to explicitly match against `&<pattern>`
+or `&mut <pattern>` depending on the reference mutability. For the bindings you need
+to use the inverse. You can leave them as plain bindings if you wish for the value
+to be copied, but you must use `ref mut <variable>` or `ref <variable>` to construct
+a reference into the matched structure.
+
+If you are looking for a way to learn about ownership semantics in more detail, it
+is recommended to look at IDE options available to you to highlight types, lifetimes
+and reference semantics in your code. The available tooling would expose these things
+in a general way even outside of the various pattern matching mechanics. Of course
+this lint can still be used to highlight areas of interest and ensure a good understanding
+of ownership semantics.
+
+**Why is this bad?** It isn't bad in general. But in some contexts it can be desirable
+because it increases ownership hints in the code, and will guard against some changes
+in ownership.
+
+**Known problems:** None.
+
+**Example:**
+
+This example shows the basic adjustments necessary to satisfy the lint. Note how
+the matched expression is explicitly dereferenced with `*` and the `inner` variable
+is bound to a shared borrow via `ref inner`.
+
+```rust,ignore
+// Bad
+let value = &Some(Box::new(23));
+match value {
+    Some(inner) => println!("{}", inner),
+    None => println!("none"),
+}
+
+// Good
+let value = &Some(Box::new(23));
+match *value {
+    Some(ref inner) => println!("{}", inner),
+    None => println!("none"),
+}
+```
+
+The following example demonstrates one of the advantages of the more verbose style.
+Note how the second version uses `ref mut a` to explicitly declare `a` a shared mutable
+borrow, while `b` is simply taken by value. This ensures that the loop body cannot
+accidentally modify the wrong part of the structure.
+
+```rust,ignore
+// Bad
+let mut values = vec![(2, 3), (3, 4)];
+for (a, b) in &mut values {
+    *a += *b;
+}
+
+// Good
+let mut values = vec![(2, 3), (3, 4)];
+for &mut (ref mut a, b) in &mut values {
+    *a += b;
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::formatting::POSSIBLE_MISSING_COMMA,
+        name: "possible_missing_comma",
+        group: "correctness",
+        docs: r"**What it does:** Checks for possible missing comma in an array. It lints if
+an array element is a binary operator expression and it lies on two lines.
+
+**Why is this bad?** This could lead to unexpected results.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+let a = &[
+    -1, -2, -3 // <= no comma here
+    -4, -5, -6
+];
+```",
+    },
+    LintInfo {
+        lint: crate::precedence::PRECEDENCE,
+        name: "precedence",
+        group: "complexity",
+        docs: r#"**What it does:** Checks for operations where precedence may be unclear
+and suggests to add parentheses. Currently it catches the following:
+* mixed usage of arithmetic and bit shifting/combining operators without
+parentheses
+* a "negative" numeric literal (which is really a unary `-` followed by a
+numeric literal)
+  followed by a method call
+
+**Why is this bad?** Not everyone knows the precedence of those operators by
+heart, so expressions like these may trip others trying to reason about the
+code.
+
+**Known problems:** None.
+
+**Example:**
+* `1 << 2 + 3` equals 32, while `(1 << 2) + 3` equals 7
+* `-1i32.abs()` equals -1, while `(-1i32).abs()` equals 1"#,
+    },
+    LintInfo {
+        lint: crate::write::PRINT_LITERAL,
+        name: "print_literal",
+        group: "style",
+        docs: r#"**What it does:** This lint warns about the use of literals as `print!`/`println!` args.
+
+**Why is this bad?** Using literals as `println!` args is inefficient
+(c.f., https://github.com/matthiaskrgr/rust-str-bench) and unnecessary
+(i.e., just put the literal in the format string)
+
+**Known problems:** Will also warn with macro calls as arguments that expand to literals
+-- e.g., `println!("{}", env!("FOO"))`.
+
+**Example:**
+```rust
+println!("{}", "foo");
+```
+use the literal without formatting:
+```rust
+println!("foo");
+```"#,
+    },
+    LintInfo {
+        lint: crate::write::PRINT_STDERR,
+        name: "print_stderr",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for printing on *stderr*. The purpose of this lint
+is to catch debugging remnants.
+
+**Why is this bad?** People often print on *stderr* while debugging an
+application and might forget to remove those prints afterward.
+
+**Known problems:** Only catches `eprint!` and `eprintln!` calls.
+
+**Example:**
+```rust
+eprintln!("Hello world!");
+```"#,
+    },
+    LintInfo {
+        lint: crate::write::PRINT_STDOUT,
+        name: "print_stdout",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for printing on *stdout*. The purpose of this lint
+is to catch debugging remnants.
+
+**Why is this bad?** People often print on *stdout* while debugging an
+application and might forget to remove those prints afterward.
+
+**Known problems:** Only catches `print!` and `println!` calls.
+
+**Example:**
+```rust
+println!("Hello world!");
+```"#,
+    },
+    LintInfo {
+        lint: crate::write::PRINT_WITH_NEWLINE,
+        name: "print_with_newline",
+        group: "style",
+        docs: r#"**What it does:** This lint warns when you use `print!()` with a format
+string that ends in a newline.
+
+**Why is this bad?** You should use `println!()` instead, which appends the
+newline.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let name = "World";
+print!("Hello {}!\n", name);
+```
+use println!() instead
+```rust
+# let name = "World";
+println!("Hello {}!", name);
+```"#,
+    },
+    LintInfo {
+        lint: crate::write::PRINTLN_EMPTY_STRING,
+        name: "println_empty_string",
+        group: "style",
+        docs: r#"**What it does:** This lint warns when you use `println!("")` to
+print a newline.
+
+**Why is this bad?** You should use `println!()`, which is simpler.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+// Bad
+println!("");
+
+// Good
+println!();
+```"#,
+    },
+    LintInfo {
+        lint: crate::ptr::PTR_ARG,
+        name: "ptr_arg",
+        group: "style",
+        docs: r"**What it does:** This lint checks for function arguments of type `&String`
+or `&Vec` unless the references are mutable. It will also suggest you
+replace `.clone()` calls with the appropriate `.to_owned()`/`to_string()`
+calls.
+
+**Why is this bad?** Requiring the argument to be of the specific size
+makes the function less useful for no benefit; slices in the form of `&[T]`
+or `&str` usually suffice and can be obtained from other types, too.
+
+**Known problems:** The lint does not follow data. So if you have an
+argument `x` and write `let y = x; y.clone()` the lint will not suggest
+changing that `.clone()` to `.to_owned()`.
+
+Other functions called from this function taking a `&String` or `&Vec`
+argument may also fail to compile if you change the argument. Applying
+this lint on them will fix the problem, but they may be in other crates.
+
+One notable example of a function that may cause issues, and which cannot
+easily be changed due to being in the standard library is `Vec::contains`.
+when called on a `Vec<Vec<T>>`. If a `&Vec` is passed to that method then
+it will compile, but if a `&[T]` is passed then it will not compile.
+
+```ignore
+fn cannot_take_a_slice(v: &Vec<u8>) -> bool {
+    let vec_of_vecs: Vec<Vec<u8>> = some_other_fn();
+
+    vec_of_vecs.contains(v)
+}
+```
+
+Also there may be `fn(&Vec)`-typed references pointing to your function.
+If you have them, you will get a compiler error after applying this lint's
+suggestions. You then have the choice to undo your changes or change the
+type of the reference.
+
+Note that if the function is part of your public interface, there may be
+other crates referencing it, of which you may not be aware. Carefully
+deprecate the function before applying the lint suggestions in this case.
+
+**Example:**
+```ignore
+// Bad
+fn foo(&Vec<u32>) { .. }
+
+// Good
+fn foo(&[u32]) { .. }
+```",
+    },
+    LintInfo {
+        lint: crate::casts::PTR_AS_PTR,
+        name: "ptr_as_ptr",
+        group: "pedantic",
+        docs: r"**What it does:**
+Checks for `as` casts between raw pointers without changing its mutability,
+namely `*const T` to `*const U` and `*mut T` to `*mut U`.
+
+**Why is this bad?**
+Though `as` casts between raw pointers is not terrible, `pointer::cast` is safer because
+it cannot accidentally change the pointer's mutability nor cast the pointer to other types like `usize`.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+let ptr: *const u32 = &42_u32;
+let mut_ptr: *mut u32 = &mut 42_u32;
+let _ = ptr as *const i32;
+let _ = mut_ptr as *mut i32;
+```
+Use instead:
+```rust
+let ptr: *const u32 = &42_u32;
+let mut_ptr: *mut u32 = &mut 42_u32;
+let _ = ptr.cast::<i32>();
+let _ = mut_ptr.cast::<i32>();
+```",
+    },
+    LintInfo {
+        lint: crate::ptr_eq::PTR_EQ,
+        name: "ptr_eq",
+        group: "style",
+        docs: r"**What it does:** Use `std::ptr::eq` when applicable
+
+**Why is this bad?** `ptr::eq` can be used to compare `&T` references
+(which coerce to `*const T` implicitly) by their address rather than
+comparing the values they point to.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+let a = &[1, 2, 3];
+let b = &[1, 2, 3];
+
+assert!(a as *const _ as usize == b as *const _ as usize);
+```
+Use instead:
+```rust
+let a = &[1, 2, 3];
+let b = &[1, 2, 3];
+
+assert!(std::ptr::eq(a, b));
+```",
+    },
+    LintInfo {
+        lint: crate::ptr_offset_with_cast::PTR_OFFSET_WITH_CAST,
+        name: "ptr_offset_with_cast",
+        group: "complexity",
+        docs: r"**What it does:** Checks for usage of the `offset` pointer method with a `usize` casted to an
+`isize`.
+
+**Why is this bad?** If we’re always increasing the pointer address, we can avoid the numeric
+cast by using the `add` method instead.
+
+**Known problems:** None
+
+**Example:**
+```rust
+let vec = vec![b'a', b'b', b'c'];
+let ptr = vec.as_ptr();
+let offset = 1_usize;
+
+unsafe {
+    ptr.offset(offset as isize);
+}
+```
+
+Could be written:
+
+```rust
+let vec = vec![b'a', b'b', b'c'];
+let ptr = vec.as_ptr();
+let offset = 1_usize;
+
+unsafe {
+    ptr.add(offset);
+}
+```",
+    },
+    LintInfo {
+        lint: crate::enum_variants::PUB_ENUM_VARIANT_NAMES,
+        name: "pub_enum_variant_names",
+        group: "pedantic",
+        docs: r"**What it does:** Detects public enumeration variants that are
+prefixed or suffixed by the same characters.
+
+**Why is this bad?** Public enumeration variant names should specify their variant,
+not repeat the enumeration name.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+pub enum Cake {
+    BlackForestCake,
+    HummingbirdCake,
+    BattenbergCake,
+}
+```
+Could be written as:
+```rust
+pub enum Cake {
+    BlackForest,
+    Hummingbird,
+    Battenberg,
+}
+```",
+    },
+    LintInfo {
+        lint: crate::question_mark::QUESTION_MARK,
+        name: "question_mark",
+        group: "style",
+        docs: r"**What it does:** Checks for expressions that could be replaced by the question mark operator.
+
+**Why is this bad?** Question mark usage is more idiomatic.
+
+**Known problems:** None
+
+**Example:**
+```ignore
+if option.is_none() {
+    return None;
+}
+```
+
+Could be written:
+
+```ignore
+option?;
+```",
+    },
+    LintInfo {
+        lint: crate::ranges::RANGE_MINUS_ONE,
+        name: "range_minus_one",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for inclusive ranges where 1 is subtracted from
+the upper bound, e.g., `x..=(y-1)`.
+
+**Why is this bad?** The code is more readable with an exclusive range
+like `x..y`.
+
+**Known problems:** This will cause a warning that cannot be fixed if
+the consumer of the range only accepts a specific range type, instead of
+the generic `RangeBounds` trait
+([#3307](https://github.com/rust-lang/rust-clippy/issues/3307)).
+
+**Example:**
+```rust,ignore
+for x..=(y-1) { .. }
+```
+Could be written as
+```rust,ignore
+for x..y { .. }
+```",
+    },
+    LintInfo {
+        lint: crate::ranges::RANGE_PLUS_ONE,
+        name: "range_plus_one",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for exclusive ranges where 1 is added to the
+upper bound, e.g., `x..(y+1)`.
+
+**Why is this bad?** The code is more readable with an inclusive range
+like `x..=y`.
+
+**Known problems:** Will add unnecessary pair of parentheses when the
+expression is not wrapped in a pair but starts with a opening parenthesis
+and ends with a closing one.
+I.e., `let _ = (f()+1)..(f()+1)` results in `let _ = ((f()+1)..=f())`.
+
+Also in many cases, inclusive ranges are still slower to run than
+exclusive ranges, because they essentially add an extra branch that
+LLVM may fail to hoist out of the loop.
+
+This will cause a warning that cannot be fixed if the consumer of the
+range only accepts a specific range type, instead of the generic
+`RangeBounds` trait
+([#3307](https://github.com/rust-lang/rust-clippy/issues/3307)).
+
+**Example:**
+```rust,ignore
+for x..(y+1) { .. }
+```
+Could be written as
+```rust,ignore
+for x..=y { .. }
+```",
+    },
+    LintInfo {
+        lint: crate::ranges::RANGE_ZIP_WITH_LEN,
+        name: "range_zip_with_len",
+        group: "complexity",
+        docs: r"**What it does:** Checks for zipping a collection with the range of
+`0.._.len()`.
+
+**Why is this bad?** The code is better expressed with `.enumerate()`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let x = vec![1];
+x.iter().zip(0..x.len());
+```
+Could be written as
+```rust
+# let x = vec![1];
+x.iter().enumerate();
+```",
+    },
+    LintInfo {
+        lint: crate::types::RC_BUFFER,
+        name: "rc_buffer",
+        group: "restriction",
+        docs: r"**What it does:** Checks for `Rc<T>` and `Arc<T>` when `T` is a mutable buffer type such as `String` or `Vec`.
+
+**Why is this bad?** Expressions such as `Rc<String>` usually have no advantage over `Rc<str>`, since
+it is larger and involves an extra level of indirection, and doesn't implement `Borrow<str>`.
+
+While mutating a buffer type would still be possible with `Rc::get_mut()`, it only
+works if there are no additional references yet, which usually defeats the purpose of
+enclosing it in a shared ownership type. Instead, additionally wrapping the inner
+type with an interior mutable container (such as `RefCell` or `Mutex`) would normally
+be used.
+
+**Known problems:** This pattern can be desirable to avoid the overhead of a `RefCell` or `Mutex` for
+cases where mutation only happens before there are any additional references.
+
+**Example:**
+```rust,ignore
+# use std::rc::Rc;
+fn foo(interned: Rc<String>) { ... }
+```
+
+Better:
+
+```rust,ignore
+fn foo(interned: Rc<str>) { ... }
+```",
+    },
+    LintInfo {
+        lint: crate::reachable_panic::REACHABLE_PANIC,
+        name: "reachable_panic",
+        group: "restriction",
+        docs: r#"**What it does:** In `#![no_std]` binaries, checks for indexing, `unwrap()` and `expect()`
+on an `Option` or `Result`, and integer division and remainder that may panic and are
+reachable from an entry point of the binary: the `main` (or `#[start]`) function and the
+functions exported with `#[no_mangle]` or `#[export_name]`, like interrupt handlers.
+
+The calls of the functions of the crate are followed, except for the functions listed in
+the `reachable-panic-allowed` configuration value, e.g. `["my_crate::debug::dump"]`.
+
+**Why is this bad?** A panic calls the `#[panic_handler]`, which usually halts or resets
+the device. Each operation that may panic also adds the panic machinery and its messages to
+the binary.
+
+**Known problems:** Calls through function pointers and trait objects, calls of trait
+methods on generic types, and the functions of other crates aren't followed. Operations are reported even if a check before them makes
+the panic impossible, in which case they can be allowed with an attribute.
+
+**Example:**
+
+```rust,ignore
+#![no_std]
+#![no_main]
+
+#[no_mangle]
+pub extern "C" fn main() -> ! {
+    // Bad
+    let x = BUFFER[read_index()];
+
+    // Good
+    let x = BUFFER.get(read_index()).copied().unwrap_or_default();
+    loop {}
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::types::REDUNDANT_ALLOCATION,
+        name: "redundant_allocation",
+        group: "perf",
+        docs: r"**What it does:** Checks for use of redundant allocations anywhere in the code.
+
+**Why is this bad?** Expressions such as `Rc<&T>`, `Rc<Rc<T>>`, `Rc<Box<T>>`, `Box<&T>`
+add an unnecessary level of indirection.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# use std::rc::Rc;
+fn foo(bar: Rc<&usize>) {}
+```
+
+Better:
+
+```rust
+fn foo(bar: &usize) {}
+```",
+    },
+    LintInfo {
+        lint: crate::redundant_clone::REDUNDANT_CLONE,
+        name: "redundant_clone",
+        group: "perf",
+        docs: r#"**What it does:** Checks for a redundant `clone()` (and its relatives) which clones an owned
+value that is going to be dropped without further use.
+
+**Why is this bad?** It is not always possible for the compiler to eliminate useless
+allocations and deallocations generated by redundant `clone()`s.
+
+**Known problems:**
+
+False-negatives: analysis performed by this lint is conservative and limited.
+
+**Example:**
+```rust
+# use std::path::Path;
+# #[derive(Clone)]
+# struct Foo;
+# impl Foo {
+#     fn new() -> Self { Foo {} }
+# }
+# fn call(x: Foo) {}
+{
+    let x = Foo::new();
+    call(x.clone());
+    call(x.clone()); // this can just pass `x`
+}
+
+["lorem", "ipsum"].join(" ").to_string();
+
+Path::new("/a/b").join("c").to_path_buf();
+```"#,
+    },
+    LintInfo {
+        lint: crate::eta_reduction::REDUNDANT_CLOSURE,
+        name: "redundant_closure",
+        group: "style",
+        docs: r"**What it does:** Checks for closures which just call another function where
+the function can be called directly. `unsafe` functions or calls where types
+get adjusted are ignored.
+
+**Why is this bad?** Needlessly creating a closure adds code for no benefit
+and gives the optimizer more work.
+
+**Known problems:** If creating the closure inside the closure has a side-
+effect then moving the closure creation out will change when that side-
+effect runs.
+See [#1439](https://github.com/rust-lang/rust-clippy/issues/1439) for more details.
+
+**Example:**
+```rust,ignore
+// Bad
+xs.map(|x| foo(x))
+
+// Good
+xs.map(foo)
+```
+where `foo(_)` is a plain function that takes the exact argument type of
+`x`.",
+    },
+    LintInfo {
+        lint: crate::redundant_closure_call::REDUNDANT_CLOSURE_CALL,
+        name: "redundant_closure_call",
+        group: "complexity",
+        docs: r"**What it does:** Detects closures called in the same expression where they
+are defined.
+
+**Why is this bad?** It is unnecessarily adding to the expression's
+complexity.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+// Bad
+let a = (|| 42)()
+
+// Good
+let a = 42
+```",
+    },
+    LintInfo {
+        lint: crate::eta_reduction::REDUNDANT_CLOSURE_FOR_METHOD_CALLS,
+        name: "redundant_closure_for_method_calls",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for closures which only invoke a method on the closure
+argument and can be replaced by referencing the method directly.
+
+**Why is this bad?** It's unnecessary to create the closure.
+
+**Known problems:** [#3071](https://github.com/rust-lang/rust-clippy/issues/3071),
+[#3942](https://github.com/rust-lang/rust-clippy/issues/3942),
+[#4002](https://github.com/rust-lang/rust-clippy/issues/4002)
+
+
+**Example:**
+```rust,ignore
+Some('a').map(|s| s.to_uppercase());
+```
+may be rewritten as
+```rust,ignore
+Some('a').map(char::to_uppercase);
+```",
+    },
+    LintInfo {
+        lint: crate::redundant_else::REDUNDANT_ELSE,
+        name: "redundant_else",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for `else` blocks that can be removed without changing semantics.
+
+**Why is this bad?** The `else` block adds unnecessary indentation and verbosity.
+
+**Known problems:** Some may prefer to keep the `else` block for clarity.
+
+**Example:**
+
+```rust
+fn my_func(count: u32) {
+    if count == 0 {
+        print!("Nothing to do");
+        return;
+    } else {
+        print!("Moving on...");
+    }
+}
+```
+Use instead:
+```rust
+fn my_func(count: u32) {
+    if count == 0 {
+        print!("Nothing to do");
+        return;
+    }
+    print!("Moving on...");
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::redundant_field_names::REDUNDANT_FIELD_NAMES,
+        name: "redundant_field_names",
+        group: "style",
+        docs: r"**What it does:** Checks for fields in struct literals where shorthands
+could be used.
+
+**Why is this bad?** If the field and variable names are the same,
+the field name is redundant.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let bar: u8 = 123;
+
+struct Foo {
+    bar: u8,
+}
+
+let foo = Foo { bar: bar };
+```
+the last line can be simplified to
+```ignore
+let foo = Foo { bar };
+```",
+    },
+    LintInfo {
+        lint: crate::misc_early::REDUNDANT_PATTERN,
+        name: "redundant_pattern",
+        group: "style",
+        docs: r#"**What it does:** Checks for patterns in the form `name @ _`.
+
+**Why is this bad?** It's almost always more readable to just use direct
+bindings.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let v = Some("abc");
+
+// Bad
+match v {
+    Some(x) => (),
+    y @ _ => (),
+}
+
+// Good
+match v {
+    Some(x) => (),
+    y => (),
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::matches::REDUNDANT_PATTERN_MATCHING,
+        name: "redundant_pattern_matching",
+        group: "style",
+        docs: r"**What it does:** Lint for redundant pattern matching over `Result`, `Option`,
+`std::task::Poll` or `std::net::IpAddr`
+
+**Why is this bad?** It's more concise and clear to just use the proper
+utility function
+
+**Known problems:** This will change the drop order for the matched type. Both `if let` and
+`while let` will drop the value at the end of the block, both `if` and `while` will drop the
+value before entering the block. For most types this change will not matter, but for a few
+types this will not be an acceptable change (e.g. locks). See the
+[reference](https://doc.rust-lang.org/reference/destructors.html#drop-scopes) for more about
+drop order.
+
+**Example:**
+
+```rust
+# use std::task::Poll;
+# use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+if let Ok(_) = Ok::<i32, i32>(42) {}
+if let Err(_) = Err::<i32, i32>(42) {}
+if let None = None::<()> {}
+if let Some(_) = Some(42) {}
+if let Poll::Pending = Poll::Pending::<()> {}
+if let Poll::Ready(_) = Poll::Ready(42) {}
+if let IpAddr::V4(_) = IpAddr::V4(Ipv4Addr::LOCALHOST) {}
+if let IpAddr::V6(_) = IpAddr::V6(Ipv6Addr::LOCALHOST) {}
+match Ok::<i32, i32>(42) {
+    Ok(_) => true,
+    Err(_) => false,
+};
+```
+
+The more idiomatic use would be:
+
+```rust
+# use std::task::Poll;
+# use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+if Ok::<i32, i32>(42).is_ok() {}
+if Err::<i32, i32>(42).is_err() {}
+if None::<()>.is_none() {}
+if Some(42).is_some() {}
+if Poll::Pending::<()>.is_pending() {}
+if Poll::Ready(42).is_ready() {}
+if IpAddr::V4(Ipv4Addr::LOCALHOST).is_ipv4() {}
+if IpAddr::V6(Ipv6Addr::LOCALHOST).is_ipv6() {}
+Ok::<i32, i32>(42).is_ok();
+```",
+    },
+    LintInfo {
+        lint: crate::redundant_pub_crate::REDUNDANT_PUB_CRATE,
+        name: "redundant_pub_crate",
+        group: "nursery",
+        docs: r"**What it does:** Checks for items declared `pub(crate)` that are not crate visible because they
+are inside a private module.
+
+**Why is this bad?** Writing `pub(crate)` is misleading when it's redundant due to the parent
+module's visibility.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+mod internal {
+    pub(crate) fn internal_fn() { }
+}
+```
+This function is not visible outside the module and it can be declared with `pub` or
+private visibility
+```rust
+mod internal {
+    pub fn internal_fn() { }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::redundant_slicing::REDUNDANT_SLICING,
+        name: "redundant_slicing",
+        group: "complexity",
+        docs: r"**What it does:** Checks for redundant slicing expressions which use the full range, and
+do not change the type.
+
+**Why is this bad?** It unnecessarily adds complexity to the expression.
+
+**Known problems:** If the type being sliced has an implementation of `Index<RangeFull>`
+that actually changes anything then it can't be removed. However, this would be surprising
+to people reading the code and should have a note with it.
+
+**Example:**
+
+```ignore
+fn get_slice(x: &[u32]) -> &[u32] {
+    &x[..]
+}
+```
+Use instead:
+```ignore
+fn get_slice(x: &[u32]) -> &[u32] {
+    x
+}
+```",
+    },
+    LintInfo {
+        lint: crate::redundant_static_lifetimes::REDUNDANT_STATIC_LIFETIMES,
+        name: "redundant_static_lifetimes",
+        group: "style",
+        docs: r"**What it does:** Checks for constants and statics with an explicit `'static` lifetime.
+
+**Why is this bad?** Adding `'static` to every reference can create very
+complicated types.
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+const FOO: &'static [(&'static str, &'static str, fn(&Bar) -> bool)] =
+&[...]
+static FOO: &'static [(&'static str, &'static str, fn(&Bar) -> bool)] =
+&[...]
+```
+This code can be rewritten as
+```ignore
+ const FOO: &[(&str, &str, fn(&Bar) -> bool)] = &[...]
+ static FOO: &[(&str, &str, fn(&Bar) -> bool)] = &[...]
+```",
+    },
+    LintInfo {
+        lint: crate::reference::REF_IN_DEREF,
+        name: "ref_in_deref",
+        group: "complexity",
+        docs: r"**What it does:** Checks for references in expressions that use
+auto dereference.
+
+**Why is this bad?** The reference is a no-op and is automatically
+dereferenced by the compiler and makes the code less clear.
+
+**Example:**
+```rust
+struct Point(u32, u32);
+let point = Point(30, 20);
+let x = (&point).0;
+```
+Use instead:
+```rust
+# struct Point(u32, u32);
+# let point = Point(30, 20);
+let x = point.0;
+```",
+    },
+    LintInfo {
+        lint: crate::ref_option_ref::REF_OPTION_REF,
+        name: "ref_option_ref",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for usage of `&Option<&T>`.
+
+**Why is this bad?** Since `&` is Copy, it's useless to have a
+reference on `Option<&T>`.
+
+**Known problems:** It may be irrelevant to use this lint on
+public API code as it will make a breaking change to apply it.
+
+**Example:**
+
+```rust,ignore
+let x: &Option<&u32> = &Some(&0u32);
+```
+Use instead:
+```rust,ignore
+let x: Option<&u32> = Some(&0u32);
+```",
+    },
+    LintInfo {
+        lint: crate::repeat_once::REPEAT_ONCE,
+        name: "repeat_once",
+        group: "complexity",
+        docs: r#"**What it does:** Checks for usage of `.repeat(1)` and suggest the following method for each types.
+- `.to_string()` for `str`
+- `.clone()` for `String`
+- `.to_vec()` for `slice`
+
+**Why is this bad?** For example, `String.repeat(1)` is equivalent to `.clone()`. If cloning the string is the intention behind this, `clone()` should be used.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+fn main() {
+    let x = String::from("hello world").repeat(1);
+}
+```
+Use instead:
+```rust
+fn main() {
+    let x = String::from("hello world").clone();
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::repeated_indexing::REPEATED_INDEXING,
+        name: "repeated_indexing",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for functions where the same non-trivial index expression, like
+`data[compute_key(x)]`, appears at least `repeated-indexing-threshold` times (3 by default).
+Indexes that are literals, constants or variables are not considered non-trivial.
+
+**Why is this bad?** The index is computed and checked against the length every time, and
+the repetition makes the code harder to read. Binding the element (or a reference to it) to
+a local variable does both only once.
+
+**Known problems:** The lint doesn't check whether the collection or the variables in the
+index are changed between the occurrences, in which case they can't be replaced by a single
+binding.
+
+**Example:**
+
+```rust
+# fn compute_key(x: u32) -> usize { x as usize }
+# fn f(data: &mut [u32], x: u32) {
+// Bad
+if data[compute_key(x)] > 10 {
+    data[compute_key(x)] -= 10;
+} else {
+    data[compute_key(x)] = 0;
+}
+
+// Good
+let elem = &mut data[compute_key(x)];
+if *elem > 10 {
+    *elem -= 10;
+} else {
+    *elem = 0;
+}
+# }
+```",
+    },
+    LintInfo {
+        lint: crate::matches::REST_PAT_IN_FULLY_BOUND_STRUCTS,
+        name: "rest_pat_in_fully_bound_structs",
+        group: "restriction",
+        docs: r"**What it does:** Checks for unnecessary '..' pattern binding on struct when all fields are explicitly matched.
+
+**Why is this bad?** Correctness and readability. It's like having a wildcard pattern after
+matching all enum variants explicitly.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# struct A { a: i32 }
+let a = A { a: 5 };
+
+// Bad
+match a {
+    A { a: 5, .. } => {},
+    _ => {},
+}
+
+// Good
+match a {
+    A { a: 5 } => {},
+    _ => {},
+}
+```",
+    },
+    LintInfo {
+        lint: crate::methods::RESULT_MAP_OR_INTO_OPTION,
+        name: "result_map_or_into_option",
+        group: "style",
+        docs: r"**What it does:** Checks for usage of `_.map_or(None, Some)`.
+
+**Why is this bad?** Readability, this can be written more concisely as
+`_.ok()`.
+
+**Known problems:** None.
+
+**Example:**
+
+Bad:
+```rust
+# let r: Result<u32, &str> = Ok(1);
+assert_eq!(Some(1), r.map_or(None, Some));
+```
+
+Good:
+```rust
+# let r: Result<u32, &str> = Ok(1);
+assert_eq!(Some(1), r.ok());
+```",
+    },
+    LintInfo {
+        lint: crate::map_unit_fn::RESULT_MAP_UNIT_FN,
+        name: "result_map_unit_fn",
+        group: "complexity",
+        docs: r"**What it does:** Checks for usage of `result.map(f)` where f is a function
+or closure that returns the unit type `()`.
+
+**Why is this bad?** Readability, this can be written more clearly with
+an if let statement
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+# fn do_stuff() -> Result<String, String> { Ok(String::new()) }
+# fn log_err_msg(foo: String) -> Result<String, String> { Ok(foo) }
+# fn format_msg(foo: String) -> String { String::new() }
+let x: Result<String, String> = do_stuff();
+x.map(log_err_msg);
+# let x: Result<String, String> = do_stuff();
+x.map(|msg| log_err_msg(format_msg(msg)));
+```
+
+The correct use would be:
+
+```rust
+# fn do_stuff() -> Result<String, String> { Ok(String::new()) }
+# fn log_err_msg(foo: String) -> Result<String, String> { Ok(foo) }
+# fn format_msg(foo: String) -> String { String::new() }
+let x: Result<String, String> = do_stuff();
+if let Ok(msg) = x {
+    log_err_msg(msg);
+};
+# let x: Result<String, String> = do_stuff();
+if let Ok(msg) = x {
+    log_err_msg(format_msg(msg));
+};
+```",
+    },
+    LintInfo {
+        lint: crate::functions::RESULT_UNIT_ERR,
+        name: "result_unit_err",
+        group: "style",
+        docs: r#"**What it does:** Checks for public functions that return a `Result`
+with an `Err` type of `()`. It suggests using a custom type that
+implements `std::error::Error`.
+
+**Why is this bad?** Unit does not implement `Error` and carries no
+further information about what went wrong.
+
+**Known problems:** Of course, this lint assumes that `Result` is used
+for a fallible operation (which is after all the intended use). However
+code may opt to (mis)use it as a basic two-variant-enum. In that case,
+the suggestion is misguided, and the code should use a custom enum
+instead.
+
+**Examples:**
+```rust
+pub fn read_u8() -> Result<u8, ()> { Err(()) }
+```
+should become
+```rust,should_panic
+use std::fmt;
+
+#[derive(Debug)]
+pub struct EndOfStream;
+
+impl fmt::Display for EndOfStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "End of Stream")
+    }
+}
+
+impl std::error::Error for EndOfStream { }
+
+pub fn read_u8() -> Result<u8, EndOfStream> { Err(EndOfStream) }
+# fn main() {
+#     read_u8().unwrap();
+# }
+```
+
+Note that there are crates that simplify creating the error type, e.g.
+[`thiserror`](https://docs.rs/thiserror)."#,
+    },
+    LintInfo {
+        lint: crate::ranges::REVERSED_EMPTY_RANGES,
+        name: "reversed_empty_ranges",
+        group: "correctness",
+        docs: r#"**What it does:** Checks for range expressions `x..y` where both `x` and `y`
+are constant and `x` is greater or equal to `y`.
+
+**Why is this bad?** Empty ranges yield no values so iterating them is a no-op.
+Reversed ranges used to slice are checked by `reversed_slice_range`.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust,no_run
+fn main() {
+    (10..=0).for_each(|x| println!("{}", x));
+}
+```
+Use instead:
+```rust
+fn main() {
+    (0..=10).rev().for_each(|x| println!("{}", x));
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::indexing_slicing::REVERSED_SLICE_RANGE,
+        name: "reversed_slice_range",
+        group: "correctness",
+        docs: r#"**What it does:** Checks for slicing with a range whose constant start is after its
+constant end, like `&x[5..2]` or `&x[4..=2]`.
+
+**Why is this bad?** Slicing with a reversed range always panics, with "slice index starts
+at 5 but ends at 2", even if both bounds are within the length. Ranges ending where they
+start, like `&x[2..2]`, are empty and don't panic.
+
+**Known problems:** Only the bounds known at compile time are checked.
+
+**Example:**
+```no_run
+let x = [1, 2, 3, 4, 5, 6];
+
+// Bad
+&x[5..2];
+
+// Good
+&x[2..5];
+```"#,
+    },
+    LintInfo {
+        lint: crate::copies::SAME_FUNCTIONS_IN_IF_CONDITION,
+        name: "same_functions_in_if_condition",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for consecutive `if`s with the same function call.
+
+**Why is this bad?** This is probably a copy & paste error.
+Despite the fact that function can have side effects and `if` works as
+intended, such an approach is implicit and can be considered a "code smell".
+
+**Known problems:** Hopefully none.
+
+**Example:**
+```ignore
+if foo() == bar {
+    …
+} else if foo() == bar {
+    …
+}
+```
+
+This probably should be:
+```ignore
+if foo() == bar {
+    …
+} else if foo() == baz {
+    …
+}
+```
+
+or if the original code was not a typo and called function mutates a state,
+consider move the mutation out of the `if` condition to avoid similarity to
+a copy & paste error:
+
+```ignore
+let first = foo();
+if first == bar {
+    …
+} else {
+    let second = foo();
+    if second == bar {
+    …
+    }
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::loops::SAME_ITEM_PUSH,
+        name: "same_item_push",
+        group: "style",
+        docs: r"**What it does:** Checks whether a for loop is being used to push a constant
+value into a Vec.
+
+**Why is this bad?** This kind of operation can be expressed more succinctly with
+`vec![item;SIZE]` or `vec.resize(NEW_SIZE, item)` and using these alternatives may also
+have better performance.
+**Known problems:** None
+
+**Example:**
+```rust
+let item1 = 2;
+let item2 = 3;
+let mut vec: Vec<u8> = Vec::new();
+for _ in 0..20 {
+   vec.push(item1);
+}
+for _ in 0..30 {
+    vec.push(item2);
+}
+```
+could be written as
+```rust
+let item1 = 2;
+let item2 = 3;
+let mut vec: Vec<u8> = vec![item1; 20];
+vec.resize(20 + 30, item2);
+```",
+    },
+    LintInfo {
+        lint: crate::methods::SEARCH_IS_SOME,
+        name: "search_is_some",
+        group: "complexity",
+        docs: r#"**What it does:** Checks for an iterator or string search (such as `find()`,
+`position()`, or `rposition()`) followed by a call to `is_some()` or `is_none()`.
+
+**Why is this bad?** Readability, this can be written more concisely as:
+* `_.any(_)`, or `_.contains(_)` for `is_some()`,
+* `!_.any(_)`, or `!_.contains(_)` for `is_none()`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let vec = vec![1];
+vec.iter().find(|x| **x == 0).is_some();
+
+let _ = "hello world".find("world").is_none();
+```
+Could be written as
+```rust
+let vec = vec![1];
+vec.iter().any(|x| *x == 0);
+
+let _ = !"hello world".contains("world");
+```"#,
+    },
+    LintInfo {
+        lint: crate::self_assignment::SELF_ASSIGNMENT,
+        name: "self_assignment",
+        group: "correctness",
+        docs: r"**What it does:** Checks for explicit self-assignments.
+
+**Why is this bad?** Self-assignments are redundant and unlikely to be
+intentional.
+
+**Known problems:** If expression contains any deref coercions or
+indexing operations they are assumed not to have any side effects.
+
+**Example:**
+
+```rust
+struct Event {
+    id: usize,
+    x: i32,
+    y: i32,
+}
+
+fn copy_position(a: &mut Event, b: &Event) {
+    a.x = b.x;
+    a.y = a.y;
+}
+```",
+    },
+    LintInfo {
+        lint: crate::semicolon_if_nothing_returned::SEMICOLON_IF_NOTHING_RETURNED,
+        name: "semicolon_if_nothing_returned",
+        group: "restriction",
+        docs: r#"**What it does:** Looks for blocks of expressions and fires if the last expression returns `()`
+but is not followed by a semicolon.
+
+**Why is this bad?** The semicolon might be optional but when
+extending the block with new code, it doesn't require a change in previous last line.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+fn main() {
+    println!("Hello world")
+}
+```
+Use instead:
+```rust
+fn main() {
+    println!("Hello world");
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::serde_api::SERDE_API_MISUSE,
+        name: "serde_api_misuse",
+        group: "correctness",
+        docs: r"**What it does:** Checks for mis-uses of the serde API.
+
+**Why is this bad?** Serde is very finnicky about how its API should be
+used, but the type system can't be used to enforce it (yet?).
+
+**Known problems:** None.
+
+**Example:** Implementing `Visitor::visit_string` but not
+`Visitor::visit_str`.",
+    },
+    LintInfo {
+        lint: crate::shadow::SHADOW_REUSE,
+        name: "shadow_reuse",
+        group: "restriction",
+        docs: r"**What it does:** Checks for bindings that shadow other bindings already in
+scope, while reusing the original value.
+
+**Why is this bad?** Not too much, in fact it's a common pattern in Rust
+code. Still, some argue that name shadowing like this hurts readability,
+because a value may be bound to different things depending on position in
+the code.
+
+**Known problems:** This lint, as the other shadowing related lints,
+currently only catches very simple patterns.
+
+**Example:**
+```rust
+let x = 2;
+let x = x + 1;
+```
+use different variable name:
+```rust
+let x = 2;
+let y = x + 1;
+```",
+    },
+    LintInfo {
+        lint: crate::shadow::SHADOW_SAME,
+        name: "shadow_same",
+        group: "restriction",
+        docs: r"**What it does:** Checks for bindings that shadow other bindings already in
+scope, while just changing reference level or mutability.
+
+**Why is this bad?** Not much, in fact it's a very common pattern in Rust
+code. Still, some may opt to avoid it in their code base, they can set this
+lint to `Warn`.
+
+**Known problems:** This lint, as the other shadowing related lints,
+currently only catches very simple patterns.
+
+**Example:**
+```rust
+# let x = 1;
+// Bad
+let x = &x;
+
+// Good
+let y = &x; // use different variable name
+```",
+    },
+    LintInfo {
+        lint: crate::shadow::SHADOW_UNRELATED,
+        name: "shadow_unrelated",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for bindings that shadow other bindings already in
+scope, either without a initialization or with one that does not even use
+the original value.
+
+**Why is this bad?** Name shadowing can hurt readability, especially in
+large code bases, because it is easy to lose track of the active binding at
+any place in the code. This can be alleviated by either giving more specific
+names to bindings or introducing more scopes to contain the bindings.
+
+**Known problems:** This lint, as the other shadowing related lints,
+currently only catches very simple patterns. Note that
+`allow`/`warn`/`deny`/`forbid` attributes only work on the function level
+for this lint.
+
+**Example:**
+```rust
+# let y = 1;
+# let z = 2;
+let x = y;
+
+// Bad
+let x = z; // shadows the earlier binding
+
+// Good
+let w = z; // use different variable name
+```",
+    },
+    LintInfo {
+        lint: crate::misc::SHORT_CIRCUIT_STATEMENT,
+        name: "short_circuit_statement",
+        group: "complexity",
+        docs: r"**What it does:** Checks for the use of short circuit boolean conditions as
+a
+statement.
+
+**Why is this bad?** Using a short circuit boolean condition as a statement
+may hide the fact that the second part is executed or not depending on the
+outcome of the first part.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+f() && g(); // We should write `if f() { g(); }`.
+```",
+    },
+    LintInfo {
+        lint: crate::methods::SHOULD_IMPLEMENT_TRAIT,
+        name: "should_implement_trait",
+        group: "style",
+        docs: r"**What it does:** Checks for methods that should live in a trait
+implementation of a `std` trait (see [llogiq's blog
+post](http://llogiq.github.io/2015/07/30/traits.html) for further
+information) instead of an inherent implementation.
+
+**Why is this bad?** Implementing the traits improve ergonomics for users of
+the code, often with very little cost. Also people seeing a `mul(...)`
+method
+may expect `*` to work equally, so you should have good reason to disappoint
+them.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+struct X;
+impl X {
+    fn add(&self, other: &X) -> X {
+        // ..
+# X
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::indexing_slicing::SIGNED_INDEX_CAST,
+        name: "signed_index_cast",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for indexes and slicing bounds that are signed integers cast to
+`usize`, like `v[x as usize]` where `x` is an `i32`.
+
+**Why is this bad?** A negative value wraps around to a huge index when cast with `as`, so
+the indexing panics with a confusing message, or, if the collection is large enough, accesses
+the wrong element. `usize::try_from` makes the conversion fail on negative values instead.
+
+**Known problems:** Checks of the value before the cast, like `if x >= 0 { v[x as usize] }`,
+aren't recognized. The suggestion needs `std::convert::TryFrom` to be imported before
+edition 2021.
+
+**Example:**
+```rust
+# use std::convert::TryFrom;
+# let v = vec![1, 2, 3];
+let x: i32 = 1;
+
+// Bad
+let y = v[x as usize];
+
+// Good
+let y = v[usize::try_from(x).expect("negative index")];
+```"#,
+    },
+    LintInfo {
+        lint: crate::non_expressive_names::SIMILAR_NAMES,
+        name: "similar_names",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for names that are very similar and thus confusing.
+
+**Why is this bad?** It's hard to distinguish between names that differ only
+by a single character.
+
+**Known problems:** None?
+
+**Example:**
+```ignore
+let checked_exp = something;
+let checked_expr = something_else;
+```",
+    },
+    LintInfo {
+        lint: crate::methods::SINGLE_CHAR_ADD_STR,
+        name: "single_char_add_str",
+        group: "style",
+        docs: r#"**What it does:** Warns when using `push_str`/`insert_str` with a single-character string literal
+where `push`/`insert` with a `char` would work fine.
+
+**Why is this bad?** It's less clear that we are pushing a single character.
+
+**Known problems:** None
+
+**Example:**
+```rust
+let mut string = String::new();
+string.insert_str(0, "R");
+string.push_str("R");
+```
+Could be written as
+```rust
+let mut string = String::new();
+string.insert(0, 'R');
+string.push('R');
+```"#,
+    },
+    LintInfo {
+        lint: crate::methods::SINGLE_CHAR_PATTERN,
+        name: "single_char_pattern",
+        group: "perf",
+        docs: r#"**What it does:** Checks for string methods that receive a single-character
+`str` as an argument, e.g., `_.split("x")`.
+
+**Why is this bad?** Performing these methods using a `char` is faster than
+using a `str`.
+
+**Known problems:** Does not catch multi-byte unicode characters.
+
+**Example:**
+```rust,ignore
+// Bad
+_.split("x");
+
+// Good
+_.split('x');"#,
+    },
+    LintInfo {
+        lint: crate::single_component_path_imports::SINGLE_COMPONENT_PATH_IMPORTS,
+        name: "single_component_path_imports",
+        group: "style",
+        docs: r#"**What it does:** Checking for imports with single component use path.
+
+**Why is this bad?** Import with single component use path such as `use cratename;`
+is not necessary, and thus should be removed.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust,ignore
+use regex;
+
+fn main() {
+    regex::Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
+}
+```
+Better as
+```rust,ignore
+fn main() {
+    regex::Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::loops::SINGLE_ELEMENT_LOOP,
+        name: "single_element_loop",
+        group: "complexity",
+        docs: r#"**What it does:** Checks whether a for loop has a single element.
+
+**Why is this bad?** There is no reason to have a loop of a
+single element.
+**Known problems:** None
+
+**Example:**
+```rust
+let item1 = 2;
+for item in &[item1] {
+    println!("{}", item);
+}
+```
+could be written as
+```rust
+let item1 = 2;
+let item = &item1;
+println!("{}", item);
+```"#,
+    },
+    LintInfo {
+        lint: crate::matches::SINGLE_MATCH,
+        name: "single_match",
+        group: "style",
+        docs: r#"**What it does:** Checks for matches with a single arm where an `if let`
+will usually suffice.
+
+**Why is this bad?** Just readability – `if let` nests less than a `match`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# fn bar(stool: &str) {}
+# let x = Some("abc");
+// Bad
+match x {
+    Some(ref foo) => bar(foo),
+    _ => (),
+}
+
+// Good
+if let Some(ref foo) = x {
+    bar(foo);
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::matches::SINGLE_MATCH_ELSE,
+        name: "single_match_else",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for matches with two arms where an `if let else` will
+usually suffice.
+
+**Why is this bad?** Just readability – `if let` nests less than a `match`.
+
+**Known problems:** Personal style preferences may differ.
+
+**Example:**
+
+Using `match`:
+
+```rust
+# fn bar(foo: &usize) {}
+# let other_ref: usize = 1;
+# let x: Option<&usize> = Some(&1);
+match x {
+    Some(ref foo) => bar(foo),
+    _ => bar(&other_ref),
+}
+```
+
+Using `if let` with `else`:
+
+```rust
+# fn bar(foo: &usize) {}
+# let other_ref: usize = 1;
+# let x: Option<&usize> = Some(&1);
+if let Some(ref foo) = x {
+    bar(foo);
+} else {
+    bar(&other_ref);
+}
+```",
+    },
+    LintInfo {
+        lint: crate::size_of_in_element_count::SIZE_OF_IN_ELEMENT_COUNT,
+        name: "size_of_in_element_count",
+        group: "correctness",
+        docs: r"**What it does:** Detects expressions where
+`size_of::<T>` or `size_of_val::<T>` is used as a
+count of elements of type `T`
+
+**Why is this bad?** These functions expect a count
+of `T` and not a number of bytes
+
+**Known problems:** None.
+
+**Example:**
+```rust,no_run
+# use std::ptr::copy_nonoverlapping;
+# use std::mem::size_of;
+const SIZE: usize = 128;
+let x = [2u8; SIZE];
+let mut y = [2u8; SIZE];
+unsafe { copy_nonoverlapping(x.as_ptr(), y.as_mut_ptr(), size_of::<u8>() * SIZE) };
+```",
+    },
+    LintInfo {
+        lint: crate::indexing_slicing::SIZE_OF_OUT_OF_BOUNDS,
+        name: "size_of_out_of_bounds",
+        group: "correctness",
+        docs: r"**What it does:** Checks for slicing and indexing of an array, or of a collection whose
+length is known, with a bound computed from `mem::size_of::<T>()` that is past its end,
+like `&buf[..size_of::<Header>()]` with a buffer smaller than `Header`, or a range of
+consecutive structures like `&buf[size_of::<Header>()..][..size_of::<Body>()]` when they
+don't all fit in the buffer.
+
+**Why is this bad?** The slicing always panics. Reading a structure from a byte buffer
+needs a buffer of at least the size of the structure.
+
+**Known problems:** The size of a type depends on the target, so the lint may only be
+emitted on some targets. Only the sizes of concrete types are known.
+
+**Example:**
+```no_run
+# use std::mem::size_of;
+struct Header {
+    magic: u32,
+    len: u32,
+}
+
+// Bad
+let buf = [0u8; 4];
+let header = &buf[..size_of::<Header>()];
+
+// Good
+let buf = [0u8; size_of::<Header>()];
+let header = &buf[..size_of::<Header>()];
+```",
+    },
+    LintInfo {
+        lint: crate::methods::SKIP_WHILE_NEXT,
+        name: "skip_while_next",
+        group: "complexity",
+        docs: r"**What it does:** Checks for usage of `_.skip_while(condition).next()`.
+
+**Why is this bad?** Readability, this can be written more concisely as
+`_.find(!condition)`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# let vec = vec![1];
+vec.iter().skip_while(|x| **x == 0).next();
+```
+Could be written as
+```rust
+# let vec = vec![1];
+vec.iter().find(|x| **x != 0);
+```",
+    },
+    LintInfo {
+        lint: crate::methods::SLICE_LENGTH_MISMATCH,
+        name: "slice_length_mismatch",
+        group: "correctness",
+        docs: r"**What it does:** Checks for calls to `copy_from_slice`, `clone_from_slice` and
+`swap_with_slice` where the lengths of both slices are known and differ, e.g. arrays,
+local `Vec`s created with `vec![elem; N]`, or subslices of them with constant bounds.
+
+**Why is this bad?** These methods panic if the slices have different lengths.
+
+**Known problems:** None.
+
+**Example:**
+```no_run
+let src = [1, 2, 3];
+let mut dst = [0; 8];
+
+// Bad
+dst[..4].copy_from_slice(&src);
+
+// Good
+dst[..3].copy_from_slice(&src);
+```",
+    },
+    LintInfo {
+        lint: crate::slow_vector_initialization::SLOW_VECTOR_INITIALIZATION,
+        name: "slow_vector_initialization",
+        group: "perf",
+        docs: r"**What it does:** Checks slow zero-filled vector initialization
+
+**Why is this bad?** These structures are non-idiomatic and less efficient than simply using
+`vec![0; len]`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# use core::iter::repeat;
+# let len = 4;
+
+// Bad
+let mut vec1 = Vec::with_capacity(len);
+vec1.resize(len, 0);
+
+let mut vec2 = Vec::with_capacity(len);
+vec2.extend(repeat(0).take(len));
+
+// Good
+let mut vec1 = vec![0; len];
+let mut vec2 = vec![0; len];
+```",
+    },
+    LintInfo {
+        lint: crate::split_indexing::SPLIT_INDEXING,
+        name: "split_indexing",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for indexing the parts of a string split with `split`, `splitn`
+or a similar method and collected into a `Vec`, like `s.split(',').collect::<Vec<_>>()[1]`,
+directly or through a local variable.
+
+**Why is this bad?** The number of parts depends on the string. Malformed input, like a
+line without the separator, makes the indexing panic.
+
+**Known problems:** Indexing a local variable isn't linted if its length is checked with
+`len()` or `is_empty()` anywhere in the function, even if the check doesn't guard the
+indexing.
+
+**Example:**
+
+```rust
+# fn f(line: &str) -> Option<()> {
+// Bad
+let value = line.split('=').collect::<Vec<_>>()[1];
+
+// Good
+let value = line.split('=').nth(1)?;
+// or
+let mut parts = line.splitn(2, '=');
+if let (Some(key), Some(value)) = (parts.next(), parts.next()) {
+    // ..
+}
+# None
+# }
+```",
+    },
+    LintInfo {
+        lint: crate::stable_sort_primitive::STABLE_SORT_PRIMITIVE,
+        name: "stable_sort_primitive",
+        group: "perf",
+        docs: r"**What it does:**
+When sorting primitive values (integers, bools, chars, as well
+as arrays, slices, and tuples of such items), it is better to
+use an unstable sort than a stable sort.
+
+**Why is this bad?**
+Using a stable sort consumes more memory and cpu cycles. Because
+values which compare equal are identical, preserving their
+relative order (the guarantee that a stable sort provides) means
+nothing, while the extra costs still apply.
+
+**Known problems:**
+None
+
+**Example:**
+
+```rust
+let mut vec = vec![2, 1, 3];
+vec.sort();
+```
+Use instead:
+```rust
+let mut vec = vec![2, 1, 3];
+vec.sort_unstable();
+```",
+    },
+    LintInfo {
+        lint: crate::strings::STR_TO_STRING,
+        name: "str_to_string",
+        group: "restriction",
+        docs: r#"**What it does:** This lint checks for `.to_string()` method calls on values of type `&str`.
+
+**Why is this bad?** The `to_string` method is also used on other types to convert them to a string.
+When called on a `&str` it turns the `&str` into the owned variant `String`, which can be better
+expressed with `.to_owned()`.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+// example code where clippy issues a warning
+let _ = "str".to_string();
+```
+Use instead:
+```rust
+// example code which does not raise clippy warning
+let _ = "str".to_owned();
+```"#,
+    },
+    LintInfo {
+        lint: crate::strings::STRING_ADD,
+        name: "string_add",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for all instances of `x + _` where `x` is of type
+`String`, but only if [`string_add_assign`](#string_add_assign) does *not*
+match.
+
+**Why is this bad?** It's not bad in and of itself. However, this particular
+`Add` implementation is asymmetric (the other operand need not be `String`,
+but `x` does), while addition as mathematically defined is symmetric, also
+the `String::push_str(_)` function is a perfectly good replacement.
+Therefore, some dislike it and wish not to have it in their code.
+
+That said, other people think that string addition, having a long tradition
+in other languages is actually fine, which is why we decided to make this
+particular lint `allow` by default.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+let x = "Hello".to_owned();
+x + ", World";
+```"#,
+    },
+    LintInfo {
+        lint: crate::strings::STRING_ADD_ASSIGN,
+        name: "string_add_assign",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for string appends of the form `x = x + y` (without
+`let`!).
+
+**Why is this bad?** It's not really bad, but some people think that the
+`.push_str(_)` method is more readable.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+let mut x = "Hello".to_owned();
+x = x + ", World";
+
+// More readable
+x += ", World";
+x.push_str(", World");
+```"#,
+    },
+    LintInfo {
+        lint: crate::methods::STRING_EXTEND_CHARS,
+        name: "string_extend_chars",
+        group: "style",
+        docs: r#"**What it does:** Checks for the use of `.extend(s.chars())` where s is a
+`&str` or `String`.
+
+**Why is this bad?** `.push_str(s)` is clearer
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let abc = "abc";
+let def = String::from("def");
+let mut s = String::new();
+s.extend(abc.chars());
+s.extend(def.chars());
+```
+The correct use would be:
+```rust
+let abc = "abc";
+let def = String::from("def");
+let mut s = String::new();
+s.push_str(abc);
+s.push_str(&def);
+```"#,
+    },
+    LintInfo {
+        lint: crate::strings::STRING_FROM_UTF8_AS_BYTES,
+        name: "string_from_utf8_as_bytes",
+        group: "complexity",
+        docs: r#"**What it does:** Check if the string is transformed to byte array and casted back to string.
+
+**Why is this bad?** It's unnecessary, the string can be used directly.
+
+**Known problems:** None
+
+**Example:**
+```rust
+let _ = std::str::from_utf8(&"Hello World!".as_bytes()[6..11]).unwrap();
+```
+could be written as
+```rust
+let _ = &"Hello World!"[6..11];
+```"#,
+    },
+    LintInfo {
+        lint: crate::strings::STRING_LIT_AS_BYTES,
+        name: "string_lit_as_bytes",
+        group: "nursery",
+        docs: r#"**What it does:** Checks for the `as_bytes` method called on string literals
+that contain only ASCII characters.
+
+**Why is this bad?** Byte string literals (e.g., `b"foo"`) can be used
+instead. They are shorter but less discoverable than `as_bytes()`.
+
+**Known Problems:**
+`"str".as_bytes()` and the suggested replacement of `b"str"` are not
+equivalent because they have different types. The former is `&[u8]`
+while the latter is `&[u8; 3]`. That means in general they will have a
+different set of methods and different trait implementations.
+
+```compile_fail
+fn f(v: Vec<u8>) {}
+
+f("...".as_bytes().to_owned()); // works
+f(b"...".to_owned()); // does not work, because arg is [u8; 3] not Vec<u8>
+
+fn g(r: impl std::io::Read) {}
+
+g("...".as_bytes()); // works
+g(b"..."); // does not work
+```
+
+The actual equivalent of `"str".as_bytes()` with the same type is not
+`b"str"` but `&b"str"[..]`, which is a great deal of punctuation and not
+more readable than a function call.
+
+**Example:**
+```rust
+// Bad
+let bs = "a byte string".as_bytes();
+
+// Good
+let bs = b"a byte string";
+```"#,
+    },
+    LintInfo {
+        lint: crate::indexing_slicing::STRING_SLICE,
+        name: "string_slice",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for slicing of `str`s and `String`s.
+
+**Why is this bad?** String slices are indexed by bytes. Slicing panics if a bound is
+not on a UTF-8 character boundary, which is easy to get wrong with non-ASCII text.
+
+**Known problems:** The lint stays silent if the string and both bounds are constants and
+the bounds are known to lie on character boundaries.
+
+**Example:**
+```rust,no_run
+let s = String::from("Ünicode");
+let n = 1;
+
+// Bad
+&s[..n];
+
+// Good
+s.get(..n);
+```"#,
+    },
+    LintInfo {
+        lint: crate::strings::STRING_TO_STRING,
+        name: "string_to_string",
+        group: "restriction",
+        docs: r#"**What it does:** This lint checks for `.to_string()` method calls on values of type `String`.
+
+**Why is this bad?** The `to_string` method is also used on other types to convert them to a string.
+When called on a `String` it only clones the `String`, which can be better expressed with `.clone()`.
+**Known problems:** None.
+
+**Example:**
+
+```rust
+// example code where clippy issues a warning
+let msg = String::from("Hello World");
+let _ = msg.to_string();
+```
+Use instead:
+```rust
+// example code which does not raise clippy warning
+let msg = String::from("Hello World");
+let _ = msg.clone();
+```"#,
+    },
+    LintInfo {
+        lint: crate::excessive_bools::STRUCT_EXCESSIVE_BOOLS,
+        name: "struct_excessive_bools",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for excessive
+use of bools in structs.
+
+**Why is this bad?** Excessive bools in a struct
+is often a sign that it's used as a state machine,
+which is much better implemented as an enum.
+If it's not the case, excessive bools usually benefit
+from refactoring into two-variant enums for better
+readability and API.
+
+**Known problems:** None.
+
+**Example:**
+Bad:
+```rust
+struct S {
+    is_pending: bool,
+    is_processing: bool,
+    is_finished: bool,
+}
+```
+
+Good:
+```rust
+enum S {
+    Pending,
+    Processing,
+    Finished,
+}
+```",
+    },
+    LintInfo {
+        lint: crate::floating_point_arithmetic::SUBOPTIMAL_FLOPS,
+        name: "suboptimal_flops",
+        group: "nursery",
+        docs: r"**What it does:** Looks for floating-point expressions that
+can be expressed using built-in methods to improve both
+accuracy and performance.
+
+**Why is this bad?** Negatively impacts accuracy and performance.
+
+**Known problems:** None
+
+**Example:**
+
+```rust
+use std::f32::consts::E;
+
+let a = 3f32;
+let _ = (2f32).powf(a);
+let _ = E.powf(a);
+let _ = a.powf(1.0 / 2.0);
+let _ = a.log(2.0);
+let _ = a.log(10.0);
+let _ = a.log(E);
+let _ = a.powf(2.0);
+let _ = a * 2.0 + 4.0;
+let _ = if a < 0.0 {
+    -a
+} else {
+    a
+};
+let _ = if a < 0.0 {
+    a
+} else {
+    -a
+};
+```
+
+is better expressed as
+
+```rust
+use std::f32::consts::E;
+
+let a = 3f32;
+let _ = a.exp2();
+let _ = a.exp();
+let _ = a.sqrt();
+let _ = a.log2();
+let _ = a.log10();
+let _ = a.ln();
+let _ = a.powi(2);
+let _ = a.mul_add(2.0, 4.0);
+let _ = a.abs();
+let _ = -a.abs();
+```",
+    },
+    LintInfo {
+        lint: crate::suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL,
+        name: "suspicious_arithmetic_impl",
+        group: "correctness",
+        docs: r"**What it does:** Lints for suspicious operations in impls of arithmetic operators, e.g.
+subtracting elements in an Add impl.
+
+**Why this is bad?** This is probably a typo or copy-and-paste error and not intended.
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+impl Add for Foo {
+    type Output = Foo;
+
+    fn add(self, other: Foo) -> Foo {
+        Foo(self.0 - other.0)
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING,
+        name: "suspicious_assignment_formatting",
+        group: "style",
+        docs: r"**What it does:** Checks for use of the non-existent `=*`, `=!` and `=-`
+operators.
+
+**Why is this bad?** This is either a typo of `*=`, `!=` or `-=` or
+confusing.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+a =- 42; // confusing, should it be `a -= 42` or `a = -42`?
+```",
+    },
+    LintInfo {
+        lint: crate::formatting::SUSPICIOUS_ELSE_FORMATTING,
+        name: "suspicious_else_formatting",
+        group: "style",
+        docs: r"**What it does:** Checks for formatting of `else`. It lints if the `else`
+is followed immediately by a newline or the `else` seems to be missing.
+
+**Why is this bad?** This is probably some refactoring remnant, even if the
+code is correct, it might look confusing.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+if foo {
+} { // looks like an `else` is missing here
+}
+
+if foo {
+} if bar { // looks like an `else` is missing here
+}
+
+if foo {
+} else
+
+{ // this is the `else` block of the previous `if`, but should it be?
+}
+
+if foo {
+} else
+
+if bar { // this is the `else` block of the previous `if`, but should it be?
+}
+```",
+    },
+    LintInfo {
+        lint: crate::methods::SUSPICIOUS_MAP,
+        name: "suspicious_map",
+        group: "complexity",
+        docs: r"**What it does:** Checks for calls to `map` followed by a `count`.
+
+**Why is this bad?** It looks suspicious. Maybe `map` was confused with `filter`.
+If the `map` call is intentional, this should be rewritten. Or, if you intend to
+drive the iterator to completion, you can just use `for_each` instead.
+
+**Known problems:** None
+
+**Example:**
+
+```rust
+let _ = (0..3).map(|x| x + 2).count();
+```",
+    },
+    LintInfo {
+        lint: crate::suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL,
+        name: "suspicious_op_assign_impl",
+        group: "correctness",
+        docs: r"**What it does:** Lints for suspicious operations in impls of OpAssign, e.g.
+subtracting elements in an AddAssign impl.
+
+**Why this is bad?** This is probably a typo or copy-and-paste error and not intended.
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+impl AddAssign for Foo {
+    fn add_assign(&mut self, other: Foo) {
+        *self = *self - other;
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::suspicious_operation_groupings::SUSPICIOUS_OPERATION_GROUPINGS,
+        name: "suspicious_operation_groupings",
+        group: "style",
+        docs: r"**What it does:**
+Checks for unlikely usages of binary operators that are almost
+certainly typos and/or copy/paste errors, given the other usages
+of binary operators nearby.
+**Why is this bad?**
+They are probably bugs and if they aren't then they look like bugs
+and you should add a comment explaining why you are doing such an
+odd set of operations.
+**Known problems:**
+There may be some false positives if you are trying to do something
+unusual that happens to look like a typo.
+
+**Example:**
+
+```rust
+struct Vec3 {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl Eq for Vec3 {}
+
+impl PartialEq for Vec3 {
+    fn eq(&self, other: &Self) -> bool {
+        // This should trigger the lint because `self.x` is compared to `other.y`
+        self.x == other.y && self.y == other.y && self.z == other.z
+    }
+}
+```
+Use instead:
+```rust
+# struct Vec3 {
+#     x: f64,
+#     y: f64,
+#     z: f64,
+# }
+// same as above except:
+impl PartialEq for Vec3 {
+    fn eq(&self, other: &Self) -> bool {
+        // Note we now compare other.x to self.x
+        self.x == other.x && self.y == other.y && self.z == other.z
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::formatting::SUSPICIOUS_UNARY_OP_FORMATTING,
+        name: "suspicious_unary_op_formatting",
+        group: "style",
+        docs: r"**What it does:** Checks the formatting of a unary operator on the right hand side
+of a binary operator. It lints if there is no space between the binary and unary operators,
+but there is a space between the unary and its operand.
+
+**Why is this bad?** This is either a typo in the binary operator or confusing.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+if foo <- 30 { // this should be `foo < -30` but looks like a different operator
+}
+
+if foo &&! bar { // this should be `foo && !bar` but looks like a different operator
+}
+```",
+    },
+    LintInfo {
+        lint: crate::tabs_in_doc_comments::TABS_IN_DOC_COMMENTS,
+        name: "tabs_in_doc_comments",
+        group: "style",
+        docs: r"**What it does:** Checks doc comments for usage of tab characters.
+
+**Why is this bad?** The rust style-guide promotes spaces instead of tabs for indentation.
+To keep a consistent view on the source, also doc comments should not have tabs.
+Also, explaining ascii-diagrams containing tabs can get displayed incorrectly when the
+display settings of the author and reader differ.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+///
+/// Struct to hold two strings:
+/// 	- first		one
+/// 	- second	one
+pub struct DoubleString {
+   ///
+   /// 	- First String:
+   /// 		- needs to be inside here
+   first_string: String,
+   ///
+   /// 	- Second String:
+   /// 		- needs to be inside here
+   second_string: String,
+}
+```
+
+Will be converted to:
+```rust
+///
+/// Struct to hold two strings:
+///     - first        one
+///     - second    one
+pub struct DoubleString {
+   ///
+   ///     - First String:
+   ///         - needs to be inside here
+   first_string: String,
+   ///
+   ///     - Second String:
+   ///         - needs to be inside here
+   second_string: String,
+}
+```",
+    },
+    LintInfo {
+        lint: crate::temporary_assignment::TEMPORARY_ASSIGNMENT,
+        name: "temporary_assignment",
+        group: "complexity",
+        docs: r"**What it does:** Checks for construction of a structure or tuple just to
+assign a value in it.
+
+**Why is this bad?** Readability. If the structure is only created to be
+updated, why not write the structure you want in the first place?
+
+**Known problems:** None.
+
+**Example:**
+```rust
+(0, 0).0 = 1
+```",
+    },
+    LintInfo {
+        lint: crate::to_digit_is_some::TO_DIGIT_IS_SOME,
+        name: "to_digit_is_some",
+        group: "style",
+        docs: r"**What it does:** Checks for `.to_digit(..).is_some()` on `char`s.
+
+**Why is this bad?** This is a convoluted way of checking if a `char` is a digit. It's
+more straight forward to use the dedicated `is_digit` method.
+
+**Example:**
+```rust
+# let c = 'c';
+# let radix = 10;
+let is_digit = c.to_digit(radix).is_some();
+```
+can be written as:
+```
+# let c = 'c';
+# let radix = 10;
+let is_digit = c.is_digit(radix);
+```",
+    },
+    LintInfo {
+        lint: crate::to_string_in_display::TO_STRING_IN_DISPLAY,
+        name: "to_string_in_display",
+        group: "correctness",
+        docs: r#"**What it does:** Checks for uses of `to_string()` in `Display` traits.
+
+**Why is this bad?** Usually `to_string` is implemented indirectly
+via `Display`. Hence using it while implementing `Display` would
+lead to infinite recursion.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+use std::fmt;
+
+struct Structure(i32);
+impl fmt::Display for Structure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_string())
+    }
+}
+
+```
+Use instead:
+```rust
+use std::fmt;
+
+struct Structure(i32);
+impl fmt::Display for Structure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::panic_unimplemented::TODO,
+        name: "todo",
+        group: "restriction",
+        docs: r"**What it does:** Checks for usage of `todo!`.
+
+**Why is this bad?** This macro should not be present in production code
+
+**Known problems:** None.
+
+**Example:**
+```no_run
+todo!();
+```",
+    },
+    LintInfo {
+        lint: crate::functions::TOO_MANY_ARGUMENTS,
+        name: "too_many_arguments",
+        group: "complexity",
+        docs: r"**What it does:** Checks for functions with too many parameters.
+
+**Why is this bad?** Functions with lots of parameters are considered bad
+style and reduce readability (“what does the 5th parameter mean?”). Consider
+grouping some parameters into a new type.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# struct Color;
+fn foo(x: u32, y: u32, name: &str, c: Color, w: f32, h: f32, a: f32, b: f32) {
+    // ..
+}
+```",
+    },
+    LintInfo {
+        lint: crate::functions::TOO_MANY_LINES,
+        name: "too_many_lines",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for functions with a large amount of lines.
+
+**Why is this bad?** Functions with a lot of lines are harder to understand
+due to having to look at a larger amount of code to understand what the
+function is doing. Consider splitting the body of the function into
+multiple functions.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn im_too_long() {
+    println!("");
+    // ... 100 more LoC
+    println!("");
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::misc::TOPLEVEL_REF_ARG,
+        name: "toplevel_ref_arg",
+        group: "style",
+        docs: r"**What it does:** Checks for function arguments and let bindings denoted as
+`ref`.
+
+**Why is this bad?** The `ref` declaration makes the function take an owned
+value, but turns the argument into a reference (which means that the value
+is destroyed when exiting the function). This adds not much value: either
+take a reference type, or take an owned value and create references in the
+body.
+
+For let bindings, `let x = &foo;` is preferred over `let ref x = foo`. The
+type of `x` is more obvious with the former.
+
+**Known problems:** If the argument is dereferenced within the function,
+removing the `ref` will lead to errors. This can be fixed by removing the
+dereferences, e.g., changing `*x` to `x` within the function.
+
+**Example:**
+```rust,ignore
+// Bad
+fn foo(ref x: u8) -> bool {
+    true
+}
+
+// Good
+fn foo(x: &u8) -> bool {
+    true
+}
+```",
+    },
+    LintInfo {
+        lint: crate::trait_bounds::TRAIT_DUPLICATION_IN_BOUNDS,
+        name: "trait_duplication_in_bounds",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for cases where generics are being used and multiple
+syntax specifications for trait bounds are used simultaneously.
+
+**Why is this bad?** Duplicate bounds makes the code
+less readable than specifing them only once.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn func<T: Clone + Default>(arg: T) where T: Clone + Default {}
+```
+
+Could be written as:
+
+```rust
+fn func<T: Clone + Default>(arg: T) {}
+```
+or
+
+```rust
+fn func<T>(arg: T) where T: Clone + Default {}
+```",
+    },
+    LintInfo {
+        lint: crate::transmute::TRANSMUTE_BYTES_TO_STR,
+        name: "transmute_bytes_to_str",
+        group: "complexity",
+        docs: r"**What it does:** Checks for transmutes from a `&[u8]` to a `&str`.
+
+**Why is this bad?** Not every byte slice is a valid UTF-8 string.
+
+**Known problems:**
+- [`from_utf8`] which this lint suggests using is slower than `transmute`
+as it needs to validate the input.
+If you are certain that the input is always a valid UTF-8,
+use [`from_utf8_unchecked`] which is as fast as `transmute`
+but has a semantically meaningful name.
+- You might want to handle errors returned from [`from_utf8`] instead of calling `unwrap`.
+
+[`from_utf8`]: https://doc.rust-lang.org/std/str/fn.from_utf8.html
+[`from_utf8_unchecked`]: https://doc.rust-lang.org/std/str/fn.from_utf8_unchecked.html
+
+**Example:**
+```rust
+let b: &[u8] = &[1_u8, 2_u8];
+unsafe {
+    let _: &str = std::mem::transmute(b); // where b: &[u8]
+}
+
+// should be:
+let _ = std::str::from_utf8(b).unwrap();
+```",
+    },
+    LintInfo {
+        lint: crate::transmute::TRANSMUTE_FLOAT_TO_INT,
+        name: "transmute_float_to_int",
+        group: "complexity",
+        docs: r"**What it does:** Checks for transmutes from a float to an integer.
+
+**Why is this bad?** Transmutes are dangerous and error-prone, whereas `to_bits` is intuitive
+and safe.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+unsafe {
+    let _: u32 = std::mem::transmute(1f32);
+}
+
+// should be:
+let _: u32 = 1f32.to_bits();
+```",
+    },
+    LintInfo {
+        lint: crate::transmute::TRANSMUTE_INT_TO_BOOL,
+        name: "transmute_int_to_bool",
+        group: "complexity",
+        docs: r"**What it does:** Checks for transmutes from an integer to a `bool`.
+
+**Why is this bad?** This might result in an invalid in-memory representation of a `bool`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = 1_u8;
+unsafe {
+    let _: bool = std::mem::transmute(x); // where x: u8
+}
+
+// should be:
+let _: bool = x != 0;
+```",
+    },
+    LintInfo {
+        lint: crate::transmute::TRANSMUTE_INT_TO_CHAR,
+        name: "transmute_int_to_char",
+        group: "complexity",
+        docs: r"**What it does:** Checks for transmutes from an integer to a `char`.
+
+**Why is this bad?** Not every integer is a Unicode scalar value.
+
+**Known problems:**
+- [`from_u32`] which this lint suggests using is slower than `transmute`
+as it needs to validate the input.
+If you are certain that the input is always a valid Unicode scalar value,
+use [`from_u32_unchecked`] which is as fast as `transmute`
+but has a semantically meaningful name.
+- You might want to handle `None` returned from [`from_u32`] instead of calling `unwrap`.
+
+[`from_u32`]: https://doc.rust-lang.org/std/char/fn.from_u32.html
+[`from_u32_unchecked`]: https://doc.rust-lang.org/std/char/fn.from_u32_unchecked.html
+
+**Example:**
+```rust
+let x = 1_u32;
+unsafe {
+    let _: char = std::mem::transmute(x); // where x: u32
+}
+
+// should be:
+let _ = std::char::from_u32(x).unwrap();
+```",
+    },
+    LintInfo {
+        lint: crate::transmute::TRANSMUTE_INT_TO_FLOAT,
+        name: "transmute_int_to_float",
+        group: "complexity",
+        docs: r"**What it does:** Checks for transmutes from an integer to a float.
+
+**Why is this bad?** Transmutes are dangerous and error-prone, whereas `from_bits` is intuitive
+and safe.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+unsafe {
+    let _: f32 = std::mem::transmute(1_u32); // where x: u32
+}
+
+// should be:
+let _: f32 = f32::from_bits(1_u32);
+```",
+    },
+    LintInfo {
+        lint: crate::transmute::TRANSMUTE_PTR_TO_PTR,
+        name: "transmute_ptr_to_ptr",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for transmutes from a pointer to a pointer, or
+from a reference to a reference.
+
+**Why is this bad?** Transmutes are dangerous, and these can instead be
+written as casts.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let ptr = &1u32 as *const u32;
+unsafe {
+    // pointer-to-pointer transmute
+    let _: *const f32 = std::mem::transmute(ptr);
+    // ref-ref transmute
+    let _: &f32 = std::mem::transmute(&1u32);
+}
+// These can be respectively written:
+let _ = ptr as *const f32;
+let _ = unsafe{ &*(&1u32 as *const u32 as *const f32) };
+```",
+    },
+    LintInfo {
+        lint: crate::transmute::TRANSMUTE_PTR_TO_REF,
+        name: "transmute_ptr_to_ref",
+        group: "complexity",
+        docs: r"**What it does:** Checks for transmutes from a pointer to a reference.
+
+**Why is this bad?** This can always be rewritten with `&` and `*`.
+
+**Known problems:**
+- `mem::transmute` in statics and constants is stable from Rust 1.46.0,
+while dereferencing raw pointer is not stable yet.
+If you need to do this in those places,
+you would have to use `transmute` instead.
+
+**Example:**
+```rust,ignore
+unsafe {
+    let _: &T = std::mem::transmute(p); // where p: *const T
+}
+
+// can be written:
+let _: &T = &*p;
+```",
+    },
+    LintInfo {
+        lint: crate::transmute::TRANSMUTES_EXPRESSIBLE_AS_PTR_CASTS,
+        name: "transmutes_expressible_as_ptr_casts",
+        group: "complexity",
+        docs: r"**What it does:**Checks for transmutes that could be a pointer cast.
+
+**Why is this bad?** Readability. The code tricks people into thinking that
+something complex is going on.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+# let p: *const [i32] = &[];
+unsafe { std::mem::transmute::<*const [i32], *const [u16]>(p) };
+```
+Use instead:
+```rust
+# let p: *const [i32] = &[];
+p as *const [u16];
+```",
+    },
+    LintInfo {
+        lint: crate::transmuting_null::TRANSMUTING_NULL,
+        name: "transmuting_null",
+        group: "correctness",
+        docs: r"**What it does:** Checks for transmute calls which would receive a null pointer.
+
+**Why is this bad?** Transmuting a null pointer is undefined behavior.
+
+**Known problems:** Not all cases can be detected at the moment of this writing.
+For example, variables which hold a null pointer and are then fed to a `transmute`
+call, aren't detectable yet.
+
+**Example:**
+```rust
+let null_ref: &u64 = unsafe { std::mem::transmute(0 as *const u64) };
+```",
+    },
+    LintInfo {
+        lint: crate::regex::TRIVIAL_REGEX,
+        name: "trivial_regex",
+        group: "nursery",
+        docs: r#"**What it does:** Checks for trivial [regex](https://crates.io/crates/regex)
+creation (with `Regex::new`, `RegexBuilder::new`, or `RegexSet::new`).
+
+**Why is this bad?** Matching the regex can likely be replaced by `==` or
+`str::starts_with`, `str::ends_with` or `std::contains` or other `str`
+methods.
+
+**Known problems:** If the same regex is going to be applied to multiple
+inputs, the precomputations done by `Regex` construction can give
+significantly better performance than any of the `str`-based methods.
+
+**Example:**
+```ignore
+Regex::new("^foobar")
+```"#,
+    },
+    LintInfo {
+        lint: crate::pass_by_ref_or_value::TRIVIALLY_COPY_PASS_BY_REF,
+        name: "trivially_copy_pass_by_ref",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for functions taking arguments by reference, where
+the argument type is `Copy` and small enough to be more efficient to always
+pass by value.
+
+**Why is this bad?** In many calling conventions instances of structs will
+be passed through registers if they fit into two or less general purpose
+registers.
+
+**Known problems:** This lint is target register size dependent, it is
+limited to 32-bit to try and reduce portability problems between 32 and
+64-bit, but if you are compiling for 8 or 16-bit targets then the limit
+will be different.
+
+The configuration option `trivial_copy_size_limit` can be set to override
+this limit for a project.
+
+This lint attempts to allow passing arguments by reference if a reference
+to that argument is returned. This is implemented by comparing the lifetime
+of the argument and return value for equality. However, this can cause
+false positives in cases involving multiple lifetimes that are bounded by
+each other.
+
+Also, it does not take account of other similar cases where getting memory addresses
+matters; namely, returning the pointer to the argument in question,
+and passing the argument, as both references and pointers,
+to a function that needs the memory address. For further details, refer to
+[this issue](https://github.com/rust-lang/rust-clippy/issues/5953)
+that explains a real case in which this false positive
+led to an **undefined behaviour** introduced with unsafe code.
+
+**Example:**
+
+```rust
+// Bad
+fn foo(v: &u32) {}
+```
+
+```rust
+// Better
+fn foo(v: u32) {}
+```",
+    },
+    LintInfo {
+        lint: crate::try_err::TRY_ERR,
+        name: "try_err",
+        group: "style",
+        docs: r#"**What it does:** Checks for usages of `Err(x)?`.
+
+**Why is this bad?** The `?` operator is designed to allow calls that
+can fail to be easily chained. For example, `foo()?.bar()` or
+`foo(bar()?)`. Because `Err(x)?` can't be used that way (it will
+always return), it is more clear to write `return Err(x)`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn foo(fail: bool) -> Result<i32, String> {
+    if fail {
+      Err("failed")?;
+    }
+    Ok(0)
+}
+```
+Could be written:
+
+```rust
+fn foo(fail: bool) -> Result<i32, String> {
+    if fail {
+      return Err("failed".into());
+    }
+    Ok(0)
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::types::TYPE_COMPLEXITY,
+        name: "type_complexity",
+        group: "complexity",
+        docs: r"**What it does:** Checks for types used in structs, parameters and `let`
+declarations above a certain complexity threshold.
+
+**Why is this bad?** Too complex types make the code less readable. Consider
+using a `type` definition to simplify them.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# use std::rc::Rc;
+struct Foo {
+    inner: Rc<Vec<Vec<Box<(u32, u32, u32, u32)>>>>,
+}
+```",
+    },
+    LintInfo {
+        lint: crate::trait_bounds::TYPE_REPETITION_IN_BOUNDS,
+        name: "type_repetition_in_bounds",
+        group: "pedantic",
+        docs: r"**What it does:** This lint warns about unnecessary type repetitions in trait bounds
+
+**Why is this bad?** Repeating the type for every bound makes the code
+less readable than combining the bounds
+
+**Known problems:** None.
+
+**Example:**
+```rust
+pub fn foo<T>(t: T) where T: Copy, T: Clone {}
+```
+
+Could be written as:
+
+```rust
+pub fn foo<T>(t: T) where T: Copy + Clone {}
+```",
+    },
+    LintInfo {
+        lint: crate::unchecked_division::UNCHECKED_DIVISION,
+        name: "unchecked_division",
+        group: "restriction",
+        docs: r"**What it does:** Checks for integer division and remainder operations (`/`, `%`, `/=` and
+`%=`) whose divisor isn't known to be nonzero. A divisor is known to be nonzero if it is a
+nonzero constant, or a variable guarded by a comparison like `b != 0` or `b > 0` (or `b < 0`
+if it is signed).
+
+**Why is this bad?** Integer division by zero panics.
+
+**Known problems:** Dividing the minimum value of a signed integer type by `-1` overflows and
+panics as well, this isn't checked.
+
+**Example:**
+
+```rust
+fn average(sum: u32, count: u32) -> u32 {
+    sum / count
+}
+```
+Use instead:
+```rust
+fn average(sum: u32, count: u32) -> Option<u32> {
+    sum.checked_div(count)
+}
+```",
+    },
+    LintInfo {
+        lint: crate::unchecked_env_args::UNCHECKED_ENV_ARGS,
+        name: "unchecked_env_args",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for `unwrap()` calls on a command line argument, like
+`env::args().nth(1).unwrap()` or `env::args().skip(1).next().unwrap()`, and for indexing
+the `Vec` collected from `env::args()` (or `env::args_os()`), like `args[1]`, without a
+check of the number of arguments.
+
+**Why is this bad?** Running the program without the argument makes it panic with a
+message like "index out of bounds: the len is 1 but the index is 1", which doesn't tell the
+user what the program expects. Matching on the arguments lets the program print its usage
+instead.
+
+**Known problems:** The first argument, the path of the program, isn't linted, even though
+it can be missing. `expect()` isn't linted, since its message can describe the usage.
+
+**Example:**
+```rust,ignore
+// Bad
+let args: Vec<String> = std::env::args().collect();
+let path = &args[1];
+
+// Good
+let path = match std::env::args().nth(1) {
+    Some(path) => path,
+    None => {
+        eprintln!("usage: program <path>");
+        std::process::exit(1);
+    },
+};
+```"#,
+    },
+    LintInfo {
+        lint: crate::undropped_manually_drops::UNDROPPED_MANUALLY_DROPS,
+        name: "undropped_manually_drops",
+        group: "correctness",
+        docs: r"**What it does:** Prevents the safe `std::mem::drop` function from being called on `std::mem::ManuallyDrop`.
+
+**Why is this bad?** The safe `drop` function does not drop the inner value of a `ManuallyDrop`.
+
+**Known problems:** Does not catch cases if the user binds `std::mem::drop`
+to a different name and calls it that way.
+
+**Example:**
+
+```rust
+struct S;
+drop(std::mem::ManuallyDrop::new(S));
+```
+Use instead:
+```rust
+struct S;
+unsafe {
+    std::mem::ManuallyDrop::drop(&mut std::mem::ManuallyDrop::new(S));
+}
+```",
+    },
+    LintInfo {
+        lint: crate::methods::UNGUARDED_GET_UNCHECKED,
+        name: "unguarded_get_unchecked",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for calls to `get_unchecked` and `get_unchecked_mut` on slices
+(and on arrays and `Vec`s) whose index isn't known to be in bounds, e.g. by an
+`if i < v.len()` or an `assert!(i < v.len())` before it, or a `for i in 0..v.len()` loop.
+
+**Why is this bad?** An out of bounds index is undefined behavior. The bounds should be
+guaranteed where they are elided, so that they can be audited in a single place.
+
+**Known problems:** Only simple guards of the index are recognized. Guarantees given by
+invariants of a type or by the documentation of an `unsafe fn` aren't visible.
+
+**Example:**
+```rust
+fn first(v: &[u8]) -> u8 {
+    unsafe { *v.get_unchecked(0) }
+}
+```
+Use instead:
+```rust
+fn first(v: &[u8]) -> u8 {
+    assert!(0 < v.len());
+    unsafe { *v.get_unchecked(0) }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::unguarded_raw_pointer_index::UNGUARDED_RAW_POINTER_INDEX,
+        name: "unguarded_raw_pointer_index",
+        group: "restriction",
+        docs: r"**What it does:** Checks for dereferences of a raw pointer offset by a value, like
+`*ptr.add(i)` or `ptr.add(i).read()`, and for indexing of a slice created from a raw
+pointer and a length, like `slice::from_raw_parts(ptr, len)[i]`, where the offset or the
+index isn't related to a length by a visible check: an `if i < len`, an `assert!(i < len)`
+or a `for i in 0..len` loop.
+
+**Why is this bad?** Nothing shows that the offset stays within the allocation the pointer
+comes from, and reading or writing out of it is undefined behavior. This lint is meant as
+an aid for reviewers of unsafe code: every diagnostic lists the values whose relation has to
+be justified.
+
+**Known problems:** Invariants of a type and the safety documentation of an `unsafe fn`
+aren't visible. The length of the allocation of a pointer isn't known, so any comparison of
+its offset with an upper bound counts as a check.
+
+**Example:**
+
+```rust
+unsafe fn nth(data: *const u32, len: usize, n: usize) -> u32 {
+    *data.add(n)
+}
+```
+Use instead:
+```rust
+unsafe fn nth(data: *const u32, len: usize, n: usize) -> u32 {
+    assert!(n < len);
+    *data.add(n)
+}
+```",
+    },
+    LintInfo {
+        lint: crate::unhelpful_expect_message::UNHELPFUL_EXPECT_MESSAGE,
+        name: "unhelpful_expect_message",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for `expect()` on an `Option` or `Result` with an empty message,
+or a message matching one of the regular expressions of the `unhelpful-expect-messages`
+configuration value, which by default match messages that just repeat the method name, like
+`expect("expect failed")`, or say nothing else than that something failed.
+
+**Why is this bad?** The message is shown when the program panics. A message describing
+why the value was expected to be there helps to find out what went wrong, an empty message
+is no better than `unwrap()`.
+
+`unwrap()` is suggested as an alternative, unless the `unwrap_used` lint is enabled.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+# let config: Option<u32> = Some(1);
+// Bad
+let x = config.expect("");
+let x = config.expect("expect failed");
+
+// Good
+let x = config.expect("the config is loaded at startup");
+```"#,
+    },
+    LintInfo {
+        lint: crate::unicode::UNICODE_NOT_NFC,
+        name: "unicode_not_nfc",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for string literals that contain Unicode in a form
+that is not equal to its
+[NFC-recomposition](http://www.unicode.org/reports/tr15/#Norm_Forms).
+
+**Why is this bad?** If such a string is compared to another, the results
+may be surprising.
+
+**Known problems** None.
+
+**Example:** You may not see it, but "à"" and "à"" aren't the same string. The
+former when escaped is actually `"a\u{300}"` while the latter is `"\u{e0}"`."#,
+    },
+    LintInfo {
+        lint: crate::panic_unimplemented::UNIMPLEMENTED,
+        name: "unimplemented",
+        group: "restriction",
+        docs: r"**What it does:** Checks for usage of `unimplemented!`.
+
+**Why is this bad?** This macro should not be present in production code
+
+**Known problems:** None.
+
+**Example:**
+```no_run
+unimplemented!();
+```",
+    },
+    LintInfo {
+        lint: crate::methods::UNINIT_ASSUMED_INIT,
+        name: "uninit_assumed_init",
+        group: "correctness",
+        docs: r"**What it does:** Checks for `MaybeUninit::uninit().assume_init()`.
+
+**Why is this bad?** For most types, this is undefined behavior.
+
+**Known problems:** For now, we accept empty tuples and tuples / arrays
+of `MaybeUninit`. There may be other types that allow uninitialized
+data, but those are not yet rigorously defined.
+
+**Example:**
+
+```rust
+// Beware the UB
+use std::mem::MaybeUninit;
+
+let _: usize = unsafe { MaybeUninit::uninit().assume_init() };
+```
+
+Note that the following is OK:
+
+```rust
+use std::mem::MaybeUninit;
+
+let _: [MaybeUninit<bool>; 5] = unsafe {
+    MaybeUninit::uninit().assume_init()
+};
+```",
+    },
+    LintInfo {
+        lint: crate::unit_types::UNIT_ARG,
+        name: "unit_arg",
+        group: "complexity",
+        docs: r"**What it does:** Checks for passing a unit value as an argument to a function without using a
+unit literal (`()`).
+
+**Why is this bad?** This is likely the result of an accidental semicolon.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+foo({
+    let a = bar();
+    baz(a);
+})
+```",
+    },
+    LintInfo {
+        lint: crate::unit_types::UNIT_CMP,
+        name: "unit_cmp",
+        group: "correctness",
+        docs: r"**What it does:** Checks for comparisons to unit. This includes all binary
+comparisons (like `==` and `<`) and asserts.
+
+**Why is this bad?** Unit is always equal to itself, and thus is just a
+clumsily written constant. Mostly this happens when someone accidentally
+adds semicolons at the end of the operands.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# fn foo() {};
+# fn bar() {};
+# fn baz() {};
+if {
+    foo();
+} == {
+    bar();
+} {
+    baz();
+}
+```
+is equal to
+```rust
+# fn foo() {};
+# fn bar() {};
+# fn baz() {};
+{
+    foo();
+    bar();
+    baz();
+}
+```
+
+For asserts:
+```rust
+# fn foo() {};
+# fn bar() {};
+assert_eq!({ foo(); }, { bar(); });
+```
+will always succeed",
+    },
+    LintInfo {
+        lint: crate::unit_return_expecting_ord::UNIT_RETURN_EXPECTING_ORD,
+        name: "unit_return_expecting_ord",
+        group: "correctness",
+        docs: r"**What it does:** Checks for functions that expect closures of type
+Fn(...) -> Ord where the implemented closure returns the unit type.
+The lint also suggests to remove the semi-colon at the end of the statement if present.
+
+**Why is this bad?** Likely, returning the unit type is unintentional, and
+could simply be caused by an extra semi-colon. Since () implements Ord
+it doesn't cause a compilation error.
+This is the same reasoning behind the unit_cmp lint.
+
+**Known problems:** If returning unit is intentional, then there is no
+way of specifying this without triggering needless_return lint
+
+**Example:**
+
+```rust
+let mut twins = vec!((1, 1), (2, 2));
+twins.sort_by_key(|x| { x.1; });
+```",
+    },
+    LintInfo {
+        lint: crate::casts::UNNECESSARY_CAST,
+        name: "unnecessary_cast",
+        group: "complexity",
+        docs: r"**What it does:** Checks for casts to the same type, casts of int literals to integer types
+and casts of float literals to float types.
+
+**Why is this bad?** It's just unnecessary.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let _ = 2i32 as i32;
+let _ = 0.5 as f32;
+```
+
+Better:
+
+```rust
+let _ = 2_i32;
+let _ = 0.5_f32;
+```",
+    },
+    LintInfo {
+        lint: crate::methods::UNNECESSARY_FILTER_MAP,
+        name: "unnecessary_filter_map",
+        group: "complexity",
+        docs: r"**What it does:** Checks for `filter_map` calls which could be replaced by `filter` or `map`.
+More specifically it checks if the closure provided is only performing one of the
+filter or map operations and suggests the appropriate option.
+
+**Why is this bad?** Complexity. The intent is also clearer if only a single
+operation is being performed.
+
+**Known problems:** None
+
+**Example:**
+```rust
+let _ = (0..3).filter_map(|x| if x > 2 { Some(x) } else { None });
+
+// As there is no transformation of the argument this could be written as:
+let _ = (0..3).filter(|&x| x > 2);
+```
+
+```rust
+let _ = (0..4).filter_map(|x| Some(x + 1));
+
+// As there is no conditional check on the argument this could be written as:
+let _ = (0..4).map(|x| x + 1);
+```",
+    },
+    LintInfo {
+        lint: crate::methods::UNNECESSARY_FOLD,
+        name: "unnecessary_fold",
+        group: "style",
+        docs: r"**What it does:** Checks for using `fold` when a more succinct alternative exists.
+Specifically, this checks for `fold`s which could be replaced by `any`, `all`,
+`sum` or `product`.
+
+**Why is this bad?** Readability.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let _ = (0..3).fold(false, |acc, x| acc || x > 2);
+```
+This could be written as:
+```rust
+let _ = (0..3).any(|x| x > 2);
+```",
+    },
+    LintInfo {
+        lint: crate::methods::UNNECESSARY_LAZY_EVALUATIONS,
+        name: "unnecessary_lazy_evaluations",
+        group: "style",
+        docs: r"**What it does:** As the counterpart to `or_fun_call`, this lint looks for unnecessary
+lazily evaluated closures on `Option` and `Result`.
+
+This lint suggests changing the following functions, when eager evaluation results in
+simpler code:
+ - `unwrap_or_else` to `unwrap_or`
+ - `and_then` to `and`
+ - `or_else` to `or`
+ - `get_or_insert_with` to `get_or_insert`
+ - `ok_or_else` to `ok_or`
+
+**Why is this bad?** Using eager evaluation is shorter and simpler in some cases.
+
+**Known problems:** It is possible, but not recommended for `Deref` and `Index` to have
+side effects. Eagerly evaluating them can change the semantics of the program.
+
+**Example:**
+
+```rust
+// example code where clippy issues a warning
+let opt: Option<u32> = None;
+
+opt.unwrap_or_else(|| 42);
+```
+Use instead:
+```rust
+let opt: Option<u32> = None;
+
+opt.unwrap_or(42);
+```",
+    },
+    LintInfo {
+        lint: crate::mut_reference::UNNECESSARY_MUT_PASSED,
+        name: "unnecessary_mut_passed",
+        group: "style",
+        docs: r"**What it does:** Detects passing a mutable reference to a function that only
+requires an immutable reference.
+
+**Why is this bad?** The mutable reference rules out all other references to
+the value. Also the code misleads about the intent of the call site.
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+// Bad
+my_vec.push(&mut value)
+
+// Good
+my_vec.push(&value)
+```",
+    },
+    LintInfo {
+        lint: crate::no_effect::UNNECESSARY_OPERATION,
+        name: "unnecessary_operation",
+        group: "complexity",
+        docs: r"**What it does:** Checks for expression statements that can be reduced to a
+sub-expression.
+
+**Why is this bad?** Expressions by themselves often have no side-effects.
+Having such expressions reduces readability.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+compute_array()[0];
+```",
+    },
+    LintInfo {
+        lint: crate::unnecessary_self_imports::UNNECESSARY_SELF_IMPORTS,
+        name: "unnecessary_self_imports",
+        group: "restriction",
+        docs: r"**What it does:** Checks for imports ending in `::{self}`.
+
+**Why is this bad?** In most cases, this can be written much more cleanly by omitting `::{self}`.
+
+**Known problems:** Removing `::{self}` will cause any non-module items at the same path to also be imported.
+This might cause a naming conflict (https://github.com/rust-lang/rustfmt/issues/3568). This lint makes no attempt
+to detect this scenario and that is why it is a restriction lint.
+
+**Example:**
+
+```rust
+use std::io::{self};
+```
+Use instead:
+```rust
+use std::io;
+```",
+    },
+    LintInfo {
+        lint: crate::unnecessary_sort_by::UNNECESSARY_SORT_BY,
+        name: "unnecessary_sort_by",
+        group: "complexity",
+        docs: r"**What it does:**
+Detects uses of `Vec::sort_by` passing in a closure
+which compares the two arguments, either directly or indirectly.
+
+**Why is this bad?**
+It is more clear to use `Vec::sort_by_key` (or `Vec::sort` if
+possible) than to use `Vec::sort_by` and a more complicated
+closure.
+
+**Known problems:**
+If the suggested `Vec::sort_by_key` uses Reverse and it isn't already
+imported by a use statement, then it will need to be added manually.
+
+**Example:**
+
+```rust
+# struct A;
+# impl A { fn foo(&self) {} }
+# let mut vec: Vec<A> = Vec::new();
+vec.sort_by(|a, b| a.foo().cmp(&b.foo()));
+```
+Use instead:
+```rust
+# struct A;
+# impl A { fn foo(&self) {} }
+# let mut vec: Vec<A> = Vec::new();
+vec.sort_by_key(|a| a.foo());
+```",
+    },
+    LintInfo {
+        lint: crate::unwrap::UNNECESSARY_UNWRAP,
+        name: "unnecessary_unwrap",
+        group: "complexity",
+        docs: r"**What it does:** Checks for calls of `unwrap[_err]()` that cannot fail.
+
+**Why is this bad?** Using `if let` or `match` is more idiomatic.
+
+**Known problems:** None
+
+**Example:**
+```rust
+# let option = Some(0);
+# fn do_something_with(_x: usize) {}
+if option.is_some() {
+    do_something_with(option.unwrap())
+}
+```
+
+Could be written:
+
+```rust
+# let option = Some(0);
+# fn do_something_with(_x: usize) {}
+if let Some(value) = option {
+    do_something_with(value)
+}
+```",
+    },
+    LintInfo {
+        lint: crate::unnecessary_wraps::UNNECESSARY_WRAPS,
+        name: "unnecessary_wraps",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for private functions that only return `Ok` or `Some`.
+
+**Why is this bad?** It is not meaningful to wrap values when no `None` or `Err` is returned.
+
+**Known problems:** There can be false positives if the function signature is designed to
+fit some external requirement.
+
+**Example:**
+
+```rust
+fn get_cool_number(a: bool, b: bool) -> Option<i32> {
+    if a && b {
+        return Some(50);
+    }
+    if a {
+        Some(0)
+    } else {
+        Some(10)
+    }
+}
+```
+Use instead:
+```rust
+fn get_cool_number(a: bool, b: bool) -> i32 {
+    if a && b {
+        return 50;
+    }
+    if a {
+        0
+    } else {
+        10
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::misc_early::UNNEEDED_FIELD_PATTERN,
+        name: "unneeded_field_pattern",
+        group: "restriction",
+        docs: r"**What it does:** Checks for structure field patterns bound to wildcards.
+
+**Why is this bad?** Using `..` instead is shorter and leaves the focus on
+the fields that are actually bound.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# struct Foo {
+#     a: i32,
+#     b: i32,
+#     c: i32,
+# }
+let f = Foo { a: 0, b: 0, c: 0 };
+
+// Bad
+match f {
+    Foo { a: _, b: 0, .. } => {},
+    Foo { a: _, b: _, c: _ } => {},
+}
+
+// Good
+match f {
+    Foo { b: 0, .. } => {},
+    Foo { .. } => {},
+}
+```",
+    },
+    LintInfo {
+        lint: crate::misc_early::UNNEEDED_WILDCARD_PATTERN,
+        name: "unneeded_wildcard_pattern",
+        group: "complexity",
+        docs: r"**What it does:** Checks for tuple patterns with a wildcard
+pattern (`_`) is next to a rest pattern (`..`).
+
+_NOTE_: While `_, ..` means there is at least one element left, `..`
+means there are 0 or more elements left. This can make a difference
+when refactoring, but shouldn't result in errors in the refactored code,
+since the wildcard pattern isn't used anyway.
+**Why is this bad?** The wildcard pattern is unneeded as the rest pattern
+can match that element as well.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# struct TupleStruct(u32, u32, u32);
+# let t = TupleStruct(1, 2, 3);
+// Bad
+match t {
+    TupleStruct(0, .., _) => (),
+    _ => (),
+}
+
+// Good
+match t {
+    TupleStruct(0, ..) => (),
+    _ => (),
+}
+```",
+    },
+    LintInfo {
+        lint: crate::unnested_or_patterns::UNNESTED_OR_PATTERNS,
+        name: "unnested_or_patterns",
+        group: "pedantic",
+        docs: r"**What it does:**
+
+Checks for unnested or-patterns, e.g., `Some(0) | Some(2)` and
+suggests replacing the pattern with a nested one, `Some(0 | 2)`.
+
+Another way to think of this is that it rewrites patterns in
+*disjunctive normal form (DNF)* into *conjunctive normal form (CNF)*.
+
+**Why is this bad?**
+
+In the example above, `Some` is repeated, which unncessarily complicates the pattern.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+fn main() {
+    if let Some(0) | Some(2) = Some(0) {}
+}
+```
+Use instead:
+```rust
+#![feature(or_patterns)]
+
+fn main() {
+    if let Some(0 | 2) = Some(0) {}
+}
+```",
+    },
+    LintInfo {
+        lint: crate::panic_unimplemented::UNREACHABLE,
+        name: "unreachable",
+        group: "restriction",
+        docs: r"**What it does:** Checks for usage of `unreachable!`.
+
+**Why is this bad?** This macro can cause code to panic
+
+**Known problems:** None.
+
+**Example:**
+```no_run
+unreachable!();
+```",
+    },
+    LintInfo {
+        lint: crate::literal_representation::UNREADABLE_LITERAL,
+        name: "unreadable_literal",
+        group: "pedantic",
+        docs: r"**What it does:** Warns if a long integral or floating-point constant does
+not contain underscores.
+
+**Why is this bad?** Reading long numbers is difficult without separators.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+// Bad
+let x: u64 = 61864918973511;
+
+// Good
+let x: u64 = 61_864_918_973_511;
+```",
+    },
+    LintInfo {
+        lint: crate::derive::UNSAFE_DERIVE_DESERIALIZE,
+        name: "unsafe_derive_deserialize",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for deriving `serde::Deserialize` on a type that
+has methods using `unsafe`.
+
+**Why is this bad?** Deriving `serde::Deserialize` will create a constructor
+that may violate invariants hold by another constructor.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust,ignore
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct Foo {
+    // ..
+}
+
+impl Foo {
+    pub fn new() -> Self {
+        // setup here ..
+    }
+
+    pub unsafe fn parts() -> (&str, &str) {
+        // assumes invariants hold
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME,
+        name: "unsafe_removed_from_name",
+        group: "style",
+        docs: r#"**What it does:** Checks for imports that remove "unsafe" from an item's
+name.
+
+**Why is this bad?** Renaming makes it less clear which traits and
+structures are unsafe.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+use std::cell::{UnsafeCell as TotallySafeCell};
+
+extern crate crossbeam;
+use crossbeam::{spawn_unsafe as spawn};
+```"#,
+    },
+    LintInfo {
+        lint: crate::misc_early::UNSEPARATED_LITERAL_SUFFIX,
+        name: "unseparated_literal_suffix",
+        group: "pedantic",
+        docs: r"**What it does:** Warns if literal suffixes are not separated by an
+underscore.
+
+**Why is this bad?** It is much less readable.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+// Bad
+let y = 123832i32;
+
+// Good
+let y = 123832_i32;
+```",
+    },
+    LintInfo {
+        lint: crate::transmute::UNSOUND_COLLECTION_TRANSMUTE,
+        name: "unsound_collection_transmute",
+        group: "correctness",
+        docs: r"**What it does:** Checks for transmutes between collections whose
+types have different ABI, size or alignment.
+
+**Why is this bad?** This is undefined behavior.
+
+**Known problems:** Currently, we cannot know whether a type is a
+collection, so we just lint the ones that come with `std`.
+
+**Example:**
+```rust
+// different size, therefore likely out-of-bounds memory access
+// You absolutely do not want this in your code!
+unsafe {
+    std::mem::transmute::<_, Vec<u32>>(vec![2_u16])
+};
+```
+
+You must always iterate, map and collect the values:
+
+```rust
+vec![2_u16].into_iter().map(u32::from).collect::<Vec<_>>();
+```",
+    },
+    LintInfo {
+        lint: crate::unused_io_amount::UNUSED_IO_AMOUNT,
+        name: "unused_io_amount",
+        group: "correctness",
+        docs: r#"**What it does:** Checks for unused written/read amount.
+
+**Why is this bad?** `io::Write::write(_vectored)` and
+`io::Read::read(_vectored)` are not guaranteed to
+process the entire buffer. They return how many bytes were processed, which
+might be smaller
+than a given buffer's length. If you don't need to deal with
+partial-write/read, use
+`write_all`/`read_exact` instead.
+
+**Known problems:** Detects only common patterns.
+
+**Example:**
+```rust,ignore
+use std::io;
+fn foo<W: io::Write>(w: &mut W) -> io::Result<()> {
+    // must be `w.write_all(b"foo")?;`
+    w.write(b"foo")?;
+    Ok(())
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::unused_self::UNUSED_SELF,
+        name: "unused_self",
+        group: "pedantic",
+        docs: r"**What it does:** Checks methods that contain a `self` argument but don't use it
+
+**Why is this bad?** It may be clearer to define the method as an associated function instead
+of an instance method if it doesn't require `self`.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+struct A;
+impl A {
+    fn method(&self) {}
+}
+```
+
+Could be written:
+
+```rust,ignore
+struct A;
+impl A {
+    fn method() {}
+}
+```",
+    },
+    LintInfo {
+        lint: crate::unused_unit::UNUSED_UNIT,
+        name: "unused_unit",
+        group: "style",
+        docs: r"**What it does:** Checks for unit (`()`) expressions that can be removed.
+
+**Why is this bad?** Such expressions add no value, but can make the code
+less readable. Depending on formatting they can make a `break` or `return`
+statement look like a function call.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn return_unit() -> () {
+    ()
+}
+```",
+    },
+    LintInfo {
+        lint: crate::literal_representation::UNUSUAL_BYTE_GROUPINGS,
+        name: "unusual_byte_groupings",
+        group: "style",
+        docs: r"**What it does:** Warns if hexadecimal or binary literals are not grouped
+by nibble or byte.
+
+**Why is this bad?** Negatively impacts readability.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+let x: u32 = 0xFFF_FFF;
+let y: u8 = 0b01_011_101;
+```",
+    },
+    LintInfo {
+        lint: crate::unwrap_in_result::UNWRAP_IN_RESULT,
+        name: "unwrap_in_result",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for functions of type Result that contain `expect()` or `unwrap()`
+
+**Why is this bad?** These functions promote recoverable errors to non-recoverable errors which may be undesirable in code bases which wish to avoid panics.
+
+**Known problems:** This can cause false positives in functions that handle both recoverable and non recoverable errors.
+
+**Example:**
+Before:
+```rust
+fn divisible_by_3(i_str: String) -> Result<(), String> {
+    let i = i_str
+        .parse::<i32>()
+        .expect("cannot divide the input by three");
+
+    if i % 3 != 0 {
+        Err("Number is not divisible by 3")?
+    }
+
+    Ok(())
+}
+```
+
+After:
+```rust
+fn divisible_by_3(i_str: String) -> Result<(), String> {
+    let i = i_str
+        .parse::<i32>()
+        .map_err(|e| format!("cannot divide the input by three: {}", e))?;
+
+    if i % 3 != 0 {
+        Err("Number is not divisible by 3")?
+    }
+
+    Ok(())
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::unwrap_indexing::UNWRAP_INDEXING,
+        name: "unwrap_indexing",
+        group: "restriction",
+        docs: r"**What it does:** Checks for indexing and slicing of the value returned by `unwrap()` or
+`expect()` on an `Option` or `Result`, or by the `?` operator, like `foo().unwrap()[i]` and
+`foo()?[i]`, where the indexed value is a `Vec`, a slice or an array.
+
+**Why is this bad?** `foo().unwrap()[i]` stacks two panics in a single expression, and the
+panic message doesn't tell which one failed. After `?`, the error handling of the function
+is bypassed by the panicking index. Using `get` turns the index into an `Option` that can be
+handled like the missing value.
+
+**Known problems:** The rewrite depends on the return type of the function, so no code
+suggestion is provided.
+
+**Example:**
+
+```rust
+# fn values() -> Option<Vec<u32>> { None }
+# fn f(i: usize) -> Option<u32> {
+// Bad
+let x = values().unwrap()[i];
+let x = values()?[i];
+
+// Good
+let x = *values()?.get(i)?;
+# Some(x)
+# }
+```",
+    },
+    LintInfo {
+        lint: crate::methods::UNWRAP_USED,
+        name: "unwrap_used",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for `.unwrap()` calls on `Option`s and on `Result`s.
+
+**Why is this bad?** It is better to handle the `None` or `Err` case,
+or at least call `.expect(_)` with a more helpful message. Still, for a lot of
+quick-and-dirty code, `unwrap` is a good choice, which is why this lint is
+`Allow` by default.
+
+`result.unwrap()` will let the thread panic on `Err` values.
+Normally, you want to implement more sophisticated error handling,
+and propagate errors upwards with `?` operator.
+
+Even if you want to panic on errors, not all `Error`s implement good
+messages on display. Therefore, it may be beneficial to look at the places
+where they may get displayed. Activate this lint to do just that.
+
+**Known problems:** None.
+
+**Examples:**
+```rust
+# let opt = Some(1);
+
+// Bad
+opt.unwrap();
+
+// Good
+opt.expect("more helpful message");
+```
+
+// or
+
+```rust
+# let res: Result<usize, ()> = Ok(1);
+
+// Bad
+res.unwrap();
+
+// Good
+res.expect("more helpful message");
+```"#,
+    },
+    LintInfo {
+        lint: crate::methods::UNWRAPPED_FIRST_OR_LAST,
+        name: "unwrapped_first_or_last",
+        group: "restriction",
+        docs: r"**What it does:** Checks for `.first().unwrap()` and `.last().unwrap()` on slices, and for
+`.next().unwrap()` on iterators. This complements the indexing lints for `v[0]`.
+
+**Why is this bad?** It panics if the slice or the iterator is empty, just like `v[0]`
+does. The emptiness should be handled, e.g. with `if let`, or at least documented in the
+message of an `expect`.
+
+**Known problems:** Some iterators are never empty, like the one returned by `str::split`.
+
+**Example:**
+```rust
+fn header(lines: &[&str]) -> usize {
+    lines.first().unwrap().len()
+}
+```
+Use instead:
+```rust
+fn header(lines: &[&str]) -> usize {
+    if let Some(first) = lines.first() { first.len() } else { 0 }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::methods::UNWRAPPED_GET,
+        name: "unwrapped_get",
+        group: "restriction",
+        docs: r"**What it does:** Checks for `.get().unwrap()` and `.get().expect()` (or their `get_mut`
+variants) on a standard library type which implements `Index`. This is the companion of
+[`indexing_slicing`](#indexing_slicing): [`get_unwrap`](#get_unwrap) doesn't lint where
+`indexing_slicing` is enabled, as its suggestion would be linted again.
+
+**Why is this bad?** It panics if the value is missing, just like indexing does. Replacing
+indexing with `.get().unwrap()` only hides the panic.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn first_word(words: &[&str]) -> usize {
+    words.get(0).unwrap().len()
+}
+```
+Use instead:
+```rust
+fn first_word(words: &[&str]) -> Option<usize> {
+    Some(words.get(0)?.len())
+}
+```",
+    },
+    LintInfo {
+        lint: crate::unwrapped_index_conversion::UNWRAPPED_INDEX_CONVERSION,
+        name: "unwrapped_index_conversion",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for indexes and slicing bounds of a `Vec`, a slice or an array that
+are converted to `usize` with `TryFrom` or `TryInto` and unwrapped, like
+`v[usize::try_from(x).unwrap()]` or `v[TryInto::<usize>::try_into(x).expect("index")]`.
+
+**Why is this bad?** The conversion panics if the value doesn't fit in a `usize`, and the
+indexing panics if the converted value is out of bounds. The two checks of the same index
+are made in a single expression, and neither failure can be handled. Propagating the
+conversion error and using `get` handles both.
+
+**Known problems:** The rewrite depends on the return type of the function, so no code
+suggestion is provided. `From` conversions to `usize` can't fail and aren't linted.
+
+**Example:**
+
+```rust
+# use std::convert::TryFrom;
+# fn f(v: &[u32], x: i64) -> Result<Option<u32>, std::num::TryFromIntError> {
+// Bad
+let y = v[usize::try_from(x).unwrap()];
+
+// Good
+let y = v.get(usize::try_from(x)?).copied();
+# Ok(y)
+# }
+```"#,
+    },
+    LintInfo {
+        lint: crate::upper_case_acronyms::UPPER_CASE_ACRONYMS,
+        name: "upper_case_acronyms",
+        group: "style",
+        docs: r"**What it does:** Checks for fully capitalized names and optionally names containing a capitalized acronym.
+
+**Why is this bad?** In CamelCase, acronyms count as one word.
+See [naming conventions](https://rust-lang.github.io/api-guidelines/naming.html#casing-conforms-to-rfc-430-c-case)
+for more.
+
+By default, the lint only triggers on fully-capitalized names.
+You can use the `upper-case-acronyms-aggressive: true` config option to enable linting
+on all camel case names
+
+**Known problems:** When two acronyms are contiguous, the lint can't tell where
+the first acronym ends and the second starts, so it suggests to lowercase all of
+the letters in the second acronym.
+
+**Example:**
+
+```rust
+struct HTTPResponse;
+```
+Use instead:
+```rust
+struct HttpResponse;
+```",
+    },
+    LintInfo {
+        lint: crate::write::USE_DEBUG,
+        name: "use_debug",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for use of `Debug` formatting. The purpose of this
+lint is to catch debugging remnants.
+
+**Why is this bad?** The purpose of the `Debug` trait is to facilitate
+debugging Rust code. It should not be used in user-facing output.
+
+**Example:**
+```rust
+# let foo = "bar";
+println!("{:?}", foo);
+```"#,
+    },
+    LintInfo {
+        lint: crate::use_self::USE_SELF,
+        name: "use_self",
+        group: "nursery",
+        docs: r"**What it does:** Checks for unnecessary repetition of structure name when a
+replacement with `Self` is applicable.
+
+**Why is this bad?** Unnecessary repetition. Mixed use of `Self` and struct
+name
+feels inconsistent.
+
+**Known problems:**
+- Unaddressed false negative in fn bodies of trait implementations
+- False positive with assotiated types in traits (#4140)
+
+**Example:**
+
+```rust
+struct Foo {}
+impl Foo {
+    fn new() -> Foo {
+        Foo {}
+    }
+}
+```
+could be
+```rust
+struct Foo {}
+impl Foo {
+    fn new() -> Self {
+        Self {}
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::misc::USED_UNDERSCORE_BINDING,
+        name: "used_underscore_binding",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for the use of bindings with a single leading
+underscore.
+
+**Why is this bad?** A single leading underscore is usually used to indicate
+that a binding will not be used. Using such a binding breaks this
+expectation.
+
+**Known problems:** The lint does not work properly with desugaring and
+macro, it has been allowed in the mean time.
+
+**Example:**
+```rust
+let _x = 0;
+let y = _x + 1; // Here we are using `_x`, even though it has a leading
+                // underscore. We should rename `_x` to `x`
+```",
+    },
+    LintInfo {
+        lint: crate::methods::USELESS_ASREF,
+        name: "useless_asref",
+        group: "complexity",
+        docs: r"**What it does:** Checks for usage of `.as_ref()` or `.as_mut()` where the
+types before and after the call are the same.
+
+**Why is this bad?** The call is unnecessary.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# fn do_stuff(x: &[i32]) {}
+let x: &[i32] = &[1, 2, 3, 4, 5];
+do_stuff(x.as_ref());
+```
+The correct use would be:
+```rust
+# fn do_stuff(x: &[i32]) {}
+let x: &[i32] = &[1, 2, 3, 4, 5];
+do_stuff(x);
+```",
+    },
+    LintInfo {
+        lint: crate::attrs::USELESS_ATTRIBUTE,
+        name: "useless_attribute",
+        group: "correctness",
+        docs: r"**What it does:** Checks for `extern crate` and `use` items annotated with
+lint attributes.
+
+This lint permits `#[allow(unused_imports)]`, `#[allow(deprecated)]`,
+`#[allow(unreachable_pub)]`, `#[allow(clippy::wildcard_imports)]` and
+`#[allow(clippy::enum_glob_use)]` on `use` items and `#[allow(unused_imports)]` on
+`extern crate` items with a `#[macro_use]` attribute.
+
+**Why is this bad?** Lint attributes have no effect on crate imports. Most
+likely a `!` was forgotten.
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+// Bad
+#[deny(dead_code)]
+extern crate foo;
+#[forbid(dead_code)]
+use foo::bar;
+
+// Ok
+#[allow(unused_imports)]
+use foo::baz;
+#[allow(unused_imports)]
+#[macro_use]
+extern crate baz;
+```",
+    },
+    LintInfo {
+        lint: crate::useless_conversion::USELESS_CONVERSION,
+        name: "useless_conversion",
+        group: "complexity",
+        docs: r#"**What it does:** Checks for `Into`, `TryInto`, `From`, `TryFrom`, or `IntoIter` calls
+which uselessly convert to the same type.
+
+**Why is this bad?** Redundant code.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+// Bad
+// format!() returns a `String`
+let s: String = format!("hello").into();
+
+// Good
+let s: String = format!("hello");
+```"#,
+    },
+    LintInfo {
+        lint: crate::format::USELESS_FORMAT,
+        name: "useless_format",
+        group: "complexity",
+        docs: r#"**What it does:** Checks for the use of `format!("string literal with no
+argument")` and `format!("{}", foo)` where `foo` is a string.
+
+**Why is this bad?** There is no point of doing that. `format!("foo")` can
+be replaced by `"foo".to_owned()` if you really need a `String`. The even
+worse `&format!("foo")` is often encountered in the wild. `format!("{}",
+foo)` can be replaced by `foo.clone()` if `foo: String` or `foo.to_owned()`
+if `foo: &str`.
+
+**Known problems:** None.
+
+**Examples:**
+```rust
+
+// Bad
+let foo = "foo";
+format!("{}", foo);
+
+// Good
+foo.to_owned();
+```"#,
+    },
+    LintInfo {
+        lint: crate::let_if_seq::USELESS_LET_IF_SEQ,
+        name: "useless_let_if_seq",
+        group: "nursery",
+        docs: r"**What it does:** Checks for variable declarations immediately followed by a
+conditional affectation.
+
+**Why is this bad?** This is not idiomatic Rust.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+let foo;
+
+if bar() {
+    foo = 42;
+} else {
+    foo = 0;
+}
+
+let mut baz = None;
+
+if bar() {
+    baz = Some(42);
+}
+```
+
+should be written
+
+```rust,ignore
+let foo = if bar() {
+    42
+} else {
+    0
+};
+
+let baz = if bar() {
+    Some(42)
+} else {
+    None
+};
+```",
+    },
+    LintInfo {
+        lint: crate::transmute::USELESS_TRANSMUTE,
+        name: "useless_transmute",
+        group: "nursery",
+        docs: r"**What it does:** Checks for transmutes to the original type of the object
+and transmutes that could be a cast.
+
+**Why is this bad?** Readability. The code tricks people into thinking that
+something complex is going on.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+core::intrinsics::transmute(t); // where the result type is the same as `t`'s
+```",
+    },
+    LintInfo {
+        lint: crate::vec::USELESS_VEC,
+        name: "useless_vec",
+        group: "perf",
+        docs: r"**What it does:** Checks for usage of `&vec![..]` when using `&[..]` would
+be possible.
+
+**Why is this bad?** This is less efficient.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# fn foo(my_vec: &[u8]) {}
+
+// Bad
+foo(&vec![1, 2]);
+
+// Good
+foo(&[1, 2]);
+```",
+    },
+    LintInfo {
+        lint: crate::types::VEC_BOX,
+        name: "vec_box",
+        group: "complexity",
+        docs: r"**What it does:** Checks for use of `Vec<Box<T>>` where T: Sized anywhere in the code.
+Check the [Box documentation](https://doc.rust-lang.org/std/boxed/index.html) for more information.
+
+**Why is this bad?** `Vec` already keeps its contents in a separate area on
+the heap. So if you `Box` its contents, you just add another level of indirection.
+
+**Known problems:** Vec<Box<T: Sized>> makes sense if T is a large type (see [#3530](https://github.com/rust-lang/rust-clippy/issues/3530),
+1st comment).
+
+**Example:**
+```rust
+struct X {
+    values: Vec<Box<i32>>,
+}
+```
+
+Better:
+
+```rust
+struct X {
+    values: Vec<i32>,
+}
+```",
+    },
+    LintInfo {
+        lint: crate::vec_front_in_loop::VEC_FRONT_IN_LOOP,
+        name: "vec_front_in_loop",
+        group: "perf",
+        docs: r"**What it does:** Checks for `insert(0, _)` and `remove(0)` on a `Vec` in a loop.
+
+**Why is this bad?** Both move all the elements of the vector, so the loop takes quadratic
+time. A `VecDeque` adds and removes elements at both ends in constant time with
+`push_front` and `pop_front`.
+
+The suggestion changes the type of the local vector to a `VecDeque` if all the other uses of
+the local are supported by `VecDeque` as well: calls of methods it has, like `len()` or
+`push` (which becomes `push_back`), indexing with a `usize` and iterating in a `for` loop.
+
+**Known problems:** The elements of a `VecDeque` aren't contiguous, so it can't be used as
+a slice without `make_contiguous`.
+
+**Example:**
+```rust
+let mut queue = Vec::new();
+queue.push(1);
+while let Some(n) = queue.pop() {
+    if n < 10 {
+        queue.insert(0, n + 1);
+    }
+}
+```
+Use instead:
+```rust
+let mut queue = std::collections::VecDeque::new();
+queue.push_back(1);
+while let Some(n) = queue.pop_back() {
+    if n < 10 {
+        queue.push_front(n + 1);
+    }
+}
+```",
+    },
+    LintInfo {
+        lint: crate::vec_init_then_push::VEC_INIT_THEN_PUSH,
+        name: "vec_init_then_push",
+        group: "perf",
+        docs: r"**What it does:** Checks for calls to `push` immediately after creating a new `Vec`.
+
+**Why is this bad?** The `vec![]` macro is both more performant and easier to read than
+multiple `push` calls.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+let mut v = Vec::new();
+v.push(0);
+```
+Use instead:
+```rust
+let v = vec![0];
+```",
+    },
+    LintInfo {
+        lint: crate::vec_resize_to_zero::VEC_RESIZE_TO_ZERO,
+        name: "vec_resize_to_zero",
+        group: "correctness",
+        docs: r"**What it does:** Finds occurrences of `Vec::resize(0, an_int)`
+
+**Why is this bad?** This is probably an argument inversion mistake.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+vec!(1, 2, 3, 4, 5).resize(0, 5)
+```",
+    },
+    LintInfo {
+        lint: crate::bit_mask::VERBOSE_BIT_MASK,
+        name: "verbose_bit_mask",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for bit masks that can be replaced by a call
+to `trailing_zeros`
+
+**Why is this bad?** `x.trailing_zeros() > 4` is much clearer than `x & 15
+== 0`
+
+**Known problems:** llvm generates better code for `x & 15 == 0` on x86
+
+**Example:**
+```rust
+# let x = 1;
+if x & 0b1111 == 0 { }
+```",
+    },
+    LintInfo {
+        lint: crate::verbose_file_reads::VERBOSE_FILE_READS,
+        name: "verbose_file_reads",
+        group: "restriction",
+        docs: r#"**What it does:** Checks for use of File::read_to_end and File::read_to_string.
+
+**Why is this bad?** `fs::{read, read_to_string}` provide the same functionality when `buf` is empty with fewer imports and no intermediate values.
+See also: [fs::read docs](https://doc.rust-lang.org/std/fs/fn.read.html), [fs::read_to_string docs](https://doc.rust-lang.org/std/fs/fn.read_to_string.html)
+
+**Known problems:** None.
+
+**Example:**
+
+```rust,no_run
+# use std::io::Read;
+# use std::fs::File;
+let mut f = File::open("foo.txt").unwrap();
+let mut bytes = Vec::new();
+f.read_to_end(&mut bytes).unwrap();
+```
+Can be written more concisely as
+```rust,no_run
+# use std::fs;
+let mut bytes = fs::read("foo.txt").unwrap();
+```"#,
+    },
+    LintInfo {
+        lint: crate::unnamed_address::VTABLE_ADDRESS_COMPARISONS,
+        name: "vtable_address_comparisons",
+        group: "correctness",
+        docs: r"**What it does:** Checks for comparisons with an address of a trait vtable.
+
+**Why is this bad?** Comparing trait objects pointers compares an vtable addresses which
+are not guaranteed to be unique and could vary between different code generation units.
+Furthermore vtables for different types could have the same address after being merged
+together.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust,ignore
+let a: Rc<dyn Trait> = ...
+let b: Rc<dyn Trait> = ...
+if Rc::ptr_eq(&a, &b) {
+    ...
+}
+```",
+    },
+    LintInfo {
+        lint: crate::loops::WHILE_IMMUTABLE_CONDITION,
+        name: "while_immutable_condition",
+        group: "correctness",
+        docs: r#"**What it does:** Checks whether variables used within while loop condition
+can be (and are) mutated in the body.
+
+**Why is this bad?** If the condition is unchanged, entering the body of the loop
+will lead to an infinite loop.
+
+**Known problems:** If the `while`-loop is in a closure, the check for mutation of the
+condition variables in the body can cause false negatives. For example when only `Upvar` `a` is
+in the condition and only `Upvar` `b` gets mutated in the body, the lint will not trigger.
+
+**Example:**
+```rust
+let i = 0;
+while i > 10 {
+    println!("let me loop forever!");
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::loops::WHILE_LET_LOOP,
+        name: "while_let_loop",
+        group: "complexity",
+        docs: r"**What it does:** Detects `loop + match` combinations that are easier
+written as a `while let` loop.
+
+**Why is this bad?** The `while let` loop is usually shorter and more
+readable.
+
+**Known problems:** Sometimes the wrong binding is displayed ([#383](https://github.com/rust-lang/rust-clippy/issues/383)).
+
+**Example:**
+```rust,no_run
+# let y = Some(1);
+loop {
+    let x = match y {
+        Some(x) => x,
+        None => break,
+    };
+    // .. do something with x
+}
+// is easier written as
+while let Some(x) = y {
+    // .. do something with x
+};
+```",
+    },
+    LintInfo {
+        lint: crate::loops::WHILE_LET_ON_ITERATOR,
+        name: "while_let_on_iterator",
+        group: "style",
+        docs: r"**What it does:** Checks for `while let` expressions on iterators.
+
+**Why is this bad?** Readability. A simple `for` loop is shorter and conveys
+the intent better.
+
+**Known problems:** None.
+
+**Example:**
+```ignore
+while let Some(val) = iter() {
+    ..
+}
+```",
+    },
+    LintInfo {
+        lint: crate::wildcard_dependencies::WILDCARD_DEPENDENCIES,
+        name: "wildcard_dependencies",
+        group: "cargo",
+        docs: r#"**What it does:** Checks for wildcard dependencies in the `Cargo.toml`.
+
+**Why is this bad?** [As the edition guide says](https://rust-lang-nursery.github.io/edition-guide/rust-2018/cargo-and-crates-io/crates-io-disallows-wildcard-dependencies.html),
+it is highly unlikely that you work with any possible version of your dependency,
+and wildcard dependencies would cause unnecessary breakage in the ecosystem.
+
+**Known problems:** None.
+
+**Example:**
+
+```toml
+[dependencies]
+regex = "*"
+```"#,
+    },
+    LintInfo {
+        lint: crate::matches::WILDCARD_ENUM_MATCH_ARM,
+        name: "wildcard_enum_match_arm",
+        group: "restriction",
+        docs: r"**What it does:** Checks for wildcard enum matches using `_`.
+
+**Why is this bad?** New enum variants added by library updates can be missed.
+
+**Known problems:** Suggested replacements may be incorrect if guards exhaustively cover some
+variants, and also may not use correct path to enum if it's not present in the current scope.
+
+**Example:**
+```rust
+# enum Foo { A(usize), B(usize) }
+# let x = Foo::B(1);
+// Bad
+match x {
+    Foo::A(_) => {},
+    _ => {},
+}
+
+// Good
+match x {
+    Foo::A(_) => {},
+    Foo::B(_) => {},
+}
+```",
+    },
+    LintInfo {
+        lint: crate::wildcard_imports::WILDCARD_IMPORTS,
+        name: "wildcard_imports",
+        group: "pedantic",
+        docs: r#"**What it does:** Checks for wildcard imports `use _::*`.
+
+**Why is this bad?** wildcard imports can pollute the namespace. This is especially bad if
+you try to import something through a wildcard, that already has been imported by name from
+a different source:
+
+```rust,ignore
+use crate1::foo; // Imports a function named foo
+use crate2::*; // Has a function named foo
+
+foo(); // Calls crate1::foo
+```
+
+This can lead to confusing error messages at best and to unexpected behavior at worst.
+
+**Exceptions:**
+
+Wildcard imports are allowed from modules named `prelude`. Many crates (including the standard library)
+provide modules named "prelude" specifically designed for wildcard import.
+
+`use super::*` is allowed in test modules. This is defined as any module with "test" in the name.
+
+These exceptions can be disabled using the `warn-on-all-wildcard-imports` configuration flag.
+
+**Known problems:** If macros are imported through the wildcard, this macro is not included
+by the suggestion and has to be added by hand.
+
+Applying the suggestion when explicit imports of the things imported with a glob import
+exist, may result in `unused_imports` warnings.
+
+**Example:**
+
+```rust,ignore
+// Bad
+use crate1::*;
+
+foo();
+```
+
+```rust,ignore
+// Good
+use crate1::foo;
+
+foo();
+```"#,
+    },
+    LintInfo {
+        lint: crate::matches::WILDCARD_IN_OR_PATTERNS,
+        name: "wildcard_in_or_patterns",
+        group: "complexity",
+        docs: r#"**What it does:** Checks for wildcard pattern used with others patterns in same match arm.
+
+**Why is this bad?** Wildcard pattern already covers any other pattern as it will match anyway.
+It makes the code less readable, especially to spot wildcard pattern use in match arm.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+// Bad
+match "foo" {
+    "a" => {},
+    "bar" | _ => {},
+}
+
+// Good
+match "foo" {
+    "a" => {},
+    _ => {},
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::indexing_slicing::WITH_CAPACITY_INDEXING,
+        name: "with_capacity_indexing",
+        group: "correctness",
+        docs: r"**What it does:** Checks for indexing and slicing a local `Vec` created with
+`Vec::with_capacity(n)` before any elements were added to it, e.g. with `push` or
+`resize`.
+
+**Why is this bad?** `with_capacity` only allocates memory for `n` elements, the length of
+the vector is still 0. Indexing it always panics at runtime.
+
+**Known problems:** Only the statements of the block declaring the vector are followed, so
+a vector passed to another function or mutated in a branch isn't checked.
+
+**Example:**
+```no_run
+// Bad
+let mut v = Vec::with_capacity(10);
+for i in 0..10 {
+    v[i] = i;
+}
+
+// Good
+let mut v = Vec::with_capacity(10);
+for i in 0..10 {
+    v.push(i);
+}
+
+// Good
+let mut v = vec![0; 10];
+for i in 0..10 {
+    v[i] = i;
+}
+```",
+    },
+    LintInfo {
+        lint: crate::write::WRITE_LITERAL,
+        name: "write_literal",
+        group: "style",
+        docs: r#"**What it does:** This lint warns about the use of literals as `write!`/`writeln!` args.
+
+**Why is this bad?** Using literals as `writeln!` args is inefficient
+(c.f., https://github.com/matthiaskrgr/rust-str-bench) and unnecessary
+(i.e., just put the literal in the format string)
+
+**Known problems:** Will also warn with macro calls as arguments that expand to literals
+-- e.g., `writeln!(buf, "{}", env!("FOO"))`.
+
+**Example:**
+```rust
+# use std::fmt::Write;
+# let mut buf = String::new();
+// Bad
+writeln!(buf, "{}", "foo");
+
+// Good
+writeln!(buf, "foo");
+```"#,
+    },
+    LintInfo {
+        lint: crate::write::WRITE_WITH_NEWLINE,
+        name: "write_with_newline",
+        group: "style",
+        docs: r#"**What it does:** This lint warns when you use `write!()` with a format
+string that
+ends in a newline.
+
+**Why is this bad?** You should use `writeln!()` instead, which appends the
+newline.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# use std::fmt::Write;
+# let mut buf = String::new();
+# let name = "World";
+// Bad
+write!(buf, "Hello {}!\n", name);
+
+// Good
+writeln!(buf, "Hello {}!", name);
+```"#,
+    },
+    LintInfo {
+        lint: crate::write::WRITELN_EMPTY_STRING,
+        name: "writeln_empty_string",
+        group: "style",
+        docs: r#"**What it does:** This lint warns when you use `writeln!(buf, "")` to
+print a newline.
+
+**Why is this bad?** You should use `writeln!(buf)`, which is simpler.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# use std::fmt::Write;
+# let mut buf = String::new();
+// Bad
+writeln!(buf, "");
+
+// Good
+writeln!(buf);
+```"#,
+    },
+    LintInfo {
+        lint: crate::methods::WRONG_PUB_SELF_CONVENTION,
+        name: "wrong_pub_self_convention",
+        group: "restriction",
+        docs: r#"**What it does:** This is the same as
+[`wrong_self_convention`](#wrong_self_convention), but for public items.
+
+**Why is this bad?** See [`wrong_self_convention`](#wrong_self_convention).
+
+**Known problems:** Actually *renaming* the function may break clients if
+the function is part of the public interface. In that case, be mindful of
+the stability guarantees you've given your users.
+
+**Example:**
+```rust
+# struct X;
+impl<'a> X {
+    pub fn as_str(self) -> &'a str {
+        "foo"
+    }
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::methods::WRONG_SELF_CONVENTION,
+        name: "wrong_self_convention",
+        group: "style",
+        docs: r#"**What it does:** Checks for methods with certain name prefixes and which
+doesn't match how self is taken. The actual rules are:
+
+|Prefix |Postfix     |`self` taken           | `self` type  |
+|-------|------------|-----------------------|--------------|
+|`as_`  | none       |`&self` or `&mut self` | any          |
+|`from_`| none       | none                  | any          |
+|`into_`| none       |`self`                 | any          |
+|`is_`  | none       |`&self` or none        | any          |
+|`to_`  | `_mut`     |`&mut self`            | any          |
+|`to_`  | not `_mut` |`self`                 | `Copy`       |
+|`to_`  | not `_mut` |`&self`                | not `Copy`   |
+
+Note: Clippy doesn't trigger methods with `to_` prefix in:
+- Traits definition.
+Clippy can not tell if a type that implements a trait is `Copy` or not.
+- Traits implementation, when `&self` is taken.
+The method signature is controlled by the trait and often `&self` is required for all types that implement the trait
+(see e.g. the `std::string::ToString` trait).
+
+Please find more info here:
+https://rust-lang.github.io/api-guidelines/naming.html#ad-hoc-conversions-follow-as_-to_-into_-conventions-c-conv
+
+**Why is this bad?** Consistency breeds readability. If you follow the
+conventions, your users won't be surprised that they, e.g., need to supply a
+mutable reference to a `as_..` function.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+# struct X;
+impl X {
+    fn as_str(self) -> &'static str {
+        // ..
+# ""
+    }
+}
+```"#,
+    },
+    LintInfo {
+        lint: crate::transmute::WRONG_TRANSMUTE,
+        name: "wrong_transmute",
+        group: "correctness",
+        docs: r"**What it does:** Checks for transmutes that can't ever be correct on any
+architecture.
+
+**Why is this bad?** It's basically guaranteed to be undefined behaviour.
+
+**Known problems:** When accessing C, users might want to store pointer
+sized objects in `extradata` arguments to save an allocation.
+
+**Example:**
+```ignore
+let ptr: *const T = core::intrinsics::transmute('x')
+```",
+    },
+    LintInfo {
+        lint: crate::zero_div_zero::ZERO_DIVIDED_BY_ZERO,
+        name: "zero_divided_by_zero",
+        group: "complexity",
+        docs: r"**What it does:** Checks for `0.0 / 0.0`.
+
+**Why is this bad?** It's less readable than `f32::NAN` or `f64::NAN`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+// Bad
+let nan = 0.0f32 / 0.0;
+
+// Good
+let nan = f32::NAN;
+```",
+    },
+    LintInfo {
+        lint: crate::misc_early::ZERO_PREFIXED_LITERAL,
+        name: "zero_prefixed_literal",
+        group: "complexity",
+        docs: r#"**What it does:** Warns if an integral constant literal starts with `0`.
+
+**Why is this bad?** In some languages (including the infamous C language
+and most of its
+family), this marks an octal constant. In Rust however, this is a decimal
+constant. This could
+be confusing for both the writer and a reader of the constant.
+
+**Known problems:** None.
+
+**Example:**
+
+In Rust:
+```rust
+fn main() {
+    let a = 0123;
+    println!("{}", a);
+}
+```
+
+prints `123`, while in C:
+
+```c
+#include <stdio.h>
+
+int main() {
+    int a = 0123;
+    printf("%d\n", a);
+}
+```
+
+prints `83` (as `83 == 0o123` while `123 == 0o173`)."#,
+    },
+    LintInfo {
+        lint: crate::misc::ZERO_PTR,
+        name: "zero_ptr",
+        group: "style",
+        docs: r"**What it does:** Catch casts from `0` to some pointer type
+
+**Why is this bad?** This generally means `null` and is better expressed as
+{`std`, `core`}`::ptr::`{`null`, `null_mut`}.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+// Bad
+let a = 0 as *const u32;
+
+// Good
+let a = std::ptr::null::<u32>();
+```",
+    },
+    LintInfo {
+        lint: crate::zero_sized_map_values::ZERO_SIZED_MAP_VALUES,
+        name: "zero_sized_map_values",
+        group: "pedantic",
+        docs: r"**What it does:** Checks for maps with zero-sized value types anywhere in the code.
+
+**Why is this bad?** Since there is only a single value for a zero-sized type, a map
+containing zero sized values is effectively a set. Using a set in that case improves
+readability and communicates intent more clearly.
+
+**Known problems:**
+* A zero-sized type cannot be recovered later if it contains private fields.
+* This lints the signature of public items
+
+**Example:**
+
+```rust
+# use std::collections::HashMap;
+fn unique_words(text: &str) -> HashMap<&str, ()> {
+    todo!();
+}
+```
+Use instead:
+```rust
+# use std::collections::HashSet;
+fn unique_words(text: &str) -> HashSet<&str> {
+    todo!();
+}
+```",
+    },
+    LintInfo {
+        lint: crate::methods::ZST_OFFSET,
+        name: "zst_offset",
+        group: "correctness",
+        docs: r"**What it does:** Checks for `offset(_)`, `wrapping_`{`add`, `sub`}, etc. on raw pointers to
+zero-sized types
+
+**Why is this bad?** This is a no-op, and likely unintended
+
+**Known problems:** None
+
+**Example:**
+```rust
+unsafe { (&() as *const ()).offset(1) };
+```",
+    },
+    // end lint list, do not remove this comment, it’s used in `update_lints`
+];
@@ -0,0 +1,82 @@
+use super::CHARS_NTH_LOOP;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::visitors::LocalUsedVisitor;
+use if_chain::if_chain;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Expr, ExprKind, HirId, Pat, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::hir::map::Map;
+
+/// Checks for `s.chars().nth(i)` in the body of a loop, with `i` depending on the loop variable.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>, arg: &'tcx Expr<'_>, body: &'tcx Expr<'_>) {
+    let var = match pat.kind {
+        PatKind::Binding(_, canonical_id, _, _) => canonical_id,
+        _ => return,
+    };
+    let mut visitor = CharsNthVisitor { cx, var, found: vec![] };
+    visitor.visit_expr(body);
+
+    for (expr, chars_recv) in visitor.found {
+        span_lint_and_then(
+            cx,
+            CHARS_NTH_LOOP,
+            expr.span,
+            "calling `chars().nth()` with the loop variable walks the string on each iteration",
+            |diag| {
+                diag.span_note(
+                    arg.span,
+                    "the string is walked from its start for each item of this loop",
+                );
+                let recv = snippet(cx, chars_recv.span, "..");
+                diag.help(&format!(
+                    "consider collecting `{}.chars()` into a `Vec<char>` before the loop, \
+                    or looping over `{}.char_indices()`",
+                    recv, recv,
+                ));
+            },
+        );
+    }
+}
+
+struct CharsNthVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    var: HirId,
+    /// The `nth` calls, with the receivers of their `chars` calls.
+    found: Vec<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for CharsNthVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if_chain! {
+            if let ExprKind::MethodCall(nth, _, [chars, index], _) = expr.kind;
+            if nth.ident.name == sym!(nth);
+            if let ExprKind::MethodCall(chars_path, _, [chars_recv], _) = chars.kind;
+            if chars_path.ident.name == sym!(chars);
+            if !expr.span.from_expansion();
+            if is_str_method(self.cx, chars);
+            let mut used_visitor = LocalUsedVisitor::new(self.cx, self.var);
+            // `s[i].chars().nth(i)` walks a different string on each iteration
+            if used_visitor.check_expr(index) && !used_visitor.check_expr(chars_recv);
+            then {
+                self.found.push((expr, chars_recv));
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
+
+/// Whether the method called by `expr` is a method of `str`.
+fn is_str_method(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    cx.typeck_results()
+        .type_dependent_def_id(expr.hir_id)
+        .and_then(|method_id| cx.tcx.impl_of_method(method_id))
+        .map_or(false, |impl_id| Some(impl_id) == cx.tcx.lang_items().str_impl())
+}
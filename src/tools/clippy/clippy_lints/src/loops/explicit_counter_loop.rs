@@ -35,7 +35,8 @@ pub(super) fn check<'tcx>(
                 if let Some((name, initializer)) = initialize_visitor.get_result();
                 if is_integer_const(cx, initializer, 0);
                 then {
-                    let mut applicability = Applicability::MachineApplicable;
+                    // the increment of the counter in the loop body has to be removed by hand
+                    let mut applicability = Applicability::MaybeIncorrect;
 
                     let for_span = get_span_of_entire_for_loop(expr);
 
@@ -0,0 +1,166 @@
+use super::MANUAL_BINARY_SEARCH;
+use crate::consts::{constant_simple, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{path_to_local, path_to_local_id};
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId, PatKind, StmtKind};
+use rustc_lint::LateContext;
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+/// Checks for a `while lo < hi` loop computing the midpoint `mid` of `lo` and `hi`, indexing a
+/// slice with `mid` and moving `lo` or `hi` to `mid`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, cond: &'tcx Expr<'_>, body: &'tcx Expr<'_>, expr: &'tcx Expr<'_>) {
+    let (lo, hi) = match cond.kind {
+        ExprKind::Binary(op, lhs, rhs) => match op.node {
+            BinOpKind::Lt | BinOpKind::Le => (lhs, rhs),
+            BinOpKind::Gt | BinOpKind::Ge => (rhs, lhs),
+            _ => return,
+        },
+        _ => return,
+    };
+    let (lo, hi) = match (path_to_local(lo), path_to_local(hi)) {
+        (Some(lo), Some(hi)) if lo != hi => (lo, hi),
+        _ => return,
+    };
+    let block = match body.kind {
+        ExprKind::Block(block, _) => block,
+        _ => return,
+    };
+    let mid = block.stmts.iter().find_map(|stmt| match stmt.kind {
+        StmtKind::Local(local) => match (&local.pat.kind, local.init) {
+            (&PatKind::Binding(_, id, ..), Some(init)) if is_midpoint(cx, init, lo, hi) => Some(id),
+            _ => None,
+        },
+        _ => None,
+    });
+    let mid = match mid {
+        Some(mid) => mid,
+        None => return,
+    };
+
+    let mut visitor = BinarySearchVisitor {
+        cx,
+        lo,
+        hi,
+        mid,
+        moves_lo: false,
+        moves_hi: false,
+        indexed: None,
+    };
+    visitor.visit_expr(body);
+    let (indexed, slice) = match visitor.indexed {
+        Some(indexed) if visitor.moves_lo && visitor.moves_hi => indexed,
+        _ => return,
+    };
+
+    span_lint_and_then(
+        cx,
+        MANUAL_BINARY_SEARCH,
+        expr.span,
+        "this loop is a manual binary search",
+        |diag| {
+            diag.span_note(
+                indexed.span,
+                "the indexing is bounds-checked and may panic on every iteration",
+            );
+            diag.help(&format!(
+                "consider using `{0}.binary_search_by(..)`, or `{0}.partition_point(..)` to find an insertion point",
+                snippet(cx, slice.span, "..")
+            ));
+        },
+    );
+}
+
+/// Whether `expr` is the midpoint of the local variables `lo` and `hi`: `(lo + hi) / 2` or
+/// `lo + (hi - lo) / 2`, or the same with `>> 1` instead of `/ 2`.
+fn is_midpoint(cx: &LateContext<'_>, expr: &Expr<'_>, lo: HirId, hi: HirId) -> bool {
+    match expr.kind {
+        // `lo + (hi - lo) / 2`
+        ExprKind::Binary(op, base, half) if op.node == BinOpKind::Add && path_to_local_id(base, lo) => {
+            halved(cx, half).map_or(false, |diff| is_binop_of(diff, BinOpKind::Sub, hi, lo))
+        },
+        // `(lo + hi) / 2`
+        _ => halved(cx, expr).map_or(false, |sum| {
+            is_binop_of(sum, BinOpKind::Add, lo, hi) || is_binop_of(sum, BinOpKind::Add, hi, lo)
+        }),
+    }
+}
+
+/// Returns `x` if `expr` is `x / 2` or `x >> 1`.
+fn halved<'tcx>(cx: &LateContext<'_>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match expr.kind {
+        ExprKind::Binary(op, lhs, rhs) => match (op.node, constant_simple(cx, cx.typeck_results(), rhs)) {
+            (BinOpKind::Div, Some(Constant::Int(2))) | (BinOpKind::Shr, Some(Constant::Int(1))) => Some(lhs),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether `expr` is `a <op> b`, with `a` and `b` local variables.
+fn is_binop_of(expr: &Expr<'_>, kind: BinOpKind, a: HirId, b: HirId) -> bool {
+    matches!(
+        expr.kind,
+        ExprKind::Binary(op, lhs, rhs) if op.node == kind && path_to_local_id(lhs, a) && path_to_local_id(rhs, b)
+    )
+}
+
+/// Whether `expr` is `mid`, `mid + 1` or `mid - 1`.
+fn is_mid_offset(cx: &LateContext<'_>, expr: &Expr<'_>, mid: HirId) -> bool {
+    match expr.kind {
+        ExprKind::Binary(op, lhs, rhs) if matches!(op.node, BinOpKind::Add | BinOpKind::Sub) => {
+            path_to_local_id(lhs, mid) && constant_simple(cx, cx.typeck_results(), rhs) == Some(Constant::Int(1))
+        },
+        _ => path_to_local_id(expr, mid),
+    }
+}
+
+struct BinarySearchVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    lo: HirId,
+    hi: HirId,
+    mid: HirId,
+    /// Whether `lo` is set to `mid` or `mid + 1`.
+    moves_lo: bool,
+    /// Whether `hi` is set to `mid` or `mid - 1`.
+    moves_hi: bool,
+    /// The first indexing of a slice with `mid`, and the slice.
+    indexed: Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for BinarySearchVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            ExprKind::Assign(place, value, _) if is_mid_offset(self.cx, value, self.mid) => {
+                self.moves_lo |= path_to_local_id(place, self.lo);
+                self.moves_hi |= path_to_local_id(place, self.hi);
+            },
+            ExprKind::Index(slice, index)
+                if self.indexed.is_none()
+                    && path_to_local_id(index, self.mid)
+                    && is_slice_like(self.cx, slice)
+                    && !expr.span.from_expansion() =>
+            {
+                self.indexed = Some((expr, slice));
+            }
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Whether `expr` is a (reference to a) slice, array or `Vec`, which have `binary_search_by`.
+fn is_slice_like(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let ty = cx.typeck_results().expr_ty(expr).peel_refs();
+    matches!(ty.kind(), ty::Slice(_) | ty::Array(..)) || is_type_diagnostic_item(cx, ty, sym::vec_type)
+}
@@ -0,0 +1,179 @@
+use super::needless_range_loop::{is_declared_in_loop, is_len_call, VarVisitor};
+use super::MANUAL_ZIP;
+use clippy_utils::diagnostics::{multispan_sugg_with_applicability, span_lint_and_then};
+use clippy_utils::source::snippet;
+use clippy_utils::ty::{has_iter_method, is_copy};
+use clippy_utils::{contains_name, get_parent_expr, higher, is_integer_const, sugg};
+use rustc_ast::ast;
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::walk_expr;
+use rustc_hir::{Expr, ExprKind, Pat, PatKind};
+use rustc_lint::LateContext;
+use rustc_span::symbol::Symbol;
+
+/// Checks for looping over a range and indexing two or more sequences with the loop variable.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    arg: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+) {
+    let (start, end, limits) = match higher::range(arg) {
+        Some(higher::Range {
+            start: Some(start),
+            end,
+            limits,
+        }) => (start, end, limits),
+        _ => return,
+    };
+    let (canonical_id, ident) = match pat.kind {
+        PatKind::Binding(_, canonical_id, ident, _) => (canonical_id, ident),
+        _ => return,
+    };
+    let mut visitor = VarVisitor::new(cx, canonical_id);
+    walk_expr(&mut visitor, body);
+    if !visitor.indexed_indirectly.is_empty() || visitor.indexed_directly.len() < 2 {
+        return;
+    }
+
+    // the indexed variables, in the order they are first indexed
+    let mut indexed: Vec<Symbol> = Vec::new();
+    for (name, _) in &visitor.direct_indexes {
+        if !indexed.contains(name) {
+            indexed.push(*name);
+        }
+    }
+    // the iterator stops at the end of the sequence anyway
+    let take = end.filter(|end| !indexed.iter().any(|name| is_len_call(end, *name)));
+    for name in &indexed {
+        let (extent, ty) = visitor.indexed_directly[name];
+        if is_declared_in_loop(cx, pat, expr, extent)
+            || has_iter_method(cx, ty).is_none()
+            // the variable can't be borrowed by the iterator and used in the loop
+            || visitor.referenced.contains(name)
+            || (visitor.indexed_mut.contains(name)
+                && (contains_name(*name, start) || take.map_or(false, |end| contains_name(*name, end))))
+        {
+            return;
+        }
+    }
+
+    let iter = zipped_iter(cx, &visitor, &indexed, start, take, limits);
+
+    // the items are copied out of the sequences, unless they are borrowed or mutated
+    let item_names: Vec<String> = indexed.iter().map(|name| format!("{}_item", name)).collect();
+    let copies_items = visitor.indexed_mut.is_empty()
+        && item_names.iter().all(|item| !contains_name(Symbol::intern(item), body))
+        && visitor.direct_indexes.iter().all(|(_, index)| {
+            !index.span.from_expansion()
+                && is_copy(cx, cx.typeck_results().expr_ty(index))
+                && !matches!(
+                    get_parent_expr(cx, index),
+                    Some(Expr {
+                        kind: ExprKind::AddrOf(..),
+                        ..
+                    })
+                )
+        });
+    let pattern = items_pattern(&item_names, copies_items, visitor.nonindex.then(|| ident.name));
+
+    let names = indexed.iter().map(|name| format!("`{}`", name)).collect::<Vec<_>>();
+    let (last, rest) = names.split_last().expect("at least two variables are indexed");
+    span_lint_and_then(
+        cx,
+        MANUAL_ZIP,
+        expr.span,
+        &format!(
+            "the loop variable `{}` is used to index {} and {}",
+            ident.name,
+            rest.join(", "),
+            last
+        ),
+        |diag| {
+            let mut suggestion = vec![(pat.span, pattern), (arg.span, iter)];
+            if copies_items {
+                for (name, index) in &visitor.direct_indexes {
+                    suggestion.push((index.span, format!("{}_item", name)));
+                }
+                multispan_sugg_with_applicability(
+                    diag,
+                    "consider zipping the iterators",
+                    Applicability::MachineApplicable,
+                    suggestion,
+                );
+            } else {
+                multispan_sugg_with_applicability(
+                    diag,
+                    "consider zipping the iterators, and using the items instead of indexing",
+                    Applicability::Unspecified,
+                    suggestion,
+                );
+            }
+        },
+    );
+}
+
+/// Returns the zipped iterators over the `indexed` sequences, like
+/// `a.iter().zip(b.iter()).take(n)`.
+fn zipped_iter<'tcx>(
+    cx: &LateContext<'tcx>,
+    visitor: &VarVisitor<'_, 'tcx>,
+    indexed: &[Symbol],
+    start: &Expr<'_>,
+    take: Option<&Expr<'_>>,
+    limits: ast::RangeLimits,
+) -> String {
+    let mut iter = String::new();
+    for (i, name) in indexed.iter().enumerate() {
+        let method = if visitor.indexed_mut.contains(name) {
+            "iter_mut"
+        } else {
+            "iter"
+        };
+        if i == 0 {
+            iter = format!("{}.{}()", name, method);
+        } else {
+            iter = format!("{}.zip({}.{}())", iter, name, method);
+        }
+    }
+    if visitor.nonindex {
+        // enumerating before `take` and `skip` keeps the values of the loop variable
+        iter.push_str(".enumerate()");
+    }
+    if let Some(end) = take {
+        match limits {
+            ast::RangeLimits::Closed => {
+                let end = sugg::Sugg::hir(cx, end, "<count>");
+                iter = format!("{}.take({})", iter, end + sugg::ONE);
+            },
+            ast::RangeLimits::HalfOpen => iter = format!("{}.take({})", iter, snippet(cx, end.span, "..")),
+        }
+    }
+    if !is_integer_const(cx, start, 0) {
+        iter = format!("{}.skip({})", iter, snippet(cx, start.span, ".."));
+    }
+    iter
+}
+
+/// Returns the pattern binding the items of the zipped iterators, preceded by the loop variable
+/// `var` if the iterator is enumerated.
+fn items_pattern(item_names: &[String], copies_items: bool, var: Option<Symbol>) -> String {
+    let mut pattern = String::new();
+    for (i, name) in item_names.iter().enumerate() {
+        let binding = if copies_items {
+            format!("&{}", name)
+        } else {
+            name.clone()
+        };
+        if i == 0 {
+            pattern = binding;
+        } else {
+            pattern = format!("({}, {})", pattern, binding);
+        }
+    }
+    if let Some(var) = var {
+        pattern = format!("({}, {})", var, pattern);
+    }
+    pattern
+}
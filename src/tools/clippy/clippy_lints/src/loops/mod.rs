@@ -1,3 +1,4 @@
+mod chars_nth_loop;
 mod empty_loop;
 mod explicit_counter_loop;
 mod explicit_into_iter_loop;
@@ -5,8 +6,10 @@
 mod for_kv_map;
 mod for_loops_over_fallibles;
 mod iter_next_loop;
+mod manual_binary_search;
 mod manual_flatten;
 mod manual_memcpy;
+mod manual_zip;
 mod mut_range_bound;
 mod needless_collect;
 mod needless_range_loop;
@@ -517,9 +520,116 @@
     "for loops over `Option`s or `Result`s with a single expression can be simplified"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for looping over a range of indices and indexing two or more
+    /// sequences with the loop variable, like `for i in 0..n { a[i] + b[i] }`.
+    ///
+    /// **Why is this bad?** Zipping the iterators of the sequences is more readable, and avoids
+    /// the bounds checks and the panics `indexing_slicing` warns about.
+    ///
+    /// **Known problems:** The zipped iterator stops at the end of the shortest sequence, where
+    /// the indexing would panic.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let a = vec![1, 2, 3];
+    /// let b = vec![4, 5, 6];
+    /// let mut sum = 0;
+    /// for i in 0..a.len() {
+    ///     sum += a[i] * b[i];
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let a = vec![1, 2, 3];
+    /// let b = vec![4, 5, 6];
+    /// let mut sum = 0;
+    /// for (&a_item, &b_item) in a.iter().zip(b.iter()) {
+    ///     sum += a_item * b_item;
+    /// }
+    /// ```
+    pub MANUAL_ZIP,
+    pedantic,
+    "for-looping over a range of indices to index several sequences, where `iter().zip()` could be used"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `s.chars().nth(i)` in the body of a loop, where `i` depends
+    /// on the loop variable.
+    ///
+    /// **Why is this bad?** `chars().nth(i)` decodes the string from its start up to the `i`th
+    /// character, so calling it on each iteration takes quadratic time. Strings can't be indexed
+    /// by characters like in some other languages.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let s = "hello";
+    /// for i in 0..s.chars().count() {
+    ///     let c = s.chars().nth(i).unwrap();
+    ///     println!("{}: {}", i, c);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let s = "hello";
+    /// for (i, c) in s.chars().enumerate() {
+    ///     println!("{}: {}", i, c);
+    /// }
+    /// // or, if the characters are accessed in any order
+    /// let chars: Vec<char> = s.chars().collect();
+    /// for i in 0..chars.len() {
+    ///     println!("{}: {}", i, chars[i]);
+    /// }
+    /// ```
+    pub CHARS_NTH_LOOP,
+    perf,
+    "calling `chars().nth()` with the loop variable, which walks the string on each iteration"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for hand-written binary searches: `while lo < hi` loops computing
+    /// the midpoint `mid` of `lo` and `hi`, indexing a slice with `mid` and moving `lo` or `hi`
+    /// to `mid`.
+    ///
+    /// **Why is this bad?** `binary_search_by` (or `partition_point`) is clearer, and doesn't
+    /// index the slice. The manual version is easy to get wrong: `(lo + hi) / 2` can overflow,
+    /// and an off-by-one in the bounds loops forever or indexes past the end of the slice, which
+    /// panics.
+    ///
+    /// **Known problems:** Only loops declaring `mid` in their body are recognized.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let v = vec![1, 3, 5, 7];
+    /// # let target = 5;
+    /// let mut lo = 0;
+    /// let mut hi = v.len();
+    /// while lo < hi {
+    ///     let mid = lo + (hi - lo) / 2;
+    ///     if v[mid] < target {
+    ///         lo = mid + 1;
+    ///     } else {
+    ///         hi = mid;
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let v = vec![1, 3, 5, 7];
+    /// # let target = 5;
+    /// let lo = v.partition_point(|&x| x < target);
+    /// ```
+    pub MANUAL_BINARY_SEARCH,
+    pedantic,
+    "a `while` loop implementing a binary search, where `binary_search_by` could be used"
+}
+
 declare_lint_pass!(Loops => [
     MANUAL_MEMCPY,
     MANUAL_FLATTEN,
+    MANUAL_ZIP,
     NEEDLESS_RANGE_LOOP,
     EXPLICIT_ITER_LOOP,
     EXPLICIT_INTO_ITER_LOOP,
@@ -536,6 +646,8 @@
     WHILE_IMMUTABLE_CONDITION,
     SAME_ITEM_PUSH,
     SINGLE_ELEMENT_LOOP,
+    CHARS_NTH_LOOP,
+    MANUAL_BINARY_SEARCH,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Loops {
@@ -572,6 +684,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
 
         if let Some((cond, body)) = higher::while_loop(expr) {
             while_immutable_condition::check(cx, cond, body);
+            manual_binary_search::check(cx, cond, body, expr);
         }
 
         needless_collect::check(expr, cx);
@@ -589,6 +702,7 @@ fn check_for_loop<'tcx>(
     let is_manual_memcpy_triggered = manual_memcpy::check(cx, pat, arg, body, expr);
     if !is_manual_memcpy_triggered {
         needless_range_loop::check(cx, pat, arg, body, expr);
+        manual_zip::check(cx, pat, arg, body, expr);
         explicit_counter_loop::check(cx, pat, arg, body, expr);
     }
     check_for_loop_arg(cx, pat, arg, expr);
@@ -597,6 +711,7 @@ fn check_for_loop<'tcx>(
     single_element_loop::check(cx, pat, arg, body, expr);
     same_item_push::check(cx, pat, arg, body, expr);
     manual_flatten::check(cx, pat, arg, body, span);
+    chars_nth_loop::check(cx, pat, arg, body);
 }
 
 fn check_for_loop_arg(cx: &LateContext<'_>, pat: &Pat<'_>, arg: &Expr<'_>, expr: &Expr<'_>) {
@@ -38,16 +38,7 @@ pub(super) fn check<'tcx>(
     {
         // the var must be a single name
         if let PatKind::Binding(_, canonical_id, ident, _) = pat.kind {
-            let mut visitor = VarVisitor {
-                cx,
-                var: canonical_id,
-                indexed_mut: FxHashSet::default(),
-                indexed_indirectly: FxHashMap::default(),
-                indexed_directly: FxHashMap::default(),
-                referenced: FxHashSet::default(),
-                nonindex: false,
-                prefer_mutable: false,
-            };
+            let mut visitor = VarVisitor::new(cx, canonical_id);
             walk_expr(&mut visitor, body);
 
             // linting condition: we only indexed one variable, and indexed it directly
@@ -59,14 +50,8 @@ pub(super) fn check<'tcx>(
                     .expect("already checked that we have exactly 1 element");
 
                 // ensure that the indexed variable was declared before the loop, see #601
-                if let Some(indexed_extent) = indexed_extent {
-                    let parent_id = cx.tcx.hir().get_parent_item(expr.hir_id);
-                    let parent_def_id = cx.tcx.hir().local_def_id(parent_id);
-                    let region_scope_tree = cx.tcx.region_scope_tree(parent_def_id);
-                    let pat_extent = region_scope_tree.var_scope(pat.hir_id.local_id);
-                    if region_scope_tree.is_subscope_of(indexed_extent, pat_extent) {
-                        return;
-                    }
+                if is_declared_in_loop(cx, pat, expr, indexed_extent) {
+                    return;
                 }
 
                 // don't lint if the container that is indexed does not have .iter() method
@@ -188,7 +173,24 @@ pub(super) fn check<'tcx>(
     }
 }
 
-fn is_len_call(expr: &Expr<'_>, var: Symbol) -> bool {
+/// Whether the variable with the scope `extent` is declared in the `for` loop `expr` with the
+/// pattern `pat`. Statics and constants have no scope.
+pub(super) fn is_declared_in_loop(
+    cx: &LateContext<'_>,
+    pat: &Pat<'_>,
+    expr: &Expr<'_>,
+    extent: Option<region::Scope>,
+) -> bool {
+    extent.map_or(false, |extent| {
+        let parent_id = cx.tcx.hir().get_parent_item(expr.hir_id);
+        let parent_def_id = cx.tcx.hir().local_def_id(parent_id);
+        let region_scope_tree = cx.tcx.region_scope_tree(parent_def_id);
+        let pat_extent = region_scope_tree.var_scope(pat.hir_id.local_id);
+        region_scope_tree.is_subscope_of(extent, pat_extent)
+    })
+}
+
+pub(super) fn is_len_call(expr: &Expr<'_>, var: Symbol) -> bool {
     if_chain! {
         if let ExprKind::MethodCall(method, _, len_args, _) = expr.kind;
         if len_args.len() == 1;
@@ -226,31 +228,47 @@ fn is_end_eq_array_len<'tcx>(
     false
 }
 
-struct VarVisitor<'a, 'tcx> {
+pub(super) struct VarVisitor<'a, 'tcx> {
     /// context reference
     cx: &'a LateContext<'tcx>,
     /// var name to look for as index
     var: HirId,
     /// indexed variables that are used mutably
-    indexed_mut: FxHashSet<Symbol>,
+    pub(super) indexed_mut: FxHashSet<Symbol>,
     /// indirectly indexed variables (`v[(i + 4) % N]`), the extend is `None` for global
-    indexed_indirectly: FxHashMap<Symbol, Option<region::Scope>>,
+    pub(super) indexed_indirectly: FxHashMap<Symbol, Option<region::Scope>>,
     /// subset of `indexed` of vars that are indexed directly: `v[i]`
     /// this will not contain cases like `v[calc_index(i)]` or `v[(i + 4) % N]`
-    indexed_directly: FxHashMap<Symbol, (Option<region::Scope>, Ty<'tcx>)>,
+    pub(super) indexed_directly: FxHashMap<Symbol, (Option<region::Scope>, Ty<'tcx>)>,
+    /// the direct index expressions, in the order they appear, with the indexed variable
+    pub(super) direct_indexes: Vec<(Symbol, &'tcx Expr<'tcx>)>,
     /// Any names that are used outside an index operation.
     /// Used to detect things like `&mut vec` used together with `vec[i]`
-    referenced: FxHashSet<Symbol>,
+    pub(super) referenced: FxHashSet<Symbol>,
     /// has the loop variable been used in expressions other than the index of
     /// an index op?
-    nonindex: bool,
+    pub(super) nonindex: bool,
     /// Whether we are inside the `$` in `&mut $` or `$ = foo` or `$.bar`, where bar
     /// takes `&mut self`
     prefer_mutable: bool,
 }
 
 impl<'a, 'tcx> VarVisitor<'a, 'tcx> {
-    fn check(&mut self, idx: &'tcx Expr<'_>, seqexpr: &'tcx Expr<'_>, expr: &'tcx Expr<'_>) -> bool {
+    pub(super) fn new(cx: &'a LateContext<'tcx>, var: HirId) -> Self {
+        Self {
+            cx,
+            var,
+            indexed_mut: FxHashSet::default(),
+            indexed_indirectly: FxHashMap::default(),
+            indexed_directly: FxHashMap::default(),
+            direct_indexes: Vec::new(),
+            referenced: FxHashSet::default(),
+            nonindex: false,
+            prefer_mutable: false,
+        }
+    }
+
+    fn check(&mut self, idx: &'tcx Expr<'_>, seqexpr: &'tcx Expr<'_>, expr: &'tcx Expr<'tcx>) -> bool {
         if_chain! {
             // the indexed container is referenced by a name
             if let ExprKind::Path(ref seqpath) = seqexpr.kind;
@@ -277,6 +295,7 @@ fn check(&mut self, idx: &'tcx Expr<'_>, seqexpr: &'tcx Expr<'_>, expr: &'tcx Ex
                             self.indexed_indirectly.insert(seqvar.segments[0].ident.name, Some(extent));
                         }
                         if index_used_directly {
+                            self.direct_indexes.push((seqvar.segments[0].ident.name, expr));
                             self.indexed_directly.insert(
                                 seqvar.segments[0].ident.name,
                                 (Some(extent), self.cx.typeck_results().node_type(seqexpr.hir_id)),
@@ -289,6 +308,7 @@ fn check(&mut self, idx: &'tcx Expr<'_>, seqexpr: &'tcx Expr<'_>, expr: &'tcx Ex
                             self.indexed_indirectly.insert(seqvar.segments[0].ident.name, None);
                         }
                         if index_used_directly {
+                            self.direct_indexes.push((seqvar.segments[0].ident.name, expr));
                             self.indexed_directly.insert(
                                 seqvar.segments[0].ident.name,
                                 (None, self.cx.typeck_results().node_type(seqexpr.hir_id)),
@@ -307,7 +327,7 @@ fn check(&mut self, idx: &'tcx Expr<'_>, seqexpr: &'tcx Expr<'_>, expr: &'tcx Ex
 impl<'a, 'tcx> Visitor<'tcx> for VarVisitor<'a, 'tcx> {
     type Map = Map<'tcx>;
 
-    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
         if_chain! {
             // a range index op
             if let ExprKind::MethodCall(meth, _, args, _) = expr.kind;
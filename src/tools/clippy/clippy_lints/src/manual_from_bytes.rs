@@ -0,0 +1,209 @@
+//! lint on integers assembled from bytes by indexing, like `(buf[0] as u32) << 8 | buf[1] as u32`
+
+use crate::utils::known_len::const_usize;
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{is_diag_trait_item, SpanlessEq};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty, UintTy};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for integers assembled from consecutive bytes of a slice, array
+    /// or `Vec` by indexing, shifting and combining them with `|` or `+`, like
+    /// `(buf[0] as u32) << 24 | (buf[1] as u32) << 16 | (buf[2] as u32) << 8 | buf[3] as u32`.
+    ///
+    /// **Why is this bad?** Each of the indexes can panic, and a wrong shift or index is hard to
+    /// spot. `from_be_bytes` and `from_le_bytes` name the byte order and convert the bytes at once,
+    /// after a single check of their number.
+    ///
+    /// **Known problems:** Only constant indexes are linted. The suggestion for a slice uses `?` on
+    /// `try_into()`, which needs `std::convert::TryInto` in scope before the 2021 edition, and an
+    /// error type of the function that `TryFromSliceError` converts into.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// // Bad
+    /// let n = (buf[0] as u32) << 24 | (buf[1] as u32) << 16 | (buf[2] as u32) << 8 | buf[3] as u32;
+    ///
+    /// // Good
+    /// let n = u32::from_be_bytes(buf[..4].try_into()?);
+    /// ```
+    pub MANUAL_FROM_BYTES,
+    pedantic,
+    "assembling an integer from bytes by indexing instead of using `from_be_bytes` or `from_le_bytes`"
+}
+
+declare_lint_pass!(ManualFromBytes => [MANUAL_FROM_BYTES]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualFromBytes {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if expr.span.from_expansion() || !is_combination(expr) {
+            return;
+        }
+        let ty = cx.typeck_results().expr_ty(expr);
+        let bits = match ty.kind() {
+            ty::Uint(uint_ty) => uint_ty.bit_width(),
+            ty::Int(int_ty) => int_ty.bit_width(),
+            _ => None,
+        };
+        let len = match bits {
+            Some(bits) => bits / 8,
+            None => return,
+        };
+        let mut terms = Vec::new();
+        collect_terms(expr, &mut terms);
+        if terms.len() as u64 != len {
+            return;
+        }
+        let bytes = match terms
+            .into_iter()
+            .map(|term| byte(cx, term, ty))
+            .collect::<Option<Vec<_>>>()
+        {
+            Some(bytes) => bytes,
+            None => return,
+        };
+        if let Some((buf, start, endianness)) = byte_order(cx, &bytes) {
+            lint(cx, expr, ty, buf, start, len, endianness);
+        }
+    }
+}
+
+/// A byte of the assembled integer: `buf[index]`, converted to the integer and shifted left by
+/// `shift` bits.
+struct Byte<'tcx> {
+    buf: &'tcx Expr<'tcx>,
+    index: u128,
+    shift: u128,
+}
+
+/// Whether `expr` is a `|` or `+` operation, which combine the bytes.
+fn is_combination(expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Binary(op, _, _) if matches!(op.node, BinOpKind::BitOr | BinOpKind::Add))
+}
+
+/// Collects the operands of the `|` and `+` operations that `expr` is made of.
+fn collect_terms<'tcx>(expr: &'tcx Expr<'tcx>, terms: &mut Vec<&'tcx Expr<'tcx>>) {
+    match expr.kind {
+        ExprKind::Binary(_, left, right) if is_combination(expr) => {
+            collect_terms(left, terms);
+            collect_terms(right, terms);
+        },
+        _ => terms.push(expr),
+    }
+}
+
+/// Returns the byte `term` puts into the integer of type `ty`, if it is `buf[index]` converted to
+/// `ty` with `as` or `From`, shifted by a constant number of bits or not.
+fn byte<'tcx>(cx: &LateContext<'tcx>, term: &'tcx Expr<'tcx>, ty: Ty<'tcx>) -> Option<Byte<'tcx>> {
+    let (value, shift) = match term.kind {
+        ExprKind::Binary(op, value, shift) if op.node == BinOpKind::Shl => (value, const_usize(cx, shift)?),
+        _ => (term, 0),
+    };
+    let byte = match value.kind {
+        ExprKind::Cast(byte, _) => byte,
+        ExprKind::Call(func, [byte]) if is_from(cx, func) => byte,
+        _ => return None,
+    };
+    if_chain! {
+        if !term.span.from_expansion();
+        if cx.typeck_results().expr_ty(value) == ty;
+        if let ty::Uint(UintTy::U8) = cx.typeck_results().expr_ty(byte).kind();
+        if let ExprKind::Index(buf, index) = byte.kind;
+        then {
+            Some(Byte {
+                buf,
+                index: const_usize(cx, index)?,
+                shift,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Whether `func` is a path to `From::from`, like `u32::from`.
+fn is_from(cx: &LateContext<'_>, func: &Expr<'_>) -> bool {
+    if let ExprKind::Path(ref qpath @ QPath::TypeRelative(..)) = func.kind {
+        if let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id() {
+            return is_diag_trait_item(cx, def_id, sym::from_trait);
+        }
+    }
+    false
+}
+
+/// Returns the indexed bytes, the index of the first one and the byte order if `bytes` are the
+/// consecutive bytes of the same buffer, each shifted to its place in this order.
+fn byte_order<'tcx>(cx: &LateContext<'tcx>, bytes: &[Byte<'tcx>]) -> Option<(&'tcx Expr<'tcx>, u128, &'static str)> {
+    let buf = bytes.first()?.buf;
+    let start = bytes.iter().map(|byte| byte.index).min()?;
+    let last = bytes.len() as u128 - 1;
+    let mut indexes: Vec<_> = bytes.iter().map(|byte| byte.index - start).collect();
+    indexes.sort_unstable();
+    indexes.dedup();
+    if indexes.len() != bytes.len()
+        || indexes.last() != Some(&last)
+        || !bytes.iter().all(|byte| SpanlessEq::new(cx).eq_expr(byte.buf, buf))
+    {
+        return None;
+    }
+    if bytes.iter().all(|byte| byte.shift == 8 * (last - (byte.index - start))) {
+        Some((buf, start, "be"))
+    } else if bytes.iter().all(|byte| byte.shift == 8 * (byte.index - start)) {
+        Some((buf, start, "le"))
+    } else {
+        None
+    }
+}
+
+/// Emits the lint at `expr`, suggesting to convert the `len` bytes of `buf` from `start` with
+/// `from_be_bytes` or `from_le_bytes`.
+fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    ty: Ty<'tcx>,
+    buf: &Expr<'_>,
+    start: u128,
+    len: u64,
+    endianness: &str,
+) {
+    let buf_ty = cx.typeck_results().expr_ty(buf);
+    let whole_array = |ty: Ty<'tcx>| matches!(ty.kind(), ty::Array(_, array_len) if array_len.try_eval_usize(cx.tcx, cx.param_env) == Some(len));
+    let (bytes, applicability) = if start == 0 && whole_array(buf_ty) {
+        (Sugg::hir(cx, buf, "..").to_string(), Applicability::MachineApplicable)
+    } else if start == 0 && matches!(buf_ty.kind(), ty::Ref(_, inner, _) if whole_array(inner)) {
+        (
+            Sugg::hir(cx, buf, "..").deref().to_string(),
+            Applicability::MachineApplicable,
+        )
+    } else {
+        let range = if start == 0 {
+            format!("..{}", len)
+        } else {
+            format!("{}..{}", start, u128::from(len) + start)
+        };
+        (
+            format!("{}[{}].try_into()?", Sugg::hir(cx, buf, "..").maybe_par(), range),
+            Applicability::MaybeIncorrect,
+        )
+    };
+    let order = if endianness == "be" {
+        "big-endian"
+    } else {
+        "little-endian"
+    };
+    span_lint_and_sugg(
+        cx,
+        MANUAL_FROM_BYTES,
+        expr.span,
+        &format!("assembling a `{}` from {} bytes by indexing", ty, order),
+        &format!("use `{}::from_{}_bytes`", ty, endianness),
+        format!("{}::from_{}_bytes({})", ty, endianness, bytes),
+        applicability,
+    );
+}
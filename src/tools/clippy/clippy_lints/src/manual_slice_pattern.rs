@@ -0,0 +1,173 @@
+//! lint on destructuring a prefix of a slice by indexing it element by element
+
+use crate::utils::known_len::const_usize;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::{snippet_indent, snippet_with_applicability};
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{path_to_local, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::{Block, Expr, ExprKind, Pat, PatKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+use rustc_span::symbol::Ident;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for consecutive `let` statements binding the first elements of a
+    /// slice or `Vec` with constant indexes, like `let a = x[0]; let b = x[1];`.
+    ///
+    /// **Why is this bad?** Each indexing checks the length of the slice again and may panic. A
+    /// slice pattern checks the length once, and `if let` can handle a shorter slice without
+    /// panicking.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # let x = vec![1, 2, 3];
+    /// let a = x[0];
+    /// let b = x[1];
+    /// let c = x[2];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = vec![1, 2, 3];
+    /// if let [a, b, c, ..] = x[..] {
+    ///     // ..
+    /// }
+    /// ```
+    pub MANUAL_SLICE_PATTERN,
+    pedantic,
+    "binding the first elements of a slice with constant indexes, where a slice pattern could be used"
+}
+
+declare_lint_pass!(ManualSlicePattern => [MANUAL_SLICE_PATTERN]);
+
+/// A `let` statement binding the element of a slice at a constant index, like `let a = x[0];`.
+struct PrefixIndex<'tcx> {
+    stmt: &'tcx Stmt<'tcx>,
+    pat: &'tcx Pat<'tcx>,
+    ident: Ident,
+    receiver: &'tcx Expr<'tcx>,
+    index: u128,
+}
+
+impl<'tcx> LateLintPass<'tcx> for ManualSlicePattern {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
+        let mut start = 0;
+        while start < block.stmts.len() {
+            let mut run: Vec<PrefixIndex<'tcx>> = Vec::new();
+            for stmt in &block.stmts[start..] {
+                match prefix_index(cx, stmt) {
+                    Some(item)
+                        if item.index == run.len() as u128
+                            && run
+                                .first()
+                                .map_or(true, |first| SpanlessEq::new(cx).eq_expr(first.receiver, item.receiver)) =>
+                    {
+                        run.push(item);
+                    }
+                    _ => break,
+                }
+            }
+            if run.len() >= 2 {
+                lint(cx, &run);
+                start += run.len();
+            } else {
+                start += 1;
+            }
+        }
+    }
+}
+
+/// Returns the parts of `stmt` if it's a `let` statement binding the element of a slice or `Vec`
+/// at a constant index to a name.
+fn prefix_index<'tcx>(cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'tcx>) -> Option<PrefixIndex<'tcx>> {
+    let local = match stmt.kind {
+        StmtKind::Local(local) if local.ty.is_none() && !stmt.span.from_expansion() => local,
+        _ => return None,
+    };
+    let ident = match local.pat.kind {
+        PatKind::Binding(_, _, ident, None) => ident,
+        _ => return None,
+    };
+    let (receiver, index) = match local.init?.kind {
+        ExprKind::Index(receiver, index) => (receiver, index),
+        _ => return None,
+    };
+    let receiver_ty = cx.typeck_results().expr_ty(receiver).peel_refs();
+    if !(matches!(receiver_ty.kind(), ty::Slice(_)) || is_type_diagnostic_item(cx, receiver_ty, sym::vec_type))
+        || !is_place(receiver)
+    {
+        return None;
+    }
+    Some(PrefixIndex {
+        stmt,
+        pat: local.pat,
+        ident,
+        receiver,
+        index: const_usize(cx, index)?,
+    })
+}
+
+/// Whether `expr` is a local variable or a field of one, like `x` or `self.parts`.
+fn is_place(expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Field(base, _) => is_place(base),
+        _ => path_to_local(expr).is_some(),
+    }
+}
+
+fn lint(cx: &LateContext<'_>, run: &[PrefixIndex<'_>]) {
+    let first = &run[0];
+    let last = run.last().unwrap();
+    let span = first.stmt.span.to(last.stmt.span);
+    let mut applicability = if path_to_local(first.receiver).is_some() {
+        Applicability::MachineApplicable
+    } else {
+        Applicability::MaybeIncorrect
+    };
+    let receiver = snippet_with_applicability(cx, first.receiver.span, "..", &mut applicability);
+    span_lint_and_then(
+        cx,
+        MANUAL_SLICE_PATTERN,
+        span,
+        &format!("binding the first {} elements of `{}` by indexing", run.len(), receiver),
+        |diag| {
+            let pats = run
+                .iter()
+                .map(|item| snippet_with_applicability(cx, item.pat.span, "..", &mut applicability))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let names = run
+                .iter()
+                .map(|item| item.ident.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let indent = snippet_indent(cx, first.stmt.span).unwrap_or_default();
+            let sugg = format!(
+                "let ({pats}) = match {recv}[..] {{\n\
+                {indent}    [{names}, ..] => ({names}),\n\
+                {indent}    _ => panic!(\"`{recv}` has fewer than {len} elements\"),\n\
+                {indent}}};",
+                pats = pats,
+                recv = receiver,
+                names = names,
+                indent = indent,
+                len = run.len(),
+            );
+            diag.span_suggestion(
+                span,
+                "consider destructuring it with a slice pattern, which checks the length once",
+                sugg,
+                applicability,
+            );
+            diag.help(&format!(
+                "or handle a shorter `{}` with `if let [{}, ..] = {}[..]`",
+                receiver, names, receiver
+            ));
+        },
+    );
+}
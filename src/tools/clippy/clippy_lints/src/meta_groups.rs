@@ -0,0 +1,101 @@
+//! The lint groups that combine lints from different categories, like `clippy::panic_free`.
+//!
+//! Unlike the category groups, which `update_lints` generates from the lint declarations, these
+//! are declared by hand in `META_GROUPS`. Adding a lint to one of them doesn't change its category
+//! or default level.
+
+use crate::{
+    arithmetic, casts, chained_indexing, fallible_impl_from, ffi_unchecked_index, indexing_slicing, integer_division,
+    manual_from_bytes, methods, modulo_arithmetic, mutable_debug_assertion, overflow_check_conditional,
+    panic_in_result_fn, panic_unimplemented, panicking_index_impl, reachable_panic, split_indexing, unchecked_division,
+    unchecked_env_args, unwrap_in_result, unwrap_indexing, unwrapped_index_conversion,
+};
+use rustc_lint::{Lint, LintId, LintStore};
+
+/// A lint group combining lints from different categories.
+pub struct MetaGroup {
+    /// The name of the group, including the `clippy::` prefix.
+    pub name: &'static str,
+    pub lints: &'static [&'static &'static Lint],
+}
+
+#[rustfmt::skip]
+pub static META_GROUPS: &[MetaGroup] = &[
+    // integer overflow, division by zero, lossy casts and arithmetic in indexes
+    MetaGroup {
+        name: "clippy::arithmetic_safety",
+        lints: &[
+            &arithmetic::INTEGER_ARITHMETIC,
+            &casts::CAST_POSSIBLE_TRUNCATION,
+            &casts::CAST_POSSIBLE_WRAP,
+            &casts::CAST_PRECISION_LOSS,
+            &casts::CAST_SIGN_LOSS,
+            &indexing_slicing::INDEX_UNDERFLOW,
+            &indexing_slicing::LOOP_INDEX_OFFSET,
+            &indexing_slicing::SIGNED_INDEX_CAST,
+            &integer_division::INTEGER_DIVISION,
+            &modulo_arithmetic::MODULO_ARITHMETIC,
+            &overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL,
+            &unchecked_division::UNCHECKED_DIVISION,
+            &unwrapped_index_conversion::UNWRAPPED_INDEX_CONVERSION,
+        ],
+    },
+    // code that can panic
+    MetaGroup {
+        name: "clippy::panic_free",
+        lints: &[
+            &arithmetic::INTEGER_ARITHMETIC,
+            &chained_indexing::CHAINED_INDEXING,
+            &fallible_impl_from::FALLIBLE_IMPL_FROM,
+            &ffi_unchecked_index::FFI_UNCHECKED_INDEX,
+            &indexing_slicing::COLLECT_THEN_INDEX,
+            &indexing_slicing::CONSTRUCT_THEN_INDEX,
+            &indexing_slicing::CONST_FN_INDEXING,
+            &indexing_slicing::INCLUSIVE_RANGE_OUT_OF_BOUNDS,
+            &indexing_slicing::INDEXING_SLICING,
+            &indexing_slicing::INDEX_MAP,
+            &indexing_slicing::INDEX_UNDERFLOW,
+            &indexing_slicing::LOOP_INDEX_OFFSET,
+            &indexing_slicing::MATCHED_LEN_OUT_OF_BOUNDS,
+            &indexing_slicing::OUT_OF_BOUNDS_INDEXING,
+            &indexing_slicing::REVERSED_SLICE_RANGE,
+            &indexing_slicing::SIGNED_INDEX_CAST,
+            &indexing_slicing::SIZE_OF_OUT_OF_BOUNDS,
+            &indexing_slicing::STRING_SLICE,
+            &indexing_slicing::WITH_CAPACITY_INDEXING,
+            &manual_from_bytes::MANUAL_FROM_BYTES,
+            &methods::EXPECT_USED,
+            &methods::GET_UNWRAP,
+            &methods::ITERATOR_STEP_BY_ZERO,
+            &methods::METHOD_INDEX_OUT_OF_BOUNDS,
+            &methods::NONZERO_NEW_UNWRAP,
+            &methods::SLICE_LENGTH_MISMATCH,
+            &methods::UNWRAPPED_FIRST_OR_LAST,
+            &methods::UNWRAPPED_GET,
+            &methods::UNWRAP_USED,
+            &mutable_debug_assertion::DEBUG_ASSERT_INDEXING,
+            &panic_in_result_fn::PANIC_IN_RESULT_FN,
+            &panic_unimplemented::PANIC,
+            &panic_unimplemented::PANIC_IN_LIBRARY,
+            &panic_unimplemented::TODO,
+            &panic_unimplemented::UNIMPLEMENTED,
+            &panic_unimplemented::UNREACHABLE,
+            &panicking_index_impl::PANICKING_USER_INDEX,
+            &reachable_panic::REACHABLE_PANIC,
+            &split_indexing::SPLIT_INDEXING,
+            &unchecked_division::UNCHECKED_DIVISION,
+            &unchecked_env_args::UNCHECKED_ENV_ARGS,
+            &unwrap_in_result::UNWRAP_IN_RESULT,
+            &unwrap_indexing::UNWRAP_INDEXING,
+            &unwrapped_index_conversion::UNWRAPPED_INDEX_CONVERSION,
+        ],
+    },
+];
+
+/// Registers the lint groups of `META_GROUPS`.
+pub fn register(store: &mut LintStore) {
+    for group in META_GROUPS {
+        let lints = group.lints.iter().map(|&&lint| LintId::of(lint)).collect();
+        store.register_group(true, group.name, None, lints);
+    }
+}
@@ -19,6 +19,7 @@ pub(super) fn check(
 ) -> bool {
     if_chain! {
         if let Some(args) = method_chain_args(info.chain, chain_methods);
+        if let Some([receiver, ..]) = args.first();
         if let hir::ExprKind::Call(fun, arg_char) = info.other.kind;
         if arg_char.len() == 1;
         if let hir::ExprKind::Path(ref qpath) = fun.kind;
@@ -26,7 +27,7 @@ pub(super) fn check(
         if segment.ident.name == sym::Some;
         then {
             let mut applicability = Applicability::MachineApplicable;
-            let self_ty = cx.typeck_results().expr_ty_adjusted(&args[0][0]).peel_refs();
+            let self_ty = cx.typeck_results().expr_ty_adjusted(receiver).peel_refs();
 
             if *self_ty.kind() != ty::Str {
                 return false;
@@ -40,7 +41,7 @@ pub(super) fn check(
                 "like this",
                 format!("{}{}.{}({})",
                         if info.eq { "" } else { "!" },
-                        snippet_with_applicability(cx, args[0][0].span, "..", &mut applicability),
+                        snippet_with_applicability(cx, receiver.span, "..", &mut applicability),
                         suggest,
                         snippet_with_applicability(cx, arg_char[0].span, "..", &mut applicability)),
                 applicability,
@@ -2,7 +2,7 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::ty::{is_type_diagnostic_item, match_type};
-use clippy_utils::{get_parent_expr, paths};
+use clippy_utils::{get_parent_expr, is_allowed, paths};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
@@ -10,6 +10,7 @@
 use rustc_span::sym;
 
 use super::GET_UNWRAP;
+use crate::indexing_slicing::INDEXING_SLICING;
 
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
@@ -18,30 +19,18 @@ pub(super) fn check<'tcx>(
     get_arg: &'tcx hir::Expr<'_>,
     is_mut: bool,
 ) {
-    // Note: we don't want to lint `get_mut().unwrap` for `HashMap` or `BTreeMap`,
-    // because they do not implement `IndexMut`
+    // The suggested indexing would be linted by `INDEXING_SLICING`, `UNWRAPPED_GET` gives
+    // better advice in this case.
+    if !is_allowed(cx, INDEXING_SLICING, expr.hir_id) {
+        return;
+    }
+    let caller_type = match indexable_type(cx, recv, is_mut) {
+        Some(caller_type) => caller_type,
+        None => return, // caller is not a type that we want to lint
+    };
     let mut applicability = Applicability::MachineApplicable;
-    let expr_ty = cx.typeck_results().expr_ty(recv);
     let get_args_str = snippet_with_applicability(cx, get_arg.span, "..", &mut applicability);
-    let mut needs_ref;
-    let caller_type = if derefs_to_slice(cx, recv, expr_ty).is_some() {
-        needs_ref = get_args_str.parse::<usize>().is_ok();
-        "slice"
-    } else if is_type_diagnostic_item(cx, expr_ty, sym::vec_type) {
-        needs_ref = get_args_str.parse::<usize>().is_ok();
-        "Vec"
-    } else if is_type_diagnostic_item(cx, expr_ty, sym::vecdeque_type) {
-        needs_ref = get_args_str.parse::<usize>().is_ok();
-        "VecDeque"
-    } else if !is_mut && is_type_diagnostic_item(cx, expr_ty, sym::hashmap_type) {
-        needs_ref = true;
-        "HashMap"
-    } else if !is_mut && match_type(cx, expr_ty, &paths::BTREEMAP) {
-        needs_ref = true;
-        "BTreeMap"
-    } else {
-        return; // caller is not a type that we want to lint
-    };
+    let mut needs_ref = matches!(caller_type, "HashMap" | "BTreeMap") || get_args_str.parse::<usize>().is_ok();
 
     let mut span = expr.span;
 
@@ -85,3 +74,28 @@ pub(super) fn check<'tcx>(
         applicability,
     );
 }
+
+/// Returns the name of the type of `recv` if it is a standard library type whose `get` (or
+/// `get_mut` if `is_mut`) method can be replaced by indexing.
+pub(super) fn indexable_type<'tcx>(
+    cx: &LateContext<'tcx>,
+    recv: &'tcx hir::Expr<'tcx>,
+    is_mut: bool,
+) -> Option<&'static str> {
+    // Note: we don't want to lint `get_mut().unwrap` for `HashMap` or `BTreeMap`,
+    // because they do not implement `IndexMut`
+    let expr_ty = cx.typeck_results().expr_ty(recv);
+    if derefs_to_slice(cx, recv, expr_ty).is_some() {
+        Some("slice")
+    } else if is_type_diagnostic_item(cx, expr_ty, sym::vec_type) {
+        Some("Vec")
+    } else if is_type_diagnostic_item(cx, expr_ty, sym::vecdeque_type) {
+        Some("VecDeque")
+    } else if !is_mut && is_type_diagnostic_item(cx, expr_ty, sym::hashmap_type) {
+        Some("HashMap")
+    } else if !is_mut && match_type(cx, expr_ty, &paths::BTREEMAP) {
+        Some("BTreeMap")
+    } else {
+        None
+    }
+}
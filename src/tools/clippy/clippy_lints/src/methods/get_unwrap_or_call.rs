@@ -0,0 +1,117 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::eager_or_lazy::is_lazyness_candidate;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::{implements_trait, is_copy, is_type_diagnostic_item};
+use clippy_utils::{contains_return, match_def_path, paths};
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::{BorrowKind, Expr, ExprKind, Mutability};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+use super::GET_UNWRAP_OR_CALL;
+
+/// Checks for `x.get(i).unwrap_or(&arg)` where `arg` calls a function. `recv` is the `get` call.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, recv: &Expr<'_>, arg: &'tcx Expr<'tcx>) {
+    let default = match arg.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, default) => default,
+        _ => return,
+    };
+    if !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::option_type)
+        || !is_lazyness_candidate(cx, default)
+        || contains_return(default)
+    {
+        return;
+    }
+    // `&String::new()` and other calls of `const fn`s without arguments are cheap
+    if let ExprKind::Call(fun, []) = default.kind {
+        if let ExprKind::Path(ref qpath) = fun.kind {
+            if let Res::Def(_, fun_id) = cx.qpath_res(qpath, fun.hir_id) {
+                if cx.tcx.is_const_fn_raw(fun_id) {
+                    return;
+                }
+            }
+        }
+    }
+    let elem_ty = cx.typeck_results().expr_ty(default);
+
+    // `&x.clone()` borrows a copy of `x`, which `x` itself can be used for
+    if let ExprKind::MethodCall(_, _, [cloned], _) = default.kind {
+        let is_clone = cx
+            .typeck_results()
+            .type_dependent_def_id(default.hir_id)
+            .map_or(false, |id| match_def_path(cx, id, &paths::CLONE_TRAIT_METHOD));
+        if is_clone {
+            let cloned_ty = cx.typeck_results().expr_ty(cloned);
+            let is_ref = match cloned_ty.kind() {
+                _ if cloned_ty == elem_ty => false,
+                ty::Ref(_, ty, Mutability::Not) if *ty == elem_ty => true,
+                _ => return,
+            };
+            span_lint_and_then(
+                cx,
+                GET_UNWRAP_OR_CALL,
+                arg.span,
+                "cloning the default of `unwrap_or` only to borrow it",
+                |diag| {
+                    let mut applicability = Applicability::MachineApplicable;
+                    let cloned = snippet_with_applicability(cx, cloned.span, "..", &mut applicability);
+                    let sugg = if is_ref {
+                        cloned.to_string()
+                    } else {
+                        format!("&{}", cloned)
+                    };
+                    diag.span_suggestion(arg.span, "borrow it without cloning", sugg, applicability);
+                },
+            );
+            return;
+        }
+    }
+
+    span_lint_and_then(
+        cx,
+        GET_UNWRAP_OR_CALL,
+        expr.span,
+        "use of `unwrap_or` with a reference to a function call after `get`",
+        |diag| {
+            diag.note("the function is called even if the element exists");
+            let method = if is_copy(cx, elem_ty) {
+                "copied"
+            } else if cx
+                .tcx
+                .lang_items()
+                .clone_trait()
+                .map_or(false, |clone| implements_trait(cx, elem_ty, clone, &[]))
+            {
+                "cloned"
+            } else {
+                return;
+            };
+            // `unwrap_or_else(|| &f())` would return a reference to a temporary
+            let mut applicability = Applicability::MaybeIncorrect;
+            let default = match default.kind {
+                ExprKind::Call(fun, []) => snippet_with_applicability(cx, fun.span, "..", &mut applicability),
+                _ => format!(
+                    "|| {}",
+                    snippet_with_applicability(cx, default.span, "..", &mut applicability)
+                )
+                .into(),
+            };
+            diag.span_suggestion(
+                expr.span,
+                &format!(
+                    "consider getting the element with `{}` and calling the function only if it's missing",
+                    method
+                ),
+                format!(
+                    "{}.{}().unwrap_or_else({})",
+                    snippet_with_applicability(cx, recv.span, "..", &mut applicability),
+                    method,
+                    default,
+                ),
+                applicability,
+            );
+        },
+    );
+}
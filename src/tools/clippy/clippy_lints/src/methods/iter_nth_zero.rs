@@ -1,4 +1,4 @@
-use crate::consts::{constant, Constant};
+use crate::consts::is_integer_const_zero;
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::is_trait_method;
 use clippy_utils::source::snippet_with_applicability;
@@ -13,7 +13,7 @@
 pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &hir::Expr<'_>, recv: &hir::Expr<'_>, arg: &hir::Expr<'_>) {
     if_chain! {
         if is_trait_method(cx, expr, sym::Iterator);
-        if let Some((Constant::Int(0), _)) = constant(cx, cx.typeck_results(), arg);
+        if is_integer_const_zero(cx, arg);
         then {
             let mut applicability = Applicability::MachineApplicable;
             span_lint_and_sugg(
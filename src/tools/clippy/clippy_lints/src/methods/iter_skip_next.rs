@@ -1,6 +1,8 @@
-use clippy_utils::diagnostics::span_lint_and_sugg;
+use crate::utils::known_len::{const_usize, known_slice_len};
+use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::is_trait_method;
 use clippy_utils::source::snippet;
+use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
@@ -11,14 +13,34 @@
 pub(super) fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, recv: &hir::Expr<'_>, arg: &hir::Expr<'_>) {
     // lint if caller of skip is an Iterator
     if is_trait_method(cx, expr, sym::Iterator) {
-        span_lint_and_sugg(
+        let span = expr.span.trim_start(recv.span).unwrap();
+        span_lint_and_then(
             cx,
             ITER_SKIP_NEXT,
-            expr.span.trim_start(recv.span).unwrap(),
+            span,
             "called `skip(..).next()` on an iterator",
-            "use `nth` instead",
-            format!(".nth({})", snippet(cx, arg.span, "..")),
-            Applicability::MachineApplicable,
+            |diag| {
+                diag.span_suggestion(
+                    span,
+                    "use `nth` instead",
+                    format!(".nth({})", snippet(cx, arg.span, "..")),
+                    Applicability::MachineApplicable,
+                );
+                // `x.iter().skip(n).next()` with a constant `n` past the end of `x`
+                if_chain! {
+                    if let hir::ExprKind::MethodCall(path, _, [collection], _) = recv.kind;
+                    if matches!(&*path.ident.as_str(), "iter" | "iter_mut" | "into_iter");
+                    if let Some(n) = const_usize(cx, arg);
+                    if let Some(len) = known_slice_len(cx, expr, collection);
+                    if n >= len.len;
+                    then {
+                        diag.span_note(
+                            len.span,
+                            &format!("the {} has length {}, so this is always `None`", len.kind, len.len),
+                        );
+                    }
+                }
+            },
         );
     }
 }
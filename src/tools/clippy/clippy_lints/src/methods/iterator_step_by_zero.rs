@@ -1,21 +1,25 @@
-use crate::consts::{constant, Constant};
-use clippy_utils::diagnostics::span_lint;
+use crate::consts::is_integer_const_zero;
+use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::is_trait_method;
 use rustc_hir as hir;
+use rustc_hir::ExprKind;
 use rustc_lint::LateContext;
 use rustc_span::sym;
 
 use super::ITERATOR_STEP_BY_ZERO;
 
 pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &hir::Expr<'_>, arg: &'tcx hir::Expr<'_>) {
-    if is_trait_method(cx, expr, sym::Iterator) {
-        if let Some((Constant::Int(0), _)) = constant(cx, cx.typeck_results(), arg) {
-            span_lint(
-                cx,
-                ITERATOR_STEP_BY_ZERO,
-                expr.span,
-                "`Iterator::step_by(0)` will panic at runtime",
-            );
-        }
+    if is_trait_method(cx, expr, sym::Iterator) && is_integer_const_zero(cx, arg) {
+        span_lint_and_then(
+            cx,
+            ITERATOR_STEP_BY_ZERO,
+            expr.span,
+            "`Iterator::step_by(0)` will panic at runtime",
+            |diag| {
+                if !matches!(arg.kind, ExprKind::Lit(_)) {
+                    diag.span_note(arg.span, "this step evaluates to `0`");
+                }
+            },
+        );
     }
 }
@@ -0,0 +1,79 @@
+use crate::utils::known_len::{const_usize, known_slice_len};
+use clippy_utils::diagnostics::span_lint_and_then_multi;
+use clippy_utils::higher;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_ast::ast::RangeLimits;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::METHOD_INDEX_OUT_OF_BOUNDS;
+
+/// lint `v.remove(n)`, `v.swap_remove(n)`, `v.split_off(n)`, `v.split_at(n)`, `v.split_at_mut(n)`,
+/// `v.drain(a..b)` and `v.splice(a..b, _)` with a constant `n`, `a` or `b` out of bounds
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &hir::Expr<'_>,
+    name: &str,
+    recv: &'tcx hir::Expr<'tcx>,
+    arg: &hir::Expr<'_>,
+) {
+    let recv_ty = cx.typeck_results().expr_ty(recv).peel_refs();
+    if !name.starts_with("split_at") && !is_type_diagnostic_item(cx, recv_ty, sym::vec_type) {
+        return;
+    }
+    let len = match known_slice_len(cx, expr, recv) {
+        Some(len) => len,
+        None => return,
+    };
+    let (index, what, kind) = if matches!(name, "drain" | "splice") {
+        match out_of_bounds_range_bound(cx, arg, len.len) {
+            Some((bound, what)) => (bound, what, "range"),
+            None => return,
+        }
+    } else {
+        let index = match const_usize(cx, arg) {
+            Some(index) => index,
+            None => return,
+        };
+        // `split_at(len)` and `split_off(len)` return an empty second half
+        let out_of_bounds = if name.starts_with("split") {
+            index > len.len
+        } else {
+            index >= len.len
+        };
+        if !out_of_bounds {
+            return;
+        }
+        (index, "index", "index")
+    };
+    let labels = [
+        (arg.span, format!("the {} is {}", what, index)),
+        (len.span, format!("the {} has length {}", len.kind, len.len)),
+    ];
+    span_lint_and_then_multi(
+        cx,
+        METHOD_INDEX_OUT_OF_BOUNDS,
+        arg.span,
+        &format!("{} passed to `{}` is out of bounds", kind, name),
+        &labels,
+        |_| {},
+    );
+}
+
+/// Returns the value of a constant bound of the range `arg` that is greater than `len`, and which
+/// bound it is.
+fn out_of_bounds_range_bound(cx: &LateContext<'_>, arg: &hir::Expr<'_>, len: u128) -> Option<(u128, &'static str)> {
+    let range = higher::range(arg)?;
+    let end = range.end.and_then(|end| match range.limits {
+        RangeLimits::HalfOpen => const_usize(cx, end),
+        RangeLimits::Closed => const_usize(cx, end)?.checked_add(1),
+    });
+    if let Some(end) = end.filter(|&end| end > len) {
+        return Some((end, "end of the range"));
+    }
+    let start = range.start.and_then(|start| const_usize(cx, start));
+    start
+        .filter(|&start| start > len)
+        .map(|start| (start, "start of the range"))
+}
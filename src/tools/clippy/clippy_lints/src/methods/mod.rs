@@ -20,6 +20,7 @@
 mod flat_map_option;
 mod from_iter_instead_of_collect;
 mod get_unwrap;
+mod get_unwrap_or_call;
 mod implicit_clone;
 mod inefficient_to_string;
 mod inspect_for_each;
@@ -35,6 +36,8 @@
 mod map_collect_result_unit;
 mod map_flatten;
 mod map_unwrap_or;
+mod method_index_out_of_bounds;
+mod nonzero_new_unwrap;
 mod ok_expect;
 mod option_as_ref_deref;
 mod option_map_or_none;
@@ -46,13 +49,17 @@
 mod single_char_pattern;
 mod single_char_push_string;
 mod skip_while_next;
+mod slice_length_mismatch;
 mod string_extend_chars;
 mod suspicious_map;
+mod unguarded_get_unchecked;
 mod uninit_assumed_init;
 mod unnecessary_filter_map;
 mod unnecessary_fold;
 mod unnecessary_lazy_eval;
 mod unwrap_used;
+mod unwrapped_first_or_last;
+mod unwrapped_get;
 mod useless_asref;
 mod utils;
 mod wrong_self_convention;
@@ -61,13 +68,12 @@
 use bind_instead_of_map::BindInsteadOfMap;
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
 use clippy_utils::ty::{contains_adt_constructor, contains_ty, implements_trait, is_copy, is_type_diagnostic_item};
-use clippy_utils::{contains_return, get_trait_def_id, in_macro, iter_input_pats, paths, return_ty};
+use clippy_utils::{contains_return, get_trait_def_id, in_external_macro, in_macro, iter_input_pats, paths, return_ty};
 use if_chain::if_chain;
 use rustc_hir as hir;
 use rustc_hir::def::Res;
 use rustc_hir::{Expr, ExprKind, PrimTy, QPath, TraitItem, TraitItemKind};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
-use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{self, TraitRef, Ty, TyS};
 use rustc_semver::RustcVersion;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
@@ -697,6 +703,40 @@
     "using any `*or` method with a function call, which suggests `*or_else`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for `.get(i).unwrap_or(&default)` where `default` calls a
+    /// function, like `v.get(i).unwrap_or(&compute())` or `v.get(i).unwrap_or(&fallback.clone())`.
+    ///
+    /// **Why is this bad?** The default is computed even if the element exists. Unlike with
+    /// `or_fun_call`, `unwrap_or_else(|| &compute())` doesn't compile, as it would return a
+    /// reference to a temporary.
+    ///
+    /// **Known problems:** Getting the element with `cloned` or `copied` changes the type of the
+    /// result from a reference to a value.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let v = vec![String::new()];
+    /// # let fallback = String::new();
+    /// # let i = 0;
+    /// # fn compute() -> String { String::new() }
+    /// let a = v.get(i).unwrap_or(&compute());
+    /// let b = v.get(i).unwrap_or(&fallback.clone());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let v = vec![String::new()];
+    /// # let fallback = String::new();
+    /// # let i = 0;
+    /// # fn compute() -> String { String::new() }
+    /// let a = v.get(i).cloned().unwrap_or_else(compute);
+    /// let b = v.get(i).unwrap_or(&fallback);
+    /// ```
+    pub GET_UNWRAP_OR_CALL,
+    perf,
+    "using `.get(i).unwrap_or(&f())`, which calls `f` even if the element exists"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for calls to `.expect(&format!(...))`, `.expect(foo(..))`,
     /// etc., and suggests to use `unwrap_or_else` instead
@@ -902,7 +942,9 @@
 }
 
 declare_clippy_lint! {
-    /// **What it does:** Checks for calling `.step_by(0)` on iterators which panics.
+    /// **What it does:** Checks for calling `.step_by(0)` on iterators which panics. The step
+    /// is evaluated as a constant, through immutable local bindings and the `const fn`s of the
+    /// crate, so `.step_by(n)` after `let n = 0;` is linted too.
     ///
     /// **Why is this bad?** This very much looks like an oversight. Use `panic!()` instead if you
     /// actually intend to panic.
@@ -999,7 +1041,9 @@
 declare_clippy_lint! {
     /// **What it does:** Checks for use of `.skip(x).next()` on iterators.
     ///
-    /// **Why is this bad?** `.nth(x)` is cleaner
+    /// **Why is this bad?** `.nth(x)` is cleaner. If `x` is a constant past the end of an array
+    /// or a vector of a known length, the result is always `None`, which the lint points out as
+    /// well.
     ///
     /// **Known problems:** None.
     ///
@@ -1053,6 +1097,61 @@
     "using `.get().unwrap()` or `.get_mut().unwrap()` when using `[]` would work instead"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for `.get().unwrap()` and `.get().expect()` (or their `get_mut`
+    /// variants) on a standard library type which implements `Index`. This is the companion of
+    /// [`indexing_slicing`](#indexing_slicing): [`get_unwrap`](#get_unwrap) doesn't lint where
+    /// `indexing_slicing` is enabled, as its suggestion would be linted again.
+    ///
+    /// **Why is this bad?** It panics if the value is missing, just like indexing does. Replacing
+    /// indexing with `.get().unwrap()` only hides the panic.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn first_word(words: &[&str]) -> usize {
+    ///     words.get(0).unwrap().len()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn first_word(words: &[&str]) -> Option<usize> {
+    ///     Some(words.get(0)?.len())
+    /// }
+    /// ```
+    pub UNWRAPPED_GET,
+    restriction,
+    "using `.get().unwrap()` or `.get().expect()`, which panics like indexing"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `.first().unwrap()` and `.last().unwrap()` on slices, and for
+    /// `.next().unwrap()` on iterators. This complements the indexing lints for `v[0]`.
+    ///
+    /// **Why is this bad?** It panics if the slice or the iterator is empty, just like `v[0]`
+    /// does. The emptiness should be handled, e.g. with `if let`, or at least documented in the
+    /// message of an `expect`.
+    ///
+    /// **Known problems:** Some iterators are never empty, like the one returned by `str::split`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn header(lines: &[&str]) -> usize {
+    ///     lines.first().unwrap().len()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn header(lines: &[&str]) -> usize {
+    ///     if let Some(first) = lines.first() { first.len() } else { 0 }
+    /// }
+    /// ```
+    pub UNWRAPPED_FIRST_OR_LAST,
+    restriction,
+    "using `.first().unwrap()`, `.last().unwrap()` or `.next().unwrap()`, which panic on empty collections"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for the use of `.extend(s.chars())` where s is a
     /// `&str` or `String`.
@@ -1657,6 +1756,124 @@
     "replace `.iter().count()` with `.len()`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `Vec::remove`, `Vec::swap_remove`,
+    /// `Vec::split_off`, `split_at` and `split_at_mut` with a constant index that is out of bounds
+    /// of an array, of a local `Vec` whose length is known, e.g. created with `vec![elem; N]`, or
+    /// of a subslice of them with constant bounds, like `x[..4]`. Calls to `Vec::drain` and
+    /// `Vec::splice` with a range whose constant start or end is greater than the length are
+    /// checked as well.
+    ///
+    /// **Why is this bad?** This will always panic at runtime.
+    ///
+    /// **Known problems:** Hopefully none.
+    ///
+    /// **Example:**
+    /// ```no_run
+    /// let mut v = vec![0; 4];
+    /// let x = [1, 2, 3, 4];
+    ///
+    /// // Bad
+    /// v.remove(4);
+    /// x.split_at(5);
+    /// v.drain(0..5);
+    ///
+    /// // Good
+    /// v.remove(3);
+    /// x.split_at(4);
+    /// v.drain(0..4);
+    /// ```
+    pub METHOD_INDEX_OUT_OF_BOUNDS,
+    correctness,
+    "calling `remove`, `split_at`, `drain` and similar methods with an index or a range that is out of bounds"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `copy_from_slice`, `clone_from_slice` and
+    /// `swap_with_slice` where the lengths of both slices are known and differ, e.g. arrays,
+    /// local `Vec`s created with `vec![elem; N]`, or subslices of them with constant bounds.
+    ///
+    /// **Why is this bad?** These methods panic if the slices have different lengths.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```no_run
+    /// let src = [1, 2, 3];
+    /// let mut dst = [0; 8];
+    ///
+    /// // Bad
+    /// dst[..4].copy_from_slice(&src);
+    ///
+    /// // Good
+    /// dst[..3].copy_from_slice(&src);
+    /// ```
+    pub SLICE_LENGTH_MISMATCH,
+    correctness,
+    "calling `copy_from_slice` and similar methods with slices of different lengths"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `get_unchecked` and `get_unchecked_mut` on slices
+    /// (and on arrays and `Vec`s) whose index isn't known to be in bounds, e.g. by an
+    /// `if i < v.len()` or an `assert!(i < v.len())` before it, or a `for i in 0..v.len()` loop.
+    ///
+    /// **Why is this bad?** An out of bounds index is undefined behavior. The bounds should be
+    /// guaranteed where they are elided, so that they can be audited in a single place.
+    ///
+    /// **Known problems:** Only simple guards of the index are recognized. Guarantees given by
+    /// invariants of a type or by the documentation of an `unsafe fn` aren't visible.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn first(v: &[u8]) -> u8 {
+    ///     unsafe { *v.get_unchecked(0) }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn first(v: &[u8]) -> u8 {
+    ///     assert!(0 < v.len());
+    ///     unsafe { *v.get_unchecked(0) }
+    /// }
+    /// ```
+    pub UNGUARDED_GET_UNCHECKED,
+    pedantic,
+    "using `get_unchecked` with an index that isn't known to be in bounds"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `.unwrap()` on the result of `NonZeroUsize::new` and the
+    /// `new` functions of the other nonzero integer types, when the value isn't known to be
+    /// nonzero. Like for `unchecked_division`, a value is known to be nonzero if it is a nonzero
+    /// constant, or a variable guarded by a comparison like `n != 0` or `n > 0` (or `n < 0` if it is
+    /// signed).
+    ///
+    /// **Why is this bad?** `new` returns `None` for zero, so the `unwrap()` panics. The zero case
+    /// should be handled, or ruled out with `new_unchecked` in an `unsafe` block documenting why
+    /// the value can't be zero.
+    ///
+    /// **Known problems:** Only simple guards of the value are recognized.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::num::NonZeroUsize;
+    /// fn chunk_size(n: usize) -> NonZeroUsize {
+    ///     NonZeroUsize::new(n).unwrap()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::num::NonZeroUsize;
+    /// fn chunk_size(n: usize) -> Option<NonZeroUsize> {
+    ///     NonZeroUsize::new(n)
+    /// }
+    /// ```
+    pub NONZERO_NEW_UNWRAP,
+    restriction,
+    "unwrapping `NonZero*::new(n)` when `n` isn't known to be nonzero"
+}
+
 pub struct Methods {
     msrv: Option<RustcVersion>,
 }
@@ -1680,6 +1897,7 @@ pub fn new(msrv: Option<RustcVersion>) -> Self {
     OPTION_MAP_OR_NONE,
     BIND_INSTEAD_OF_MAP,
     OR_FUN_CALL,
+    GET_UNWRAP_OR_CALL,
     EXPECT_FUN_CALL,
     CHARS_NEXT_CMP,
     CHARS_LAST_CMP,
@@ -1710,6 +1928,8 @@ pub fn new(msrv: Option<RustcVersion>) -> Self {
     BYTES_NTH,
     ITER_SKIP_NEXT,
     GET_UNWRAP,
+    UNWRAPPED_GET,
+    UNWRAPPED_FIRST_OR_LAST,
     STRING_EXTEND_CHARS,
     ITER_CLONED_COLLECT,
     USELESS_ASREF,
@@ -1726,7 +1946,11 @@ pub fn new(msrv: Option<RustcVersion>) -> Self {
     MAP_COLLECT_RESULT_UNIT,
     FROM_ITER_INSTEAD_OF_COLLECT,
     INSPECT_FOR_EACH,
-    IMPLICIT_CLONE
+    IMPLICIT_CLONE,
+    METHOD_INDEX_OUT_OF_BOUNDS,
+    SLICE_LENGTH_MISMATCH,
+    UNGUARDED_GET_UNCHECKED,
+    NONZERO_NEW_UNWRAP
 ]);
 
 /// Extracts a method call name, args, and `Span` of the method name.
@@ -1959,6 +2183,9 @@ fn check_methods<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, msrv: Optio
             ("as_mut", []) => useless_asref::check(cx, expr, "as_mut", recv),
             ("as_ref", []) => useless_asref::check(cx, expr, "as_ref", recv),
             ("assume_init", []) => uninit_assumed_init::check(cx, expr, recv),
+            ("clone_from_slice" | "copy_from_slice" | "swap_with_slice", [arg]) => {
+                slice_length_mismatch::check(cx, expr, name, recv, arg)
+            },
             ("cloned", []) => cloned_instead_of_copied::check(cx, expr, recv, span, msrv),
             ("collect", []) => match method_call!(recv) {
                 Some(("cloned", [recv2], _)) => iter_cloned_collect::check(cx, expr, recv2),
@@ -1976,6 +2203,14 @@ fn check_methods<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, msrv: Optio
             },
             ("expect", [_]) => match method_call!(recv) {
                 Some(("ok", [recv], _)) => ok_expect::check(cx, expr, recv),
+                Some(("get", [recv, get_arg], _)) => {
+                    unwrapped_get::check(cx, expr, recv, get_arg, false, "expect");
+                    expect_used::check(cx, expr, recv);
+                },
+                Some(("get_mut", [recv, get_arg], _)) => {
+                    unwrapped_get::check(cx, expr, recv, get_arg, true, "expect");
+                    expect_used::check(cx, expr, recv);
+                },
                 _ => expect_used::check(cx, expr, recv),
             },
             ("extend", [arg]) => string_extend_chars::check(cx, expr, recv, arg),
@@ -1999,6 +2234,7 @@ fn check_methods<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, msrv: Optio
                 }
             },
             ("get_or_insert_with", [arg]) => unnecessary_lazy_eval::check(cx, expr, recv, arg, "get_or_insert"),
+            ("get_unchecked" | "get_unchecked_mut", [arg]) => unguarded_get_unchecked::check(cx, expr, name, recv, arg),
             ("is_file", []) => filetype_is_file::check(cx, expr, recv),
             ("is_none", []) => check_is_some_is_none(cx, expr, recv, false),
             ("is_some", []) => check_is_some_is_none(cx, expr, recv, true),
@@ -2040,14 +2276,29 @@ fn check_methods<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, msrv: Optio
                     unnecessary_lazy_eval::check(cx, expr, recv, arg, "or");
                 }
             },
+            ("remove" | "split_at" | "split_at_mut" | "split_off" | "swap_remove" | "drain", [arg])
+            | ("splice", [arg, _]) => method_index_out_of_bounds::check(cx, expr, name, recv, arg),
             ("step_by", [arg]) => iterator_step_by_zero::check(cx, expr, arg),
             ("to_os_string" | "to_owned" | "to_path_buf" | "to_vec", []) => {
                 implicit_clone::check(cx, name, expr, recv, span);
             },
             ("unwrap", []) => match method_call!(recv) {
-                Some(("get", [recv, get_arg], _)) => get_unwrap::check(cx, expr, recv, get_arg, false),
-                Some(("get_mut", [recv, get_arg], _)) => get_unwrap::check(cx, expr, recv, get_arg, true),
-                _ => unwrap_used::check(cx, expr, recv),
+                Some(("get", [recv, get_arg], _)) => {
+                    get_unwrap::check(cx, expr, recv, get_arg, false);
+                    unwrapped_get::check(cx, expr, recv, get_arg, false, "unwrap");
+                },
+                Some(("get_mut", [recv, get_arg], _)) => {
+                    get_unwrap::check(cx, expr, recv, get_arg, true);
+                    unwrapped_get::check(cx, expr, recv, get_arg, true, "unwrap");
+                },
+                Some((name @ ("first" | "last" | "next"), [_], _)) => {
+                    unwrapped_first_or_last::check(cx, expr, recv, name);
+                    unwrap_used::check(cx, expr, recv);
+                },
+                _ => {
+                    nonzero_new_unwrap::check(cx, expr, recv);
+                    unwrap_used::check(cx, expr, recv);
+                },
             },
             ("unwrap_or", [u_arg]) => match method_call!(recv) {
                 Some((arith @ ("checked_add" | "checked_sub" | "checked_mul"), [lhs, rhs], _)) => {
@@ -2056,6 +2307,7 @@ fn check_methods<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, msrv: Optio
                 Some(("map", [m_recv, m_arg], span)) => {
                     option_map_unwrap_or::check(cx, expr, m_recv, m_arg, recv, u_arg, span)
                 },
+                Some(("get", [_, _], _)) => get_unwrap_or_call::check(cx, expr, recv, u_arg),
                 _ => {},
             },
             ("unwrap_or_else", [u_arg]) => match method_call!(recv) {
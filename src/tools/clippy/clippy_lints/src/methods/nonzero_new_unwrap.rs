@@ -0,0 +1,55 @@
+use crate::consts::is_integer_const_zero;
+use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
+use clippy_utils::source::snippet;
+use clippy_utils::value_range::is_nonzero;
+use if_chain::if_chain;
+use rustc_hir as hir;
+use rustc_hir::{ExprKind, QPath};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+use super::NONZERO_NEW_UNWRAP;
+
+/// lint `NonZeroUsize::new(n).unwrap()` and the like where `n` isn't known to be nonzero
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &hir::Expr<'_>, recv: &'tcx hir::Expr<'tcx>) {
+    if_chain! {
+        if let ExprKind::Call(func, [arg]) = recv.kind;
+        if let ExprKind::Path(QPath::TypeRelative(_, segment)) = func.kind;
+        if segment.ident.name == sym::new;
+        if let ty::Adt(option, substs) = cx.typeck_results().expr_ty(recv).kind();
+        if cx.tcx.is_diagnostic_item(sym::option_type, option.did);
+        if let ty::Adt(nonzero, _) = substs.type_at(0).kind();
+        if cx.tcx.crate_name(nonzero.did.krate) == sym::core;
+        let name = cx.tcx.item_name(nonzero.did);
+        if name.as_str().starts_with("NonZero");
+        if !is_nonzero(cx, arg);
+        then {
+            if is_integer_const_zero(cx, arg) {
+                span_lint(
+                    cx,
+                    NONZERO_NEW_UNWRAP,
+                    expr.span,
+                    &format!("called `{}::new(..).unwrap()` with zero, which always panics", name),
+                );
+                return;
+            }
+            let arg = snippet(cx, arg.span, "..");
+            span_lint_and_help(
+                cx,
+                NONZERO_NEW_UNWRAP,
+                expr.span,
+                &format!("called `{}::new(..).unwrap()` with a value that isn't known to be nonzero", name),
+                None,
+                &format!(
+                    "consider handling the zero case with `{}.ok_or(..)`, or use `unsafe {{ {}::new_unchecked({}) }}` \
+                    with a comment explaining why `{}` can't be zero",
+                    snippet(cx, recv.span, ".."),
+                    name,
+                    arg,
+                    arg,
+                ),
+            );
+        }
+    }
+}
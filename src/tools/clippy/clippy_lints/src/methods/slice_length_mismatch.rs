@@ -0,0 +1,45 @@
+use crate::utils::known_len::known_slice_len;
+use clippy_utils::diagnostics::span_lint_and_then_multi;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+
+use super::SLICE_LENGTH_MISMATCH;
+
+/// lint `dst.copy_from_slice(src)`, `dst.clone_from_slice(src)` and `dst.swap_with_slice(src)`
+/// where `dst` and `src` have different known lengths
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &hir::Expr<'_>,
+    name: &str,
+    recv: &'tcx hir::Expr<'tcx>,
+    arg: &'tcx hir::Expr<'tcx>,
+) {
+    let is_slice_method = cx
+        .typeck_results()
+        .type_dependent_def_id(expr.hir_id)
+        .and_then(|method_id| cx.tcx.impl_of_method(method_id))
+        .map_or(false, |impl_id| Some(impl_id) == cx.tcx.lang_items().slice_impl());
+    if !is_slice_method {
+        return;
+    }
+    let (dst, src) = match (known_slice_len(cx, expr, recv), known_slice_len(cx, expr, arg)) {
+        (Some(dst), Some(src)) => (dst, src),
+        _ => return,
+    };
+    if dst.len != src.len {
+        let labels = [
+            (dst.span, format!("the {} has length {}", dst.kind, dst.len)),
+            (src.span, format!("the {} has length {}", src.kind, src.len)),
+        ];
+        span_lint_and_then_multi(
+            cx,
+            SLICE_LENGTH_MISMATCH,
+            expr.span,
+            &format!("`{}` with slices of different lengths", name),
+            &labels,
+            |diag| {
+                diag.note(&format!("`{}` panics if the lengths differ", name));
+            },
+        );
+    }
+}
@@ -15,8 +15,7 @@ pub(super) fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, recv: &hir::Expr
     if !is_type_diagnostic_item(cx, obj_ty, sym::string_type) {
         return;
     }
-    if let Some(arglists) = method_chain_args(arg, &["chars"]) {
-        let target = &arglists[0][0];
+    if let Some([[target, ..], ..]) = method_chain_args(arg, &["chars"]).as_deref() {
         let self_ty = cx.typeck_results().expr_ty(target).peel_refs();
         let ref_str = if *self_ty.kind() == ty::Str {
             ""
@@ -0,0 +1,63 @@
+use crate::utils::known_len::known_len;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::higher;
+use clippy_utils::source::snippet;
+use clippy_utils::value_range::{value_range, ValueRange};
+use rustc_ast::ast::RangeLimits;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+
+use super::UNGUARDED_GET_UNCHECKED;
+
+/// lint `v.get_unchecked(i)` and `v.get_unchecked_mut(i)` where `i` isn't known to be in bounds
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'tcx>,
+    name: &str,
+    recv: &'tcx hir::Expr<'tcx>,
+    arg: &'tcx hir::Expr<'tcx>,
+) {
+    let is_slice_method = cx
+        .typeck_results()
+        .type_dependent_def_id(expr.hir_id)
+        .and_then(|method_id| cx.tcx.impl_of_method(method_id))
+        .map_or(false, |impl_id| Some(impl_id) == cx.tcx.lang_items().slice_impl());
+    if !is_slice_method {
+        return;
+    }
+
+    let len = known_len(cx, expr, recv).map(|len| len.len);
+    let in_bounds = match higher::range(arg) {
+        Some(range) => {
+            let start = range
+                .start
+                .map_or_else(|| ValueRange::exact(0), |start| value_range(cx, start));
+            match range.end {
+                Some(end) => {
+                    let end = if range.limits == RangeLimits::Closed {
+                        value_range(cx, end).plus(1)
+                    } else {
+                        value_range(cx, end)
+                    };
+                    start.max.map_or(false, |max| max <= end.min) && end.is_at_most_len(cx, recv, len)
+                },
+                // `v[i..]` is in bounds if `i <= v.len()`
+                None => start.is_at_most_len(cx, recv, len),
+            }
+        },
+        None => value_range(cx, arg).is_below_len(cx, recv, len),
+    };
+    if !in_bounds {
+        span_lint_and_help(
+            cx,
+            UNGUARDED_GET_UNCHECKED,
+            expr.span,
+            &format!("`{}` with an index that isn't known to be in bounds", name),
+            None,
+            &format!(
+                "check the index against `{}.len()` before, e.g. with `assert!` or an `if`",
+                snippet(cx, recv.span, "..")
+            ),
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::is_trait_method;
+use clippy_utils::source::snippet;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::UNWRAPPED_FIRST_OR_LAST;
+
+/// lint `.first().unwrap()` and `.last().unwrap()` on slices and `.next().unwrap()` on iterators
+pub(super) fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, call: &hir::Expr<'_>, name: &str) {
+    let kind = if name == "next" {
+        if !is_trait_method(cx, call, sym::Iterator) {
+            return;
+        }
+        "iterator"
+    } else {
+        let is_slice_method = cx
+            .typeck_results()
+            .type_dependent_def_id(call.hir_id)
+            .and_then(|method_id| cx.tcx.impl_of_method(method_id))
+            .map_or(false, |impl_id| Some(impl_id) == cx.tcx.lang_items().slice_impl());
+        if !is_slice_method {
+            return;
+        }
+        "slice"
+    };
+    span_lint_and_help(
+        cx,
+        UNWRAPPED_FIRST_OR_LAST,
+        expr.span,
+        &format!("called `.{}().unwrap()`, which panics if the {} is empty", name, kind),
+        None,
+        &format!(
+            "consider handling the empty case with `if let Some(..) = {}`, or use `expect()` to explain why it can't \
+            be empty",
+            snippet(cx, call.span, ".."),
+        ),
+    );
+}
@@ -0,0 +1,40 @@
+use super::get_unwrap::indexable_type;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+
+use super::UNWRAPPED_GET;
+
+/// lint `.get(..).unwrap()`, `.get(..).expect(..)` and their `get_mut` variants
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &hir::Expr<'_>,
+    recv: &'tcx hir::Expr<'tcx>,
+    get_arg: &hir::Expr<'_>,
+    is_mut: bool,
+    unwrap_name: &str,
+) {
+    let caller_type = match indexable_type(cx, recv, is_mut) {
+        Some(caller_type) => caller_type,
+        None => return,
+    };
+    let mut_str = if is_mut { "_mut" } else { "" };
+    span_lint_and_then(
+        cx,
+        UNWRAPPED_GET,
+        expr.span,
+        &format!(
+            "called `.get{}().{}()` on a {}, which panics just like indexing",
+            mut_str, unwrap_name, caller_type
+        ),
+        |diag| {
+            diag.help("handle the `None` case, for example by returning an error");
+            diag.help(&format!(
+                "if it can't be `None`, index with `{}[{}]` and allow `clippy::indexing_slicing` there",
+                snippet(cx, recv.span, ".."),
+                snippet(cx, get_arg.span, ".."),
+            ));
+        },
+    );
+}
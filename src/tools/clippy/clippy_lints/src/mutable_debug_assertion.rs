@@ -1,5 +1,7 @@
-use clippy_utils::diagnostics::span_lint;
-use clippy_utils::{higher, is_direct_expn_of};
+use crate::utils::known_len::const_usize;
+use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
+use clippy_utils::source::snippet;
+use clippy_utils::{higher, is_direct_expn_of, is_expn_of, SpanlessEq};
 use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
 use rustc_hir::{BorrowKind, Expr, ExprKind, MatchSource, Mutability};
 use rustc_lint::{LateContext, LateLintPass};
@@ -31,7 +33,32 @@
     "mutable arguments in `debug_assert{,_ne,_eq}!`"
 }
 
-declare_lint_pass!(DebugAssertWithMutCall => [DEBUG_ASSERT_WITH_MUT_CALL]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for indexing in `debug_assert!`, `debug_assert_eq!` and
+    /// `debug_assert_ne!` macros that isn't done outside of them too, like `debug_assert!(v[i] > 0)`.
+    ///
+    /// **Why is this bad?** The indexing panics in debug builds when the index is out of bounds,
+    /// but disappears with the assertion in release builds, so the two builds behave differently.
+    /// Comparing the `Option` returned by `get` makes a missing element an explicit failure of the
+    /// assertion.
+    ///
+    /// **Known problems:** Indexing an array with a constant index is only linted if it is out of
+    /// bounds, since rustc checks it.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// // Bad
+    /// debug_assert_eq!(v[i], 1);
+    ///
+    /// // Good
+    /// debug_assert_eq!(v.get(i), Some(&1));
+    /// ```
+    pub DEBUG_ASSERT_INDEXING,
+    pedantic,
+    "indexing that is only done in `debug_assert{,_ne,_eq}!`"
+}
+
+declare_lint_pass!(DebugAssertWithMutCall => [DEBUG_ASSERT_WITH_MUT_CALL, DEBUG_ASSERT_INDEXING]);
 
 const DEBUG_MACRO_NAMES: [&str; 3] = ["debug_assert", "debug_assert_eq", "debug_assert_ne"];
 
@@ -41,6 +68,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
             if is_direct_expn_of(e.span, dmn).is_some() {
                 if let Some(macro_args) = higher::extract_assert_macro_args(e) {
                     for arg in macro_args {
+                        check_indexing(cx, arg, dmn);
                         let mut visitor = MutArgVisitor::new(cx);
                         visitor.visit_expr(arg);
                         if let Some(span) = visitor.expr_span() {
@@ -58,6 +86,88 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
     }
 }
 
+/// Lints the fallible indexing in the argument `arg` of the `debug_assert` macro `dmn`, unless the
+/// same indexing is done outside of the debug assertions.
+fn check_indexing<'tcx>(cx: &LateContext<'tcx>, arg: &'tcx Expr<'_>, dmn: &str) {
+    let mut indexing = IndexVisitor {
+        cx,
+        indexes: Vec::new(),
+        skip_debug_assertions: false,
+    };
+    indexing.visit_expr(arg);
+    if indexing.indexes.is_empty() {
+        return;
+    }
+    let mut outside = IndexVisitor {
+        cx,
+        indexes: Vec::new(),
+        skip_debug_assertions: true,
+    };
+    if let Some(body_id) = cx.enclosing_body {
+        outside.visit_expr(&cx.tcx.hir().body(body_id).value);
+    }
+    for (expr, base, index) in indexing.indexes {
+        if outside.indexes.iter().any(|&(_, outside_base, outside_index)| {
+            SpanlessEq::new(cx).eq_expr(base, outside_base) && SpanlessEq::new(cx).eq_expr(index, outside_index)
+        }) {
+            continue;
+        }
+        span_lint_and_help(
+            cx,
+            DEBUG_ASSERT_INDEXING,
+            expr.span,
+            &format!(
+                "indexing only inside of `{}!`, which panics in debug builds but not in release builds",
+                dmn
+            ),
+            None,
+            &format!(
+                "consider comparing `{}.get({})` in the assertion",
+                snippet(cx, base.span, ".."),
+                snippet(cx, index.span, "..")
+            ),
+        );
+    }
+}
+
+/// Collects the indexing expressions that can panic, with their base and index.
+struct IndexVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    indexes: Vec<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>, &'tcx Expr<'tcx>)>,
+    /// Whether the arguments of the `debug_assert` macros are skipped.
+    skip_debug_assertions: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for IndexVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if self.skip_debug_assertions && DEBUG_MACRO_NAMES.iter().any(|dmn| is_expn_of(expr.span, dmn).is_some()) {
+            return;
+        }
+        if let ExprKind::Index(base, index) = expr.kind {
+            if !is_const_in_bounds(self.cx, base, index) {
+                self.indexes.push((expr, base, index));
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Whether `base` is an array and `index` is a constant in its bounds, which rustc checks.
+fn is_const_in_bounds(cx: &LateContext<'_>, base: &Expr<'_>, index: &Expr<'_>) -> bool {
+    if let ty::Array(_, len) = cx.typeck_results().expr_ty(base).peel_refs().kind() {
+        if let (Some(len), Some(index)) = (len.try_eval_usize(cx.tcx, cx.param_env), const_usize(cx, index)) {
+            return index < u128::from(len);
+        }
+    }
+    false
+}
+
 struct MutArgVisitor<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
     expr_span: Option<Span>,
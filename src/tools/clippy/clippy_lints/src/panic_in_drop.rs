@@ -0,0 +1,176 @@
+//! lint on operations that may panic in `Drop::drop`
+
+use crate::utils::known_len::const_usize;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{higher, in_macro, is_expn_of, trait_ref_of_method};
+use if_chain::if_chain;
+use rustc_hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirId};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `unwrap()` and `expect()` on an `Option` or `Result`,
+    /// indexing, and panicking macros like `panic!()`, `unreachable!()` and `assert!()` in
+    /// implementations of `Drop::drop`.
+    ///
+    /// **Why is this bad?** Values are dropped while the stack is unwound after a panic. A panic in
+    /// `drop` during the unwinding aborts the process, without running the remaining destructors
+    /// or reporting the first panic to a `catch_unwind`.
+    ///
+    /// **Known problems:** Functions called from `drop` aren't checked.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # use std::io::Write;
+    /// struct Log(std::fs::File);
+    ///
+    /// // Bad
+    /// impl Drop for Log {
+    ///     fn drop(&mut self) {
+    ///         self.0.flush().unwrap();
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::io::Write;
+    /// # struct Log(std::fs::File);
+    /// impl Drop for Log {
+    ///     fn drop(&mut self) {
+    ///         if let Err(e) = self.0.flush() {
+    ///             eprintln!("failed to flush the log: {}", e);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub PANIC_IN_DROP,
+    restriction,
+    "`unwrap()`, indexing or `panic!()` in `Drop::drop`"
+}
+
+declare_lint_pass!(PanicInDrop => [PANIC_IN_DROP]);
+
+const PANIC_MACROS: [&str; 7] = [
+    "panic",
+    "unreachable",
+    "todo",
+    "unimplemented",
+    "assert",
+    "assert_eq",
+    "assert_ne",
+];
+
+impl<'tcx> LateLintPass<'tcx> for PanicInDrop {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        _: Span,
+        hir_id: HirId,
+    ) {
+        if_chain! {
+            if matches!(kind, FnKind::Method(..));
+            if let Some(trait_ref) = trait_ref_of_method(cx, hir_id);
+            if let Some(drop_trait) = cx.tcx.lang_items().drop_trait();
+            if trait_ref.trait_def_id() == Some(drop_trait);
+            then {
+                let mut visitor = PanicVisitor { cx, panics: Vec::new() };
+                visitor.visit_expr(&body.value);
+                for (span, msg, help) in visitor.panics {
+                    span_lint_and_then(
+                        cx,
+                        PANIC_IN_DROP,
+                        span,
+                        &msg,
+                        |diag| {
+                            diag.note("a panic in `drop` while the stack is unwound after another panic aborts the process");
+                            diag.help(help);
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+struct PanicVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    /// The operations that may panic, with the lint message and a help message.
+    panics: Vec<(Span, String, &'static str)>,
+}
+
+impl<'a, 'tcx> PanicVisitor<'a, 'tcx> {
+    /// Whether the index `index` of `base` can't be out of bounds, i.e. `base` is an array and
+    /// `index` is a constant smaller than its length, or `index` is `..`.
+    fn is_in_bounds(&self, base: &Expr<'_>, index: &Expr<'_>) -> bool {
+        if matches!(higher::range(index), Some(range) if range.start.is_none() && range.end.is_none()) {
+            return true;
+        }
+        match self.cx.typeck_results().expr_ty(base).peel_refs().kind() {
+            ty::Array(_, len) => const_usize(self.cx, index)
+                .zip(len.try_eval_usize(self.cx.tcx, self.cx.param_env))
+                .map_or(false, |(index, len)| index < u128::from(len)),
+            _ => false,
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for PanicVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let Some((name, span)) = PANIC_MACROS
+            .iter()
+            .find_map(|name| is_expn_of(expr.span, name).map(|span| (name, span)))
+        {
+            // the arguments of the macro aren't checked
+            self.panics.push((
+                span,
+                format!("`{}!()` in `Drop::drop`", name),
+                "consider logging the error instead",
+            ));
+            return;
+        }
+        if in_macro(expr.span) {
+            walk_expr(self, expr);
+            return;
+        }
+        match expr.kind {
+            ExprKind::MethodCall(path, _, [receiver, ..], _)
+                if matches!(&*path.ident.as_str(), "unwrap" | "expect") =>
+            {
+                let ty = self.cx.typeck_results().expr_ty(receiver).peel_refs();
+                if is_type_diagnostic_item(self.cx, ty, sym::option_type)
+                    || is_type_diagnostic_item(self.cx, ty, sym::result_type)
+                {
+                    self.panics.push((
+                        expr.span,
+                        format!("`{}()` may panic in `Drop::drop`", path.ident),
+                        "consider logging the error, or ignoring it with `let _ = ..`",
+                    ));
+                }
+            }
+            ExprKind::Index(base, index) if !self.is_in_bounds(base, index) => {
+                self.panics.push((
+                    expr.span,
+                    "indexing may panic in `Drop::drop`".to_string(),
+                    "consider using `.get()` and handling the `None` case",
+                ));
+            },
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
@@ -0,0 +1,158 @@
+//! lint on indexing, slicing and `unwrap()` in `Display::fmt` and `Debug::fmt`
+
+use crate::utils::known_len::const_usize;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{higher, in_macro, match_def_path, paths, trait_ref_of_method};
+use if_chain::if_chain;
+use rustc_hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirId};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `unwrap()` and `expect()` on an `Option` or `Result`,
+    /// indexing and slicing in implementations of `Display::fmt` and `Debug::fmt`.
+    ///
+    /// **Why is this bad?** Values are formatted in log messages, error reports and panic
+    /// messages, far from the code that broke the invariant. A panic while formatting hides the
+    /// message being written, and a panic while formatting a panic message aborts the process.
+    /// Writing a fallback keeps the output readable.
+    ///
+    /// **Known problems:** Functions called from `fmt` aren't checked.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// struct Name {
+    ///     parts: Vec<String>,
+    /// }
+    ///
+    /// // Bad
+    /// impl fmt::Display for Name {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "{}", self.parts[0])
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::fmt;
+    /// # struct Name {
+    /// #     parts: Vec<String>,
+    /// # }
+    /// impl fmt::Display for Name {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         match self.parts.first() {
+    ///             Some(first) => write!(f, "{}", first),
+    ///             None => write!(f, "<unnamed>"),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub PANIC_IN_FMT,
+    restriction,
+    "`unwrap()`, indexing or slicing in `Display::fmt` or `Debug::fmt`"
+}
+
+declare_lint_pass!(PanicInFmt => [PANIC_IN_FMT]);
+
+impl<'tcx> LateLintPass<'tcx> for PanicInFmt {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        hir_id: HirId,
+    ) {
+        if_chain! {
+            if matches!(kind, FnKind::Method(..));
+            if !in_macro(span);
+            if let Some(trait_ref) = trait_ref_of_method(cx, hir_id);
+            if let Some(trait_id) = trait_ref.trait_def_id();
+            if let Some(trait_name) = if match_def_path(cx, trait_id, &paths::DISPLAY_TRAIT) {
+                Some("Display")
+            } else if match_def_path(cx, trait_id, &paths::DEBUG_TRAIT) {
+                Some("Debug")
+            } else {
+                None
+            };
+            then {
+                let mut visitor = PanicVisitor { cx, panics: Vec::new() };
+                visitor.visit_expr(&body.value);
+                for (span, what) in visitor.panics {
+                    span_lint_and_then(
+                        cx,
+                        PANIC_IN_FMT,
+                        span,
+                        &format!("{} may panic in `{}::fmt`", what, trait_name),
+                        |diag| {
+                            diag.note("a panic while formatting hides the message being written");
+                            diag.help("consider writing a fallback with `write!(f, ..)` instead");
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+struct PanicVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    /// The operations that may panic, with a description for the lint message.
+    panics: Vec<(Span, String)>,
+}
+
+impl<'a, 'tcx> PanicVisitor<'a, 'tcx> {
+    /// Whether the index `index` of `base` can't be out of bounds, i.e. `base` is an array and
+    /// `index` is a constant smaller than its length.
+    fn is_in_bounds(&self, base: &Expr<'_>, index: &Expr<'_>) -> bool {
+        match self.cx.typeck_results().expr_ty(base).peel_refs().kind() {
+            ty::Array(_, len) => const_usize(self.cx, index)
+                .zip(len.try_eval_usize(self.cx.tcx, self.cx.param_env))
+                .map_or(false, |(index, len)| index < u128::from(len)),
+            _ => false,
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for PanicVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if in_macro(expr.span) {
+            walk_expr(self, expr);
+            return;
+        }
+        match expr.kind {
+            ExprKind::MethodCall(path, _, [receiver, ..], _)
+                if matches!(&*path.ident.as_str(), "unwrap" | "expect") =>
+            {
+                let ty = self.cx.typeck_results().expr_ty(receiver).peel_refs();
+                if is_type_diagnostic_item(self.cx, ty, sym::option_type)
+                    || is_type_diagnostic_item(self.cx, ty, sym::result_type)
+                {
+                    self.panics.push((expr.span, format!("`{}()`", path.ident)));
+                }
+            }
+            ExprKind::Index(base, index) => match higher::range(index) {
+                Some(range) if range.start.is_none() && range.end.is_none() => {},
+                Some(_) => self.panics.push((expr.span, "slicing".to_string())),
+                None if !self.is_in_bounds(base, index) => self.panics.push((expr.span, "indexing".to_string())),
+                None => {},
+            },
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
@@ -1,5 +1,5 @@
-use clippy_utils::diagnostics::span_lint;
-use clippy_utils::{is_expn_of, match_panic_call};
+use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
+use clippy_utils::{is_expn_of, is_lib_crate, match_panic_call};
 use if_chain::if_chain;
 use rustc_hir::Expr;
 use rustc_lint::{LateContext, LateLintPass};
@@ -70,25 +70,72 @@
     "usage of the `unreachable!` macro"
 }
 
-declare_lint_pass!(PanicUnimplemented => [UNIMPLEMENTED, UNREACHABLE, TODO, PANIC]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `panic!`, `unimplemented!`, `todo!` and
+    /// `unreachable!` in crates that are not compiled as an executable.
+    ///
+    /// **Why is this bad?** A library can't know whether its caller is able to recover from an
+    /// error. Returning a `Result` or an `Option` leaves that decision to the caller, a panic
+    /// takes it away.
+    ///
+    /// **Known problems:** Test harnesses are executables, so `#[cfg(test)]` code of a library
+    /// is only checked when the library itself is compiled.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// pub fn parse_digit(c: char) -> u32 {
+    ///     match c.to_digit(10) {
+    ///         Some(d) => d,
+    ///         None => panic!("not a digit: {}", c),
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// pub fn parse_digit(c: char) -> Option<u32> {
+    ///     c.to_digit(10)
+    /// }
+    /// ```
+    pub PANIC_IN_LIBRARY,
+    restriction,
+    "usage of `panic!` and related macros in a library crate"
+}
+
+declare_lint_pass!(PanicUnimplemented => [UNIMPLEMENTED, UNREACHABLE, TODO, PANIC, PANIC_IN_LIBRARY]);
 
 impl<'tcx> LateLintPass<'tcx> for PanicUnimplemented {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if match_panic_call(cx, expr).is_some() && is_expn_of(expr.span, "debug_assert").is_none() {
             let span = get_outer_span(expr);
-            if is_expn_of(expr.span, "unimplemented").is_some() {
+            let name = if is_expn_of(expr.span, "unimplemented").is_some() {
                 span_lint(
                     cx,
                     UNIMPLEMENTED,
                     span,
                     "`unimplemented` should not be present in production code",
                 );
+                "unimplemented"
             } else if is_expn_of(expr.span, "todo").is_some() {
                 span_lint(cx, TODO, span, "`todo` should not be present in production code");
+                "todo"
             } else if is_expn_of(expr.span, "unreachable").is_some() {
                 span_lint(cx, UNREACHABLE, span, "usage of the `unreachable!` macro");
+                "unreachable"
             } else if is_expn_of(expr.span, "panic").is_some() {
                 span_lint(cx, PANIC, span, "`panic` should not be present in production code");
+                "panic"
+            } else {
+                return;
+            };
+            if is_lib_crate(cx) {
+                span_lint_and_help(
+                    cx,
+                    PANIC_IN_LIBRARY,
+                    span,
+                    &format!("usage of `{}!` in a library crate", name),
+                    None,
+                    "consider returning a `Result` or an `Option` instead",
+                );
             }
         }
     }
@@ -0,0 +1,301 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{find_macro_calls, is_expn_of, trait_ref_of_method};
+use rustc_ast::ast::Attribute;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{AnonConst, Body, Expr, ExprKind, ImplItem, ImplItemKind, TraitRef};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty::{self, Instance};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `unwrap()`, `expect()` and indexing in implementations of
+    /// `Index` and `IndexMut`, unless the panic is documented in a `# Panics` section or the type
+    /// has a `get` method.
+    ///
+    /// **Why is this bad?** Indexing with `[]` looks the same for every type, so the panic is
+    /// hidden from the users of the type. Without a `get`-style accessor, they can't look up an
+    /// element that might be missing without panicking.
+    ///
+    /// **Known problems:** Functions called from the implementation may panic as well. This is
+    /// not checked.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use std::ops::Index;
+    ///
+    /// struct Registry {
+    ///     names: Vec<String>,
+    /// }
+    ///
+    /// impl Index<usize> for Registry {
+    ///     type Output = String;
+    ///
+    ///     fn index(&self, id: usize) -> &String {
+    ///         &self.names[id]
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::ops::Index;
+    /// # struct Registry {
+    /// #     names: Vec<String>,
+    /// # }
+    /// impl Registry {
+    ///     pub fn get(&self, id: usize) -> Option<&String> {
+    ///         self.names.get(id)
+    ///     }
+    /// }
+    ///
+    /// impl Index<usize> for Registry {
+    ///     type Output = String;
+    ///
+    ///     fn index(&self, id: usize) -> &String {
+    ///         &self.names[id]
+    ///     }
+    /// }
+    /// ```
+    pub PANICKING_INDEX_IMPL,
+    restriction,
+    "`unwrap()`, `expect()` or indexing in an `Index` or `IndexMut` implementation"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for indexing a type from outside of the standard library whose
+    /// `Index` or `IndexMut` implementation documents a panic in a `# Panics` section, or calls
+    /// `panic!`, `assert!` or a similar macro.
+    ///
+    /// **Why is this bad?** Like indexing a slice out of bounds, the indexing panics on some
+    /// values, but `[]` doesn't show it. `indexing_slicing` only checks slices, arrays and `Vec`s.
+    ///
+    /// **Known problems:** Functions called from the implementation may panic as well. This is
+    /// not checked. The implementations of other crates are only checked for a `# Panics` section.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use std::ops::Index;
+    ///
+    /// struct Grid {
+    ///     cells: Vec<u8>,
+    /// }
+    ///
+    /// impl Grid {
+    ///     fn get(&self, i: usize) -> Option<&u8> {
+    ///         self.cells.get(i)
+    ///     }
+    /// }
+    ///
+    /// impl Index<usize> for Grid {
+    ///     type Output = u8;
+    ///
+    ///     /// # Panics
+    ///     ///
+    ///     /// Panics if `i` is out of bounds.
+    ///     fn index(&self, i: usize) -> &u8 {
+    ///         &self.cells[i]
+    ///     }
+    /// }
+    ///
+    /// # let grid = Grid { cells: vec![0] };
+    /// // Bad
+    /// let cell = grid[0];
+    ///
+    /// // Good
+    /// let cell = grid.get(0);
+    /// ```
+    pub PANICKING_USER_INDEX,
+    restriction,
+    "indexing a type whose `Index` implementation documents a panic or calls `panic!`"
+}
+
+declare_lint_pass!(PanickingIndexImpl => [PANICKING_INDEX_IMPL, PANICKING_USER_INDEX]);
+
+impl<'tcx> LateLintPass<'tcx> for PanickingIndexImpl {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let base = match expr.kind {
+            ExprKind::Index(base, _) if !expr.span.from_expansion() => base,
+            _ => return,
+        };
+        // indexing slices and arrays is built in, other types call their `Index` implementation
+        let (trait_method, substs) = match cx.typeck_results().type_dependent_def(expr.hir_id) {
+            Some((_, def_id)) => (def_id, cx.typeck_results().node_substs(expr.hir_id)),
+            None => return,
+        };
+        let method = match Instance::resolve(cx.tcx, cx.param_env, trait_method, substs) {
+            Ok(Some(instance)) => instance.def_id(),
+            _ => return,
+        };
+        if matches!(&*cx.tcx.crate_name(method.krate).as_str(), "core" | "alloc" | "std") {
+            return;
+        }
+        let impl_id = match cx.tcx.impl_of_method(method) {
+            Some(impl_id) => impl_id,
+            None => return,
+        };
+
+        let documented = has_panics_section(cx.tcx.get_attrs(method)) || has_panics_section(cx.tcx.get_attrs(impl_id));
+        let panics = match method.as_local() {
+            Some(local) if !documented => match cx
+                .tcx
+                .hir()
+                .maybe_body_owned_by(cx.tcx.hir().local_def_id_to_hir_id(local))
+            {
+                Some(body_id) => panic_macro_calls(cx.tcx.hir().body(body_id)),
+                None => return,
+            },
+            _ => Vec::new(),
+        };
+        if !documented && panics.is_empty() {
+            return;
+        }
+
+        let trait_name = if cx.tcx.lang_items().index_mut_trait() == cx.tcx.trait_of_item(trait_method) {
+            "IndexMut"
+        } else {
+            "Index"
+        };
+        let ty = cx.typeck_results().expr_ty(base).peel_refs();
+        span_lint_and_then(
+            cx,
+            PANICKING_USER_INDEX,
+            expr.span,
+            &format!("indexing `{}` may panic", ty),
+            |diag| {
+                if documented {
+                    diag.span_note(
+                        cx.tcx.def_span(method),
+                        &format!("the `{}` implementation documents a panic", trait_name),
+                    );
+                } else {
+                    diag.span_note(panics, &format!("the `{}` implementation panics here", trait_name));
+                }
+                if has_get_method(cx, ty) {
+                    diag.help(&format!("consider using `{}::get` instead", ty));
+                }
+            },
+        );
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, impl_item: &'tcx ImplItem<'_>) {
+        let (sig, body_id) = match impl_item.kind {
+            ImplItemKind::Fn(ref sig, body_id) => (sig, body_id),
+            _ => return,
+        };
+        let trait_id = match trait_ref_of_method(cx, impl_item.hir_id()).and_then(TraitRef::trait_def_id) {
+            Some(trait_id) => trait_id,
+            None => return,
+        };
+        let lang_items = cx.tcx.lang_items();
+        let trait_name = if lang_items.index_trait() == Some(trait_id) {
+            "Index"
+        } else if lang_items.index_mut_trait() == Some(trait_id) {
+            "IndexMut"
+        } else {
+            return;
+        };
+
+        let impl_id = cx.tcx.hir().get_parent_item(impl_item.hir_id());
+        if has_panics_section(cx.tcx.hir().attrs(impl_item.hir_id()))
+            || has_panics_section(cx.tcx.hir().attrs(impl_id))
+            || has_get_method(cx, cx.tcx.type_of(cx.tcx.hir().local_def_id(impl_id)))
+        {
+            return;
+        }
+
+        let mut finder = FindPanics {
+            cx,
+            typeck_results: cx.tcx.typeck_body(body_id),
+            panics: Vec::new(),
+        };
+        finder.visit_expr(&cx.tcx.hir().body(body_id).value);
+        if finder.panics.is_empty() {
+            return;
+        }
+
+        span_lint_and_then(
+            cx,
+            PANICKING_INDEX_IMPL,
+            sig.span,
+            &format!("this `{}` implementation can panic", trait_name),
+            |diag| {
+                diag.span_note(finder.panics, "the panic originates here");
+                diag.help(
+                    "document when it panics in a `# Panics` section, or add a `get` method returning an `Option`",
+                );
+            },
+        );
+    }
+}
+
+/// Returns the calls of `panic!` and of the macros panicking on a condition in `body`.
+fn panic_macro_calls(body: &Body<'_>) -> Vec<Span> {
+    const PANIC_MACROS: [&str; 6] = ["panic", "assert", "assert_eq", "assert_ne", "todo", "unimplemented"];
+    let mut panics: Vec<Span> = find_macro_calls(&PANIC_MACROS, body)
+        .into_iter()
+        .filter(|span| is_expn_of(*span, "debug_assert").is_none())
+        .filter_map(|span| PANIC_MACROS.iter().find_map(|name| is_expn_of(span, name)))
+        .collect();
+    panics.dedup();
+    panics
+}
+
+fn has_panics_section(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter_map(Attribute::doc_str)
+        .any(|doc| doc.as_str().lines().any(|line| line.trim() == "# Panics"))
+}
+
+/// Whether the ADT `ty` has an inherent `get` method.
+fn has_get_method(cx: &LateContext<'_>, ty: ty::Ty<'_>) -> bool {
+    match ty.kind() {
+        ty::Adt(adt, _) => cx.tcx.inherent_impls(adt.did).iter().any(|&impl_id| {
+            cx.tcx
+                .associated_items(impl_id)
+                .filter_by_name_unhygienic(sym!(get))
+                .any(|item| item.kind == ty::AssocKind::Fn)
+        }),
+        _ => false,
+    }
+}
+
+struct FindPanics<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    typeck_results: &'tcx ty::TypeckResults<'tcx>,
+    panics: Vec<Span>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for FindPanics<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        match expr.kind {
+            _ if expr.span.from_expansion() => {},
+            ExprKind::MethodCall(path, _, [receiver, ..], _)
+                if path.ident.name == sym::unwrap || path.ident.name == sym::expect =>
+            {
+                let receiver_ty = self.typeck_results.expr_ty(receiver).peel_refs();
+                if is_type_diagnostic_item(self.cx, receiver_ty, sym::option_type)
+                    || is_type_diagnostic_item(self.cx, receiver_ty, sym::result_type)
+                {
+                    self.panics.push(expr.span);
+                }
+            }
+            ExprKind::Index(..) => self.panics.push(expr.span),
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    // Panics in const blocks will cause compilation to fail.
+    fn visit_anon_const(&mut self, _: &'tcx AnonConst) {}
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
@@ -0,0 +1,273 @@
+//! lint on using a whole array buffer after `read` filled only a part of it
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::{higher, match_trait_method, path_to_local, path_to_local_id, paths};
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{BinOpKind, Block, Expr, ExprKind, HirId, Mutability, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty;
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::symbol::Ident;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for array buffers that are filled with `Read::read`, whose count of
+    /// read bytes is bound to a variable, and then used as a whole, like `&buf[..]` or `&buf`.
+    ///
+    /// **Why is this bad?** `read` may fill only a prefix of the buffer. The rest holds its initial
+    /// zeroes or the data of a previous read, which is then used as if it had just been read, e.g.
+    /// written to a file or sent over the network.
+    ///
+    /// **Known problems:** Only the statements following the `read` in the same block are checked.
+    /// Comparing the count to anything but `0` is assumed to check that the buffer is full. The count
+    /// isn't followed through later control flow, so a short read handled by a loop accumulating
+    /// the count or by a function it is passed to is reported as well.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # use std::io::{Read, Write};
+    /// # fn f(mut input: impl Read, mut output: impl Write) -> std::io::Result<()> {
+    /// let mut buf = [0u8; 64];
+    /// let n = input.read(&mut buf)?;
+    /// output.write_all(&buf[..])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::io::{Read, Write};
+    /// # fn f(mut input: impl Read, mut output: impl Write) -> std::io::Result<()> {
+    /// let mut buf = [0u8; 64];
+    /// let n = input.read(&mut buf)?;
+    /// output.write_all(&buf[..n])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub PARTIALLY_READ_BUFFER,
+    nursery,
+    "using a whole array buffer after `read` filled it, instead of the part that was read"
+}
+
+declare_lint_pass!(PartiallyReadBuffer => [PARTIALLY_READ_BUFFER]);
+
+impl<'tcx> LateLintPass<'tcx> for PartiallyReadBuffer {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            let (count, count_ident, init) = match stmt.kind {
+                StmtKind::Local(local) if !stmt.span.from_expansion() => match (&local.pat.kind, local.init) {
+                    (&PatKind::Binding(_, count, ident, None), Some(init)) => (count, ident, init),
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if !matches!(cx.typeck_results().node_type(count).kind(), ty::Uint(_)) {
+                continue;
+            }
+            let mut read_finder = ReadFinder { cx, found: None };
+            read_finder.visit_expr(init);
+            let (read, buf) = match read_finder.found {
+                Some(found) => found,
+                None => continue,
+            };
+
+            let mut visitor = WholeUseVisitor {
+                cx,
+                buf,
+                count,
+                found: None,
+                done: false,
+            };
+            for stmt in &block.stmts[i + 1..] {
+                visitor.visit_stmt(stmt);
+                if visitor.done {
+                    break;
+                }
+            }
+            if let (Some(expr), false) = (block.expr, visitor.done) {
+                visitor.visit_expr(expr);
+            }
+            if let Some(whole_use) = visitor.found {
+                lint(cx, whole_use, read, count_ident);
+            }
+        }
+    }
+}
+
+fn lint(cx: &LateContext<'_>, whole_use: &Expr<'_>, read: &Expr<'_>, count: Ident) {
+    span_lint_and_then(
+        cx,
+        PARTIALLY_READ_BUFFER,
+        whole_use.span,
+        "using the whole buffer, but `read` may have filled only a part of it",
+        |diag| {
+            diag.span_note(read.span, "the number of bytes read is returned here");
+            let mut applicability = Applicability::MaybeIncorrect;
+            let sugg = match whole_use.kind {
+                ExprKind::AddrOf(_, _, inner) => {
+                    let buf = match inner.kind {
+                        ExprKind::Index(buf, _) => buf,
+                        _ => inner,
+                    };
+                    format!(
+                        "&{}[..{}]",
+                        snippet_with_applicability(cx, buf.span, "..", &mut applicability),
+                        count
+                    )
+                },
+                ExprKind::MethodCall(path, _, [buf], _) => format!(
+                    "{}[..{}].{}()",
+                    snippet_with_applicability(cx, buf.span, "..", &mut applicability),
+                    count,
+                    path.ident
+                ),
+                _ => return,
+            };
+            diag.span_suggestion(whole_use.span, "use only the part that was read", sugg, applicability);
+        },
+    );
+}
+
+/// Returns the local array that `expr` refers to, directly or as a full range, like `buf` or
+/// `buf[..]`.
+fn whole_array(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<HirId> {
+    let array = match expr.kind {
+        ExprKind::Index(array, index) => match higher::range(index) {
+            Some(higher::Range {
+                start: None, end: None, ..
+            }) => array,
+            _ => return None,
+        },
+        _ => expr,
+    };
+    match cx.typeck_results().expr_ty(array).kind() {
+        ty::Array(..) => path_to_local(array),
+        _ => None,
+    }
+}
+
+/// Finds a `Read::read` call filling a whole local array, like `r.read(&mut buf)`.
+struct ReadFinder<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    /// The call and the local array.
+    found: Option<(&'tcx Expr<'tcx>, HirId)>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for ReadFinder<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::MethodCall(path, _, [_, arg], _) = expr.kind {
+            if let ExprKind::AddrOf(_, Mutability::Mut, buf) = arg.kind {
+                if path.ident.as_str() == "read" && match_trait_method(self.cx, expr, &paths::IO_READ) {
+                    if let Some(buf) = whole_array(self.cx, buf) {
+                        self.found = Some((expr, buf));
+                        return;
+                    }
+                }
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Finds the first use of the whole buffer `buf`, like `&buf[..]`, `&buf` or `buf.to_vec()`,
+/// stopping at a mutation of the buffer or at a comparison of the count of read bytes `count`.
+struct WholeUseVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    buf: HirId,
+    count: HirId,
+    found: Option<&'tcx Expr<'tcx>>,
+    done: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for WholeUseVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if self.done {
+            return;
+        }
+        match expr.kind {
+            ExprKind::AddrOf(_, mutability, inner) if whole_array(self.cx, inner) == Some(self.buf) => {
+                if mutability == Mutability::Not {
+                    self.found = Some(expr);
+                }
+                self.done = true;
+                return;
+            },
+            ExprKind::MethodCall(path, _, [receiver, ..], _) if is_buf_place(receiver, self.buf) => {
+                let borrows_mut = self
+                    .cx
+                    .typeck_results()
+                    .expr_adjustments(receiver)
+                    .iter()
+                    .any(|adjustment| {
+                        matches!(
+                            adjustment.kind,
+                            Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Mut { .. }))
+                        )
+                    });
+                if borrows_mut {
+                    self.done = true;
+                    return;
+                }
+                if path.ident.as_str() == "to_vec" && path_to_local_id(receiver, self.buf) {
+                    self.found = Some(expr);
+                    self.done = true;
+                    return;
+                }
+            },
+            ExprKind::Assign(place, ..) | ExprKind::AssignOp(_, place, _) if is_buf_place(place, self.buf) => {
+                self.done = true;
+                return;
+            },
+            ExprKind::Binary(op, left, right) if is_comparison(op.node) => {
+                let other = if path_to_local_id(left, self.count) {
+                    Some(right)
+                } else if path_to_local_id(right, self.count) {
+                    Some(left)
+                } else {
+                    None
+                };
+                if other.map_or(false, |other| !is_zero(other)) {
+                    self.done = true;
+                    return;
+                }
+            },
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
+
+/// Whether `place` is the local `buf` or a part of it, like `buf[i]` or `buf[n..]`.
+fn is_buf_place(place: &Expr<'_>, buf: HirId) -> bool {
+    match place.kind {
+        ExprKind::Index(array, _) => path_to_local_id(array, buf),
+        _ => path_to_local_id(place, buf),
+    }
+}
+
+fn is_comparison(op: BinOpKind) -> bool {
+    matches!(
+        op,
+        BinOpKind::Eq | BinOpKind::Ne | BinOpKind::Lt | BinOpKind::Le | BinOpKind::Gt | BinOpKind::Ge
+    )
+}
+
+fn is_zero(expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Lit(ref lit) if matches!(lit.node, LitKind::Int(0, _)))
+}
@@ -0,0 +1,100 @@
+//! Support for `--filter`, which only runs the lint passes of the given lints.
+//!
+//! The passes are collected in a `PassRegistry` together with the lints they declare in
+//! `declare_lint_pass!` or `impl_lint_pass!`, and only the passes that can emit one of the selected
+//! lints are added to the lint store. The pre-expansion passes are always registered.
+//!
+//! The late passes that need to see every item of the crate are registered with
+//! `register_whole_crate_late_pass`, so that `cargo clippy --cache` never skips items for them.
+
+use crate::lint_cache;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::sync;
+use rustc_lint::{EarlyLintPass, LateLintPass, LintArray, LintId, LintStore};
+use rustc_session::Session;
+
+type EarlyLintPassObject = Box<dyn EarlyLintPass + sync::Send + sync::Sync>;
+type LateLintPassObject = Box<dyn for<'tcx> LateLintPass<'tcx> + sync::Send + sync::Sync>;
+type Ctor<T> = Box<dyn Fn() -> T + sync::Send + sync::Sync>;
+
+enum PassCtor {
+    Early(Ctor<EarlyLintPassObject>),
+    Late(Ctor<LateLintPassObject>),
+    /// A late pass that needs to see every item of the crate.
+    WholeCrateLate(Ctor<LateLintPassObject>),
+}
+
+/// The lint passes, with the lints they emit, in the order they were registered.
+#[derive(Default)]
+pub struct PassRegistry {
+    passes: Vec<(LintArray, PassCtor)>,
+}
+
+impl PassRegistry {
+    pub fn register_early_pass(
+        &mut self,
+        lints: LintArray,
+        pass: impl Fn() -> EarlyLintPassObject + 'static + sync::Send + sync::Sync,
+    ) {
+        self.passes.push((lints, PassCtor::Early(Box::new(pass))));
+    }
+
+    pub fn register_late_pass(
+        &mut self,
+        lints: LintArray,
+        pass: impl Fn() -> LateLintPassObject + 'static + sync::Send + sync::Sync,
+    ) {
+        self.passes.push((lints, PassCtor::Late(Box::new(pass))));
+    }
+
+    /// Like `register_late_pass`, for a pass that collects information from all items of the crate
+    /// and lints in `check_crate_post`. The lint cache doesn't skip any items for these passes.
+    pub fn register_whole_crate_late_pass(
+        &mut self,
+        lints: LintArray,
+        pass: impl Fn() -> LateLintPassObject + 'static + sync::Send + sync::Sync,
+    ) {
+        self.passes.push((lints, PassCtor::WholeCrateLate(Box::new(pass))));
+    }
+
+    /// Adds the passes to `store`. With a `filter`, only the passes emitting one of the lints in it
+    /// are added, and the passes that don't declare any lints.
+    pub fn register(self, store: &mut LintStore, sess: &Session, filter: Option<&[String]>) {
+        let selected = filter.map(|names| selected_lints(store, sess, names));
+        for (lints, ctor) in self.passes {
+            let is_selected = selected.as_ref().map_or(true, |selected| {
+                lints.is_empty() || lints.iter().any(|&lint| selected.contains(&LintId::of(lint)))
+            });
+            if !is_selected {
+                continue;
+            }
+            match ctor {
+                PassCtor::Early(ctor) => store.register_early_pass(ctor),
+                PassCtor::Late(ctor) => store.register_late_pass(ctor),
+                PassCtor::WholeCrateLate(ctor) => {
+                    lint_cache::add_whole_crate_pass(ctor().name());
+                    store.register_late_pass(ctor);
+                },
+            }
+        }
+    }
+}
+
+/// Returns the lints named in `names`, expanding the lint groups. The `clippy::` prefix is
+/// optional, and unknown names are reported as errors.
+fn selected_lints(store: &LintStore, sess: &Session, names: &[String]) -> FxHashSet<LintId> {
+    let mut selected = FxHashSet::default();
+    for name in names {
+        let name = name.trim().to_lowercase().replace('-', "_");
+        let name = if name.contains("::") {
+            name
+        } else {
+            format!("clippy::{}", name)
+        };
+        match store.find_lints(&name) {
+            Ok(lints) => selected.extend(lints),
+            Err(_) => sess.err(&format!("unknown lint `{}` passed to `--filter`", name)),
+        }
+    }
+    selected
+}
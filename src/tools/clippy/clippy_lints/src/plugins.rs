@@ -0,0 +1,145 @@
+//! Out-of-tree lint plugins, loaded from the dynamic libraries listed in the `plugins` key of the
+//! configuration file:
+//!
+//! ```toml
+//! plugins = ["../target/release/libmy_lints.so"]
+//! ```
+//!
+//! Relative paths are resolved against the directory of the configuration file. A plugin is a
+//! `dylib` crate that depends on `clippy_lints` and `clippy_utils`, so its lint passes can use the
+//! same utilities as Clippy's own ones, and declares its registrar function with `clippy_plugin!`:
+//!
+//! ```rust,ignore
+//! #![feature(rustc_private)]
+//!
+//! extern crate rustc_hir;
+//! extern crate rustc_lint;
+//! #[macro_use]
+//! extern crate rustc_session;
+//!
+//! use clippy_lints::plugins::Registry;
+//! use clippy_utils::diagnostics::span_lint;
+//! use rustc_hir::{Expr, ExprKind};
+//! use rustc_lint::{LateContext, LateLintPass};
+//!
+//! declare_tool_lint! {
+//!     pub clippy::NO_LOOPS, Warn, "usage of `loop`"
+//! }
+//!
+//! declare_lint_pass!(NoLoops => [NO_LOOPS]);
+//!
+//! impl LateLintPass<'_> for NoLoops {
+//!     fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
+//!         if let ExprKind::Loop(..) = expr.kind {
+//!             span_lint(cx, NO_LOOPS, expr.span, "loops are not allowed here");
+//!         }
+//!     }
+//! }
+//!
+//! clippy_lints::clippy_plugin!(register);
+//!
+//! fn register(reg: &mut Registry<'_>) {
+//!     reg.lint_store.register_lints(&[&NO_LOOPS]);
+//!     reg.lint_store.register_late_pass(|| Box::new(NoLoops));
+//! }
+//! ```
+//!
+//! Rust has no stable ABI, so a plugin has to be built with the same compiler and the same
+//! versions of `clippy_lints` and `clippy_utils` as the `clippy-driver` loading it. Only the
+//! version of this interface, `API_VERSION`, is checked when loading a plugin.
+
+use crate::Conf;
+use rustc_lint::LintStore;
+use rustc_metadata::dynamic_lib::DynamicLibrary;
+use rustc_session::Session;
+use std::env;
+use std::mem;
+use std::path::{Path, PathBuf};
+
+/// The version of the plugin interface. It is increased whenever `Registry` or the symbols exported
+/// by `clippy_plugin!` change.
+pub const API_VERSION: u32 = 1;
+
+/// What a plugin registers its lints and lint passes with.
+pub struct Registry<'a> {
+    /// The store of the lints, lint groups and lint passes of the compiler session.
+    pub lint_store: &'a mut LintStore,
+    pub sess: &'a Session,
+    /// Clippy's configuration, as read from the configuration file.
+    pub conf: &'a Conf,
+}
+
+/// The registrar function of a plugin, which is exported by `clippy_plugin!`.
+pub type RegistrarFn = fn(&mut Registry<'_>);
+
+/// Exports the registrar function of a plugin, together with the `API_VERSION` it was built with.
+#[macro_export]
+macro_rules! clippy_plugin {
+    ($registrar:path) => {
+        #[no_mangle]
+        pub static CLIPPY_PLUGIN_API_VERSION: u32 = $crate::plugins::API_VERSION;
+
+        #[no_mangle]
+        pub fn clippy_plugin_registrar(reg: &mut $crate::plugins::Registry<'_>) {
+            $registrar(reg)
+        }
+    };
+}
+
+/// Loads the plugins listed in the configuration file and lets them register their lints. A plugin
+/// that can't be loaded is reported as an error, but doesn't stop the other ones from loading.
+///
+/// Used in `./src/driver.rs`.
+pub fn load(store: &mut LintStore, sess: &Session, conf: &Conf) {
+    if conf.plugins.is_empty() {
+        return;
+    }
+
+    let conf_dir = match crate::conf_file_path(sess) {
+        Ok(Some(conf_file)) => conf_file.parent().map(Path::to_path_buf).unwrap_or_default(),
+        _ => PathBuf::new(),
+    };
+    for plugin in &conf.plugins {
+        // the path must not be a bare file name, which would be looked up in the library path
+        let path = env::current_dir().unwrap_or_default().join(&conf_dir).join(plugin);
+        match load_registrar(&path) {
+            Ok(registrar) => registrar(&mut Registry {
+                lint_store: store,
+                sess,
+                conf,
+            }),
+            Err(error) => sess.err(&format!(
+                "could not load the Clippy plugin `{}`: {}",
+                path.display(),
+                error
+            )),
+        }
+    }
+}
+
+/// Opens the dynamic library at `path` and returns its registrar function.
+fn load_registrar(path: &Path) -> Result<RegistrarFn, String> {
+    if !path.is_file() {
+        return Err(String::from("no such file"));
+    }
+
+    let lib = DynamicLibrary::open(path)?;
+    // SAFETY: the symbols are the ones exported by `clippy_plugin!`, whose types match as long as
+    // the plugin was built for the same `API_VERSION`.
+    unsafe {
+        let version = *lib.symbol::<u32>("CLIPPY_PLUGIN_API_VERSION")?;
+        if version != API_VERSION {
+            return Err(format!(
+                "the plugin was built for version {} of the plugin interface, but Clippy uses version {}",
+                version, API_VERSION
+            ));
+        }
+        let registrar = mem::transmute::<*mut u8, RegistrarFn>(lib.symbol("clippy_plugin_registrar")?);
+
+        // The library is never unloaded, as the lints and passes it registers live as long as the
+        // compiler session.
+        mem::forget(lib);
+
+        Ok(registrar)
+    }
+}
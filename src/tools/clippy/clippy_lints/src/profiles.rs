@@ -0,0 +1,109 @@
+//! Lint profiles: named sets of lint levels and configuration values, selected with
+//! `cargo clippy --profile=<name>`. Clippy has the built-in profiles `strict`, `embedded` and `ci`,
+//! and more can be defined in the `profiles` table of the configuration file:
+//!
+//! ```toml
+//! [profiles.review]
+//! lints = { "clippy::pedantic" = "warn", module_name_repetitions = "allow" }
+//! config = { too-many-lines-threshold = 50 }
+//! ```
+//!
+//! Lint names without a tool prefix are Clippy lints. The lint levels are applied in order, as if
+//! they were passed on the command line before all other lint flags, so `-A`/`-W`/`-D` flags and
+//! attributes in the code take precedence. The configuration values replace the ones in the
+//! configuration file. A profile in the configuration file replaces a built-in one with the same
+//! name.
+
+use crate::utils::conf::{self, ProfileConf};
+use rustc_lint::Level;
+use std::path::Path;
+
+/// The built-in profiles, written like an entry of the `profiles` table.
+const BUILTIN_PROFILES: &[(&str, &str)] = &[
+    (
+        "strict",
+        r#"
+lints = { "clippy::pedantic" = "warn", "clippy::nursery" = "warn" }
+config = { cognitive-complexity-threshold = 15, too-many-arguments-threshold = 5, too-many-lines-threshold = 60 }
+"#,
+    ),
+    (
+        "embedded",
+        r#"
+lints = { "clippy::panic_free" = "warn", large_stack_arrays = "warn", large_types_passed_by_value = "warn" }
+config = { too-large-for-stack = 64, array-size-threshold = 4096, pass-by-value-size-limit = 64 }
+"#,
+    ),
+    (
+        "ci",
+        r#"
+lints = { "clippy::all" = "deny", dbg_macro = "deny", todo = "deny", unimplemented = "deny" }
+"#,
+    ),
+];
+
+/// A lint profile, see the module documentation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Profile {
+    pub name: String,
+    pub conf: ProfileConf,
+}
+
+impl Profile {
+    /// Finds the profile `name` in the configuration file of the crate whose root is `crate_file`,
+    /// or among the built-in profiles.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if there is no profile `name`.
+    pub fn find(name: &str, crate_file: Option<&Path>) -> Result<Self, String> {
+        // errors finding or reading the configuration file are reported by `read_conf`
+        if let Ok(Some(conf_file)) = crate::find_conf_file(crate_file) {
+            let profiles = conf::read(&conf_file).conf.profiles;
+            if let Some((_, conf)) = profiles.0.into_iter().find(|(profile, _)| profile == name) {
+                return Ok(Self {
+                    name: name.to_string(),
+                    conf,
+                });
+            }
+        }
+        Self::builtin(name).ok_or_else(|| {
+            format!(
+                "unknown Clippy profile `{}`, the built-in profiles are {}",
+                name,
+                BUILTIN_PROFILES
+                    .iter()
+                    .map(|(name, _)| format!("`{}`", name))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })
+    }
+
+    fn builtin(name: &str) -> Option<Self> {
+        BUILTIN_PROFILES
+            .iter()
+            .find(|(builtin, _)| *builtin == name)
+            .map(|(_, content)| Self {
+                name: name.to_string(),
+                conf: toml::from_str(content).expect("invalid built-in profile"),
+            })
+    }
+
+    /// The lint levels of the profile as command-line lint options.
+    pub fn lint_opts(&self) -> Vec<(String, Level)> {
+        self.conf
+            .lints
+            .0
+            .iter()
+            .map(|(name, level)| {
+                let name = if name.contains("::") {
+                    name.clone()
+                } else {
+                    format!("clippy::{}", name)
+                };
+                (name, level.level())
+            })
+            .collect()
+    }
+}
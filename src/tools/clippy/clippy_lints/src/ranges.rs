@@ -104,7 +104,7 @@
     /// are constant and `x` is greater or equal to `y`.
     ///
     /// **Why is this bad?** Empty ranges yield no values so iterating them is a no-op.
-    /// Moreover, trying to use a reversed range to index a slice will panic at run-time.
+    /// Reversed ranges used to slice are checked by `reversed_slice_range`.
     ///
     /// **Known problems:** None.
     ///
@@ -113,18 +113,12 @@
     /// ```rust,no_run
     /// fn main() {
     ///     (10..=0).for_each(|x| println!("{}", x));
-    ///
-    ///     let arr = [1, 2, 3, 4, 5];
-    ///     let sub = &arr[3..1];
     /// }
     /// ```
     /// Use instead:
     /// ```rust
     /// fn main() {
     ///     (0..=10).rev().for_each(|x| println!("{}", x));
-    ///
-    ///     let arr = [1, 2, 3, 4, 5];
-    ///     let sub = &arr[1..3];
     /// }
     /// ```
     pub REVERSED_EMPTY_RANGES,
@@ -458,19 +452,13 @@ fn is_empty_range(limits: RangeLimits, ordering: Ordering) -> bool {
         if let Some((end_idx, _)) = constant(cx, cx.typeck_results(), end);
         if let Some(ordering) = Constant::partial_cmp(cx.tcx, ty, &start_idx, &end_idx);
         if is_empty_range(limits, ordering);
+        // Slicing with a reversed range is linted by `reversed_slice_range`, and `N..N` has proven to
+        // be useful to slice, see #5689 and #5628
+        if !inside_indexing_expr(cx, expr);
         then {
-            if inside_indexing_expr(cx, expr) {
-                // Avoid linting `N..N` as it has proven to be useful, see #5689 and #5628 ...
-                if ordering != Ordering::Equal {
-                    span_lint(
-                        cx,
-                        REVERSED_EMPTY_RANGES,
-                        expr.span,
-                        "this range is reversed and using it to index a slice will panic at run-time",
-                    );
-                }
-            // ... except in for loop arguments for backwards compatibility with `reverse_range_loop`
-            } else if ordering != Ordering::Equal || is_for_loop_arg(cx, expr) {
+            // Avoid linting `N..N` except in for loop arguments for backwards compatibility with
+            // `reverse_range_loop`
+            if ordering != Ordering::Equal || is_for_loop_arg(cx, expr) {
                 span_lint_and_then(
                     cx,
                     REVERSED_EMPTY_RANGES,
@@ -0,0 +1,272 @@
+//! lint on operations that may panic and are reachable from the entry points of a `no_std` binary
+
+use crate::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{higher, is_no_std_crate};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId, LOCAL_CRATE};
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{BinOpKind, Crate, Expr, ExprKind, HirId};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty::subst::SubstsRef;
+use rustc_middle::ty::{self, Instance, Ty, TypeckResults};
+use rustc_session::config::CrateType;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span, Symbol};
+use std::collections::VecDeque;
+
+declare_clippy_lint! {
+    /// **What it does:** In `#![no_std]` binaries, checks for indexing, `unwrap()` and `expect()`
+    /// on an `Option` or `Result`, and integer division and remainder that may panic and are
+    /// reachable from an entry point of the binary: the `main` (or `#[start]`) function and the
+    /// functions exported with `#[no_mangle]` or `#[export_name]`, like interrupt handlers.
+    ///
+    /// The calls of the functions of the crate are followed, except for the functions listed in
+    /// the `reachable-panic-allowed` configuration value, e.g. `["my_crate::debug::dump"]`.
+    ///
+    /// **Why is this bad?** A panic calls the `#[panic_handler]`, which usually halts or resets
+    /// the device. Each operation that may panic also adds the panic machinery and its messages to
+    /// the binary.
+    ///
+    /// **Known problems:** Calls through function pointers and trait objects, calls of trait
+    /// methods on generic types, and the functions of other crates aren't followed. Operations are reported even if a check before them makes
+    /// the panic impossible, in which case they can be allowed with an attribute.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,ignore
+    /// #![no_std]
+    /// #![no_main]
+    ///
+    /// #[no_mangle]
+    /// pub extern "C" fn main() -> ! {
+    ///     // Bad
+    ///     let x = BUFFER[read_index()];
+    ///
+    ///     // Good
+    ///     let x = BUFFER.get(read_index()).copied().unwrap_or_default();
+    ///     loop {}
+    /// }
+    /// ```
+    pub REACHABLE_PANIC,
+    restriction,
+    "an operation that may panic, reachable from an entry point of a `no_std` binary"
+}
+
+pub struct ReachablePanic {
+    allowed: FxHashSet<Vec<Symbol>>,
+}
+
+impl ReachablePanic {
+    pub fn new(allowed: &[String]) -> Self {
+        Self {
+            allowed: allowed
+                .iter()
+                .map(|path| path.split("::").map(Symbol::intern).collect())
+                .collect(),
+        }
+    }
+}
+
+impl_lint_pass!(ReachablePanic => [REACHABLE_PANIC]);
+
+impl<'tcx> LateLintPass<'tcx> for ReachablePanic {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>, _: &'tcx Crate<'_>) {
+        if !is_no_std_crate(cx) || !cx.tcx.sess.crate_types().contains(&CrateType::Executable) {
+            return;
+        }
+
+        let tcx = cx.tcx;
+        let entry = tcx.entry_fn(LOCAL_CRATE).and_then(|(def_id, _)| def_id.as_local());
+        let exported = tcx.body_owners().filter(|&def_id| {
+            matches!(tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+                && tcx.codegen_fn_attrs(def_id).contains_extern_indicator()
+        });
+
+        // the function each reachable function is first called from, `None` for the entry points
+        let mut callers: FxHashMap<LocalDefId, Option<LocalDefId>> = FxHashMap::default();
+        let mut queue = VecDeque::new();
+        for def_id in entry.into_iter().chain(exported) {
+            if callers.insert(def_id, None).is_none() {
+                queue.push_back(def_id);
+            }
+        }
+
+        while let Some(def_id) = queue.pop_front() {
+            if Some(def_id.to_def_id()) == tcx.lang_items().panic_impl()
+                || self.allowed.contains(&cx.get_def_path(def_id.to_def_id()))
+            {
+                continue;
+            }
+            let body = tcx
+                .hir()
+                .body(tcx.hir().body_owned_by(tcx.hir().local_def_id_to_hir_id(def_id)));
+            let mut visitor = PanicVisitor {
+                cx,
+                typeck: tcx.typeck(def_id),
+                param_env: tcx.param_env(def_id),
+                panics: Vec::new(),
+                callees: Vec::new(),
+            };
+            visitor.visit_expr(&body.value);
+
+            for (hir_id, span, what) in visitor.panics {
+                let chain = call_chain(cx, &callers, def_id);
+                span_lint_hir_and_then(
+                    cx,
+                    REACHABLE_PANIC,
+                    hir_id,
+                    span,
+                    &format!("{} may panic in a `no_std` binary", what),
+                    |diag| {
+                        diag.note(&format!("reachable from the entry point: `{}`", chain));
+                    },
+                );
+            }
+            for callee in visitor.callees {
+                callers.entry(callee).or_insert_with(|| {
+                    queue.push_back(callee);
+                    Some(def_id)
+                });
+            }
+        }
+    }
+}
+
+/// Returns the calls from an entry point to `def_id`, like `main -> parse -> read_byte`.
+fn call_chain(cx: &LateContext<'_>, callers: &FxHashMap<LocalDefId, Option<LocalDefId>>, def_id: LocalDefId) -> String {
+    let mut chain = vec![cx.tcx.item_name(def_id.to_def_id())];
+    let mut current = def_id;
+    while let Some(&Some(caller)) = callers.get(&current) {
+        if chain.len() > callers.len() {
+            break;
+        }
+        chain.push(cx.tcx.item_name(caller.to_def_id()));
+        current = caller;
+    }
+    chain
+        .into_iter()
+        .rev()
+        .map(Symbol::to_ident_string)
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+struct PanicVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    typeck: &'tcx TypeckResults<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    /// The operations that may panic, with a description.
+    panics: Vec<(HirId, Span, &'static str)>,
+    /// The functions of this crate that are called.
+    callees: Vec<LocalDefId>,
+}
+
+impl<'a, 'tcx> PanicVisitor<'a, 'tcx> {
+    /// Adds the function of this crate called with `def_id` and `substs` to the callees.
+    fn add_callee(&mut self, def_id: DefId, substs: SubstsRef<'tcx>) {
+        let tcx = self.cx.tcx;
+        // resolve trait methods to the method of the implementation
+        let def_id = match Instance::resolve(tcx, self.param_env, def_id, substs) {
+            Ok(Some(instance)) => instance.def_id(),
+            _ => def_id,
+        };
+        if let Some(def_id) = def_id.as_local() {
+            if tcx
+                .hir()
+                .maybe_body_owned_by(tcx.hir().local_def_id_to_hir_id(def_id))
+                .is_some()
+            {
+                self.callees.push(def_id);
+            }
+        }
+    }
+
+    fn is_option_or_result(&self, ty: Ty<'tcx>) -> bool {
+        is_type_diagnostic_item(self.cx, ty, sym::option_type) || is_type_diagnostic_item(self.cx, ty, sym::result_type)
+    }
+
+    /// Whether the index `index` of `base` can't be out of bounds, i.e. `base` is an array and
+    /// `index` is a constant smaller than its length, or `index` is `..`.
+    fn is_in_bounds(&self, base: &Expr<'_>, index: &Expr<'_>) -> bool {
+        if matches!(higher::range(index), Some(range) if range.start.is_none() && range.end.is_none()) {
+            return true;
+        }
+        match self.typeck.expr_ty(base).peel_refs().kind() {
+            ty::Array(_, len) => match (
+                constant(self.cx, self.typeck, index),
+                len.try_eval_usize(self.cx.tcx, self.param_env),
+            ) {
+                (Some((Constant::Int(index), _)), Some(len)) => index < u128::from(len),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Whether the integer division or remainder by `divisor` can't panic, i.e. `divisor` is a
+    /// constant other than 0, and other than -1 for signed integers.
+    fn is_safe_divisor(&self, divisor: &Expr<'_>) -> bool {
+        match (
+            constant(self.cx, self.typeck, divisor),
+            self.typeck.expr_ty(divisor).kind(),
+        ) {
+            (Some((Constant::Int(n), _)), ty::Uint(_)) => n != 0,
+            (Some((Constant::Int(n), _)), ty::Int(ity)) => !matches!(clippy_utils::sext(self.cx.tcx, n, *ity), 0 | -1),
+            _ => false,
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for PanicVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            ExprKind::Index(base, index) if !self.is_in_bounds(base, index) => {
+                self.panics.push((expr.hir_id, expr.span, "indexing"));
+            },
+            ExprKind::Binary(op, lhs, rhs) | ExprKind::AssignOp(op, lhs, rhs)
+                if matches!(op.node, BinOpKind::Div | BinOpKind::Rem)
+                    && self.typeck.expr_ty(lhs).is_integral()
+                    && !self.is_safe_divisor(rhs) =>
+            {
+                let what = if op.node == BinOpKind::Div {
+                    "integer division"
+                } else {
+                    "integer remainder"
+                };
+                self.panics.push((expr.hir_id, expr.span, what));
+            }
+            ExprKind::MethodCall(path, _, [receiver, ..], _) => {
+                let name = path.ident.as_str();
+                if matches!(&*name, "unwrap" | "expect")
+                    && self.is_option_or_result(self.typeck.expr_ty(receiver).peel_refs())
+                {
+                    let what = if &*name == "unwrap" { "`unwrap()`" } else { "`expect()`" };
+                    self.panics.push((expr.hir_id, expr.span, what));
+                }
+                if let Some(def_id) = self.typeck.type_dependent_def_id(expr.hir_id) {
+                    self.add_callee(def_id, self.typeck.node_substs(expr.hir_id));
+                }
+            },
+            ExprKind::Call(func, _) => {
+                if let ExprKind::Path(ref qpath) = func.kind {
+                    if let Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) = self.typeck.qpath_res(qpath, func.hir_id)
+                    {
+                        self.add_callee(def_id, self.typeck.node_substs(func.hir_id));
+                    }
+                }
+            },
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
@@ -0,0 +1,151 @@
+//! lint on the same non-trivial index expression repeated in a function, e.g. `data[key(x)]`
+
+use crate::consts::constant;
+use clippy_utils::diagnostics::span_lint_and_then_multi;
+use clippy_utils::source::snippet;
+use clippy_utils::{higher, SpanlessEq, SpanlessHash};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirId};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for functions where the same non-trivial index expression, like
+    /// `data[compute_key(x)]`, appears at least `repeated-indexing-threshold` times (3 by default).
+    /// Indexes that are literals, constants or variables are not considered non-trivial.
+    ///
+    /// **Why is this bad?** The index is computed and checked against the length every time, and
+    /// the repetition makes the code harder to read. Binding the element (or a reference to it) to
+    /// a local variable does both only once.
+    ///
+    /// **Known problems:** The lint doesn't check whether the collection or the variables in the
+    /// index are changed between the occurrences, in which case they can't be replaced by a single
+    /// binding.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # fn compute_key(x: u32) -> usize { x as usize }
+    /// # fn f(data: &mut [u32], x: u32) {
+    /// // Bad
+    /// if data[compute_key(x)] > 10 {
+    ///     data[compute_key(x)] -= 10;
+    /// } else {
+    ///     data[compute_key(x)] = 0;
+    /// }
+    ///
+    /// // Good
+    /// let elem = &mut data[compute_key(x)];
+    /// if *elem > 10 {
+    ///     *elem -= 10;
+    /// } else {
+    ///     *elem = 0;
+    /// }
+    /// # }
+    /// ```
+    pub REPEATED_INDEXING,
+    pedantic,
+    "the same non-trivial index expression repeated in a function, like `data[compute_key(x)]`"
+}
+
+pub struct RepeatedIndexing {
+    threshold: u64,
+}
+
+impl RepeatedIndexing {
+    pub fn new(threshold: u64) -> Self {
+        Self { threshold }
+    }
+}
+
+impl_lint_pass!(RepeatedIndexing => [REPEATED_INDEXING]);
+
+impl<'tcx> LateLintPass<'tcx> for RepeatedIndexing {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        _: Span,
+        _: HirId,
+    ) {
+        // the bodies of closures are visited with the function containing them
+        if matches!(kind, FnKind::Closure) {
+            return;
+        }
+
+        let mut visitor = IndexVisitor {
+            cx,
+            indexes: FxHashMap::default(),
+        };
+        visitor.visit_expr(&body.value);
+
+        let mut groups: Vec<Vec<&Expr<'_>>> = visitor.indexes.into_iter().flat_map(|(_, groups)| groups).collect();
+        groups.retain(|group| group.len() as u64 >= self.threshold.max(2));
+        groups.sort_by_key(|group| group[0].span.lo());
+        for group in groups {
+            let first = group[0];
+            let labels: Vec<_> = group[1..]
+                .iter()
+                .map(|expr| (expr.span, "indexed again here".to_string()))
+                .collect();
+            span_lint_and_then_multi(
+                cx,
+                REPEATED_INDEXING,
+                first.span,
+                &format!("the same index expression is repeated {} times", group.len()),
+                &labels,
+                |diag| {
+                    diag.help(&format!(
+                        "consider binding the element to a local variable once, e.g. `let elem = &{};`",
+                        snippet(cx, first.span, "..")
+                    ));
+                },
+            );
+        }
+    }
+}
+
+struct IndexVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    /// The non-trivial index expressions, grouped by equality and by their hash.
+    indexes: FxHashMap<u64, Vec<Vec<&'tcx Expr<'tcx>>>>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for IndexVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Index(_, index) = expr.kind {
+            if !expr.span.from_expansion() && is_non_trivial(self.cx, index) {
+                let cx = self.cx;
+                let mut hasher = SpanlessHash::new(cx);
+                hasher.hash_expr(expr);
+                let groups = self.indexes.entry(hasher.finish()).or_default();
+                match groups
+                    .iter_mut()
+                    .find(|group| SpanlessEq::new(cx).eq_expr(group[0], expr))
+                {
+                    Some(group) => group.push(expr),
+                    None => groups.push(vec![expr]),
+                }
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
+
+/// Whether `index` is computed, i.e. isn't a literal, a constant, a variable or a range.
+fn is_non_trivial(cx: &LateContext<'_>, index: &Expr<'_>) -> bool {
+    !matches!(index.kind, ExprKind::Lit(_) | ExprKind::Path(_))
+        && higher::range(index).is_none()
+        && constant(cx, cx.typeck_results(), index).is_none()
+}
@@ -0,0 +1,206 @@
+//! lint on indexing the parts of a split string collected into a `Vec`
+
+use crate::utils::known_len::const_usize;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{find_binding_init, higher, path_to_local, path_to_local_id};
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Expr, ExprKind, HirId};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for indexing the parts of a string split with `split`, `splitn`
+    /// or a similar method and collected into a `Vec`, like `s.split(',').collect::<Vec<_>>()[1]`,
+    /// directly or through a local variable.
+    ///
+    /// **Why is this bad?** The number of parts depends on the string. Malformed input, like a
+    /// line without the separator, makes the indexing panic.
+    ///
+    /// **Known problems:** Indexing a local variable isn't linted if its length is checked with
+    /// `len()` or `is_empty()` anywhere in the function, even if the check doesn't guard the
+    /// indexing.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # fn f(line: &str) -> Option<()> {
+    /// // Bad
+    /// let value = line.split('=').collect::<Vec<_>>()[1];
+    ///
+    /// // Good
+    /// let value = line.split('=').nth(1)?;
+    /// // or
+    /// let mut parts = line.splitn(2, '=');
+    /// if let (Some(key), Some(value)) = (parts.next(), parts.next()) {
+    ///     // ..
+    /// }
+    /// # None
+    /// # }
+    /// ```
+    pub SPLIT_INDEXING,
+    pedantic,
+    "indexing the parts of a split string collected into a `Vec`, like `s.split(',').collect::<Vec<_>>()[1]`"
+}
+
+declare_lint_pass!(SplitIndexing => [SPLIT_INDEXING]);
+
+/// The methods of `str` splitting it into parts.
+const SPLIT_METHODS: [&str; 8] = [
+    "split",
+    "rsplit",
+    "splitn",
+    "rsplitn",
+    "split_terminator",
+    "rsplit_terminator",
+    "split_whitespace",
+    "split_ascii_whitespace",
+];
+
+impl<'tcx> LateLintPass<'tcx> for SplitIndexing {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let (base, index) = match expr.kind {
+            ExprKind::Index(base, index) if !expr.span.from_expansion() => (base, index),
+            _ => return,
+        };
+        if higher::range(index).is_some() {
+            return;
+        }
+        let local = path_to_local(base);
+        let collect = match local {
+            Some(local) => match find_binding_init(cx, local) {
+                Some(init) => init,
+                None => return,
+            },
+            None => base,
+        };
+        let split = match split_collected(cx, collect) {
+            Some(split) => split,
+            None => return,
+        };
+        if const_usize(cx, index) == Some(0) && has_first_part(cx, split) {
+            return;
+        }
+        if let Some(local) = local {
+            if is_len_checked(cx, local) {
+                return;
+            }
+        }
+
+        span_lint_and_then(
+            cx,
+            SPLIT_INDEXING,
+            expr.span,
+            "indexing the parts of a split string may panic",
+            |diag| {
+                diag.note("the string may have fewer parts than expected, e.g. if the input is malformed");
+                if local.is_some() {
+                    diag.help("consider using `.get()` and handling the `None` case");
+                } else {
+                    let mut applicability = Applicability::MaybeIncorrect;
+                    let split = snippet_with_applicability(cx, split.span, "..", &mut applicability);
+                    let (method, sugg) = if const_usize(cx, index) == Some(0) {
+                        ("next", format!("{}.next()", split))
+                    } else {
+                        let index = snippet_with_applicability(cx, index.span, "..", &mut applicability);
+                        ("nth", format!("{}.nth({})", split, index))
+                    };
+                    diag.span_suggestion(
+                        expr.span,
+                        &format!(
+                            "consider getting the part with `{}` and handling the `None` case",
+                            method
+                        ),
+                        sugg,
+                        applicability,
+                    );
+                }
+            },
+        );
+    }
+}
+
+/// Returns the call of a method splitting a string if `expr` collects its result into a `Vec`,
+/// like `s.split(',').collect::<Vec<_>>()`.
+fn split_collected<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match expr.kind {
+        ExprKind::MethodCall(path, _, [split], _)
+            if path.ident.name == sym!(collect)
+                && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::vec_type) =>
+        {
+            match split.kind {
+                ExprKind::MethodCall(path, _, _, _)
+                    if SPLIT_METHODS.contains(&&*path.ident.as_str()) && is_str_method(cx, split) =>
+                {
+                    Some(split)
+                },
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Whether the method called by `expr` is a method of `str`.
+fn is_str_method(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    cx.typeck_results()
+        .type_dependent_def_id(expr.hir_id)
+        .and_then(|method_id| cx.tcx.impl_of_method(method_id))
+        .map_or(false, |impl_id| Some(impl_id) == cx.tcx.lang_items().str_impl())
+}
+
+/// Whether the split `split` always returns at least one part: `split` and `rsplit` do, even on
+/// an empty string, and so do `splitn` and `rsplitn` with a count other than 0.
+fn has_first_part(cx: &LateContext<'_>, split: &Expr<'_>) -> bool {
+    match split.kind {
+        ExprKind::MethodCall(path, _, args, _) => match (&*path.ident.as_str(), args) {
+            ("split" | "rsplit", _) => true,
+            ("splitn" | "rsplitn", [_, n, _]) => const_usize(cx, n).map_or(false, |n| n > 0),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Whether `len()` or `is_empty()` is called on the local `local` in the enclosing body.
+fn is_len_checked(cx: &LateContext<'_>, local: HirId) -> bool {
+    let body = match cx.enclosing_body {
+        Some(body) => cx.tcx.hir().body(body),
+        None => return false,
+    };
+    let mut visitor = LenCheckVisitor {
+        cx,
+        local,
+        found: false,
+    };
+    visitor.visit_expr(&body.value);
+    visitor.found
+}
+
+struct LenCheckVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    local: HirId,
+    found: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LenCheckVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::MethodCall(path, _, [receiver], _) = expr.kind {
+            if matches!(&*path.ident.as_str(), "len" | "is_empty") && path_to_local_id(receiver, self.local) {
+                self.found = true;
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
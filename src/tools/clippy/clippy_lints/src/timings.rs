@@ -0,0 +1,149 @@
+//! Measures the time spent in every lint pass, for `cargo clippy -Z clippy-timings`.
+//!
+//! The passes are wrapped in a pass that forwards every `check_*` method to the wrapped pass and
+//! adds up the time it took. Measuring adds some overhead to every call, so the numbers are only
+//! meaningful relative to each other.
+
+use rustc_ast as ast;
+use rustc_data_structures::sync;
+use rustc_hir as hir;
+use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintPass, LintStore};
+use rustc_span::symbol::{Ident, Symbol};
+use rustc_span::Span;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+type EarlyLintPassObject = Box<dyn EarlyLintPass + sync::Send + sync::Sync>;
+type LateLintPassObject = Box<dyn for<'tcx> LateLintPass<'tcx> + sync::Send + sync::Sync>;
+type Ctor<T> = Box<dyn Fn() -> T + sync::Send + sync::Sync>;
+
+/// The total time spent in every lint pass, by pass name.
+#[derive(Clone, Default)]
+pub struct PassTimes(Arc<Mutex<BTreeMap<&'static str, Duration>>>);
+
+impl PassTimes {
+    /// Returns the measured passes, the slowest first.
+    pub fn sorted(&self) -> Vec<(&'static str, Duration)> {
+        let mut timings: Vec<_> = self.lock().iter().map(|(&name, &time)| (name, time)).collect();
+        timings.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        timings
+    }
+
+    fn add(&self, name: &'static str, time: Duration) {
+        *self.lock().entry(name).or_default() += time;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<&'static str, Duration>> {
+        self.0.lock().expect("a lint pass panicked while recording its time")
+    }
+}
+
+/// Calls `register` and measures the time spent in the lint passes it registers in `store`.
+pub fn register_timed(store: &mut LintStore, timings: &PassTimes, register: impl FnOnce(&mut LintStore)) {
+    let pre_expansion = store.pre_expansion_passes.len();
+    let early = store.early_passes.len();
+    let late = store.late_passes.len();
+    register(store);
+
+    for ctor in &mut store.pre_expansion_passes[pre_expansion..] {
+        wrap_early(ctor, timings);
+    }
+    for ctor in &mut store.early_passes[early..] {
+        wrap_early(ctor, timings);
+    }
+    for ctor in &mut store.late_passes[late..] {
+        wrap_late(ctor, timings);
+    }
+}
+
+fn wrap_early(ctor: &mut Ctor<EarlyLintPassObject>, timings: &PassTimes) {
+    let timings = timings.clone();
+    let inner = std::mem::replace(ctor, Box::new(|| unreachable!()));
+    *ctor = Box::new(move || -> EarlyLintPassObject {
+        box TimedEarlyPass {
+            pass: inner(),
+            timings: timings.clone(),
+            time: Duration::ZERO,
+        }
+    });
+}
+
+fn wrap_late(ctor: &mut Ctor<LateLintPassObject>, timings: &PassTimes) {
+    let timings = timings.clone();
+    let inner = std::mem::replace(ctor, Box::new(|| unreachable!()));
+    *ctor = Box::new(move || -> LateLintPassObject {
+        box TimedLatePass {
+            pass: inner(),
+            timings: timings.clone(),
+            time: Duration::ZERO,
+        }
+    });
+}
+
+struct TimedLatePass {
+    pass: LateLintPassObject,
+    timings: PassTimes,
+    time: Duration,
+}
+
+struct TimedEarlyPass {
+    pass: EarlyLintPassObject,
+    timings: PassTimes,
+    time: Duration,
+}
+
+impl Drop for TimedLatePass {
+    fn drop(&mut self) {
+        self.timings.add(self.pass.name(), self.time);
+    }
+}
+
+impl Drop for TimedEarlyPass {
+    fn drop(&mut self) {
+        self.timings.add(self.pass.name(), self.time);
+    }
+}
+
+#[allow(rustc::lint_pass_impl_without_macro)]
+impl LintPass for TimedLatePass {
+    fn name(&self) -> &'static str {
+        self.pass.name()
+    }
+}
+
+#[allow(rustc::lint_pass_impl_without_macro)]
+impl LintPass for TimedEarlyPass {
+    fn name(&self) -> &'static str {
+        self.pass.name()
+    }
+}
+
+macro_rules! timed_methods {
+    ($context:ty, [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => (
+        $(fn $name(&mut self, cx: $context, $($param: $arg),*) {
+            let start = Instant::now();
+            self.pass.$name(cx, $($param),*);
+            self.time += start.elapsed();
+        })*
+    )
+}
+
+macro_rules! timed_late_lint_pass {
+    ([], [$hir:tt], $methods:tt) => {
+        impl<$hir> LateLintPass<$hir> for TimedLatePass {
+            timed_methods!(&LateContext<$hir>, $methods);
+        }
+    };
+}
+
+macro_rules! timed_early_lint_pass {
+    ([], $methods:tt) => {
+        impl EarlyLintPass for TimedEarlyPass {
+            timed_methods!(&EarlyContext<'_>, $methods);
+        }
+    };
+}
+
+rustc_lint::late_lint_methods!(timed_late_lint_pass, [], ['tcx]);
+rustc_lint::early_lint_methods!(timed_early_lint_pass, []);
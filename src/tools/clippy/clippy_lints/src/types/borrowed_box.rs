@@ -1,13 +1,15 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet;
-use clippy_utils::{match_def_path, paths};
+use clippy_utils::{match_def_path, meets_edition, paths};
 use if_chain::if_chain;
+use rustc_ast::TraitObjectSyntax;
 use rustc_errors::Applicability;
 use rustc_hir::{
     self as hir, GenericArg, GenericBounds, GenericParamKind, HirId, Lifetime, MutTy, Mutability, Node, QPath,
     SyntheticTyParamKind, TyKind,
 };
 use rustc_lint::LateContext;
+use rustc_span::edition::Edition;
 
 use super::BORROWED_BOX;
 
@@ -50,8 +52,20 @@ pub(super) fn check(cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>, lt: &Lifetime, m
                     // Originally reported as the issue #3128.
                     let inner_snippet = snippet(cx, inner.span, "..");
                     let suggestion = match &inner.kind {
-                        TyKind::TraitObject(bounds, lt_bound, _) if bounds.len() > 1 || !lt_bound.is_elided() => {
-                            format!("&{}({})", ltopt, &inner_snippet)
+                        TyKind::TraitObject(bounds, lt_bound, syntax) => {
+                            // bare trait objects are deprecated since the 2018 edition
+                            let dyn_kw = if *syntax == TraitObjectSyntax::None
+                                && meets_edition(inner.span, Edition::Edition2018)
+                            {
+                                "dyn "
+                            } else {
+                                ""
+                            };
+                            if bounds.len() > 1 || !lt_bound.is_elided() {
+                                format!("&{}({}{})", ltopt, dyn_kw, &inner_snippet)
+                            } else {
+                                format!("&{}{}{}", ltopt, dyn_kw, &inner_snippet)
+                            }
                         },
                         TyKind::Path(qpath)
                             if get_bounds_if_impl_trait(cx, qpath, inner.hir_id)
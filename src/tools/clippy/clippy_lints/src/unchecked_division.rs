@@ -0,0 +1,69 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::in_external_macro;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::value_range::is_nonzero;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for integer division and remainder operations (`/`, `%`, `/=` and
+    /// `%=`) whose divisor isn't known to be nonzero. A divisor is known to be nonzero if it is a
+    /// nonzero constant, or a variable guarded by a comparison like `b != 0` or `b > 0` (or `b < 0`
+    /// if it is signed).
+    ///
+    /// **Why is this bad?** Integer division by zero panics.
+    ///
+    /// **Known problems:** Dividing the minimum value of a signed integer type by `-1` overflows and
+    /// panics as well, this isn't checked.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// fn average(sum: u32, count: u32) -> u32 {
+    ///     sum / count
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn average(sum: u32, count: u32) -> Option<u32> {
+    ///     sum.checked_div(count)
+    /// }
+    /// ```
+    pub UNCHECKED_DIVISION,
+    restriction,
+    "integer division or remainder by a value that can be zero"
+}
+
+declare_lint_pass!(UncheckedDivision => [UNCHECKED_DIVISION]);
+
+impl<'tcx> LateLintPass<'tcx> for UncheckedDivision {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let (op, lhs, rhs) = match expr.kind {
+            ExprKind::Binary(op, lhs, rhs) | ExprKind::AssignOp(op, lhs, rhs) => (op.node, lhs, rhs),
+            _ => return,
+        };
+        let (method, operation) = match op {
+            BinOpKind::Div => ("checked_div", "division"),
+            BinOpKind::Rem => ("checked_rem", "remainder"),
+            _ => return,
+        };
+        if in_external_macro(cx.sess(), expr.span)
+            || !cx.typeck_results().expr_ty(lhs).is_integral()
+            || !cx.typeck_results().expr_ty(rhs).is_integral()
+            || is_nonzero(cx, rhs)
+        {
+            return;
+        }
+
+        let sugg = Sugg::method_call(Sugg::hir(cx, lhs, ".."), method, &[Sugg::hir(cx, rhs, "..")]);
+        span_lint_and_help(
+            cx,
+            UNCHECKED_DIVISION,
+            expr.span,
+            &format!("this {} panics if the divisor is zero", operation),
+            None,
+            &format!("consider using `{}`", sugg),
+        );
+    }
+}
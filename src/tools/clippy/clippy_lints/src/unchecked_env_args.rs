@@ -0,0 +1,154 @@
+//! lint on unwrapping and indexing the command line arguments without checking how many there are
+
+use crate::utils::guards;
+use crate::utils::known_len::const_usize;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{expr_or_init, is_trait_method, match_def_path, paths};
+use if_chain::if_chain;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, UintTy};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `unwrap()` calls on a command line argument, like
+    /// `env::args().nth(1).unwrap()` or `env::args().skip(1).next().unwrap()`, and for indexing
+    /// the `Vec` collected from `env::args()` (or `env::args_os()`), like `args[1]`, without a
+    /// check of the number of arguments.
+    ///
+    /// **Why is this bad?** Running the program without the argument makes it panic with a
+    /// message like "index out of bounds: the len is 1 but the index is 1", which doesn't tell the
+    /// user what the program expects. Matching on the arguments lets the program print its usage
+    /// instead.
+    ///
+    /// **Known problems:** The first argument, the path of the program, isn't linted, even though
+    /// it can be missing. `expect()` isn't linted, since its message can describe the usage.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// // Bad
+    /// let args: Vec<String> = std::env::args().collect();
+    /// let path = &args[1];
+    ///
+    /// // Good
+    /// let path = match std::env::args().nth(1) {
+    ///     Some(path) => path,
+    ///     None => {
+    ///         eprintln!("usage: program <path>");
+    ///         std::process::exit(1);
+    ///     },
+    /// };
+    /// ```
+    pub UNCHECKED_ENV_ARGS,
+    pedantic,
+    "unwrapping or indexing the command line arguments without checking how many there are"
+}
+
+declare_lint_pass!(UncheckedEnvArgs => [UNCHECKED_ENV_ARGS]);
+
+impl<'tcx> LateLintPass<'tcx> for UncheckedEnvArgs {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        match expr.kind {
+            ExprKind::MethodCall(path, _, [arg], _) if path.ident.as_str() == "unwrap" => {
+                check_unwrap(cx, expr, arg);
+            },
+            ExprKind::Index(args, index) => check_index(cx, expr, args, index),
+            _ => {},
+        }
+    }
+}
+
+/// Lints the `unwrap()` call `expr` on `arg` if `arg` is `nth(n)` or `next()` on the command line
+/// arguments, see `skipped_args`, and the argument isn't the path of the program.
+fn check_unwrap<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, arg: &'tcx Expr<'tcx>) {
+    let (iter, n) = match arg.kind {
+        ExprKind::MethodCall(path, _, [iter, n], _) if path.ident.as_str() == "nth" => (iter, Some(n)),
+        ExprKind::MethodCall(path, _, [iter], _) if path.ident.as_str() == "next" => (iter, None),
+        _ => return,
+    };
+    let skipped = match skipped_args(cx, iter) {
+        Some(skipped) => skipped,
+        None => return,
+    };
+    let position = skipped
+        .into_iter()
+        .chain(n)
+        .try_fold(0, |position, n| Some(position + const_usize(cx, n)?));
+    if position == Some(0) || !is_trait_method(cx, arg, sym::Iterator) {
+        return;
+    }
+    lint(
+        cx,
+        expr,
+        "called `unwrap()` on a command line argument, which panics if it is missing",
+        &snippet(cx, arg.span, ".."),
+    );
+}
+
+/// Returns the numbers of command line arguments skipped by `iter`, if it is a call of
+/// `env::args()` or `env::args_os()`, directly or followed by `skip(n)` calls.
+fn skipped_args<'tcx>(cx: &LateContext<'tcx>, iter: &'tcx Expr<'tcx>) -> Option<Vec<&'tcx Expr<'tcx>>> {
+    match iter.kind {
+        ExprKind::MethodCall(path, _, [inner, n], _) if path.ident.as_str() == "skip" => {
+            let mut skipped = skipped_args(cx, inner)?;
+            skipped.push(n);
+            Some(skipped)
+        },
+        _ if is_env_args(cx, iter) => Some(Vec::new()),
+        _ => None,
+    }
+}
+
+/// Lints the indexing `expr` of `args` with `index` if `args` is a `Vec` collected from the
+/// command line arguments, and `index` isn't compared with its length. Ranges aren't linted, since
+/// `args[1..]` doesn't panic without arguments.
+fn check_index<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, args: &'tcx Expr<'tcx>, index: &'tcx Expr<'tcx>) {
+    let position = const_usize(cx, index);
+    if_chain! {
+        if position != Some(0);
+        if matches!(cx.typeck_results().expr_ty(index).kind(), ty::Uint(UintTy::Usize));
+        if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(args).peel_refs(), sym::vec_type);
+        if let ExprKind::MethodCall(path, _, [iter], _) = expr_or_init(cx, args).kind;
+        if path.ident.as_str() == "collect" && is_env_args(cx, iter);
+        if !guards::is_len_guarded(cx, expr, index, args, false);
+        if !guards::matched_len(cx, expr, args)
+            .map_or(false, |matched| position.map_or(false, |position| position < matched.min));
+        then {
+            lint(
+                cx,
+                expr,
+                "indexing the command line arguments, which panics if the argument is missing",
+                &format!("{}.get({})", snippet(cx, args.span, ".."), snippet(cx, index.span, "..")),
+            );
+        }
+    }
+}
+
+/// Whether `expr` is a call of `env::args()` or `env::args_os()`.
+fn is_env_args(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let ExprKind::Call(func, []) = expr.kind {
+        if let ExprKind::Path(ref qpath) = func.kind {
+            if let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id() {
+                return match_def_path(cx, def_id, &paths::ENV_ARGS) || match_def_path(cx, def_id, &paths::ENV_ARGS_OS);
+            }
+        }
+    }
+    false
+}
+
+/// Emits the lint at `expr`, suggesting to match on the `Option` of the argument `arg` instead.
+fn lint(cx: &LateContext<'_>, expr: &Expr<'_>, msg: &str, arg: &str) {
+    span_lint_and_then(cx, UNCHECKED_ENV_ARGS, expr.span, msg, |diag| {
+        diag.help(&format!(
+            "consider matching on `{}` and printing the usage when it is `None`",
+            arg
+        ));
+        diag.note("for more than a few arguments, consider an argument parsing library");
+    });
+}
@@ -0,0 +1,216 @@
+//! lint on offsets of raw pointers and indexing of slices made from raw parts that aren't compared
+//! with a length
+
+use crate::utils::guards::{self, Guard};
+use crate::utils::known_len::const_usize;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::value_range::value_range;
+use clippy_utils::{higher, match_def_path, path_to_local, paths, peel_hir_expr_while, SpanlessEq};
+use rustc_ast::ast::RangeLimits;
+use rustc_hir::{Expr, ExprKind, Node, PatKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::source_map::{MultiSpan, Span};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for dereferences of a raw pointer offset by a value, like
+    /// `*ptr.add(i)` or `ptr.add(i).read()`, and for indexing of a slice created from a raw
+    /// pointer and a length, like `slice::from_raw_parts(ptr, len)[i]`, where the offset or the
+    /// index isn't related to a length by a visible check: an `if i < len`, an `assert!(i < len)`
+    /// or a `for i in 0..len` loop.
+    ///
+    /// **Why is this bad?** Nothing shows that the offset stays within the allocation the pointer
+    /// comes from, and reading or writing out of it is undefined behavior. This lint is meant as
+    /// an aid for reviewers of unsafe code: every diagnostic lists the values whose relation has to
+    /// be justified.
+    ///
+    /// **Known problems:** Invariants of a type and the safety documentation of an `unsafe fn`
+    /// aren't visible. The length of the allocation of a pointer isn't known, so any comparison of
+    /// its offset with an upper bound counts as a check.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// unsafe fn nth(data: *const u32, len: usize, n: usize) -> u32 {
+    ///     *data.add(n)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// unsafe fn nth(data: *const u32, len: usize, n: usize) -> u32 {
+    ///     assert!(n < len);
+    ///     *data.add(n)
+    /// }
+    /// ```
+    pub UNGUARDED_RAW_POINTER_INDEX,
+    restriction,
+    "dereferencing an offset raw pointer or indexing a slice from raw parts without a visible bounds check"
+}
+
+declare_lint_pass!(UnguardedRawPointerIndex => [UNGUARDED_RAW_POINTER_INDEX]);
+
+impl<'tcx> LateLintPass<'tcx> for UnguardedRawPointerIndex {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        match expr.kind {
+            ExprKind::Unary(UnOp::Deref, ptr) => check_offset(cx, expr, ptr),
+            ExprKind::MethodCall(path, _, [ptr, ..], _)
+                if is_access_method(&path.ident.as_str()) && cx.typeck_results().expr_ty(ptr).is_unsafe_ptr() =>
+            {
+                check_offset(cx, expr, ptr);
+            }
+            ExprKind::Index(slice, index) => check_from_raw_parts(cx, expr, slice, index),
+            _ => {},
+        }
+    }
+}
+
+/// Whether `name` is a method reading or writing the value behind a raw pointer.
+fn is_access_method(name: &str) -> bool {
+    matches!(
+        name,
+        "read" | "read_unaligned" | "read_volatile" | "write" | "write_unaligned" | "write_volatile"
+    )
+}
+
+/// Lints the access `expr` of the value behind `ptr` if it is `p.add(i)` or a similar offset of
+/// the raw pointer `p`, and `i` isn't compared with an upper bound.
+fn check_offset<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, ptr: &'tcx Expr<'tcx>) {
+    if let ExprKind::MethodCall(path, _, [base, offset], _) = ptr.kind {
+        if matches!(
+            &*path.ident.as_str(),
+            "add" | "sub" | "offset" | "wrapping_add" | "wrapping_sub" | "wrapping_offset"
+        ) && cx.typeck_results().expr_ty(base).is_unsafe_ptr()
+            && value_range(cx, offset).max != Some(0)
+            && !is_checked(cx, expr, offset, None, false)
+        {
+            lint(
+                cx,
+                expr,
+                "dereferencing a raw pointer offset by a value that isn't compared with a length",
+                &[(base.span, "the pointer"), (offset.span, "the offset")],
+            );
+        }
+    }
+}
+
+/// Lints the indexing `expr` of `slice` with `index` if `slice` is `slice::from_raw_parts(p, len)`
+/// (or `from_raw_parts_mut`), and the index (or a bound of the range) isn't compared with `len`.
+fn check_from_raw_parts<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    slice: &'tcx Expr<'tcx>,
+    index: &'tcx Expr<'tcx>,
+) {
+    let (ptr, len) = match peel_hir_expr_while(slice, |e| match e.kind {
+        ExprKind::AddrOf(_, _, inner) => Some(inner),
+        _ => None,
+    })
+    .kind
+    {
+        ExprKind::Call(func, [ptr, len]) if is_from_raw_parts(cx, func) => (ptr, len),
+        _ => return,
+    };
+    let (unchecked, label) = match higher::range(index) {
+        Some(range) => {
+            let end = range.end.map(|end| (end, range.limits == RangeLimits::HalfOpen));
+            match range
+                .start
+                .map(|start| (start, true))
+                .into_iter()
+                .chain(end)
+                .find(|&(bound, inclusive)| !is_checked(cx, expr, bound, Some(len), inclusive))
+            {
+                Some((bound, _)) => (bound, "the bound of the range"),
+                None => return,
+            }
+        },
+        None if !is_checked(cx, expr, index, Some(len), false) => (index, "the index"),
+        None => return,
+    };
+    lint(
+        cx,
+        expr,
+        "indexing a slice from raw parts with a value that isn't compared with its length",
+        &[
+            (ptr.span, "the pointer"),
+            (len.span, "the length"),
+            (unchecked.span, label),
+        ],
+    );
+}
+
+/// Whether `func` is `slice::from_raw_parts` or `slice::from_raw_parts_mut`.
+fn is_from_raw_parts(cx: &LateContext<'_>, func: &Expr<'_>) -> bool {
+    if let ExprKind::Path(ref qpath) = func.kind {
+        if let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id() {
+            return match_def_path(cx, def_id, &paths::SLICE_FROM_RAW_PARTS)
+                || match_def_path(cx, def_id, &paths::SLICE_FROM_RAW_PARTS_MUT);
+        }
+    }
+    false
+}
+
+/// Whether `value` is known to be less than `len`, or at most `len` if `inclusive` is set, at
+/// `expr`. Without a `len`, any upper bound of `value` counts.
+fn is_checked<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    value: &'tcx Expr<'tcx>,
+    len: Option<&Expr<'_>>,
+    inclusive: bool,
+) -> bool {
+    let value = peel_hir_expr_while(value, |e| match e.kind {
+        ExprKind::Cast(inner, _) => Some(inner),
+        _ => None,
+    });
+    if let (Some(max), Some(len)) = (value_range(cx, value).max, len.and_then(|len| const_usize(cx, len))) {
+        if max < len || (inclusive && max == len) {
+            return true;
+        }
+    }
+    let bounded_by_guard = guards::dominating_comparisons(cx, expr)
+        .iter()
+        .any(|guard| match (guard, len) {
+            (_, Some(len)) => guard.implies(cx, value, len, inclusive),
+            (Guard::Comparison { lhs, .. }, None) => SpanlessEq::new(cx).eq_expr(lhs, value),
+            (Guard::NonEmpty(_), None) => false,
+        });
+    bounded_by_guard
+        || loop_bound(cx, expr, value).map_or(false, |(end, loop_inclusive)| {
+            len.map_or(true, |len| {
+                (inclusive || !loop_inclusive) && SpanlessEq::new(cx).eq_expr(end, len)
+            })
+        })
+}
+
+/// Returns the end of the range of the `for` loop enclosing `expr` whose loop variable is
+/// `value`, and whether it is inclusive.
+fn loop_bound<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, value: &Expr<'_>) -> Option<(&'tcx Expr<'tcx>, bool)> {
+    let local = path_to_local(value)?;
+    cx.tcx.hir().parent_iter(expr.hir_id).find_map(|(_, node)| {
+        let (pat, arg, ..) = match node {
+            Node::Expr(parent) => higher::for_loop(parent)?,
+            _ => return None,
+        };
+        match (&pat.kind, higher::range(arg)) {
+            (&PatKind::Binding(_, id, _, None), Some(range)) if id == local => {
+                range.end.map(|end| (end, range.limits == RangeLimits::Closed))
+            },
+            _ => None,
+        }
+    })
+}
+
+/// Emits the lint at `expr` with a note pointing at the values whose relation isn't checked.
+fn lint(cx: &LateContext<'_>, expr: &Expr<'_>, msg: &str, values: &[(Span, &str)]) {
+    span_lint_and_then(cx, UNGUARDED_RAW_POINTER_INDEX, expr.span, msg, |diag| {
+        let mut spans = MultiSpan::from_spans(values.iter().map(|&(span, _)| span).collect());
+        for &(span, label) in values {
+            spans.push_span_label(span, label.to_string());
+        }
+        diag.span_note(spans, "the values involved aren't related by a visible check");
+    });
+}
@@ -0,0 +1,102 @@
+//! lint on `expect()` with an empty message or one that doesn't describe the expectation
+
+use crate::methods::UNWRAP_USED;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{in_macro, is_allowed};
+use regex::Regex;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `expect()` on an `Option` or `Result` with an empty message,
+    /// or a message matching one of the regular expressions of the `unhelpful-expect-messages`
+    /// configuration value, which by default match messages that just repeat the method name, like
+    /// `expect("expect failed")`, or say nothing else than that something failed.
+    ///
+    /// **Why is this bad?** The message is shown when the program panics. A message describing
+    /// why the value was expected to be there helps to find out what went wrong, an empty message
+    /// is no better than `unwrap()`.
+    ///
+    /// `unwrap()` is suggested as an alternative, unless the `unwrap_used` lint is enabled.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # let config: Option<u32> = Some(1);
+    /// // Bad
+    /// let x = config.expect("");
+    /// let x = config.expect("expect failed");
+    ///
+    /// // Good
+    /// let x = config.expect("the config is loaded at startup");
+    /// ```
+    pub UNHELPFUL_EXPECT_MESSAGE,
+    pedantic,
+    "`expect()` with an empty message or one that doesn't describe the expectation"
+}
+
+pub struct UnhelpfulExpectMessage {
+    patterns: Vec<Regex>,
+}
+
+impl UnhelpfulExpectMessage {
+    pub fn new(patterns: Vec<Regex>) -> Self {
+        Self { patterns }
+    }
+}
+
+impl_lint_pass!(UnhelpfulExpectMessage => [UNHELPFUL_EXPECT_MESSAGE]);
+
+impl<'tcx> LateLintPass<'tcx> for UnhelpfulExpectMessage {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let (path, receiver, arg) = match expr.kind {
+            ExprKind::MethodCall(path, _, [receiver, arg], _) if path.ident.as_str() == "expect" => {
+                (path, receiver, arg)
+            },
+            _ => return,
+        };
+        if in_macro(expr.span) {
+            return;
+        }
+        let message = match arg.kind {
+            ExprKind::Lit(ref lit) => match lit.node {
+                LitKind::Str(message, _) => message.as_str(),
+                _ => return,
+            },
+            _ => return,
+        };
+        let ty = cx.typeck_results().expr_ty(receiver).peel_refs();
+        if !is_type_diagnostic_item(cx, ty, sym::option_type) && !is_type_diagnostic_item(cx, ty, sym::result_type) {
+            return;
+        }
+
+        let message = message.trim();
+        let msg = if message.is_empty() {
+            "`expect()` with an empty message"
+        } else if self.patterns.iter().any(|pattern| pattern.is_match(message)) {
+            "`expect()` with a message that doesn't describe the expectation"
+        } else {
+            return;
+        };
+        span_lint_and_then(cx, UNHELPFUL_EXPECT_MESSAGE, expr.span, msg, |diag| {
+            diag.help(
+                "describe why the value is expected to be present, e.g. `expect(\"the config is loaded at startup\")`",
+            );
+            if is_allowed(cx, UNWRAP_USED, expr.hir_id) {
+                diag.span_suggestion(
+                    path.ident.span.to(expr.span.shrink_to_hi()),
+                    "or use `unwrap()`",
+                    "unwrap()".to_string(),
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        });
+    }
+}
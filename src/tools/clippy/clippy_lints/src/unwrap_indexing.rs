@@ -0,0 +1,101 @@
+//! lint on indexing the value returned by `unwrap()`, `expect()` or `?`, e.g. `foo().unwrap()[i]`
+
+use crate::utils::known_len::const_usize;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::higher;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_hir::{Expr, ExprKind, MatchSource};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for indexing and slicing of the value returned by `unwrap()` or
+    /// `expect()` on an `Option` or `Result`, or by the `?` operator, like `foo().unwrap()[i]` and
+    /// `foo()?[i]`, where the indexed value is a `Vec`, a slice or an array.
+    ///
+    /// **Why is this bad?** `foo().unwrap()[i]` stacks two panics in a single expression, and the
+    /// panic message doesn't tell which one failed. After `?`, the error handling of the function
+    /// is bypassed by the panicking index. Using `get` turns the index into an `Option` that can be
+    /// handled like the missing value.
+    ///
+    /// **Known problems:** The rewrite depends on the return type of the function, so no code
+    /// suggestion is provided.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # fn values() -> Option<Vec<u32>> { None }
+    /// # fn f(i: usize) -> Option<u32> {
+    /// // Bad
+    /// let x = values().unwrap()[i];
+    /// let x = values()?[i];
+    ///
+    /// // Good
+    /// let x = *values()?.get(i)?;
+    /// # Some(x)
+    /// # }
+    /// ```
+    pub UNWRAP_INDEXING,
+    restriction,
+    "indexing the value returned by `unwrap()` or `?`, like `foo().unwrap()[i]`"
+}
+
+declare_lint_pass!(UnwrapIndexing => [UNWRAP_INDEXING]);
+
+impl<'tcx> LateLintPass<'tcx> for UnwrapIndexing {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let (base, index) = match expr.kind {
+            ExprKind::Index(base, index) if !expr.span.from_expansion() => (base, index),
+            _ => return,
+        };
+        let ty = cx.typeck_results().expr_ty(base);
+        if !is_indexable_collection(cx, ty) || is_in_bounds(cx, ty, index) {
+            return;
+        }
+        if matches!(higher::range(index), Some(range) if range.start.is_none() && range.end.is_none()) {
+            // [..] is ok.
+            return;
+        }
+
+        let (msg, help) = match base.kind {
+            ExprKind::MethodCall(path, _, [receiver, ..], _)
+                if matches!(path.ident.as_str().as_ref(), "unwrap" | "expect") && is_option_or_result(cx, receiver) =>
+            {
+                (
+                    format!("indexing the value returned by `{}()`", path.ident),
+                    "consider propagating the missing value with `?` (or `.ok_or(..)?` for an `Option` in a \
+                     function returning `Result`) and using `.get()` for the index",
+                )
+            },
+            ExprKind::Match(_, _, MatchSource::TryDesugar) => (
+                "indexing the value returned by `?`".to_string(),
+                "consider using `.get()` for the index and handling the `None` like the error",
+            ),
+            _ => return,
+        };
+        span_lint_and_help(cx, UNWRAP_INDEXING, expr.span, &msg, None, help);
+    }
+}
+
+/// Whether `ty` is a (reference to a) `Vec`, slice or array.
+fn is_indexable_collection(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    let ty = ty.peel_refs();
+    matches!(ty.kind(), ty::Slice(_) | ty::Array(..)) || is_type_diagnostic_item(cx, ty, sym::vec_type)
+}
+
+/// Whether `index` is a constant index into the array type `ty` that is in bounds.
+fn is_in_bounds<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, index: &Expr<'_>) -> bool {
+    match ty.peel_refs().kind() {
+        ty::Array(_, len) => const_usize(cx, index)
+            .zip(len.try_eval_usize(cx.tcx, cx.param_env))
+            .map_or(false, |(index, len)| index < u128::from(len)),
+        _ => false,
+    }
+}
+
+fn is_option_or_result(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let ty = cx.typeck_results().expr_ty(expr).peel_refs();
+    is_type_diagnostic_item(cx, ty, sym::option_type) || is_type_diagnostic_item(cx, ty, sym::result_type)
+}
@@ -0,0 +1,114 @@
+//! lint on indexing with a `TryFrom` conversion to `usize` that is unwrapped, e.g.
+//! `v[usize::try_from(x).unwrap()]`
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{higher, is_expr_path_def_path, paths};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty, UintTy};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for indexes and slicing bounds of a `Vec`, a slice or an array that
+    /// are converted to `usize` with `TryFrom` or `TryInto` and unwrapped, like
+    /// `v[usize::try_from(x).unwrap()]` or `v[TryInto::<usize>::try_into(x).expect("index")]`.
+    ///
+    /// **Why is this bad?** The conversion panics if the value doesn't fit in a `usize`, and the
+    /// indexing panics if the converted value is out of bounds. The two checks of the same index
+    /// are made in a single expression, and neither failure can be handled. Propagating the
+    /// conversion error and using `get` handles both.
+    ///
+    /// **Known problems:** The rewrite depends on the return type of the function, so no code
+    /// suggestion is provided. `From` conversions to `usize` can't fail and aren't linted.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # use std::convert::TryFrom;
+    /// # fn f(v: &[u32], x: i64) -> Result<Option<u32>, std::num::TryFromIntError> {
+    /// // Bad
+    /// let y = v[usize::try_from(x).unwrap()];
+    ///
+    /// // Good
+    /// let y = v.get(usize::try_from(x)?).copied();
+    /// # Ok(y)
+    /// # }
+    /// ```
+    pub UNWRAPPED_INDEX_CONVERSION,
+    restriction,
+    "indexing with an unwrapped conversion to `usize`, like `v[usize::try_from(x).unwrap()]`"
+}
+
+declare_lint_pass!(UnwrappedIndexConversion => [UNWRAPPED_INDEX_CONVERSION]);
+
+impl<'tcx> LateLintPass<'tcx> for UnwrappedIndexConversion {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let (base, index) = match expr.kind {
+            ExprKind::Index(base, index) if !expr.span.from_expansion() => (base, index),
+            _ => return,
+        };
+        if !is_indexable_collection(cx, cx.typeck_results().expr_ty(base)) {
+            return;
+        }
+        match higher::range(index) {
+            Some(range) => {
+                for bound in range.start.into_iter().chain(range.end) {
+                    check_index(cx, bound);
+                }
+            },
+            None => check_index(cx, index),
+        }
+    }
+}
+
+/// Emits `UNWRAPPED_INDEX_CONVERSION` if `index` unwraps a conversion to `usize`.
+fn check_index(cx: &LateContext<'_>, index: &Expr<'_>) {
+    let (method, conversion) = match index.kind {
+        ExprKind::MethodCall(path, _, [receiver, ..], _)
+            if matches!(path.ident.as_str().as_ref(), "unwrap" | "expect") && !index.span.from_expansion() =>
+        {
+            (path.ident, receiver)
+        },
+        _ => return,
+    };
+    if !matches!(cx.typeck_results().expr_ty(index).kind(), ty::Uint(UintTy::Usize))
+        || !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(conversion), sym::result_type)
+        || !is_try_conversion(cx, conversion)
+    {
+        return;
+    }
+    span_lint_and_then(
+        cx,
+        UNWRAPPED_INDEX_CONVERSION,
+        index.span,
+        &format!("calling `{}()` on a conversion to `usize` used as an index", method),
+        |diag| {
+            diag.note("the conversion panics if the value doesn't fit, and the indexing if it's out of bounds");
+            diag.help(&format!(
+                "consider propagating the conversion error with `{}?` and using `.get()` for the index",
+                snippet(cx, conversion.span, "..")
+            ));
+        },
+    );
+}
+
+/// Whether `expr` is a call of `TryFrom::try_from` or `TryInto::try_into`. The method call
+/// `x.try_into()` can't infer the index type, so it's only used with a path like
+/// `TryInto::<usize>::try_into(x)`.
+fn is_try_conversion(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Call(fun, [_]) => {
+            is_expr_path_def_path(cx, fun, &paths::TRY_FROM) || is_expr_path_def_path(cx, fun, &paths::TRY_INTO)
+        },
+        _ => false,
+    }
+}
+
+/// Whether `ty` is a (reference to a) `Vec`, slice or array.
+fn is_indexable_collection(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    let ty = ty.peel_refs();
+    matches!(ty.kind(), ty::Slice(_) | ty::Array(..)) || is_type_diagnostic_item(cx, ty, sym::vec_type)
+}
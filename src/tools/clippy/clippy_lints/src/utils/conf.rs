@@ -2,32 +2,120 @@
 
 #![allow(clippy::module_name_repetitions)]
 
+use rustc_span::lev_distance::lev_distance;
 use serde::de::{Deserializer, IgnoredAny, IntoDeserializer, MapAccess, Visitor};
 use serde::Deserialize;
 use std::error::Error;
+use std::marker::PhantomData;
+use std::ops::{Range, RangeInclusive};
 use std::path::{Path, PathBuf};
 use std::{env, fmt, fs, io};
 
+/// An error in the configuration file.
+pub struct ConfError {
+    pub message: String,
+    /// The top-level key of the erroneous value, to point at it in the file with `key_span`.
+    pub key: Option<String>,
+}
+
+impl ConfError {
+    fn new(message: String, key: &str) -> Self {
+        Self {
+            message,
+            key: Some(key.to_string()),
+        }
+    }
+}
+
 /// Conf with parse errors
 #[derive(Default)]
 pub struct TryConf {
     pub conf: Conf,
-    pub errors: Vec<String>,
+    pub errors: Vec<ConfError>,
 }
 
 impl TryConf {
     fn from_error(error: impl Error) -> Self {
         Self {
             conf: Conf::default(),
-            errors: vec![error.to_string()],
+            errors: vec![ConfError {
+                message: error.to_string(),
+                key: None,
+            }],
         }
     }
 }
 
+/// The types of the configuration values, with the description of the expected values used in
+/// the error messages.
+trait ConfType {
+    const DESCRIPTION: &'static str;
+
+    /// Whether the value is in `range`, for the values limited with `#[conf_range(..)]`.
+    fn in_range(&self, _range: &RangeInclusive<u64>) -> bool {
+        true
+    }
+}
+
+impl ConfType for u64 {
+    const DESCRIPTION: &'static str = "a non-negative integer";
+
+    fn in_range(&self, range: &RangeInclusive<u64>) -> bool {
+        range.contains(self)
+    }
+}
+
+impl ConfType for bool {
+    const DESCRIPTION: &'static str = "`true` or `false`";
+}
+
+impl ConfType for String {
+    const DESCRIPTION: &'static str = "a string";
+}
+
+impl ConfType for Vec<String> {
+    const DESCRIPTION: &'static str = "an array of strings";
+}
+
+impl<T: ConfType> ConfType for Option<T> {
+    const DESCRIPTION: &'static str = T::DESCRIPTION;
+
+    fn in_range(&self, range: &RangeInclusive<u64>) -> bool {
+        self.as_ref().map_or(true, |value| value.in_range(range))
+    }
+}
+
+/// Describes the values expected for a configuration value of type `description`, or the integers
+/// in `range` if the value is limited to a range.
+fn expected(description: &str, range: Option<RangeInclusive<u64>>) -> String {
+    match range {
+        Some(range) if *range.end() == u64::MAX => format!("an integer of at least {}", range.start()),
+        Some(range) => format!("an integer between {} and {}", range.start(), range.end()),
+        None => description.to_string(),
+    }
+}
+
+/// The message for the unknown field `name`, suggesting the most similar of `fields`, the names
+/// of the fields in snake case.
+fn unknown_field(name: &str, fields: &[&str]) -> String {
+    let normalized = name.replace('-', "_");
+    let max_distance = normalized.len().max(3) / 3;
+    let best = fields
+        .iter()
+        .map(|field| (lev_distance(&normalized, field), field))
+        .filter(|&(distance, _)| distance <= max_distance)
+        .min_by_key(|&(distance, _)| distance);
+    best.map_or_else(
+        || format!("unknown field `{}`", name),
+        |(_, field)| format!("unknown field `{}`, did you mean `{}`?", name, field.replace('_', "-")),
+    )
+}
+
 macro_rules! define_Conf {
     ($(
         #[$doc:meta]
         $(#[conf_deprecated($dep:literal)])?
+        $(#[conf_range($range:expr)])?
         ($name:ident: $ty:ty = $default:expr),
     )*) => {
         /// Clippy lint configuration
@@ -36,6 +124,9 @@ pub struct Conf {
         }
 
         mod defaults {
+            #[allow(clippy::wildcard_imports)]
+            use super::*;
+
             $(pub fn $name() -> $ty { $default })*
         }
 
@@ -56,6 +147,8 @@ fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializ
         #[allow(non_camel_case_types)]
         enum Field { $($name,)* third_party, }
 
+        const FIELDS: &[&str] = &[$(stringify!($name),)*];
+
         struct ConfVisitor;
 
         impl<'de> Visitor<'de> for ConfVisitor {
@@ -70,13 +163,33 @@ fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error> where V: MapA
                 $(let mut $name = None;)*
                 // could get `Field` here directly, but get `str` first for diagnostics
                 while let Some(name) = map.next_key::<&str>()? {
-                    match Field::deserialize(name.into_deserializer())? {
+                    let field: Result<Field, serde::de::value::Error> = Field::deserialize(name.into_deserializer());
+                    let field = match field {
+                        Ok(field) => field,
+                        Err(_) => {
+                            errors.push(ConfError::new(unknown_field(name, FIELDS), name));
+                            drop(map.next_value::<IgnoredAny>());
+                            continue;
+                        },
+                    };
+                    match field {
                         $(Field::$name => {
-                            $(errors.push(format!("deprecated field `{}`. {}", name, $dep));)?
-                            match map.next_value() {
-                                Err(e) => errors.push(e.to_string()),
+                            $(errors.push(ConfError::new(format!("deprecated field `{}`. {}", name, $dep), name));)?
+                            let range: Option<RangeInclusive<u64>> = None $(.or(Some($range)))?;
+                            let expected = expected(<$ty as ConfType>::DESCRIPTION, range.clone());
+                            match map.next_value::<$ty>() {
+                                Err(e) => errors.push(ConfError::new(
+                                    format!("invalid value for `{}`, expected {}: {}", name, expected, e),
+                                    name,
+                                )),
                                 Ok(value) => match $name {
-                                    Some(_) => errors.push(format!("duplicate field `{}`", name)),
+                                    Some(_) => errors.push(ConfError::new(format!("duplicate field `{}`", name), name)),
+                                    None if !range.map_or(true, |range| value.in_range(&range)) => {
+                                        errors.push(ConfError::new(
+                                            format!("invalid value for `{}`, expected {}", name, expected),
+                                            name,
+                                        ));
+                                    },
                                     None => $name = Some(value),
                                 }
                             }
@@ -92,6 +205,102 @@ fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error> where V: MapA
     };
 }
 
+/// A lint level set in the configuration file.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+    Forbid,
+}
+
+impl LintLevel {
+    pub fn level(self) -> rustc_lint::Level {
+        match self {
+            Self::Allow => rustc_lint::Level::Allow,
+            Self::Warn => rustc_lint::Level::Warn,
+            Self::Deny => rustc_lint::Level::Deny,
+            Self::Forbid => rustc_lint::Level::Forbid,
+        }
+    }
+}
+
+/// The lowest applicability of the suggestions applied by `cargo clippy --fix`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum FixApplicability {
+    MachineApplicable,
+    MaybeIncorrect,
+}
+
+impl ConfType for FixApplicability {
+    const DESCRIPTION: &'static str = "`\"machine-applicable\"` or `\"maybe-incorrect\"`";
+}
+
+impl FixApplicability {
+    pub fn applicability(self) -> rustc_errors::Applicability {
+        match self {
+            Self::MachineApplicable => rustc_errors::Applicability::MachineApplicable,
+            Self::MaybeIncorrect => rustc_errors::Applicability::MaybeIncorrect,
+        }
+    }
+}
+
+/// A table whose entries are kept in the order they appear in the configuration file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderedTable<V>(pub Vec<(String, V)>);
+
+impl<V> Default for OrderedTable<V> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<V> ConfType for OrderedTable<V> {
+    const DESCRIPTION: &'static str = "a table";
+}
+
+impl<'de, V: Deserialize<'de>> Deserialize<'de> for OrderedTable<V> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct TableVisitor<V>(PhantomData<V>);
+
+        impl<'de, V: Deserialize<'de>> Visitor<'de> for TableVisitor<V> {
+            type Value = OrderedTable<V>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a table")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(OrderedTable(entries))
+            }
+        }
+
+        deserializer.deserialize_map(TableVisitor(PhantomData))
+    }
+}
+
+/// A lint profile in the `profiles` table of the configuration file, see the `profiles` module.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProfileConf {
+    /// The lint levels set by the profile, in the order they are applied.
+    pub lints: OrderedTable<LintLevel>,
+    /// The configuration values the profile overrides.
+    pub config: toml::value::Table,
+}
+
 // N.B., this macro is parsed by util/lintlib.py
 define_Conf! {
     /// Lint: CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE. The minimum rust version that the project supports
@@ -99,6 +308,7 @@ fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error> where V: MapA
     /// Lint: BLACKLISTED_NAME. The list of blacklisted names to lint about. NB: `bar` is not here since it has legitimate uses
     (blacklisted_names: Vec<String> = ["foo", "baz", "quux"].iter().map(ToString::to_string).collect()),
     /// Lint: COGNITIVE_COMPLEXITY. The maximum cognitive complexity a function can have
+    #[conf_range(1..=u64::MAX)]
     (cognitive_complexity_threshold: u64 = 25),
     /// DEPRECATED LINT: CYCLOMATIC_COMPLEXITY. Use the Cognitive Complexity lint instead.
     #[conf_deprecated("Please use `cognitive-complexity-threshold` instead")]
@@ -127,6 +337,7 @@ fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error> where V: MapA
     /// Lint: TOO_MANY_ARGUMENTS. The maximum number of argument a function or method can have
     (too_many_arguments_threshold: u64 = 7),
     /// Lint: TYPE_COMPLEXITY. The maximum complexity a type can have
+    #[conf_range(1..=u64::MAX)]
     (type_complexity_threshold: u64 = 250),
     /// Lint: MANY_SINGLE_CHAR_NAMES. The maximum number of single char bindings a scope may have
     (single_char_binding_names_threshold: u64 = 4),
@@ -145,6 +356,7 @@ fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error> where V: MapA
     /// Lint: LARGE_TYPE_PASS_BY_MOVE. The minimum size (in bytes) to consider a type for passing by reference instead of by value.
     (pass_by_value_size_limit: u64 = 256),
     /// Lint: TOO_MANY_LINES. The maximum number of lines a function or method can have
+    #[conf_range(1..=u64::MAX)]
     (too_many_lines_threshold: u64 = 100),
     /// Lint: LARGE_STACK_ARRAYS, LARGE_CONST_ARRAYS. The maximum allowed size for arrays on the stack
     (array_size_threshold: u64 = 512_000),
@@ -164,8 +376,31 @@ fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error> where V: MapA
     (unreadable_literal_lint_fractions: bool = true),
     /// Lint: UPPER_CASE_ACRONYMS. Enables verbose mode. Triggers if there is more than one uppercase char next to each other
     (upper_case_acronyms_aggressive: bool = false),
+    /// Lint: INDEXING_SLICING, INDEX_MAP, INDEX_UNDERFLOW. Whether to allow indexing and slicing in `#[test]` functions and `#[cfg(test)]` code. The default will change to `true`
+    (allow_indexing_slicing_in_tests: bool = false),
+    /// Lint: INDEXING_SLICING. Arrays with at most this many elements may be indexed and sliced with any index, e.g. lookup tables and ring buffers. `0` disables this
+    (indexing_slicing_max_array_size: u64 = 0),
+    /// Lint: REACHABLE_PANIC. The functions, written as fully qualified paths (e.g. `my_crate::debug::dump`), whose operations that may panic aren't linted and whose calls aren't followed
+    (reachable_panic_allowed: Vec<String> = Vec::new()),
+    /// Lint: REPEATED_INDEXING. The minimum number of times the same non-trivial index expression has to appear in a function to be linted
+    #[conf_range(2..=u64::MAX)]
+    (repeated_indexing_threshold: u64 = 3),
+    /// Lint: UNHELPFUL_EXPECT_MESSAGE. Regular expressions matching (trimmed) `expect()` messages that don't describe the expectation
+    (unhelpful_expect_messages: Vec<String> = [r"(?i)^(expect|expected|unwrap|expect failed|unwrap failed|failed|fail|error|err|none|oops|todo)[.!]*$"].iter().map(ToString::to_string).collect()),
     /// Lint: _CARGO_COMMON_METADATA. For internal testing only, ignores the current `publish` settings in the Cargo manifest.
     (cargo_ignore_publish: bool = false),
+    /// Lint level overrides for parts of the crate. Maps module paths (e.g. `generated::proto`) or file globs relative to the configuration file (e.g. `"src/generated/**"`) to tables of lint levels (e.g. `{ indexing_slicing = "allow" }`)
+    (levels: OrderedTable<OrderedTable<LintLevel>> = OrderedTable::default()),
+    /// Paths of dynamic libraries with additional lints, relative to the configuration file. See the `plugins` module of `clippy_lints` for how to write one
+    (plugins: Vec<String> = Vec::new()),
+    /// Lint profiles selected with `cargo clippy --profile=<name>`. Maps profile names to tables of lint levels (`lints`) and configuration values (`config`), e.g. `{ lints = { "clippy::pedantic" = "warn" }, config = { too-many-lines-threshold = 50 } }`
+    (profiles: OrderedTable<ProfileConf> = OrderedTable::default()),
+    /// The lowest applicability of the suggestions applied by `cargo clippy --fix`: `"machine-applicable"` or `"maybe-incorrect"`, which also applies suggestions that may change the behavior of the code or not compile, like replacing indexing with `.get()`
+    (fix_applicability: FixApplicability = FixApplicability::MachineApplicable),
+    /// Whether the lints in the `macro_rules!` and function-like macros of other crates are reported at the macro calls, with a note naming the macro and its crate, instead of being skipped
+    (report_in_external_macros: bool = false),
+    /// Lints moved to other lint groups, whose default levels they get. Maps lint names (e.g. `indexing_slicing`) to the names of the groups (e.g. `"correctness"`)
+    (reclassify: OrderedTable<String> = OrderedTable::default()),
 }
 
 /// Search for the configuration file.
@@ -209,3 +444,59 @@ pub fn read(path: &Path) -> TryConf {
     };
     toml::from_str(&content).unwrap_or_else(TryConf::from_error)
 }
+
+/// Read the `toml` configuration file like `read`, with the values in `overrides` replacing the
+/// ones in the file. Without a file, `overrides` are applied to the default configuration.
+pub fn read_with_overrides(path: Option<&Path>, overrides: &toml::value::Table) -> TryConf {
+    let mut table: toml::value::Table = match path.map(fs::read_to_string) {
+        None => toml::value::Table::new(),
+        Some(Err(e)) => return TryConf::from_error(e),
+        Some(Ok(content)) => match toml::from_str(&content) {
+            Ok(table) => table,
+            Err(e) => return TryConf::from_error(e),
+        },
+    };
+    table.extend(overrides.iter().map(|(key, value)| (key.clone(), value.clone())));
+    // `TryConf` borrows the keys from the input, which a `toml::Value` can't do
+    let mut conf = match toml::to_string(&table) {
+        Ok(content) => toml::from_str(&content).unwrap_or_else(TryConf::from_error),
+        Err(e) => TryConf::from_error(e),
+    };
+    // the overridden values aren't in the file
+    for error in &mut conf.errors {
+        if error.key.as_ref().map_or(false, |key| overrides.contains_key(key)) {
+            error.key = None;
+        }
+    }
+    conf
+}
+
+/// Returns the byte range of the top-level key `key` in the configuration file `src`: the key of
+/// a `key = ..` line before the first table header, or the first table header `[key]` or
+/// `[key.(..)]`. The key may be quoted.
+pub fn key_span(src: &str, key: &str) -> Option<Range<usize>> {
+    let is_key_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '"';
+    let quoted = format!("\"{}\"", key);
+    let mut offset = 0;
+    let mut top_level = true;
+    for line in src.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+        let trimmed = line.trim_start();
+        let candidate = if let Some(header) = trimmed.strip_prefix('[') {
+            top_level = false;
+            header.trim_start_matches('[').trim_start()
+        } else if top_level {
+            trimmed
+        } else {
+            continue;
+        };
+        let start = line_start + line.len() - candidate.len();
+        for key in &[key, &*quoted] {
+            if candidate.starts_with(key) && !candidate[key.len()..].starts_with(is_key_char) {
+                return Some(start..start + key.len());
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,130 @@
+//! The functions at the boundary with foreign code and the values their callers provide, shared by
+//! the lints on FFI safety.
+//!
+//! A function is at the FFI boundary if it has a foreign ABI, like `extern "C" fn`, or takes a raw
+//! pointer together with an integer, like `fn sum(data: *const u32, len: usize)`. The values
+//! provided by the caller are the parameters, and the local variables initialized or assigned
+//! with an expression using one of them, like `n` in `let n = len as usize - 1` or `s` in
+//! `let s = slice::from_raw_parts(data, len)`.
+
+use clippy_utils::iter_input_pats;
+use rustc_hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirIdSet, Local, PatKind, TyKind};
+use rustc_lint::LateContext;
+use rustc_middle::hir::map::Map;
+use rustc_target::spec::abi::Abi;
+
+/// Returns the parameters of the function declared with `decl` whose type is a raw pointer, like
+/// `data` in `fn f(data: *const u8)`.
+pub fn raw_ptr_params(decl: &FnDecl<'_>, body: &Body<'_>) -> HirIdSet {
+    iter_input_pats(decl, body)
+        .zip(decl.inputs.iter())
+        .filter_map(|(param, ty)| match (&param.pat.kind, &ty.kind) {
+            (&PatKind::Binding(_, id, ..), TyKind::Ptr(_)) => Some(id),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether the function `kind` is at the FFI boundary, see the module documentation.
+pub fn is_ffi_boundary(cx: &LateContext<'_>, kind: FnKind<'_>, decl: &FnDecl<'_>, body: &Body<'_>) -> bool {
+    let abi = match kind {
+        FnKind::ItemFn(_, _, header, _) => header.abi,
+        FnKind::Method(_, sig, _) => sig.header.abi,
+        FnKind::Closure => return false,
+    };
+    abi != Abi::Rust
+        || (!raw_ptr_params(decl, body).is_empty()
+            && body
+                .params
+                .iter()
+                .any(|param| cx.typeck_results().pat_ty(param.pat).is_integral()))
+}
+
+/// Returns the parameters of the function of `body` and the local variables holding values
+/// computed from them, see the module documentation.
+pub fn caller_provided_locals(body: &Body<'_>) -> HirIdSet {
+    let mut locals = HirIdSet::default();
+    for param in body.params {
+        param.pat.each_binding(|_, id, _, _| {
+            locals.insert(id);
+        });
+    }
+
+    let mut visitor = DefinitionsVisitor {
+        locals: &mut locals,
+        changed: true,
+    };
+    // a variable can be assigned a caller-provided value after it is used to initialize another
+    while visitor.changed {
+        visitor.changed = false;
+        visitor.visit_expr(&body.value);
+    }
+    locals
+}
+
+/// Adds the local variables initialized or assigned with an expression using one of `locals` to
+/// `locals`.
+struct DefinitionsVisitor<'a> {
+    locals: &'a mut HirIdSet,
+    changed: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for DefinitionsVisitor<'a> {
+    type Map = Map<'tcx>;
+
+    fn visit_local(&mut self, local: &'tcx Local<'tcx>) {
+        if let Some(init) = local.init {
+            if uses_any(init, self.locals) {
+                let (locals, changed) = (&mut *self.locals, &mut self.changed);
+                local.pat.each_binding(|_, id, _, _| {
+                    *changed |= locals.insert(id);
+                });
+            }
+        }
+        rustc_hir::intravisit::walk_local(self, local);
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Assign(place, value, _) | ExprKind::AssignOp(_, place, value) = expr.kind {
+            if let Some(id) = clippy_utils::path_to_local(place) {
+                if uses_any(value, self.locals) {
+                    self.changed |= self.locals.insert(id);
+                }
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Whether `expr` uses one of the local variables `locals`.
+pub fn uses_any(expr: &Expr<'_>, locals: &HirIdSet) -> bool {
+    struct UsesVisitor<'a> {
+        locals: &'a HirIdSet,
+        found: bool,
+    }
+
+    impl<'a, 'tcx> Visitor<'tcx> for UsesVisitor<'a> {
+        type Map = Map<'tcx>;
+
+        fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+            if clippy_utils::path_to_local(expr).map_or(false, |id| self.locals.contains(&id)) {
+                self.found = true;
+            } else if !self.found {
+                walk_expr(self, expr);
+            }
+        }
+
+        fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+            NestedVisitorMap::None
+        }
+    }
+
+    let mut visitor = UsesVisitor { locals, found: false };
+    visitor.visit_expr(expr);
+    visitor.found
+}
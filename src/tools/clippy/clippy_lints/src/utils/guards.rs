@@ -0,0 +1,437 @@
+//! The comparisons guarding an expression, like `i < v.len()` in `if i < v.len() { v[i] }`,
+//! shared by the lints that are silenced by a check of the value that would make the expression
+//! panic.
+//!
+//! Checks that a collection isn't empty, like `!v.is_empty()` or `v.len() != 0`, are guards as
+//! well.
+//!
+//! An expression is guarded by the conditions of the `if` expressions and `while` loops it is
+//! nested in, by the left operand of the `&&` (or the negated left operand of the `||`) it is the
+//! right operand of, and by the `assert!`s and the `if`s that return early (or otherwise diverge)
+//! preceding it in the enclosing blocks. Unlike the value-range analysis, the operands of a
+//! comparison can be any expressions built from local variables, constants, fields, `len()` calls
+//! and arithmetic, like `i + 1 < self.items.len()`. A comparison only guards the expression if none
+//! of the local variables in its operands are mutated in the guarded code.
+//!
+//! The arm of a `match` on the length of a collection, like `2 => v[1]` in `match v.len() { .. }`,
+//! gives the length of the collection in the arm, see `matched_len`.
+
+use crate::consts::{constant_simple, Constant};
+use clippy_utils::comparisons::{normalize_comparison, Rel};
+use clippy_utils::usage::mutated_variables;
+use clippy_utils::{path_to_local, SpanlessEq};
+use if_chain::if_chain;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{
+    BinOpKind, Block, Expr, ExprKind, HirId, HirIdSet, MatchSource, Node, Pat, PatKind, RangeEnd, Stmt, StmtKind, UnOp,
+};
+use rustc_lint::LateContext;
+use rustc_span::Span;
+use std::iter;
+
+/// A condition known to hold when an expression is evaluated.
+#[derive(Clone, Copy, Debug)]
+pub enum Guard<'tcx> {
+    /// `lhs < rhs`, or `lhs <= rhs` if `inclusive` is set.
+    Comparison {
+        lhs: &'tcx Expr<'tcx>,
+        rhs: &'tcx Expr<'tcx>,
+        inclusive: bool,
+    },
+    /// The collection isn't empty.
+    NonEmpty(&'tcx Expr<'tcx>),
+}
+
+impl<'tcx> Guard<'tcx> {
+    /// Whether the guard implies `a < b`, or `a <= b` if `inclusive` is set.
+    pub fn implies(&self, cx: &LateContext<'_>, a: &Expr<'_>, b: &Expr<'_>, inclusive: bool) -> bool {
+        match *self {
+            Self::Comparison {
+                lhs,
+                rhs,
+                inclusive: guard_inclusive,
+            } => {
+                (inclusive || !guard_inclusive)
+                    && SpanlessEq::new(cx).eq_expr(lhs, a)
+                    && SpanlessEq::new(cx).eq_expr(rhs, b)
+            },
+            Self::NonEmpty(_) => false,
+        }
+    }
+
+    /// Whether the guard implies `value < collection.len()`, or `value <= collection.len()` if
+    /// `inclusive` is set.
+    pub fn implies_len(&self, cx: &LateContext<'_>, value: &Expr<'_>, collection: &Expr<'_>, inclusive: bool) -> bool {
+        match *self {
+            Self::Comparison {
+                lhs,
+                rhs,
+                inclusive: guard_inclusive,
+            } => {
+                (inclusive || !guard_inclusive)
+                    && SpanlessEq::new(cx).eq_expr(lhs, value)
+                    && len_receiver(rhs).map_or(false, |receiver| SpanlessEq::new(cx).eq_expr(receiver, collection))
+            },
+            Self::NonEmpty(_) => false,
+        }
+    }
+
+    /// Whether the guard implies that `collection` isn't empty, i.e. it is
+    /// `!collection.is_empty()`, `collection.len() != 0`, `0 < collection.len()` or
+    /// `1 <= collection.len()`.
+    pub fn implies_non_empty(&self, cx: &LateContext<'_>, collection: &Expr<'_>) -> bool {
+        let non_empty = match *self {
+            Self::Comparison { lhs, rhs, inclusive } => {
+                let min = if inclusive { 1 } else { 0 };
+                match constant_simple(cx, cx.typeck_results(), lhs) {
+                    Some(Constant::Int(n)) if n >= min => len_receiver(rhs),
+                    _ => None,
+                }
+            },
+            Self::NonEmpty(non_empty) => Some(non_empty),
+        };
+        non_empty.map_or(false, |non_empty| SpanlessEq::new(cx).eq_expr(non_empty, collection))
+    }
+
+    /// Whether none of the `mutated` local variables appear in the guard, see
+    /// `is_unmutated_operand`.
+    fn is_unmutated(&self, cx: &LateContext<'_>, mutated: &HirIdSet) -> bool {
+        match *self {
+            Self::Comparison { lhs, rhs, .. } => {
+                is_unmutated_operand(cx, lhs, mutated) && is_unmutated_operand(cx, rhs, mutated)
+            },
+            Self::NonEmpty(collection) => is_unmutated_operand(cx, collection, mutated),
+        }
+    }
+}
+
+/// Returns the guards of `expr`, see the module documentation.
+pub fn dominating_comparisons<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Vec<Guard<'tcx>> {
+    let mut guards = Vec::new();
+    let mut child = expr.hir_id;
+    for (parent_id, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Expr(parent) => match parent.kind {
+                ExprKind::If(cond, then, else_) => {
+                    if then.hir_id == child {
+                        add_conditions(cx, cond, false, then, &mut guards);
+                    } else if let Some(else_) = else_.filter(|else_| else_.hir_id == child) {
+                        add_conditions(cx, cond, true, else_, &mut guards);
+                    }
+                },
+                ExprKind::Match(cond, [arm, ..], MatchSource::WhileDesugar) if arm.hir_id == child => {
+                    add_conditions(cx, cond, false, arm.body, &mut guards);
+                },
+                ExprKind::Binary(op, lhs, rhs) if rhs.hir_id == child => match op.node {
+                    BinOpKind::And => add_conditions(cx, lhs, false, rhs, &mut guards),
+                    BinOpKind::Or => add_conditions(cx, lhs, true, rhs, &mut guards),
+                    _ => {},
+                },
+                ExprKind::Closure(..) => break,
+                _ => {},
+            },
+            Node::Block(block) => add_block_guards(cx, block, child, &mut guards),
+            Node::Arm(_) | Node::Stmt(_) | Node::Local(_) => {},
+            _ => break,
+        }
+        child = parent_id;
+    }
+    guards
+}
+
+/// Whether `expr` is guarded by `a < b`, or `a <= b` if `inclusive` is set.
+pub fn is_dominated_by<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    a: &Expr<'_>,
+    b: &Expr<'_>,
+    inclusive: bool,
+) -> bool {
+    dominating_comparisons(cx, expr)
+        .iter()
+        .any(|guard| guard.implies(cx, a, b, inclusive))
+}
+
+/// Whether `expr` is guarded by `value < collection.len()`, or `value <= collection.len()` if
+/// `inclusive` is set.
+pub fn is_len_guarded<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    value: &Expr<'_>,
+    collection: &Expr<'_>,
+    inclusive: bool,
+) -> bool {
+    dominating_comparisons(cx, expr)
+        .iter()
+        .any(|guard| guard.implies_len(cx, value, collection, inclusive))
+}
+
+/// Whether `expr` is guarded by a check that `collection` isn't empty.
+pub fn is_non_empty_guarded<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, collection: &Expr<'_>) -> bool {
+    dominating_comparisons(cx, expr)
+        .iter()
+        .any(|guard| guard.implies_non_empty(cx, collection))
+}
+
+/// The lengths of a collection in an arm of a `match` on its length, see `matched_len`.
+pub struct MatchedLen {
+    pub min: u128,
+    /// The largest length, `None` if the pattern is open, like `2..`.
+    pub max: Option<u128>,
+    /// The span of the pattern of the arm.
+    pub span: Span,
+}
+
+/// Returns the lengths `collection` can have at `expr` if `expr` is in an arm of a
+/// `match collection.len() { .. }` whose pattern is a constant, like `2 => ..`, a range, like
+/// `2..=4 => ..`, or alternatives of them. `collection` must not be mutated in the arm.
+pub fn matched_len<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, collection: &Expr<'_>) -> Option<MatchedLen> {
+    let mut child = expr.hir_id;
+    for (parent_id, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Expr(parent) => match parent.kind {
+                ExprKind::Match(scrutinee, arms, MatchSource::Normal) => {
+                    let arm = arms.iter().find(|arm| arm.hir_id == child);
+                    if_chain! {
+                        if let Some(arm) = arm;
+                        if let Some(receiver) = len_receiver(scrutinee);
+                        if SpanlessEq::new(cx).eq_expr(receiver, collection);
+                        then {
+                            let mutated = mutated_variables(arm.body, cx)?;
+                            if !is_unmutated_operand(cx, collection, &mutated) {
+                                return None;
+                            }
+                            let (min, max) = pat_values(cx, arm.pat)?;
+                            return Some(MatchedLen {
+                                min,
+                                max,
+                                span: arm.pat.span,
+                            });
+                        }
+                    }
+                },
+                ExprKind::Closure(..) => break,
+                _ => {},
+            },
+            Node::Arm(_) | Node::Block(_) | Node::Stmt(_) | Node::Local(_) => {},
+            _ => break,
+        }
+        child = parent_id;
+    }
+    None
+}
+
+/// Returns the smallest and the largest value matched by the integer pattern `pat`, if it is a
+/// constant, a range or alternatives of them.
+fn pat_values(cx: &LateContext<'_>, pat: &Pat<'_>) -> Option<(u128, Option<u128>)> {
+    let int = |expr: &Expr<'_>| match constant_simple(cx, cx.typeck_results(), expr) {
+        Some(Constant::Int(n)) => Some(n),
+        _ => None,
+    };
+    match pat.kind {
+        PatKind::Lit(lit) => int(lit).map(|n| (n, Some(n))),
+        PatKind::Range(lo, hi, end) => {
+            let max = match hi {
+                Some(hi) if end == RangeEnd::Excluded => Some(int(hi)?.checked_sub(1)?),
+                Some(hi) => Some(int(hi)?),
+                None => None,
+            };
+            Some((lo.map_or(Some(0), int)?, max))
+        },
+        PatKind::Binding(.., Some(sub)) => pat_values(cx, sub),
+        PatKind::Or(pats) => pats.iter().try_fold((u128::MAX, Some(0)), |(min, max), pat| {
+            let (pat_min, pat_max) = pat_values(cx, pat)?;
+            Some((min.min(pat_min), max.zip(pat_max).map(|(a, b)| a.max(b))))
+        }),
+        _ => None,
+    }
+}
+
+/// Returns the receiver of `expr` if it is a `len()` call.
+fn len_receiver<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match expr.kind {
+        ExprKind::MethodCall(path, _, [receiver], _) if path.ident.name == sym!(len) => Some(receiver),
+        _ => None,
+    }
+}
+
+/// Adds the comparisons that hold in `scope` if `cond` is true, or false if `negate` is set.
+fn add_conditions<'tcx>(
+    cx: &LateContext<'tcx>,
+    cond: &'tcx Expr<'tcx>,
+    negate: bool,
+    scope: &'tcx Expr<'tcx>,
+    guards: &mut Vec<Guard<'tcx>>,
+) {
+    let mut comparisons = Vec::new();
+    add_comparisons(cx, cond, negate, &mut comparisons);
+    if comparisons.is_empty() {
+        return;
+    }
+    if let Some(mutated) = mutated_variables(scope, cx) {
+        guards.extend(comparisons.into_iter().filter(|guard| guard.is_unmutated(cx, &mutated)));
+    }
+}
+
+/// Adds the `<` and `<=` comparisons and the non-emptiness checks that hold if `cond` is true, or
+/// false if `negate` is set.
+fn add_comparisons<'tcx>(
+    cx: &LateContext<'tcx>,
+    cond: &'tcx Expr<'tcx>,
+    negate: bool,
+    comparisons: &mut Vec<Guard<'tcx>>,
+) {
+    match cond.kind {
+        ExprKind::DropTemps(cond) => add_comparisons(cx, cond, negate, comparisons),
+        ExprKind::Unary(UnOp::Not, cond) => add_comparisons(cx, cond, !negate, comparisons),
+        // `!(a || b)` is `!a && !b`
+        ExprKind::Binary(op, lhs, rhs)
+            if (op.node == BinOpKind::And && !negate) || (op.node == BinOpKind::Or && negate) =>
+        {
+            add_comparisons(cx, lhs, negate, comparisons);
+            add_comparisons(cx, rhs, negate, comparisons);
+        }
+        ExprKind::MethodCall(path, _, [receiver], _) if path.ident.name == sym!(is_empty) && negate => {
+            comparisons.push(Guard::NonEmpty(receiver));
+        },
+        // `x.len() != 0`
+        ExprKind::Binary(op, lhs, rhs)
+            if (op.node == BinOpKind::Ne && !negate) || (op.node == BinOpKind::Eq && negate) =>
+        {
+            let is_zero = |e| constant_simple(cx, cx.typeck_results(), e) == Some(Constant::Int(0));
+            let receiver = match (len_receiver(lhs), len_receiver(rhs)) {
+                (Some(receiver), _) if is_zero(rhs) => receiver,
+                (_, Some(receiver)) if is_zero(lhs) => receiver,
+                _ => return,
+            };
+            comparisons.push(Guard::NonEmpty(receiver));
+        }
+        ExprKind::Binary(op, lhs, rhs) => {
+            let op = if negate {
+                match op.node {
+                    BinOpKind::Lt => BinOpKind::Ge,
+                    BinOpKind::Le => BinOpKind::Gt,
+                    BinOpKind::Gt => BinOpKind::Le,
+                    BinOpKind::Ge => BinOpKind::Lt,
+                    _ => return,
+                }
+            } else {
+                op.node
+            };
+            if let Some((rel @ (Rel::Lt | Rel::Le), lhs, rhs)) = normalize_comparison(op, lhs, rhs) {
+                comparisons.push(Guard::Comparison {
+                    lhs,
+                    rhs,
+                    inclusive: rel == Rel::Le,
+                });
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Adds the comparisons checked by the `assert!`s and the diverging `if`s without `else`
+/// preceding `child`, a statement or the trailing expression of `block`. The local variables in
+/// their operands must not be mutated in the statements following them, up to and including
+/// `child`.
+fn add_block_guards<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'tcx>,
+    child: HirId,
+    guards: &mut Vec<Guard<'tcx>>,
+) {
+    let pos = block
+        .stmts
+        .iter()
+        .position(|stmt| stmt.hir_id == child)
+        .unwrap_or(block.stmts.len());
+    let checks: Vec<(usize, &Expr<'_>)> = block.stmts[..pos]
+        .iter()
+        .enumerate()
+        .filter_map(|(i, stmt)| match stmt.kind {
+            // `assert!(cond)` expands to `if !cond { panic!(..) }`
+            StmtKind::Expr(expr) | StmtKind::Semi(expr) => match expr.kind {
+                ExprKind::If(cond, then, None) if diverges(cx, then) => Some((i, cond)),
+                _ => None,
+            },
+            StmtKind::Local(_) | StmtKind::Item(_) => None,
+        })
+        .collect();
+    if checks.is_empty() {
+        return;
+    }
+
+    // the local variables mutated by each statement up to `child`, and by `child`
+    let child_mutated = match block.stmts.get(pos) {
+        Some(stmt) => stmt_mutated_variables(cx, stmt),
+        None => block
+            .expr
+            .map_or_else(|| Some(HirIdSet::default()), |expr| mutated_variables(expr, cx)),
+    };
+    let mutated: Vec<Option<HirIdSet>> = block.stmts[..pos]
+        .iter()
+        .map(|stmt| stmt_mutated_variables(cx, stmt))
+        .chain(iter::once(child_mutated))
+        .collect();
+
+    for (i, cond) in checks {
+        let mut comparisons = Vec::new();
+        add_comparisons(cx, cond, true, &mut comparisons);
+        let mut mutated_after = HirIdSet::default();
+        for stmt_mutated in &mutated[i + 1..] {
+            match stmt_mutated {
+                Some(stmt_mutated) => mutated_after.extend(stmt_mutated),
+                None => return,
+            }
+        }
+        guards.extend(
+            comparisons
+                .into_iter()
+                .filter(|guard| guard.is_unmutated(cx, &mutated_after)),
+        );
+    }
+}
+
+/// Returns the local variables mutated by `stmt`, see [`mutated_variables`].
+fn stmt_mutated_variables<'tcx>(cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'tcx>) -> Option<HirIdSet> {
+    match stmt.kind {
+        StmtKind::Expr(expr) | StmtKind::Semi(expr) => mutated_variables(expr, cx),
+        StmtKind::Local(local) => local
+            .init
+            .map_or_else(|| Some(HirIdSet::default()), |init| mutated_variables(init, cx)),
+        StmtKind::Item(_) => Some(HirIdSet::default()),
+    }
+}
+
+/// Whether the block `expr` never completes, e.g. because it returns early or panics.
+fn diverges(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    // the trailing expression of a block is coerced to the type expected from the block
+    cx.typeck_results().expr_ty(expr).is_never()
+        || matches!(expr.kind, ExprKind::Block(block, _) if block.expr.map_or(false, |e| diverges(cx, e)))
+}
+
+/// Whether `expr` always has the same value if none of the `mutated` local variables change: it
+/// is built from literals, constants, local variables that aren't `mutated`, fields, `len()` calls
+/// and operators.
+fn is_unmutated_operand(cx: &LateContext<'_>, expr: &Expr<'_>, mutated: &HirIdSet) -> bool {
+    match expr.kind {
+        ExprKind::Lit(_) => true,
+        ExprKind::Path(ref qpath) => match path_to_local(expr) {
+            Some(local) => !mutated.contains(&local),
+            None => matches!(
+                cx.qpath_res(qpath, expr.hir_id),
+                Res::Def(DefKind::Const | DefKind::AssocConst, _)
+            ),
+        },
+        ExprKind::Field(base, _) | ExprKind::Unary(_, base) | ExprKind::Cast(base, _) => {
+            is_unmutated_operand(cx, base, mutated)
+        },
+        ExprKind::Binary(_, lhs, rhs) | ExprKind::Index(lhs, rhs) => {
+            is_unmutated_operand(cx, lhs, mutated) && is_unmutated_operand(cx, rhs, mutated)
+        },
+        ExprKind::MethodCall(path, _, [receiver], _) if path.ident.name == sym!(len) => {
+            is_unmutated_operand(cx, receiver, mutated)
+        },
+        _ => false,
+    }
+}
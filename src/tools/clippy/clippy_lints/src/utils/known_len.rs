@@ -0,0 +1,271 @@
+//! The statically known length of arrays and of local `Vec`s, shared by the lints reporting
+//! out of bounds accesses.
+
+use crate::consts::{constant_with_locals, Constant};
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::usage::mutated_variables;
+use clippy_utils::{higher, path_to_local, path_to_local_id};
+use if_chain::if_chain;
+use rustc_ast::ast::RangeLimits;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Expr, ExprKind, HirId, Mutability, Node, QPath, StmtKind};
+use rustc_lint::LateContext;
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty;
+use rustc_span::{sym, Span};
+
+/// The length of a collection, see `known_len`.
+pub struct KnownLen {
+    pub len: u128,
+    /// `"array"`, `"vector"` or `"slice"`
+    pub kind: &'static str,
+    /// The span of the expression setting the length, e.g. the initializer of the local.
+    pub span: Span,
+}
+
+/// Returns the length of `collection` at `expr`, if it is an array (or a reference to one) of a
+/// constant length, or a local `Vec` whose length is known, see `vec_len`.
+pub fn known_len<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, collection: &Expr<'_>) -> Option<KnownLen> {
+    match cx.typeck_results().expr_ty(collection).peel_refs().kind() {
+        ty::Array(_, len) => Some(KnownLen {
+            len: len.try_eval_usize(cx.tcx, cx.param_env)?.into(),
+            kind: "array",
+            span: definition_span(cx, collection),
+        }),
+        _ => vec_len(cx, expr, collection).map(|(len, span)| KnownLen {
+            len,
+            kind: "vector",
+            span,
+        }),
+    }
+}
+
+/// Returns the length of the slice `slice` at `expr`, like `known_len`, looking through references
+/// and subslices with constant bounds, like `&x[2..6]`, or `&x[2..]` if the length of `x` is known.
+/// Subslices out of bounds of `x` are left to `OUT_OF_BOUNDS_INDEXING`.
+pub fn known_slice_len<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, mut slice: &Expr<'_>) -> Option<KnownLen> {
+    while let ExprKind::AddrOf(_, _, inner) = slice.kind {
+        slice = inner;
+    }
+    if let ExprKind::Index(base, index) = slice.kind {
+        if let Some(range) = higher::range(index) {
+            let base_len = known_slice_len(cx, expr, base).map(|len| len.len);
+            let start = range.start.map_or(Some(0), |start| const_usize(cx, start))?;
+            let end = match range.end {
+                Some(end) if range.limits == RangeLimits::Closed => const_usize(cx, end)?.checked_add(1)?,
+                Some(end) => const_usize(cx, end)?,
+                None => base_len?,
+            };
+            if end < start || base_len.map_or(false, |len| end > len) {
+                return None;
+            }
+            return Some(KnownLen {
+                len: end - start,
+                kind: "slice",
+                span: slice.span,
+            });
+        }
+    }
+    known_len(cx, expr, slice)
+}
+
+/// Returns the span of the initializer of the local `array` refers to, or of its binding if it has
+/// none, e.g. a parameter. Returns the span of the declaration of the field if `array` is a field
+/// of a struct of this crate, like `self.field`, and the span of `array` otherwise.
+pub fn definition_span(cx: &LateContext<'_>, array: &Expr<'_>) -> Span {
+    let hir = cx.tcx.hir();
+    if let ExprKind::Field(base, name) = array.kind {
+        return match cx.typeck_results().expr_ty_adjusted(base).peel_refs().kind() {
+            ty::Adt(adt, _) if adt.is_struct() => adt
+                .non_enum_variant()
+                .fields
+                .iter()
+                .find(|field| field.ident.name == name.name)
+                .and_then(|field| field.did.as_local())
+                .map_or(array.span, |def_id| cx.tcx.def_span(def_id)),
+            _ => array.span,
+        };
+    }
+    match path_to_local(array) {
+        Some(local_id) => match hir.find(hir.get_parent_node(local_id)) {
+            Some(Node::Local(local)) if local.pat.hir_id == local_id => {
+                local.init.map_or(local.pat.span, |init| init.span.source_callsite())
+            },
+            _ => hir.span(local_id),
+        },
+        None => array.span,
+    }
+}
+
+/// Returns the length of the `Vec` the local variable `array` refers to at `expr`, if it is known,
+/// together with the span of the expression setting it.
+///
+/// The length is known if the variable is initialized with `vec![elem; N]` or
+/// `Vec::with_capacity(_)`, is only changed by `resize(N, elem)` statements following its
+/// declaration, and `N` is a constant. Any other mutation before `expr` gives up, except for
+/// assigning to its elements, like `v[i] = x`. If `expr` is a method call, like `v.remove(5)`, the
+/// mutation by the call itself happens after it.
+pub fn vec_len<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, array: &Expr<'_>) -> Option<(u128, Span)> {
+    let hir = cx.tcx.hir();
+    let local_id = path_to_local(array)?;
+    let local = match hir.find(hir.get_parent_node(local_id)) {
+        Some(Node::Local(local)) if local.pat.hir_id == local_id => local,
+        _ => return None,
+    };
+    let init = local.init?;
+    let mut len_span = init.span.source_callsite();
+    let mut len = match higher::vec_macro(cx, init) {
+        Some(higher::VecArgs::Repeat(_, len)) => const_usize(cx, len)?,
+        Some(higher::VecArgs::Vec(_)) => return None,
+        None if is_vec_with_capacity(cx, init) => 0,
+        None => return None,
+    };
+
+    let block = match hir.find(hir.get_parent_node(hir.get_parent_node(local.hir_id))) {
+        Some(Node::Block(block)) => block,
+        _ => return None,
+    };
+    let is_mutated = |e: &'tcx Expr<'tcx>| {
+        mutated_variables(e, cx).map_or(true, |mutated| mutated.contains(&local_id)) && changes_len(cx, e, local_id)
+    };
+    let is_mutated_before = |e: &'tcx Expr<'tcx>| {
+        if e.hir_id == expr.hir_id && matches!(e.kind, ExprKind::MethodCall(..)) {
+            false
+        } else {
+            is_mutated(e)
+        }
+    };
+    let stmts = block
+        .stmts
+        .iter()
+        .skip_while(|stmt| !matches!(stmt.kind, StmtKind::Local(l) if l.hir_id == local.hir_id))
+        .skip(1);
+    for stmt in stmts {
+        let stmt_expr = match stmt.kind {
+            StmtKind::Local(l) => match l.init {
+                Some(init) => init,
+                None => continue,
+            },
+            StmtKind::Expr(e) | StmtKind::Semi(e) => e,
+            StmtKind::Item(_) => continue,
+        };
+        if stmt.span.contains(expr.span) {
+            return if is_mutated_before(stmt_expr) {
+                None
+            } else {
+                Some((len, len_span))
+            };
+        }
+        if_chain! {
+            if let ExprKind::MethodCall(path, _, [receiver, new_len, _], _) = stmt_expr.kind;
+            if path.ident.name == sym!(resize);
+            if path_to_local_id(receiver, local_id);
+            then {
+                len = const_usize(cx, new_len)?;
+                len_span = stmt_expr.span;
+                continue;
+            }
+        }
+        if is_mutated(stmt_expr) {
+            return None;
+        }
+    }
+
+    match block.expr {
+        Some(e) if e.span.contains(expr.span) && !is_mutated_before(e) => Some((len, len_span)),
+        _ => None,
+    }
+}
+
+/// Returns the span of the `Vec::with_capacity(_)` call initializing the local variable `array`, if
+/// the vector is still empty at `expr`, see `vec_len`.
+pub fn empty_with_capacity<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, array: &Expr<'_>) -> Option<Span> {
+    let hir = cx.tcx.hir();
+    let local_id = path_to_local(array)?;
+    let init = match hir.find(hir.get_parent_node(local_id)) {
+        Some(Node::Local(local)) if local.pat.hir_id == local_id => local.init?,
+        _ => return None,
+    };
+    let init_span = init.span.source_callsite();
+    match vec_len(cx, expr, array) {
+        // a `resize` sets the span to its statement
+        Some((0, span)) if span == init_span && is_vec_with_capacity(cx, init) => Some(span),
+        _ => None,
+    }
+}
+
+/// Whether `expr` may change the length of the `Vec` in the local variable `local_id`, i.e. uses it
+/// other than by indexing it or calling a method taking `&self` on it.
+fn changes_len<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, local_id: HirId) -> bool {
+    let mut visitor = LenChangeVisitor {
+        cx,
+        local_id,
+        changes_len: false,
+    };
+    visitor.visit_expr(expr);
+    visitor.changes_len
+}
+
+struct LenChangeVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    local_id: HirId,
+    changes_len: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LenChangeVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if self.changes_len {
+            return;
+        }
+        match expr.kind {
+            ExprKind::Index(base, index) if path_to_local_id(base, self.local_id) => self.visit_expr(index),
+            ExprKind::MethodCall(_, _, [receiver, args @ ..], _)
+                if path_to_local_id(receiver, self.local_id) && takes_shared_self(self.cx, expr) =>
+            {
+                args.iter().for_each(|arg| self.visit_expr(arg));
+            }
+            _ if path_to_local_id(expr, self.local_id) => self.changes_len = true,
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
+
+/// Whether the method called by `expr` takes `&self`.
+fn takes_shared_self(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    cx.typeck_results()
+        .type_dependent_def_id(expr.hir_id)
+        .and_then(|method_id| {
+            let sig = cx.tcx.fn_sig(method_id).skip_binder();
+            sig.inputs()
+                .first()
+                .map(|self_ty| matches!(self_ty.kind(), ty::Ref(_, _, Mutability::Not)))
+        })
+        .unwrap_or(false)
+}
+
+fn is_vec_with_capacity(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if_chain! {
+        if let ExprKind::Call(func, [_]) = expr.kind;
+        if let ExprKind::Path(QPath::TypeRelative(ty, name)) = func.kind;
+        if name.ident.as_str() == "with_capacity";
+        then {
+            is_type_diagnostic_item(cx, cx.typeck_results().node_type(ty.hir_id), sym::vec_type)
+        } else {
+            false
+        }
+    }
+}
+
+/// Returns the value of `expr` if it is an integer constant.
+pub fn const_usize(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<u128> {
+    match constant_with_locals(cx, cx.typeck_results(), expr) {
+        Some((Constant::Int(n), _)) => Some(n),
+        _ => None,
+    }
+}
@@ -0,0 +1,250 @@
+//! Lint level overrides for parts of a crate, configured in the `levels` table of the
+//! configuration file:
+//!
+//! ```toml
+//! [levels]
+//! "src/generated/**" = { indexing_slicing = "allow" }
+//! "parser::tables" = { "clippy::pedantic" = "warn", unreadable_literal = "allow" }
+//! ```
+//!
+//! Keys containing a `/`, `*` or `?` or ending in `.rs` are globs matched against the files of the
+//! modules, relative to the configuration file. Other keys are module paths. Lint names without a
+//! tool prefix are looked up as Clippy lints first. The levels apply as if they were set with an
+//! attribute on the module, below the attributes on the module itself, so `#![allow(..)]` in the
+//! code still takes precedence.
+//!
+//! The levels are added to the `lint_levels` query, which means that they are only respected by
+//! lints running after type checking.
+
+use crate::utils::conf::{self, LintLevel, OrderedTable};
+use crate::utils::reclassify;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def_id::CrateNum;
+use rustc_hir::{HirId, CRATE_HIR_ID};
+use rustc_lint::{Level, LintId, LintStore};
+use rustc_middle::lint::{LevelAndSource, LintLevelMap, LintLevelSource, LintSet};
+use rustc_middle::ty::query::Providers;
+use rustc_middle::ty::TyCtxt;
+use rustc_span::{FileName, Span, Symbol, DUMMY_SP};
+use std::any::Any;
+use std::convert::TryFrom;
+use std::env;
+use std::path::Path;
+
+pub fn provide(providers: &mut Providers) {
+    providers.lint_levels = lint_levels;
+}
+
+/// A key of the `levels` table.
+enum Pattern<'a> {
+    /// A glob matched against the path of a module's file, split into segments.
+    File(Vec<&'a str>),
+    /// The path of a module, the crate root being the empty path.
+    Module(&'a str),
+}
+
+impl<'a> Pattern<'a> {
+    fn new(key: &'a str) -> Self {
+        let is_file = Path::new(key).extension().map_or(false, |ext| ext == "rs");
+        if is_file || key.contains(|c| matches!(c, '/' | '*' | '?')) {
+            Self::File(key.split('/').filter(|s| !s.is_empty() && *s != ".").collect())
+        } else if key == "crate" {
+            Self::Module("")
+        } else {
+            Self::Module(key.strip_prefix("crate::").unwrap_or(key))
+        }
+    }
+}
+
+fn lint_levels(tcx: TyCtxt<'_>, cnum: CrateNum) -> LintLevelMap {
+    let mut providers = Providers::default();
+    rustc_lint::provide(&mut providers);
+    let mut map = (providers.lint_levels)(tcx, cnum);
+    add_reclassified_levels(&mut map);
+
+    // errors finding or reading the configuration file are reported by `read_conf`
+    let conf_file = match crate::conf_file_path(tcx.sess) {
+        Ok(Some(conf_file)) => conf_file,
+        _ => return map,
+    };
+    let levels = conf::read(&conf_file).conf.levels;
+    if levels.0.is_empty() {
+        return map;
+    }
+
+    let store: &dyn Any = &*tcx.lint_store;
+    let store: &LintStore = store.downcast_ref().unwrap();
+    let overrides: Vec<_> = levels
+        .0
+        .iter()
+        .map(|(key, lints)| (Pattern::new(key), resolve_lints(tcx, store, &conf_file, key, lints)))
+        .collect();
+    let conf_dir = absolute(conf_file.parent().unwrap_or_else(|| Path::new("")));
+
+    let hir = tcx.hir();
+    for &module in hir.krate().modules.keys() {
+        let (module_def, span, id) = hir.get_module(module);
+        let module_path = if id == CRATE_HIR_ID {
+            String::new()
+        } else {
+            tcx.def_path_str(module.to_def_id())
+        };
+        // only the crate root and modules loaded from their own file are matched against the globs
+        let file = if id == CRATE_HIR_ID || !span.contains(module_def.inner) {
+            module_file(tcx, module_def.inner, &conf_dir)
+        } else {
+            None
+        };
+
+        let mut specs = FxHashMap::default();
+        for (pattern, lints) in &overrides {
+            let matches = match pattern {
+                Pattern::File(glob) => file.as_ref().map_or(false, |file| matches_glob(glob, file)),
+                Pattern::Module(path) => *path == module_path,
+            };
+            if matches {
+                specs.extend(lints.iter().copied());
+            }
+        }
+        if !specs.is_empty() {
+            insert_set(tcx, &mut map, id, specs);
+        }
+    }
+
+    map
+}
+
+/// Sets the default levels of the lints moved to other groups by the `reclassify` table, unless
+/// their levels are set on the command line, see the `reclassify` module.
+fn add_reclassified_levels(map: &mut LintLevelMap) {
+    // the first set holds the levels of the command line
+    if let Some(LintSet::CommandLine { specs }) = map.sets.list.first_mut() {
+        for (lint, level) in reclassify::default_levels() {
+            specs.entry(lint).or_insert((level, LintLevelSource::Default));
+        }
+    }
+}
+
+/// Resolves the lint names of an entry of the `levels` table.
+fn resolve_lints(
+    tcx: TyCtxt<'_>,
+    store: &LintStore,
+    conf_file: &Path,
+    key: &str,
+    lints: &OrderedTable<LintLevel>,
+) -> Vec<(LintId, LevelAndSource)> {
+    let reason = Symbol::intern(&format!("lint level set for `{}` in `{}`", key, conf_file.display()));
+    let mut specs = Vec::new();
+    for (name, level) in &lints.0 {
+        let clippy_name = format!("clippy::{}", name);
+        let resolved = match store.find_lints(&clippy_name) {
+            Ok(ids) if !name.contains("::") => Ok((ids, clippy_name.as_str())),
+            _ => store.find_lints(name).map(|ids| (ids, name.as_str())),
+        };
+        match resolved {
+            Ok((ids, name)) => {
+                let level = level.level();
+                let src = LintLevelSource::Node(Symbol::intern(name), DUMMY_SP, Some(reason));
+                specs.extend(ids.into_iter().map(|id| (id, (level, src))));
+            },
+            Err(_) => tcx
+                .sess
+                .struct_err(&format!(
+                    "error reading Clippy's configuration file `{}`: unknown lint `{}` in `levels`",
+                    conf_file.display(),
+                    name
+                ))
+                .emit(),
+        }
+    }
+    specs
+}
+
+/// Inserts a lint set with `specs` between the lint levels set on the module `id` itself and the
+/// ones of its parents.
+fn insert_set(tcx: TyCtxt<'_>, map: &mut LintLevelMap, id: HirId, mut specs: FxHashMap<LintId, LevelAndSource>) {
+    let hir = tcx.hir();
+    let own_set = map.id_to_set.get(&id).copied();
+    let parent = match own_set {
+        Some(idx) => match map.sets.list.get(idx as usize) {
+            Some(&LintSet::Node { parent, .. }) => parent,
+            _ => return,
+        },
+        None => match hir
+            .parent_iter(id)
+            .find_map(|(parent, _)| map.id_to_set.get(&parent).copied())
+        {
+            Some(parent) => parent,
+            None => return,
+        },
+    };
+    specs.retain(|lint_id, _| map.sets.get_lint_level(lint_id.lint, parent, None, tcx.sess).0 != Level::Forbid);
+
+    let new = u32::try_from(map.sets.list.len()).unwrap();
+    map.sets.list.push(LintSet::Node { specs, parent });
+    let children: Vec<u32> = match own_set {
+        Some(idx) => vec![idx],
+        // the items in the module with lint attributes have their lint sets attached to `parent`
+        None => map
+            .id_to_set
+            .iter()
+            .filter(|&(&child, &idx)| {
+                matches!(map.sets.list[idx as usize], LintSet::Node { parent: p, .. } if p == parent)
+                    && hir.parent_iter(child).any(|(p, _)| p == id)
+            })
+            .map(|(_, &idx)| idx)
+            .collect(),
+    };
+    for idx in children {
+        if let LintSet::Node { parent, .. } = &mut map.sets.list[idx as usize] {
+            *parent = new;
+        }
+    }
+    if own_set.is_none() {
+        map.id_to_set.insert(id, new);
+    }
+}
+
+/// Returns the segments of the path of the file containing `inner`, relative to `conf_dir`.
+fn module_file(tcx: TyCtxt<'_>, inner: Span, conf_dir: &Path) -> Option<Vec<String>> {
+    let file = match tcx.sess.source_map().span_to_filename(inner) {
+        FileName::Real(name) => name.into_local_path()?,
+        _ => return None,
+    };
+    let file = absolute(&file);
+    let relative = file.strip_prefix(conf_dir).ok()?;
+    Some(
+        relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect(),
+    )
+}
+
+fn absolute(path: &Path) -> std::path::PathBuf {
+    env::current_dir().map_or_else(|_| path.to_path_buf(), |dir| dir.join(path))
+}
+
+/// Whether the path `segments` match the glob `pattern`, where `**` matches any number of
+/// segments, `*` any sequence of characters within a segment and `?` a single character. A glob
+/// matching a directory matches all the files in it.
+fn matches_glob(pattern: &[&str], segments: &[String]) -> bool {
+    match pattern.split_first() {
+        None => true,
+        Some((&"**", rest)) => (0..=segments.len()).any(|i| matches_glob(rest, &segments[i..])),
+        Some((first, rest)) => segments.split_first().map_or(false, |(segment, segments)| {
+            let first: Vec<char> = first.chars().collect();
+            let segment: Vec<char> = segment.chars().collect();
+            matches_segment(&first, &segment) && matches_glob(rest, segments)
+        }),
+    }
+}
+
+fn matches_segment(pattern: &[char], segment: &[char]) -> bool {
+    match pattern.split_first() {
+        None => segment.is_empty(),
+        Some(('*', rest)) => (0..=segment.len()).any(|i| matches_segment(rest, &segment[i..])),
+        Some(('?', rest)) => !segment.is_empty() && matches_segment(rest, &segment[1..]),
+        Some((c, rest)) => segment.first() == Some(c) && matches_segment(rest, &segment[1..]),
+    }
+}
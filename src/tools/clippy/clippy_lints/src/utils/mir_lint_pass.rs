@@ -0,0 +1,338 @@
+//! Lint passes running on the MIR of function bodies instead of their HIR.
+//!
+//! MIR makes the control flow explicit: every bounds check is an `Assert` terminator, every
+//! overloaded index a call, and temporaries are assigned once. A `MirLintPass` can use this to
+//! follow values through the locals they are stored in, with the dominator tree of the body telling
+//! whether an assignment is always executed before a use. Passes are registered in `lib.rs` as part
+//! of `MirLints`, which builds the MIR of each function once and hands it to all of them.
+
+use clippy_utils::diagnostics::span_lint_hir;
+use clippy_utils::{clip, fn_has_unsatisfiable_preds};
+use rustc_data_structures::graph::dominators::Dominators;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, FnDecl, HirId};
+use rustc_index::vec::IndexVec;
+use rustc_lint::{LateContext, LateLintPass, Lint, LintPass};
+use rustc_middle::mir::visit::{PlaceContext, Visitor};
+use rustc_middle::mir::{
+    self, BasicBlock, BinOp, CastKind, Local, Location, Operand, Place, ProjectionElem, Rvalue, SourceInfo,
+    StatementKind, TerminatorKind,
+};
+use rustc_middle::ty::adjustment::PointerCast;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::Span;
+
+/// A lint pass over the MIR of a function body. The methods are called for every body by
+/// `MirLints`, with a `MirContext` that evaluates the values of locals.
+pub trait MirLintPass: LintPass {
+    /// Called once for every body, before its statements and terminators.
+    fn check_body<'tcx>(&mut self, _: &MirContext<'_, 'tcx>) {}
+
+    fn check_statement<'tcx>(&mut self, _: &MirContext<'_, 'tcx>, _: &mir::Statement<'tcx>, _: Location) {}
+
+    fn check_terminator<'tcx>(&mut self, _: &MirContext<'_, 'tcx>, _: &mir::Terminator<'tcx>, _: Location) {}
+}
+
+/// The late lint pass running the registered `MirLintPass`es on the optimized MIR of every function
+/// and closure.
+pub struct MirLints {
+    passes: Vec<Box<dyn MirLintPass>>,
+}
+
+impl MirLints {
+    pub fn new(passes: Vec<Box<dyn MirLintPass>>) -> Self {
+        Self { passes }
+    }
+}
+
+impl LintPass for MirLints {
+    fn name(&self) -> &'static str {
+        "MirLints"
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for MirLints {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        _: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        _: Span,
+        _: HirId,
+    ) {
+        let def_id = cx.tcx.hir().body_owner_def_id(body.id());
+
+        // Building MIR for `fn`s with unsatisfiable preds results in ICE.
+        if fn_has_unsatisfiable_preds(cx, def_id.to_def_id()) {
+            return;
+        }
+
+        let mir = cx.tcx.optimized_mir(def_id.to_def_id());
+        let mcx = MirContext::new(cx, mir);
+        for pass in &mut self.passes {
+            pass.check_body(&mcx);
+        }
+        for (block, data) in mir.basic_blocks().iter_enumerated() {
+            for (statement_index, statement) in data.statements.iter().enumerate() {
+                let location = Location { block, statement_index };
+                for pass in &mut self.passes {
+                    pass.check_statement(&mcx, statement, location);
+                }
+            }
+            let location = mir.terminator_loc(block);
+            for pass in &mut self.passes {
+                pass.check_terminator(&mcx, data.terminator(), location);
+            }
+        }
+    }
+}
+
+/// How a place is given its value.
+#[derive(Clone, Copy)]
+pub enum Def<'a, 'tcx> {
+    /// `place = rvalue`
+    Assign(&'a Rvalue<'tcx>),
+    /// `place = func(args)`, the value is only available in the return block of the call.
+    Call(&'a Operand<'tcx>, &'a [Operand<'tcx>]),
+}
+
+/// The MIR of a body together with the information needed to follow values through it.
+pub struct MirContext<'a, 'tcx> {
+    pub cx: &'a LateContext<'tcx>,
+    pub mir: &'tcx mir::Body<'tcx>,
+    dominators: Dominators<BasicBlock>,
+    /// The locations at which each local or a field of it is assigned, borrowed mutably or
+    /// otherwise changed.
+    mutations: IndexVec<Local, Vec<Location>>,
+}
+
+impl<'a, 'tcx> MirContext<'a, 'tcx> {
+    fn new(cx: &'a LateContext<'tcx>, mir: &'tcx mir::Body<'tcx>) -> Self {
+        let mut collector = MutationCollector {
+            mutations: IndexVec::from_elem_n(Vec::new(), mir.local_decls.len()),
+        };
+        collector.visit_body(mir);
+        Self {
+            cx,
+            mir,
+            dominators: mir.dominators(),
+            mutations: collector.mutations,
+        }
+    }
+
+    /// Emits `lint` at the span of `source_info`, with the lint level of its scope.
+    pub fn span_lint(&self, lint: &'static Lint, source_info: SourceInfo, msg: &str) {
+        if let Some(hir_id) = source_info.scope.lint_root(&self.mir.source_scopes) {
+            span_lint_hir(self.cx, lint, hir_id, source_info.span, msg);
+        }
+    }
+
+    /// Returns how `place`, which is a local or a field of one, is given the value it has at
+    /// `location`. This requires a single definition that is executed before every path reaching
+    /// `location`, and no other changes to `place`.
+    pub fn def(&self, place: Place<'tcx>, location: Location) -> Option<(Def<'tcx, 'tcx>, Location)> {
+        if !is_local_or_field(place) {
+            return None;
+        }
+
+        let mut def = None;
+        for &def_location in &self.mutations[place.local] {
+            let (dest, kind) = self.def_at(def_location)?;
+            if dest == place {
+                if def.is_some() {
+                    return None;
+                }
+                def = Some((kind, def_location));
+            } else if !is_sibling_field(dest, place) {
+                return None;
+            }
+        }
+
+        let (kind, def_location) = def?;
+        let available_from = match (kind, &self.mir[def_location.block].terminator().kind) {
+            (
+                Def::Call(..),
+                TerminatorKind::Call {
+                    destination: Some((_, target)),
+                    ..
+                },
+            ) => target.start_location(),
+            _ => def_location.successor_within_block(),
+        };
+        available_from
+            .dominates(location, &self.dominators)
+            .then(|| (kind, def_location))
+    }
+
+    fn def_at(&self, location: Location) -> Option<(Place<'tcx>, Def<'tcx, 'tcx>)> {
+        let data = &self.mir[location.block];
+        match data.statements.get(location.statement_index) {
+            Some(statement) => match &statement.kind {
+                StatementKind::Assign(box (dest, rvalue)) => Some((*dest, Def::Assign(rvalue))),
+                _ => None,
+            },
+            None => match &data.terminator().kind {
+                TerminatorKind::Call {
+                    func,
+                    args,
+                    destination: Some((dest, _)),
+                    ..
+                } => Some((*dest, Def::Call(func, args))),
+                _ => None,
+            },
+        }
+    }
+
+    /// Evaluates the unsigned integer `operand` at `location`, as far as the values it is computed
+    /// from are known.
+    pub fn eval_uint(&self, operand: &Operand<'tcx>, location: Location) -> Option<u128> {
+        match operand {
+            Operand::Constant(constant) => {
+                let ty = constant.ty();
+                if let ty::Uint(_) = ty.kind() {
+                    constant.literal.try_eval_bits(self.cx.tcx, self.cx.param_env, ty)
+                } else {
+                    None
+                }
+            },
+            Operand::Copy(place) | Operand::Move(place) => self.eval_uint_place(*place, location),
+        }
+    }
+
+    fn eval_uint_place(&self, place: Place<'tcx>, location: Location) -> Option<u128> {
+        let ty = self.place_ty(place);
+        if !matches!(ty.kind(), ty::Uint(_)) {
+            return None;
+        }
+
+        if let Some((def, def_location)) = self.def(place, location) {
+            return match def {
+                Def::Assign(Rvalue::Use(operand)) => self.eval_uint(operand, def_location),
+                Def::Assign(Rvalue::BinaryOp(op, box (lhs, rhs))) => self.eval_binop(*op, lhs, rhs, ty, def_location),
+                Def::Assign(Rvalue::Len(place)) => self.len(*place, def_location),
+                _ => None,
+            };
+        }
+
+        // A field of a local that is assigned as a whole, like the result of a checked addition
+        if let [ProjectionElem::Field(field, _)] = **place.projection {
+            let base = Place::from(place.local);
+            match self.def(base, location)? {
+                (Def::Assign(Rvalue::Use(Operand::Copy(src) | Operand::Move(src))), def_location) => {
+                    let src = self.cx.tcx.mk_place_field(*src, field, ty);
+                    self.eval_uint_place(src, def_location)
+                },
+                (Def::Assign(Rvalue::Aggregate(_, operands)), def_location) => {
+                    self.eval_uint(operands.get(field.index())?, def_location)
+                },
+                (Def::Assign(Rvalue::CheckedBinaryOp(op, box (lhs, rhs))), def_location) if field.index() == 0 => {
+                    self.eval_binop(*op, lhs, rhs, ty, def_location)
+                },
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Evaluates `lhs op rhs`, giving up if it overflows `ty`.
+    fn eval_binop(
+        &self,
+        op: BinOp,
+        lhs: &Operand<'tcx>,
+        rhs: &Operand<'tcx>,
+        ty: Ty<'tcx>,
+        location: Location,
+    ) -> Option<u128> {
+        let lhs = self.eval_uint(lhs, location)?;
+        let rhs = self.eval_uint(rhs, location)?;
+        let value = match op {
+            BinOp::Add => lhs.checked_add(rhs),
+            BinOp::Sub => lhs.checked_sub(rhs),
+            BinOp::Mul => lhs.checked_mul(rhs),
+            BinOp::Div => lhs.checked_div(rhs),
+            BinOp::Rem => lhs.checked_rem(rhs),
+            _ => None,
+        }?;
+        match ty.kind() {
+            ty::Uint(uint_ty) if clip(self.cx.tcx, value, *uint_ty) == value => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the length of the array or slice `place` at `location`, if it is known. The length
+    /// of a slice is known if it is created from a reference to an array.
+    pub fn len(&self, place: Place<'tcx>, location: Location) -> Option<u128> {
+        match self.place_ty(place).kind() {
+            ty::Array(_, len) => len.try_eval_usize(self.cx.tcx, self.cx.param_env).map(u128::from),
+            ty::Slice(_) => match **place.projection {
+                [ProjectionElem::Deref] => self.pointee_len(Place::from(place.local), location),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the length of the array or slice the reference `operand` points to at `location`.
+    pub fn ref_len(&self, operand: &Operand<'tcx>, location: Location) -> Option<u128> {
+        match operand {
+            Operand::Constant(constant) => array_len_of_pointee(self.cx, constant.ty()),
+            Operand::Copy(place) | Operand::Move(place) => self.pointee_len(*place, location),
+        }
+    }
+
+    fn pointee_len(&self, place: Place<'tcx>, location: Location) -> Option<u128> {
+        if let Some(len) = array_len_of_pointee(self.cx, self.place_ty(place)) {
+            return Some(len);
+        }
+        match self.def(place, location)? {
+            (
+                Def::Assign(Rvalue::Use(operand) | Rvalue::Cast(CastKind::Pointer(PointerCast::Unsize), operand, _)),
+                def_location,
+            ) => self.ref_len(operand, def_location),
+            // a reborrow
+            (Def::Assign(Rvalue::Ref(_, _, borrowed)), def_location) => self.len(*borrowed, def_location),
+            _ => None,
+        }
+    }
+
+    fn place_ty(&self, place: Place<'tcx>) -> Ty<'tcx> {
+        place.ty(self.mir, self.cx.tcx).ty
+    }
+}
+
+/// Returns the length of the array `ty` points to, if it is a reference to an array.
+fn array_len_of_pointee<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<u128> {
+    match ty.builtin_deref(true)?.ty.kind() {
+        ty::Array(_, len) => len.try_eval_usize(cx.tcx, cx.param_env).map(u128::from),
+        _ => None,
+    }
+}
+
+fn is_local_or_field(place: Place<'_>) -> bool {
+    matches!(**place.projection, [] | [ProjectionElem::Field(..)])
+}
+
+/// Whether `a` and `b` are different fields of the same local.
+fn is_sibling_field(a: Place<'_>, b: Place<'_>) -> bool {
+    match (&**a.projection, &**b.projection) {
+        ([ProjectionElem::Field(a_field, _)], [ProjectionElem::Field(b_field, _)]) => {
+            a.local == b.local && a_field != b_field
+        },
+        _ => false,
+    }
+}
+
+struct MutationCollector {
+    mutations: IndexVec<Local, Vec<Location>>,
+}
+
+impl<'tcx> Visitor<'tcx> for MutationCollector {
+    fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, location: Location) {
+        // writing through a reference doesn't change the reference
+        if context.is_mutating_use() && !place.is_indirect() {
+            self.mutations[place.local].push(location);
+        }
+    }
+}
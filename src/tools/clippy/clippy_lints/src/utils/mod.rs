@@ -1,5 +1,12 @@
 pub mod author;
 pub mod conf;
+pub mod ffi;
+pub mod guards;
 pub mod inspector;
 #[cfg(any(feature = "internal-lints", feature = "metadata-collector-lint"))]
 pub mod internal_lints;
+pub mod known_len;
+pub mod levels;
+pub mod mir_lint_pass;
+pub mod reclassify;
+pub mod repeated_in_macros;
@@ -0,0 +1,123 @@
+//! Lints moved to other lint groups by the `reclassify` table of the configuration file:
+//!
+//! ```toml
+//! [reclassify]
+//! indexing_slicing = "correctness"
+//! ```
+//!
+//! A reclassified lint is registered in its new group instead of its category group, and in
+//! `clippy::all` if the new group is part of it, so `-D clippy::correctness` denies
+//! `indexing_slicing` in this example. The lint also gets the default level of its new group,
+//! unless its level is set on the command line. Like the levels of the `levels` table, the default
+//! level is added to the `lint_levels` query, which means that it is only respected by lints
+//! running after type checking.
+
+use crate::utils::conf::OrderedTable;
+use rustc_lint::{Level, LintId, LintStore};
+use rustc_session::Session;
+use std::cell::RefCell;
+
+/// The groups lints can be moved to, with their default levels.
+const GROUPS: [(&str, Level); 8] = [
+    ("clippy::correctness", Level::Deny),
+    ("clippy::style", Level::Warn),
+    ("clippy::complexity", Level::Warn),
+    ("clippy::perf", Level::Warn),
+    ("clippy::pedantic", Level::Allow),
+    ("clippy::restriction", Level::Allow),
+    ("clippy::nursery", Level::Allow),
+    ("clippy::cargo", Level::Allow),
+];
+
+/// The groups that are part of `clippy::all`.
+const ALL_GROUPS: [&str; 4] = [
+    "clippy::correctness",
+    "clippy::style",
+    "clippy::complexity",
+    "clippy::perf",
+];
+
+thread_local! {
+    /// The reclassified lints whose default level differs from the one of their new group, with
+    /// the level of the group, see `default_levels`.
+    static DEFAULT_LEVELS: RefCell<Vec<(LintId, Level)>> = RefCell::new(Vec::new());
+}
+
+/// Registers the lint groups of Clippy, with the lints of the `reclassify` table moved to their
+/// new groups.
+pub struct Groups<'a> {
+    store: &'a mut LintStore,
+    /// The reclassified lints with the names of their new groups.
+    moved: Vec<(LintId, &'static str)>,
+}
+
+impl<'a> Groups<'a> {
+    /// Resolves the entries of the `reclassify` table, reporting the unknown lints and groups. The
+    /// lints have to be registered already.
+    pub fn new(store: &'a mut LintStore, sess: &Session, reclassify: &OrderedTable<String>) -> Self {
+        let mut moved = Vec::new();
+        let mut default_levels = Vec::new();
+        for (lint, group) in &reclassify.0 {
+            let lint_name = format!("clippy::{}", lint.strip_prefix("clippy::").unwrap_or(lint));
+            let group_name = format!("clippy::{}", group.strip_prefix("clippy::").unwrap_or(group));
+            let target = GROUPS.iter().find(|(name, _)| *name == group_name);
+            match (store.find_lints(&lint_name), target) {
+                (Err(_), _) => report(sess, &format!("unknown lint `{}` in `reclassify`", lint)),
+                (_, None) => report(
+                    sess,
+                    &format!(
+                        "unknown lint group `{}` in `reclassify`, expected one of `correctness`, `style`, \
+                         `complexity`, `perf`, `pedantic`, `restriction`, `nursery` or `cargo`",
+                        group
+                    ),
+                ),
+                (Ok(ids), Some(&(group, level))) => {
+                    for id in ids {
+                        if id.lint.default_level != level {
+                            default_levels.push((id, level));
+                        }
+                        moved.push((id, group));
+                    }
+                },
+            }
+        }
+        DEFAULT_LEVELS.with(|levels| *levels.borrow_mut() = default_levels);
+        Self { store, moved }
+    }
+
+    /// Like `LintStore::register_group`, but with the reclassified lints removed from the lints
+    /// of the group, and added to it if it is their new group or a group containing it.
+    pub fn register_group(
+        &mut self,
+        from_plugin: bool,
+        name: &'static str,
+        deprecated_name: Option<&'static str>,
+        mut lints: Vec<LintId>,
+    ) {
+        if name == "clippy::all" || GROUPS.iter().any(|(group, _)| *group == name) {
+            lints.retain(|lint| !self.moved.iter().any(|(moved, _)| moved == lint));
+            lints.extend(
+                self.moved
+                    .iter()
+                    .filter(|(_, group)| *group == name || name == "clippy::all" && ALL_GROUPS.contains(group))
+                    .map(|&(lint, _)| lint),
+            );
+        }
+        self.store.register_group(from_plugin, name, deprecated_name, lints);
+    }
+}
+
+/// Returns the reclassified lints whose default level differs from the one of their new group,
+/// with the level of the group.
+pub fn default_levels() -> Vec<(LintId, Level)> {
+    DEFAULT_LEVELS.with(|levels| levels.borrow().clone())
+}
+
+fn report(sess: &Session, message: &str) {
+    let source = match crate::conf_file_path(sess) {
+        Ok(Some(file_name)) => format!("Clippy's configuration file `{}`", file_name.display()),
+        _ => "Clippy's configuration".to_string(),
+    };
+    sess.struct_err(&format!("error reading {}: {}", source, message))
+        .emit();
+}
@@ -0,0 +1,15 @@
+//! Reports the lints that weren't emitted again for every expansion of a macro, see
+//! `clippy_utils::diagnostics::report_repeated_in_macros`.
+
+use clippy_utils::diagnostics::report_repeated_in_macros;
+use rustc_hir::Crate;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_session::declare_lint_pass;
+
+declare_lint_pass!(RepeatedInMacros => []);
+
+impl<'tcx> LateLintPass<'tcx> for RepeatedInMacros {
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>, _: &'tcx Crate<'_>) {
+        report_repeated_in_macros(cx.sess());
+    }
+}
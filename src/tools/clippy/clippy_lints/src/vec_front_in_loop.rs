@@ -0,0 +1,306 @@
+//! lint on inserting at and removing from the front of a `Vec` in a loop
+
+use crate::utils::known_len::const_usize;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{get_parent_expr, match_def_path, path_to_local, path_to_local_id, paths};
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{BindingAnnotation, Body, Expr, ExprKind, HirId, Local, MatchSource, Node, PatKind, QPath, TyKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty::{self, UintTy};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `insert(0, _)` and `remove(0)` on a `Vec` in a loop.
+    ///
+    /// **Why is this bad?** Both move all the elements of the vector, so the loop takes quadratic
+    /// time. A `VecDeque` adds and removes elements at both ends in constant time with
+    /// `push_front` and `pop_front`.
+    ///
+    /// The suggestion changes the type of the local vector to a `VecDeque` if all the other uses of
+    /// the local are supported by `VecDeque` as well: calls of methods it has, like `len()` or
+    /// `push` (which becomes `push_back`), indexing with a `usize` and iterating in a `for` loop.
+    ///
+    /// **Known problems:** The elements of a `VecDeque` aren't contiguous, so it can't be used as
+    /// a slice without `make_contiguous`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut queue = Vec::new();
+    /// queue.push(1);
+    /// while let Some(n) = queue.pop() {
+    ///     if n < 10 {
+    ///         queue.insert(0, n + 1);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut queue = std::collections::VecDeque::new();
+    /// queue.push_back(1);
+    /// while let Some(n) = queue.pop_back() {
+    ///     if n < 10 {
+    ///         queue.push_front(n + 1);
+    ///     }
+    /// }
+    /// ```
+    pub VEC_FRONT_IN_LOOP,
+    perf,
+    "inserting at or removing from the front of a `Vec` in a loop, instead of using a `VecDeque`"
+}
+
+declare_lint_pass!(VecFrontInLoop => [VEC_FRONT_IN_LOOP]);
+
+impl<'tcx> LateLintPass<'tcx> for VecFrontInLoop {
+    fn check_body(&mut self, cx: &LateContext<'tcx>, body: &'tcx Body<'tcx>) {
+        let mut visitor = FrontCallVisitor {
+            cx,
+            loop_depth: 0,
+            calls: Vec::new(),
+        };
+        visitor.visit_expr(&body.value);
+
+        // the suggestion for a local is attached to its first call
+        let mut suggested: Vec<HirId> = Vec::new();
+        for call in visitor.calls {
+            let local = path_to_local(call.receiver).filter(|local| !suggested.contains(local));
+            let sugg = local.and_then(|local| {
+                suggested.push(local);
+                deque_sugg(cx, body, local)
+            });
+            lint(cx, &call, sugg);
+        }
+    }
+}
+
+/// A call of `insert(0, _)` or `remove(0)` on a `Vec`.
+struct FrontCall<'tcx> {
+    expr: &'tcx Expr<'tcx>,
+    receiver: &'tcx Expr<'tcx>,
+    insert: bool,
+}
+
+/// Collects the `FrontCall`s in loops. Closures are checked with their own body.
+struct FrontCallVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    loop_depth: u32,
+    calls: Vec<FrontCall<'tcx>>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for FrontCallVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            ExprKind::Loop(..) => {
+                self.loop_depth += 1;
+                walk_expr(self, expr);
+                self.loop_depth -= 1;
+                return;
+            },
+            ExprKind::MethodCall(path, _, [receiver, index, rest @ ..], _)
+                if self.loop_depth > 0
+                    && !expr.span.from_expansion()
+                    && is_type_diagnostic_item(
+                        self.cx,
+                        self.cx.typeck_results().expr_ty(receiver).peel_refs(),
+                        sym::vec_type,
+                    )
+                    && const_usize(self.cx, index) == Some(0) =>
+            {
+                let insert = match (&*path.ident.as_str(), rest) {
+                    ("insert", [_]) => Some(true),
+                    ("remove", []) => Some(false),
+                    _ => None,
+                };
+                if let Some(insert) = insert {
+                    self.calls.push(FrontCall { expr, receiver, insert });
+                }
+            }
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+fn lint(cx: &LateContext<'_>, call: &FrontCall<'_>, sugg: Option<Vec<(Span, String)>>) {
+    let msg = if call.insert {
+        "inserting at the front of a `Vec` in a loop"
+    } else {
+        "removing from the front of a `Vec` in a loop"
+    };
+    span_lint_and_then(cx, VEC_FRONT_IN_LOOP, call.expr.span, msg, |diag| {
+        diag.note("this moves all the elements of the vector on each iteration");
+        match sugg {
+            Some(sugg) => {
+                diag.multipart_suggestion(
+                    "consider using a `VecDeque`, which adds and removes elements at the front in constant time",
+                    sugg,
+                    Applicability::MaybeIncorrect,
+                );
+            },
+            None => {
+                diag.help(
+                    "consider using a `VecDeque`, which adds and removes elements at the front in constant time with \
+                     `push_front` and `pop_front`",
+                );
+            },
+        }
+    });
+}
+
+/// Returns the changes turning the local vector `local` into a `VecDeque`, if it is declared with
+/// `let mut` in `body`, created empty, and all its uses are supported by `VecDeque`, see
+/// `use_sugg`.
+fn deque_sugg<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Body<'tcx>, local: HirId) -> Option<Vec<(Span, String)>> {
+    let hir = cx.tcx.hir();
+    let decl = match hir.find(hir.get_parent_node(local)) {
+        Some(Node::Local(decl)) => decl,
+        _ => return None,
+    };
+    if !matches!(decl.pat.kind, PatKind::Binding(BindingAnnotation::Mutable, id, _, None) if id == local)
+        || hir.enclosing_body_owner(local) != hir.body_owner(body.id())
+    {
+        return None;
+    }
+
+    let mut sugg = decl_sugg(cx, decl)?;
+    let mut visitor = UseVisitor {
+        cx,
+        local,
+        sugg: Some(Vec::new()),
+    };
+    visitor.visit_expr(&body.value);
+    sugg.extend(visitor.sugg?);
+    Some(sugg)
+}
+
+/// Returns the changes of the declaration `decl` of the local vector: its type, if it is given,
+/// and its initializer, which has to be `Vec::new()`, `vec![]` or `Vec::with_capacity(n)`.
+fn decl_sugg(cx: &LateContext<'_>, decl: &Local<'_>) -> Option<Vec<(Span, String)>> {
+    let mut sugg = Vec::new();
+    if let Some(ty) = decl.ty {
+        let elem = match ty.kind {
+            TyKind::Path(QPath::Resolved(None, path)) => match path.segments.last()?.args {
+                Some(args) => match args.args {
+                    [elem] => snippet(cx, elem.span(), "_"),
+                    _ => return None,
+                },
+                None => "_".into(),
+            },
+            _ => return None,
+        };
+        sugg.push((ty.span, format!("std::collections::VecDeque<{}>", elem)));
+    }
+    let init = decl.init?;
+    let callee = match init.kind {
+        ExprKind::Call(callee, _) => callee,
+        _ => return None,
+    };
+    let def_id = match callee.kind {
+        ExprKind::Path(ref qpath) => cx.qpath_res(qpath, callee.hir_id).opt_def_id()?,
+        _ => return None,
+    };
+    if match_def_path(cx, def_id, &paths::VEC_NEW) {
+        // `vec![]` expands to `Vec::new()`
+        sugg.push((init.span.source_callsite(), "std::collections::VecDeque::new()".into()));
+    } else if match_def_path(cx, def_id, &paths::VEC_WITH_CAPACITY) && !init.span.from_expansion() {
+        sugg.push((callee.span, "std::collections::VecDeque::with_capacity".into()));
+    } else {
+        return None;
+    }
+    Some(sugg)
+}
+
+/// Collects the changes of the uses of the local vector `local`, see `use_sugg`. `sugg` becomes
+/// `None` at the first use `VecDeque` doesn't support.
+struct UseVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    local: HirId,
+    sugg: Option<Vec<(Span, String)>>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UseVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if path_to_local_id(expr, self.local) {
+            let sugg = use_sugg(self.cx, expr);
+            self.sugg = self.sugg.take().and_then(|mut all| {
+                all.extend(sugg?);
+                Some(all)
+            });
+        }
+        if self.sugg.is_some() {
+            walk_expr(self, expr);
+        }
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
+
+/// Returns the changes of the use `local` of the local vector if `VecDeque` supports it: a call of
+/// a method `VecDeque` has, the methods at the ends of the vector being renamed (`push` becomes
+/// `push_back`, `insert(0, x)` becomes `push_front(x)`, ..), indexing with a `usize`, or iterating
+/// over it (or a reference to it) in a `for` loop.
+fn use_sugg(cx: &LateContext<'_>, local: &Expr<'_>) -> Option<Vec<(Span, String)>> {
+    let parent = get_parent_expr(cx, local)?;
+    match parent.kind {
+        ExprKind::MethodCall(path, _, [receiver, args @ ..], _) if receiver.hir_id == local.hir_id => {
+            let name = path.ident.as_str();
+            let method_span = parent.span.with_lo(path.ident.span.lo());
+            let is_front = args.first().map_or(false, |index| const_usize(cx, index) == Some(0));
+            let renamed = match (&*name, args) {
+                ("insert", [_, value]) if is_front => {
+                    let value = snippet(cx, value.span, "..");
+                    return Some(vec![(method_span, format!("push_front({})", value))]);
+                },
+                ("remove", [_]) if is_front => return Some(vec![(method_span, "pop_front().unwrap()".into())]),
+                // `VecDeque::remove` returns an `Option`
+                ("remove", [_]) => return Some(vec![(parent.span.shrink_to_hi(), ".unwrap()".into())]),
+                ("push", [_]) => "push_back",
+                ("pop", []) => "pop_back",
+                ("first", []) => "front",
+                ("first_mut", []) => "front_mut",
+                ("last", []) => "back",
+                ("last_mut", []) => "back_mut",
+                ("get" | "get_mut", [index]) if !is_usize(cx, index) => return None,
+                (
+                    "capacity" | "clear" | "contains" | "drain" | "extend" | "get" | "get_mut" | "insert" | "is_empty"
+                    | "iter" | "iter_mut" | "len" | "reserve" | "retain" | "swap" | "truncate",
+                    _,
+                ) => return Some(Vec::new()),
+                _ => return None,
+            };
+            Some(vec![(path.ident.span, renamed.into())])
+        },
+        ExprKind::Index(base, index) if base.hir_id == local.hir_id && is_usize(cx, index) => Some(Vec::new()),
+        ExprKind::AddrOf(..) if is_for_loop_arg(cx, parent) => Some(Vec::new()),
+        _ if is_for_loop_arg(cx, local) => Some(Vec::new()),
+        _ => None,
+    }
+}
+
+fn is_usize(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    matches!(cx.typeck_results().expr_ty(expr).kind(), ty::Uint(UintTy::Usize))
+}
+
+/// Whether `expr` is the expression a `for` loop iterates over.
+fn is_for_loop_arg(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    get_parent_expr(cx, expr).map_or(false, |into_iter| {
+        matches!(into_iter.kind, ExprKind::Call(_, [arg]) if arg.hir_id == expr.hir_id)
+            && get_parent_expr(cx, into_iter).map_or(false, |parent| {
+                matches!(parent.kind, ExprKind::Match(_, _, MatchSource::ForLoopDesugar))
+            })
+    })
+}
@@ -15,6 +15,7 @@ pub enum DeprecationStatus {
 }
 
 pub const BUILTIN_ATTRIBUTES: &[(&str, DeprecationStatus)] = &[
+    ("allow_next", DeprecationStatus::None),
     ("author", DeprecationStatus::None),
     ("cognitive_complexity", DeprecationStatus::None),
     (
@@ -1,15 +1,18 @@
 #![allow(clippy::float_cmp)]
 
-use crate::{clip, sext, unsext};
+use crate::{clip, find_binding_init, int_bits, match_def_path, paths, sext, unsext};
 use if_chain::if_chain;
 use rustc_ast::ast::{self, LitFloatType, LitKind};
 use rustc_data_structures::sync::Lrc;
 use rustc_hir::def::{DefKind, Res};
-use rustc_hir::{BinOp, BinOpKind, Block, Expr, ExprKind, HirId, QPath, UnOp};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{
+    BinOp, BinOpKind, BindingAnnotation, Block, Expr, ExprKind, HirId, HirIdMap, PatKind, QPath, StmtKind, UnOp,
+};
 use rustc_lint::LateContext;
 use rustc_middle::mir::interpret::Scalar;
 use rustc_middle::ty::subst::{Subst, SubstsRef};
-use rustc_middle::ty::{self, FloatTy, ScalarInt, Ty, TyCtxt};
+use rustc_middle::ty::{self, FloatTy, ScalarInt, Ty, TyCtxt, TypeFoldable};
 use rustc_middle::{bug, span_bug};
 use rustc_span::symbol::Symbol;
 use std::cmp::Ordering::{self, Equal};
@@ -189,11 +192,29 @@ pub fn constant<'tcx>(
         typeck_results,
         param_env: lcx.param_env,
         needed_resolution: false,
+        resolve_locals: false,
         substs: lcx.tcx.intern_substs(&[]),
+        params: HirIdMap::default(),
+        depth: 0,
+        steps: MAX_CONST_FN_STEPS,
     };
     cx.expr(e).map(|cst| (cst, cx.needed_resolution))
 }
 
+/// Like `constant`, but also looks through immutable local bindings initialized with a constant
+/// expression, e.g. `n` after `let n = 3;`, evaluates `mem::size_of::<T>()` for concrete types,
+/// and the calls of the `const fn`s of the crate with constant arguments, like `magic_index()`.
+/// Resolving a binding or evaluating a call counts as needing resolution.
+pub fn constant_with_locals<'tcx>(
+    lcx: &LateContext<'tcx>,
+    typeck_results: &ty::TypeckResults<'tcx>,
+    e: &Expr<'_>,
+) -> Option<(Constant, bool)> {
+    let mut cx = constant_context(lcx, typeck_results);
+    cx.resolve_locals = true;
+    cx.expr(e).map(|cst| (cst, cx.needed_resolution))
+}
+
 pub fn constant_simple<'tcx>(
     lcx: &LateContext<'tcx>,
     typeck_results: &ty::TypeckResults<'tcx>,
@@ -202,6 +223,15 @@ pub fn constant_simple<'tcx>(
     constant(lcx, typeck_results, e).and_then(|(cst, res)| if res { None } else { Some(cst) })
 }
 
+/// Whether `e` is the integer constant `0`, looking through local bindings and `const fn` calls
+/// like `constant_with_locals`.
+pub fn is_integer_const_zero<'tcx>(lcx: &LateContext<'tcx>, e: &Expr<'_>) -> bool {
+    matches!(
+        constant_with_locals(lcx, lcx.typeck_results(), e),
+        Some((Constant::Int(0), _))
+    )
+}
+
 /// Creates a `ConstEvalLateContext` from the given `LateContext` and `TypeckResults`.
 pub fn constant_context<'a, 'tcx>(
     lcx: &'a LateContext<'tcx>,
@@ -212,21 +242,40 @@ pub fn constant_context<'a, 'tcx>(
         typeck_results,
         param_env: lcx.param_env,
         needed_resolution: false,
+        resolve_locals: false,
         substs: lcx.tcx.intern_substs(&[]),
+        params: HirIdMap::default(),
+        depth: 0,
+        steps: MAX_CONST_FN_STEPS,
     }
 }
 
+/// The number of nested calls of `const fn`s evaluated, see `ConstEvalLateContext::const_fn_call`.
+const MAX_CONST_FN_DEPTH: u32 = 4;
+/// The number of expressions evaluated in the bodies of `const fn`s for a single constant.
+const MAX_CONST_FN_STEPS: u32 = 1000;
+
 pub struct ConstEvalLateContext<'a, 'tcx> {
     lcx: &'a LateContext<'tcx>,
     typeck_results: &'a ty::TypeckResults<'tcx>,
     param_env: ty::ParamEnv<'tcx>,
     needed_resolution: bool,
+    resolve_locals: bool,
     substs: SubstsRef<'tcx>,
+    /// The values of the parameters of the `const fn` whose body is evaluated.
+    params: HirIdMap<Constant>,
+    /// The number of `const fn` calls the evaluated expression is nested in.
+    depth: u32,
+    /// The number of expressions left to evaluate in the bodies of `const fn`s.
+    steps: u32,
 }
 
 impl<'a, 'tcx> ConstEvalLateContext<'a, 'tcx> {
     /// Simple constant folding: Insert an expression, get a constant or none.
     pub fn expr(&mut self, e: &Expr<'_>) -> Option<Constant> {
+        if self.depth > 0 {
+            self.steps = self.steps.checked_sub(1)?;
+        }
         match e.kind {
             ExprKind::Path(ref qpath) => self.fetch_path(qpath, e.hir_id, self.typeck_results.expr_ty(e)),
             ExprKind::Block(ref block, _) => self.block(block),
@@ -247,6 +296,11 @@ pub fn expr(&mut self, e: &Expr<'_>) -> Option<Constant> {
             }),
             ExprKind::If(ref cond, ref then, ref otherwise) => self.ifthenelse(cond, then, *otherwise),
             ExprKind::Binary(op, ref left, ref right) => self.binop(op, left, right),
+            ExprKind::Call(ref callee, args) if self.resolve_locals && self.is_local_const_fn(callee) => {
+                self.const_fn_call(callee, args)
+            },
+            ExprKind::Call(ref callee, args) if !args.is_empty() => self.call(e, callee, args),
+            ExprKind::Call(ref callee, _) if self.resolve_locals && self.is_size_of(callee) => self.size_of(callee),
             ExprKind::Call(ref callee, ref args) => {
                 // We only handle a few const functions for now.
                 if_chain! {
@@ -274,7 +328,23 @@ pub fn expr(&mut self, e: &Expr<'_>) -> Option<Constant> {
                 }
             },
             ExprKind::Index(ref arr, ref index) => self.index(arr, index),
+            ExprKind::MethodCall(path, _, [ref receiver], _) if path.ident.name.as_str() == "len" => {
+                let method_id = self.typeck_results.type_dependent_def_id(e.hir_id)?;
+                self.array_len(method_id, receiver)
+            },
+            ExprKind::MethodCall(path, _, [ref receiver, ref arg], _) => {
+                let method_id = self.typeck_results.type_dependent_def_id(e.hir_id)?;
+                match &*path.ident.name.as_str() {
+                    "min" | "max" if !self.is_ord_method(method_id) => None,
+                    "min" => self.min_max(receiver, arg, Ordering::Less),
+                    "max" => self.min_max(receiver, arg, Ordering::Greater),
+                    "saturating_add" => self.saturating(receiver, arg, method_id, BinOpKind::Add),
+                    "saturating_sub" => self.saturating(receiver, arg, method_id, BinOpKind::Sub),
+                    _ => None,
+                }
+            },
             ExprKind::AddrOf(_, _, ref inner) => self.expr(inner).map(|r| Constant::Ref(Box::new(r))),
+            ExprKind::Field(ref base, _) => self.field(e, base),
             // TODO: add other expressions.
             _ => None,
         }
@@ -327,29 +397,21 @@ fn multi(&mut self, vec: &[Expr<'_>]) -> Option<Vec<Constant>> {
     fn fetch_path(&mut self, qpath: &QPath<'_>, id: HirId, ty: Ty<'tcx>) -> Option<Constant> {
         let res = self.typeck_results.qpath_res(qpath, id);
         match res {
+            Res::Local(hir_id) if self.params.contains_key(&hir_id) => self.params.get(&hir_id).cloned(),
             Res::Def(DefKind::Const | DefKind::AssocConst, def_id) => {
-                let substs = self.typeck_results.node_substs(id);
-                let substs = if self.substs.is_empty() {
-                    substs
-                } else {
-                    substs.subst(self.lcx.tcx, self.substs)
-                };
-
-                let result = self
-                    .lcx
-                    .tcx
-                    .const_eval_resolve(
-                        self.param_env,
-                        ty::Unevaluated {
-                            def: ty::WithOptConstParam::unknown(def_id),
-                            substs,
-                            promoted: None,
-                        },
-                        None,
-                    )
-                    .ok()
-                    .map(|val| rustc_middle::ty::Const::from_value(self.lcx.tcx, val, ty))?;
-                let result = miri_to_const(&result);
+                let result = miri_to_const(self.const_value(def_id, id, ty)?);
+                if result.is_some() {
+                    self.needed_resolution = true;
+                }
+                result
+            },
+            // An immutable binding always holds the value it has been initialized with.
+            Res::Local(hir_id) if self.resolve_locals => {
+                let init = find_binding_init(self.lcx, hir_id)?;
+                if init.hir_id.owner != self.typeck_results.hir_owner {
+                    return None;
+                }
+                let result = self.expr(init);
                 if result.is_some() {
                     self.needed_resolution = true;
                 }
@@ -360,6 +422,69 @@ fn fetch_path(&mut self, qpath: &QPath<'_>, id: HirId, ty: Ty<'tcx>) -> Option<C
         }
     }
 
+    /// Evaluates the constant item `def_id`, referred to by the path `id` of type `ty`.
+    fn const_value(&self, def_id: DefId, id: HirId, ty: Ty<'tcx>) -> Option<&'tcx ty::Const<'tcx>> {
+        let substs = self.typeck_results.node_substs(id);
+        let substs = if self.substs.is_empty() {
+            substs
+        } else {
+            substs.subst(self.lcx.tcx, self.substs)
+        };
+
+        self.lcx
+            .tcx
+            .const_eval_resolve(
+                self.param_env,
+                ty::Unevaluated {
+                    def: ty::WithOptConstParam::unknown(def_id),
+                    substs,
+                    promoted: None,
+                },
+                None,
+            )
+            .ok()
+            .map(|val| rustc_middle::ty::Const::from_value(self.lcx.tcx, val, ty))
+    }
+
+    /// Evaluates the field access `e`, like `TUPLE.0` or `STRUCT_CONST.field`.
+    fn field(&mut self, e: &Expr<'_>, base: &Expr<'_>) -> Option<Constant> {
+        let index = self.lcx.tcx.field_index(e.hir_id, self.typeck_results);
+        if let Some(Constant::Tuple(fields)) = self.expr(base) {
+            return fields.into_iter().nth(index);
+        }
+        let result = miri_to_const(self.field_value(e)?);
+        if result.is_some() {
+            self.needed_resolution = true;
+        }
+        result
+    }
+
+    /// Evaluates the path to a constant item, possibly behind field accesses, like
+    /// `STRUCT_CONST.field.0`, to a value of the compiler's constant evaluation, which unlike a
+    /// `Constant` can be a struct.
+    fn field_value(&self, e: &Expr<'_>) -> Option<&'tcx ty::Const<'tcx>> {
+        match e.kind {
+            ExprKind::Path(ref qpath) => match self.typeck_results.qpath_res(qpath, e.hir_id) {
+                Res::Def(DefKind::Const | DefKind::AssocConst, def_id) => {
+                    self.const_value(def_id, e.hir_id, self.typeck_results.expr_ty(e))
+                },
+                _ => None,
+            },
+            ExprKind::Field(base, _) => {
+                let base_value = self.field_value(base)?;
+                match base_value.ty.kind() {
+                    ty::Adt(adt, _) if adt.is_struct() => {},
+                    ty::Tuple(_) => {},
+                    _ => return None,
+                }
+                let index = self.lcx.tcx.field_index(e.hir_id, self.typeck_results);
+                let fields = self.lcx.tcx.destructure_const(self.param_env.and(base_value)).fields;
+                fields.get(index).copied()
+            },
+            _ => None,
+        }
+    }
+
     fn index(&mut self, lhs: &'_ Expr<'_>, index: &'_ Expr<'_>) -> Option<Constant> {
         let lhs = self.expr(lhs);
         let index = self.expr(index);
@@ -385,9 +510,206 @@ fn index(&mut self, lhs: &'_ Expr<'_>, index: &'_ Expr<'_>) -> Option<Constant>
         }
     }
 
+    /// Evaluates the calls of `<[T]>::len(&ARRAY)`, `std::cmp::min`, `std::cmp::max`, and
+    /// `From::from` between integer types, like `usize::from(n)`.
+    fn call(&mut self, e: &Expr<'_>, callee: &Expr<'_>, args: &[Expr<'_>]) -> Option<Constant> {
+        let def_id = match callee.kind {
+            ExprKind::Path(ref qpath) => self.typeck_results.qpath_res(qpath, callee.hir_id).opt_def_id()?,
+            _ => return None,
+        };
+        match args {
+            [arg] if self.lcx.tcx.item_name(def_id).as_str() == "len" => self.array_len(def_id, arg),
+            [arg] if match_def_path(self.lcx, def_id, &paths::FROM_FROM) => self.int_from(e, arg),
+            [left, right] if match_def_path(self.lcx, def_id, &paths::CMP_MIN) => {
+                self.min_max(left, right, Ordering::Less)
+            },
+            [left, right] if match_def_path(self.lcx, def_id, &paths::CMP_MAX) => {
+                self.min_max(left, right, Ordering::Greater)
+            },
+            _ => None,
+        }
+    }
+
+    /// Whether `callee` is a `const fn` of the crate, without generic arguments.
+    fn is_local_const_fn(&self, callee: &Expr<'_>) -> bool {
+        match callee.kind {
+            ExprKind::Path(ref qpath) => {
+                self.typeck_results
+                    .qpath_res(qpath, callee.hir_id)
+                    .opt_def_id()
+                    .map_or(false, |def_id| {
+                        def_id.is_local()
+                            && self.lcx.tcx.is_const_fn_raw(def_id)
+                            && self.typeck_results.node_substs(callee.hir_id).is_empty()
+                    })
+            },
+            _ => false,
+        }
+    }
+
+    /// Evaluates the call of the `const fn` of the crate `callee` with `args` by evaluating its
+    /// body with the parameters bound to the values of the arguments. To keep the time spent
+    /// bounded, calls nested deeper than `MAX_CONST_FN_DEPTH` aren't evaluated, and the evaluation
+    /// fails after `MAX_CONST_FN_STEPS` expressions.
+    fn const_fn_call(&mut self, callee: &Expr<'_>, args: &[Expr<'_>]) -> Option<Constant> {
+        if self.depth >= MAX_CONST_FN_DEPTH {
+            return None;
+        }
+        let def_id = match callee.kind {
+            ExprKind::Path(ref qpath) => self.typeck_results.qpath_res(qpath, callee.hir_id).opt_def_id()?,
+            _ => return None,
+        };
+        let hir = self.lcx.tcx.hir();
+        let body_id = hir.maybe_body_owned_by(hir.local_def_id_to_hir_id(def_id.as_local()?))?;
+        let body = hir.body(body_id);
+        let mut params = HirIdMap::default();
+        for (param, arg) in body.params.iter().zip(args) {
+            match param.pat.kind {
+                PatKind::Binding(BindingAnnotation::Unannotated, id, _, None) => {
+                    params.insert(id, self.expr(arg)?);
+                },
+                _ => return None,
+            }
+        }
+        let mut body_cx = ConstEvalLateContext {
+            lcx: self.lcx,
+            typeck_results: self.lcx.tcx.typeck_body(body_id),
+            param_env: self.lcx.tcx.param_env(def_id),
+            needed_resolution: false,
+            resolve_locals: true,
+            substs: self.lcx.tcx.intern_substs(&[]),
+            params,
+            depth: self.depth + 1,
+            steps: self.steps,
+        };
+        let result = body_cx.expr(&body.value);
+        self.steps = body_cx.steps;
+        self.needed_resolution = true;
+        result
+    }
+
+    /// Whether `callee` is `mem::size_of`.
+    fn is_size_of(&self, callee: &Expr<'_>) -> bool {
+        match callee.kind {
+            ExprKind::Path(ref qpath) => self
+                .typeck_results
+                .qpath_res(qpath, callee.hir_id)
+                .opt_def_id()
+                .map_or(false, |def_id| match_def_path(self.lcx, def_id, &paths::MEM_SIZE_OF)),
+            _ => false,
+        }
+    }
+
+    /// Evaluates the call of `mem::size_of::<T>()` whose callee is `callee`, if `T` is a concrete
+    /// type. The size depends on the target, so it is only evaluated with the values of the local
+    /// bindings, for the bounds of indexes, and not for lints on constant expressions like
+    /// `assert!(size_of::<T>() == 8)`.
+    fn size_of(&self, callee: &Expr<'_>) -> Option<Constant> {
+        let substs = self.typeck_results.node_substs(callee.hir_id);
+        let substs = if self.substs.is_empty() {
+            substs
+        } else {
+            substs.subst(self.lcx.tcx, self.substs)
+        };
+        let ty = substs.types().next()?;
+        if ty.has_param_types_or_consts() {
+            return None;
+        }
+        let layout = self.lcx.tcx.layout_of(self.param_env.and(ty)).ok()?;
+        Some(Constant::Int(layout.size.bytes().into()))
+    }
+
+    /// Evaluates the conversion `e` of `arg` with `From::from`, if both are integers (or `arg`
+    /// is a `bool`). The `From` implementations between them are lossless.
+    fn int_from(&mut self, e: &Expr<'_>, arg: &Expr<'_>) -> Option<Constant> {
+        let value = self.expr(arg)?;
+        match (
+            value,
+            self.typeck_results.expr_ty(arg).kind(),
+            self.typeck_results.expr_ty(e).kind(),
+        ) {
+            (Constant::Int(n), ty::Uint(_), ty::Uint(_) | ty::Int(_)) => Some(Constant::Int(n)),
+            (Constant::Int(n), &ty::Int(from), &ty::Int(to)) => {
+                Some(Constant::Int(unsext(self.lcx.tcx, sext(self.lcx.tcx, n, from), to)))
+            },
+            (Constant::Bool(b), ty::Bool, ty::Uint(_) | ty::Int(_)) => Some(Constant::Int(b.into())),
+            _ => None,
+        }
+    }
+
+    /// Whether `method_id` is `Ord::min` or `Ord::max`, rather than e.g. `f64::min`.
+    fn is_ord_method(&self, method_id: DefId) -> bool {
+        self.lcx
+            .tcx
+            .trait_of_item(method_id)
+            .map_or(false, |trait_id| match_def_path(self.lcx, trait_id, &paths::ORD))
+    }
+
+    /// Evaluates the minimum (if `keep` is `Less`) or maximum (if `keep` is `Greater`) of the
+    /// integers `left` and `right`.
+    fn min_max(&mut self, left: &Expr<'_>, right: &Expr<'_>, keep: Ordering) -> Option<Constant> {
+        let ty = self.typeck_results.expr_ty(left);
+        if !ty.is_integral() {
+            return None;
+        }
+        let l = self.expr(left)?;
+        let r = self.expr(right)?;
+        if Constant::partial_cmp(self.lcx.tcx, ty, &l, &r)? == keep {
+            Some(l)
+        } else {
+            Some(r)
+        }
+    }
+
+    /// Evaluates `left.saturating_add(right)` or `left.saturating_sub(right)`, where `method_id`
+    /// is the method of an integer type.
+    fn saturating(&mut self, left: &Expr<'_>, right: &Expr<'_>, method_id: DefId, op: BinOpKind) -> Option<Constant> {
+        let tcx = self.lcx.tcx;
+        let ty = tcx.type_of(tcx.impl_of_method(method_id)?);
+        let (l, r) = match (self.expr(left)?, self.expr(right)?) {
+            (Constant::Int(l), Constant::Int(r)) => (l, r),
+            _ => return None,
+        };
+        match *ty.kind() {
+            ty::Uint(uty) => Some(Constant::Int(match op {
+                BinOpKind::Add => l.saturating_add(r).min(clip(tcx, u128::MAX, uty)),
+                _ => l.saturating_sub(r),
+            })),
+            ty::Int(ity) => {
+                let shift = 128 - int_bits(tcx, ity);
+                let (l, r) = (sext(tcx, l, ity), sext(tcx, r, ity));
+                let value = match op {
+                    BinOpKind::Add => l.saturating_add(r),
+                    _ => l.saturating_sub(r),
+                };
+                let value = value.clamp(i128::MIN >> shift, i128::MAX >> shift);
+                Some(Constant::Int(unsext(tcx, value, ity)))
+            },
+            _ => None,
+        }
+    }
+
+    /// Evaluates `receiver.len()` (a call of the method `method_id`) if `receiver` is a fixed-size
+    /// array, or a reference to one. The length is part of the type, so the value of `receiver`
+    /// isn't needed, as long as evaluating it has no side effects.
+    fn array_len(&mut self, method_id: DefId, receiver: &Expr<'_>) -> Option<Constant> {
+        let impl_id = self.lcx.tcx.impl_of_method(method_id)?;
+        if !matches!(self.lcx.tcx.type_of(impl_id).kind(), ty::Slice(_)) || !is_place(receiver) {
+            return None;
+        }
+        match self.typeck_results.expr_ty(receiver).peel_refs().kind() {
+            ty::Array(_, n) => n
+                .try_eval_usize(self.lcx.tcx, self.param_env)
+                .map(|n| Constant::Int(n.into())),
+            _ => None,
+        }
+    }
+
     /// A block can only yield a constant if it only has one constant expression.
     fn block(&mut self, block: &Block<'_>) -> Option<Constant> {
-        if block.stmts.is_empty() {
+        // in the body of a `const fn`, the immutable bindings are resolved by `fetch_path`
+        let only_bindings = self.depth > 0 && block.stmts.iter().all(|stmt| matches!(stmt.kind, StmtKind::Local(_)));
+        if block.stmts.is_empty() || only_bindings {
             block.expr.as_ref().and_then(|b| self.expr(b))
         } else {
             None
@@ -499,6 +821,18 @@ fn binop(&mut self, op: BinOp, left: &Expr<'_>, right: &Expr<'_>) -> Option<Cons
     }
 }
 
+/// Whether `expr` is a path, possibly behind field accesses, references and dereferences, whose
+/// evaluation has no side effects.
+fn is_place(expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Path(_) => true,
+        ExprKind::Field(inner, _) | ExprKind::AddrOf(_, _, inner) | ExprKind::Unary(UnOp::Deref, inner) => {
+            is_place(inner)
+        },
+        _ => false,
+    }
+}
+
 pub fn miri_to_const(result: &ty::Const<'_>) -> Option<Constant> {
     use rustc_middle::mir::interpret::ConstValue;
     match result.val {
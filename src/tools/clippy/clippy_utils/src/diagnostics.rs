@@ -9,22 +9,262 @@
 //! ~The `INTERNAL_METADATA_COLLECTOR` lint
 
 use rustc_errors::{Applicability, DiagnosticBuilder};
+use rustc_hir::def_id::DefId;
 use rustc_hir::HirId;
 use rustc_lint::{LateContext, Lint, LintContext};
+use rustc_middle::ty::tls;
+use rustc_session::Session;
+use rustc_span::hygiene::{ExpnId, ExpnKind, MacroKind, SyntaxContext};
 use rustc_span::source_map::{MultiSpan, Span};
+use rustc_span::Symbol;
+use std::cell::{Cell, RefCell};
 use std::env;
+use std::ptr;
 
-fn docs_link(diag: &mut DiagnosticBuilder<'_>, lint: &'static Lint) {
+thread_local! {
+    /// The spans of the statements in which lints are allowed, see `allow_in_stmt`.
+    static ALLOWED_IN_STMTS: RefCell<Vec<(Span, Vec<String>)>> = RefCell::new(Vec::new());
+    /// The lints emitted in macro definitions with their first expansion and the number of times
+    /// they were repeated by other expansions, see `cancel_if_repeated_in_macro`.
+    static REPEATED_IN_MACROS: RefCell<Vec<(&'static str, Span, ExpnId, usize)>> = RefCell::new(Vec::new());
+    /// The lowest applicability of the suggestions applied by `cargo clippy --fix`, see
+    /// `set_fix_applicability`.
+    static FIX_APPLICABILITY: Cell<Applicability> = Cell::new(Applicability::MachineApplicable);
+    /// The notes added to the diagnostics of some lints, see `set_migration_notes`.
+    static MIGRATION_NOTES: Cell<&'static [(&'static &'static Lint, &'static str)]> = Cell::new(&[]);
+    /// The primary spans of the emitted lints, if they are recorded, see `record_emitted_spans`.
+    static EMITTED_SPANS: RefCell<Option<Vec<Span>>> = RefCell::new(None);
+    /// Whether the lints in the macros of other crates are reported at the macro calls, see
+    /// `set_report_in_external_macros`.
+    static REPORT_IN_EXTERNAL_MACROS: Cell<bool> = Cell::new(false);
+}
+
+/// Allows the lints named `lints` (like `clippy::indexing_slicing`) in the statement at `span`.
+/// This is used for `#[clippy::allow_next(..)]`, which can be put on any statement, and for
+/// `#[allow(..)]` on macro calls, whose attributes are dropped by the expansion.
+///
+/// The functions of this module don't emit the lints whose primary span (or the call site of the
+/// macro it comes from) is in such a statement.
+pub fn allow_in_stmt(span: Span, lints: Vec<String>) {
+    ALLOWED_IN_STMTS.with(|allowed| allowed.borrow_mut().push((span, lints)));
+}
+
+/// Whether `lint` is allowed in a statement containing `span`, see `allow_in_stmt`.
+pub fn is_allowed_in_stmt(lint: &Lint, span: Option<Span>) -> bool {
+    let span = match span {
+        Some(span) => span.source_callsite(),
+        None => return false,
+    };
+    ALLOWED_IN_STMTS.with(|allowed| {
+        let allowed = allowed.borrow();
+        if allowed.is_empty() {
+            return false;
+        }
+        let name = lint.name_lower();
+        allowed
+            .iter()
+            .any(|(stmt, lints)| stmt.contains(span) && lints.contains(&name))
+    })
+}
+
+/// Cancels `diag` if `lint` was already emitted at the same place in the definition of a macro by
+/// another expansion of the macro. Otherwise a lint in a macro is emitted once per call, which
+/// floods the output when the macro is called many times. The repetitions are counted and reported
+/// by `report_repeated_in_macros`.
+fn cancel_if_repeated_in_macro(diag: &mut DiagnosticBuilder<'_>, lint: &'static Lint) -> bool {
+    // a repeated lint is still emitted at this place, only not shown
+    record_emitted(diag);
+    let span = match diag.span.primary_span() {
+        Some(span) if span.from_expansion() => span,
+        _ => return false,
+    };
+    let expn = span.ctxt().outer_expn();
+    if !matches!(
+        expn.expn_data().kind,
+        ExpnKind::Macro {
+            kind: MacroKind::Bang,
+            ..
+        }
+    ) {
+        return false;
+    }
+    // the span in the definition, which is the same for all expansions
+    let def_span = span.with_ctxt(SyntaxContext::root());
+    let repeated = REPEATED_IN_MACROS.with(|repeated| {
+        let mut repeated = repeated.borrow_mut();
+        match repeated
+            .iter_mut()
+            .find(|(name, span, _, _)| *name == lint.name && *span == def_span)
+        {
+            Some((_, _, first, _)) if *first == expn => false,
+            Some((_, _, _, count)) => {
+                *count += 1;
+                true
+            },
+            None => {
+                repeated.push((lint.name, def_span, expn, 0));
+                false
+            },
+        }
+    });
+    if repeated {
+        diag.cancel();
+    }
+    repeated
+}
+
+/// Reports how many times the lints emitted in macro definitions were repeated by other expansions
+/// of the macros, see `cancel_if_repeated_in_macro`. This is called once all lints have run.
+pub fn report_repeated_in_macros(sess: &Session) {
+    let repeated = REPEATED_IN_MACROS.with(RefCell::take);
+    for (name, span, _, count) in repeated {
+        if count > 0 {
+            sess.span_note_without_error(
+                span,
+                &format!(
+                    "{} more occurrence{} of `{}` in other expansions of this macro",
+                    count,
+                    if count == 1 { "" } else { "s" },
+                    name.to_lowercase()
+                ),
+            );
+        }
+    }
+}
+
+/// Starts recording the primary spans of the emitted lints, which are returned by
+/// `take_emitted_spans`. This is used by the lint cache to find the items without diagnostics.
+pub fn record_emitted_spans() {
+    EMITTED_SPANS.with(|spans| *spans.borrow_mut() = Some(Vec::new()));
+}
+
+/// Returns the primary spans of the lints emitted since `record_emitted_spans` was called.
+pub fn take_emitted_spans() -> Vec<Span> {
+    EMITTED_SPANS.with(|spans| spans.borrow_mut().take().unwrap_or_default())
+}
+
+fn record_emitted(diag: &DiagnosticBuilder<'_>) {
+    EMITTED_SPANS.with(|spans| {
+        if let Some(spans) = &mut *spans.borrow_mut() {
+            spans.extend(diag.span.primary_span());
+        }
+    });
+}
+
+/// Sets the lowest applicability of the suggestions applied by `cargo clippy --fix`, which only
+/// applies machine applicable suggestions. With `Applicability::MaybeIncorrect`, the suggestions
+/// of this applicability are emitted as machine applicable.
+pub fn set_fix_applicability(applicability: Applicability) {
+    FIX_APPLICABILITY.with(|fix| fix.set(applicability));
+}
+
+/// Sets the notes added to every diagnostic of a lint, before the link to its documentation. Like
+/// the future incompatibility notes of rustc, they announce a change of the behavior of the lint
+/// and explain how to prepare for it.
+pub fn set_migration_notes(notes: &'static [(&'static &'static Lint, &'static str)]) {
+    MIGRATION_NOTES.with(|migration_notes| migration_notes.set(notes));
+}
+
+/// Sets whether the lints in the expansions of the `macro_rules!` and function-like macros of
+/// other crates are reported, instead of being skipped by `crate::in_external_macro`. They are
+/// reported at the call of the macro, with a note naming the macro and its crate, so that they can
+/// be reported to the authors of the macro.
+pub fn set_report_in_external_macros(report: bool) {
+    REPORT_IN_EXTERNAL_MACROS.with(|report_in| report_in.set(report));
+}
+
+/// Whether the lints in the macros of other crates are reported, see
+/// `set_report_in_external_macros`.
+pub fn reports_in_external_macros() -> bool {
+    REPORT_IN_EXTERNAL_MACROS.with(Cell::get)
+}
+
+/// Returns the name and the definition of the outermost `macro_rules!` or function-like macro of
+/// another crate that `span` was expanded from, with the span of its call.
+pub fn external_macro_call(span: Span) -> Option<(Symbol, DefId, Span)> {
+    let mut ctxt = span.ctxt();
+    let mut call = None;
+    while ctxt != SyntaxContext::root() {
+        let data = ctxt.outer_expn_data();
+        if let ExpnKind::Macro {
+            kind: MacroKind::Bang,
+            name,
+            ..
+        } = data.kind
+        {
+            if let Some(def_id) = data.macro_def_id.filter(|def_id| !def_id.is_local()) {
+                call = Some((name, def_id, data.call_site));
+            }
+        }
+        ctxt = data.call_site.ctxt();
+    }
+    call
+}
+
+/// Moves `diag` to the call of the macro of another crate its primary span comes from, if any,
+/// see `set_report_in_external_macros`. Its suggestions are dropped, since they would change the
+/// code of the macro.
+fn move_out_of_external_macro(diag: &mut DiagnosticBuilder<'_>) {
+    let (name, def_id, call_site) = match diag.span.primary_span().and_then(external_macro_call) {
+        Some(call) => call,
+        None => return,
+    };
+    // the early lint passes run without a type context
+    let krate = tls::with_opt(|tcx| tcx.map(|tcx| tcx.crate_name(def_id.krate)));
+    diag.set_span(call_site);
+    diag.suggestions.clear();
+    diag.note(&krate.map_or_else(
+        || format!("this lint originates in the macro `{}!` from another crate", name),
+        |krate| {
+            format!(
+                "this lint originates in the macro `{}!` from the crate `{}`",
+                name, krate
+            )
+        },
+    ));
+}
+
+/// Returns the URL of the documentation of `lint` for this version of Clippy, or `None` if it
+/// isn't a Clippy lint.
+pub fn docs_url(lint: &Lint) -> Option<String> {
+    let name = lint.name_lower();
+    let name = name.strip_prefix("clippy::")?;
+    Some(format!(
+        "https://rust-lang.github.io/rust-clippy/{}/index.html#{}",
+        &option_env!("RUST_RELEASE_NUM").map_or("master".to_string(), |n| {
+            // extract just major + minor version and ignore patch versions
+            format!("rust-{}", n.rsplitn(2, '.').nth(1).unwrap())
+        }),
+        name
+    ))
+}
+
+/// Adds the migration note and the link to the documentation of `lint` to `diag` and emits it,
+/// see `set_fix_applicability` for the applicability of its suggestions. A lint in the macro of
+/// another crate is moved to the call of the macro, see `set_report_in_external_macros`.
+fn emit(mut diag: DiagnosticBuilder<'_>, lint: &'static Lint) {
+    if reports_in_external_macros() {
+        move_out_of_external_macro(&mut diag);
+    }
+    if FIX_APPLICABILITY.with(Cell::get) == Applicability::MaybeIncorrect {
+        for suggestion in &mut diag.suggestions {
+            if suggestion.applicability == Applicability::MaybeIncorrect {
+                suggestion.applicability = Applicability::MachineApplicable;
+            }
+        }
+    }
+    add_references(&mut diag, lint);
+    diag.emit();
+}
+
+fn add_references(diag: &mut DiagnosticBuilder<'_>, lint: &'static Lint) {
+    let notes = MIGRATION_NOTES.with(Cell::get);
+    for &(_, note) in notes.iter().filter(|(noted, _)| ptr::eq(**noted, lint)) {
+        diag.note(note);
+    }
     if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
-        if let Some(lint) = lint.name_lower().strip_prefix("clippy::") {
-            diag.help(&format!(
-                "for further information visit https://rust-lang.github.io/rust-clippy/{}/index.html#{}",
-                &option_env!("RUST_RELEASE_NUM").map_or("master".to_string(), |n| {
-                    // extract just major + minor version and ignore patch versions
-                    format!("rust-{}", n.rsplitn(2, '.').nth(1).unwrap())
-                }),
-                lint
-            ));
+        if let Some(url) = docs_url(lint) {
+            diag.help(&format!("for further information visit {}", url));
         }
     }
 }
@@ -47,10 +287,16 @@ fn docs_link(diag: &mut DiagnosticBuilder<'_>, lint: &'static Lint) {
 ///    |     ^^^^^^^^^^^^^^^^^^^^^^^
 /// ```
 pub fn span_lint<T: LintContext>(cx: &T, lint: &'static Lint, sp: impl Into<MultiSpan>, msg: &str) {
+    let sp = sp.into();
+    if is_allowed_in_stmt(lint, sp.primary_span()) {
+        return;
+    }
     cx.struct_span_lint(lint, sp, |diag| {
         let mut diag = diag.build(msg);
-        docs_link(&mut diag, lint);
-        diag.emit();
+        if cancel_if_repeated_in_macro(&mut diag, lint) {
+            return;
+        }
+        emit(diag, lint);
     });
 }
 
@@ -82,15 +328,20 @@ pub fn span_lint_and_help<'a, T: LintContext>(
     help_span: Option<Span>,
     help: &str,
 ) {
+    if is_allowed_in_stmt(lint, Some(span)) {
+        return;
+    }
     cx.struct_span_lint(lint, span, |diag| {
         let mut diag = diag.build(msg);
+        if cancel_if_repeated_in_macro(&mut diag, lint) {
+            return;
+        }
         if let Some(help_span) = help_span {
             diag.span_help(help_span, help);
         } else {
             diag.help(help);
         }
-        docs_link(&mut diag, lint);
-        diag.emit();
+        emit(diag, lint);
     });
 }
 
@@ -125,15 +376,21 @@ pub fn span_lint_and_note<'a, T: LintContext>(
     note_span: Option<Span>,
     note: &str,
 ) {
+    let span = span.into();
+    if is_allowed_in_stmt(lint, span.primary_span()) {
+        return;
+    }
     cx.struct_span_lint(lint, span, |diag| {
         let mut diag = diag.build(msg);
+        if cancel_if_repeated_in_macro(&mut diag, lint) {
+            return;
+        }
         if let Some(note_span) = note_span {
             diag.span_note(note_span, note);
         } else {
             diag.note(note);
         }
-        docs_link(&mut diag, lint);
-        diag.emit();
+        emit(diag, lint);
     });
 }
 
@@ -147,19 +404,56 @@ pub fn span_lint_and_then<C, S, F>(cx: &C, lint: &'static Lint, sp: S, msg: &str
     S: Into<MultiSpan>,
     F: FnOnce(&mut DiagnosticBuilder<'_>),
 {
+    let sp = sp.into();
+    if is_allowed_in_stmt(lint, sp.primary_span()) {
+        return;
+    }
     cx.struct_span_lint(lint, sp, |diag| {
         let mut diag = diag.build(msg);
+        if cancel_if_repeated_in_macro(&mut diag, lint) {
+            return;
+        }
         f(&mut diag);
-        docs_link(&mut diag, lint);
-        diag.emit();
+        emit(diag, lint);
     });
 }
 
+/// Like `span_lint_and_then` but with labeled spans, to point at the parts of the code that explain
+/// the lint. A label can be attached to the primary span `sp` as well.
+///
+/// # Example
+///
+/// ```ignore
+/// error: index is out of bounds
+///   --> $DIR/vec.rs:5:5
+///    |
+/// 4  |     let v = vec![0; 4];
+///    |             ---------- the vector has length 4
+/// 5  |     v[9];
+///    |     ^^^^ this index is 9
+/// ```
+pub fn span_lint_and_then_multi<C, F>(cx: &C, lint: &'static Lint, sp: Span, msg: &str, labels: &[(Span, String)], f: F)
+where
+    C: LintContext,
+    F: FnOnce(&mut DiagnosticBuilder<'_>),
+{
+    let mut multi_span = MultiSpan::from_span(sp);
+    for (span, label) in labels {
+        multi_span.push_span_label(*span, label.clone());
+    }
+    span_lint_and_then(cx, lint, multi_span, msg, f);
+}
+
 pub fn span_lint_hir(cx: &LateContext<'_>, lint: &'static Lint, hir_id: HirId, sp: Span, msg: &str) {
+    if is_allowed_in_stmt(lint, Some(sp)) {
+        return;
+    }
     cx.tcx.struct_span_lint_hir(lint, hir_id, sp, |diag| {
         let mut diag = diag.build(msg);
-        docs_link(&mut diag, lint);
-        diag.emit();
+        if cancel_if_repeated_in_macro(&mut diag, lint) {
+            return;
+        }
+        emit(diag, lint);
     });
 }
 
@@ -171,11 +465,16 @@ pub fn span_lint_hir_and_then(
     msg: &str,
     f: impl FnOnce(&mut DiagnosticBuilder<'_>),
 ) {
+    if is_allowed_in_stmt(lint, Some(sp)) {
+        return;
+    }
     cx.tcx.struct_span_lint_hir(lint, hir_id, sp, |diag| {
         let mut diag = diag.build(msg);
+        if cancel_if_repeated_in_macro(&mut diag, lint) {
+            return;
+        }
         f(&mut diag);
-        docs_link(&mut diag, lint);
-        diag.emit();
+        emit(diag, lint);
     });
 }
 
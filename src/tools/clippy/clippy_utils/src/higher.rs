@@ -3,7 +3,8 @@
 
 #![deny(clippy::missing_docs_in_private_items)]
 
-use crate::{is_expn_of, match_def_path, paths};
+use crate::ty::implements_trait;
+use crate::{expr_or_init, get_trait_def_id, is_expn_of, match_def_path, paths};
 use if_chain::if_chain;
 use rustc_ast::ast;
 use rustc_hir as hir;
@@ -102,6 +103,36 @@ fn get_field<'c>(name: &str, fields: &'c [hir::ExprField<'_>]) -> Option<&'c hir
     }
 }
 
+/// A range expression, or another expression whose type implements `RangeBounds<usize>`.
+#[derive(Debug, Copy, Clone)]
+pub enum RangeLike<'a> {
+    /// A range expression, possibly stored in an immutable local binding first, like `r` in
+    /// `let r = a..b; &x[r]`.
+    Range(Range<'a>),
+    /// Any other expression implementing `RangeBounds<usize>`, e.g. a `Range<usize>` parameter or a
+    /// user-defined type. Its bounds are unknown.
+    Opaque,
+}
+
+/// Like `range`, but also recognizes ranges stored in a local binding and the expressions whose
+/// type implements `RangeBounds<usize>`.
+///
+/// Note that the bounds of a range stored in a binding are evaluated where the binding is
+/// initialized, so they don't need to be related to the place `expr` is used at.
+pub fn range_like<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<RangeLike<'tcx>> {
+    if let Some(range) = range(expr_or_init(cx, expr)) {
+        return Some(RangeLike::Range(range));
+    }
+
+    let ty = cx.typeck_results().expr_ty(expr);
+    let range_bounds = get_trait_def_id(cx, &paths::RANGE_ARGUMENT_TRAIT)?;
+    if implements_trait(cx, ty, range_bounds, &[cx.tcx.types.usize.into()]) {
+        Some(RangeLike::Opaque)
+    } else {
+        None
+    }
+}
+
 /// Checks if a `let` statement is from a `for` loop desugaring.
 pub fn is_from_for_desugar(local: &hir::Local<'_>) -> bool {
     // This will detect plain for-loops without an actual variable binding:
@@ -47,6 +47,7 @@
 pub mod sugg;
 pub mod ty;
 pub mod usage;
+pub mod value_range;
 pub mod visitors;
 
 pub use self::attrs::*;
@@ -54,6 +55,7 @@
 
 use std::collections::hash_map::Entry;
 use std::hash::BuildHasherDefault;
+use std::iter;
 
 use if_chain::if_chain;
 use rustc_ast::ast::{self, Attribute, BorrowKind, LitKind};
@@ -74,7 +76,9 @@
 use rustc_middle::ty as rustc_ty;
 use rustc_middle::ty::{layout::IntegerExt, DefIdTree, Ty, TyCtxt, TypeFoldable};
 use rustc_semver::RustcVersion;
+use rustc_session::config::CrateType;
 use rustc_session::Session;
+use rustc_span::edition::Edition;
 use rustc_span::hygiene::{ExpnKind, MacroKind};
 use rustc_span::source_map::original_sp;
 use rustc_span::sym;
@@ -100,6 +104,13 @@ pub fn meets_msrv(msrv: Option<&RustcVersion>, lint_msrv: &RustcVersion) -> bool
     msrv.map_or(true, |msrv| msrv.meets(*lint_msrv))
 }
 
+/// Returns whether the code at `span` is compiled with `edition` or a later one, e.g. whether a
+/// suggestion for it can use the syntax or the prelude of that edition. Code expanded from a macro
+/// has the edition of the crate defining the macro.
+pub fn meets_edition(span: Span, edition: Edition) -> bool {
+    span.edition() >= edition
+}
+
 #[macro_export]
 macro_rules! extract_msrv_attr {
     (LateContext) => {
@@ -248,6 +259,27 @@ pub fn in_macro(span: Span) -> bool {
     }
 }
 
+/// Like `rustc_middle::lint::in_external_macro`, but `false` for the expansions of the
+/// `macro_rules!` and function-like macros of other crates if the lints in them are reported, see
+/// `diagnostics::set_report_in_external_macros`. The lint passes use this to skip the code they
+/// can't change.
+pub fn in_external_macro(sess: &Session, span: Span) -> bool {
+    if !rustc_middle::lint::in_external_macro(sess, span) {
+        return false;
+    }
+    if !diagnostics::reports_in_external_macros() {
+        return true;
+    }
+    let data = span.ctxt().outer_expn_data();
+    !(matches!(
+        data.kind,
+        ExpnKind::Macro {
+            kind: MacroKind::Bang,
+            ..
+        }
+    ) && data.macro_def_id.map_or(false, |def_id| !def_id.is_local()))
+}
+
 /// Checks if given pattern is a wildcard (`_`)
 pub fn is_wild<'tcx>(pat: &impl std::ops::Deref<Target = Pat<'tcx>>) -> bool {
     matches!(pat.kind, PatKind::Wild)
@@ -1159,6 +1191,7 @@ fn is_err(cx: &LateContext<'_>, arm: &Arm<'_>) -> bool {
 /// Useful for skipping long running code when it's unnecessary
 pub fn is_allowed(cx: &LateContext<'_>, lint: &'static Lint, id: HirId) -> bool {
     cx.tcx.lint_level_at_node(lint, id).0 == Level::Allow
+        || diagnostics::is_allowed_in_stmt(lint, Some(cx.tcx.hir().span(id)))
 }
 
 pub fn strip_pat_refs<'hir>(mut pat: &'hir Pat<'hir>) -> &'hir Pat<'hir> {
@@ -1420,6 +1453,46 @@ pub fn is_no_std_crate(cx: &LateContext<'_>) -> bool {
     })
 }
 
+/// Checks if the crate is compiled as a library, i.e. none of its crate types is an executable.
+/// Test harnesses are executables as well.
+pub fn is_lib_crate(cx: &LateContext<'_>) -> bool {
+    !cx.tcx.sess.crate_types().contains(&CrateType::Executable)
+}
+
+/// Checks if the given `HirId` is part of test code, i.e. if it is inside a `#[test]` function or
+/// an item marked `#[cfg(test)]`.
+pub fn is_in_test_code(tcx: TyCtxt<'_>, id: HirId) -> bool {
+    iter::once((id, tcx.hir().get(id)))
+        .chain(tcx.hir().parent_iter(id))
+        .any(|(id, node)| is_cfg_test(tcx, id) || matches!(node, Node::Item(item) if is_test_function(tcx, item)))
+}
+
+/// Checks if the given `HirId` has a `#[cfg(test)]` attribute.
+fn is_cfg_test(tcx: TyCtxt<'_>, id: HirId) -> bool {
+    tcx.hir().attrs(id).iter().any(|attr| {
+        attr.has_name(sym::cfg)
+            && attr
+                .meta_item_list()
+                .map_or(false, |items| items.iter().any(|item| item.has_name(sym::test)))
+    })
+}
+
+/// Checks if the item is a `#[test]` function. The test harness generates a `#[rustc_test_marker]`
+/// constant with the same name next to each test function, so this is only true when compiling
+/// with `--test`.
+fn is_test_function(tcx: TyCtxt<'_>, item: &Item<'_>) -> bool {
+    if !matches!(item.kind, ItemKind::Fn(..)) {
+        return false;
+    }
+    let (module, _, _) = tcx.hir().get_module(tcx.parent_module(item.hir_id()));
+    module.item_ids.iter().any(|&id| {
+        let marker = tcx.hir().item(id);
+        marker.ident.name == item.ident.name
+            && matches!(marker.kind, ItemKind::Const(..))
+            && tcx.has_attr(marker.def_id.to_def_id(), sym::rustc_test_marker)
+    })
+}
+
 /// Check if parent of a hir node is a trait implementation block.
 /// For example, `f` in
 /// ```rust,ignore
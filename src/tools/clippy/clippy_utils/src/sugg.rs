@@ -238,6 +238,19 @@ pub fn mut_addr_deref(self) -> Sugg<'static> {
         make_unop("&mut *", self)
     }
 
+    /// Convenience method to create the `<receiver>.<name>(<args>)` suggestion. The receiver is
+    /// parenthesized if needed, so a borrowed receiver stays borrowed (`(&x).get(n)`, not
+    /// `&x.get(n)`) and operators bind correctly (`(a + b).pow(2)`).
+    pub fn method_call(receiver: Self, name: &str, args: &[Sugg<'_>]) -> Sugg<'static> {
+        let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+        Sugg::NonParen(Cow::Owned(format!(
+            "{}.{}({})",
+            receiver.maybe_par(),
+            name,
+            args.join(", ")
+        )))
+    }
+
     /// Convenience method to transform suggestion into a return call
     pub fn make_return(self) -> Sugg<'static> {
         Sugg::NonParen(Cow::Owned(format!("return {}", self)))
@@ -719,4 +732,24 @@ fn binop_maybe_par() {
         let sugg = Sugg::BinOp(AssocOp::Add, "(1 + 1) + (1 + 1)".into());
         assert_eq!("((1 + 1) + (1 + 1))", sugg.maybe_par().to_string());
     }
+
+    #[test]
+    fn method_call() {
+        let index = Sugg::NonParen("n".into());
+        let sugg = Sugg::method_call(Sugg::NonParen("x".into()), "get", &[index.clone()]);
+        assert_eq!("x.get(n)", sugg.to_string());
+
+        let sugg = Sugg::method_call(Sugg::MaybeParen("&x".into()), "get", &[index]);
+        assert_eq!("(&x).get(n)", sugg.to_string());
+
+        let sugg = Sugg::method_call(
+            Sugg::BinOp(AssocOp::Add, "a + b".into()),
+            "pow",
+            &[Sugg::NonParen("2".into())],
+        );
+        assert_eq!("(a + b).pow(2)", sugg.to_string());
+
+        let sugg = Sugg::method_call(SUGGESTION, "split_at", &[Sugg::NonParen("1".into()), sugg]);
+        assert_eq!("function_call().split_at(1, (a + b).pow(2))", sugg.to_string());
+    }
 }
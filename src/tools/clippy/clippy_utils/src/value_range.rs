@@ -0,0 +1,540 @@
+//! A small value-range analysis for unsigned integer expressions, based on the comparisons
+//! guarding them.
+//!
+//! The range of a local variable is narrowed by the conditions of the `if` expressions and
+//! `while` loops the expression is nested in, as long as the variable isn't mutated in the
+//! guarded branch, and by the range a `for` loop iterates over if the variable is the loop
+//! variable, like `0..v.len() - 1`. The `assert!`s and `assert_eq!`s and the `if`s that return
+//! early (or otherwise diverge) preceding the expression in the same block narrow the range as
+//! well, as long as the variable isn't mutated in between. Bounds can be constants or the length of
+//! a collection (`i < v.len()`) minus a constant (`i < v.len() - 2`), and `i != 0` is treated as `i
+//! > 0`.
+//!
+//! [`is_nonzero`] uses the same guards for signed local variables, where `i != 0`, `i > 0` and `i <
+//! 0` rule out zero.
+
+#![deny(clippy::missing_docs_in_private_items)]
+
+use crate::comparisons::{normalize_comparison, Rel};
+use crate::consts::{constant_with_locals, Constant};
+use crate::higher;
+use crate::usage::mutated_variables;
+use crate::{is_direct_expn_of, path_to_local, path_to_local_id, sext, SpanlessEq};
+use rustc_ast::ast::RangeLimits;
+use rustc_hir::{BinOpKind, Block, Expr, ExprKind, HirId, HirIdSet, MatchSource, Node, PatKind, Stmt, StmtKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+
+/// An upper bound given by the length of a collection: the value plus `margin` is at most the
+/// length.
+#[derive(Clone, Copy, Debug)]
+pub struct LenBound<'tcx> {
+    /// The receiver of the `len()` call, e.g. `v` in `i < v.len()`.
+    pub receiver: &'tcx Expr<'tcx>,
+    /// How much smaller than the length the value is at least, e.g. 1 for `i < v.len()` and 0 for
+    /// `i <= v.len()`.
+    pub margin: u128,
+}
+
+/// The values an unsigned integer expression can have.
+#[derive(Clone, Debug)]
+pub struct ValueRange<'tcx> {
+    /// The smallest possible value.
+    pub min: u128,
+    /// The largest possible value, if known.
+    pub max: Option<u128>,
+    /// The lengths the value is bounded by.
+    pub len_bounds: Vec<LenBound<'tcx>>,
+}
+
+impl<'tcx> ValueRange<'tcx> {
+    /// The range of an expression nothing is known about.
+    pub fn unknown() -> Self {
+        Self {
+            min: 0,
+            max: None,
+            len_bounds: Vec::new(),
+        }
+    }
+
+    /// The range containing only `value`.
+    pub fn exact(value: u128) -> Self {
+        Self {
+            min: value,
+            max: Some(value),
+            len_bounds: Vec::new(),
+        }
+    }
+
+    /// The range of the length of `collection`, whose length is `len` if known.
+    pub fn len_of(collection: &'tcx Expr<'tcx>, len: Option<u128>) -> Self {
+        Self {
+            min: len.unwrap_or(0),
+            max: len,
+            len_bounds: vec![LenBound {
+                receiver: collection,
+                margin: 0,
+            }],
+        }
+    }
+
+    /// The range of the value plus `n`. Bounds by a length are only kept if their margin is at
+    /// least `n`.
+    #[must_use]
+    pub fn plus(mut self, n: u128) -> Self {
+        self.min = self.min.saturating_add(n);
+        self.max = self.max.and_then(|max| max.checked_add(n));
+        self.len_bounds.retain(|bound| bound.margin >= n);
+        for bound in &mut self.len_bounds {
+            bound.margin -= n;
+        }
+        self
+    }
+
+    /// The range of the value minus `n`. Subtraction that would underflow is ignored, as it
+    /// panics or wraps around.
+    #[must_use]
+    pub fn minus(mut self, n: u128) -> Self {
+        self.min = self.min.saturating_sub(n);
+        if let Some(max) = self.max {
+            match max.checked_sub(n) {
+                Some(max) => self.max = Some(max),
+                None => return Self::unknown(),
+            }
+        }
+        for bound in &mut self.len_bounds {
+            bound.margin = bound.margin.saturating_add(n);
+        }
+        self
+    }
+
+    /// Whether the value is less than the length of `collection`, whose length is `len` if known.
+    pub fn is_below_len(&self, cx: &LateContext<'_>, collection: &Expr<'_>, len: Option<u128>) -> bool {
+        self.max.zip(len).map_or(false, |(max, len)| max < len)
+            || self
+                .len_bounds
+                .iter()
+                .any(|bound| bound.margin > 0 && SpanlessEq::new(cx).eq_expr(bound.receiver, collection))
+    }
+
+    /// Whether the value is at most the length of `collection`, whose length is `len` if known.
+    pub fn is_at_most_len(&self, cx: &LateContext<'_>, collection: &Expr<'_>, len: Option<u128>) -> bool {
+        self.max.zip(len).map_or(false, |(max, len)| max <= len)
+            || self
+                .len_bounds
+                .iter()
+                .any(|bound| SpanlessEq::new(cx).eq_expr(bound.receiver, collection))
+    }
+
+    /// Narrows the range to the values `> n`, or `>= n` if `inclusive`.
+    fn set_min(&mut self, n: u128, inclusive: bool) {
+        let min = if inclusive { Some(n) } else { n.checked_add(1) };
+        if let Some(min) = min {
+            self.min = self.min.max(min);
+        }
+    }
+
+    /// Narrows the range to the values `< n`, or `<= n` if `inclusive`.
+    fn set_max(&mut self, n: u128, inclusive: bool) {
+        let max = if inclusive { Some(n) } else { n.checked_sub(1) };
+        if let Some(max) = max {
+            self.max = Some(self.max.map_or(max, |m| m.min(max)));
+        }
+    }
+
+    /// Narrows the range to the range `other`.
+    fn intersect(&mut self, other: Self) {
+        self.set_min(other.min, true);
+        if let Some(max) = other.max {
+            self.set_max(max, true);
+        }
+        self.len_bounds.extend(other.len_bounds);
+    }
+}
+
+/// Returns the values the unsigned integer expression `expr` can have. Constants, local
+/// variables, the addition or subtraction of a constant, and masks and remainders by a constant
+/// (`i & 15`, `i % 16`) are supported.
+pub fn value_range<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> ValueRange<'tcx> {
+    if !matches!(cx.typeck_results().expr_ty(expr).kind(), ty::Uint(_)) {
+        return ValueRange::unknown();
+    }
+    if let Some(n) = const_int(cx, expr) {
+        return ValueRange::exact(n);
+    }
+
+    match expr.kind {
+        ExprKind::Binary(op, lhs, rhs) => match (op.node, const_int(cx, lhs), const_int(cx, rhs)) {
+            (BinOpKind::Add, Some(n), None) => value_range(cx, rhs).plus(n),
+            (BinOpKind::Add, None, Some(n)) => value_range(cx, lhs).plus(n),
+            (BinOpKind::Sub, None, Some(n)) => value_range(cx, lhs).minus(n),
+            (BinOpKind::BitAnd, Some(n), None) | (BinOpKind::BitAnd, None, Some(n)) => {
+                let mut range = ValueRange::unknown();
+                range.set_max(n, true);
+                range
+            },
+            (BinOpKind::Rem, None, Some(n)) => {
+                let mut range = ValueRange::unknown();
+                range.set_max(n, false);
+                range
+            },
+            _ => ValueRange::unknown(),
+        },
+        _ => match path_to_local(expr) {
+            Some(local) => local_range(cx, local, expr),
+            None => ValueRange::unknown(),
+        },
+    }
+}
+
+/// Whether the integer expression `expr` is known not to be zero: it is a nonzero constant, an
+/// unsigned expression whose range doesn't contain zero, like a variable guarded by `n != 0`, or a
+/// signed variable guarded by `n != 0`, `n > 0` or `n < 0`.
+pub fn is_nonzero<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
+    match constant_with_locals(cx, cx.typeck_results(), expr) {
+        Some((Constant::Int(n), _)) => n != 0,
+        // the negative constants are ignored, so the minimum of a signed variable is only raised by
+        // the guards ruling out zero
+        _ if is_signed(cx, expr) => path_to_local(expr).map_or(false, |local| local_range(cx, local, expr).min > 0),
+        _ => value_range(cx, expr).min > 0,
+    }
+}
+
+/// Returns the values the local variable `local` can have at `expr`.
+fn local_range<'tcx>(cx: &LateContext<'tcx>, local: HirId, expr: &'tcx Expr<'tcx>) -> ValueRange<'tcx> {
+    let mut range = ValueRange::unknown();
+    // the receivers of the method calls `expr` is an argument of, like `v` in `v.get_unchecked_mut(i)`
+    let mut receivers = HirIdSet::default();
+    let mut child = expr.hir_id;
+    for (parent_id, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        let parent = match node {
+            Node::Expr(parent) => parent,
+            Node::Block(block) => {
+                range.intersect(guards_range(cx, local, block, child, &receivers));
+                child = parent_id;
+                continue;
+            },
+            Node::Arm(_) | Node::Stmt(_) | Node::Local(_) => {
+                child = parent_id;
+                continue;
+            },
+            _ => break,
+        };
+
+        match parent.kind {
+            ExprKind::If(cond, then, else_) => {
+                if then.hir_id == child {
+                    range.intersect(condition_range(cx, local, cond, then, false));
+                } else if let Some(else_) = else_.filter(|else_| else_.hir_id == child) {
+                    range.intersect(condition_range(cx, local, cond, else_, true));
+                }
+            },
+            ExprKind::Match(cond, [arm, ..], MatchSource::WhileDesugar) if arm.hir_id == child => {
+                range.intersect(condition_range(cx, local, cond, arm.body, false));
+            },
+            ExprKind::MethodCall(_, _, [receiver, args @ ..], _) if args.iter().any(|arg| arg.hir_id == child) => {
+                receivers.extend(path_to_local(receiver));
+            },
+            ExprKind::Closure(..) => break,
+            _ => {
+                if let Some((pat, arg, body, _)) = higher::for_loop(parent) {
+                    if matches!(pat.kind, PatKind::Binding(..)) && pat.hir_id == local {
+                        range.intersect(for_loop_range(cx, local, arg, body));
+                    }
+                }
+            },
+        }
+        child = parent_id;
+    }
+    range
+}
+
+/// Returns the values `local` can have in `scope` if `cond` is true, or false if `negate` is set.
+/// The range is unknown if `local` is mutated in `scope`.
+fn condition_range<'tcx>(
+    cx: &LateContext<'tcx>,
+    local: HirId,
+    cond: &'tcx Expr<'tcx>,
+    scope: &'tcx Expr<'tcx>,
+    negate: bool,
+) -> ValueRange<'tcx> {
+    let mut range = ValueRange::unknown();
+    add_condition(cx, local, cond, negate, &mut range);
+    unless_mutated(range, local, mutated_variables(scope, cx))
+}
+
+/// Returns the values `local` can have at `child`, a statement or the trailing expression of
+/// `block`, given the assertions and diverging `if`s preceding it in `block`. The `receivers` of
+/// the method calls in `child` that the value is passed to are only borrowed after it is computed,
+/// so they don't invalidate the bounds by their length.
+fn guards_range<'tcx>(
+    cx: &LateContext<'tcx>,
+    local: HirId,
+    block: &'tcx Block<'tcx>,
+    child: HirId,
+    receivers: &HirIdSet,
+) -> ValueRange<'tcx> {
+    let pos = block
+        .stmts
+        .iter()
+        .position(|stmt| stmt.hir_id == child)
+        .unwrap_or(block.stmts.len());
+    let child_mutated = match block.stmts.get(pos) {
+        Some(stmt) => mutated_in_stmts(cx, std::slice::from_ref(stmt), None),
+        None => mutated_in_stmts(cx, &[], block.expr),
+    }
+    .map(|mutated| {
+        mutated
+            .into_iter()
+            .filter(|id| !receivers.contains(id))
+            .collect::<HirIdSet>()
+    });
+    let mut range = ValueRange::unknown();
+    for (i, stmt) in block.stmts[..pos].iter().enumerate() {
+        let mut guard_range = ValueRange::unknown();
+        if !add_guard(cx, local, stmt, &mut guard_range) {
+            continue;
+        }
+        // the statements up to and including `child` must not invalidate the guard
+        let mutated = mutated_in_stmts(cx, &block.stmts[i + 1..pos], None)
+            .zip(child_mutated.as_ref())
+            .map(|(mut mutated, child_mutated)| {
+                mutated.extend(child_mutated);
+                mutated
+            });
+        range.intersect(unless_mutated(guard_range, local, mutated));
+    }
+    range
+}
+
+/// Narrows `range` to the values `local` can have after the statement `stmt`, if it is an
+/// `assert!`, an `assert_eq!` or an `if` without `else` whose `then` branch diverges. Returns
+/// whether `stmt` is such a guard.
+fn add_guard<'tcx>(cx: &LateContext<'tcx>, local: HirId, stmt: &'tcx Stmt<'tcx>, range: &mut ValueRange<'tcx>) -> bool {
+    let expr = match stmt.kind {
+        StmtKind::Expr(expr) | StmtKind::Semi(expr) => expr,
+        StmtKind::Local(_) | StmtKind::Item(_) => return false,
+    };
+    if is_direct_expn_of(stmt.span, "assert_eq").is_some() {
+        if let Some([lhs, rhs]) = higher::extract_assert_macro_args(expr).as_deref() {
+            add_equality(cx, local, lhs, rhs, range);
+            return true;
+        }
+    }
+    // `assert!(cond)` expands to `if !cond { panic!(..) }`
+    match expr.kind {
+        ExprKind::If(cond, then, None) if diverges(cx, then) => {
+            add_condition(cx, local, cond, true, range);
+            true
+        },
+        _ => false,
+    }
+}
+
+/// Whether the block `expr` never completes, e.g. because it returns early or panics.
+fn diverges(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    // the trailing expression of a block is coerced to the type expected from the block
+    cx.typeck_results().expr_ty(expr).is_never()
+        || matches!(expr.kind, ExprKind::Block(block, _) if block.expr.map_or(false, |e| diverges(cx, e)))
+}
+
+/// Returns the local variables mutated in `stmts` and `expr`, see [`mutated_variables`].
+fn mutated_in_stmts<'tcx>(
+    cx: &LateContext<'tcx>,
+    stmts: &'tcx [Stmt<'tcx>],
+    expr: Option<&'tcx Expr<'tcx>>,
+) -> Option<HirIdSet> {
+    let mut mutated = HirIdSet::default();
+    let exprs = stmts.iter().filter_map(|stmt| match stmt.kind {
+        StmtKind::Expr(expr) | StmtKind::Semi(expr) => Some(expr),
+        StmtKind::Local(local) => local.init,
+        StmtKind::Item(_) => None,
+    });
+    for expr in exprs.chain(expr) {
+        mutated.extend(mutated_variables(expr, cx)?);
+    }
+    Some(mutated)
+}
+
+/// Returns `range` if `local` isn't in the `mutated` variables, keeping only the bounds by the
+/// length of collections that aren't mutated either.
+fn unless_mutated(mut range: ValueRange<'_>, local: HirId, mutated: Option<HirIdSet>) -> ValueRange<'_> {
+    match mutated {
+        Some(mutated) if !mutated.contains(&local) => {
+            range
+                .len_bounds
+                .retain(|bound| is_unmutated_place(bound.receiver, &mutated));
+            range
+        },
+        _ => ValueRange::unknown(),
+    }
+}
+
+/// Whether `expr` is a place based on a local variable, like `v`, `*v`, `s.v` or `v[i]`, where
+/// none of the local variables involved are in the `mutated` variables.
+fn is_unmutated_place(expr: &Expr<'_>, mutated: &HirIdSet) -> bool {
+    match expr.kind {
+        ExprKind::Field(base, _) | ExprKind::Unary(UnOp::Deref, base) => is_unmutated_place(base, mutated),
+        ExprKind::Index(base, index) => {
+            is_unmutated_place(base, mutated)
+                && (matches!(index.kind, ExprKind::Lit(_)) || is_unmutated_place(index, mutated))
+        },
+        _ => path_to_local(expr).map_or(false, |id| !mutated.contains(&id)),
+    }
+}
+
+/// Narrows `range` to the values `local` can have if `cond` is true, or false if `negate` is set.
+fn add_condition<'tcx>(
+    cx: &LateContext<'tcx>,
+    local: HirId,
+    cond: &'tcx Expr<'tcx>,
+    negate: bool,
+    range: &mut ValueRange<'tcx>,
+) {
+    match cond.kind {
+        ExprKind::DropTemps(cond) => add_condition(cx, local, cond, negate, range),
+        ExprKind::Unary(UnOp::Not, cond) => add_condition(cx, local, cond, !negate, range),
+        // `!(a || b)` is `!a && !b`
+        ExprKind::Binary(op, lhs, rhs)
+            if (op.node == BinOpKind::And && !negate) || (op.node == BinOpKind::Or && negate) =>
+        {
+            add_condition(cx, local, lhs, negate, range);
+            add_condition(cx, local, rhs, negate, range);
+        }
+        ExprKind::Binary(op, lhs, rhs) => {
+            let op = if negate {
+                match op.node {
+                    BinOpKind::Lt => BinOpKind::Ge,
+                    BinOpKind::Le => BinOpKind::Gt,
+                    BinOpKind::Gt => BinOpKind::Le,
+                    BinOpKind::Ge => BinOpKind::Lt,
+                    BinOpKind::Eq => BinOpKind::Ne,
+                    BinOpKind::Ne => BinOpKind::Eq,
+                    _ => return,
+                }
+            } else {
+                op.node
+            };
+            match normalize_comparison(op, lhs, rhs) {
+                Some((rel @ (Rel::Lt | Rel::Le), lhs, rhs)) => {
+                    let inclusive = rel == Rel::Le;
+                    if path_to_local_id(lhs, local) {
+                        // a signed `x < 0` isn't zero either, see `is_nonzero`
+                        if !inclusive && is_signed(cx, lhs) && const_int(cx, rhs) == Some(0) {
+                            range.set_min(1, true);
+                        }
+                        add_upper_bound(cx, rhs, inclusive, range);
+                    } else if path_to_local_id(rhs, local) {
+                        if let Some(n) = const_int(cx, lhs) {
+                            range.set_min(n, inclusive);
+                        }
+                    }
+                },
+                Some((Rel::Eq, lhs, rhs)) => add_equality(cx, local, lhs, rhs, range),
+                Some((Rel::Ne, lhs, rhs)) => {
+                    // `x != 0` is `x > 0` for unsigned values, and rules out zero for signed ones
+                    let is_zero = |e| const_int(cx, e) == Some(0);
+                    if (path_to_local_id(lhs, local) && is_zero(rhs)) || (path_to_local_id(rhs, local) && is_zero(lhs))
+                    {
+                        range.set_min(1, true);
+                    }
+                },
+                _ => {},
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Narrows `range` to the values `local` can have if `lhs == rhs`.
+fn add_equality<'tcx>(
+    cx: &LateContext<'tcx>,
+    local: HirId,
+    lhs: &'tcx Expr<'tcx>,
+    rhs: &'tcx Expr<'tcx>,
+    range: &mut ValueRange<'tcx>,
+) {
+    let other = if path_to_local_id(lhs, local) {
+        rhs
+    } else if path_to_local_id(rhs, local) {
+        lhs
+    } else {
+        return;
+    };
+    add_upper_bound(cx, other, true, range);
+    if let Some(n) = const_int(cx, other) {
+        range.set_min(n, true);
+    }
+}
+
+/// Narrows `range` to the values `< bound`, or `<= bound` if `inclusive`.
+fn add_upper_bound<'tcx>(
+    cx: &LateContext<'tcx>,
+    bound: &'tcx Expr<'tcx>,
+    inclusive: bool,
+    range: &mut ValueRange<'tcx>,
+) {
+    if let Some(n) = const_int(cx, bound) {
+        range.set_max(n, inclusive);
+    } else if let Some((receiver, n)) = len_minus_const(cx, bound) {
+        // a subtraction that would underflow panics or wraps around, so it isn't considered
+        range.len_bounds.push(LenBound {
+            receiver,
+            margin: n.saturating_add(u128::from(!inclusive)),
+        });
+    }
+}
+
+/// Returns the values the loop variable `local` of a `for` loop over `arg` can have in the loop
+/// `body`.
+fn for_loop_range<'tcx>(
+    cx: &LateContext<'tcx>,
+    local: HirId,
+    arg: &'tcx Expr<'tcx>,
+    body: &'tcx Expr<'tcx>,
+) -> ValueRange<'tcx> {
+    let (start, end, limits) = match higher::range(arg) {
+        Some(higher::Range {
+            start: Some(start),
+            end: Some(end),
+            limits,
+        }) => (start, end, limits),
+        _ => return ValueRange::unknown(),
+    };
+
+    let mut range = ValueRange::unknown();
+    if let Some(n) = const_int(cx, start) {
+        range.set_min(n, true);
+    }
+    let inclusive = limits == RangeLimits::Closed;
+    add_upper_bound(cx, end, inclusive, &mut range);
+    unless_mutated(range, local, mutated_variables(body, cx))
+}
+
+/// Returns `(x, 0)` if `expr` is `x.len()`, and `(x, n)` if it is `x.len() - n` for a constant `n`.
+fn len_minus_const<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, u128)> {
+    match expr.kind {
+        ExprKind::MethodCall(path, _, [receiver], _) if path.ident.name.as_str() == "len" => Some((receiver, 0)),
+        ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::Sub => {
+            let (receiver, m) = len_minus_const(cx, lhs)?;
+            Some((receiver, m.checked_add(const_int(cx, rhs)?)?))
+        },
+        _ => None,
+    }
+}
+
+/// Returns the value of `expr` if it is an integer constant that isn't negative.
+fn const_int(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<u128> {
+    match constant_with_locals(cx, cx.typeck_results(), expr) {
+        Some((Constant::Int(n), _)) => match *cx.typeck_results().expr_ty(expr).kind() {
+            ty::Int(ity) if sext(cx.tcx, n, ity) < 0 => None,
+            _ => Some(n),
+        },
+        _ => None,
+    }
+}
+
+/// Whether `expr` has a signed integer type.
+fn is_signed(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    matches!(cx.typeck_results().expr_ty(expr).kind(), ty::Int(_))
+}
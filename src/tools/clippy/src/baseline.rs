@@ -0,0 +1,266 @@
+//! Support for `cargo clippy --baseline=<file>`: the first run records the lint diagnostics in the
+//! baseline file, later runs only report the diagnostics that aren't recorded in it.
+//!
+//! Diagnostics are identified by their lint, file, message and the source lines of their primary
+//! span, but not by their line number, so that the baseline survives unrelated changes to a file.
+
+use crate::fingerprint;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashSet};
+use std::convert::TryFrom;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// The version of the baseline file format.
+const VERSION: u64 = 1;
+
+/// The identity of a lint diagnostic in the baseline.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Fingerprint {
+    file: String,
+    lint: String,
+    message: String,
+    snippet: String,
+}
+
+impl Fingerprint {
+    /// Returns the fingerprint of `diag`, or `None` if it isn't a lint diagnostic with a location.
+    fn of(diag: &Value) -> Option<Self> {
+        let lint = diag["code"]["code"].as_str()?;
+        // Errors like `E0308` can't be suppressed.
+        if is_error_code(lint) {
+            return None;
+        }
+        let span = fingerprint::primary_span(diag)?;
+        Some(Self {
+            file: span["file_name"].as_str()?.to_string(),
+            lint: lint.to_string(),
+            message: diag["message"].as_str()?.to_string(),
+            snippet: fingerprint::snippet(span)?,
+        })
+    }
+}
+
+pub fn is_error_code(code: &str) -> bool {
+    code.len() == 5 && code.starts_with('E') && code[1..].bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Whether `diag` is a summary like "aborting due to previous error", which is wrong once
+/// diagnostics have been suppressed.
+pub fn is_summary(diag: &Value) -> bool {
+    let message = diag["message"].as_str().unwrap_or_default();
+    diag["spans"].as_array().map_or(true, Vec::is_empty)
+        && (message.starts_with("aborting due to") || message.ends_with(" emitted"))
+}
+
+/// The diagnostics recorded in a baseline file, with the number of their occurrences.
+#[derive(Debug, Default, PartialEq)]
+struct Baseline {
+    diagnostics: BTreeMap<Fingerprint, usize>,
+}
+
+impl Baseline {
+    fn parse(content: &str) -> Result<Self, String> {
+        let value: Value = serde_json::from_str(content).map_err(|e| e.to_string())?;
+        if value["version"] != VERSION {
+            return Err(format!(
+                "unsupported version {}, expected {}",
+                value["version"], VERSION
+            ));
+        }
+
+        let mut baseline = Self::default();
+        for entry in value["diagnostics"].as_array().ok_or("missing `diagnostics`")? {
+            let field = |name: &str| {
+                entry[name]
+                    .as_str()
+                    .map(ToString::to_string)
+                    .ok_or_else(|| format!("missing `{}` in diagnostic {}", name, entry))
+            };
+            let fingerprint = Fingerprint {
+                file: field("file")?,
+                lint: field("lint")?,
+                message: field("message")?,
+                snippet: field("snippet")?,
+            };
+            let count = entry["count"]
+                .as_u64()
+                .and_then(|count| usize::try_from(count).ok())
+                .unwrap_or(1);
+            *baseline.diagnostics.entry(fingerprint).or_default() += count;
+        }
+        Ok(baseline)
+    }
+
+    fn to_json(&self) -> Value {
+        let diagnostics: Vec<Value> = self
+            .diagnostics
+            .iter()
+            .map(|(fingerprint, count)| {
+                json!({
+                    "file": fingerprint.file,
+                    "lint": fingerprint.lint,
+                    "message": fingerprint.message,
+                    "snippet": fingerprint.snippet,
+                    "count": count,
+                })
+            })
+            .collect();
+        json!({ "version": VERSION, "diagnostics": diagnostics })
+    }
+
+    /// Removes one occurrence of `fingerprint`, returning whether it was recorded.
+    fn take(&mut self, fingerprint: &Fingerprint) -> bool {
+        match self.diagnostics.get_mut(fingerprint) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Runs `cmd` (a `cargo check` emitting JSON messages). If the baseline file at `path` exists,
+/// only the diagnostics that aren't recorded in it are printed. Otherwise the lint diagnostics are
+/// recorded in a new baseline file.
+pub fn run(cmd: &mut Command, path: &Path) -> Result<(), i32> {
+    let mut baseline = if path.exists() {
+        let baseline = fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| Baseline::parse(&content));
+        match baseline {
+            Ok(baseline) => Some(baseline),
+            Err(e) => {
+                eprintln!("error: failed to read the baseline `{}`: {}", path.display(), e);
+                return Err(1);
+            },
+        }
+    } else {
+        None
+    };
+
+    let mut recorded = Baseline::default();
+    let mut seen = HashSet::new();
+    let (mut suppressed, mut suppressed_errors, mut reported_errors) = (0, 0, 0);
+    let exit_status = crate::run_with_diagnostics(cmd, |diag| {
+        let rendered = diag["rendered"].as_str().unwrap_or_default();
+        // The same diagnostic is emitted once for every target that includes the file.
+        if is_summary(diag) || !seen.insert(rendered.to_string()) {
+            return;
+        }
+        let is_error = diag["level"].as_str().map_or(false, |level| level.starts_with("error"));
+        let fingerprint = Fingerprint::of(diag);
+        let suppress = match (&mut baseline, fingerprint) {
+            (Some(baseline), Some(fingerprint)) => baseline.take(&fingerprint),
+            (None, Some(fingerprint)) => {
+                *recorded.diagnostics.entry(fingerprint).or_default() += 1;
+                true
+            },
+            (_, None) => false,
+        };
+        if suppress {
+            suppressed += 1;
+            suppressed_errors += usize::from(is_error);
+        } else {
+            reported_errors += usize::from(is_error);
+            eprint!("{}", rendered);
+        }
+    });
+
+    if let Some(baseline) = baseline {
+        if suppressed > 0 {
+            eprintln!(
+                "note: {} diagnostic{} suppressed by the baseline `{}`",
+                suppressed,
+                if suppressed == 1 { "" } else { "s" },
+                path.display()
+            );
+        }
+        // Lints aren't checked if the compilation failed early.
+        let fixed: usize = baseline.diagnostics.values().sum();
+        if fixed > 0 && exit_status.success() {
+            eprintln!(
+                "note: {} diagnostic{} in the baseline no longer occur{}, remove `{}` to record a new baseline",
+                fixed,
+                if fixed == 1 { "" } else { "s" },
+                if fixed == 1 { "s" } else { "" },
+                path.display()
+            );
+        }
+    } else {
+        let content = serde_json::to_string_pretty(&recorded.to_json()).expect("failed to serialize the baseline");
+        fs::write(path, content + "\n").unwrap_or_else(|e| panic!("failed to write `{}`: {}", path.display(), e));
+        eprintln!(
+            "{:>12} {} diagnostic{} in {}",
+            "Recorded",
+            suppressed,
+            if suppressed == 1 { "" } else { "s" },
+            path.display()
+        );
+    }
+
+    // Lints denied with `-D` make the compilation fail even if all of them were suppressed.
+    if exit_status.success() || (reported_errors == 0 && suppressed_errors > 0) {
+        Ok(())
+    } else {
+        Err(exit_status.code().unwrap_or(-1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_error_code, is_summary, Baseline, Fingerprint};
+    use serde_json::json;
+
+    fn diagnostic(code: &str, line: u64, text: &str) -> serde_json::Value {
+        json!({
+            "message": "indexing may panic",
+            "code": { "code": code, "explanation": null },
+            "level": "warning",
+            "spans": [{
+                "file_name": "src/lib.rs", "is_primary": true, "line_start": line, "line_end": line,
+                "text": [{ "text": text, "highlight_start": 5, "highlight_end": 9 }],
+            }],
+            "children": [],
+        })
+    }
+
+    #[test]
+    fn fingerprint_ignores_lines() {
+        let fingerprint = Fingerprint::of(&diagnostic("clippy::indexing_slicing", 3, "    x[0]")).unwrap();
+        assert_eq!(
+            fingerprint,
+            Fingerprint {
+                file: "src/lib.rs".to_string(),
+                lint: "clippy::indexing_slicing".to_string(),
+                message: "indexing may panic".to_string(),
+                snippet: "x[0]".to_string(),
+            }
+        );
+        assert_eq!(
+            Fingerprint::of(&diagnostic("clippy::indexing_slicing", 10, "x[0]")),
+            Some(fingerprint)
+        );
+        assert_eq!(Fingerprint::of(&diagnostic("E0308", 3, "x[0]")), None);
+        assert!(is_error_code("E0601"));
+        assert!(!is_error_code("clippy::indexing_slicing"));
+        assert!(is_summary(&json!({ "message": "2 warnings emitted", "spans": [] })));
+    }
+
+    #[test]
+    fn round_trip() {
+        let fingerprint = Fingerprint::of(&diagnostic("clippy::indexing_slicing", 3, "x[0]")).unwrap();
+        let mut baseline = Baseline::default();
+        baseline.diagnostics.insert(fingerprint.clone(), 2);
+        let parsed = Baseline::parse(&baseline.to_json().to_string()).unwrap();
+        assert_eq!(parsed, baseline);
+
+        let mut parsed = parsed;
+        assert!(parsed.take(&fingerprint));
+        assert!(parsed.take(&fingerprint));
+        assert!(!parsed.take(&fingerprint));
+        assert!(Baseline::parse(r#"{ "version": 2, "diagnostics": [] }"#).is_err());
+    }
+}
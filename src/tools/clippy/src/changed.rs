@@ -0,0 +1,264 @@
+//! Support for `cargo clippy --changed[=<rev>]`: only reports the diagnostics whose primary span
+//! overlaps with a line that changed since the git revision `rev` (`HEAD` by default), including
+//! the uncommitted changes. Files that aren't tracked by git count as changed entirely. Errors
+//! that aren't lints are always reported.
+
+use crate::baseline::{is_error_code, is_summary};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+
+/// The lines that changed in every file, as inclusive ranges of 1-based line numbers.
+#[derive(Debug, Default, PartialEq)]
+pub struct Lines {
+    files: BTreeMap<PathBuf, Vec<(u64, u64)>>,
+}
+
+impl Lines {
+    /// Collects the added and modified lines of the files in a unified diff with zero lines of
+    /// context, as printed by `git diff --unified=0`. The paths are relative to the repository.
+    fn parse_diff(diff: &str) -> Self {
+        let mut changed = Self::default();
+        let mut file = None;
+        for line in diff.lines() {
+            if let Some(path) = line.strip_prefix("+++ ") {
+                // `/dev/null` for deleted files
+                file = path.strip_prefix("b/").map(PathBuf::from);
+            } else if let (Some(file), Some(hunk)) = (&file, line.strip_prefix("@@ ")) {
+                // `@@ -<start>,<count> +<start>,<count> @@`, where the count is omitted if it's 1
+                let new = hunk.split(' ').find_map(|range| range.strip_prefix('+'));
+                let mut parts = new.unwrap_or_default().splitn(2, ',');
+                let start: u64 = match parts.next().and_then(|start| start.parse().ok()) {
+                    Some(start) => start,
+                    None => continue,
+                };
+                let count: u64 = parts.next().map_or(Some(1), |count| count.parse().ok()).unwrap_or(0);
+                if count > 0 {
+                    changed.add(file.clone(), (start, start + count - 1));
+                }
+            }
+        }
+        changed
+    }
+
+    pub fn add(&mut self, file: PathBuf, lines: (u64, u64)) {
+        self.files.entry(file).or_default().push(lines);
+    }
+
+    /// Whether one of the lines `start..=end` of `file` changed.
+    fn contains(&self, file: &Path, start: u64, end: u64) -> bool {
+        self.files.get(file).map_or(false, |lines| {
+            lines
+                .iter()
+                .any(|&(changed_start, changed_end)| changed_start <= end && start <= changed_end)
+        })
+    }
+
+    /// Makes the paths absolute, resolving them relative to `root`. Paths that don't exist
+    /// anymore are dropped.
+    fn canonicalize(self, root: &Path) -> Self {
+        let files = self
+            .files
+            .into_iter()
+            .filter_map(|(file, lines)| Some((fs::canonicalize(root.join(file)).ok()?, lines)))
+            .collect();
+        Self { files }
+    }
+}
+
+/// Runs `program` with `args` and returns its standard output, or an error message.
+fn output(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| format!("could not run `{}`: {}", program, e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "`{} {}` failed: {}",
+            program,
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    String::from_utf8(output.stdout).map_err(|e| e.to_string())
+}
+
+/// Asks git for the lines that changed since `rev`.
+fn changed_lines(rev: &str) -> Result<Lines, String> {
+    let root = output("git", &["rev-parse", "--show-toplevel"])?;
+    let root = Path::new(root.trim());
+    let diff = output(
+        "git",
+        &[
+            "-C",
+            &root.to_string_lossy(),
+            "diff",
+            "--unified=0",
+            "--no-color",
+            "--no-ext-diff",
+            rev,
+            "--",
+        ],
+    )?;
+    let mut changed = Lines::parse_diff(&diff);
+    let untracked = output(
+        "git",
+        &[
+            "-C",
+            &root.to_string_lossy(),
+            "ls-files",
+            "--others",
+            "--exclude-standard",
+        ],
+    )?;
+    for file in untracked.lines() {
+        changed.add(PathBuf::from(file), (1, u64::MAX));
+    }
+    Ok(changed.canonicalize(root))
+}
+
+/// Returns the root directory of the workspace, which the paths in the diagnostics are relative
+/// to.
+pub fn workspace_root() -> Result<PathBuf, String> {
+    let manifest = output("cargo", &["locate-project", "--workspace", "--message-format", "plain"])?;
+    Path::new(manifest.trim())
+        .parent()
+        .map(Path::to_path_buf)
+        .ok_or_else(|| format!("invalid manifest path `{}`", manifest.trim()))
+}
+
+/// The number of diagnostics of a run of `cargo check` that were reported or suppressed.
+pub struct Report {
+    pub exit_status: ExitStatus,
+    pub suppressed: usize,
+    suppressed_errors: usize,
+    reported_errors: usize,
+}
+
+impl Report {
+    pub fn result(&self) -> Result<(), i32> {
+        // Lints denied with `-D` make the compilation fail even if all of them were suppressed.
+        if self.exit_status.success() || (self.reported_errors == 0 && self.suppressed_errors > 0) {
+            Ok(())
+        } else {
+            Err(self.exit_status.code().unwrap_or(-1))
+        }
+    }
+}
+
+/// Runs `cmd` (a `cargo check` emitting JSON messages) and prints the lint diagnostics whose
+/// primary span is on one of the `changed` lines, and all other diagnostics. The paths in
+/// `changed` are canonical, the paths of the diagnostics are relative to `root`.
+pub fn report(cmd: &mut Command, root: &Path, changed: &Lines) -> Report {
+    let mut canonical_paths: HashMap<String, Option<PathBuf>> = HashMap::new();
+    let mut seen = HashSet::new();
+    let (mut suppressed, mut suppressed_errors, mut reported_errors) = (0, 0, 0);
+    let exit_status = crate::run_with_diagnostics(cmd, |diag| {
+        let rendered = diag["rendered"].as_str().unwrap_or_default();
+        // The same diagnostic is emitted once for every target that includes the file.
+        if is_summary(diag) || !seen.insert(rendered.to_string()) {
+            return;
+        }
+        let is_error = diag["level"].as_str().map_or(false, |level| level.starts_with("error"));
+        let primary_span = diag["spans"]
+            .as_array()
+            .and_then(|spans| spans.iter().find(|span| span["is_primary"] == true));
+        let is_lint = diag["code"]["code"].as_str().map_or(false, |code| !is_error_code(code));
+        let report = !is_lint
+            || primary_span.map_or(true, |span| {
+                let file = span["file_name"].as_str().unwrap_or_default();
+                let path = canonical_paths
+                    .entry(file.to_string())
+                    .or_insert_with(|| fs::canonicalize(root.join(file)).ok());
+                let start = span["line_start"].as_u64().unwrap_or_default();
+                let end = span["line_end"].as_u64().unwrap_or(start);
+                path.as_ref().map_or(false, |path| changed.contains(path, start, end))
+            });
+        if report {
+            reported_errors += usize::from(is_error);
+            eprint!("{}", rendered);
+        } else {
+            suppressed += 1;
+            suppressed_errors += usize::from(is_error);
+        }
+    });
+    Report {
+        exit_status,
+        suppressed,
+        suppressed_errors,
+        reported_errors,
+    }
+}
+
+/// Runs `cmd` (a `cargo check` emitting JSON messages) and prints the lint diagnostics whose
+/// primary span is on a line that changed since `rev`, and all other diagnostics.
+pub fn run(cmd: &mut Command, rev: &str) -> Result<(), i32> {
+    let (changed, root) = match changed_lines(rev).and_then(|changed| Ok((changed, workspace_root()?))) {
+        Ok(changed) => changed,
+        Err(e) => {
+            eprintln!("error: failed to find the changed lines: {}", e);
+            return Err(1);
+        },
+    };
+
+    let report = report(cmd, &root, &changed);
+    if report.suppressed > 0 {
+        eprintln!(
+            "note: {} diagnostic{} outside of the lines changed since `{}` suppressed",
+            report.suppressed,
+            if report.suppressed == 1 { "" } else { "s" },
+            rev
+        );
+    }
+    report.result()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Lines;
+    use std::path::{Path, PathBuf};
+
+    const DIFF: &str = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 0123456..789abcd 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -3 +3 @@ fn main() {
+-    let x = 1;
++    let x = 2;
+@@ -10,0 +11,3 @@ fn other() {
++    a();
++    b();
++    c();
+@@ -20,2 +23,0 @@ fn removed() {
+-    d();
+-    e();
+diff --git a/src/old.rs b/src/old.rs
+deleted file mode 100644
+--- a/src/old.rs
++++ /dev/null
+@@ -1,2 +0,0 @@
+-fn f() {}
+-fn g() {}
+";
+
+    #[test]
+    fn parse_diff() {
+        let changed = Lines::parse_diff(DIFF);
+        assert_eq!(changed.files.len(), 1);
+        assert_eq!(changed.files[&PathBuf::from("src/lib.rs")], vec![(3, 3), (11, 13)]);
+    }
+
+    #[test]
+    fn contains() {
+        let changed = Lines::parse_diff(DIFF);
+        let file = Path::new("src/lib.rs");
+        assert!(changed.contains(file, 3, 3));
+        assert!(changed.contains(file, 12, 12));
+        assert!(changed.contains(file, 8, 11));
+        assert!(!changed.contains(file, 4, 10));
+        assert!(!changed.contains(file, 23, 23));
+        assert!(!changed.contains(Path::new("src/old.rs"), 1, 1));
+    }
+}
@@ -11,16 +11,26 @@
 extern crate rustc_driver;
 extern crate rustc_errors;
 extern crate rustc_interface;
+extern crate rustc_lint;
 extern crate rustc_session;
 extern crate rustc_span;
 
+mod explain;
+
+use clippy_lints::lint_cache::LintCache;
+use clippy_lints::profiles::Profile;
+use clippy_lints::timings::PassTimes;
 use rustc_interface::interface;
+use rustc_lint::{Level, LintStore};
+use rustc_session::early_error;
 use rustc_session::parse::ParseSess;
 use rustc_span::symbol::Symbol;
 use rustc_tools_util::VersionInfo;
 
+use serde_json::json;
 use std::borrow::Cow;
 use std::env;
+use std::fs;
 use std::lazy::SyncLazy;
 use std::ops::Deref;
 use std::panic;
@@ -63,6 +73,67 @@ fn test_arg_value() {
     assert_eq!(arg_value(args, "--foo", |_| true), None);
 }
 
+/// Removes all occurrences of the option `name`, as `name value` or `name=value`, from `args` and
+/// returns their values.
+fn take_arg(args: &mut Vec<String>, name: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut i = 0;
+    while i < args.len() {
+        if args[i] == name && i + 1 < args.len() {
+            args.remove(i);
+            values.push(args.remove(i));
+        } else if let Some(value) = args[i].strip_prefix(name).and_then(|rest| rest.strip_prefix('=')) {
+            values.push(value.to_string());
+            args.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    values
+}
+
+/// Removes all occurrences of the flag `name` from `args` and returns whether there were any.
+fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    let len = args.len();
+    args.retain(|arg| arg != name);
+    args.len() != len
+}
+
+/// Removes `--filter <lints>` and `--filter=<lints>` from `args` and returns the comma separated
+/// lints, or `None` if there is no `--filter`.
+fn take_filter(args: &mut Vec<String>) -> Option<Vec<String>> {
+    let values = take_arg(args, "--filter");
+    if values.is_empty() {
+        return None;
+    }
+    Some(
+        values
+            .iter()
+            .flat_map(|value| value.split(','))
+            .filter(|lint| !lint.is_empty())
+            .map(ToString::to_string)
+            .collect(),
+    )
+}
+
+#[test]
+fn test_take_filter() {
+    let mut args: Vec<String> = ["--filter", "a,b", "x.rs", "--filter=c", "--edition"]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(take_filter(&mut args), Some(vec!["a".into(), "b".into(), "c".into()]));
+    assert_eq!(args, vec!["x.rs", "--edition"]);
+    assert_eq!(take_filter(&mut args), None);
+
+    let mut args: Vec<String> = ["--profile=strict", "x.rs", "--profile", "ci"]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(take_arg(&mut args, "--profile"), vec!["strict", "ci"]);
+    assert_eq!(args, vec!["x.rs"]);
+}
+
 fn track_clippy_args(parse_sess: &mut ParseSess, args_env_var: &Option<String>) {
     parse_sess.env_depinfo.get_mut().insert((
         Symbol::intern("CLIPPY_ARGS"),
@@ -90,12 +161,48 @@ fn config(&mut self, config: &mut interface::Config) {
 
 struct ClippyCallbacks {
     clippy_args_var: Option<String>,
+    timings: Option<PassTimes>,
+    /// The cache of the items without diagnostics, enabled by `--clippy-cache`.
+    cache: Option<LintCache>,
+    /// The lints passed to `--filter`, the passes of the other lints don't run.
+    filter: Option<Vec<String>>,
+    /// The name of the lint profile passed to `--profile`.
+    profile: Option<String>,
+    /// Whether the suggestions are applied by `cargo clippy --fix`.
+    fix: bool,
+    /// The lint passed to `--suggest-only`, whose suggestions are printed as a diff by `cargo
+    /// clippy`.
+    suggest_only: Option<String>,
 }
 
 impl rustc_driver::Callbacks for ClippyCallbacks {
     fn config(&mut self, config: &mut interface::Config) {
         let previous = config.register_lints.take();
         let clippy_args_var = self.clippy_args_var.take();
+        let timings = self.timings.clone();
+        let cache = self.cache.take();
+        let suggest_only = self.suggest_only.take().map(|name| {
+            explain::lint_name(&name)
+                .unwrap_or_else(|msg| early_error(config.opts.error_format, &format!("`--suggest-only`: {}", msg)))
+        });
+        // only the passes of the lint run, and it is emitted even if it is allowed by default
+        let filter = match suggest_only {
+            Some(name) => Some(vec![name.to_string()]),
+            None => self.filter.take(),
+        };
+        let fix = self.fix;
+        let profile = self.profile.take().map(|name| {
+            Profile::find(&name, config.input_path.as_deref())
+                .unwrap_or_else(|msg| early_error(config.opts.error_format, &msg))
+        });
+        if let Some(profile) = &profile {
+            // the lint flags on the command line take precedence
+            config.opts.lint_opts.splice(0..0, profile.lint_opts());
+        }
+        if let Some(name) = &suggest_only {
+            config.opts.lint_opts.push((format!("clippy::{}", name), Level::Warn));
+        }
+        let suggest_only = suggest_only.is_some();
         config.parse_sess_created = Some(Box::new(move |parse_sess| {
             track_clippy_args(parse_sess, &clippy_args_var);
         }));
@@ -106,11 +213,28 @@ fn config(&mut self, config: &mut interface::Config) {
                 (previous)(sess, lint_store);
             }
 
-            let conf = clippy_lints::read_conf(sess);
-            clippy_lints::register_plugins(lint_store, sess, &conf);
-            clippy_lints::register_pre_expansion_lints(lint_store);
-            clippy_lints::register_renamed(lint_store);
+            let register = |lint_store: &mut LintStore| {
+                let conf = clippy_lints::read_conf(sess, profile.as_ref());
+                if fix {
+                    clippy_lints::set_fix_applicability(&conf);
+                } else if suggest_only {
+                    clippy_lints::set_suggest_only_applicability();
+                }
+                clippy_lints::register_plugins(lint_store, sess, &conf, filter.as_deref());
+                clippy_lints::register_pre_expansion_lints(lint_store);
+                clippy_lints::register_renamed(lint_store);
+                clippy_lints::plugins::load(lint_store, sess, &conf);
+            };
+            let register_cached = |lint_store: &mut LintStore| match &cache {
+                Some(cache) => clippy_lints::lint_cache::register_cached(lint_store, cache, register),
+                None => register(lint_store),
+            };
+            match &timings {
+                Some(timings) => clippy_lints::timings::register_timed(lint_store, timings, register_cached),
+                None => register_cached(lint_store),
+            }
         }));
+        config.override_queries = Some(clippy_lints::override_queries);
 
         // FIXME: #4825; This is required, because Clippy lints that are based on MIR have to be
         // run on the unoptimized MIR. On the other hand this results in some false negatives. If
@@ -120,6 +244,25 @@ fn config(&mut self, config: &mut interface::Config) {
     }
 }
 
+/// Writes the time spent in every lint pass to a JSON file in `dir`, for
+/// `cargo clippy -Z clippy-timings`.
+fn write_timings(dir: &Path, crate_name: &str, timings: &PassTimes) {
+    let passes: Vec<_> = timings
+        .sorted()
+        .into_iter()
+        .map(|(name, time)| json!({ "name": name, "seconds": time.as_secs_f64() }))
+        .collect();
+    // Cargo runs the driver without any lint passes to query information about the target.
+    if passes.is_empty() {
+        return;
+    }
+    let path = dir.join(format!("{}-{}.json", crate_name, std::process::id()));
+    let content = json!({ "crate": crate_name, "passes": passes }).to_string();
+    if let Err(e) = fs::write(&path, content) {
+        eprintln!("warning: failed to write `{}`: {}", path.display(), e);
+    }
+}
+
 fn display_help() {
     println!(
         "\
@@ -132,6 +275,15 @@ fn display_help() {
     -h, --help               Print this message
         --rustc              Pass all args to rustc
     -V, --version            Print version info and exit
+        --explain clippy::<lint>
+                             Print the documentation of a lint, e.g. `clippy::needless_return`, and exit
+        --filter <lints>     Only run the lint passes of the given comma separated lints and lint
+                             groups, e.g. `--filter clippy::correctness,needless_return`
+        --profile <name>     Set the lint levels and configuration values of a lint profile: `strict`,
+                             `embedded`, `ci` or one defined in the configuration file
+        --suggest-only <lint>
+                             Only run the lint passes of the given lint and emit it even if it is
+                             allowed, for `cargo clippy` to print its suggestions as a diff
 
 Other options are the same as `cargo check`.
 
@@ -287,6 +439,20 @@ pub fn main() {
             exit(0);
         }
 
+        // `--explain E0308` is left to rustc
+        if let Some(pos) = orig_args.iter().position(|a| a == "--explain") {
+            if let Some(lint) = orig_args
+                .get(pos + 1)
+                .filter(|lint| lint.to_ascii_lowercase().starts_with("clippy::"))
+            {
+                if let Err(msg) = explain::explain(lint) {
+                    eprintln!("error: {}", msg);
+                    exit(1);
+                }
+                exit(0);
+            }
+        }
+
         // Setting RUSTC_WRAPPER causes Cargo to pass 'rustc' as the first argument.
         // We're invoking the compiler programmatically, so we ignore this/
         let wrapper_mode = orig_args.get(1).map(Path::new).and_then(Path::file_stem) == Some("rustc".as_ref());
@@ -310,6 +476,8 @@ pub fn main() {
         };
 
         let mut no_deps = false;
+        let mut timings_dir = None;
+        let mut use_cache = false;
         let clippy_args_var = env::var("CLIPPY_ARGS").ok();
         let clippy_args = clippy_args_var
             .as_deref()
@@ -321,6 +489,14 @@ pub fn main() {
                     no_deps = true;
                     None
                 },
+                _ if s.starts_with("--clippy-timings=") => {
+                    timings_dir = Some(PathBuf::from(&s["--clippy-timings=".len()..]));
+                    None
+                },
+                "--clippy-cache" => {
+                    use_cache = true;
+                    None
+                },
                 _ => Some(s.to_string()),
             })
             .chain(vec!["--cfg".into(), r#"feature="cargo-clippy""#.into()])
@@ -339,9 +515,35 @@ pub fn main() {
         if clippy_enabled {
             args.extend(clippy_args);
         }
+        // rustc doesn't know `--filter`, `--profile`, `--fix` and `--suggest-only`, even if Clippy is
+        // disabled for this crate
+        let filter = take_filter(&mut args);
+        let profile = take_arg(&mut args, "--profile").pop();
+        let fix = take_flag(&mut args, "--fix");
+        let suggest_only = take_arg(&mut args, "--suggest-only")
+            .pop()
+            .map(|name| name.trim_start_matches("clippy::").to_string());
 
         if clippy_enabled {
-            rustc_driver::RunCompiler::new(&args, &mut ClippyCallbacks { clippy_args_var }).run()
+            let timings = timings_dir.as_ref().map(|_| PassTimes::default());
+            // the cache is next to the output directory of Cargo, like `target/debug/clippy-cache`
+            let cache = arg_value(&orig_args, "--out-dir", |_| use_cache)
+                .map(|dir| LintCache::new(Path::new(dir).join("..").join("clippy-cache")));
+            let mut callbacks = ClippyCallbacks {
+                clippy_args_var,
+                timings: timings.clone(),
+                cache,
+                filter,
+                profile,
+                fix,
+                suggest_only,
+            };
+            let result = rustc_driver::RunCompiler::new(&args, &mut callbacks).run();
+            if let (Some(dir), Some(timings)) = (&timings_dir, &timings) {
+                let crate_name = arg_value(&orig_args, "--crate-name", |_| true).unwrap_or("unknown");
+                write_timings(dir, crate_name, timings);
+            }
+            result
         } else {
             rustc_driver::RunCompiler::new(&args, &mut RustcCallbacks { clippy_args_var }).run()
         }
@@ -0,0 +1,122 @@
+//! Support for `clippy-driver --explain clippy::<lint>`, which `cargo clippy --explain <lint>`
+//! runs: prints the documentation of a lint from `clippy_lints::ALL_LINTS`.
+
+use clippy_lints::{LintInfo, ALL_LINTS};
+use rustc_lint::Level;
+
+/// Prints the documentation of the lint `name`, which may be written like `clippy::Needless-Return`
+/// as well. Returns an error message if there is no such lint.
+pub fn explain(name: &str) -> Result<(), String> {
+    let lint = find(name)?;
+    println!(
+        "clippy::{} ({}, {} by default)\n",
+        lint.name,
+        lint.group,
+        default_level(lint)
+    );
+    print!("{}", render(lint.docs));
+    Ok(())
+}
+
+/// Returns the lowercase name of the lint `name` without the `clippy::` prefix, or an error
+/// message if there is no such lint.
+pub fn lint_name(name: &str) -> Result<&'static str, String> {
+    find(name).map(|lint| lint.name)
+}
+
+fn find(name: &str) -> Result<&'static LintInfo, String> {
+    let name = normalize_name(name);
+    if let Some(lint) = ALL_LINTS.iter().find(|lint| lint.name == name) {
+        return Ok(lint);
+    }
+
+    let similar: Vec<String> = ALL_LINTS
+        .iter()
+        .filter(|lint| !name.is_empty() && lint.name.contains(&name))
+        .map(|lint| format!("`{}`", lint.name))
+        .collect();
+    if similar.is_empty() {
+        Err(format!("unknown lint `{}`", name))
+    } else {
+        Err(format!("unknown lint `{}`, did you mean {}?", name, similar.join(", ")))
+    }
+}
+
+fn normalize_name(name: &str) -> String {
+    let name = name.trim().to_ascii_lowercase().replace('-', "_");
+    name.strip_prefix("clippy::").unwrap_or(&name).to_string()
+}
+
+fn default_level(lint: &LintInfo) -> &'static str {
+    match lint.default_level() {
+        Level::Allow => "allowed",
+        Level::Warn => "warned",
+        Level::Deny | Level::Forbid => "denied",
+    }
+}
+
+/// Removes the lines of the Rust code blocks in `docs` that rustdoc hides, i.e. the ones starting
+/// with `# `, and unescapes the `##` that rustdoc shows as `#`.
+fn render(docs: &str) -> String {
+    let mut rendered = String::with_capacity(docs.len());
+    // whether the current line is in a code block, and whether that is a Rust one
+    let mut code_block = None;
+    for line in docs.lines() {
+        let trimmed = line.trim_start();
+        let in_rust_block = code_block == Some(true);
+        if let Some(lang) = trimmed.strip_prefix("```") {
+            // code blocks are Rust unless another language is given
+            code_block = match code_block {
+                Some(_) => None,
+                None => Some(lang.split(',').all(|attr| {
+                    matches!(
+                        attr.trim(),
+                        "" | "rust" | "ignore" | "no_run" | "should_panic" | "compile_fail"
+                    )
+                })),
+            };
+        } else if in_rust_block && (trimmed == "#" || trimmed.starts_with("# ")) {
+            continue;
+        } else if in_rust_block && trimmed.starts_with("##") {
+            rendered.push_str(&line.replacen("##", "#", 1));
+            rendered.push('\n');
+            continue;
+        }
+        rendered.push_str(line);
+        rendered.push('\n');
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{default_level, find, normalize_name, render};
+
+    #[test]
+    fn lint_docs() {
+        let lint = find("indexing_slicing").unwrap();
+        assert_eq!(lint.group, "restriction");
+        assert_eq!(default_level(lint), "allowed");
+        assert!(lint.docs.starts_with("**What it does:**"));
+        assert_eq!(
+            default_level(find("clippy::absurd-extreme-comparisons").unwrap()),
+            "denied"
+        );
+        assert!(find("indexing").unwrap_err().contains("`indexing_slicing`"));
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_name("clippy::Needless-Return"), "needless_return");
+        assert_eq!(normalize_name("needless_return"), "needless_return");
+    }
+
+    #[test]
+    fn hide_lines() {
+        let docs = "**Example:**\n```rust\n# let v = vec![1];\nlet x = v[0];\n#![allow(unused)]\n```\n```toml\n# comment\n```\n";
+        assert_eq!(
+            render(docs),
+            "**Example:**\n```rust\nlet x = v[0];\n#![allow(unused)]\n```\n```toml\n# comment\n```\n"
+        );
+    }
+}
@@ -0,0 +1,133 @@
+//! Stable fingerprints of diagnostics, added as the `fingerprint` field of the compiler messages
+//! with `cargo clippy --message-format=json` and as the partial fingerprints of SARIF results.
+//!
+//! A fingerprint is made of the lint name, the path of the file and a hash of the trimmed source
+//! lines of the primary span, like `clippy::indexing_slicing:src/lib.rs:2c7714541ac4b0c3`. It
+//! doesn't depend on line numbers, so it survives unrelated changes to the file. Diagnostics of
+//! the same lint on identical lines of the same file have the same fingerprint.
+
+use serde_json::Value;
+use std::env;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Returns the primary span of `diag`.
+pub fn primary_span(diag: &Value) -> Option<&Value> {
+    diag["spans"].as_array()?.iter().find(|span| span["is_primary"] == true)
+}
+
+/// Returns the source lines of `span`, trimmed and joined with newlines.
+pub fn snippet(span: &Value) -> Option<String> {
+    let lines: Vec<&str> = span["text"]
+        .as_array()?
+        .iter()
+        .filter_map(|line| line["text"].as_str())
+        .map(str::trim)
+        .collect();
+    Some(lines.join("\n"))
+}
+
+/// Returns the fingerprint of `diag`, or `None` if it has no code or no location.
+pub fn of(diag: &Value) -> Option<String> {
+    let code = diag["code"]["code"].as_str()?;
+    let span = primary_span(diag)?;
+    let file = relative_path(span["file_name"].as_str()?);
+    Some(format!("{}:{}:{:016x}", code, file, fnv1a(snippet(span)?.as_bytes())))
+}
+
+/// Returns `file` relative to the current directory if it's inside it, with `/` as separator.
+fn relative_path(file: &str) -> String {
+    let path = Path::new(file);
+    let path = env::current_dir()
+        .ok()
+        .and_then(|dir| path.strip_prefix(dir).ok())
+        .unwrap_or(path);
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// The 64-bit FNV-1a hash of `bytes`, which unlike `DefaultHasher` is the same with every
+/// version of Rust.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Adds the fingerprint to the diagnostic of `msg` if it's a compiler message with one.
+fn add_fingerprint(msg: &mut Value) {
+    if msg["reason"] == "compiler-message" {
+        if let Some(fingerprint) = of(&msg["message"]) {
+            msg["message"]["fingerprint"] = fingerprint.into();
+        }
+    }
+}
+
+/// Runs `cmd` (a `cargo check` with a JSON `--message-format`) and prints its messages to stdout,
+/// with the fingerprints added to the diagnostics.
+pub fn run(cmd: &mut Command) -> Result<(), i32> {
+    let mut child = cmd.stdout(Stdio::piped()).spawn().expect("could not run cargo");
+    let stdout = child.stdout.take().expect("cargo stdout is piped");
+    for line in BufReader::new(stdout).lines() {
+        let line = line.expect("failed to read cargo output");
+        match serde_json::from_str::<Value>(&line) {
+            Ok(mut msg) => {
+                add_fingerprint(&mut msg);
+                println!("{}", msg);
+            },
+            Err(_) => println!("{}", line),
+        }
+    }
+    let exit_status = child.wait().expect("failed to wait for cargo?");
+
+    if exit_status.success() {
+        Ok(())
+    } else {
+        Err(exit_status.code().unwrap_or(-1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_fingerprint, of};
+    use serde_json::json;
+
+    fn diagnostic(code: &str, line: u64, text: &str) -> serde_json::Value {
+        json!({
+            "message": "indexing may panic",
+            "code": { "code": code, "explanation": null },
+            "level": "warning",
+            "spans": [{
+                "file_name": "src/lib.rs", "is_primary": true, "line_start": line, "line_end": line,
+                "text": [{ "text": text, "highlight_start": 5, "highlight_end": 9 }],
+            }],
+            "children": [],
+        })
+    }
+
+    #[test]
+    fn fingerprint_ignores_lines() {
+        let fingerprint = of(&diagnostic("clippy::indexing_slicing", 3, "    x[0]")).unwrap();
+        assert_eq!(fingerprint, "clippy::indexing_slicing:src/lib.rs:2c7714541ac4b0c3");
+        assert_eq!(
+            of(&diagnostic("clippy::indexing_slicing", 10, "x[0]")),
+            Some(fingerprint)
+        );
+        assert_ne!(
+            of(&diagnostic("clippy::indexing_slicing", 3, "x[1]")),
+            of(&diagnostic("clippy::indexing_slicing", 3, "x[0]"))
+        );
+        assert_eq!(of(&json!({ "message": "2 warnings emitted", "spans": [] })), None);
+    }
+
+    #[test]
+    fn add_to_compiler_messages() {
+        let mut msg = json!({ "reason": "compiler-message", "message": diagnostic("E0308", 3, "x[0]") });
+        add_fingerprint(&mut msg);
+        assert_eq!(msg["message"]["fingerprint"], "E0308:src/lib.rs:2c7714541ac4b0c3");
+
+        let mut msg = json!({ "reason": "build-finished", "success": true });
+        add_fingerprint(&mut msg);
+        assert_eq!(msg, json!({ "reason": "build-finished", "success": true }));
+    }
+}
@@ -0,0 +1,268 @@
+//! Support for `cargo clippy --fix`: the suggestions are applied by `cargo fix`, which checks the
+//! state of the working tree and reverts the changes if they don't compile. With `--dry-run`, the
+//! machine applicable suggestions are collected from the JSON messages emitted by `cargo check`
+//! and printed as a unified diff instead. The suggestions of a single lint are printed the same
+//! way by `cargo clippy -- --suggest-only=<lint>`.
+
+use crate::changed::workspace_root;
+use crate::suggestions::{choose_non_overlapping, Replacement, Suggestions};
+use std::fmt::Write as _;
+use std::fs;
+use std::iter;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The number of unchanged lines shown around a change in a `--dry-run` diff.
+const DIFF_CONTEXT: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Mode {
+    /// Apply the suggestions with `cargo fix`.
+    Apply,
+    /// Print a unified diff of the suggestions without changing any file.
+    DryRun,
+}
+
+/// A change of the lines `old` of a file to the lines `new`.
+struct Change {
+    old: Range<usize>,
+    new: Vec<String>,
+}
+
+/// Creates a unified diff of applying the sorted, non-overlapping `replacements` to `src`.
+fn unified_diff(path: &str, src: &str, replacements: &[&Replacement]) -> String {
+    let lines: Vec<&str> = src.lines().collect();
+    if lines.is_empty() {
+        return String::new();
+    }
+    let line_starts: Vec<usize> = iter::once(0)
+        .chain(src.match_indices('\n').map(|(i, _)| i + 1))
+        .collect();
+    let line_of = |pos: usize| (line_starts.partition_point(|&start| start <= pos) - 1).min(lines.len() - 1);
+
+    // Replacements on the same or adjacent lines are combined into one change.
+    let mut changes: Vec<Change> = Vec::new();
+    let mut text = String::new();
+    let mut pos = 0;
+    for (i, r) in replacements.iter().enumerate() {
+        let first = line_of(r.start);
+        if changes.last().map_or(true, |c| c.old.end < first) {
+            text.push_str(&src[line_starts[first]..r.start]);
+            changes.push(Change {
+                old: first..first,
+                new: Vec::new(),
+            });
+        } else {
+            text.push_str(&src[pos..r.start]);
+        }
+        text.push_str(&r.text);
+        pos = r.end;
+
+        let change = changes.last_mut().unwrap();
+        change.old.end = line_of(r.end) + 1;
+        if replacements
+            .get(i + 1)
+            .map_or(true, |next| line_of(next.start) > change.old.end)
+        {
+            let end = line_starts.get(change.old.end).copied().unwrap_or(src.len());
+            text.push_str(&src[pos..end]);
+            change.new = text.lines().map(ToString::to_string).collect();
+            text.clear();
+        }
+    }
+
+    let mut diff = format!("--- a/{}\n+++ b/{}\n", path, path);
+    // The number of lines added and removed by the previous hunks.
+    let (mut added, mut removed) = (0, 0);
+    let mut i = 0;
+    while i < changes.len() {
+        // Changes whose context would overlap are shown in the same hunk.
+        let mut j = i;
+        while j + 1 < changes.len() && changes[j + 1].old.start <= changes[j].old.end + 2 * DIFF_CONTEXT {
+            j += 1;
+        }
+        let start = changes[i].old.start.saturating_sub(DIFF_CONTEXT);
+        let end = (changes[j].old.end + DIFF_CONTEXT).min(lines.len());
+        let new_len = changes[i..=j]
+            .iter()
+            .fold(end - start, |len, c| len + c.new.len() - c.old.len());
+        let _ = writeln!(
+            diff,
+            "@@ -{},{} +{},{} @@",
+            start + 1,
+            end - start,
+            start + 1 + added - removed,
+            new_len
+        );
+
+        let mut line = start;
+        for change in &changes[i..=j] {
+            for l in &lines[line..change.old.start] {
+                let _ = writeln!(diff, " {}", l);
+            }
+            for l in &lines[change.old.clone()] {
+                let _ = writeln!(diff, "-{}", l);
+            }
+            for l in &change.new {
+                let _ = writeln!(diff, "+{}", l);
+            }
+            line = change.old.end;
+        }
+        for l in &lines[line..end] {
+            let _ = writeln!(diff, " {}", l);
+        }
+        added += new_len;
+        removed += end - start;
+        i = j + 1;
+    }
+    diff
+}
+
+/// Returns the workspace root the paths of the diagnostics are relative to, canonicalized.
+fn canonical_workspace_root() -> Result<PathBuf, i32> {
+    match workspace_root().and_then(|root| fs::canonicalize(root).map_err(|e| e.to_string())) {
+        Ok(root) => Ok(root),
+        Err(e) => {
+            eprintln!("error: failed to find the workspace root: {}", e);
+            Err(1)
+        },
+    }
+}
+
+/// Returns the canonical path of the file `path` of a diagnostic, which is relative to the
+/// canonical workspace `root`, or `None` if the file doesn't exist or is outside of the workspace,
+/// like a dependency from a registry.
+fn workspace_file(root: &Path, path: &Path) -> Option<PathBuf> {
+    let file = fs::canonicalize(root.join(path)).ok()?;
+    file.starts_with(root).then(|| file)
+}
+
+/// Runs `cmd` (a `cargo check` emitting JSON messages) and prints the suggestions of the emitted
+/// diagnostics as a unified diff, without changing any file. The rendered diagnostics are printed
+/// as well.
+pub fn dry_run(cmd: &mut Command) -> Result<(), i32> {
+    let root = canonical_workspace_root()?;
+    let mut rendered = Vec::new();
+    let mut suggestions = Suggestions::default();
+    let exit_status = crate::run_with_diagnostics(cmd, |diag| {
+        if let Some(r) = diag["rendered"].as_str() {
+            rendered.push(r.to_string());
+        }
+        suggestions.collect_message(diag);
+    });
+
+    print_diff(&root, &suggestions);
+    for r in &rendered {
+        eprint!("{}", r);
+    }
+    if exit_status.success() {
+        Ok(())
+    } else {
+        Err(exit_status.code().unwrap_or(-1))
+    }
+}
+
+/// Prints the non-overlapping `suggestions` of the files of the workspace `root` as a unified
+/// diff.
+fn print_diff(root: &Path, suggestions: &Suggestions) {
+    for (path, file_suggestions) in &suggestions.files {
+        if let Some(src) = workspace_file(root, path).and_then(|file| fs::read_to_string(file).ok()) {
+            let (replacements, count) = choose_non_overlapping(&src, file_suggestions);
+            if count > 0 {
+                print!("{}", unified_diff(&path.display().to_string(), &src, &replacements));
+            }
+        }
+    }
+}
+
+/// Runs `cmd` (a `cargo check` emitting JSON messages with `--suggest-only=<lint>`) and prints the
+/// suggestions of the diagnostics of `lint`, like `clippy::indexing_slicing`, as a unified diff.
+/// Only the rendered errors are printed, not the warnings.
+pub fn suggest_only(cmd: &mut Command, lint: &str) -> Result<(), i32> {
+    let root = canonical_workspace_root()?;
+    let mut suggestions = Suggestions::default();
+    let exit_status = crate::run_with_diagnostics(cmd, |diag| {
+        if diag["code"]["code"] == lint {
+            suggestions.collect_message(diag);
+        } else if diag["level"] == "error" {
+            if let Some(r) = diag["rendered"].as_str() {
+                eprint!("{}", r);
+            }
+        }
+    });
+
+    print_diff(&root, &suggestions);
+    if exit_status.success() {
+        Ok(())
+    } else {
+        Err(exit_status.code().unwrap_or(-1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{unified_diff, workspace_file};
+    use crate::suggestions::{choose_non_overlapping, Replacement};
+    use std::fs;
+    use std::process;
+
+    fn replacement(start: usize, end: usize, text: &str) -> Replacement {
+        Replacement {
+            start,
+            end,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn diff() {
+        let src = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\n";
+        let suggestions = vec![
+            vec![replacement(2, 3, "B\nB")],
+            vec![replacement(14, 15, "H")],
+            vec![replacement(20, 21, "K\nK")],
+        ];
+        let (replacements, _) = choose_non_overlapping(src, &suggestions);
+        assert_eq!(
+            unified_diff("src/lib.rs", src, &replacements),
+            "--- a/src/lib.rs\n+++ b/src/lib.rs\n\
+             @@ -1,12 +1,14 @@\n a\n-b\n+B\n+B\n c\n d\n e\n f\n g\n-h\n+H\n i\n j\n-k\n+K\n+K\n l\n"
+        );
+        let suggestions = vec![suggestions[0].clone(), suggestions[2].clone()];
+        let (replacements, _) = choose_non_overlapping(src, &suggestions);
+        assert_eq!(
+            unified_diff("src/lib.rs", src, &replacements),
+            "--- a/src/lib.rs\n+++ b/src/lib.rs\n\
+             @@ -1,5 +1,6 @@\n a\n-b\n+B\n+B\n c\n d\n e\n\
+             @@ -8,5 +9,6 @@\n h\n i\n j\n-k\n+K\n+K\n l\n"
+        );
+    }
+
+    #[test]
+    fn files_relative_to_workspace() {
+        let dir = std::env::temp_dir().join(format!("clippy-fix-{}", process::id()));
+        let root = dir.join("workspace");
+        fs::create_dir_all(root.join("member/src")).unwrap();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("member/src/lib.rs"), "").unwrap();
+        fs::write(root.join("src/main.rs"), "").unwrap();
+        fs::write(dir.join("outside.rs"), "").unwrap();
+        let root = fs::canonicalize(root).unwrap();
+
+        // the paths are resolved against the workspace root, not the current directory, which
+        // has a `src/main.rs` as well when the tests run
+        assert_eq!(
+            workspace_file(&root, "member/src/lib.rs".as_ref()),
+            Some(root.join("member/src/lib.rs"))
+        );
+        assert_eq!(
+            workspace_file(&root, "src/main.rs".as_ref()),
+            Some(root.join("src/main.rs"))
+        );
+        assert_eq!(workspace_file(&root, "../outside.rs".as_ref()), None);
+        assert_eq!(workspace_file(&root, "src/lib.rs".as_ref()), None);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
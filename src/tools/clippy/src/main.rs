@@ -2,11 +2,22 @@
 // warn on lints, that are included in `rust-lang/rust`s bootstrap
 #![warn(rust_2018_idioms, unused_lifetimes)]
 
+mod baseline;
+mod changed;
+mod fingerprint;
+mod fix;
+mod sarif;
+mod suggestions;
+mod timings;
+mod watch;
+
 use rustc_tools_util::VersionInfo;
+use serde_json::Value;
 use std::env;
 use std::ffi::OsString;
+use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
-use std::process::{self, Command};
+use std::process::{self, Command, ExitStatus, Stdio};
 
 const CARGO_CLIPPY_HELP: &str = r#"Checks a package to catch common mistakes and improve your Rust code.
 
@@ -16,6 +27,42 @@
 Common options:
     -h, --help               Print this message
     -V, --version            Print version info and exit
+        --explain <lint>     Print the documentation of a lint, e.g. `needless_return`, and exit
+        --fix                Automatically apply lint suggestions with `cargo fix`, which also
+                             accepts `--allow-dirty`, `--allow-staged` and `--broken-code`. This
+                             flag implies `--no-deps` and requires `-Z unstable-options`
+        --dry-run            With `--fix`, print the changes as a diff instead of applying them
+        --message-format=sarif
+                             Print the diagnostics as a SARIF 2.1 log to stdout
+        --message-format=json
+                             Print the messages as JSON to stdout, with a `fingerprint` of every
+                             diagnostic that doesn't depend on its line number
+        --baseline=<file>    Only report lint diagnostics that aren't recorded in the baseline
+                             file. If the file doesn't exist, record the current diagnostics in it
+        --changed[=<rev>]    Only report diagnostics on lines that changed since the git revision
+                             `rev`, `HEAD` by default, including uncommitted changes
+        --watch              Check again every time a file of the workspace changes, and only report
+                             the lint diagnostics on the lines that changed since the previous check.
+                             This flag implies `--cache`
+        --cache              Skip the items that didn't change since the previous check and had no
+                             lint diagnostics, with a cache in the target directory
+    -Z clippy-timings        Report the time spent in every lint pass and the number of diagnostics
+                             of every lint, and write them to `clippy-timings.json`
+        --deny-group <groups>
+                             Deny the comma separated lint groups, e.g. `correctness,perf`.
+                             `--warn-group` and `--allow-group` set the other levels. The `-A`/`-W`/
+                             `-D` flags after `--` take precedence
+        --profile=<name>     Set the lint levels and configuration values of a lint profile:
+                             `strict`, `embedded`, `ci` or one defined in the configuration file.
+                             Cargo's build profiles `dev`, `release`, `test` and `bench` are passed
+                             to `cargo check`
+    -p, --package <spec>     Only check the given workspace members, not the members they depend on.
+                             This flag implies `--no-deps`
+        --exclude <spec>     Don't check the given workspace members, even if other members depend
+                             on them. This flag implies `--no-deps`
+
+Every workspace member is checked with its own `clippy.toml`, or the one of the closest parent
+directory, like the workspace root.
 
 Other options are the same as `cargo check`.
 
@@ -27,6 +74,12 @@
     -D --deny OPT       Set lint denied
     -F --forbid OPT     Set lint forbidden
 
+To only run the lint passes of some lints and lint groups, use `cargo clippy --`
+with `--filter <lints>`, e.g. `--filter clippy::correctness,needless_return`.
+
+To print the suggestions of a lint as a unified diff instead of the warnings, use
+`cargo clippy --` with `--suggest-only=<lint>`, e.g. `--suggest-only=indexing_slicing`.
+
 You can use tool lints to allow or deny lints from your code, eg.:
 
     #[allow(clippy::needless_lifetimes)]
@@ -53,33 +106,185 @@ pub fn main() {
         return;
     }
 
+    if let Some(pos) = env::args().position(|a| a == "--explain") {
+        match env::args().nth(pos + 1) {
+            // the driver links `clippy_lints`, which has the documentation of the lints
+            Some(lint) => {
+                let status = Command::new(ClippyCmd::path())
+                    .arg("--explain")
+                    .arg(format!("clippy::{}", normalize_lint_name(&lint)))
+                    .status()
+                    .expect("could not run clippy-driver");
+                if !status.success() {
+                    process::exit(status.code().unwrap_or(-1));
+                }
+            },
+            None => show_help(),
+        }
+        return;
+    }
+
     if let Err(code) = process(env::args().skip(2)) {
         process::exit(code);
     }
 }
 
+/// The flags of `cargo fix` that `cargo clippy --fix` passes on, and `--dry-run` drops.
+const FIX_FLAGS: [&str; 3] = ["--allow-dirty", "--allow-staged", "--broken-code"];
+
 struct ClippyCmd {
-    cargo_subcommand: &'static str,
+    fix: Option<fix::Mode>,
+    /// The lint passed to `--suggest-only`, like `clippy::indexing_slicing`.
+    suggest_only: Option<String>,
+    sarif: bool,
+    json: bool,
+    baseline: Option<PathBuf>,
+    changed: Option<String>,
+    timings: Option<PathBuf>,
+    watch: bool,
     args: Vec<String>,
     clippy_args: Vec<String>,
 }
 
 impl ClippyCmd {
+    #[allow(clippy::too_many_lines)]
     fn new<I>(mut old_args: I) -> Self
     where
         I: Iterator<Item = String>,
     {
-        let mut cargo_subcommand = "check";
+        let mut fix = false;
+        let mut dry_run = false;
+        let mut sarif = false;
+        let mut json = false;
+        let mut message_format_value = false;
+        let mut baseline = None;
+        let mut baseline_value = false;
+        let mut changed = None;
+        let mut timings = false;
+        let mut watch = false;
+        let mut cache = false;
+        let mut profile = None;
+        let mut profile_value = false;
+        let mut group_value = None;
+        let mut group_args = vec![];
+        let mut z_value = false;
         let mut unstable_options = false;
+        let mut selects_packages = false;
         let mut args = vec![];
 
         for arg in old_args.by_ref() {
+            // Cover --message-format=sarif and --message-format sarif
+            if message_format_value && arg == "sarif" {
+                args.pop();
+                sarif = true;
+                continue;
+            }
+            // Cover --message-format json, --message-format json-diagnostic-short and the like
+            if message_format_value && arg.starts_with("json") {
+                json = true;
+            }
+            message_format_value = false;
+            // Cover -Zclippy-timings and -Z clippy-timings
+            if z_value && arg == "clippy-timings" {
+                args.pop();
+                timings = true;
+                continue;
+            }
+            z_value = false;
+            // Cover --baseline=<file> and --baseline <file>
+            if baseline_value {
+                baseline = Some(PathBuf::from(arg));
+                baseline_value = false;
+                continue;
+            }
+            if let Some(path) = arg.strip_prefix("--baseline=") {
+                baseline = Some(PathBuf::from(path));
+                continue;
+            }
+            if let Some(rev) = arg.strip_prefix("--changed=") {
+                changed = Some(rev.to_string());
+                continue;
+            }
+            // Cover --deny-group <groups>, --deny-group=<groups> and the same for --warn-group and
+            // --allow-group
+            if let Some(flag) = group_value.take() {
+                group_args.extend(group_level_args(flag, &arg));
+                continue;
+            }
+            if let Some((flag, groups)) = strip_group_flag(&arg) {
+                group_args.extend(group_level_args(flag, groups));
+                continue;
+            }
+            // Cover --profile=<name> and --profile <name>
+            if profile_value {
+                profile_value = false;
+                if !is_cargo_profile(&arg) {
+                    args.pop();
+                    profile = Some(arg);
+                    continue;
+                }
+            }
+            if let Some(name) = arg.strip_prefix("--profile=") {
+                if !is_cargo_profile(name) {
+                    profile = Some(name.to_string());
+                    continue;
+                }
+            }
+
             match arg.as_str() {
+                "--message-format=sarif" => {
+                    sarif = true;
+                    continue;
+                },
+                "--message-format" => message_format_value = true,
+                s if s.starts_with("--message-format=json") => json = true,
+                "-Z" => z_value = true,
+                "-Zclippy-timings" => {
+                    timings = true;
+                    continue;
+                },
                 "--fix" => {
-                    cargo_subcommand = "fix";
+                    fix = true;
+                    continue;
+                },
+                "--dry-run" => {
+                    dry_run = true;
+                    continue;
+                },
+                "--baseline" => {
+                    baseline_value = true;
+                    continue;
+                },
+                "--changed" => {
+                    changed = Some("HEAD".to_string());
                     continue;
                 },
+                "--watch" => {
+                    watch = true;
+                    continue;
+                },
+                "--cache" => {
+                    cache = true;
+                    continue;
+                },
+                "--deny-group" => {
+                    group_value = Some("-D");
+                    continue;
+                },
+                "--warn-group" => {
+                    group_value = Some("-W");
+                    continue;
+                },
+                "--allow-group" => {
+                    group_value = Some("-A");
+                    continue;
+                },
+                "--profile" => profile_value = true,
                 "--" => break,
+                // Cover -p <spec>, -p<spec>, --package <spec>, --package=<spec> and the same for --exclude
+                s if s.starts_with("-p") || s.starts_with("--package") || s.starts_with("--exclude") => {
+                    selects_packages = true;
+                },
                 // Cover -Zunstable-options and -Z unstable-options
                 s if s.ends_with("unstable-options") => unstable_options = true,
                 _ => {},
@@ -88,17 +293,89 @@ fn new<I>(mut old_args: I) -> Self
             args.push(arg);
         }
 
-        if cargo_subcommand == "fix" && !unstable_options {
+        if fix && !unstable_options {
             panic!("Usage of `--fix` requires `-Z unstable-options`");
         }
+        if dry_run && !fix {
+            panic!("Usage of `--dry-run` requires `--fix`");
+        }
+        if dry_run {
+            // `--dry-run` runs `cargo check`, which doesn't know the flags of `cargo fix`
+            args.retain(|arg| !FIX_FLAGS.contains(&arg.as_str()));
+        }
+        if sarif && fix {
+            panic!("`--message-format=sarif` can't be used together with `--fix`");
+        }
+        if baseline.is_some() && (fix || sarif) {
+            panic!("`--baseline` can't be used together with `--fix` or `--message-format=sarif`");
+        }
+        if changed.is_some() && (fix || sarif || baseline.is_some()) {
+            panic!("`--changed` can't be used together with `--fix`, `--message-format=sarif` or `--baseline`");
+        }
+        if timings && (fix || sarif || baseline.is_some() || changed.is_some()) {
+            panic!(
+                "`-Z clippy-timings` can't be used together with `--fix`, `--message-format=sarif`, `--baseline` or `--changed`"
+            );
+        }
+        if watch && (fix || sarif || json || baseline.is_some() || changed.is_some() || timings) {
+            panic!(
+                "`--watch` can't be used together with `--fix`, `--message-format`, `--baseline`, `--changed` or `-Z clippy-timings`"
+            );
+        }
 
-        let mut clippy_args: Vec<String> = old_args.collect();
-        if cargo_subcommand == "fix" && !clippy_args.iter().any(|arg| arg == "--no-deps") {
+        // the lint flags after `--` take precedence
+        let mut clippy_args: Vec<String> = group_args.into_iter().chain(old_args).collect();
+        // unknown lints are reported by the driver
+        let suggest_only = take_suggest_only(&mut clippy_args).map(|name| normalize_lint_name(&name));
+        if suggest_only.is_some()
+            && (fix || sarif || json || baseline.is_some() || changed.is_some() || timings || watch)
+        {
+            panic!(
+                "`--suggest-only` can't be used together with `--fix`, `--message-format`, `--baseline`, `--changed`, `-Z clippy-timings` or `--watch`"
+            );
+        }
+        if let Some(profile) = profile {
+            clippy_args.push(format!("--profile={}", profile));
+        }
+        // the workspace members that aren't selected are only checked as dependencies
+        if (fix || selects_packages || suggest_only.is_some()) && !clippy_args.iter().any(|arg| arg == "--no-deps") {
             clippy_args.push("--no-deps".into());
         }
+        // the checks of `--watch` only lint the items that changed since the previous check
+        if cache || watch {
+            clippy_args.push("--clippy-cache".into());
+        }
+        if fix {
+            // lets the driver apply the `fix-applicability` configuration value
+            clippy_args.push("--fix".into());
+        }
+        if let Some(name) = &suggest_only {
+            clippy_args.push(format!("--suggest-only={}", name));
+        }
+
+        let timings = if timings {
+            let dir = timings::dir();
+            clippy_args.push(format!("--clippy-timings={}", dir.display()));
+            Some(dir)
+        } else {
+            None
+        };
+
+        let fix = match (fix, dry_run) {
+            (false, _) => None,
+            (true, false) => Some(fix::Mode::Apply),
+            (true, true) => Some(fix::Mode::DryRun),
+        };
 
         ClippyCmd {
-            cargo_subcommand,
+            fix,
+            suggest_only: suggest_only.map(|name| format!("clippy::{}", name)),
+            sarif,
+            json,
+            baseline,
+            changed,
+            timings,
+            watch,
             args,
             clippy_args,
         }
@@ -143,21 +420,146 @@ fn into_std_cmd(self) -> Command {
         cmd.env("RUSTC_WORKSPACE_WRAPPER", Self::path())
             .envs(ClippyCmd::target_dir())
             .env("CLIPPY_ARGS", clippy_args)
-            .arg(self.cargo_subcommand)
+            .arg(if self.fix == Some(fix::Mode::Apply) {
+                "fix"
+            } else {
+                "check"
+            })
             .args(&self.args);
 
+        if self.fix == Some(fix::Mode::DryRun)
+            || self.suggest_only.is_some()
+            || self.sarif
+            || self.baseline.is_some()
+            || self.changed.is_some()
+            || self.timings.is_some()
+            || self.watch
+        {
+            cmd.arg("--message-format=json");
+        }
+
         cmd
     }
 }
 
+/// Returns the lint level flag, like `-D`, and the groups of `--deny-group=<groups>`,
+/// `--warn-group=<groups>` and `--allow-group=<groups>`.
+fn strip_group_flag(arg: &str) -> Option<(&'static str, &str)> {
+    [
+        ("--deny-group=", "-D"),
+        ("--warn-group=", "-W"),
+        ("--allow-group=", "-A"),
+    ]
+    .iter()
+    .find_map(|&(prefix, flag)| arg.strip_prefix(prefix).map(|groups| (flag, groups)))
+}
+
+/// Returns the lint level flags setting the level `flag` for every lint group of the comma
+/// separated `groups`, e.g. `-D clippy::correctness` for `-D` and `correctness`.
+fn group_level_args(flag: &str, groups: &str) -> Vec<String> {
+    groups
+        .split(',')
+        .map(str::trim)
+        .filter(|group| !group.is_empty())
+        .flat_map(|group| {
+            let group = if group.starts_with("clippy::") {
+                group.to_string()
+            } else {
+                format!("clippy::{}", group)
+            };
+            vec![flag.to_string(), group]
+        })
+        .collect()
+}
+
+/// Removes `--suggest-only <lint>` and `--suggest-only=<lint>` from the arguments passed to the
+/// driver and returns the last lint.
+fn take_suggest_only(args: &mut Vec<String>) -> Option<String> {
+    let mut lint = None;
+    let mut i = 0;
+    while i < args.len() {
+        if args[i] == "--suggest-only" && i + 1 < args.len() {
+            args.remove(i);
+            lint = Some(args.remove(i));
+        } else if let Some(name) = args[i].strip_prefix("--suggest-only=") {
+            lint = Some(name.to_string());
+            args.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    lint
+}
+
+/// Normalizes a lint name written like `Clippy::Needless-Return` to `needless_return`.
+fn normalize_lint_name(name: &str) -> String {
+    let name = name.trim().to_ascii_lowercase().replace('-', "_");
+    name.strip_prefix("clippy::").unwrap_or(&name).to_string()
+}
+
+/// Whether `--profile=<name>` selects one of Cargo's build profiles instead of a lint profile.
+fn is_cargo_profile(name: &str) -> bool {
+    matches!(name, "dev" | "release" | "test" | "bench")
+}
+
+/// Runs `cmd`, a `cargo check` with `--message-format=json`, and calls `f` on every diagnostic
+/// emitted by the compiler. Output that isn't a JSON message is passed through to stderr.
+fn run_with_diagnostics(cmd: &mut Command, mut f: impl FnMut(&Value)) -> ExitStatus {
+    let mut child = cmd.stdout(Stdio::piped()).spawn().expect("could not run cargo");
+    let stdout = child.stdout.take().expect("cargo stdout is piped");
+    for line in BufReader::new(stdout).lines() {
+        let line = line.expect("failed to read cargo output");
+        match serde_json::from_str::<Value>(&line) {
+            Ok(msg) => {
+                if msg["reason"] == "compiler-message" {
+                    f(&msg["message"]);
+                }
+            },
+            Err(_) => eprintln!("{}", line),
+        }
+    }
+    child.wait().expect("failed to wait for cargo?")
+}
+
 fn process<I>(old_args: I) -> Result<(), i32>
 where
     I: Iterator<Item = String>,
 {
     let cmd = ClippyCmd::new(old_args);
+    let (fix, sarif, json) = (cmd.fix, cmd.sarif, cmd.json);
+    let suggest_only = cmd.suggest_only.clone();
+    let baseline = cmd.baseline.clone();
+    let changed = cmd.changed.clone();
+    let timings = cmd.timings.clone();
+    let watch = cmd.watch;
 
     let mut cmd = cmd.into_std_cmd();
 
+    if fix == Some(fix::Mode::DryRun) {
+        return fix::dry_run(&mut cmd);
+    }
+    if let Some(lint) = suggest_only {
+        return fix::suggest_only(&mut cmd, &lint);
+    }
+    if sarif {
+        return sarif::run(&mut cmd);
+    }
+    if let Some(path) = baseline {
+        return baseline::run(&mut cmd, &path);
+    }
+    if let Some(rev) = changed {
+        return changed::run(&mut cmd, &rev);
+    }
+    if watch {
+        return watch::run(&mut cmd);
+    }
+    if let Some(dir) = timings {
+        return timings::run(&mut cmd, &dir);
+    }
+    if json {
+        return fingerprint::run(&mut cmd);
+    }
+
     let exit_status = cmd
         .spawn()
         .expect("could not run cargo")
@@ -173,7 +575,8 @@ fn process<I>(old_args: I) -> Result<(), i32>
 
 #[cfg(test)]
 mod tests {
-    use super::ClippyCmd;
+    use super::{fix, ClippyCmd};
+    use std::path::PathBuf;
 
     #[test]
     #[should_panic]
@@ -188,8 +591,282 @@ fn fix_unstable() {
             .split_whitespace()
             .map(ToString::to_string);
         let cmd = ClippyCmd::new(args);
-        assert_eq!("fix", cmd.cargo_subcommand);
+        assert_eq!(Some(fix::Mode::Apply), cmd.fix);
         assert!(cmd.args.iter().any(|arg| arg.ends_with("unstable-options")));
+        assert!(cmd.clippy_args.iter().any(|arg| arg == "--fix"));
+    }
+
+    #[test]
+    fn fix_dry_run() {
+        let args = "cargo clippy --fix --dry-run -Zunstable-options"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(Some(fix::Mode::DryRun), cmd.fix);
+        assert!(!cmd.args.iter().any(|arg| arg == "--dry-run"));
+    }
+
+    #[test]
+    fn fix_flags() {
+        let args = "cargo clippy --fix --allow-dirty --broken-code -Zunstable-options"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.args.iter().any(|arg| arg == "--allow-dirty"));
+        assert!(cmd.args.iter().any(|arg| arg == "--broken-code"));
+
+        let args = "cargo clippy --fix --dry-run --allow-dirty --allow-staged -Zunstable-options"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(!cmd.args.iter().any(|arg| arg.starts_with("--allow")));
+    }
+
+    #[test]
+    #[should_panic]
+    fn dry_run_without_fix() {
+        let args = "cargo clippy --dry-run".split_whitespace().map(ToString::to_string);
+        ClippyCmd::new(args);
+    }
+
+    #[test]
+    fn sarif() {
+        let args = "cargo clippy --message-format=sarif"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.sarif);
+        assert!(!cmd.args.iter().any(|arg| arg.contains("sarif")));
+
+        let args = "cargo clippy --message-format sarif --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.sarif);
+        assert!(!cmd.args.iter().any(|arg| arg == "--message-format" || arg == "sarif"));
+        assert!(cmd.args.iter().any(|arg| arg == "--all-targets"));
+
+        let args = "cargo clippy --message-format short"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(!cmd.sarif);
+        assert!(cmd.args.iter().any(|arg| arg == "short"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn sarif_with_fix() {
+        let args = "cargo clippy --fix -Zunstable-options --message-format=sarif"
+            .split_whitespace()
+            .map(ToString::to_string);
+        ClippyCmd::new(args);
+    }
+
+    #[test]
+    fn json() {
+        let args = "cargo clippy --message-format=json-diagnostic-short"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.json);
+        assert!(
+            cmd.args
+                .iter()
+                .any(|arg| arg == "--message-format=json-diagnostic-short")
+        );
+
+        let args = "cargo clippy --message-format json --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.json);
+        assert!(cmd.args.iter().any(|arg| arg == "json"));
+
+        let args = "cargo clippy --message-format short"
+            .split_whitespace()
+            .map(ToString::to_string);
+        assert!(!ClippyCmd::new(args).json);
+    }
+
+    #[test]
+    fn baseline() {
+        let args = "cargo clippy --baseline=clippy-baseline.json --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.baseline, Some(PathBuf::from("clippy-baseline.json")));
+        assert!(!cmd.args.iter().any(|arg| arg.contains("baseline")));
+        assert!(cmd.args.iter().any(|arg| arg == "--all-targets"));
+
+        let args = "cargo clippy --baseline clippy-baseline.json --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.baseline, Some(PathBuf::from("clippy-baseline.json")));
+        assert!(!cmd.args.iter().any(|arg| arg.contains("baseline")));
+        assert!(cmd.args.iter().any(|arg| arg == "--all-targets"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn baseline_with_sarif() {
+        let args = "cargo clippy --baseline=clippy-baseline.json --message-format=sarif"
+            .split_whitespace()
+            .map(ToString::to_string);
+        ClippyCmd::new(args);
+    }
+
+    #[test]
+    fn changed() {
+        let args = "cargo clippy --changed --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.changed.as_deref(), Some("HEAD"));
+        assert!(!cmd.args.iter().any(|arg| arg.contains("changed")));
+        assert!(cmd.args.iter().any(|arg| arg == "--all-targets"));
+
+        let args = "cargo clippy --changed=origin/master"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.changed.as_deref(), Some("origin/master"));
+        assert!(!cmd.args.iter().any(|arg| arg.contains("changed")));
+    }
+
+    #[test]
+    #[should_panic]
+    fn changed_with_baseline() {
+        let args = "cargo clippy --changed --baseline=clippy-baseline.json"
+            .split_whitespace()
+            .map(ToString::to_string);
+        ClippyCmd::new(args);
+    }
+
+    #[test]
+    fn timings() {
+        let args = "cargo clippy -Z clippy-timings --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.timings.is_some());
+        assert!(!cmd.args.iter().any(|arg| arg == "-Z" || arg.contains("timings")));
+        assert!(cmd.args.iter().any(|arg| arg == "--all-targets"));
+        assert!(cmd.clippy_args.iter().any(|arg| arg.starts_with("--clippy-timings=")));
+
+        let args = "cargo clippy -Zclippy-timings -Z unstable-options"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.timings.is_some());
+        assert!(!cmd.args.iter().any(|arg| arg.contains("timings")));
+        assert!(cmd.args.iter().any(|arg| arg == "unstable-options"));
+    }
+
+    #[test]
+    fn cache() {
+        let args = "cargo clippy --cache --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(!cmd.args.iter().any(|arg| arg.contains("cache")));
+        assert!(cmd.args.iter().any(|arg| arg == "--all-targets"));
+        assert!(cmd.clippy_args.iter().any(|arg| arg == "--clippy-cache"));
+    }
+
+    #[test]
+    fn suggest_only() {
+        let args = "cargo clippy --all-targets -- --suggest-only Clippy::Indexing-Slicing -W clippy::pedantic"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.suggest_only.as_deref(), Some("clippy::indexing_slicing"));
+        assert!(cmd.args.iter().any(|arg| arg == "--all-targets"));
+        assert_eq!(
+            cmd.clippy_args,
+            ["-W", "clippy::pedantic", "--no-deps", "--suggest-only=indexing_slicing"]
+        );
+    }
+
+    #[test]
+    fn suggest_only_unknown_lint() {
+        // the driver reports that there is no such lint
+        let args = "cargo clippy -- --suggest-only=Indexing"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.suggest_only.as_deref(), Some("clippy::indexing"));
+        assert_eq!(cmd.clippy_args, ["--no-deps", "--suggest-only=indexing"]);
+    }
+
+    #[test]
+    fn lint_groups() {
+        let args = "cargo clippy --deny-group correctness --all-targets --warn-group=pedantic,clippy::nursery -- -A clippy::module_name_repetitions"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--all-targets"]);
+        assert_eq!(
+            cmd.clippy_args,
+            [
+                "-D",
+                "clippy::correctness",
+                "-W",
+                "clippy::pedantic",
+                "-W",
+                "clippy::nursery",
+                "-A",
+                "clippy::module_name_repetitions"
+            ]
+        );
+    }
+
+    #[test]
+    fn watch() {
+        let args = "cargo clippy --watch --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.watch);
+        assert!(!cmd.args.iter().any(|arg| arg.contains("watch")));
+        assert!(cmd.args.iter().any(|arg| arg == "--all-targets"));
+        assert!(cmd.clippy_args.iter().any(|arg| arg == "--clippy-cache"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn watch_with_changed() {
+        let args = "cargo clippy --watch --changed"
+            .split_whitespace()
+            .map(ToString::to_string);
+        ClippyCmd::new(args);
+    }
+
+    #[test]
+    fn profile() {
+        let args = "cargo clippy --profile=strict --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(!cmd.args.iter().any(|arg| arg.contains("profile")));
+        assert!(cmd.args.iter().any(|arg| arg == "--all-targets"));
+        assert!(cmd.clippy_args.iter().any(|arg| arg == "--profile=strict"));
+
+        let args = "cargo clippy --profile ci".split_whitespace().map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(!cmd.args.iter().any(|arg| arg == "--profile" || arg == "ci"));
+        assert!(cmd.clippy_args.iter().any(|arg| arg == "--profile=ci"));
+
+        let args = "cargo clippy --profile test --profile=release"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(
+            cmd.args
+                .ends_with(&["--profile".into(), "test".into(), "--profile=release".into()])
+        );
+        assert!(!cmd.clippy_args.iter().any(|arg| arg.contains("profile")));
     }
 
     #[test]
@@ -210,10 +887,43 @@ fn no_deps_not_duplicated_with_fix() {
         assert_eq!(cmd.clippy_args.iter().filter(|arg| *arg == "--no-deps").count(), 1);
     }
 
+    #[test]
+    fn package_implies_no_deps() {
+        for args in &[
+            "cargo clippy -p foo",
+            "cargo clippy --package=foo",
+            "cargo clippy -pfoo",
+        ] {
+            let cmd = ClippyCmd::new(args.split_whitespace().map(ToString::to_string));
+            assert!(cmd.clippy_args.iter().any(|arg| arg == "--no-deps"));
+            assert!(cmd.args.iter().any(|arg| arg.contains("foo")));
+        }
+    }
+
+    #[test]
+    fn exclude_implies_no_deps() {
+        let args = "cargo clippy --workspace --exclude foo -- --no-deps"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(
+            cmd.args
+                .ends_with(&["--workspace".into(), "--exclude".into(), "foo".into()])
+        );
+        assert_eq!(cmd.clippy_args, vec!["--no-deps"]);
+    }
+
+    #[test]
+    fn workspace_lints_dependencies() {
+        let args = "cargo clippy --workspace".split_whitespace().map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.clippy_args.is_empty());
+    }
+
     #[test]
     fn check() {
         let args = "cargo clippy".split_whitespace().map(ToString::to_string);
         let cmd = ClippyCmd::new(args);
-        assert_eq!("check", cmd.cargo_subcommand);
+        assert_eq!(None, cmd.fix);
     }
 }
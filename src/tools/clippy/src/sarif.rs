@@ -0,0 +1,759 @@
+//! Support for `cargo clippy --message-format=sarif`: converts the diagnostics emitted by
+//! `cargo check` into a SARIF 2.1 log, the format read by GitHub code scanning and other CI tools.
+//!
+//! See <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html> for the specification.
+
+use serde_json::{json, Value};
+use std::iter;
+use std::process::Command;
+
+const DOCS_LINK: &str = "https://rust-lang.github.io/rust-clippy/master/index.html";
+
+/// The category of every Clippy lint, sorted by lint name.
+static LINT_CATEGORIES: &[(&str, &str)] = &[
+    // begin lint categories, do not remove this comment, it’s used in `update_lints`
+    ("absurd_extreme_comparisons", "correctness"),
+    ("almost_swapped", "correctness"),
+    ("approx_constant", "correctness"),
+    ("as_conversions", "restriction"),
+    ("assertions_on_constants", "style"),
+    ("assign_op_pattern", "style"),
+    ("async_yields_async", "correctness"),
+    ("await_holding_lock", "pedantic"),
+    ("await_holding_refcell_ref", "pedantic"),
+    ("bad_bit_mask", "correctness"),
+    ("bind_instead_of_map", "complexity"),
+    ("blacklisted_name", "style"),
+    ("blanket_clippy_restriction_lints", "style"),
+    ("blocks_in_if_conditions", "style"),
+    ("bool_assert_comparison", "style"),
+    ("bool_comparison", "complexity"),
+    ("borrow_interior_mutable_const", "style"),
+    ("borrowed_box", "complexity"),
+    ("box_vec", "perf"),
+    ("boxed_local", "perf"),
+    ("branches_sharing_code", "complexity"),
+    ("builtin_type_shadow", "style"),
+    ("bytes_nth", "style"),
+    ("cargo_common_metadata", "cargo"),
+    ("case_sensitive_file_extension_comparisons", "pedantic"),
+    ("cast_lossless", "pedantic"),
+    ("cast_possible_truncation", "pedantic"),
+    ("cast_possible_wrap", "pedantic"),
+    ("cast_precision_loss", "pedantic"),
+    ("cast_ptr_alignment", "pedantic"),
+    ("cast_ref_to_mut", "correctness"),
+    ("cast_sign_loss", "pedantic"),
+    ("chained_indexing", "pedantic"),
+    ("char_lit_as_u8", "complexity"),
+    ("chars_last_cmp", "style"),
+    ("chars_next_cmp", "style"),
+    ("chars_nth_loop", "perf"),
+    ("checked_conversions", "pedantic"),
+    ("clone_double_ref", "correctness"),
+    ("clone_on_copy", "complexity"),
+    ("clone_on_ref_ptr", "restriction"),
+    ("cloned_instead_of_copied", "pedantic"),
+    ("cmp_nan", "correctness"),
+    ("cmp_null", "style"),
+    ("cmp_owned", "perf"),
+    ("cognitive_complexity", "nursery"),
+    ("collapsible_else_if", "style"),
+    ("collapsible_if", "style"),
+    ("collapsible_match", "style"),
+    ("collect_then_index", "perf"),
+    ("comparison_chain", "style"),
+    ("comparison_to_empty", "style"),
+    ("const_fn_indexing", "correctness"),
+    ("construct_then_index", "pedantic"),
+    ("copy_iterator", "pedantic"),
+    ("create_dir", "restriction"),
+    ("crosspointer_transmute", "complexity"),
+    ("dbg_macro", "restriction"),
+    ("debug_assert_indexing", "pedantic"),
+    ("debug_assert_with_mut_call", "nursery"),
+    ("decimal_literal_representation", "restriction"),
+    ("declare_interior_mutable_const", "style"),
+    ("default_numeric_fallback", "restriction"),
+    ("default_trait_access", "pedantic"),
+    ("deprecated_cfg_attr", "complexity"),
+    ("deprecated_semver", "correctness"),
+    ("deref_addrof", "complexity"),
+    ("derive_hash_xor_eq", "correctness"),
+    ("derive_ord_xor_partial_ord", "correctness"),
+    ("disallowed_method", "nursery"),
+    ("diverging_sub_expression", "complexity"),
+    ("doc_markdown", "pedantic"),
+    ("double_comparisons", "complexity"),
+    ("double_must_use", "style"),
+    ("double_neg", "style"),
+    ("double_parens", "complexity"),
+    ("drop_copy", "correctness"),
+    ("drop_ref", "correctness"),
+    ("duplicate_underscore_argument", "style"),
+    ("duration_subsec", "complexity"),
+    ("else_if_without_else", "restriction"),
+    ("empty_enum", "pedantic"),
+    ("empty_line_after_outer_attr", "nursery"),
+    ("empty_loop", "style"),
+    ("enum_clike_unportable_variant", "correctness"),
+    ("enum_glob_use", "pedantic"),
+    ("enum_variant_names", "style"),
+    ("eq_op", "correctness"),
+    ("erasing_op", "correctness"),
+    ("eval_order_dependence", "complexity"),
+    ("excessive_precision", "style"),
+    ("exhaustive_enums", "restriction"),
+    ("exhaustive_structs", "restriction"),
+    ("exit", "restriction"),
+    ("expect_fun_call", "perf"),
+    ("expect_used", "restriction"),
+    ("expl_impl_clone_on_copy", "pedantic"),
+    ("explicit_counter_loop", "complexity"),
+    ("explicit_deref_methods", "pedantic"),
+    ("explicit_into_iter_loop", "pedantic"),
+    ("explicit_iter_loop", "pedantic"),
+    ("explicit_write", "complexity"),
+    ("extra_unused_lifetimes", "complexity"),
+    ("fallible_impl_from", "nursery"),
+    ("ffi_unchecked_index", "restriction"),
+    ("field_reassign_with_default", "style"),
+    ("filetype_is_file", "restriction"),
+    ("filter_map_identity", "complexity"),
+    ("filter_map_next", "pedantic"),
+    ("filter_next", "complexity"),
+    ("flat_map_identity", "complexity"),
+    ("flat_map_option", "pedantic"),
+    ("float_arithmetic", "restriction"),
+    ("float_cmp", "correctness"),
+    ("float_cmp_const", "restriction"),
+    ("float_equality_without_abs", "correctness"),
+    ("fn_address_comparisons", "correctness"),
+    ("fn_params_excessive_bools", "pedantic"),
+    ("fn_to_numeric_cast", "style"),
+    ("fn_to_numeric_cast_with_truncation", "style"),
+    ("for_kv_map", "style"),
+    ("for_loops_over_fallibles", "correctness"),
+    ("forget_copy", "correctness"),
+    ("forget_ref", "correctness"),
+    ("from_iter_instead_of_collect", "style"),
+    ("from_over_into", "style"),
+    ("from_str_radix_10", "style"),
+    ("future_not_send", "nursery"),
+    ("get_last_with_len", "complexity"),
+    ("get_unwrap", "restriction"),
+    ("get_unwrap_or_call", "perf"),
+    ("identity_op", "complexity"),
+    ("if_let_mutex", "correctness"),
+    ("if_let_some_result", "style"),
+    ("if_not_else", "pedantic"),
+    ("if_same_then_else", "correctness"),
+    ("if_then_some_else_none", "restriction"),
+    ("ifs_same_cond", "correctness"),
+    ("implicit_clone", "pedantic"),
+    ("implicit_hasher", "pedantic"),
+    ("implicit_return", "restriction"),
+    ("implicit_saturating_sub", "pedantic"),
+    ("imprecise_flops", "nursery"),
+    ("inclusive_range_out_of_bounds", "correctness"),
+    ("inconsistent_digit_grouping", "style"),
+    ("inconsistent_struct_constructor", "style"),
+    ("index_map", "restriction"),
+    ("index_underflow", "restriction"),
+    ("indexing_last_with_len", "style"),
+    ("indexing_slicing", "restriction"),
+    ("ineffective_bit_mask", "correctness"),
+    ("inefficient_to_string", "pedantic"),
+    ("infallible_destructuring_match", "style"),
+    ("infinite_iter", "correctness"),
+    ("inherent_to_string", "style"),
+    ("inherent_to_string_shadow_display", "correctness"),
+    ("inline_always", "pedantic"),
+    ("inline_asm_x86_att_syntax", "restriction"),
+    ("inline_asm_x86_intel_syntax", "restriction"),
+    ("inline_fn_without_body", "correctness"),
+    ("inspect_for_each", "complexity"),
+    ("int_plus_one", "complexity"),
+    ("integer_arithmetic", "restriction"),
+    ("integer_division", "restriction"),
+    ("into_iter_on_ref", "style"),
+    ("invalid_atomic_ordering", "correctness"),
+    ("invalid_null_ptr_usage", "correctness"),
+    ("invalid_regex", "correctness"),
+    ("invalid_upcast_comparisons", "pedantic"),
+    ("invisible_characters", "correctness"),
+    ("items_after_statements", "pedantic"),
+    ("iter_cloned_collect", "style"),
+    ("iter_count", "complexity"),
+    ("iter_next_loop", "correctness"),
+    ("iter_next_slice", "style"),
+    ("iter_nth", "perf"),
+    ("iter_nth_zero", "style"),
+    ("iter_skip_next", "style"),
+    ("iterator_step_by_zero", "correctness"),
+    ("just_underscores_and_digits", "style"),
+    ("large_const_arrays", "perf"),
+    ("large_digit_groups", "pedantic"),
+    ("large_enum_variant", "perf"),
+    ("large_stack_arrays", "pedantic"),
+    ("large_types_passed_by_value", "pedantic"),
+    ("len_without_is_empty", "style"),
+    ("len_zero", "style"),
+    ("let_and_return", "style"),
+    ("let_underscore_drop", "pedantic"),
+    ("let_underscore_lock", "correctness"),
+    ("let_underscore_must_use", "restriction"),
+    ("let_unit_value", "pedantic"),
+    ("linkedlist", "pedantic"),
+    ("logic_bug", "correctness"),
+    ("loop_index_offset", "pedantic"),
+    ("lossy_float_literal", "restriction"),
+    ("macro_use_imports", "pedantic"),
+    ("main_recursion", "style"),
+    ("manual_async_fn", "style"),
+    ("manual_binary_search", "pedantic"),
+    ("manual_filter_map", "complexity"),
+    ("manual_find_map", "complexity"),
+    ("manual_flatten", "complexity"),
+    ("manual_from_bytes", "pedantic"),
+    ("manual_map", "style"),
+    ("manual_memcpy", "perf"),
+    ("manual_non_exhaustive", "style"),
+    ("manual_ok_or", "pedantic"),
+    ("manual_range_contains", "style"),
+    ("manual_saturating_arithmetic", "style"),
+    ("manual_slice_pattern", "pedantic"),
+    ("manual_strip", "complexity"),
+    ("manual_swap", "complexity"),
+    ("manual_unwrap_or", "complexity"),
+    ("manual_zip", "pedantic"),
+    ("many_single_char_names", "style"),
+    ("map_clone", "style"),
+    ("map_collect_result_unit", "style"),
+    ("map_entry", "perf"),
+    ("map_err_ignore", "restriction"),
+    ("map_flatten", "pedantic"),
+    ("map_identity", "complexity"),
+    ("map_unwrap_or", "pedantic"),
+    ("match_as_ref", "complexity"),
+    ("match_bool", "pedantic"),
+    ("match_like_matches_macro", "style"),
+    ("match_on_vec_items", "pedantic"),
+    ("match_overlapping_arm", "style"),
+    ("match_ref_pats", "style"),
+    ("match_same_arms", "pedantic"),
+    ("match_single_binding", "complexity"),
+    ("match_wild_err_arm", "pedantic"),
+    ("match_wildcard_for_single_variants", "pedantic"),
+    ("matched_len_out_of_bounds", "correctness"),
+    ("maybe_infinite_iter", "pedantic"),
+    ("mem_discriminant_non_enum", "correctness"),
+    ("mem_forget", "restriction"),
+    ("mem_replace_option_with_none", "style"),
+    ("mem_replace_with_default", "style"),
+    ("mem_replace_with_uninit", "correctness"),
+    ("method_index_out_of_bounds", "correctness"),
+    ("min_max", "correctness"),
+    ("mismatched_target_os", "correctness"),
+    ("misrefactored_assign_op", "complexity"),
+    ("missing_const_for_fn", "nursery"),
+    ("missing_docs_in_private_items", "restriction"),
+    ("missing_errors_doc", "pedantic"),
+    ("missing_inline_in_public_items", "restriction"),
+    ("missing_panics_doc", "pedantic"),
+    ("missing_safety_doc", "style"),
+    ("mistyped_literal_suffixes", "correctness"),
+    ("mixed_case_hex_literals", "style"),
+    ("module_inception", "style"),
+    ("module_name_repetitions", "pedantic"),
+    ("modulo_arithmetic", "restriction"),
+    ("modulo_one", "correctness"),
+    ("multiple_crate_versions", "cargo"),
+    ("multiple_inherent_impl", "restriction"),
+    ("must_use_candidate", "pedantic"),
+    ("must_use_unit", "style"),
+    ("mut_from_ref", "correctness"),
+    ("mut_mut", "pedantic"),
+    ("mut_mutex_lock", "style"),
+    ("mut_range_bound", "complexity"),
+    ("mutable_key_type", "correctness"),
+    ("mutex_atomic", "perf"),
+    ("mutex_integer", "nursery"),
+    ("naive_bytecount", "pedantic"),
+    ("needless_arbitrary_self_type", "complexity"),
+    ("needless_bool", "complexity"),
+    ("needless_borrow", "nursery"),
+    ("needless_borrowed_reference", "complexity"),
+    ("needless_collect", "perf"),
+    ("needless_continue", "pedantic"),
+    ("needless_doctest_main", "style"),
+    ("needless_for_each", "pedantic"),
+    ("needless_lifetimes", "complexity"),
+    ("needless_pass_by_value", "pedantic"),
+    ("needless_question_mark", "complexity"),
+    ("needless_range_loop", "style"),
+    ("needless_return", "style"),
+    ("needless_update", "complexity"),
+    ("neg_cmp_op_on_partial_ord", "complexity"),
+    ("neg_multiply", "style"),
+    ("never_loop", "correctness"),
+    ("new_ret_no_self", "style"),
+    ("new_without_default", "style"),
+    ("no_effect", "complexity"),
+    ("non_ascii_literal", "pedantic"),
+    ("non_octal_unix_permissions", "correctness"),
+    ("nonminimal_bool", "complexity"),
+    ("nonsensical_open_options", "correctness"),
+    ("nonzero_new_unwrap", "restriction"),
+    ("not_unsafe_ptr_arg_deref", "correctness"),
+    ("nth_out_of_bounds", "correctness"),
+    ("ok_expect", "style"),
+    ("op_ref", "style"),
+    ("option_as_ref_deref", "complexity"),
+    ("option_env_unwrap", "correctness"),
+    ("option_filter_map", "complexity"),
+    ("option_if_let_else", "pedantic"),
+    ("option_map_or_none", "style"),
+    ("option_map_unit_fn", "complexity"),
+    ("option_option", "pedantic"),
+    ("or_fun_call", "perf"),
+    ("out_of_bounds_indexing", "correctness"),
+    ("overflow_check_conditional", "complexity"),
+    ("panic", "restriction"),
+    ("panic_in_drop", "restriction"),
+    ("panic_in_fmt", "restriction"),
+    ("panic_in_library", "restriction"),
+    ("panic_in_result_fn", "restriction"),
+    ("panicking_index_impl", "restriction"),
+    ("panicking_unwrap", "correctness"),
+    ("panicking_user_index", "restriction"),
+    ("partialeq_ne_impl", "complexity"),
+    ("partially_read_buffer", "nursery"),
+    ("path_buf_push_overwrite", "nursery"),
+    ("pattern_type_mismatch", "restriction"),
+    ("possible_missing_comma", "correctness"),
+    ("precedence", "complexity"),
+    ("print_literal", "style"),
+    ("print_stderr", "restriction"),
+    ("print_stdout", "restriction"),
+    ("print_with_newline", "style"),
+    ("println_empty_string", "style"),
+    ("ptr_arg", "style"),
+    ("ptr_as_ptr", "pedantic"),
+    ("ptr_eq", "style"),
+    ("ptr_offset_with_cast", "complexity"),
+    ("pub_enum_variant_names", "pedantic"),
+    ("question_mark", "style"),
+    ("range_minus_one", "pedantic"),
+    ("range_plus_one", "pedantic"),
+    ("range_zip_with_len", "complexity"),
+    ("rc_buffer", "restriction"),
+    ("reachable_panic", "restriction"),
+    ("redundant_allocation", "perf"),
+    ("redundant_clone", "perf"),
+    ("redundant_closure", "style"),
+    ("redundant_closure_call", "complexity"),
+    ("redundant_closure_for_method_calls", "pedantic"),
+    ("redundant_else", "pedantic"),
+    ("redundant_field_names", "style"),
+    ("redundant_pattern", "style"),
+    ("redundant_pattern_matching", "style"),
+    ("redundant_pub_crate", "nursery"),
+    ("redundant_slicing", "complexity"),
+    ("redundant_static_lifetimes", "style"),
+    ("ref_in_deref", "complexity"),
+    ("ref_option_ref", "pedantic"),
+    ("repeat_once", "complexity"),
+    ("repeated_indexing", "pedantic"),
+    ("rest_pat_in_fully_bound_structs", "restriction"),
+    ("result_map_or_into_option", "style"),
+    ("result_map_unit_fn", "complexity"),
+    ("result_unit_err", "style"),
+    ("reversed_empty_ranges", "correctness"),
+    ("reversed_slice_range", "correctness"),
+    ("same_functions_in_if_condition", "pedantic"),
+    ("same_item_push", "style"),
+    ("search_is_some", "complexity"),
+    ("self_assignment", "correctness"),
+    ("semicolon_if_nothing_returned", "restriction"),
+    ("serde_api_misuse", "correctness"),
+    ("shadow_reuse", "restriction"),
+    ("shadow_same", "restriction"),
+    ("shadow_unrelated", "pedantic"),
+    ("short_circuit_statement", "complexity"),
+    ("should_implement_trait", "style"),
+    ("signed_index_cast", "pedantic"),
+    ("similar_names", "pedantic"),
+    ("single_char_add_str", "style"),
+    ("single_char_pattern", "perf"),
+    ("single_component_path_imports", "style"),
+    ("single_element_loop", "complexity"),
+    ("single_match", "style"),
+    ("single_match_else", "pedantic"),
+    ("size_of_in_element_count", "correctness"),
+    ("size_of_out_of_bounds", "correctness"),
+    ("skip_while_next", "complexity"),
+    ("slice_length_mismatch", "correctness"),
+    ("slow_vector_initialization", "perf"),
+    ("split_indexing", "pedantic"),
+    ("stable_sort_primitive", "perf"),
+    ("str_to_string", "restriction"),
+    ("string_add", "restriction"),
+    ("string_add_assign", "pedantic"),
+    ("string_extend_chars", "style"),
+    ("string_from_utf8_as_bytes", "complexity"),
+    ("string_lit_as_bytes", "nursery"),
+    ("string_slice", "restriction"),
+    ("string_to_string", "restriction"),
+    ("struct_excessive_bools", "pedantic"),
+    ("suboptimal_flops", "nursery"),
+    ("suspicious_arithmetic_impl", "correctness"),
+    ("suspicious_assignment_formatting", "style"),
+    ("suspicious_else_formatting", "style"),
+    ("suspicious_map", "complexity"),
+    ("suspicious_op_assign_impl", "correctness"),
+    ("suspicious_operation_groupings", "style"),
+    ("suspicious_unary_op_formatting", "style"),
+    ("tabs_in_doc_comments", "style"),
+    ("temporary_assignment", "complexity"),
+    ("to_digit_is_some", "style"),
+    ("to_string_in_display", "correctness"),
+    ("todo", "restriction"),
+    ("too_many_arguments", "complexity"),
+    ("too_many_lines", "pedantic"),
+    ("toplevel_ref_arg", "style"),
+    ("trait_duplication_in_bounds", "pedantic"),
+    ("transmute_bytes_to_str", "complexity"),
+    ("transmute_float_to_int", "complexity"),
+    ("transmute_int_to_bool", "complexity"),
+    ("transmute_int_to_char", "complexity"),
+    ("transmute_int_to_float", "complexity"),
+    ("transmute_ptr_to_ptr", "pedantic"),
+    ("transmute_ptr_to_ref", "complexity"),
+    ("transmutes_expressible_as_ptr_casts", "complexity"),
+    ("transmuting_null", "correctness"),
+    ("trivial_regex", "nursery"),
+    ("trivially_copy_pass_by_ref", "pedantic"),
+    ("try_err", "style"),
+    ("type_complexity", "complexity"),
+    ("type_repetition_in_bounds", "pedantic"),
+    ("unchecked_division", "restriction"),
+    ("unchecked_env_args", "pedantic"),
+    ("undropped_manually_drops", "correctness"),
+    ("unguarded_get_unchecked", "pedantic"),
+    ("unguarded_raw_pointer_index", "restriction"),
+    ("unhelpful_expect_message", "pedantic"),
+    ("unicode_not_nfc", "pedantic"),
+    ("unimplemented", "restriction"),
+    ("uninit_assumed_init", "correctness"),
+    ("unit_arg", "complexity"),
+    ("unit_cmp", "correctness"),
+    ("unit_return_expecting_ord", "correctness"),
+    ("unnecessary_cast", "complexity"),
+    ("unnecessary_filter_map", "complexity"),
+    ("unnecessary_fold", "style"),
+    ("unnecessary_lazy_evaluations", "style"),
+    ("unnecessary_mut_passed", "style"),
+    ("unnecessary_operation", "complexity"),
+    ("unnecessary_self_imports", "restriction"),
+    ("unnecessary_sort_by", "complexity"),
+    ("unnecessary_unwrap", "complexity"),
+    ("unnecessary_wraps", "pedantic"),
+    ("unneeded_field_pattern", "restriction"),
+    ("unneeded_wildcard_pattern", "complexity"),
+    ("unnested_or_patterns", "pedantic"),
+    ("unreachable", "restriction"),
+    ("unreadable_literal", "pedantic"),
+    ("unsafe_derive_deserialize", "pedantic"),
+    ("unsafe_removed_from_name", "style"),
+    ("unseparated_literal_suffix", "pedantic"),
+    ("unsound_collection_transmute", "correctness"),
+    ("unused_io_amount", "correctness"),
+    ("unused_self", "pedantic"),
+    ("unused_unit", "style"),
+    ("unusual_byte_groupings", "style"),
+    ("unwrap_in_result", "restriction"),
+    ("unwrap_indexing", "restriction"),
+    ("unwrap_used", "restriction"),
+    ("unwrapped_first_or_last", "restriction"),
+    ("unwrapped_get", "restriction"),
+    ("unwrapped_index_conversion", "restriction"),
+    ("upper_case_acronyms", "style"),
+    ("use_debug", "restriction"),
+    ("use_self", "nursery"),
+    ("used_underscore_binding", "pedantic"),
+    ("useless_asref", "complexity"),
+    ("useless_attribute", "correctness"),
+    ("useless_conversion", "complexity"),
+    ("useless_format", "complexity"),
+    ("useless_let_if_seq", "nursery"),
+    ("useless_transmute", "nursery"),
+    ("useless_vec", "perf"),
+    ("vec_box", "complexity"),
+    ("vec_front_in_loop", "perf"),
+    ("vec_init_then_push", "perf"),
+    ("vec_resize_to_zero", "correctness"),
+    ("verbose_bit_mask", "pedantic"),
+    ("verbose_file_reads", "restriction"),
+    ("vtable_address_comparisons", "correctness"),
+    ("while_immutable_condition", "correctness"),
+    ("while_let_loop", "complexity"),
+    ("while_let_on_iterator", "style"),
+    ("wildcard_dependencies", "cargo"),
+    ("wildcard_enum_match_arm", "restriction"),
+    ("wildcard_imports", "pedantic"),
+    ("wildcard_in_or_patterns", "complexity"),
+    ("with_capacity_indexing", "correctness"),
+    ("write_literal", "style"),
+    ("write_with_newline", "style"),
+    ("writeln_empty_string", "style"),
+    ("wrong_pub_self_convention", "restriction"),
+    ("wrong_self_convention", "style"),
+    ("wrong_transmute", "correctness"),
+    ("zero_divided_by_zero", "complexity"),
+    ("zero_prefixed_literal", "complexity"),
+    ("zero_ptr", "style"),
+    ("zero_sized_map_values", "pedantic"),
+    ("zst_offset", "correctness"),
+    // end lint categories, do not remove this comment, it’s used in `update_lints`
+];
+
+fn lint_category(name: &str) -> Option<&'static str> {
+    LINT_CATEGORIES
+        .binary_search_by_key(&name, |&(lint, _)| lint)
+        .ok()
+        .map(|i| LINT_CATEGORIES[i].1)
+}
+
+/// The rules and results of a SARIF run.
+#[derive(Default)]
+struct Log {
+    rules: Vec<Value>,
+    results: Vec<Value>,
+}
+
+impl Log {
+    /// Adds a diagnostic emitted by the compiler as a result.
+    fn add_diagnostic(&mut self, diag: &Value) {
+        let spans = diag["spans"].as_array().map_or(&[][..], Vec::as_slice);
+        let locations: Vec<Value> = spans
+            .iter()
+            .filter(|span| span["is_primary"] == true)
+            .map(|span| json!({ "physicalLocation": physical_location(span) }))
+            .collect();
+        // Summaries like "aborting due to previous error" have no location.
+        if locations.is_empty() {
+            return;
+        }
+
+        let mut result = json!({
+            "level": match diag["level"].as_str() {
+                Some(level) if level.starts_with("error") => "error",
+                Some("warning") => "warning",
+                _ => "note",
+            },
+            "message": { "text": diag["message"] },
+            "locations": locations,
+        });
+        if let Some(code) = diag["code"]["code"].as_str() {
+            result["ruleId"] = code.into();
+            result["ruleIndex"] = self.rule_index(code).into();
+        }
+        if let Some(fingerprint) = crate::fingerprint::of(diag) {
+            result["partialFingerprints"] = json!({ "clippy/v1": fingerprint });
+        }
+
+        let children = diag["children"].as_array().map_or(&[][..], Vec::as_slice);
+        let fixes: Vec<Value> = iter::once(diag).chain(children).filter_map(fix).collect();
+        if !fixes.is_empty() {
+            result["fixes"] = fixes.into();
+        }
+
+        // The same diagnostic is emitted once for every target that includes the file.
+        if !self.results.contains(&result) {
+            self.results.push(result);
+        }
+    }
+
+    /// Returns the index of the rule for the lint or error code `code`, adding it if necessary.
+    fn rule_index(&mut self, code: &str) -> usize {
+        if let Some(index) = self.rules.iter().position(|rule| rule["id"] == code) {
+            return index;
+        }
+
+        let mut rule = json!({ "id": code });
+        if let Some(name) = code.strip_prefix("clippy::") {
+            rule["name"] = name.into();
+            rule["helpUri"] = format!("{}#{}", DOCS_LINK, name).into();
+            if let Some(category) = lint_category(name) {
+                rule["properties"] = json!({ "category": category });
+            }
+        }
+        self.rules.push(rule);
+        self.rules.len() - 1
+    }
+
+    fn into_sarif(self) -> Value {
+        json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "clippy",
+                        "informationUri": "https://github.com/rust-lang/rust-clippy",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": self.rules,
+                    },
+                },
+                "results": self.results,
+            }],
+        })
+    }
+}
+
+fn physical_location(span: &Value) -> Value {
+    json!({
+        "artifactLocation": { "uri": span["file_name"] },
+        "region": region(span),
+    })
+}
+
+fn region(span: &Value) -> Value {
+    json!({
+        "startLine": span["line_start"],
+        "startColumn": span["column_start"],
+        "endLine": span["line_end"],
+        "endColumn": span["column_end"],
+    })
+}
+
+/// Converts the suggestion of the diagnostic `diag`, if any, into a SARIF fix.
+fn fix(diag: &Value) -> Option<Value> {
+    let spans: Vec<&Value> = diag["spans"]
+        .as_array()?
+        .iter()
+        .filter(|span| span["suggested_replacement"].is_string())
+        .collect();
+    let applicability = spans.first()?["suggestion_applicability"].clone();
+
+    let mut changes: Vec<Value> = Vec::new();
+    for span in spans {
+        let replacement = json!({
+            "deletedRegion": region(span),
+            "insertedContent": { "text": span["suggested_replacement"] },
+        });
+        match changes
+            .iter_mut()
+            .find(|change| change["artifactLocation"]["uri"] == span["file_name"])
+        {
+            Some(change) => change["replacements"].as_array_mut()?.push(replacement),
+            None => changes.push(json!({
+                "artifactLocation": { "uri": span["file_name"] },
+                "replacements": [replacement],
+            })),
+        }
+    }
+
+    Some(json!({
+        "description": { "text": diag["message"] },
+        "artifactChanges": changes,
+        "properties": { "applicability": applicability },
+    }))
+}
+
+/// Runs `cmd` (a `cargo check` emitting JSON messages) and prints the emitted diagnostics as a
+/// SARIF log to stdout. The rendered diagnostics are printed to stderr.
+pub fn run(cmd: &mut Command) -> Result<(), i32> {
+    let mut log = Log::default();
+    let exit_status = crate::run_with_diagnostics(cmd, |diag| {
+        if let Some(r) = diag["rendered"].as_str() {
+            eprint!("{}", r);
+        }
+        log.add_diagnostic(diag);
+    });
+
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&log.into_sarif()).expect("failed to serialize SARIF log")
+    );
+
+    if exit_status.success() {
+        Ok(())
+    } else {
+        Err(exit_status.code().unwrap_or(-1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lint_category, Log, LINT_CATEGORIES};
+    use serde_json::json;
+
+    #[test]
+    fn lint_categories_sorted() {
+        assert!(LINT_CATEGORIES.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(lint_category("get_last_with_len"), Some("complexity"));
+        assert_eq!(lint_category("unused_variables"), None);
+    }
+
+    #[test]
+    fn convert_diagnostic() {
+        let span = |start, end, replacement: Option<&str>| {
+            json!({
+                "file_name": "src/main.rs", "is_primary": true,
+                "line_start": 3, "line_end": 3, "column_start": start, "column_end": end,
+                "suggested_replacement": replacement,
+                "suggestion_applicability": replacement.map(|_| "MachineApplicable"),
+                "text": [{ "text": "    let _ = x.get(x.len() - 1);", "highlight_start": 13, "highlight_end": 31 }],
+            })
+        };
+        let diag = json!({
+            "message": "accessing last element with `x.get(x.len() - 1)`",
+            "code": { "code": "clippy::get_last_with_len", "explanation": null },
+            "level": "warning",
+            "spans": [span(13, 31, None)],
+            "children": [
+                { "message": "try", "level": "help", "spans": [span(13, 31, Some("x.last()"))], "children": [] },
+            ],
+        });
+
+        let mut log = Log::default();
+        log.add_diagnostic(&diag);
+        log.add_diagnostic(&diag);
+        let sarif = log.into_sarif();
+        let run = &sarif["runs"][0];
+
+        assert_eq!(
+            run["tool"]["driver"]["rules"],
+            json!([{
+                "id": "clippy::get_last_with_len",
+                "name": "get_last_with_len",
+                "helpUri": "https://rust-lang.github.io/rust-clippy/master/index.html#get_last_with_len",
+                "properties": { "category": "complexity" },
+            }])
+        );
+        let region = json!({ "startLine": 3, "startColumn": 13, "endLine": 3, "endColumn": 31 });
+        assert_eq!(
+            run["results"],
+            json!([{
+                "ruleId": "clippy::get_last_with_len",
+                "ruleIndex": 0,
+                "level": "warning",
+                "message": { "text": "accessing last element with `x.get(x.len() - 1)`" },
+                "locations": [{
+                    "physicalLocation": { "artifactLocation": { "uri": "src/main.rs" }, "region": region },
+                }],
+                "partialFingerprints": { "clippy/v1": "clippy::get_last_with_len:src/main.rs:58a6fb8627362804" },
+                "fixes": [{
+                    "description": { "text": "try" },
+                    "artifactChanges": [{
+                        "artifactLocation": { "uri": "src/main.rs" },
+                        "replacements": [{ "deletedRegion": region, "insertedContent": { "text": "x.last()" } }],
+                    }],
+                    "properties": { "applicability": "MachineApplicable" },
+                }],
+            }])
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! The machine applicable suggestions of the diagnostics emitted with `--error-format=json`, as
+//! used by `cargo clippy --fix --dry-run`. This module doesn't depend on the rest of the crate, so
+//! that the suggestion round-trip test in `tests/suggestions.rs` can include it as well.
+
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
+/// A replacement of the bytes `start..end` of a file.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Replacement {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+/// The collected suggestions, grouped by file. Every suggestion consists of one or more
+/// replacements that have to be applied together.
+#[derive(Default)]
+pub struct Suggestions {
+    pub files: BTreeMap<PathBuf, Vec<Vec<Replacement>>>,
+}
+
+impl Suggestions {
+    /// Collects the suggestions of a diagnostic emitted by rustc, which cargo wraps in a
+    /// `compiler-message`.
+    pub fn collect_message(&mut self, message: &Value) {
+        for diag in message["children"].as_array().into_iter().flatten() {
+            self.collect_diagnostic(diag);
+        }
+        self.collect_diagnostic(message);
+    }
+
+    fn collect_diagnostic(&mut self, diag: &Value) {
+        let mut file = None;
+        let mut replacements = Vec::new();
+        for span in diag["spans"].as_array().into_iter().flatten() {
+            let text = match span["suggested_replacement"].as_str() {
+                Some(text) if span["suggestion_applicability"] == "MachineApplicable" => text,
+                _ => continue,
+            };
+            let (file_name, start, end) = match (
+                span["file_name"].as_str(),
+                span["byte_start"]
+                    .as_u64()
+                    .and_then(|start| usize::try_from(start).ok()),
+                span["byte_end"].as_u64().and_then(|end| usize::try_from(end).ok()),
+            ) {
+                (Some(file_name), Some(start), Some(end)) if start <= end => (file_name, start, end),
+                _ => return,
+            };
+            // A suggestion spanning several files is rare enough to not be worth the trouble.
+            if file.get_or_insert(file_name) != &file_name {
+                return;
+            }
+            replacements.push(Replacement {
+                start,
+                end,
+                text: text.to_string(),
+            });
+        }
+
+        if let Some(file) = file {
+            replacements.sort();
+            if replacements.windows(2).all(|w| w[0].end <= w[1].start) {
+                let suggestions = self.files.entry(PathBuf::from(file)).or_default();
+                // The same suggestion is emitted once for every target that includes the file.
+                if !suggestions.contains(&replacements) {
+                    suggestions.push(replacements);
+                }
+            }
+        }
+    }
+}
+
+/// Chooses as many of the `suggestions` for `src` as possible. Suggestions overlapping with a
+/// previously chosen one are skipped. Returns the chosen replacements, sorted by their position,
+/// and the number of chosen suggestions.
+pub fn choose_non_overlapping<'a>(src: &str, suggestions: &'a [Vec<Replacement>]) -> (Vec<&'a Replacement>, usize) {
+    let mut chosen: Vec<&Replacement> = Vec::new();
+    let mut count = 0;
+    for suggestion in suggestions {
+        let fits = suggestion.iter().all(|r| {
+            r.end <= src.len()
+                && src.is_char_boundary(r.start)
+                && src.is_char_boundary(r.end)
+                && chosen.iter().all(|c| r.end <= c.start || c.end <= r.start)
+        });
+        if fits {
+            chosen.extend(suggestion);
+            count += 1;
+        }
+    }
+    chosen.sort();
+    (chosen, count)
+}
+
+/// Applies the sorted, non-overlapping `replacements` to `src`. `cargo clippy --fix` leaves this to
+/// `cargo fix`, only the round-trip test uses it.
+#[allow(dead_code)]
+pub fn apply_replacements(src: &str, replacements: &[&Replacement]) -> String {
+    let mut fixed = String::with_capacity(src.len());
+    let mut pos = 0;
+    for r in replacements {
+        fixed.push_str(&src[pos..r.start]);
+        fixed.push_str(&r.text);
+        pos = r.end;
+    }
+    fixed.push_str(&src[pos..]);
+    fixed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_replacements, choose_non_overlapping, Replacement, Suggestions};
+    use serde_json::json;
+
+    fn replacement(start: usize, end: usize, text: &str) -> Replacement {
+        Replacement {
+            start,
+            end,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn collect_machine_applicable() {
+        let message = json!({
+            "spans": [],
+            "children": [
+                {
+                    "spans": [
+                        { "file_name": "src/lib.rs", "byte_start": 4, "byte_end": 7, "suggested_replacement": "b",
+                          "suggestion_applicability": "MachineApplicable" }
+                    ]
+                },
+                {
+                    "spans": [
+                        { "file_name": "src/lib.rs", "byte_start": 0, "byte_end": 1, "suggested_replacement": "c",
+                          "suggestion_applicability": "MaybeIncorrect" }
+                    ]
+                }
+            ]
+        });
+        let mut suggestions = Suggestions::default();
+        suggestions.collect_message(&message);
+        suggestions.collect_message(&message);
+        let file = &suggestions.files[std::path::Path::new("src/lib.rs")];
+        assert_eq!(file, &vec![vec![replacement(4, 7, "b")]]);
+    }
+
+    #[test]
+    fn skip_overlapping() {
+        let src = "let x = a + b;";
+        let suggestions = vec![
+            vec![replacement(8, 13, "a.add(b)")],
+            vec![replacement(12, 13, "c")],
+            vec![replacement(4, 5, "y")],
+        ];
+        let (replacements, count) = choose_non_overlapping(src, &suggestions);
+        assert_eq!(count, 2);
+        assert_eq!(apply_replacements(src, &replacements), "let y = a.add(b);");
+    }
+}
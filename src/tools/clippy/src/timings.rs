@@ -0,0 +1,185 @@
+//! Support for `cargo clippy -Z clippy-timings`: reports the time spent in every lint pass and the
+//! number of diagnostics emitted by every lint, and writes them to `clippy-timings.json`.
+//!
+//! Every `clippy-driver` invocation writes the timings of its crate to a file in a temporary
+//! directory, which is passed to it in `CLIPPY_ARGS`. As Cargo reruns the driver when
+//! `CLIPPY_ARGS` changes, every crate is checked again.
+
+use serde_json::{json, Value};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+
+/// The file the report is written to, in the current directory.
+const REPORT_FILE: &str = "clippy-timings.json";
+
+/// The number of passes and lints printed in the summary.
+const SUMMARY_LEN: usize = 10;
+
+/// Returns the directory the drivers write their timings to.
+pub fn dir() -> PathBuf {
+    std::env::temp_dir().join(format!("clippy-timings-{}", process::id()))
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Report {
+    /// The time spent in every pass in seconds, by crate and pass name.
+    crates: BTreeMap<String, BTreeMap<String, f64>>,
+    /// The number of diagnostics emitted by every lint.
+    lints: BTreeMap<String, usize>,
+}
+
+impl Report {
+    /// Adds the timings written by a driver, see `write_timings` in `driver.rs`.
+    fn add_crate(&mut self, content: &str) -> Result<(), String> {
+        let value: Value = serde_json::from_str(content).map_err(|e| e.to_string())?;
+        let name = value["crate"].as_str().ok_or("missing `crate`")?;
+        let passes = self.crates.entry(name.to_string()).or_default();
+        for pass in value["passes"].as_array().ok_or("missing `passes`")? {
+            let pass_name = pass["name"].as_str().ok_or("missing `name`")?;
+            *passes.entry(pass_name.to_string()).or_default() += pass["seconds"].as_f64().unwrap_or_default();
+        }
+        Ok(())
+    }
+
+    /// The time spent in every pass in all crates, the slowest first.
+    fn total_passes(&self) -> Vec<(&str, f64)> {
+        let mut totals = BTreeMap::new();
+        for (name, seconds) in self.crates.values().flatten() {
+            *totals.entry(name.as_str()).or_default() += seconds;
+        }
+        sort_passes(totals.into_iter().collect())
+    }
+
+    /// The lints by the number of their diagnostics, the most frequent first.
+    fn sorted_lints(&self) -> Vec<(&str, usize)> {
+        let mut lints: Vec<_> = self.lints.iter().map(|(name, &count)| (name.as_str(), count)).collect();
+        lints.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        lints
+    }
+
+    fn to_json(&self) -> Value {
+        let pass_list = |passes: Vec<(&str, f64)>| -> Vec<Value> {
+            passes
+                .into_iter()
+                .map(|(name, seconds)| json!({ "name": name, "seconds": seconds }))
+                .collect()
+        };
+        let crates: Vec<Value> = self
+            .crates
+            .iter()
+            .map(|(name, passes)| {
+                let passes = passes.iter().map(|(name, &seconds)| (name.as_str(), seconds)).collect();
+                json!({ "name": name, "passes": pass_list(sort_passes(passes)) })
+            })
+            .collect();
+        let lints: Vec<Value> = self
+            .sorted_lints()
+            .into_iter()
+            .map(|(name, count)| json!({ "name": name, "count": count }))
+            .collect();
+        json!({ "passes": pass_list(self.total_passes()), "crates": crates, "lints": lints })
+    }
+}
+
+/// Sorts the passes by their time, the slowest first.
+fn sort_passes(mut passes: Vec<(&str, f64)>) -> Vec<(&str, f64)> {
+    passes.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal).then(a.0.cmp(b.0)));
+    passes
+}
+
+/// Reads the timings the drivers wrote to `dir`.
+fn read_crates(report: &mut Report, dir: &Path) -> Result<(), String> {
+    for entry in fs::read_dir(dir).map_err(|e| e.to_string())? {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| report.add_crate(&content))
+            .map_err(|e| format!("`{}`: {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+/// Runs `cmd` (a `cargo check` emitting JSON messages, whose drivers write their timings to
+/// `dir`), prints the diagnostics and a summary of the timings, and writes the report.
+pub fn run(cmd: &mut Command, dir: &Path) -> Result<(), i32> {
+    if let Err(e) = fs::create_dir_all(dir) {
+        eprintln!("error: failed to create `{}`: {}", dir.display(), e);
+        return Err(1);
+    }
+
+    let mut report = Report::default();
+    let mut seen = HashSet::new();
+    let exit_status = crate::run_with_diagnostics(cmd, |diag| {
+        let rendered = diag["rendered"].as_str().unwrap_or_default();
+        // The same diagnostic is emitted once for every target that includes the file.
+        if !seen.insert(rendered.to_string()) {
+            return;
+        }
+        eprint!("{}", rendered);
+        if let Some(lint) = diag["code"]["code"].as_str() {
+            if !crate::baseline::is_error_code(lint) {
+                *report.lints.entry(lint.to_string()).or_default() += 1;
+            }
+        }
+    });
+
+    let result = read_crates(&mut report, dir);
+    fs::remove_dir_all(dir).ok();
+    if let Err(e) = result {
+        eprintln!("error: failed to read the timings: {}", e);
+        return Err(1);
+    }
+
+    eprintln!("\nSlowest lint passes:");
+    for (name, seconds) in report.total_passes().into_iter().take(SUMMARY_LEN) {
+        eprintln!("{:>10.3}s  {}", seconds, name);
+    }
+    if !report.lints.is_empty() {
+        eprintln!("\nMost frequent lints:");
+        for (name, count) in report.sorted_lints().into_iter().take(SUMMARY_LEN) {
+            eprintln!("{:>10}  {}", count, name);
+        }
+    }
+    let content = serde_json::to_string_pretty(&report.to_json()).expect("failed to serialize the timings");
+    match fs::write(REPORT_FILE, content + "\n") {
+        Ok(()) => eprintln!("\n{:>12} {}", "Wrote", REPORT_FILE),
+        Err(e) => eprintln!("error: failed to write `{}`: {}", REPORT_FILE, e),
+    }
+
+    if exit_status.success() {
+        Ok(())
+    } else {
+        Err(exit_status.code().unwrap_or(-1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Report;
+    use serde_json::json;
+
+    #[test]
+    fn report() {
+        let mut report = Report::default();
+        let lib = r#"{ "crate": "foo", "passes": [{ "name": "Loops", "seconds": 0.5 }, { "name": "Types", "seconds": 0.25 }] }"#;
+        let bin = r#"{ "crate": "bar", "passes": [{ "name": "Types", "seconds": 0.5 }] }"#;
+        report.add_crate(lib).unwrap();
+        report.add_crate(bin).unwrap();
+        assert!(report.add_crate(r#"{ "passes": [] }"#).is_err());
+        assert_eq!(report.total_passes(), vec![("Types", 0.75), ("Loops", 0.5)]);
+
+        report.lints.insert("clippy::needless_return".to_string(), 1);
+        report.lints.insert("clippy::indexing_slicing".to_string(), 3);
+        let json = report.to_json();
+        assert_eq!(json["passes"][0], json!({ "name": "Types", "seconds": 0.75 }));
+        assert_eq!(json["crates"][0]["name"], "bar");
+        assert_eq!(json["crates"][1]["passes"][0]["name"], "Loops");
+        assert_eq!(
+            json["lints"][0],
+            json!({ "name": "clippy::indexing_slicing", "count": 3 })
+        );
+    }
+}
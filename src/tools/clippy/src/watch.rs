@@ -0,0 +1,224 @@
+//! Support for `cargo clippy --watch`: checks the workspace, then checks it again every time a
+//! source file or a configuration file changes. After the first check, only the lint diagnostics
+//! whose primary span overlaps with a line that changed since the previous check are reported.
+//! Errors that aren't lints are always reported, and a change to a `Cargo.toml` or `clippy.toml`
+//! reports all the diagnostics again.
+//!
+//! Every check is a new `cargo check`, made fast by the incremental compilation of the crates
+//! that changed and by the lint cache (see `clippy_lints::lint_cache`), which skips the items that
+//! didn't change and had no lint diagnostics. The compiler session isn't kept alive between the
+//! checks: a session of `rustc_interface` is created for one set of inputs, and its query results
+//! can't be reused for the next one.
+
+use crate::changed::{self, workspace_root};
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How often the workspace is scanned for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The modification times of the watched files of the workspace.
+type Mtimes = BTreeMap<PathBuf, SystemTime>;
+
+/// Whether changes to `path` trigger a new check.
+fn is_watched(path: &Path) -> bool {
+    is_source(path)
+        || matches!(
+            path.file_name().and_then(OsStr::to_str),
+            Some("Cargo.toml" | "clippy.toml" | ".clippy.toml")
+        )
+}
+
+fn is_source(path: &Path) -> bool {
+    path.extension().map_or(false, |ext| ext == "rs")
+}
+
+/// Collects the modification times of the watched files in `dir`, skipping the `target`
+/// directory and hidden directories.
+fn scan(dir: &Path, mtimes: &mut Mtimes) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let file_type = match entry.file_type() {
+            Ok(file_type) => file_type,
+            Err(_) => continue,
+        };
+        if file_type.is_dir() {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if name != "target" && !name.starts_with('.') {
+                scan(&path, mtimes);
+            }
+        } else if is_watched(&path) {
+            if let Ok(mtime) = entry.metadata().and_then(|metadata| metadata.modified()) {
+                mtimes.insert(path, mtime);
+            }
+        }
+    }
+}
+
+/// Returns the lines of `new` that were added or modified since `old`, as an inclusive range of
+/// 1-based line numbers, or `None` if the contents are the same. If lines were only removed, the
+/// line following them is returned.
+fn changed_range(old: &str, new: &str) -> Option<(u64, u64)> {
+    if old == new {
+        return None;
+    }
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let start = prefix as u64 + 1;
+    let end = (new.len() - suffix) as u64;
+    Some((start, end.max(start)))
+}
+
+/// The state of the workspace at the previous check.
+struct Snapshot {
+    mtimes: Mtimes,
+    /// The contents of the Rust source files.
+    sources: HashMap<PathBuf, String>,
+}
+
+impl Snapshot {
+    fn new(root: &Path) -> Self {
+        let mut mtimes = Mtimes::new();
+        scan(root, &mut mtimes);
+        let sources = mtimes
+            .keys()
+            .filter(|path| is_source(path))
+            .filter_map(|path| Some((path.clone(), fs::read_to_string(path).ok()?)))
+            .collect();
+        Self { mtimes, sources }
+    }
+
+    /// Updates the snapshot to the new modification times `mtimes`, and returns the lines that
+    /// changed, or `None` if a configuration file changed.
+    fn update(&mut self, mtimes: Mtimes) -> Option<changed::Lines> {
+        let config_changed = mtimes
+            .iter()
+            .filter(|(path, _)| !is_source(path))
+            .ne(self.mtimes.iter().filter(|(path, _)| !is_source(path)));
+        let mut changed = changed::Lines::default();
+        for (path, mtime) in &mtimes {
+            if !is_source(path) || self.mtimes.get(path) == Some(mtime) {
+                continue;
+            }
+            let new = fs::read_to_string(path).unwrap_or_default();
+            let range = match self.sources.get(path) {
+                Some(old) => changed_range(old, &new),
+                None => Some((1, u64::MAX)),
+            };
+            if let Some(range) = range {
+                changed.add(path.clone(), range);
+            }
+            self.sources.insert(path.clone(), new);
+        }
+        self.sources.retain(|path, _| mtimes.contains_key(path));
+        self.mtimes = mtimes;
+        if config_changed { None } else { Some(changed) }
+    }
+}
+
+/// Marks all the lines of the files in `mtimes` as changed.
+fn all_lines(mtimes: &Mtimes) -> changed::Lines {
+    let mut changed = changed::Lines::default();
+    for path in mtimes.keys() {
+        changed.add(path.clone(), (1, u64::MAX));
+    }
+    changed
+}
+
+/// Waits until a watched file of the workspace is added, removed or modified, and returns the new
+/// modification times once they stop changing.
+fn wait_for_change(root: &Path, mtimes: &Mtimes) -> Mtimes {
+    let mut current = mtimes.clone();
+    while current == *mtimes {
+        thread::sleep(POLL_INTERVAL);
+        current.clear();
+        scan(root, &mut current);
+    }
+    // editors often write a file in several steps
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let mut next = Mtimes::new();
+        scan(root, &mut next);
+        if next == current {
+            return current;
+        }
+        current = next;
+    }
+}
+
+/// Runs `cmd` (a `cargo check` emitting JSON messages) every time the workspace changes, until
+/// the process is interrupted.
+pub fn run(cmd: &mut Command) -> Result<(), i32> {
+    let root = match workspace_root().and_then(|root| fs::canonicalize(root).map_err(|e| e.to_string())) {
+        Ok(root) => root,
+        Err(e) => {
+            eprintln!("error: failed to find the workspace root: {}", e);
+            return Err(1);
+        },
+    };
+
+    let mut snapshot = Snapshot::new(&root);
+    let mut changed = None;
+    loop {
+        let report = match &changed {
+            Some(changed) => changed::report(cmd, &root, changed),
+            None => changed::report(cmd, &root, &all_lines(&snapshot.mtimes)),
+        };
+        if report.suppressed > 0 {
+            eprintln!(
+                "note: {} diagnostic{} outside of the lines changed since the previous check suppressed",
+                report.suppressed,
+                if report.suppressed == 1 { "" } else { "s" },
+            );
+        }
+        eprintln!("{:>12} for changes, press Ctrl-C to stop", "Watching");
+        let mtimes = wait_for_change(&root, &snapshot.mtimes);
+        changed = snapshot.update(mtimes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{changed_range, is_watched};
+    use std::path::Path;
+
+    #[test]
+    fn changed_ranges() {
+        let old = "fn main() {\n    let x = 1;\n    println!(\"{}\", x);\n}\n";
+        assert_eq!(changed_range(old, old), None);
+        let modified = "fn main() {\n    let x = 2;\n    println!(\"{}\", x);\n}\n";
+        assert_eq!(changed_range(old, modified), Some((2, 2)));
+        let added = "fn main() {\n    let x = 1;\n    let y = x;\n    let z = y;\n    println!(\"{}\", x);\n}\n";
+        assert_eq!(changed_range(old, added), Some((3, 4)));
+        let removed = "fn main() {\n    println!(\"{}\", x);\n}\n";
+        assert_eq!(changed_range(old, removed), Some((2, 2)));
+        let appended = format!("{}fn f() {{}}\n", old);
+        assert_eq!(changed_range(old, &appended), Some((5, 5)));
+    }
+
+    #[test]
+    fn watched_files() {
+        assert!(is_watched(Path::new("src/lib.rs")));
+        assert!(is_watched(Path::new("Cargo.toml")));
+        assert!(is_watched(Path::new("crates/a/clippy.toml")));
+        assert!(!is_watched(Path::new("README.md")));
+        assert!(!is_watched(Path::new("Cargo.lock")));
+    }
+}
@@ -8,7 +8,9 @@
 use std::ffi::OsStr;
 use std::fs;
 use std::io;
+use std::panic::{self, AssertUnwindSafe};
 use std::path::{Path, PathBuf};
+use std::process;
 
 mod cargo;
 
@@ -256,12 +258,98 @@ fn prepare_env() {
     //set_var("RUST_BACKTRACE", "0");
 }
 
-#[test]
-fn compile_test() {
+/// Returns the `.rs` files in `dir` and its subdirectories.
+fn test_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(test_files(&path)?);
+        } else if path.extension() == Some(OsStr::new("rs")) {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+/// Returns the paths of the `.stderr`, `.stdout` and `.fixed` files of the tests in `tests/ui*`,
+/// together with the paths compiletest writes the output of the tests to in `build_base`.
+fn reference_files(build_base: &Path) -> io::Result<Vec<(PathBuf, PathBuf)>> {
+    let mut paths = Vec::new();
+    for suite in &["ui", "ui-toml", "ui-cargo", "ui-internal"] {
+        let suite_dir = Path::new("tests").join(suite);
+        for file in test_files(&suite_dir)? {
+            let test_name = file.strip_prefix(&suite_dir).unwrap();
+            for ext in &["stderr", "stdout", "fixed"] {
+                let output_path = build_base.join(test_name.with_extension(format!("stage-id.{}", ext)));
+                paths.push((file.with_extension(ext), output_path));
+            }
+        }
+    }
+    Ok(paths)
+}
+
+/// Removes the output of earlier test runs, so that only the tests that run now are blessed.
+fn remove_outputs(build_base: &Path) -> io::Result<()> {
+    for (_, output_path) in reference_files(build_base)? {
+        if output_path.exists() {
+            fs::remove_file(output_path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Replaces the reference files with the output of the tests, and removes the ones whose output is
+/// empty. Compiletest has already normalized the output, e.g. the paths to `$DIR` and the line
+/// numbers to `LL`.
+fn bless(build_base: &Path) -> io::Result<()> {
+    for (reference_path, output_path) in reference_files(build_base)? {
+        let output = match fs::read(&output_path) {
+            Ok(output) => output,
+            Err(_) => continue,
+        };
+        if output == fs::read(&reference_path).unwrap_or_default() {
+            continue;
+        }
+        if output.is_empty() {
+            println!("removing {}", reference_path.display());
+            fs::remove_file(&reference_path)?;
+        } else {
+            println!("updating {}", reference_path.display());
+            fs::write(&reference_path, output)?;
+        }
+    }
+    Ok(())
+}
+
+/// Runs the UI tests. With `--bless` (`cargo uitest -- --bless`), the expected output of the tests
+/// that fail is updated instead of only being compared with their output.
+fn main() {
+    let bless_output = env::args().skip(1).any(|arg| arg == "--bless");
+
     prepare_env();
     let mut config = default_config();
-    run_mode(&mut config);
-    run_ui_toml(&mut config);
-    run_ui_cargo(&mut config);
-    run_internal_tests(&mut config);
+    if bless_output {
+        if let Err(e) = remove_outputs(&config.build_base) {
+            panic!("I/O failure during blessing: {:?}", e);
+        }
+    }
+    let suites: [fn(&mut compiletest::Config); 4] = [run_mode, run_ui_toml, run_ui_cargo, run_internal_tests];
+    let mut passed = true;
+    for run_suite in &suites {
+        // every suite panics if one of its tests fails, keep going to bless all of them
+        passed &= panic::catch_unwind(AssertUnwindSafe(|| run_suite(&mut config))).is_ok();
+    }
+
+    if bless_output {
+        if let Err(e) = bless(&config.build_base) {
+            panic!("I/O failure during blessing: {:?}", e);
+        }
+        if !passed {
+            println!("note: the expected output was updated, rerun the tests to check that they pass now");
+        }
+    }
+    if !passed {
+        process::exit(1);
+    }
 }
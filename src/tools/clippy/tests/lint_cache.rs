@@ -0,0 +1,170 @@
+//! Checks the lint cache of `cargo clippy --cache`: the items without diagnostics are skipped by
+//! the next run until they or the crate change, and the passes that need to see every item of the
+//! crate still see the skipped ones.
+
+#![feature(once_cell)]
+
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+mod cargo;
+
+fn clippy_driver_path() -> PathBuf {
+    option_env!("CLIPPY_DRIVER_PATH").map_or(cargo::TARGET_LIB.join("clippy-driver"), PathBuf::from)
+}
+
+/// The directory of a test, with the checked `lib.rs`, the `clippy.toml` and the cache.
+struct TestDir(PathBuf);
+
+impl TestDir {
+    fn new(name: &str) -> Self {
+        let dir = cargo::CARGO_TARGET_DIR.join("lint_cache").join(name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("out")).unwrap();
+        fs::write(dir.join("clippy.toml"), "").unwrap();
+        Self(dir)
+    }
+
+    fn write(&self, file: &str, contents: &str) {
+        fs::write(self.0.join(file), contents).unwrap();
+    }
+
+    /// Checks `lib.rs` with the cache and returns the names of the emitted Clippy lints.
+    fn check(&self, lints: &[&str]) -> Vec<String> {
+        let output = Command::new(clippy_driver_path())
+            .arg(self.0.join("lib.rs"))
+            .args(&["--crate-type=lib", "--emit=metadata", "--error-format=json"])
+            // the cache is written next to the output directory, like Cargo's `target/debug`
+            .arg("--out-dir")
+            .arg(self.0.join("out"))
+            .args(lints.iter().flat_map(|lint| vec!["-W", lint]))
+            .env("CLIPPY_ARGS", "--clippy-cache__CLIPPY_HACKERY__")
+            .env("CLIPPY_CONF_DIR", &self.0)
+            .env("CLIPPY_DISABLE_DOCS_LINKS", "true")
+            .output()
+            .expect("failed to run clippy-driver");
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        String::from_utf8_lossy(&output.stderr)
+            .lines()
+            .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+            .filter_map(|diag| diag["code"]["code"].as_str().map(ToString::to_string))
+            .filter(|code| code.starts_with("clippy::"))
+            .collect()
+    }
+
+    /// The lines of the cache file of the crate: the hash of the crate, then the hashes of the
+    /// items without diagnostics.
+    fn cache(&self) -> Vec<String> {
+        let cache_dir = self.0.join("clippy-cache");
+        let file = fs::read_dir(&cache_dir).unwrap().next().unwrap().unwrap().path();
+        fs::read_to_string(file)
+            .unwrap()
+            .lines()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn write_cache(&self, lines: &[String]) {
+        let cache_dir = self.0.join("clippy-cache");
+        let file = fs::read_dir(&cache_dir).unwrap().next().unwrap().unwrap().path();
+        fs::write(file, lines.join("\n") + "\n").unwrap();
+    }
+}
+
+impl Drop for TestDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+const TOO_MANY_ARGUMENTS: &str = "pub fn clean() {}\n\npub fn many(a: u8, b: u8, c: u8) {}\n";
+
+#[test]
+fn items_with_diagnostics_are_checked_again() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    let dir = TestDir::new("items_with_diagnostics_are_checked_again");
+    dir.write("clippy.toml", "too-many-arguments-threshold = 2\n");
+    dir.write("lib.rs", TOO_MANY_ARGUMENTS);
+
+    assert_eq!(dir.check(&[]), ["clippy::too_many_arguments"]);
+    let cache = dir.cache();
+
+    // `many` isn't in the cache, so it's checked again and the cache stays the same
+    assert_eq!(dir.check(&[]), ["clippy::too_many_arguments"]);
+    assert_eq!(dir.cache(), cache);
+}
+
+#[test]
+fn clean_items_are_skipped_until_the_configuration_changes() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    let dir = TestDir::new("clean_items_are_skipped_until_the_configuration_changes");
+    dir.write("lib.rs", TOO_MANY_ARGUMENTS);
+    assert!(dir.check(&[]).is_empty());
+    let clean_items = dir.cache().split_off(1);
+
+    // changing the configuration clears the cache
+    dir.write("clippy.toml", "too-many-arguments-threshold = 2\n");
+    assert_eq!(dir.check(&[]), ["clippy::too_many_arguments"]);
+
+    // with the hash of the new configuration and the items of the first run, `many` is skipped
+    let mut cache = dir.cache();
+    cache.truncate(1);
+    cache.extend(clean_items);
+    dir.write_cache(&cache);
+    assert!(dir.check(&[]).is_empty());
+}
+
+#[test]
+fn changed_items_are_checked_again() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    let dir = TestDir::new("changed_items_are_checked_again");
+    dir.write("lib.rs", "pub fn f() -> u8 {\n    1\n}\n");
+    assert!(dir.check(&[]).is_empty());
+    let cache = dir.cache();
+
+    // the body of a function isn't part of the hash of the crate, only of the item
+    dir.write("lib.rs", "pub fn f() -> u8 {\n    let x = 1;\n    x\n}\n");
+    assert_eq!(dir.check(&[]), ["clippy::let_and_return"]);
+    assert_eq!(dir.cache()[0], cache[0]);
+}
+
+#[test]
+fn items_with_changed_hir_are_checked_again() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    let dir = TestDir::new("items_with_changed_hir_are_checked_again");
+    let src = "macro_rules! value {\n    () => {\n        1\n    };\n}\n\npub fn f() -> u8 {\n    value!()\n}\n";
+    dir.write("lib.rs", src);
+    assert!(dir.check(&[]).is_empty());
+    let cache = dir.cache();
+
+    // the source of `f` is the same, but its HIR isn't
+    dir.write("lib.rs", &src.replace("        1\n", "        2\n"));
+    assert!(dir.check(&[]).is_empty());
+    let new_cache = dir.cache();
+    assert_eq!(new_cache[0], cache[0]);
+    assert!(new_cache[1..].iter().any(|item| !cache.contains(item)));
+}
+
+#[test]
+fn whole_crate_passes_see_skipped_items() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    let dir = TestDir::new("whole_crate_passes_see_skipped_items");
+    dir.write("lib.rs", "pub struct S;\n\nimpl S {}\n\nimpl S {}\n");
+    let lints = ["clippy::multiple_inherent_impl"];
+
+    assert_eq!(dir.check(&lints), lints);
+    // the second run skips the clean items, but `MultipleInherentImpl` still sees the first `impl`
+    assert_eq!(dir.check(&lints), lints);
+}
@@ -0,0 +1,168 @@
+//! Checks that the machine applicable suggestions of Clippy's lints in the UI tests produce code
+//! that still compiles: every test is compiled with `--error-format=json`, the suggestions are
+//! applied to a copy of it like `cargo clippy --fix` does, and the copy is compiled again.
+//!
+//! Tests that don't compile on their own (e.g. because they need auxiliary crates) are skipped.
+//! Use `TESTNAME=foo,bar` to only check the tests whose name contains `foo` or `bar`.
+
+#![feature(once_cell)]
+
+use serde_json::Value;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+mod cargo;
+
+#[path = "../src/suggestions.rs"]
+mod suggestions;
+
+use suggestions::{apply_replacements, choose_non_overlapping, Suggestions};
+
+fn clippy_driver_path() -> PathBuf {
+    option_env!("CLIPPY_DRIVER_PATH").map_or(cargo::TARGET_LIB.join("clippy-driver"), PathBuf::from)
+}
+
+/// The header comments of a UI test that affect how it is compiled.
+#[derive(Default)]
+struct Headers {
+    edition: Option<String>,
+    compile_flags: Vec<String>,
+}
+
+impl Headers {
+    fn parse(src: &str) -> Self {
+        let mut headers = Self::default();
+        for line in src.lines().map(str::trim).filter(|line| line.starts_with("//")) {
+            let line = line.trim_start_matches('/').trim();
+            if let Some(edition) = line.strip_prefix("edition:") {
+                headers.edition = Some(edition.trim().to_string());
+            } else if let Some(flags) = line.strip_prefix("compile-flags:") {
+                headers
+                    .compile_flags
+                    .extend(flags.split_whitespace().map(ToString::to_string));
+            }
+        }
+        headers
+    }
+}
+
+/// Compiles `file` with the lints capped at `warn`, returning the emitted diagnostics.
+fn compile(file: &Path, headers: &Headers, out_dir: &Path) -> Vec<Value> {
+    let output = Command::new(clippy_driver_path())
+        .arg(file)
+        .args(&["--emit=metadata", "--error-format=json", "--cap-lints=warn"])
+        .args(&["--edition", headers.edition.as_deref().unwrap_or("2015")])
+        .arg("--out-dir")
+        .arg(out_dir)
+        .args(&headers.compile_flags)
+        .env("CLIPPY_DISABLE_DOCS_LINKS", "true")
+        .output()
+        .expect("failed to run clippy-driver");
+    String::from_utf8_lossy(&output.stderr)
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+fn is_error(diag: &Value) -> bool {
+    diag["level"].as_str().map_or(false, |level| level.starts_with("error"))
+}
+
+/// The lints whose diagnostics in `diags` have a machine applicable suggestion.
+fn lints_with_suggestions(diags: &[Value]) -> Vec<&str> {
+    let mut lints: Vec<&str> = diags
+        .iter()
+        .filter(|diag| {
+            diag.to_string()
+                .contains(r#""suggestion_applicability":"MachineApplicable""#)
+        })
+        .filter_map(|diag| diag["code"]["code"].as_str())
+        .collect();
+    lints.sort_unstable();
+    lints.dedup();
+    lints
+}
+
+/// Applies the suggestions for the UI test `file` and compiles the result. Returns a description
+/// of the errors if the result doesn't compile.
+fn check_suggestions(file: &Path, out_dir: &Path) -> Result<(), String> {
+    let src = fs::read_to_string(file).unwrap();
+    let headers = Headers::parse(&src);
+    let diags = compile(file, &headers, out_dir);
+    if diags.iter().any(is_error) {
+        return Ok(());
+    }
+
+    // the suggestions of rustc's lints, e.g. `unused_variables`, aren't checked
+    let diags: Vec<Value> = diags
+        .into_iter()
+        .filter(|diag| {
+            diag["code"]["code"]
+                .as_str()
+                .map_or(false, |code| code.starts_with("clippy::"))
+        })
+        .collect();
+    let mut suggestions = Suggestions::default();
+    for diag in &diags {
+        suggestions.collect_message(diag);
+    }
+    let (replacements, count) = match suggestions.files.get(file) {
+        Some(file_suggestions) => choose_non_overlapping(&src, file_suggestions),
+        None => return Ok(()),
+    };
+    if count == 0 {
+        return Ok(());
+    }
+
+    let fixed_file = out_dir.join(file.file_name().unwrap());
+    fs::write(&fixed_file, apply_replacements(&src, &replacements)).unwrap();
+    let fixed_diags = compile(&fixed_file, &headers, out_dir);
+    let errors: Vec<&str> = fixed_diags
+        .iter()
+        .filter(|diag| is_error(diag))
+        .filter_map(|diag| diag["rendered"].as_str())
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{}: applying the suggestions of {} makes the code fail to compile:\n{}",
+            file.display(),
+            lints_with_suggestions(&diags).join(", "),
+            errors.concat()
+        ))
+    }
+}
+
+#[test]
+fn suggestions_compile() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    let filters: Vec<String> = env::var("TESTNAME")
+        .map(|filters| filters.split(',').map(ToString::to_string).collect())
+        .unwrap_or_default();
+
+    let root_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let out_dir = cargo::CARGO_TARGET_DIR.join("suggestions");
+    let mut failures = Vec::new();
+    for entry in fs::read_dir(root_dir.join("tests/ui")).unwrap() {
+        let file = entry.unwrap().path();
+        let name = file.file_stem().unwrap().to_string_lossy().into_owned();
+        if file.extension().map_or(true, |ext| ext != "rs")
+            || (!filters.is_empty() && !filters.iter().any(|filter| name.contains(filter)))
+        {
+            continue;
+        }
+        // every test gets its own directory, so that the outputs of different tests don't collide
+        let test_dir = out_dir.join(&name);
+        fs::create_dir_all(&test_dir).unwrap();
+        if let Err(failure) = check_suggestions(&file, &test_dir) {
+            failures.push(failure);
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+}
@@ -0,0 +1,10 @@
+// compile-flags: --fix
+// run-rustfix
+#![warn(clippy::out_of_bounds_indexing)]
+#![allow(clippy::no_effect, unused)]
+
+fn main() {
+    let x = [1, 2, 3, 4];
+    // the suggestion is `MaybeIncorrect`, `fix-applicability` makes it machine applicable
+    let _ = &x[1..9];
+}
@@ -0,0 +1,30 @@
+// compile-flags: --test
+#![warn(clippy::indexing_slicing)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, dead_code)]
+
+fn index(x: &[u8], i: usize) -> u8 {
+    x[i]
+}
+
+#[test]
+fn test_index() {
+    let x = vec![1, 2, 3];
+    let i = 1;
+    assert_eq!(x[i], 2);
+    &x[i..];
+}
+
+#[cfg(test)]
+mod tests {
+    fn helper(x: &[u8], i: usize) -> &[u8] {
+        &x[..i]
+    }
+
+    #[test]
+    fn test_helper() {
+        let x = vec![1, 2, 3];
+        let i = 2;
+        super::index(&x, i);
+        x[i];
+    }
+}
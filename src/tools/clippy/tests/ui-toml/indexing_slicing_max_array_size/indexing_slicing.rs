@@ -0,0 +1,19 @@
+#![warn(clippy::indexing_slicing)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation)]
+
+const TABLE: [u8; 16] = [0; 16];
+
+fn small(ring: &mut [u32; 8], table: &[u8; 16], i: usize) {
+    ring[i % 8] = 1;
+    table[i];
+    TABLE[i];
+    &table[i..];
+}
+
+fn large(buf: [u8; 17], v: &[u8], i: usize) {
+    buf[i];
+    &buf[..i];
+    v[i];
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+#![warn(clippy::indexing_slicing)]
+
+#[path = "auxiliary/generated.rs"]
+mod generated;
+
+mod checked {
+    pub fn first(x: &[u8], y: Option<u8>) -> u8 {
+        x[0] + y.unwrap()
+    }
+
+    pub mod relaxed {
+        pub fn first(x: &[u8], y: Option<u8>) -> u8 {
+            x[0] + y.unwrap()
+        }
+    }
+
+    #[warn(clippy::indexing_slicing)]
+    pub fn second(x: &[u8]) -> u8 {
+        x[1]
+    }
+}
+
+#[warn(clippy::indexing_slicing)]
+mod attrs {
+    pub fn first(x: &[u8]) -> u8 {
+        x[0]
+    }
+}
+
+fn main() {
+    let x = [1, 2, 3];
+    let _ = &x[..];
+    let v = vec![1, 2, 3];
+    let _ = v[0] + generated::first(&x) + checked::first(&x, None) + checked::relaxed::first(&x, None);
+    let _ = checked::second(&x) + attrs::first(&x);
+}
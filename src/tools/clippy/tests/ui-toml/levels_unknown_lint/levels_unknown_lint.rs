@@ -0,0 +1,3 @@
+// error-pattern: unknown lint `not_a_lint` in `levels`
+
+fn main() {}
@@ -0,0 +1,3 @@
+// error-pattern: could not load the Clippy plugin
+
+fn main() {}
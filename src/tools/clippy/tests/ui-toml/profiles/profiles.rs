@@ -0,0 +1,19 @@
+// compile-flags: --profile=review
+
+fn too_many_lines() {
+    println!("a");
+    println!("b");
+    println!("c");
+}
+
+fn main() {
+    let x: Option<u32> = None;
+    x.unwrap();
+}
+
+#[allow(clippy::too_many_lines)]
+fn allowed() {
+    println!("a");
+    println!("b");
+    println!("c");
+}
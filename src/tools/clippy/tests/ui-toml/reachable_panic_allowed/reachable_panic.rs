@@ -0,0 +1,37 @@
+// compile-flags: -Clink-arg=-nostartfiles
+// ignore-macos
+// ignore-windows
+
+#![feature(lang_items, start, libc)]
+#![no_std]
+#![warn(clippy::reachable_panic)]
+
+use core::panic::PanicInfo;
+
+mod debug {
+    pub fn dump(bytes: &[u8]) -> u8 {
+        // Ok, `dump` is allowed
+        bytes[0] + checksum(bytes)
+    }
+
+    fn checksum(bytes: &[u8]) -> u8 {
+        // Ok, only called by `dump`
+        bytes[1]
+    }
+}
+
+#[start]
+fn main(argc: isize, _argv: *const *const u8) -> isize {
+    let bytes = [argc as u8, 2];
+    let x = debug::dump(&bytes);
+    (x / bytes[1]) as isize
+}
+
+#[allow(clippy::empty_loop)]
+#[panic_handler]
+fn panic(_info: &PanicInfo) -> ! {
+    loop {}
+}
+
+#[lang = "eh_personality"]
+extern "C" fn eh_personality() {}
@@ -0,0 +1,21 @@
+// `indexing_slicing` is denied by default as a correctness lint
+fn index(v: &[u32]) -> u32 {
+    v[0]
+}
+
+#[allow(clippy::correctness)]
+fn allowed(v: &[u32]) -> u32 {
+    v[0]
+}
+
+// `needless_return` is allowed by default as a pedantic lint
+fn needless_return() -> u32 {
+    return 1;
+}
+
+#[warn(clippy::pedantic)]
+fn pedantic() -> u32 {
+    return 1;
+}
+
+fn main() {}
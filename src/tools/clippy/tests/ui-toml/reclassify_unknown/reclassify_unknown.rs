@@ -0,0 +1,3 @@
+// error-pattern: unknown lint `indexing_slicingg` in `reclassify`
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![warn(clippy::repeated_indexing)]
+
+fn sum(v: &[u32], i: usize) -> u32 {
+    v[i + 1] + v[i + 1]
+}
+
+fn ok(v: &[u32], i: usize) -> u32 {
+    v[i + 1] + v[i + 2]
+}
+
+fn main() {}
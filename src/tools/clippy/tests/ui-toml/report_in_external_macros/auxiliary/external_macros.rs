@@ -0,0 +1,30 @@
+//! Used to test that lints in imported external macros are reported at the macro calls
+
+pub struct Point {
+    pub x: i32,
+}
+
+#[macro_export]
+macro_rules! cast_u64 {
+    ($e:expr) => {
+        $e as u64
+    };
+}
+
+#[macro_export]
+macro_rules! is_first_one {
+    ($v:expr) => {
+        match $v[0] {
+            1 => true,
+            _ => false,
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! point {
+    ($e:expr) => {{
+        let x = $e;
+        $crate::Point { x: x }
+    }};
+}
@@ -0,0 +1,11 @@
+// aux-build:external_macros.rs
+#![warn(clippy::as_conversions, clippy::redundant_field_names)]
+
+#[macro_use]
+extern crate external_macros;
+
+fn main() {
+    // the early lint passes run before the names of the other crates are known
+    let _ = cast_u64!(1u32);
+    let _ = point!(1);
+}
@@ -0,0 +1,23 @@
+// aux-build:external_macros.rs
+#![warn(clippy::match_on_vec_items)]
+
+#[macro_use]
+extern crate external_macros;
+
+macro_rules! local_is_first_one {
+    ($v:expr) => {
+        match $v[0] {
+            1 => true,
+            _ => false,
+        }
+    };
+}
+
+fn main() {
+    let v = vec![1];
+    // the suggestion would change the macro, so it is dropped
+    let _ = is_first_one!(v);
+
+    // local macros are linted as before
+    let _ = local_is_first_one!(v);
+}
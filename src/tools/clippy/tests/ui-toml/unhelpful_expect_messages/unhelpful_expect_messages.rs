@@ -0,0 +1,13 @@
+#![warn(clippy::unhelpful_expect_message)]
+
+fn main() {
+    let opt: Option<u32> = Some(1);
+
+    let _ = opt.expect("should work");
+    let _ = opt.expect("BUG");
+    let _ = opt.expect("");
+
+    // the default patterns are replaced
+    let _ = opt.expect("expect failed");
+    let _ = opt.expect("should work, the value is set above");
+}
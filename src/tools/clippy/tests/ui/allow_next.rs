@@ -0,0 +1,31 @@
+#![warn(clippy::indexing_slicing, clippy::unwrap_used)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation)]
+
+macro_rules! first {
+    ($v:expr) => {
+        $v[0]
+    };
+}
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let o = Some(1);
+
+    #[clippy::allow_next(indexing_slicing)]
+    let _ = (v[0], v[1]);
+    #[clippy::allow_next(clippy::indexing_slicing, unwrap_used)]
+    let _ = v[o.unwrap()];
+    #[clippy::allow_next(indexing_slicing)]
+    println!("{}", v[0]);
+    #[allow(clippy::indexing_slicing)]
+    println!("{}", v[1]);
+    #[clippy::allow_next(indexing_slicing)]
+    let _ = first!(v);
+
+    // only the listed lints are allowed
+    #[clippy::allow_next(indexing_slicing)]
+    let _ = v[o.unwrap()];
+    // only in the next statement
+    println!("{}", v[2]);
+    let _ = first!(v);
+}
@@ -0,0 +1,22 @@
+#![deny(clippy::arithmetic_safety)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation)]
+
+fn arithmetic(v: &[u8], a: u32, b: u32, x: i64) -> u32 {
+    let sum = a + b;
+    let ratio = a / b;
+    let small = x as u8;
+    v[a as usize - 1];
+    sum + ratio + u32::from(small)
+}
+
+#[allow(clippy::integer_arithmetic)]
+fn allowed(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+fn main() {
+    // indexing isn't arithmetic
+    let v = [1, 2, 3];
+    let i = 1;
+    v[i];
+}
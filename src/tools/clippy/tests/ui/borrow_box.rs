@@ -104,6 +104,9 @@ pub fn test19<'a>(_display: &'a Box<impl Display + 'a>) {}
 // Even though the current implementation doesn't put extra parentheses,
 // it's fine that unnecessary parentheses appear in the future for some reason.
 pub fn test20(_display: &Box<(dyn Display + Send)>) {}
+// Bare trait objects stay bare in the 2015 edition, see `borrow_box_dyn.rs` for the 2018 edition.
+pub fn test21(_display: &Box<Display>) {}
+pub fn test22(_display: &Box<Display + Send>) {}
 
 fn main() {
     test1(&mut Box::new(false));
@@ -0,0 +1,12 @@
+// edition:2018
+#![deny(clippy::borrowed_box)]
+#![allow(bare_trait_objects)]
+
+use std::fmt::Display;
+
+pub fn test1(_display: &Box<Display>) {}
+pub fn test2(_display: &Box<Display + Send>) {}
+pub fn test3<'a>(_display: &'a Box<Display + 'a>) {}
+pub fn test4(_display: &Box<dyn Display>) {}
+
+fn main() {}
@@ -0,0 +1,38 @@
+#![warn(clippy::chained_indexing)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, clippy::needless_range_loop)]
+
+fn main() {
+    let mut grid = vec![vec![0u8; 3]; 3];
+    let (i, j, k) = (1, 2, 0);
+
+    grid[i][j];
+    let _ = &grid[i][j];
+    let _ = &mut grid[i][j];
+    let cube = vec![grid.clone(); 2];
+    let _ = &cube[k][i][j];
+    let slices: &[&[u8]] = &[&[1, 2], &[3]];
+    let _ = &slices[i][j];
+    let arrays = [[1, 2], [3, 4]];
+    let _ = &arrays[i][j];
+    let r = 1;
+    let _ = &grid[i][r];
+
+    // place expressions only get a help message
+    grid[i][j] = 1;
+    grid[i][j].to_string();
+
+    // Ok
+    &grid[i];
+    &grid[i][..j];
+    arrays[1][0];
+    let strings = vec![String::from("a")];
+    &strings[0][..1];
+    let range = 0..j;
+    &grid[i][range];
+    if i < grid.len() && j < grid[i].len() {
+        grid[i][j];
+    }
+    for i in 0..2 {
+        arrays[i][1];
+    }
+}
@@ -0,0 +1,34 @@
+#![warn(clippy::chars_nth_loop)]
+#![allow(clippy::needless_range_loop)]
+
+fn main() {
+    let s = "hello";
+    let words = ["a", "b"];
+
+    for i in 0..s.len() {
+        let _ = s.chars().nth(i);
+        let _ = s.chars().nth(i + 1).unwrap();
+    }
+
+    let mut line = String::from("abc");
+    for i in (0..3).rev() {
+        if line.chars().nth(i) == Some('c') {
+            line.push('d');
+        }
+    }
+
+    for i in 0..3 {
+        let _: Vec<_> = (0..2).map(|j| s.chars().nth(i + j)).collect();
+    }
+
+    // Ok
+    for i in 0..s.len() {
+        let _ = s.chars().nth(3);
+        let _ = words[i].chars().nth(i);
+    }
+    for _ in 0..3 {
+        let i = 2;
+        let _ = s.chars().nth(i);
+    }
+    let _ = s.chars().nth(2);
+}
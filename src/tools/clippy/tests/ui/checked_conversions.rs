@@ -2,6 +2,7 @@
 
 #![allow(
     clippy::cast_lossless,
+    clippy::manual_range_contains,
     // Int::max_value will be deprecated in the future
     deprecated,
 )]
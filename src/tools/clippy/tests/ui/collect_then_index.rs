@@ -0,0 +1,19 @@
+#![warn(clippy::collect_then_index)]
+#![allow(clippy::indexing_slicing, clippy::iter_nth_zero)]
+
+fn main() {
+    let names = ["a", "bc", "def"];
+    let n = 1;
+
+    let _ = names.iter().map(|name| name.len()).collect::<Vec<_>>()[0];
+    let _ = names.iter().map(|name| name.len()).collect::<Vec<usize>>()[2];
+    let _ = &names.iter().copied().filter(|name| name.len() > 1).collect::<Vec<_>>()[n];
+    let lens: Vec<usize> = names.iter().map(|name| name.len()).collect();
+    let _ = lens[0];
+
+    // slicing
+    let _ = names.iter().collect::<Vec<_>>()[..2].len();
+    // not a `Vec`
+    let _ = names.iter().copied().collect::<String>().len();
+    let _ = names.iter().map(|name| (*name, 1)).collect::<std::collections::HashMap<_, _>>()["a"];
+}
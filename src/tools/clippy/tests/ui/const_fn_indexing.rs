@@ -0,0 +1,54 @@
+#![warn(clippy::const_fn_indexing)]
+#![allow(clippy::many_single_char_names)]
+
+const DIGITS: &[u8] = b"0123456789";
+
+const fn nth_digit(i: usize) -> u8 {
+    b"0123456789"[i]
+}
+
+const fn first(s: &[u8]) -> u8 {
+    s[0]
+}
+
+const fn get(a: [u32; 4], i: usize) -> u32 {
+    a[i]
+}
+
+const fn digit(i: usize) -> u8 {
+    DIGITS[i]
+}
+
+const fn masked_too_wide(table: [u8; 16], i: usize) -> u8 {
+    table[i & 31]
+}
+
+// Ok
+const fn guarded(s: &[u8], i: usize) -> u8 {
+    if i < s.len() { s[i] } else { 0 }
+}
+
+const fn guarded_literal(i: usize) -> u8 {
+    let digits = b"0123456789";
+    if i < digits.len() { digits[i] } else { b'?' }
+}
+
+const fn constant_index(a: [u32; 4]) -> u32 {
+    a[3]
+}
+
+const fn masked(table: [u8; 16], i: usize) -> u8 {
+    table[i & 15]
+}
+
+const fn remainder(table: &[u8; 16], i: usize) -> u8 {
+    table[i % 16]
+}
+
+fn not_const(s: &[u8], i: usize) -> u8 {
+    s[i]
+}
+
+const ITEM: u8 = DIGITS[1];
+
+fn main() {}
@@ -0,0 +1,27 @@
+#![warn(clippy::construct_then_index)]
+
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+fn main() {
+    let s = "hello";
+    let v = vec![1, 2, 3];
+    let i = 2;
+
+    let _ = &Cow::from(s)[1..3];
+    let _ = &Cow::Borrowed(s)[..i];
+    let _ = Box::new([1, 2, 3])[i];
+    let _ = Rc::new(v.clone())[i];
+    let _ = Arc::<[i32]>::from(v.clone())[0];
+    let _ = &Box::<str>::from(s)[1..];
+
+    // bound first
+    let cow = Cow::from(s);
+    let _ = &cow[1..3];
+    // not a smart pointer
+    let _ = &String::from(s)[1..3];
+    // not a constructor
+    let _ = &s.to_owned()[1..3];
+    let _ = Rc::clone(&Rc::new(v))[0];
+}
@@ -0,0 +1,70 @@
+// compile-flags: -Clink-arg=-nostartfiles
+// ignore-macos
+// ignore-windows
+
+#![feature(lang_items, start, libc)]
+#![no_std]
+#![warn(clippy::reachable_panic)]
+
+use core::panic::PanicInfo;
+
+static TABLE: [u8; 4] = [1, 2, 3, 4];
+
+trait Sensor {
+    fn read(&self) -> u8;
+}
+
+struct Thermometer(u8);
+
+impl Sensor for Thermometer {
+    fn read(&self) -> u8 {
+        TABLE[self.0 as usize]
+    }
+}
+
+fn parse(bytes: &[u8]) -> u8 {
+    let first = bytes.first().copied().unwrap();
+    first / bytes[1]
+}
+
+fn scale(x: u8) -> u8 {
+    // Ok, constant divisor and index
+    x / 2 + TABLE[3]
+}
+
+#[allow(clippy::reachable_panic)]
+fn checked(bytes: &[u8]) -> u8 {
+    if bytes.is_empty() { 0 } else { bytes[0] }
+}
+
+fn unreachable(bytes: &[u8]) -> u8 {
+    // Ok, not called
+    bytes[0]
+}
+
+fn sensor(s: &Thermometer) -> u8 {
+    s.read()
+}
+
+#[no_mangle]
+pub extern "C" fn interrupt_handler(x: u32) -> u32 {
+    100 % x
+}
+
+#[start]
+fn main(argc: isize, _argv: *const *const u8) -> isize {
+    let bytes = [argc as u8, 2];
+    let x = parse(&bytes) + scale(1) + checked(&bytes) + sensor(&Thermometer(argc as u8));
+    let _ = Some(x).expect("no value");
+    0
+}
+
+#[allow(clippy::empty_loop)]
+#[panic_handler]
+fn panic(_info: &PanicInfo) -> ! {
+    let _ = TABLE[_info.location().map_or(0, |l| l.line() as usize)];
+    loop {}
+}
+
+#[lang = "eh_personality"]
+extern "C" fn eh_personality() {}
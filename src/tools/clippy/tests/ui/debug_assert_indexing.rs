@@ -0,0 +1,22 @@
+#![warn(clippy::debug_assert_indexing)]
+
+fn lint(v: &[u32], i: usize, arr: [u32; 4]) {
+    debug_assert!(v[i] > 0);
+    debug_assert_eq!(v[0], 1);
+    debug_assert_ne!(v[1..3].len(), 1);
+    // an index of an array that isn't constant
+    debug_assert!(arr[i] > 0);
+}
+
+fn no_lint(v: &[u32], i: usize, arr: [u32; 4]) {
+    // the same indexing is done outside of the assertion
+    debug_assert!(v[i] > 0);
+    let _ = v[i] + 1;
+    // rustc checks constant indexes of arrays
+    debug_assert_eq!(arr[3], 1);
+    // not a debug assertion
+    assert!(v[2] > 0);
+    debug_assert!(v.get(4) == Some(&1));
+}
+
+fn main() {}
@@ -0,0 +1,61 @@
+#![warn(clippy::ffi_unchecked_index)]
+#![allow(clippy::missing_safety_doc, clippy::not_unsafe_ptr_arg_deref)]
+
+use std::slice;
+
+#[no_mangle]
+pub extern "C" fn nth(data: *const u32, len: usize, n: usize) -> u32 {
+    let data = unsafe { slice::from_raw_parts(data, len) };
+    data[n]
+}
+
+#[no_mangle]
+pub extern "C" fn nth_checked(data: *const u32, len: usize, n: usize) -> u32 {
+    let data = unsafe { slice::from_raw_parts(data, len) };
+    if n < data.len() { data[n] } else { 0 }
+}
+
+#[no_mangle]
+pub extern "C" fn first(data: *const u32, len: usize) -> u32 {
+    let data = unsafe { slice::from_raw_parts(data, len) };
+    data[0]
+}
+
+#[no_mangle]
+pub extern "C" fn head(data: *const u8, len: usize, n: usize) -> usize {
+    let data = unsafe { slice::from_raw_parts(data, len) };
+    let end = n + 1;
+    data[..end].len()
+}
+
+#[no_mangle]
+pub extern "C" fn head_checked(data: *const u8, len: usize, n: usize) -> usize {
+    let data = unsafe { slice::from_raw_parts(data, len) };
+    if n <= data.len() { data[..n].len() } else { 0 }
+}
+
+#[no_mangle]
+pub extern "C" fn boxed(n: usize) -> u8 {
+    let data: Box<[u8]> = vec![0; 16].into_boxed_slice();
+    data[n]
+}
+
+// Rust ABI, but takes a raw pointer and a length
+pub unsafe fn sum(data: *const u32, len: usize, skip: usize) -> u32 {
+    let data = slice::from_raw_parts(data, len);
+    data[skip..].iter().sum()
+}
+
+// not at the FFI boundary
+pub fn nth_rust(data: &[u32], n: usize) -> u32 {
+    data[n]
+}
+
+// the index isn't provided by the caller
+#[no_mangle]
+pub extern "C" fn fixed(n: usize) -> u32 {
+    let data = vec![1, 2, 3];
+    data[2] + n as u32
+}
+
+fn main() {}
@@ -1,6 +1,6 @@
 // run-rustfix
 
-#![allow(dead_code, unused)]
+#![allow(dead_code, unused, clippy::indexing_last_with_len)]
 
 use std::collections::*;
 
@@ -1,6 +1,7 @@
 // run-rustfix
 
 #![warn(clippy::get_last_with_len)]
+#![allow(clippy::indexing_last_with_len)]
 
 fn dont_use_last() {
     let x = vec![2, 3, 5];
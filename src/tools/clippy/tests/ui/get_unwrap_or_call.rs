@@ -0,0 +1,32 @@
+#![warn(clippy::get_unwrap_or_call)]
+#![allow(clippy::redundant_clone)]
+
+use std::collections::HashMap;
+
+fn compute() -> String {
+    String::from("default")
+}
+
+fn main() {
+    let v = vec![String::from("a")];
+    let fallback = String::from("b");
+    let fallback_ref = &fallback;
+    let i = 3;
+
+    let _ = v.get(i).unwrap_or(&compute());
+    let _ = v.get(i).unwrap_or(&fallback.clone());
+    let _ = v.get(i).unwrap_or(&fallback_ref.clone());
+
+    let numbers = vec![1, 2, 3];
+    let _ = numbers.get(i).unwrap_or(&numbers.iter().sum());
+
+    let map: HashMap<u32, String> = HashMap::new();
+    let _ = map.get(&1).unwrap_or(&compute());
+
+    // Ok
+    let _ = v.get(i).unwrap_or(&fallback);
+    let _ = v.get(i).unwrap_or(fallback_ref);
+    let _ = numbers.get(i).unwrap_or(&0);
+    let _ = v.get(i).unwrap_or(&String::new());
+    let _ = v.get(i).cloned().unwrap_or_else(compute);
+}
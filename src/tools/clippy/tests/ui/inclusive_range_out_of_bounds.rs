@@ -0,0 +1,26 @@
+#![warn(clippy::inclusive_range_out_of_bounds)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, clippy::out_of_bounds_indexing)]
+
+const LEN: usize = 4;
+
+fn main() {
+    let x = [1, 2, 3, 4];
+    let v = vec![0; 3];
+    let s: &[u8] = &[1, 2];
+
+    &x[..=4];
+    &x[1..=LEN];
+    &x[..=5];
+    &v[..=3];
+    &s[..=usize::MAX];
+    &v[1..=usize::MAX];
+    let end = usize::MAX;
+    &s[..=end];
+
+    // Ok
+    &x[..=3];
+    &x[..4];
+    &v[..=2];
+    &s[..=1];
+    &s[1..];
+}
@@ -0,0 +1,21 @@
+#![warn(clippy::index_map)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation)]
+
+use std::collections::{BTreeMap, HashMap};
+
+fn main() {
+    let mut map: HashMap<String, u32> = HashMap::new();
+    let key = String::from("apples");
+    map[&key];
+    let _ = &map["apples"];
+    map[&key].to_string();
+    let tree: BTreeMap<u32, Vec<u8>> = BTreeMap::new();
+    let _ = tree[&1].len();
+    let _ = &tree[&1][..];
+
+    // Ok
+    map.get(&key);
+    *map.entry(key).or_insert(0) += 1;
+    let v = vec![1, 2];
+    v[0];
+}
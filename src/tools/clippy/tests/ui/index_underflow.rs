@@ -0,0 +1,40 @@
+#![warn(clippy::index_underflow)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, clippy::many_single_char_names)]
+
+fn main() {
+    underflow(&[1, 2, 3], 1, 2, 1);
+}
+
+fn underflow(v: &[u8], i: usize, n: usize, x: u8) {
+    v[i - 1];
+    v[v.len() - n];
+    &v[i - 1..];
+    &v[..v.len() - n];
+    v[(x - 1) as usize];
+    v[i - 1 + n];
+    v[(i - 1) - 1];
+
+    // Ok
+    v[i + 1];
+    v[3 - 1];
+    if i > 0 {
+        v[i - 1];
+    }
+    if i >= 2 {
+        v[i - 2];
+    }
+    if n <= v.len() {
+        v[v.len() - n];
+    }
+    if n <= i {
+        v[i - n];
+    }
+    if i > n + 1 && i < v.len() {
+        v[i - (n + 1)];
+    }
+    for j in 1..v.len() {
+        v[j - 1];
+    }
+    let k: i32 = 1;
+    [1, 2][(k - 1) as usize];
+}
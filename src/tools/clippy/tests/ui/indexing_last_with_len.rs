@@ -0,0 +1,74 @@
+// run-rustfix
+
+#![warn(clippy::indexing_last_with_len)]
+#![allow(clippy::get_last_with_len, clippy::ptr_arg)]
+
+struct Point {
+    x: i32,
+}
+
+fn vec() {
+    let x = vec![2u32, 3, 5];
+    let _ = x[x.len() - 1];
+    let _ = &x[x.len() - 1];
+    let _ = x[x.len() - 1].count_ones();
+}
+
+fn slice(x: &[u32], y: &mut [u32]) {
+    let _ = x[x.len() - 1];
+    y[y.len() - 1] = 3;
+    y[y.len() - 1] += 1;
+    let _ = &mut y[y.len() - 1];
+}
+
+fn references(v: &Vec<u32>, a: &[u32; 3]) {
+    let _ = v[v.len() - 1];
+    let _ = a[a.len() - 1];
+}
+
+fn array() {
+    let mut x = [Point { x: 1 }, Point { x: 2 }];
+    let _ = x[x.len() - 1].x;
+    x[x.len() - 1].x = 3;
+}
+
+fn nested() {
+    let mut x = [vec![1], vec![2, 3]];
+    x[x.len() - 1][0] = 4;
+    x[x.len() - 1].push(5);
+}
+
+fn through_bindings() {
+    let x = vec![2, 3, 5];
+    let len = x.len();
+    let _ = x[len - 1];
+    let last = x.len() - 1;
+    let _ = x[last];
+}
+
+fn dont_lint() {
+    let x = vec![2, 3, 5];
+    let y = vec![1, 2];
+
+    // not the last element
+    let _ = x[x.len() - 2];
+
+    // different receiver
+    let _ = x[y.len() - 1];
+
+    // the length may change between the binding and the indexing
+    let mut z = vec![2, 3, 5];
+    let len = z.len();
+    z.push(7);
+    let _ = z[len - 1];
+}
+
+fn main() {
+    vec();
+    slice(&[1], &mut [2]);
+    references(&vec![1], &[1, 2, 3]);
+    array();
+    nested();
+    through_bindings();
+    dont_lint();
+}
@@ -0,0 +1,144 @@
+#![warn(clippy::indexing_slicing, clippy::out_of_bounds_indexing)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, clippy::needless_range_loop)]
+
+fn guarded_by_if(x: &[u8], y: &[u8], i: usize, j: usize) {
+    if i < x.len() {
+        x[i]; // Ok, `i` is in bounds
+        y[i];
+        &x[..i]; // Ok
+        &x[i..]; // Ok
+        &x[i + 1..]; // Ok
+    }
+    if i < x.len() && j <= x.len() {
+        &x[..=i]; // Ok
+        &x[..j]; // Ok
+        &x[i..j];
+    }
+    if i < x.len() && i < y.len() {
+        x[i] + y[i]; // Ok
+    }
+    if x.len() > i + 1 {
+        x[i + 1]; // Ok
+    }
+    if i >= x.len() {
+        return;
+    } else {
+        x[i]; // Ok
+    }
+    if !(i >= x.len() || j >= y.len()) {
+        x[i] + y[j]; // Ok
+    }
+}
+
+struct Buffer {
+    items: Vec<u8>,
+    pos: usize,
+}
+
+impl Buffer {
+    fn guarded_by_expression(&mut self, x: &[u8], i: usize, j: usize) {
+        if self.pos < self.items.len() {
+            self.items[self.pos]; // Ok
+        }
+        if i <= j && j <= x.len() {
+            &x[i..j]; // Ok
+            &x[i..=j]; // `j` can be the length
+        }
+        if i < x.len() && x[i] == 0 {} // Ok
+        if i >= x.len() || x[i] == 0 {} // Ok
+        if self.pos < self.items.len() {
+            self.items.clear();
+            self.items[self.pos]; // `self` is mutated after the check
+        }
+    }
+}
+
+fn guarded_by_constant(i: usize) {
+    let x = [1, 2, 3, 4];
+    if i < 4 {
+        x[i]; // Ok
+        &x[i..]; // Ok
+    }
+    if i <= 4 {
+        x[i];
+        &x[..i]; // Ok
+    }
+    if i > 5 {
+        x[i];
+        &x[i..];
+    }
+    if i == 2 {
+        x[i]; // Ok
+    }
+}
+
+fn guarded_by_loop(x: &[u8], mut v: Vec<u8>) {
+    for i in 0..x.len() {
+        x[i]; // Ok
+        &x[..=i]; // Ok
+    }
+    for i in 2..=3 {
+        [1, 2, 3, 4][i]; // Ok
+    }
+    for i in 0..v.len() {
+        v[i]; // Ok
+    }
+    let mut i = 0;
+    while i < x.len() {
+        x[i]; // `i` is mutated in the loop
+        i += 1;
+    }
+    let i = 1;
+    while i < v.len() {
+        v[i]; // `v` is mutated in the loop
+        v.pop();
+    }
+}
+
+fn guarded_by_assert(x: &[u8], mut v: Vec<u8>, i: usize, mut j: usize) {
+    assert!(i < x.len());
+    x[i]; // Ok
+    v[i];
+    assert!(j < v.len(), "index out of bounds");
+    v[j]; // Ok
+    v.clear();
+    v[j]; // `v` is mutated after the assertion
+    assert_eq!(j, 3);
+    [1, 2, 3, 4][j]; // Ok
+    j += 1;
+    [1, 2, 3, 4][j]; // `j` is mutated after the assertion
+    debug_assert!(i < v.len());
+    v[i]; // `debug_assert!` isn't checked in release builds
+}
+
+fn guarded_by_early_return(x: &[u8], i: usize, j: usize) -> u8 {
+    if i >= x.len() {
+        return 0;
+    }
+    let first = x[i]; // Ok
+    if j >= x.len() {
+        panic!("out of bounds");
+    }
+    if j < 2 {
+        println!("small index");
+    }
+    let second = x[j]; // Ok
+    {
+        let inner = x[i]; // Ok, in a nested block
+        if i > 2 {
+            return inner;
+        }
+    }
+    first + second
+}
+
+fn guarded_in_loop(x: &[u8]) {
+    for i in 0.. {
+        if i >= x.len() {
+            break;
+        }
+        x[i]; // Ok
+    }
+}
+
+fn main() {}
@@ -7,7 +7,7 @@
 fn main() {
     let x = [1, 2, 3, 4];
     let index: usize = 1;
-    x[index];
+    x[index]; // Ok, the index is known to be in bounds.
     x[4]; // Ok, let rustc's `const_err` lint handle `usize` indexing on arrays.
     x[1 << 3]; // Ok, let rustc's `const_err` lint handle `usize` indexing on arrays.
 
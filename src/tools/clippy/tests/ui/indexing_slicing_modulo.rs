@@ -0,0 +1,46 @@
+#![warn(clippy::indexing_slicing, clippy::index_underflow)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, clippy::len_zero)]
+
+fn modulo(x: &[u8], v: &mut Vec<u8>, i: usize) {
+    x[i % x.len()];
+    v[i % v.len()];
+    x[i & (x.len() - 1)];
+    x[i % v.len()];
+}
+
+fn non_empty(x: &[u8], v: &mut Vec<u8>, i: usize) {
+    if !x.is_empty() {
+        x[i % x.len()]; // Ok
+        x[i & (x.len() - 1)]; // Ok
+        x[(x.len() - 1) & i]; // Ok
+    }
+    if x.len() > 0 {
+        x[i % x.len()]; // Ok
+    }
+    if x.len() != 0 && i > 0 {
+        x[i % x.len()]; // Ok
+    }
+    if v.is_empty() {
+        return;
+    }
+    v[i % v.len()]; // Ok
+    v.clear();
+    v[i % v.len()];
+}
+
+fn not_non_empty(x: &[u8], y: &[u8], i: usize) {
+    if x.is_empty() {
+        x[i % x.len()];
+    }
+    if !y.is_empty() {
+        x[i % x.len()];
+    }
+}
+
+fn arrays(a: [u8; 4], i: usize) {
+    a[i % a.len()]; // Ok
+    let v = vec![0; 4];
+    v[i % v.len()]; // Ok
+}
+
+fn main() {}
@@ -0,0 +1,78 @@
+#![warn(clippy::indexing_slicing, clippy::out_of_bounds_indexing, clippy::string_slice)]
+#![allow(
+    clippy::no_effect,
+    clippy::unnecessary_operation,
+    clippy::many_single_char_names,
+    clippy::redundant_slicing
+)]
+
+use std::ops::{Bound, Index, Range, RangeBounds};
+
+struct Span {
+    start: usize,
+    end: usize,
+}
+
+impl RangeBounds<usize> for Span {
+    fn start_bound(&self) -> Bound<&usize> {
+        Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&usize> {
+        Bound::Excluded(&self.end)
+    }
+}
+
+struct Buf(Vec<u8>);
+
+impl Index<Span> for Buf {
+    type Output = [u8];
+
+    fn index(&self, span: Span) -> &[u8] {
+        self.0.get(span.start..span.end).unwrap_or_default()
+    }
+}
+
+fn stored(x: &[u8], v: &mut Vec<u8>, n: usize) {
+    let r = 1..n;
+    &x[r];
+    let r = ..n;
+    &v[r];
+
+    let arr = [1, 2, 3, 4];
+    let r = 2..6;
+    &arr[r];
+    let r = 5..;
+    &arr[r];
+    let r = ..=4;
+    &arr[r];
+
+    let s = "hello";
+    let r = 1..n;
+    &s[r];
+}
+
+fn stored_len_is_stale(v: &mut Vec<u8>, n: usize) {
+    if n <= v.len() {
+        let r = ..n;
+        v.clear();
+        &v[r];
+    }
+}
+
+fn opaque(x: &[u8], s: &str, r: Range<usize>, buf: &Buf, span: Span) {
+    &x[r.clone()];
+    &s[r];
+    &x[(Bound::Included(1), Bound::Excluded(2))];
+    &buf[span];
+}
+
+fn ok(x: &[u8]) {
+    let arr = [1, 2, 3, 4];
+    let r = 1..3;
+    &arr[r];
+    let r = ..;
+    &x[r];
+}
+
+fn main() {}
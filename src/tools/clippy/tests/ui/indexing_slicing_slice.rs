@@ -5,10 +5,11 @@
 #![allow(clippy::no_effect, clippy::unnecessary_operation)]
 
 fn main() {
+    slicing(1, 2, 3);
+}
+
+fn slicing(index: usize, index_from: usize, index_to: usize) {
     let x = [1, 2, 3, 4];
-    let index: usize = 1;
-    let index_from: usize = 2;
-    let index_to: usize = 3;
     &x[index..];
     &x[..index];
     &x[index_from..index_to];
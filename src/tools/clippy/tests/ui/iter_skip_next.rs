@@ -19,4 +19,11 @@ fn main() {
     let foo = IteratorFalsePositives { foo: 0 };
     let _ = foo.skip(42).next();
     let _ = foo.filter().skip(42).next();
+
+    // the result is always `None`
+    let array = [0, 1, 2, 3];
+    let _ = array.iter().skip(4).next();
+    let zeros = vec![0; 4];
+    let _ = zeros.into_iter().skip(5).next();
+    let _ = array.iter().skip(3).next();
 }
@@ -20,6 +20,23 @@ fn main() {
     // check const eval
     let v1 = vec![1, 2, 3];
     let _ = v1.iter().step_by(2 / 3);
+
+    // check local bindings and `const fn`s
+    let step = 0;
+    let _ = (0..1).step_by(step);
+    let _ = (0..1).step_by(zero());
+    let _ = (0..1).step_by(ZERO);
+
+    // No warning for a mutated binding
+    let mut step = 0;
+    step += 1;
+    let _ = (0..1).step_by(step);
+}
+
+const ZERO: usize = 0;
+
+const fn zero() -> usize {
+    0
 }
 
 struct NotIterator;
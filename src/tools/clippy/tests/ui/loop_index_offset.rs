@@ -0,0 +1,51 @@
+#![warn(clippy::loop_index_offset)]
+#![allow(clippy::needless_range_loop, clippy::many_single_char_names)]
+
+fn sum(v: &[u32], n: usize, k: usize) -> u32 {
+    let mut sum = 0;
+    for i in 0..n {
+        sum += v[i + k];
+    }
+    for i in 0..v.len() {
+        sum += v[i + 1];
+    }
+    for i in 2..n {
+        sum += v[k + i];
+    }
+    for i in 0..=n {
+        let _ = (0..3).map(|j| v[i + j]);
+    }
+    sum
+}
+
+fn ok(v: &[u32], a: [u32; 8], n: usize, k: usize) -> u32 {
+    let mut sum = 0;
+    // bounded by the length minus the offset
+    for i in 0..v.len() - 1 {
+        sum += v[i + 1];
+    }
+    for i in 0..v.len() - 2 {
+        sum += v[i + 2] + v[i + 1] + v[i];
+    }
+    for i in 1..=v.len() - 3 {
+        sum += v[i + 2];
+    }
+    // bounded by the length of the array
+    for i in 0..4 {
+        sum += a[i + 3];
+    }
+    // not a loop variable
+    let i = n;
+    sum += v[i + k];
+    // not an offset
+    for i in 0..n {
+        sum += v[i] + v[i * 2];
+    }
+    // not a range
+    for i in (0..n).rev() {
+        sum += v[i + 1];
+    }
+    sum
+}
+
+fn main() {}
@@ -0,0 +1,77 @@
+#![warn(clippy::manual_binary_search)]
+#![allow(clippy::comparison_chain)]
+
+fn lower_bound(v: &[i32], target: i32) -> usize {
+    let mut lo = 0;
+    let mut hi = v.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if v[mid] < target {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+fn find(v: &[u64], target: u64) -> Option<usize> {
+    let (mut low, mut high) = (0, v.len());
+    while low < high {
+        let mid = (low + high) >> 1;
+        match v[mid].cmp(&target) {
+            std::cmp::Ordering::Less => low = mid + 1,
+            std::cmp::Ordering::Greater => high = mid,
+            std::cmp::Ordering::Equal => return Some(mid),
+        }
+    }
+    None
+}
+
+fn inclusive(v: [u8; 8], target: u8) -> Option<usize> {
+    let mut lo = 0;
+    let mut hi = v.len() - 1;
+    while lo <= hi {
+        let mid = (lo + hi) / 2;
+        if v[mid] == target {
+            return Some(mid);
+        } else if v[mid] < target {
+            lo = mid + 1;
+        } else {
+            hi = mid - 1;
+        }
+    }
+    None
+}
+
+// Ok, the slice isn't indexed
+fn bisect(f: impl Fn(u64) -> bool) -> u64 {
+    let mut lo = 0;
+    let mut hi = u64::MAX;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if f(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    lo
+}
+
+// Ok, not a midpoint
+fn scan(v: &[i32], target: i32) -> usize {
+    let mut lo = 0;
+    let mut hi = v.len();
+    while lo < hi {
+        let next = lo + 1;
+        if v[next - 1] < target {
+            lo = next;
+        } else {
+            hi = next;
+        }
+    }
+    lo
+}
+
+fn main() {}
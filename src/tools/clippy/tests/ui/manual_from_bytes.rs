@@ -0,0 +1,53 @@
+#![warn(clippy::manual_from_bytes)]
+#![allow(clippy::identity_op, clippy::precedence, clippy::cast_lossless, clippy::ptr_arg)]
+
+use std::convert::TryInto;
+
+fn slice(buf: &[u8]) -> Result<u32, std::array::TryFromSliceError> {
+    let _ = (buf[0] as u32) << 24 | (buf[1] as u32) << 16 | (buf[2] as u32) << 8 | buf[3] as u32;
+    // the order of the bytes doesn't matter
+    let _ = buf[3] as u32 | (buf[2] as u32) << 8 | (buf[1] as u32) << 16 | (buf[0] as u32) << 24;
+    let _ = (buf[0] as i64)
+        | (buf[1] as i64) << 8
+        | (buf[2] as i64) << 16
+        | (buf[3] as i64) << 24
+        | (buf[4] as i64) << 32
+        | (buf[5] as i64) << 40
+        | (buf[6] as i64) << 48
+        | (buf[7] as i64) << 56;
+    Ok(0)
+}
+
+fn vec(buf: &Vec<u8>) -> Result<u32, std::array::TryFromSliceError> {
+    let _ = (u32::from(buf[2]) << 24) + (u32::from(buf[3]) << 16) + (u32::from(buf[4]) << 8) + u32::from(buf[5]);
+    Ok(0)
+}
+
+fn array(bytes: [u8; 2], header: &[u8; 4]) {
+    let _ = bytes[0] as u16 | (bytes[1] as u16) << 8;
+    let _ = (header[0] as u32) << 24 | (header[1] as u32) << 16 | (header[2] as u32) << 8 | header[3] as u32;
+}
+
+macro_rules! be_u16 {
+    ($buf:expr) => {
+        ($buf[0] as u16) << 8 | $buf[1] as u16
+    };
+}
+
+fn no_lint(buf: &[u8], other: &[u8], i: usize) {
+    // a wrong shift
+    let _ = (buf[0] as u32) << 24 | (buf[1] as u32) << 16 | (buf[2] as u32) << 4 | buf[3] as u32;
+    // a missing byte
+    let _ = (buf[0] as u32) << 24 | (buf[1] as u32) << 16 | buf[3] as u32;
+    // a repeated byte
+    let _ = (buf[0] as u16) << 8 | buf[0] as u16;
+    // different buffers
+    let _ = (buf[0] as u16) << 8 | other[1] as u16;
+    // indexes that aren't constant
+    let _ = (buf[i] as u16) << 8 | buf[i + 1] as u16;
+    // fewer bytes than the integer has
+    let _ = (buf[0] as u32) << 8 | buf[1] as u32;
+    let _ = be_u16!(buf);
+}
+
+fn main() {}
@@ -0,0 +1,56 @@
+// run-rustfix
+#![warn(clippy::manual_slice_pattern)]
+#![allow(unused)]
+
+struct Parts {
+    parts: Vec<u8>,
+}
+
+impl Parts {
+    fn first(&self) -> u8 {
+        let a = self.parts[0];
+        let b = self.parts[1];
+        a + b
+    }
+}
+
+fn slice(x: &[u32]) -> u32 {
+    let a = x[0];
+    let mut b = x[1];
+    let c = x[2];
+    b += 1;
+    a + b + c
+}
+
+fn vec(v: Vec<(u8, u8)>) {
+    let first = v[0];
+    let second = v[1];
+    println!("{:?} {:?}", first, second);
+}
+
+fn main() {
+    // Ok
+    let x = vec![1, 2, 3];
+    let a = x[0];
+    println!("{}", a);
+
+    let a = x[1];
+    let b = x[2];
+
+    let arr = [1, 2, 3];
+    let a = arr[0];
+    let b = arr[1];
+
+    let y = vec![4, 5, 6];
+    let a = x[0];
+    let b = y[1];
+
+    let a = x[0];
+    let b: i32 = x[1];
+
+    let a = &x[0];
+    let b = &x[1];
+
+    let x = x[0];
+    let b = x;
+}
@@ -0,0 +1,34 @@
+// run-rustfix
+#![warn(clippy::manual_zip)]
+#![allow(unused, clippy::needless_range_loop)]
+
+fn dot(a: &[i32], b: &[i32]) -> i32 {
+    let mut sum = 0;
+    for i in 0..a.len() {
+        sum += a[i] * b[i];
+    }
+    sum
+}
+
+fn three(a: &[f64], b: &[f64], c: &[f64], n: usize) -> f64 {
+    let mut sum = 0.0;
+    for i in 0..n {
+        sum += a[i] * b[i] + c[i] - a[i];
+    }
+    for i in 1..=n {
+        sum += a[i] + b[i];
+    }
+    sum
+}
+
+fn enumerated(a: &[u32], b: &[u32]) -> usize {
+    let mut count = 0;
+    for i in 2..a.len() {
+        if a[i] == b[i] {
+            count += i;
+        }
+    }
+    count
+}
+
+fn main() {}
@@ -0,0 +1,38 @@
+#![warn(clippy::manual_zip)]
+#![allow(clippy::needless_range_loop)]
+
+fn mutated(a: &mut [i32], b: &[i32]) {
+    for i in 0..a.len() {
+        a[i] += b[i];
+    }
+}
+
+fn borrowed(a: &[String], b: &[String]) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    for i in 0..a.len() {
+        pairs.push((a[i].clone(), b[i].clone()));
+    }
+    pairs
+}
+
+fn not_linted(a: &[i32], b: &[i32], c: &mut Vec<i32>) {
+    // only one sequence
+    for i in 0..a.len() {
+        c.push(a[i]);
+    }
+    // different indexes
+    for i in 0..a.len() - 1 {
+        c.push(a[i] + b[i + 1]);
+    }
+    // the sequence is used otherwise
+    for i in 0..a.len() {
+        c.push(a[i] + b[i] + a.len() as i32);
+    }
+    // the sequence is declared in the loop
+    for i in 0..a.len() {
+        let d = vec![0; 4];
+        c.push(a[i] + d[i]);
+    }
+}
+
+fn main() {}
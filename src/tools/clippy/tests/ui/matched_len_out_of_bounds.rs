@@ -0,0 +1,42 @@
+#![warn(clippy::matched_len_out_of_bounds, clippy::indexing_slicing)]
+#![allow(clippy::cast_possible_truncation)]
+
+fn matched(v: &[u8], w: &mut Vec<u8>) -> u8 {
+    match v.len() {
+        0 => v[0],
+        2 => v[0] + v[2],
+        3 | 4 => v[4],
+        5..=7 => v[9],
+        _ => 0,
+    };
+    match w.len() {
+        1 => w[1],
+        _ => 0,
+    }
+}
+
+fn consistent(v: &[u8], w: &mut Vec<u8>, i: usize) -> u8 {
+    // Ok, in bounds for every length of the arm
+    match v.len() {
+        2 => v[0] + v[1],
+        3 | 4 => v[2],
+        n @ 5..=7 => v[4] + n as u8,
+        8..=100 => v[7],
+        _ => 0,
+    };
+    // the index isn't known to be in bounds in these arms
+    match v.len() {
+        2 => v[i],
+        3..=5 => v[3],
+        _ => 0,
+    };
+    match w.len() {
+        2 => {
+            w.clear();
+            w[1]
+        },
+        _ => 0,
+    }
+}
+
+fn main() {}
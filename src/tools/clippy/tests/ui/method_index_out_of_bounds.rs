@@ -0,0 +1,75 @@
+#![warn(clippy::method_index_out_of_bounds)]
+#![allow(clippy::many_single_char_names)]
+
+fn main() {
+    let mut v = vec![0; 4];
+    v.remove(4);
+    let mut v = vec![0; 4];
+    let _ = v.swap_remove(5);
+
+    let x = [1, 2, 3, 4];
+    let _ = x.split_at(5);
+    let r = &x;
+    let _ = r.split_at(6);
+
+    let mut w = Vec::with_capacity(10);
+    w.resize(2, 0);
+    w.remove(2);
+
+    let n = 7;
+    let mut u = vec![0; 4];
+    u.swap_remove(n);
+
+    let mut y = [0; 8];
+    let _ = y.split_at_mut(9);
+    let _ = x[..2].split_at(3);
+    let _ = y[4..].split_at_mut(5);
+    let _ = (&u[1..=2]).split_at(3);
+
+    let mut v = vec![0; 4];
+    let _ = v.split_off(5);
+    let mut v = vec![0; 4];
+    v.drain(0..5);
+    let mut v = vec![0; 4];
+    v.drain(..=4);
+    let mut v = vec![0; 4];
+    v.drain(6..);
+    let mut v = vec![0; 4];
+    v.splice(2..8, [1, 2].iter().copied());
+
+    // Ok
+    let mut v = vec![0; 4];
+    let _ = v.split_at(4);
+    v.remove(3);
+    let _ = x.split_at(4);
+    let _ = y.split_at_mut(8);
+    let _ = x[..2].split_at(2);
+    let _ = y[4..].split_at_mut(4);
+    let mut v = vec![0; 4];
+    let _ = v.split_off(4);
+    let mut v = vec![0; 4];
+    v.drain(1..4);
+    let mut v = vec![0; 4];
+    v.drain(..=3);
+    let mut v = vec![0; 4];
+    v.drain(4..);
+    let mut v = vec![0; 4];
+    v.splice(.., [1, 2].iter().copied());
+
+    // Ok, the length changes
+    let mut v = vec![0; 4];
+    v.push(1);
+    v.remove(4);
+
+    // Ok, `VecDeque::remove` returns an `Option`
+    let mut d = std::collections::VecDeque::from(vec![1]);
+    d.remove(3);
+}
+
+fn param(v: &mut Vec<u8>, s: &[u8]) {
+    // Ok, the length is unknown
+    v.remove(10);
+    v.drain(..10);
+    let _ = s.split_at(10);
+    let _ = s[2..].split_at(10);
+}
@@ -0,0 +1,10 @@
+#![warn(clippy::indexing_slicing)]
+
+// `indexing_slicing` has a migration note announcing the new default of
+// `allow-indexing-slicing-in-tests`
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let i = v.len();
+    let _ = v[i];
+}
@@ -50,3 +50,14 @@ fn f(_i: u32, _j: u32) -> u32 {
 
     if i != j && f(i, j) != 0 || i == j && f(i, j) != 1 {}
 }
+
+fn not_in_macro_definition() {
+    macro_rules! nonzero {
+        ($x:expr) => {
+            !($x == 0)
+        };
+    }
+
+    let i = 1;
+    if nonzero!(i) {}
+}
@@ -0,0 +1,44 @@
+#![warn(clippy::nonzero_new_unwrap)]
+
+use std::num::{NonZeroI32, NonZeroU8, NonZeroUsize};
+
+fn unknown(n: usize, i: i32) {
+    let _ = NonZeroUsize::new(n).unwrap();
+    let _ = NonZeroI32::new(i).unwrap();
+    let _ = NonZeroU8::new(0).unwrap();
+    if i > -1 {
+        let _ = NonZeroI32::new(i).unwrap();
+    }
+}
+
+fn known(n: usize, i: i32) {
+    let _ = NonZeroUsize::new(1).unwrap();
+    let size = 4;
+    let _ = NonZeroUsize::new(size).unwrap();
+    let _ = NonZeroUsize::new(n + 1).unwrap();
+    if n != 0 {
+        let _ = NonZeroUsize::new(n).unwrap();
+    }
+    assert!(n > 0);
+    let _ = NonZeroUsize::new(n).unwrap();
+    if i != 0 {
+        let _ = NonZeroI32::new(i).unwrap();
+    }
+    if i < 0 {
+        let _ = NonZeroI32::new(i).unwrap();
+    }
+    assert!(i > 0);
+    let _ = NonZeroI32::new(i).unwrap();
+}
+
+fn handled(n: usize) -> Option<NonZeroUsize> {
+    // No lint, the zero case is handled
+    let _ = NonZeroUsize::new(n).unwrap_or(NonZeroUsize::new(1)?);
+    NonZeroUsize::new(n)
+}
+
+fn main() {
+    unknown(1, 1);
+    known(1, 1);
+    handled(1);
+}
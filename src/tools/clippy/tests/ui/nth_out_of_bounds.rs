@@ -0,0 +1,27 @@
+#![warn(clippy::nth_out_of_bounds)]
+#![allow(clippy::iter_nth, clippy::iter_nth_zero, clippy::iter_skip_next, clippy::reversed_empty_ranges)]
+
+const N: usize = 8;
+
+fn main() {
+    let x = [1, 2, 3, 4];
+    let _ = x.iter().nth(9);
+    let _ = x.iter().nth(4);
+    let _ = x.iter().nth(N);
+    let _ = x.iter().nth(3); // Ok
+
+    let mut v = vec![0; 3];
+    let _ = v.iter_mut().nth(5);
+    let _ = v.iter().nth(2); // Ok
+
+    let _ = (0..3).nth(10);
+    let _ = (0..=3).nth(4);
+    let _ = (-2i32..2).nth(4);
+    let _ = (0..=3).nth(3); // Ok
+    let _ = (5..3).nth(0);
+    let _ = (0..).nth(100); // Ok
+
+    let i = std::env::args().count();
+    let _ = x.iter().nth(i); // Ok
+    let _ = (0..i).nth(9); // Ok
+}
@@ -0,0 +1,25 @@
+#![warn(clippy::out_of_bounds_indexing)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, const_err)]
+
+use std::cmp::{max, min};
+
+const HEADER: u8 = 2;
+const LEN: usize = 6;
+
+fn main() {
+    let x = [1, 2, 3, 4];
+
+    &x[..max(LEN, 2)];
+    &x[min(LEN, 5)..];
+    &x[..LEN.min(7)];
+    &x[usize::from(HEADER) + 3..];
+    &x[..LEN.saturating_sub(1)];
+    &x[..3usize.saturating_add(2)];
+    &x[usize::from(true) + 4..];
+    &x[..usize::MAX.saturating_add(1) - usize::MAX + 5];
+
+    &x[..min(LEN, 4)]; // Ok, should not produce stderr.
+    &x[usize::from(HEADER)..LEN.max(1) - 2]; // Ok, should not produce stderr.
+    &x[..1usize.saturating_sub(LEN)]; // Ok, should not produce stderr.
+    &x[..usize::MAX.saturating_add(1) - usize::MAX + 4]; // Ok, should not produce stderr.
+}
@@ -0,0 +1,60 @@
+#![warn(clippy::out_of_bounds_indexing)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, const_err)]
+
+const HEADER: usize = 2;
+
+const fn magic_index() -> usize {
+    7
+}
+
+const fn header_end(fields: usize) -> usize {
+    let field_size = 2;
+    HEADER + fields * field_size
+}
+
+const fn last(len: usize) -> usize {
+    if len == 0 { 0 } else { len - 1 }
+}
+
+const fn nested(n: usize) -> usize {
+    header_end(n) + last(n)
+}
+
+const fn recursive(n: usize) -> usize {
+    if n == 0 { 0 } else { 1 + recursive(n - 1) }
+}
+
+const fn generic<T>(n: usize) -> usize {
+    n
+}
+
+fn not_const() -> usize {
+    7
+}
+
+struct Layout;
+
+impl Layout {
+    const fn size() -> usize {
+        5
+    }
+}
+
+fn main() {
+    let x = [1, 2, 3, 4];
+    let v = vec![0; 4];
+
+    x[magic_index()];
+    v[magic_index()];
+    &x[..header_end(2)];
+    x[last(5)];
+    x[nested(1)];
+    x[Layout::size()];
+    x[recursive(3) + 1];
+
+    x[last(4)]; // Ok, should not produce stderr.
+    &x[..header_end(1)]; // Ok, should not produce stderr.
+    x[recursive(100)]; // Not evaluated: too deep.
+    x[generic::<u8>(7)]; // Not evaluated: generic.
+    x[not_const()]; // Not evaluated: not a `const fn`.
+}
@@ -0,0 +1,32 @@
+#![warn(clippy::out_of_bounds_indexing, clippy::indexing_slicing)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, const_err)]
+
+const ARR: [u8; 4] = [1, 2, 3, 4];
+const BOUNDS: (usize, usize) = (2, 6);
+
+struct Layout {
+    header: usize,
+    offsets: (usize, usize),
+}
+
+const LAYOUT: Layout = Layout {
+    header: 3,
+    offsets: (1, 4),
+};
+
+fn main() {
+    let x = [1, 2, 3, 4];
+
+    &x[BOUNDS.0..BOUNDS.1];
+    &x[LAYOUT.offsets.1 + 1..];
+    &x[..<[u8]>::len(&ARR) + 1];
+
+    let pair = (1, 5);
+    &x[pair.0..pair.1];
+
+    x[BOUNDS.0]; // Ok, should not produce stderr.
+    x[LAYOUT.header]; // Ok, should not produce stderr.
+    x[LAYOUT.offsets.0]; // Ok, should not produce stderr.
+    &x[LAYOUT.offsets.0..LAYOUT.offsets.1]; // Ok, should not produce stderr.
+    &x[..<[u8]>::len(&ARR)]; // Ok, should not produce stderr.
+}
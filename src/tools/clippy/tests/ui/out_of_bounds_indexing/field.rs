@@ -0,0 +1,35 @@
+#![warn(clippy::out_of_bounds_indexing)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, clippy::boxed_local, dead_code, unconditional_panic)]
+
+struct Buffer {
+    data: [u8; 4],
+    header: (u8, [u8; 2]),
+}
+
+impl Buffer {
+    fn read(&self) {
+        self.data[4];
+        self.header.1[2];
+        self.data[3]; // Ok
+
+        const IDX: usize = 7;
+        self.data[IDX];
+    }
+
+    fn write(&mut self) {
+        self.data[5] = 0;
+    }
+}
+
+fn by_ref(buffer: &Buffer, boxed: Box<Buffer>) {
+    buffer.data[4];
+    boxed.data[6];
+}
+
+fn by_value(buffer: Buffer, array: [u8; 4]) {
+    // Ok, rustc's `unconditional_panic` lint catches these
+    buffer.data[4];
+    array[4];
+}
+
+fn main() {}
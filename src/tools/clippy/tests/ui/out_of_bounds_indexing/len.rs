@@ -0,0 +1,25 @@
+#![warn(clippy::out_of_bounds_indexing)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, const_err)]
+
+const ARR: [u8; 4] = [1, 2, 3, 4];
+
+struct Wrapper {
+    inner: [u8; 3],
+}
+
+fn main() {
+    let x = [1, 2, 3, 4];
+    let r = &x;
+    let w = Wrapper { inner: [0; 3] };
+
+    &x[x.len()..x.len() + 2];
+    &x[..x.len() + 1];
+    &r[r.len() + 1..];
+    &ARR[..ARR.len() + 1];
+    &w.inner[w.inner.len() + 1..];
+
+    &x[x.len()..]; // Ok, should not produce stderr.
+    &x[..x.len()]; // Ok, should not produce stderr.
+    &x[x.len() - 2..]; // Ok, should not produce stderr.
+    &ARR[1..ARR.len()]; // Ok, should not produce stderr.
+}
@@ -19,4 +19,21 @@ fn main() {
 
     &x[0..].get(..3); // Ok, should not produce stderr.
     &x[0..3]; // Ok, should not produce stderr.
+
+    // Immutable bindings initialized with a constant are looked through.
+    let n = 5;
+    &x[n..];
+    &x[..n];
+    let m = n - 3;
+    &x[m..]; // Ok, should not produce stderr.
+
+    let mut k = 5;
+    k -= 3;
+    &x[k..]; // Ok, mutable bindings aren't looked through.
+}
+
+fn param(x: [i32; 4], i: usize) {
+    if i >= 4 {
+        x[i];
+    }
 }
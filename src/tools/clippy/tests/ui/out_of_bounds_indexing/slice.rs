@@ -0,0 +1,58 @@
+#![warn(clippy::out_of_bounds_indexing)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, clippy::many_single_char_names, unconditional_panic)]
+
+fn slices(x: [i32; 4], k: usize) {
+    let s: &[i32] = &x;
+    s[4];
+    &s[1..5];
+    &s[5..];
+    &s[..5];
+    &s[..=4];
+    &s[2..=4];
+
+    s[3]; // Ok
+    &s[1..4]; // Ok
+    &s[..=3]; // Ok
+    &s[4..]; // Ok
+
+    // Values computed from constants are followed through the locals they are stored in.
+    let i = 2;
+    let j = i * 3;
+    s[j];
+    s[j - 3]; // Ok
+    let r = 1..j;
+    &s[r];
+
+    let lit: &[u8] = &[1, 2, 3];
+    lit[3];
+
+    // Ok, the index isn't constant.
+    let n = if k > 2 { 1 } else { 9 };
+    s[n];
+    let mut m = 1;
+    m += 8;
+    s[m];
+    s[k];
+}
+
+fn mutable(x: &mut [u8; 2]) {
+    let s: &mut [u8] = x;
+    s[2] = 0;
+    s[1] = 0; // Ok
+}
+
+fn arrays(x: [i32; 4]) {
+    // Ok, rustc's `unconditional_panic` lint catches these.
+    let i = 9;
+    x[i];
+    let rx = &x;
+    rx[9];
+}
+
+#[allow(clippy::out_of_bounds_indexing)]
+fn allowed(x: [i32; 4]) {
+    let s: &[i32] = &x;
+    s[4];
+}
+
+fn main() {}
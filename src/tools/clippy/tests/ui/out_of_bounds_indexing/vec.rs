@@ -0,0 +1,67 @@
+#![warn(clippy::out_of_bounds_indexing)]
+#![allow(
+    clippy::no_effect,
+    clippy::unnecessary_operation,
+    clippy::slow_vector_initialization,
+    clippy::many_single_char_names
+)]
+
+const LEN: usize = 4;
+
+fn main() {
+    let v = vec![0; 4];
+    v[4];
+    &v[1..5];
+    &v[5..];
+    &v[..=4];
+
+    v[3]; // Ok, should not produce stderr.
+    &v[..4]; // Ok, should not produce stderr.
+    &v[1..3]; // Ok, should not produce stderr.
+
+    let w = vec![1u8; LEN];
+    w[LEN];
+
+    let mut x = Vec::with_capacity(10);
+    x.resize(2, 0);
+    x[2];
+    x[1]; // Ok, should not produce stderr.
+
+    let mut y = vec![0; 2];
+    y[0] = 1;
+    y[2];
+}
+
+fn mutated() {
+    let mut v = vec![0; 4];
+    v.push(1);
+    v[4]; // Ok, `v` has been mutated.
+
+    let mut w = vec![0; 4];
+    w[4]; // Mutating `w` after indexing doesn't matter.
+    w.clear();
+
+    let mut x = Vec::with_capacity(10);
+    for i in 0..3 {
+        x.push(i);
+    }
+    x[2]; // Ok, `x` has been mutated.
+
+    let mut y = vec![0; 2];
+    let mut grow = || y.push(1);
+    grow();
+    y[3]; // Ok, `y` is mutated in a closure.
+
+    let z = vec![0; 2];
+    if true {
+        z[2];
+    }
+}
+
+fn unknown_len(n: usize) {
+    let v = vec![0; n];
+    v[4]; // Ok, the length isn't a constant.
+
+    let w = vec![1, 2];
+    w[2]; // Ok, only `vec![elem; N]` is handled.
+}
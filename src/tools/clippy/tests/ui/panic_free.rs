@@ -0,0 +1,28 @@
+#![deny(clippy::panic_free)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, clippy::needless_range_loop)]
+
+fn panics(v: &[u8], i: usize, o: Option<u8>, r: Result<u8, ()>) -> u8 {
+    v[i];
+    &v[1..i];
+    v[i - 1];
+    o.unwrap();
+    r.expect("no value");
+    let sum = v.len() + i;
+    if sum == 0 {
+        panic!("empty");
+    }
+    todo!()
+}
+
+#[allow(clippy::unwrap_used)]
+fn allowed(o: Option<u8>) -> u8 {
+    o.unwrap()
+}
+
+fn main() {
+    let v = [1, 2, 3];
+    for i in 0..v.len() {
+        v[i];
+    }
+    v.get(5);
+}
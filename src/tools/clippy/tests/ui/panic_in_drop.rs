@@ -0,0 +1,53 @@
+#![warn(clippy::panic_in_drop)]
+#![allow(clippy::unnecessary_operation)]
+
+use std::collections::HashMap;
+
+struct Buffers {
+    data: Vec<u8>,
+    lookup: [u8; 4],
+    names: HashMap<u32, String>,
+    file: Option<std::fs::File>,
+}
+
+impl Drop for Buffers {
+    fn drop(&mut self) {
+        let _ = self.file.take().unwrap();
+        let _ = self.names.get(&1).expect("the name is set in `new`");
+        let _ = self.data[0];
+        let _ = &self.names[&1];
+        self.data.iter().for_each(|_| {
+            let _ = self.data[1];
+        });
+        if self.data.is_empty() {
+            panic!("no data");
+        }
+        assert_eq!(self.data.len(), 4);
+        unreachable!();
+    }
+}
+
+struct Fine {
+    lookup: [u8; 4],
+    data: Vec<u8>,
+}
+
+impl Drop for Fine {
+    fn drop(&mut self) {
+        // constant indexes into arrays can't panic
+        let _ = self.lookup[3];
+        let _ = &self.data[..];
+        let _ = self.data.get(0);
+        let _ = self.data.first().copied().unwrap_or_default();
+        debug_assert!(self.data.is_empty());
+    }
+}
+
+impl Fine {
+    fn not_drop(&mut self) {
+        let _ = self.data[0];
+        let _ = self.data.first().unwrap();
+    }
+}
+
+fn main() {}
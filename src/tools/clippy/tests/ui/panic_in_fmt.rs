@@ -0,0 +1,50 @@
+#![warn(clippy::panic_in_fmt)]
+
+use std::collections::HashMap;
+use std::fmt;
+
+struct Name {
+    parts: Vec<String>,
+    initials: [char; 2],
+    aliases: HashMap<u32, String>,
+    title: Option<String>,
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.title.as_ref().unwrap())?;
+        write!(f, "{}", self.parts[0])?;
+        write!(f, "{}", &self.parts[1][..1])?;
+        write!(f, "{}", self.aliases.get(&0).expect("the first alias is set"))?;
+        write!(f, "{}{}", self.initials[0], self.initials[1])
+    }
+}
+
+impl fmt::Debug for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for part in &self.parts[1..] {
+            write!(f, "{} ", part)?;
+        }
+        write!(f, "{:?}", &self.parts[..])
+    }
+}
+
+struct Fine(Vec<u8>);
+
+impl fmt::Display for Fine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.first() {
+            Some(first) => write!(f, "{}", first),
+            None => write!(f, "<empty>"),
+        }
+    }
+}
+
+// not a formatting trait
+impl Fine {
+    fn fmt(&self) -> u8 {
+        self.0[0]
+    }
+}
+
+fn main() {}
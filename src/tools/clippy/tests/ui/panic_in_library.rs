@@ -0,0 +1,34 @@
+#![crate_type = "lib"]
+#![warn(clippy::panic_in_library)]
+
+pub fn parse_digit(c: char) -> u32 {
+    match c.to_digit(10) {
+        Some(d) => d,
+        None => panic!("not a digit: {}", c),
+    }
+}
+
+pub fn later() {
+    todo!()
+}
+
+pub fn never() {
+    unimplemented!()
+}
+
+pub fn checked(x: u32) -> u32 {
+    if x > 10 {
+        unreachable!("checked by the caller")
+    }
+    x
+}
+
+pub fn asserts(x: u32) {
+    assert!(x > 0);
+    assert_eq!(x, 1);
+    debug_assert!(x > 0);
+}
+
+pub fn fine(c: char) -> Option<u32> {
+    c.to_digit(10)
+}
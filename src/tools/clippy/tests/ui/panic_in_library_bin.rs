@@ -0,0 +1,8 @@
+#![warn(clippy::panic_in_library)]
+
+fn main() {
+    if std::env::args().count() > 3 {
+        panic!("too many arguments");
+    }
+    todo!()
+}
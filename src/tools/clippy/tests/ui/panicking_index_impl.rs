@@ -0,0 +1,73 @@
+#![warn(clippy::panicking_index_impl)]
+
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+
+struct Registry {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+}
+
+impl Index<usize> for Registry {
+    type Output = String;
+
+    fn index(&self, id: usize) -> &String {
+        &self.names[id]
+    }
+}
+
+impl IndexMut<usize> for Registry {
+    fn index_mut(&mut self, id: usize) -> &mut String {
+        self.names.get_mut(id).expect("unknown id")
+    }
+}
+
+impl Index<&str> for Registry {
+    type Output = usize;
+
+    fn index(&self, name: &str) -> &usize {
+        let id = self.ids.get(name);
+        id.unwrap()
+    }
+}
+
+struct Documented(Vec<u8>);
+
+impl Index<usize> for Documented {
+    type Output = u8;
+
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    fn index(&self, i: usize) -> &u8 {
+        &self.0[i]
+    }
+}
+
+struct WithGet(Vec<u8>);
+
+impl WithGet {
+    fn get(&self, i: usize) -> Option<&u8> {
+        self.0.get(i)
+    }
+}
+
+impl Index<usize> for WithGet {
+    type Output = u8;
+
+    fn index(&self, i: usize) -> &u8 {
+        self.get(i).unwrap()
+    }
+}
+
+struct Clamped(Vec<u8>, u8);
+
+impl Index<usize> for Clamped {
+    type Output = u8;
+
+    fn index(&self, i: usize) -> &u8 {
+        self.0.get(i).unwrap_or(&self.1)
+    }
+}
+
+fn main() {}
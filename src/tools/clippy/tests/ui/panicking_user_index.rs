@@ -0,0 +1,82 @@
+#![warn(clippy::panicking_user_index)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation)]
+
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+
+struct Grid {
+    cells: Vec<u8>,
+    width: usize,
+}
+
+impl Grid {
+    fn get(&self, (x, y): (usize, usize)) -> Option<&u8> {
+        if x < self.width {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+}
+
+impl Index<(usize, usize)> for Grid {
+    type Output = u8;
+
+    /// # Panics
+    ///
+    /// Panics if the cell is outside of the grid.
+    fn index(&self, (x, y): (usize, usize)) -> &u8 {
+        self.get((x, y)).unwrap()
+    }
+}
+
+impl IndexMut<(usize, usize)> for Grid {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut u8 {
+        assert!(x < self.width, "column out of bounds");
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+struct Names(Vec<String>);
+
+impl Index<&str> for Names {
+    type Output = String;
+
+    fn index(&self, name: &str) -> &String {
+        match self.0.iter().find(|n| *n == name) {
+            Some(n) => n,
+            None => panic!("unknown name `{}`", name),
+        }
+    }
+}
+
+struct Clamped(Vec<u8>, u8);
+
+impl Index<usize> for Clamped {
+    type Output = u8;
+
+    fn index(&self, i: usize) -> &u8 {
+        debug_assert!(i < 100);
+        self.0.get(i).unwrap_or(&self.1)
+    }
+}
+
+fn main() {
+    let mut grid = Grid {
+        cells: vec![0; 4],
+        width: 2,
+    };
+    grid[(0, 1)];
+    grid[(1, 1)] = 3;
+    let names = Names(vec!["a".to_string()]);
+    &names["a"];
+
+    // Ok
+    let clamped = Clamped(vec![1], 0);
+    clamped[3];
+    let v = vec![1, 2, 3];
+    v[1];
+    let m: HashMap<u8, u8> = HashMap::new();
+    m[&1];
+    [1, 2, 3][1];
+}
@@ -0,0 +1,61 @@
+#![warn(clippy::partially_read_buffer)]
+#![allow(clippy::unused_io_amount)]
+
+use std::io::{self, Read, Write};
+
+fn copy(mut input: impl Read, mut output: impl Write) -> io::Result<()> {
+    let mut buf = [0u8; 64];
+    let n = input.read(&mut buf)?;
+    output.write_all(&buf[..])?;
+    output.write_all(&buf[..n])?;
+    Ok(())
+}
+
+fn copy_loop(mut input: impl Read, mut output: impl Write) -> io::Result<()> {
+    let mut buf = [0u8; 64];
+    loop {
+        let n = input.read(&mut buf[..])?;
+        if n == 0 {
+            break;
+        }
+        output.write_all(&buf)?;
+    }
+    Ok(())
+}
+
+fn to_vec(mut input: impl Read) -> Vec<u8> {
+    let mut buf = [0u8; 16];
+    let _n = input.read(&mut buf).unwrap();
+    buf.to_vec()
+}
+
+fn ok(mut input: impl Read, mut output: impl Write) -> io::Result<()> {
+    let mut buf = [0u8; 64];
+    let n = input.read(&mut buf)?;
+    output.write_all(&buf[..n])?;
+
+    // the count is checked
+    let n = input.read(&mut buf)?;
+    if n < buf.len() {
+        return Ok(());
+    }
+    output.write_all(&buf)?;
+
+    // the buffer is filled again
+    let _n = input.read(&mut buf)?;
+    input.read_exact(&mut buf)?;
+    output.write_all(&buf)?;
+
+    // the rest of the buffer is cleared
+    let n = input.read(&mut buf)?;
+    buf[n..].iter_mut().for_each(|b| *b = 0);
+    output.write_all(&buf)?;
+
+    // not an array
+    let mut v = vec![0u8; 64];
+    let _n = input.read(&mut v)?;
+    output.write_all(&v)?;
+    Ok(())
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+// compile-flags: --profile=ci
+
+fn main() {
+    let v = vec![1];
+    let _ = v.len() == 0;
+}
+
+fn unfinished() {
+    todo!()
+}
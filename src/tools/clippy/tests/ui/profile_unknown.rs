@@ -0,0 +1,3 @@
+// compile-flags: --profile=unknown
+
+fn main() {}
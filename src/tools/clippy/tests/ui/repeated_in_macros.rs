@@ -0,0 +1,29 @@
+#![warn(clippy::indexing_slicing)]
+
+macro_rules! first {
+    ($slice:expr) => {
+        $slice[0]
+    };
+}
+
+macro_rules! get {
+    ($slice:expr, $i:expr) => {{
+        let slice: &[u8] = $slice;
+        slice[$i]
+    }};
+}
+
+fn main() {
+    let x: &[u8] = &[1, 2, 3];
+    // the indexing is in the macro, the lint is emitted once with a note counting the other calls
+    first!(x);
+    first!(x);
+
+    get!(x, 0);
+    get!(x, 1);
+    get!(x, 2);
+
+    // lints in the arguments are emitted for every call
+    get!(x, x[0] as usize);
+    get!(x, x[1] as usize);
+}
@@ -0,0 +1,42 @@
+#![warn(clippy::repeated_indexing)]
+#![allow(clippy::many_single_char_names)]
+
+use std::collections::HashMap;
+
+fn compute_key(x: u32) -> usize {
+    x as usize
+}
+
+fn bad(data: &mut [u32], x: u32) {
+    if data[compute_key(x)] > 10 {
+        data[compute_key(x)] -= 10;
+    } else {
+        data[compute_key(x)] = 0;
+    }
+}
+
+fn bad_map(map: &HashMap<String, u32>, name: &str) -> u32 {
+    let a = map[&name.to_lowercase()];
+    let b = map[&name.to_lowercase()] * 2;
+    let f = || map[&name.to_lowercase()];
+    a + b + f()
+}
+
+fn bad_arithmetic(v: &[u32], i: usize) -> u32 {
+    v[i + 1] + v[i + 1] * v[i + 1] + v[i + 1]
+}
+
+fn ok(data: &[u32], x: u32, i: usize) -> u32 {
+    const K: usize = 2;
+    // trivial indexes
+    let a = data[i] + data[i] + data[i];
+    let b = data[K] + data[K] + data[K];
+    let c = data[0] + data[0] + data[0];
+    // only twice
+    let d = data[compute_key(x)] + data[compute_key(x)];
+    // different collections
+    let e = data[i + 1] + data[i + 1..][i + 1] + data[..][i + 1];
+    a + b + c + d + e
+}
+
+fn main() {}
@@ -1,13 +1,8 @@
 #![warn(clippy::reversed_empty_ranges)]
 
 const ANSWER: i32 = 42;
-const SOME_NUM: usize = 3;
 
 fn main() {
-    let arr = [1, 2, 3, 4, 5];
-    let _ = &arr[3usize..=1usize];
-    let _ = &arr[SOME_NUM..1];
-
     for _ in ANSWER..ANSWER {}
 
     // Should not be linted, see issue #5689
@@ -0,0 +1,31 @@
+#![warn(clippy::reversed_slice_range)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation)]
+
+const SOME_NUM: usize = 3;
+
+fn main() {
+    let arr = [1, 2, 3, 4, 5, 6];
+    let v = vec![1, 2, 3, 4, 5, 6];
+    let s = "hello";
+
+    // These should be linted:
+    &arr[5..2];
+    &arr[3usize..=1usize];
+    &arr[SOME_NUM..1];
+    &v[4..=2];
+    &s[3..1];
+
+    let start = 4;
+    &v[start..2];
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let range = 5..2;
+    &arr[range];
+
+    // These should be ignored as they don't panic:
+    &arr[2..5];
+    &arr[2..2];
+    &arr[3..=2];
+    &v[start..];
+    &v[..2];
+}
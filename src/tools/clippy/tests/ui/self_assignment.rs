@@ -1,4 +1,5 @@
 #![warn(clippy::self_assignment)]
+#![allow(clippy::out_of_bounds_indexing)]
 
 pub struct S<'a> {
     a: i32,
@@ -0,0 +1,26 @@
+#![warn(clippy::signed_index_cast)]
+#![allow(
+    unused,
+    clippy::no_effect,
+    clippy::unnecessary_operation,
+    clippy::many_single_char_names
+)]
+
+use std::convert::TryFrom;
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let i: i32 = 1;
+    let j: i64 = 2;
+    v[i as usize];
+    &v[i as usize..j as usize];
+    &v[..=(i + 1) as usize];
+
+    // Ok
+    let n: u32 = 1;
+    v[n as usize];
+    v[1i32 as usize];
+    v[usize::try_from(i).unwrap()];
+    let k = i as usize;
+    v[k];
+}
@@ -0,0 +1,43 @@
+#![warn(clippy::size_of_out_of_bounds)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, dead_code)]
+
+use std::mem::{self, size_of};
+
+#[repr(C)]
+struct Header {
+    magic: u32,
+    len: u32,
+}
+
+#[repr(C)]
+struct Body {
+    data: [u8; 16],
+}
+
+fn generic<T>(buf: &[u8; 4]) {
+    // the size of `T` isn't known
+    &buf[..size_of::<T>()];
+}
+
+fn main() {
+    let buf = [0u8; 4];
+    let large = [0u8; 20];
+    let v = vec![0u8; 12];
+
+    // These should be linted:
+    &buf[..size_of::<Header>()];
+    &buf[..mem::size_of::<u64>()];
+    &large[size_of::<Header>()..size_of::<Header>() + size_of::<Body>()];
+    &v[size_of::<Body>()..];
+    buf[size_of::<Header>()];
+
+    // These should be ignored as they are in bounds:
+    &large[..size_of::<Header>()];
+    &large[size_of::<Header>()..];
+    &buf[..size_of::<u32>()];
+    &v[..size_of::<Header>()];
+
+    // These are linted by `out_of_bounds_indexing`:
+    #[allow(clippy::out_of_bounds_indexing)]
+    &buf[..8];
+}
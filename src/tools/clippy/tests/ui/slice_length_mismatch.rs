@@ -0,0 +1,25 @@
+#![warn(clippy::slice_length_mismatch)]
+
+fn main() {
+    let src = [1, 2, 3];
+    let mut dst = [0; 8];
+
+    dst[..4].copy_from_slice(&src);
+    dst.copy_from_slice(&src);
+    dst[2..4].clone_from_slice(&src[..]);
+    let mut v = vec![0; 5];
+    v[..].swap_with_slice(&mut dst[2..]);
+    dst[1..=3].copy_from_slice(&[1, 2]);
+
+    // Ok
+    dst[..3].copy_from_slice(&src);
+    dst[5..].clone_from_slice(&src);
+    v.swap_with_slice(&mut dst[3..]);
+    dst[1..=3].copy_from_slice(&src);
+}
+
+fn param(dst: &mut [u8], src: &[u8]) {
+    // Ok, the lengths are unknown
+    dst.copy_from_slice(src);
+    dst[..4].copy_from_slice(&src[1..]);
+}
@@ -0,0 +1,37 @@
+#![warn(clippy::split_indexing)]
+#![allow(clippy::needless_collect, clippy::collect_then_index)]
+
+fn direct(line: &str) {
+    let _ = line.split('=').collect::<Vec<_>>()[1];
+    let _ = line.splitn(2, ',').collect::<Vec<&str>>()[1];
+    let _ = line.split_whitespace().collect::<Vec<_>>()[0];
+    let i = 2;
+    let _ = line.rsplit(':').collect::<Vec<_>>()[i];
+
+    // Ok, `split` always returns at least one part
+    let _ = line.split('=').collect::<Vec<_>>()[0];
+    let _ = line.splitn(3, ',').collect::<Vec<_>>()[0];
+    // Ok, not indexed with a single index
+    let _ = &line.split('=').collect::<Vec<_>>()[1..];
+}
+
+fn local(line: &str) {
+    let parts: Vec<&str> = line.split(' ').collect();
+    let _ = parts[2];
+
+    // Ok, the length is checked
+    let fields: Vec<_> = line.split(';').collect();
+    if fields.len() == 3 {
+        let _ = fields[2];
+    }
+
+    // Ok, the parts may have changed
+    let mut words: Vec<_> = line.split(' ').collect();
+    words.push("end");
+    let _ = words[1];
+}
+
+fn main() {
+    direct("a=b");
+    local("a b c");
+}
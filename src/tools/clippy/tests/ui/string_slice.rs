@@ -0,0 +1,23 @@
+#![warn(clippy::string_slice)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation)]
+
+fn main() {
+    let s = String::from("Ünicode");
+    let n = 1;
+    &s[..n];
+    &s[n..];
+    &s[1..3];
+
+    let t: &str = &s;
+    &t[n..=2];
+    let _ = &mut String::new()[..n];
+
+    let ascii = "hello";
+    &ascii[1..3]; // Ok, should not produce stderr.
+    let u = "Ünicode";
+    &u[..2]; // Ok, should not produce stderr.
+    &u[..1];
+    &u[..10];
+
+    &s[..]; // Ok, should not produce stderr.
+}
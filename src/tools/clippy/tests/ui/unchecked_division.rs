@@ -0,0 +1,70 @@
+#![warn(clippy::unchecked_division)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, clippy::integer_division)]
+#![allow(clippy::many_single_char_names, unconditional_panic)]
+
+fn unchecked(a: u32, b: u32, c: i64, d: i64) {
+    a / b;
+    a % b;
+    c / d;
+    (a + 1) / (b * 2);
+    let mut x = a;
+    x /= b;
+    x %= b;
+}
+
+fn constants(a: u32, c: i64) {
+    const TWO: u32 = 2;
+    a / 3;
+    a % TWO;
+    c / -1;
+    let n = 4;
+    a / n;
+    a / 0;
+}
+
+fn guarded(a: u32, b: u32, c: i64, d: i64, v: &[u32]) {
+    if b != 0 {
+        a / b;
+    }
+    if b > 0 {
+        a % b;
+    }
+    if 0 != b && a > 1 {
+        a / b;
+    }
+    if b == 0 {
+        return;
+    }
+    a / b;
+    for i in 1..10 {
+        a / i;
+    }
+    let len = v.len();
+    assert!(len > 0);
+    len % len;
+    if d != 0 {
+        c / d;
+    }
+    if d < 0 {
+        c % d;
+    }
+}
+
+fn not_guarded(a: u32, mut b: u32, c: i64, d: i64) {
+    if b != 0 {
+        b -= 1;
+        a / b;
+    }
+    if b == 0 {
+        a / b;
+    }
+    if d > -1 {
+        c / d;
+    }
+}
+
+fn floats(a: f64, b: f64) {
+    a / b;
+}
+
+fn main() {}
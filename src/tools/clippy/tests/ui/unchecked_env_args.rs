@@ -0,0 +1,54 @@
+#![warn(clippy::unchecked_env_args)]
+#![allow(
+    clippy::collect_then_index,
+    clippy::iter_nth_zero,
+    clippy::iter_skip_next,
+    clippy::single_match
+)]
+
+use std::env;
+
+fn unwrap() {
+    let _ = env::args().nth(1).unwrap();
+    let _ = env::args_os().nth(2).unwrap();
+    let _ = env::args().skip(1).next().unwrap();
+    let _ = std::env::args().skip(1).nth(1).unwrap();
+
+    // the path of the program
+    let _ = env::args().next().unwrap();
+    let _ = env::args().nth(0).unwrap();
+
+    // `expect()` can describe the usage
+    let _ = env::args().nth(1).expect("usage: program <path>");
+}
+
+fn index() {
+    let args: Vec<String> = env::args().collect();
+    let _ = &args[1];
+    let _ = &env::args().collect::<Vec<_>>()[2];
+    let _ = &args[0];
+    // no panic without arguments
+    let _ = &args[1..];
+
+    if args.len() > 2 {
+        let _ = &args[2];
+    }
+    if 1 < args.len() {
+        let _ = &args[1];
+    }
+    match args.len() {
+        3 => {
+            let _ = (&args[1], &args[2]);
+        },
+        _ => {},
+    }
+
+    // not the command line arguments
+    let other: Vec<String> = vec!["a".to_string(), "b".to_string()];
+    let _ = &other[1];
+}
+
+fn main() {
+    unwrap();
+    index();
+}
@@ -0,0 +1,33 @@
+#![warn(clippy::unguarded_get_unchecked)]
+#![allow(clippy::needless_range_loop)]
+
+unsafe fn unguarded(v: &[u8], mut w: Vec<u8>, i: usize) {
+    let _ = v.get_unchecked(i);
+    let _ = w.get_unchecked_mut(i);
+    let _ = v.get_unchecked(1..i);
+    let _ = v.get_unchecked(..=i);
+    if i <= v.len() {
+        let _ = v.get_unchecked(i);
+    }
+    assert!(i < w.len());
+    w.clear();
+    let _ = w.get_unchecked_mut(i);
+}
+
+unsafe fn guarded(v: &[u8], mut w: Vec<u8>, i: usize) {
+    if i < v.len() {
+        let _ = v.get_unchecked(i);
+        let _ = v.get_unchecked(..i);
+        let _ = v.get_unchecked(i..);
+    }
+    assert!(i < w.len());
+    let _ = w.get_unchecked_mut(i);
+    for j in 0..v.len() {
+        let _ = v.get_unchecked(j);
+    }
+    let a = [1, 2, 3];
+    let _ = a.get_unchecked(2);
+    let _ = a.get_unchecked(1..3);
+}
+
+fn main() {}
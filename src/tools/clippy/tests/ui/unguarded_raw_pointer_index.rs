@@ -0,0 +1,39 @@
+#![warn(clippy::unguarded_raw_pointer_index)]
+#![allow(clippy::ptr_offset_with_cast)]
+
+use std::slice;
+
+unsafe fn unguarded(data: *const u32, out: *mut u32, len: usize, i: usize) {
+    let _ = *data.add(i);
+    let _ = data.offset(i as isize).read();
+    out.add(i).write(0);
+    let _ = slice::from_raw_parts(data, len)[i];
+    let _ = &slice::from_raw_parts(data, len)[1..i];
+    if i < 4 {
+        let _ = slice::from_raw_parts(data, len)[i];
+    }
+    if i <= len {
+        let _ = slice::from_raw_parts(data, len)[i];
+    }
+}
+
+unsafe fn guarded(data: *const u32, out: *mut u32, len: usize, i: usize) {
+    let _ = *data;
+    let _ = *data.add(0);
+    if i < len {
+        let _ = *data.add(i);
+        out.add(i).write(0);
+        let _ = slice::from_raw_parts(data, len)[i];
+        let _ = &slice::from_raw_parts(data, len)[..i];
+        let _ = &slice::from_raw_parts(data, len)[i..];
+    }
+    assert!(i < len);
+    let _ = data.offset(i as isize).read();
+    for j in 0..len {
+        let _ = *data.add(j);
+        let _ = slice::from_raw_parts(data, len)[j];
+    }
+    let _ = slice::from_raw_parts(data, 4)[3];
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+#![warn(clippy::unhelpful_expect_message)]
+
+macro_rules! expect_empty {
+    ($e:expr) => {
+        $e.expect("")
+    };
+}
+
+struct NotAnOption;
+
+impl NotAnOption {
+    fn expect(&self, _: &str) {}
+}
+
+fn main() {
+    let opt: Option<u32> = Some(1);
+    let res: Result<u32, ()> = Ok(1);
+
+    // empty messages
+    let _ = opt.expect("");
+    let _ = res.expect("   ");
+
+    // messages that don't describe the expectation
+    let _ = opt.expect("expect failed");
+    let _ = res.expect("Unwrap failed!");
+    let _ = opt.expect("none");
+    let _ = (&res).expect("error");
+
+    // ok
+    let _ = opt.expect("the value is always set in `main`");
+    let _ = res.expect("unwrap the value computed before");
+    let msg = "";
+    let _ = opt.expect(msg);
+    let _ = expect_empty!(opt);
+    NotAnOption.expect("");
+}
+
+#[warn(clippy::unwrap_used)]
+fn unwrap_used(opt: Option<u32>) -> u32 {
+    opt.expect("")
+}
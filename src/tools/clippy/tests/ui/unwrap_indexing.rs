@@ -0,0 +1,36 @@
+#![warn(clippy::unwrap_indexing)]
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+
+fn values() -> Option<Vec<u32>> {
+    Some(vec![1, 2, 3])
+}
+
+fn parse() -> Result<[u32; 4], String> {
+    Ok([1, 2, 3, 4])
+}
+
+fn slice(v: &[u32]) -> Option<&[u32]> {
+    Some(v)
+}
+
+fn main() {
+    let i = std::env::args().count();
+    let _ = values().unwrap()[i];
+    let _ = &values().expect("no values")[1..];
+    let _ = parse().unwrap()[i];
+    let _ = slice(&[1, 2]).unwrap()[0];
+
+    // Ok
+    let _ = parse().unwrap()[3];
+    let _ = &values().unwrap()[..];
+    let v = values().unwrap();
+    let _ = v[i];
+    let s = Some("abc".to_string()).unwrap();
+    let _ = &s[..1];
+}
+
+fn try_op(i: usize) -> Option<u32> {
+    let x = values()?[i];
+    let y = parse().ok()?[i];
+    Some(x + y)
+}
@@ -0,0 +1,33 @@
+#![warn(clippy::unwrapped_first_or_last)]
+#![allow(clippy::unwrap_used, clippy::iter_next_slice)]
+
+use std::collections::VecDeque;
+
+fn unwrapped(v: &[u8], vec: Vec<u8>, s: &str) {
+    let _ = v.first().unwrap();
+    let _ = v.last().unwrap();
+    let _ = vec.first().unwrap();
+    let _ = v.iter().next().unwrap();
+    let _ = s.chars().next().unwrap();
+}
+
+struct Cursor;
+
+impl Cursor {
+    fn next(&mut self) -> Option<u8> {
+        None
+    }
+}
+
+fn fine(v: &[u8], deque: &VecDeque<u8>, mut cursor: Cursor) -> Option<u8> {
+    let _ = v.first().expect("`v` isn't empty");
+    if let Some(last) = v.last() {
+        let _ = last;
+    }
+    let _ = v.get(0).unwrap();
+    let _ = deque.back().unwrap();
+    let _ = cursor.next().unwrap();
+    v.iter().next().copied()
+}
+
+fn main() {}
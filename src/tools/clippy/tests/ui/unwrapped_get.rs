@@ -0,0 +1,27 @@
+#![warn(clippy::unwrapped_get, clippy::get_unwrap)]
+#![allow(clippy::expect_used)]
+
+use std::collections::{HashMap, VecDeque};
+
+fn unwrapped(v: &[u8], mut vec: Vec<u8>, deque: VecDeque<u8>, map: HashMap<u8, u8>, i: usize) {
+    let _ = v.get(i).unwrap();
+    let _ = v.get(i).expect("in bounds");
+    let _ = vec.get_mut(i).unwrap();
+    let _ = vec.get_mut(1).expect("in bounds");
+    let _ = deque.get(i).unwrap();
+    let _ = map.get(&1).unwrap();
+}
+
+#[warn(clippy::indexing_slicing)]
+fn with_indexing_slicing(v: &[u8], i: usize) {
+    // `get_unwrap` isn't emitted, it would suggest indexing
+    let _ = v.get(i).unwrap();
+}
+
+fn fine(v: &[u8], map: &mut HashMap<u8, u8>, i: usize) -> Option<u8> {
+    let _ = v.get(i).copied().unwrap_or_default();
+    let _ = map.get_mut(&1).unwrap();
+    Some(*v.get(i)?)
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+#![warn(clippy::unwrapped_index_conversion)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, clippy::many_single_char_names)]
+
+use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let a = [1, 2, 3];
+    let x: i64 = 1;
+    let y: u64 = 2;
+
+    v[usize::try_from(x).unwrap()];
+    a[usize::try_from(y).expect("index too large")];
+    v[TryInto::<usize>::try_into(x).unwrap()];
+    &v[usize::try_from(x).unwrap()..];
+    &a[..<u64 as TryInto<usize>>::try_into(y).unwrap()];
+
+    // Ok
+    let i = usize::try_from(x).unwrap();
+    v[i];
+    v[usize::from(1u8)];
+    v[1];
+    let _ = v.get(usize::try_from(x).unwrap_or(0));
+    let m = HashMap::<usize, u32>::new();
+    let _ = m[&usize::try_from(x).unwrap()];
+}
@@ -0,0 +1,67 @@
+#![warn(clippy::vec_front_in_loop)]
+#![allow(dead_code, clippy::vec_init_then_push)]
+
+fn queue() {
+    let mut queue = Vec::new();
+    queue.push(1);
+    while let Some(n) = queue.pop() {
+        if n < 10 && !queue.is_empty() {
+            queue.insert(0, n + 1);
+        }
+        let _ = queue.first();
+    }
+}
+
+fn remove_front() {
+    let mut items: Vec<u32> = Vec::with_capacity(4);
+    items.extend([1, 2, 3].iter().copied());
+    for _ in 0..2 {
+        let _ = items.remove(0);
+        let _ = items.remove(1);
+    }
+    for item in &items {
+        let _ = items[0] + item;
+    }
+}
+
+fn macro_init() {
+    let mut v = vec![];
+    loop {
+        v.insert(0, 1);
+        if v.len() > 3 {
+            break;
+        }
+    }
+}
+
+fn as_slice(v: &[i32]) -> i32 {
+    v[0]
+}
+
+// no suggestion: the vector is used as a slice
+fn used_as_slice() {
+    let mut v = Vec::new();
+    for i in 0..3 {
+        v.insert(0, i);
+    }
+    as_slice(&v);
+}
+
+// no suggestion: the vector isn't created empty
+fn parameter(v: &mut Vec<i32>) {
+    for i in 0..3 {
+        v.insert(0, i);
+    }
+}
+
+fn not_in_loop() {
+    let mut v = vec![1, 2];
+    v.insert(0, 0);
+    v.remove(0);
+    let _ = (0..3).map(|i| {
+        v.insert(1, i);
+        i
+    });
+}
+
+fn main() {}
@@ -0,0 +1,51 @@
+#![warn(clippy::with_capacity_indexing)]
+#![allow(
+    clippy::no_effect,
+    clippy::unnecessary_operation,
+    clippy::needless_range_loop,
+    clippy::many_single_char_names
+)]
+
+fn main() {
+    let mut v = Vec::with_capacity(10);
+    for i in 0..10 {
+        v[i] = i;
+    }
+
+    let w: Vec<u8> = Vec::with_capacity(4);
+    w[0];
+    &w[..2];
+    &w[1..];
+
+    let mut x = Vec::with_capacity(4);
+    x[0] = 1;
+    x.push(1);
+}
+
+fn ok(n: usize) {
+    let mut v = Vec::with_capacity(n);
+    v.push(1);
+    v[0];
+
+    let mut w = Vec::with_capacity(n);
+    w.resize(n, 1);
+    w[0];
+
+    let x: Vec<u8> = Vec::with_capacity(n);
+    &x[..];
+    &x[0..0];
+
+    let mut y = Vec::with_capacity(n);
+    if n > 0 {
+        y.push(1);
+    }
+    y[0];
+
+    let mut z = Vec::with_capacity(n);
+    fill(&mut z);
+    z[0];
+}
+
+fn fill(v: &mut Vec<u8>) {
+    v.push(1);
+}